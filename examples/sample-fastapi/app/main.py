@@ -43,7 +43,6 @@ async def lifespan(app: FastAPI):
     # Aerospike client with backpressure config
     config: dict = {
         "hosts": [(settings.aerospike_host, settings.aerospike_port)],
-        "policies": {"key": aerospike_py.POLICY_KEY_SEND},
     }
     if settings.max_concurrent_ops > 0:
         config["max_concurrent_operations"] = settings.max_concurrent_ops
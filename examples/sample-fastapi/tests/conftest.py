@@ -171,7 +171,6 @@ def aerospike_client(aerospike_container):
     container, port = aerospike_container
     config = {
         "hosts": [("127.0.0.1", port)],
-        "policies": {"key": aerospike_py.POLICY_KEY_SEND},
     }
     # Only set cluster_name when using the managed container (cluster-name "docker").
     if container is not None:
@@ -198,7 +197,6 @@ def client(aerospike_container):
 
         ac_config = {
             "hosts": [("127.0.0.1", port)],
-            "policies": {"key": aerospike_py.POLICY_KEY_SEND},
         }
         # Only set cluster_name when using the managed container.
         if container is not None:
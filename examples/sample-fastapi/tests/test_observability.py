@@ -135,7 +135,6 @@ def test_tracing_spans_sent_to_jaeger(aerospike_container, jaeger_container, aer
             {
                 "hosts": [("127.0.0.1", as_port)],
                 "cluster_name": "docker",
-                "policies": {"key": aerospike_py.POLICY_KEY_SEND},
             }
         )
         await ac.connect()
@@ -0,0 +1,426 @@
+//! Apache Arrow output for batch reads and query/scan results.
+//!
+//! Builds one Arrow column per bin straight from `aerospike_core` records and
+//! hands the resulting `RecordBatch` to `pyarrow` through the Arrow C Data
+//! Interface, so no per-value Python objects are created. The batch can then
+//! be passed zero-copy to pandas, polars, or duckdb.
+//!
+//! Column types are inferred from the values: `int` → `int64`, `float` →
+//! `float64` (a bin mixing ints and floats is widened to `float64`), `str` /
+//! GeoJSON → `utf8`, `bytes` / HLL → `binary`, `bool` → `bool`. Missing bins
+//! and `Nil` values become nulls. Lists, maps, and bins mixing otherwise
+//! incompatible types are rejected with `TypeError`.
+//!
+//! Compiled only with the `arrow` Cargo feature; without it the entry points
+//! raise `ClientError`.
+
+use aerospike_core::{BatchRecord, Record};
+use pyo3::prelude::*;
+
+#[cfg(feature = "arrow")]
+mod imp {
+    use std::sync::Arc;
+
+    use aerospike_core::{BatchRecord, Record, Value};
+    use arrow_array::builder::{
+        BinaryBuilder, BooleanBuilder, FixedSizeBinaryBuilder, Float64Builder, Int64Builder,
+        StringBuilder, UInt32Builder,
+    };
+    use arrow_array::ffi::{to_ffi, FFI_ArrowArray, FFI_ArrowSchema};
+    use arrow_array::{Array, ArrayRef, Int32Array, NullArray, StructArray};
+    use arrow_schema::{ArrowError, DataType, Field, Fields};
+    use log::debug;
+    use pyo3::exceptions::{PyTypeError, PyValueError};
+    use pyo3::prelude::*;
+
+    use crate::columns::{column_names, column_values, infer_column_kind, ColumnKind, Row};
+    use crate::errors::result_code_to_int;
+    use crate::numpy_support::float_value_to_f64;
+    use crate::record_helpers::record_ttl_seconds;
+
+    /// Length of an Aerospike key digest (RIPEMD-160).
+    const DIGEST_LEN: i32 = 20;
+
+    fn data_type(kind: ColumnKind) -> DataType {
+        match kind {
+            ColumnKind::Null => DataType::Null,
+            ColumnKind::Int => DataType::Int64,
+            ColumnKind::Float => DataType::Float64,
+            ColumnKind::Bool => DataType::Boolean,
+            ColumnKind::Utf8 => DataType::Utf8,
+            ColumnKind::Binary => DataType::Binary,
+            ColumnKind::List | ColumnKind::Map | ColumnKind::Other => {
+                unreachable!("rejected by bin_column_kind")
+            }
+        }
+    }
+
+    /// Infer the column kind for `bin`, rejecting collections and mixed types.
+    pub(super) fn bin_column_kind(bin: &str, rows: &[Row<'_>]) -> PyResult<ColumnKind> {
+        let unsupported = |kind: ColumnKind| {
+            matches!(kind, ColumnKind::List | ColumnKind::Map | ColumnKind::Other)
+        };
+        let holds = |kind: ColumnKind| {
+            PyTypeError::new_err(format!(
+                "bin '{bin}' holds a {} value, which output='arrow' does not support",
+                kind.label()
+            ))
+        };
+        match infer_column_kind(bin, rows) {
+            Ok(k) if unsupported(k) => Err(holds(k)),
+            Ok(k) => Ok(k),
+            Err((k, _)) if unsupported(k) => Err(holds(k)),
+            Err((_, n)) if unsupported(n) => Err(holds(n)),
+            Err((k, n)) => Err(PyTypeError::new_err(format!(
+                "bin '{bin}' mixes {} and {} values; Arrow columns need a single type",
+                k.label(),
+                n.label()
+            ))),
+        }
+    }
+
+    /// Build the Arrow array for one bin column of an already-inferred kind.
+    pub(super) fn build_bin_column(bin: &str, kind: ColumnKind, rows: &[Row<'_>]) -> ArrayRef {
+        let values = column_values(bin, rows);
+        match kind {
+            ColumnKind::Null => Arc::new(NullArray::new(rows.len())),
+            ColumnKind::List | ColumnKind::Map | ColumnKind::Other => {
+                unreachable!("rejected by bin_column_kind")
+            }
+            ColumnKind::Int => {
+                let mut b = Int64Builder::with_capacity(rows.len());
+                for v in values {
+                    match v {
+                        Some(Value::Int(i)) => b.append_value(*i),
+                        _ => b.append_null(),
+                    }
+                }
+                Arc::new(b.finish())
+            }
+            ColumnKind::Float => {
+                let mut b = Float64Builder::with_capacity(rows.len());
+                for v in values {
+                    match v {
+                        Some(Value::Float(f)) => b.append_value(float_value_to_f64(f)),
+                        Some(Value::Int(i)) => b.append_value(*i as f64),
+                        _ => b.append_null(),
+                    }
+                }
+                Arc::new(b.finish())
+            }
+            ColumnKind::Bool => {
+                let mut b = BooleanBuilder::with_capacity(rows.len());
+                for v in values {
+                    match v {
+                        Some(Value::Bool(x)) => b.append_value(*x),
+                        _ => b.append_null(),
+                    }
+                }
+                Arc::new(b.finish())
+            }
+            ColumnKind::Utf8 => {
+                let mut b = StringBuilder::new();
+                for v in values {
+                    match v {
+                        Some(Value::String(s)) | Some(Value::GeoJSON(s)) => b.append_value(s),
+                        _ => b.append_null(),
+                    }
+                }
+                Arc::new(b.finish())
+            }
+            ColumnKind::Binary => {
+                let mut b = BinaryBuilder::new();
+                for v in values {
+                    match v {
+                        Some(Value::Blob(x)) | Some(Value::HLL(x)) => b.append_value(x),
+                        _ => b.append_null(),
+                    }
+                }
+                Arc::new(b.finish())
+            }
+        }
+    }
+
+    fn arrow_err(e: ArrowError) -> PyErr {
+        PyValueError::new_err(format!("failed to build Arrow batch: {e}"))
+    }
+
+    /// Metadata columns prepended before the bin columns.
+    struct MetaColumns {
+        fields: Vec<Field>,
+        arrays: Vec<ArrayRef>,
+    }
+
+    fn meta_columns(
+        digests: &[Option<[u8; 20]>],
+        records: &[Option<&Record>],
+        result_codes: Option<&[i32]>,
+    ) -> PyResult<MetaColumns> {
+        let n = records.len();
+        let mut digest_b = FixedSizeBinaryBuilder::with_capacity(n, DIGEST_LEN);
+        for d in digests {
+            match d {
+                Some(d) => digest_b.append_value(d).map_err(arrow_err)?,
+                None => digest_b.append_null(),
+            }
+        }
+        let mut gen_b = UInt32Builder::with_capacity(n);
+        let mut ttl_b = UInt32Builder::with_capacity(n);
+        for rec in records {
+            match rec {
+                Some(r) => {
+                    gen_b.append_value(r.generation);
+                    ttl_b.append_value(record_ttl_seconds(r));
+                }
+                None => {
+                    gen_b.append_null();
+                    ttl_b.append_null();
+                }
+            }
+        }
+
+        let mut fields = vec![Field::new(
+            "_digest",
+            DataType::FixedSizeBinary(DIGEST_LEN),
+            true,
+        )];
+        let mut arrays: Vec<ArrayRef> = vec![Arc::new(digest_b.finish())];
+        if let Some(codes) = result_codes {
+            fields.push(Field::new("_result_code", DataType::Int32, false));
+            arrays.push(Arc::new(Int32Array::from(codes.to_vec())));
+        }
+        fields.push(Field::new("_gen", DataType::UInt32, true));
+        arrays.push(Arc::new(gen_b.finish()));
+        fields.push(Field::new("_ttl", DataType::UInt32, true));
+        arrays.push(Arc::new(ttl_b.finish()));
+        Ok(MetaColumns { fields, arrays })
+    }
+
+    /// Assemble meta + bin columns into a `StructArray` (the C Data Interface
+    /// representation of a record batch).
+    fn build_struct(
+        meta: MetaColumns,
+        bins: Option<&[String]>,
+        rows: &[Row<'_>],
+    ) -> PyResult<StructArray> {
+        let MetaColumns {
+            mut fields,
+            mut arrays,
+        } = meta;
+        for name in column_names(bins, rows) {
+            if name.starts_with('_') && fields.iter().any(|f| f.name() == &name) {
+                return Err(PyValueError::new_err(format!(
+                    "bin '{name}' collides with an Arrow metadata column"
+                )));
+            }
+            let kind = bin_column_kind(&name, rows)?;
+            arrays.push(build_bin_column(&name, kind, rows));
+            fields.push(Field::new(name, data_type(kind), true));
+        }
+        StructArray::try_new(Fields::from(fields), arrays, None).map_err(arrow_err)
+    }
+
+    /// Export a `StructArray` through the C Data Interface into a
+    /// `pyarrow.RecordBatch`.
+    fn export_to_pyarrow(py: Python<'_>, batch: StructArray) -> PyResult<Py<PyAny>> {
+        let pa = py.import("pyarrow").map_err(|_| {
+            pyo3::exceptions::PyImportError::new_err(
+                "output='arrow' requires pyarrow (pip install 'aerospike-py[arrow]')",
+            )
+        })?;
+        let (array, schema) = to_ffi(&batch.to_data()).map_err(arrow_err)?;
+        let array = Box::new(array);
+        let schema = Box::new(schema);
+        let array_ptr = &*array as *const FFI_ArrowArray as usize;
+        let schema_ptr = &*schema as *const FFI_ArrowSchema as usize;
+        // pyarrow moves the structs' contents out and marks them released,
+        // so dropping the boxes afterwards does not free the buffers.
+        let rb = pa
+            .getattr("RecordBatch")?
+            .call_method1("_import_from_c", (array_ptr, schema_ptr))?;
+        Ok(rb.unbind())
+    }
+
+    pub fn batch_to_arrow_py(
+        py: Python<'_>,
+        results: &[BatchRecord],
+        bins: Option<&[String]>,
+    ) -> PyResult<Py<PyAny>> {
+        debug!("Converting batch to arrow: records_count={}", results.len());
+        let result_codes: Vec<i32> = results
+            .iter()
+            .map(|br| br.result_code.as_ref().map_or(0, result_code_to_int))
+            .collect();
+        let records: Vec<Option<&Record>> = results
+            .iter()
+            .zip(&result_codes)
+            .map(|(br, &rc)| br.record.as_ref().filter(|_| rc == 0))
+            .collect();
+        let digests: Vec<Option<[u8; 20]>> = results.iter().map(|br| Some(br.key.digest)).collect();
+        let rows: Vec<Row<'_>> = records.iter().map(|r| r.map(|r| &r.bins)).collect();
+
+        let meta = meta_columns(&digests, &records, Some(&result_codes))?;
+        let batch = build_struct(meta, bins, &rows)?;
+        export_to_pyarrow(py, batch)
+    }
+
+    pub fn records_to_arrow_py(
+        py: Python<'_>,
+        results: &[Record],
+        bins: &[String],
+    ) -> PyResult<Py<PyAny>> {
+        debug!(
+            "Converting records to arrow: records_count={}",
+            results.len()
+        );
+        let records: Vec<Option<&Record>> = results.iter().map(Some).collect();
+        let digests: Vec<Option<[u8; 20]>> = results
+            .iter()
+            .map(|r| r.key.as_ref().map(|k| k.digest))
+            .collect();
+        let rows: Vec<Row<'_>> = results.iter().map(|r| Some(&r.bins)).collect();
+
+        let meta = meta_columns(&digests, &records, None)?;
+        let batch = build_struct(meta, Some(bins), &rows)?;
+        export_to_pyarrow(py, batch)
+    }
+}
+
+/// Convert batch read results into a `pyarrow.RecordBatch`.
+///
+/// Columns: `_digest`, `_result_code`, `_gen`, `_ttl`, then one column per
+/// bin (the `bins` selection order, or sorted bin names when reading all bins).
+/// Rows for failed or missing records have null metadata and bins.
+#[cfg(feature = "arrow")]
+pub fn batch_to_arrow_py(
+    py: Python<'_>,
+    results: &[BatchRecord],
+    bins: Option<&[String]>,
+) -> PyResult<Py<PyAny>> {
+    imp::batch_to_arrow_py(py, results, bins)
+}
+
+/// Convert query/scan records into a `pyarrow.RecordBatch`.
+///
+/// Columns: `_digest`, `_gen`, `_ttl`, then one column per bin.
+#[cfg(feature = "arrow")]
+pub fn records_to_arrow_py(
+    py: Python<'_>,
+    results: &[Record],
+    bins: &[String],
+) -> PyResult<Py<PyAny>> {
+    imp::records_to_arrow_py(py, results, bins)
+}
+
+#[cfg(not(feature = "arrow"))]
+fn arrow_unavailable() -> PyErr {
+    crate::errors::ClientError::new_err(
+        "output='arrow' is not available (compiled without 'arrow' feature)",
+    )
+}
+
+#[cfg(not(feature = "arrow"))]
+pub fn batch_to_arrow_py(
+    _py: Python<'_>,
+    _results: &[BatchRecord],
+    _bins: Option<&[String]>,
+) -> PyResult<Py<PyAny>> {
+    Err(arrow_unavailable())
+}
+
+#[cfg(not(feature = "arrow"))]
+pub fn records_to_arrow_py(
+    _py: Python<'_>,
+    _results: &[Record],
+    _bins: &[String],
+) -> PyResult<Py<PyAny>> {
+    Err(arrow_unavailable())
+}
+
+#[cfg(all(test, feature = "arrow"))]
+mod tests {
+    use std::collections::HashMap;
+
+    use aerospike_core::{FloatValue, Value};
+    use arrow_array::{Array, Float64Array, Int64Array, StringArray};
+    use pyo3::Python;
+
+    use super::imp::{bin_column_kind, build_bin_column};
+    use crate::columns::ColumnKind;
+
+    fn row(pairs: &[(&str, Value)]) -> HashMap<String, Value> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn int_and_float_widen_to_float() {
+        let a = row(&[("x", Value::Int(1))]);
+        let b = row(&[("x", Value::Float(FloatValue::from(2.5)))]);
+        let rows = vec![Some(&a), None, Some(&b)];
+        let kind = bin_column_kind("x", &rows).unwrap();
+        assert_eq!(kind, ColumnKind::Float);
+
+        let col = build_bin_column("x", kind, &rows);
+        let col = col.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(col.value(0), 1.0);
+        assert!(col.is_null(1));
+        assert_eq!(col.value(2), 2.5);
+    }
+
+    #[test]
+    fn missing_and_nil_become_null() {
+        let a = row(&[("x", Value::Int(7))]);
+        let b = row(&[("x", Value::Nil)]);
+        let c = row(&[]);
+        let rows = vec![Some(&a), Some(&b), Some(&c)];
+        let kind = bin_column_kind("x", &rows).unwrap();
+        assert_eq!(kind, ColumnKind::Int);
+
+        let col = build_bin_column("x", kind, &rows);
+        let col = col.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(col.value(0), 7);
+        assert_eq!(col.null_count(), 2);
+    }
+
+    #[test]
+    fn all_missing_is_null_column() {
+        let a = row(&[]);
+        let rows = vec![Some(&a), None];
+        assert_eq!(bin_column_kind("x", &rows).unwrap(), ColumnKind::Null);
+        assert_eq!(build_bin_column("x", ColumnKind::Null, &rows).len(), 2);
+    }
+
+    #[test]
+    fn geojson_shares_utf8_column() {
+        let a = row(&[("s", Value::String("a".into()))]);
+        let b = row(&[("s", Value::GeoJSON("{}".into()))]);
+        let rows = vec![Some(&a), Some(&b)];
+        let kind = bin_column_kind("s", &rows).unwrap();
+        assert_eq!(kind, ColumnKind::Utf8);
+        let col = build_bin_column("s", kind, &rows);
+        let col = col.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(col.value(1), "{}");
+    }
+
+    #[test]
+    fn mixed_types_rejected() {
+        Python::initialize();
+        let a = row(&[("x", Value::Int(1))]);
+        let b = row(&[("x", Value::String("one".into()))]);
+        let rows = vec![Some(&a), Some(&b)];
+        let err = bin_column_kind("x", &rows).unwrap_err();
+        assert!(err.to_string().contains("mixes int and str"));
+    }
+
+    #[test]
+    fn list_values_rejected() {
+        Python::initialize();
+        let a = row(&[("l", Value::List(vec![Value::Int(1)]))]);
+        let rows = vec![Some(&a)];
+        let err = bin_column_kind("l", &rows).unwrap_err();
+        assert!(err.to_string().contains("does not support"));
+    }
+}
@@ -2,8 +2,13 @@ use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 
 use crate::backpressure::OperationLimiter;
+use crate::bin_limits::BinLimits;
+use crate::cipher::BinCipher;
+use crate::circuit_breaker::CircuitBreaker;
 use crate::client_common;
 use crate::client_ops;
+use crate::cluster_name::ClusterNameGuard;
+use crate::compression::BinCompression;
 use aerospike_core::Client as AsClient;
 use arc_swap::ArcSwapOption;
 use log::{debug, info, trace, warn};
@@ -33,12 +38,57 @@ enum CloseOutcome {
     },
 }
 
-use crate::batch_types::{PendingBatchRead, PendingBatchRecords};
+impl CloseOutcome {
+    /// Close the client on a detached task and resolve once it is closed.
+    ///
+    /// Detaching means a cancelled `close()` / `__aexit__` still finishes
+    /// closing instead of leaving the state stuck at `CLOSING`.
+    async fn finish(client: Option<Arc<AsClient>>, state: Arc<AtomicU8>) -> PyResult<()> {
+        let task = tokio::spawn(async move {
+            let result = match client {
+                Some(c) => c.close().await.map_err(as_to_pyerr),
+                None => Ok(()),
+            };
+            // Always transition to Disconnected — inner is already None.
+            state.store(DISCONNECTED, Ordering::SeqCst);
+            result
+        });
+        task.await
+            .map_err(|e| crate::errors::ClientError::new_err(format!("close task failed: {e}")))?
+    }
+}
+
+/// Reverts `CONNECTING` to `DISCONNECTED` when a `connect()` future is
+/// dropped (cancelled) before it resolves, so the client can be reconnected.
+struct ConnectingGuard(Arc<AtomicU8>);
+
+impl Drop for ConnectingGuard {
+    fn drop(&mut self) {
+        let _ =
+            self.0
+                .compare_exchange(CONNECTING, DISCONNECTED, Ordering::SeqCst, Ordering::SeqCst);
+    }
+}
+
+use crate::batch_types::{
+    check_partial_results, PendingBatchExists, PendingBatchRead, PendingBatchRecords,
+};
+use crate::cluster_events::ClusterEvents;
+use crate::dns::SrvRefresh;
 use crate::errors::as_to_pyerr;
-use crate::panic_safety::future_into_py_panic_safe;
+use crate::info_parsers;
+use crate::panic_safety::{future_into_py_panic_safe, future_into_py_with_timeout};
 use crate::policy::admin_policy::{parse_privileges, role_to_py, user_to_py};
-use crate::policy::client_policy::{parse_backpressure_config, parse_client_policy};
-use crate::record_helpers::{PendingExists, PendingOrderedRecord, PendingRecord};
+use crate::policy::batch_policy::parse_allow_partial_results;
+use crate::policy::client_policy::{
+    parse_backpressure_config, parse_bin_compression_config, parse_circuit_breaker_config,
+    parse_client_policy, parse_refresh_config, validate_client_config,
+};
+use crate::record_helpers::{
+    PendingExists, PendingMeta, PendingOrderedRecord, PendingRawRecord, PendingRecord, PendingValue,
+};
+use crate::runtime::ClientRuntime;
+use crate::session::{self, SessionRefresh};
 use crate::types::host::parse_hosts_from_config;
 use crate::types::key::key_to_py;
 
@@ -64,20 +114,45 @@ pub struct PyAsyncClient {
     connection_info: Arc<crate::tracing::ConnectionInfo>,
     /// Operation concurrency limiter (disabled by default).
     limiter: Arc<OperationLimiter>,
+    /// Per-node circuit breaker (disabled by default).
+    breaker: Arc<CircuitBreaker>,
+    /// Bin compression (`bin_compression`, disabled by default).
+    compression: Arc<BinCompression>,
+    /// Bin name / bin count limits of the connected cluster.
+    bin_limits: Arc<BinLimits>,
+    /// Bin encryption hooks registered via `set_bin_cipher()`.
+    cipher: BinCipher,
     /// Lifecycle state: Disconnected(0) → Connecting(1) → Connected(2) → Closing(3).
     state: Arc<AtomicU8>,
+    /// Cluster topology callback registered via `on_cluster_event()`.
+    cluster_events: ClusterEvents,
+    /// Periodic connection re-authentication (`refresh_seconds`).
+    session_refresh: SessionRefresh,
+    /// Periodic `hosts_srv` re-resolution (`hosts_srv_refresh_seconds`).
+    srv_refresh: SrvRefresh,
+    /// Tend-time `cluster_name` check.
+    cluster_name_guard: ClusterNameGuard,
 }
 
 #[pymethods]
 impl PyAsyncClient {
     #[new]
-    fn new(config: Py<PyAny>) -> PyResult<Self> {
+    fn new(config: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let config = crate::types::host::config_arg(config)?;
         Ok(PyAsyncClient {
             inner: Arc::new(ArcSwapOption::empty()),
             config,
             connection_info: Arc::new(crate::tracing::ConnectionInfo::default()),
             limiter: Arc::new(OperationLimiter::new(0, 0)),
+            breaker: Arc::new(CircuitBreaker::default()),
+            compression: Arc::new(BinCompression::default()),
+            bin_limits: Arc::new(BinLimits::default()),
+            cipher: BinCipher::default(),
             state: Arc::new(AtomicU8::new(DISCONNECTED)),
+            cluster_events: ClusterEvents::default(),
+            session_refresh: SessionRefresh::default(),
+            srv_refresh: SrvRefresh::default(),
+            cluster_name_guard: ClusterNameGuard::default(),
         })
     }
 
@@ -89,6 +164,7 @@ impl PyAsyncClient {
         username: Option<&str>,
         password: Option<&str>,
     ) -> PyResult<Bound<'py, PyAny>> {
+        crate::runtime::claim_async_runtime()?;
         // Guard: only allow Disconnected → Connecting transition.
         // Validate and parse config BEFORE the CAS so that config errors
         // don't leave the client stuck in CONNECTING.
@@ -106,9 +182,15 @@ impl PyAsyncClient {
             effective_config.set_item("password", pass)?;
         }
 
+        validate_client_config(&effective_config, true)?;
         let parsed = parse_hosts_from_config(&effective_config)?;
-        let client_policy = parse_client_policy(&effective_config)?;
-        let (max_ops, timeout_ms) = parse_backpressure_config(&effective_config)?;
+        let mut client_policy = parse_client_policy(&effective_config)?;
+        // Checked by `cluster_name` rather than by aerospike_core (see there).
+        let expected_cluster = client_policy.cluster_name.take();
+        let (max_ops, timeout_ms, ops_per_second) = parse_backpressure_config(&effective_config)?;
+        let circuit_breaker = parse_circuit_breaker_config(&effective_config)?;
+        let bin_compression = parse_bin_compression_config(&effective_config)?;
+        let refresh_interval = parse_refresh_config(&effective_config)?;
 
         let cluster_name = client_common::extract_cluster_name(&effective_config)?;
 
@@ -132,6 +214,10 @@ impl PyAsyncClient {
 
         let inner = self.inner.clone();
         let state = self.state.clone();
+        let cluster_events = self.cluster_events.clone();
+        let session_refresh = self.session_refresh.clone();
+        let srv_refresh = self.srv_refresh.clone();
+        let cluster_name_guard = self.cluster_name_guard.clone();
 
         self.connection_info = Arc::new(crate::tracing::ConnectionInfo {
             server_address: Arc::from(parsed.first_address.as_str()),
@@ -139,11 +225,27 @@ impl PyAsyncClient {
             cluster_name: Arc::from(cluster_name.as_str()),
         });
 
-        self.limiter = Arc::new(OperationLimiter::new(max_ops, timeout_ms));
+        self.limiter =
+            Arc::new(OperationLimiter::new(max_ops, timeout_ms).with_rate_limit(ops_per_second));
+        self.breaker = Arc::new(CircuitBreaker::new(circuit_breaker));
+        let breaker = self.breaker.clone();
+        self.compression = Arc::new(BinCompression::new(bin_compression));
+        self.bin_limits = Arc::new(BinLimits::default());
+        let bin_limits = self.bin_limits.clone();
 
-        let hosts_str = parsed.connection_string;
-        info!("Async connecting to Aerospike cluster: {}", hosts_str);
+        info!(
+            "Async connecting to Aerospike cluster: {}",
+            parsed.connection_string
+        );
         future_into_py(py, async move {
+            let _connecting = ConnectingGuard(state.clone());
+            let hosts_str = match crate::dns::resolve_seeds(&parsed).await {
+                Ok(hosts) => hosts,
+                Err(e) => {
+                    state.store(DISCONNECTED, Ordering::SeqCst);
+                    return Err(e);
+                }
+            };
             let result = AsClient::new(
                 &client_policy,
                 &hosts_str as &(dyn aerospike_core::ToHosts + Send + Sync),
@@ -152,7 +254,29 @@ impl PyAsyncClient {
 
             match result {
                 Ok(client) => {
-                    inner.store(Some(Arc::new(client)));
+                    if let Some(expected) = &expected_cluster {
+                        if let Err(e) = crate::cluster_name::verify(&client, expected).await {
+                            let _ = client.close().await;
+                            state.store(DISCONNECTED, Ordering::SeqCst);
+                            return Err(e);
+                        }
+                    }
+                    bin_limits.detect(&client).await;
+                    let client = Arc::new(client);
+                    cluster_events.start(client.clone(), &tokio::runtime::Handle::current());
+                    session_refresh.start(
+                        client.clone(),
+                        &tokio::runtime::Handle::current(),
+                        refresh_interval,
+                    );
+                    srv_refresh.start(client.clone(), &tokio::runtime::Handle::current(), &parsed);
+                    cluster_name_guard.start(
+                        client.clone(),
+                        &tokio::runtime::Handle::current(),
+                        expected_cluster,
+                    );
+                    crate::metrics::register_client(&client, &breaker, &cluster_name);
+                    inner.store(Some(client));
                     state.store(CONNECTED, Ordering::SeqCst);
                     Ok(())
                 }
@@ -168,11 +292,14 @@ impl PyAsyncClient {
     /// Check if connected (sync, no I/O, lock-free).
     fn is_connected(&self) -> bool {
         trace!("Checking async client connection status");
-        self.state.load(Ordering::SeqCst) == CONNECTED && self.inner.load().is_some()
+        self.state.load(Ordering::SeqCst) == CONNECTED
+            && self.inner.load().is_some()
+            && !crate::runtime::async_runtime_forked()
     }
 
     /// Lightweight health check: returns `True` if a random node responds.
     fn ping<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        crate::runtime::check_async_runtime_fork()?;
         let client = self.inner.load_full();
         future_into_py(py, async move {
             match client {
@@ -182,21 +309,64 @@ impl PyAsyncClient {
         })
     }
 
+    /// Pre-open `conns_per_node` connections to each node (async).
+    fn warm_up<'py>(&self, py: Python<'py>, conns_per_node: usize) -> PyResult<Bound<'py, PyAny>> {
+        client_common::validate_warm_up_conns(conns_per_node)?;
+        let client = self.get_client()?;
+        info!(
+            "Async warming up connections: conns_per_node={}",
+            conns_per_node
+        );
+        future_into_py_panic_safe(py, "AsyncClient.warm_up", async move {
+            Ok(client_ops::do_warm_up(&client, conns_per_node).await)
+        })
+    }
+
+    /// Close idle pooled connections so the next commands log in again (async).
+    ///
+    /// Called by the Python wrapper after `SessionExpired`; resolves to the
+    /// number of connections closed.
+    fn _relogin<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        info!("Async re-authenticating pooled connections");
+        future_into_py(py, async move {
+            Ok(session::recycle_connections(&client).await)
+        })
+    }
+
+    /// Register a callback for node and partition map changes (`None` removes it).
+    #[pyo3(signature = (callback))]
+    fn on_cluster_event(&self, callback: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        let callback = ClusterEvents::parse_callback(callback)?;
+        self.cluster_events.set_callback(callback);
+        if let Some(client) = self.inner.load_full() {
+            crate::runtime::check_async_runtime_fork()?;
+            self.cluster_events
+                .start(client, pyo3_async_runtimes::tokio::get_runtime().handle());
+        }
+        Ok(())
+    }
+
+    /// Register `bytes -> bytes` hooks that encrypt `bins` on write and
+    /// decrypt them on read (`None` for both removes them).
+    #[pyo3(signature = (encrypt_fn, decrypt_fn, bins=None))]
+    fn set_bin_cipher(
+        &self,
+        encrypt_fn: Option<&Bound<'_, PyAny>>,
+        decrypt_fn: Option<&Bound<'_, PyAny>>,
+        bins: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        self.cipher.set(encrypt_fn, decrypt_fn, bins)
+    }
+
     /// Close connection (async).
     fn close<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         info!("Closing async client connection");
         match self.prepare_close()? {
             CloseOutcome::Idempotent => future_into_py(py, async move { Ok(()) }),
-            CloseOutcome::Proceed { client, state } => future_into_py(py, async move {
-                let result = if let Some(c) = client {
-                    c.close().await.map_err(as_to_pyerr)
-                } else {
-                    Ok(())
-                };
-                // Always transition to Disconnected — inner is already None.
-                state.store(DISCONNECTED, Ordering::SeqCst);
-                result
-            }),
+            CloseOutcome::Proceed { client, state } => {
+                future_into_py(py, CloseOutcome::finish(client, state))
+            }
         }
     }
 
@@ -205,6 +375,35 @@ impl PyAsyncClient {
         Ok(self.get_client()?.node_names())
     }
 
+    /// Name, address, port, state and rack of every node (async).
+    fn get_nodes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        future_into_py_panic_safe(py, "AsyncClient.get_nodes", async move {
+            Ok(client_ops::do_get_nodes(&client).await)
+        })
+    }
+
+    /// Master node (name, address, port) of a key's partition (sync, no I/O).
+    fn get_node_for_key(&self, key: &Bound<'_, PyAny>) -> PyResult<client_common::KeyNode> {
+        let key = crate::types::key::py_to_key(key)?;
+        client_ops::node_for_key(&*self.get_client()?, &key)
+    }
+
+    /// Partition (0-4095) a key belongs to; computed client-side.
+    fn get_partition_id(&self, key: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let key = crate::types::key::py_to_key(key)?;
+        Ok(crate::types::key::partition_id(&key.digest))
+    }
+
+    /// Per-node client-side stats keyed by node name (async).
+    fn get_cluster_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let breaker = self.breaker.clone();
+        future_into_py_panic_safe(py, "AsyncClient.get_cluster_stats", async move {
+            Ok(client_ops::do_get_cluster_stats(&client, &breaker).await)
+        })
+    }
+
     // ── Info ─────────────────────────────────────────────────────
 
     /// Send an info command to all nodes in the cluster (async).
@@ -238,6 +437,81 @@ impl PyAsyncClient {
         })
     }
 
+    /// Send an info command to one node, chosen by node name or `host:port` (async).
+    #[pyo3(signature = (command, node, policy=None))]
+    fn info_single_node<'py>(
+        &self,
+        py: Python<'py>,
+        command: &str,
+        node: String,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args = client_common::prepare_info_args(command, policy)?;
+        future_into_py(py, async move {
+            client_ops::do_info_single_node(&client, &args, &node).await
+        })
+    }
+
+    /// List namespaces (`info("namespaces")`, async).
+    #[pyo3(signature = (node=None, policy=None))]
+    fn info_namespaces<'py>(
+        &self,
+        py: Python<'py>,
+        node: Option<String>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args = client_common::prepare_info_args("namespaces", policy)?;
+        future_into_py(py, async move {
+            let resp = client_ops::do_info_node(&client, &args, node.as_deref()).await?;
+            Ok(info_parsers::parse_list(info_parsers::check_response(
+                &resp,
+            )?))
+        })
+    }
+
+    /// List sets of a namespace with their statistics (`info("sets/<ns>")`, async).
+    #[pyo3(signature = (namespace, node=None, policy=None))]
+    fn info_sets<'py>(
+        &self,
+        py: Python<'py>,
+        namespace: &str,
+        node: Option<String>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args = client_common::prepare_info_args(&format!("sets/{namespace}"), policy)?;
+        future_into_py(py, async move {
+            let resp = client_ops::do_info_node(&client, &args, node.as_deref()).await?;
+            Ok(info_parsers::parse_records(
+                info_parsers::check_response(&resp)?,
+                ':',
+            ))
+        })
+    }
+
+    /// List secondary indexes, optionally of one namespace (`info("sindex-list")`, async).
+    #[pyo3(signature = (namespace=None, node=None, policy=None))]
+    fn info_sindex<'py>(
+        &self,
+        py: Python<'py>,
+        namespace: Option<&str>,
+        node: Option<String>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let command = client_common::sindex_list_command(namespace);
+        let args = client_common::prepare_info_args(&command, policy)?;
+        future_into_py(py, async move {
+            let resp = client_ops::do_info_node(&client, &args, node.as_deref()).await?;
+            Ok(info_parsers::parse_records(
+                info_parsers::check_response(&resp)?,
+                ':',
+            ))
+        })
+    }
+
     /// Async context manager entry.
     fn __aenter__<'py>(slf: Py<Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         future_into_py(py, async move { Ok(slf) })
@@ -268,22 +542,16 @@ impl PyAsyncClient {
         match self.prepare_close()? {
             CloseOutcome::Idempotent => future_into_py(py, async move { Ok(false) }),
             CloseOutcome::Proceed { client, state } => future_into_py(py, async move {
-                let result = if let Some(c) = client {
-                    c.close().await.map_err(as_to_pyerr)
-                } else {
-                    Ok(())
-                };
-                // Always transition to Disconnected — inner is already None.
-                state.store(DISCONNECTED, Ordering::SeqCst);
-                result.map(|()| false)
+                CloseOutcome::finish(client, state).await.map(|()| false)
             }),
         }
     }
 
     // ── CRUD ──────────────────────────────────────────────────
 
-    /// Write a record (async).
-    #[pyo3(signature = (key, bins, meta=None, policy=None))]
+    /// Write a record (async); with `return_meta`, return its meta after the write.
+    #[pyo3(signature = (key, bins, meta=None, policy=None, timeout=None, return_meta=false))]
+    #[allow(clippy::too_many_arguments)]
     fn put<'py>(
         &self,
         py: Python<'py>,
@@ -291,31 +559,46 @@ impl PyAsyncClient {
         bins: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+        return_meta: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
-        let args =
+        let mut args =
             client_common::prepare_put_args(py, key, bins, meta, policy, &self.connection_info)?;
+        args.return_meta = return_meta;
+        self.bin_limits.check(&args.bins)?;
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
         debug!(
             "async put: ns={} set={}",
             args.key.namespace, args.key.set_name
         );
-        future_into_py_panic_safe(py, "AsyncClient.put", async move {
+        future_into_py_with_timeout(py, "AsyncClient.put", timeout, async move {
+            compression.compress_bins(&mut args.bins);
+            cipher.encrypt_bins(&mut args.bins)?;
             let _permit = limiter.acquire_named("put").await?;
-            client_ops::do_put(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            let record = circuit.record(client_ops::do_put(&client, args).await)?;
+            Ok(PendingMeta(record))
         })
     }
 
     /// Read a record (async).
-    #[pyo3(signature = (key, policy=None))]
+    #[pyo3(signature = (key, policy=None, timeout=None))]
     fn get<'py>(
         &self,
         py: Python<'py>,
         key: &Bound<'_, PyAny>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
         let args = client_common::prepare_get_args(py, key, policy, &self.connection_info)?;
         debug!(
             "async get: ns={} set={}",
@@ -323,24 +606,31 @@ impl PyAsyncClient {
         );
         let key_py = key_to_py(py, &args.key)?;
 
-        future_into_py_panic_safe(py, "AsyncClient.get", async move {
+        future_into_py_with_timeout(py, "AsyncClient.get", timeout, async move {
             let _permit = limiter.acquire_named("get").await?;
-            let record = client_ops::do_get(&client, &args).await?;
+            let circuit = breaker.check(&client, &args.key)?;
+            let mut record = circuit.record(client_ops::do_get(&client, &args).await)?;
+            cipher.decrypt_record(&mut record)?;
+            compression.decompress_record(&mut record);
             Ok(PendingRecord { record, key_py })
         })
     }
 
     /// Read specific bins (async).
-    #[pyo3(signature = (key, bins, policy=None))]
+    #[pyo3(signature = (key, bins, policy=None, timeout=None))]
     fn select<'py>(
         &self,
         py: Python<'py>,
         key: &Bound<'_, PyAny>,
         bins: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
         let args =
             client_common::prepare_select_args(py, key, bins, policy, &self.connection_info)?;
         debug!(
@@ -349,23 +639,91 @@ impl PyAsyncClient {
         );
         let key_py = key_to_py(py, &args.key)?;
 
-        future_into_py_panic_safe(py, "AsyncClient.select", async move {
+        future_into_py_with_timeout(py, "AsyncClient.select", timeout, async move {
             let _permit = limiter.acquire_named("select").await?;
-            let record = client_ops::do_select(&client, &args).await?;
+            let circuit = breaker.check(&client, &args.key)?;
+            let mut record = circuit.record(client_ops::do_select(&client, &args).await)?;
+            cipher.decrypt_record(&mut record)?;
+            compression.decompress_record(&mut record);
             Ok(PendingRecord { record, key_py })
         })
     }
 
+    /// Read a record with bins as `(particle_type, payload)` pairs (async).
+    #[pyo3(signature = (key, policy=None, timeout=None))]
+    fn get_raw<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'_, PyAny>,
+        policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let args = client_common::prepare_get_args(py, key, policy, &self.connection_info)?;
+        debug!(
+            "async get_raw: ns={} set={}",
+            args.key.namespace, args.key.set_name
+        );
+        let key_py = key_to_py(py, &args.key)?;
+
+        future_into_py_with_timeout(py, "AsyncClient.get_raw", timeout, async move {
+            let _permit = limiter.acquire_named("get_raw").await?;
+            let circuit = breaker.check(&client, &args.key)?;
+            let record = circuit.record(client_ops::do_get(&client, &args).await)?;
+            Ok(PendingRawRecord { record, key_py })
+        })
+    }
+
+    /// Write a record from `(particle_type, payload)` bins (async).
+    #[pyo3(signature = (key, bins, meta=None, policy=None, timeout=None))]
+    fn put_raw<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'_, PyAny>,
+        bins: &Bound<'_, PyDict>,
+        meta: Option<&Bound<'_, PyDict>>,
+        policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let args = client_common::prepare_put_raw_args(
+            py,
+            key,
+            bins,
+            meta,
+            policy,
+            &self.connection_info,
+        )?;
+        self.bin_limits.check(&args.bins)?;
+        let client = self.get_client()?;
+        let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        debug!(
+            "async put_raw: ns={} set={}",
+            args.key.namespace, args.key.set_name
+        );
+        future_into_py_with_timeout(py, "AsyncClient.put_raw", timeout, async move {
+            let _permit = limiter.acquire_named("put_raw").await?;
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit
+                .record(client_ops::do_put(&client, args).await)
+                .map(|_| ())
+        })
+    }
+
     /// Check if a record exists (async).
-    #[pyo3(signature = (key, policy=None))]
+    #[pyo3(signature = (key, policy=None, timeout=None))]
     fn exists<'py>(
         &self,
         py: Python<'py>,
         key: &Bound<'_, PyAny>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let args = client_common::prepare_exists_args(py, key, policy, &self.connection_info)?;
         debug!(
             "async exists: ns={} set={}",
@@ -373,38 +731,42 @@ impl PyAsyncClient {
         );
         let key_py = key_to_py(py, &args.key)?;
 
-        future_into_py_panic_safe(py, "AsyncClient.exists", async move {
+        future_into_py_with_timeout(py, "AsyncClient.exists", timeout, async move {
             let _permit = limiter.acquire_named("exists").await?;
-            let result = client_ops::do_exists(&client, &args).await;
+            let circuit = breaker.check(&client, &args.key)?;
+            let result = circuit.record(client_ops::do_exists(&client, &args).await);
             Ok(PendingExists { result, key_py })
         })
     }
 
     /// Remove a record (async).
-    #[pyo3(signature = (key, meta=None, policy=None))]
+    #[pyo3(signature = (key, meta=None, policy=None, timeout=None))]
     fn remove<'py>(
         &self,
         py: Python<'py>,
         key: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let args =
             client_common::prepare_remove_args(py, key, meta, policy, &self.connection_info)?;
         debug!(
             "async remove: ns={} set={}",
             args.key.namespace, args.key.set_name
         );
-        future_into_py_panic_safe(py, "AsyncClient.remove", async move {
+        future_into_py_with_timeout(py, "AsyncClient.remove", timeout, async move {
             let _permit = limiter.acquire_named("remove").await?;
-            client_ops::do_remove(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit.record(client_ops::do_remove(&client, args).await)
         })
     }
 
     /// Touch a record (async).
-    #[pyo3(signature = (key, val=0, meta=None, policy=None))]
+    #[pyo3(signature = (key, val=0, meta=None, policy=None, timeout=None))]
     fn touch<'py>(
         &self,
         py: Python<'py>,
@@ -412,23 +774,27 @@ impl PyAsyncClient {
         val: u32,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let args =
             client_common::prepare_touch_args(py, key, val, meta, policy, &self.connection_info)?;
         debug!(
             "async touch: ns={} set={}",
             args.key.namespace, args.key.set_name
         );
-        future_into_py_panic_safe(py, "AsyncClient.touch", async move {
+        future_into_py_with_timeout(py, "AsyncClient.touch", timeout, async move {
             let _permit = limiter.acquire_named("touch").await?;
-            client_ops::do_touch(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit.record(client_ops::do_touch(&client, args).await)
         })
     }
 
-    /// Increment a bin (async).
-    #[pyo3(signature = (key, bin, offset, meta=None, policy=None))]
+    /// Increment a bin (async); with `return_value`, resolve to the bin's new value.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, bin, offset, meta=None, policy=None, timeout=None, return_value=false))]
     fn increment<'py>(
         &self,
         py: Python<'py>,
@@ -437,10 +803,13 @@ impl PyAsyncClient {
         offset: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+        return_value: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
-        let args = client_common::prepare_increment_args(
+        let breaker = self.breaker.clone();
+        let mut args = client_common::prepare_increment_args(
             py,
             key,
             bin,
@@ -449,18 +818,22 @@ impl PyAsyncClient {
             policy,
             &self.connection_info,
         )?;
+        args.return_value = return_value;
         debug!(
             "async increment: ns={} set={} bin={}",
             args.key.namespace, args.key.set_name, bin
         );
-        future_into_py_panic_safe(py, "AsyncClient.increment", async move {
+        future_into_py_with_timeout(py, "AsyncClient.increment", timeout, async move {
             let _permit = limiter.acquire_named("increment").await?;
-            client_ops::do_increment(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit
+                .record(client_ops::do_increment(&client, args).await)
+                .map(PendingValue)
         })
     }
 
     /// Operate on a record (async).
-    #[pyo3(signature = (key, ops, meta=None, policy=None))]
+    #[pyo3(signature = (key, ops, meta=None, policy=None, timeout=None))]
     fn operate<'py>(
         &self,
         py: Python<'py>,
@@ -468,9 +841,13 @@ impl PyAsyncClient {
         ops: &Bound<'_, PyList>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
         let args =
             client_common::prepare_operate_args(py, key, ops, meta, policy, &self.connection_info)?;
         debug!(
@@ -481,17 +858,21 @@ impl PyAsyncClient {
         );
         let key_py = key_to_py(py, &args.key)?;
 
-        future_into_py_panic_safe(py, "AsyncClient.operate", async move {
+        future_into_py_with_timeout(py, "AsyncClient.operate", timeout, async move {
             let _permit = limiter.acquire_named("operate").await?;
-            let record = client_ops::do_operate(&client, &args).await?;
+            let circuit = breaker.check(&client, &args.key)?;
+            let mut record = circuit.record(client_ops::do_operate(&client, &args).await)?;
+            cipher.decrypt_record(&mut record)?;
+            compression.decompress_record(&mut record);
             Ok(PendingRecord { record, key_py })
         })
     }
 
     // ── String / Numeric ───────────────────────────────────────
 
-    /// Append a string to a bin (async).
-    #[pyo3(signature = (key, bin, val, meta=None, policy=None))]
+    /// Append a string to a bin (async); with `return_value`, resolve to the bin's new value.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, bin, val, meta=None, policy=None, timeout=None, return_value=false))]
     fn append<'py>(
         &self,
         py: Python<'py>,
@@ -500,10 +881,13 @@ impl PyAsyncClient {
         val: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+        return_value: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
-        let args = client_common::prepare_single_bin_write_args(
+        let breaker = self.breaker.clone();
+        let mut args = client_common::prepare_single_bin_write_args(
             py,
             key,
             bin,
@@ -512,18 +896,23 @@ impl PyAsyncClient {
             policy,
             &self.connection_info,
         )?;
+        args.return_value = return_value;
         debug!(
             "async append: ns={} set={} bin={}",
             args.key.namespace, args.key.set_name, bin
         );
-        future_into_py_panic_safe(py, "AsyncClient.append", async move {
+        future_into_py_with_timeout(py, "AsyncClient.append", timeout, async move {
             let _permit = limiter.acquire_named("append").await?;
-            client_ops::do_append(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit
+                .record(client_ops::do_append(&client, args).await)
+                .map(PendingValue)
         })
     }
 
-    /// Prepend a string to a bin (async).
-    #[pyo3(signature = (key, bin, val, meta=None, policy=None))]
+    /// Prepend a string to a bin (async); with `return_value`, resolve to the bin's new value.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, bin, val, meta=None, policy=None, timeout=None, return_value=false))]
     fn prepend<'py>(
         &self,
         py: Python<'py>,
@@ -532,10 +921,13 @@ impl PyAsyncClient {
         val: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+        return_value: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
-        let args = client_common::prepare_single_bin_write_args(
+        let breaker = self.breaker.clone();
+        let mut args = client_common::prepare_single_bin_write_args(
             py,
             key,
             bin,
@@ -544,18 +936,22 @@ impl PyAsyncClient {
             policy,
             &self.connection_info,
         )?;
+        args.return_value = return_value;
         debug!(
             "async prepend: ns={} set={} bin={}",
             args.key.namespace, args.key.set_name, bin
         );
-        future_into_py_panic_safe(py, "AsyncClient.prepend", async move {
+        future_into_py_with_timeout(py, "AsyncClient.prepend", timeout, async move {
             let _permit = limiter.acquire_named("prepend").await?;
-            client_ops::do_prepend(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit
+                .record(client_ops::do_prepend(&client, args).await)
+                .map(PendingValue)
         })
     }
 
     /// Remove bins from a record by setting them to nil (async).
-    #[pyo3(signature = (key, bin_names, meta=None, policy=None))]
+    #[pyo3(signature = (key, bin_names, meta=None, policy=None, timeout=None))]
     fn remove_bin<'py>(
         &self,
         py: Python<'py>,
@@ -563,9 +959,11 @@ impl PyAsyncClient {
         bin_names: &Bound<'_, PyList>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let args = client_common::prepare_remove_bin_args(
             py,
             key,
@@ -574,16 +972,17 @@ impl PyAsyncClient {
             policy,
             &self.connection_info,
         )?;
-        future_into_py_panic_safe(py, "AsyncClient.remove_bin", async move {
+        future_into_py_with_timeout(py, "AsyncClient.remove_bin", timeout, async move {
             let _permit = limiter.acquire_named("remove_bin").await?;
-            client_ops::do_remove_bin(&client, args).await
+            let circuit = breaker.check(&client, &args.key)?;
+            circuit.record(client_ops::do_remove_bin(&client, args).await)
         })
     }
 
     // ── Multi-operation (ordered) ────────────────────────────────
 
     /// Perform multiple operations on a single record, returning ordered results (async).
-    #[pyo3(signature = (key, ops, meta=None, policy=None))]
+    #[pyo3(signature = (key, ops, meta=None, policy=None, timeout=None))]
     fn operate_ordered<'py>(
         &self,
         py: Python<'py>,
@@ -591,9 +990,13 @@ impl PyAsyncClient {
         ops: &Bound<'_, PyList>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
         let args =
             client_common::prepare_operate_args(py, key, ops, meta, policy, &self.connection_info)?;
         debug!(
@@ -604,9 +1007,13 @@ impl PyAsyncClient {
         );
         let pre_key_py = key_to_py(py, &args.key)?;
 
-        future_into_py_panic_safe(py, "AsyncClient.operate_ordered", async move {
+        future_into_py_with_timeout(py, "AsyncClient.operate_ordered", timeout, async move {
             let _permit = limiter.acquire_named("operate_ordered").await?;
-            let record = client_ops::do_operate_ordered(&client, &args).await?;
+            let circuit = breaker.check(&client, &args.key)?;
+            let mut record =
+                circuit.record(client_ops::do_operate_ordered(&client, &args).await)?;
+            cipher.decrypt_record(&mut record)?;
+            compression.decompress_record(&mut record);
             Ok(PendingOrderedRecord {
                 record,
                 key_py: pre_key_py,
@@ -617,7 +1024,8 @@ impl PyAsyncClient {
     // ── Truncate ─────────────────────────────────────────────
 
     /// Remove records in specified namespace/set efficiently (async).
-    #[pyo3(signature = (namespace, set_name, nanos=0, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, nanos=0, policy=None, wait=false, timeout=None))]
     fn truncate<'py>(
         &self,
         py: Python<'py>,
@@ -625,30 +1033,54 @@ impl PyAsyncClient {
         set_name: &str,
         nanos: i64,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         warn!("Async truncating: ns={} set={}", namespace, set_name);
         let client = self.get_client()?;
-        let args = client_common::prepare_truncate_args(namespace, set_name, nanos, policy)?;
+        let args = client_common::prepare_truncate_args(
+            namespace, set_name, nanos, policy, wait, timeout,
+        )?;
         future_into_py(
             py,
             async move { client_ops::do_truncate(&client, args).await },
         )
     }
 
+    /// Whether a truncate of `namespace`/`set_name` has finished on every
+    /// node (async). Polled by `AsyncTruncateTask`.
+    #[pyo3(signature = (namespace, set_name, nanos=0, policy=None))]
+    fn _truncate_done<'py>(
+        &self,
+        py: Python<'py>,
+        namespace: &str,
+        set_name: &str,
+        nanos: i64,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args =
+            client_common::prepare_truncate_args(namespace, set_name, nanos, policy, false, None)?;
+        future_into_py(py, async move {
+            client_ops::do_truncate_done(&client, &args).await
+        })
+    }
+
     // ── UDF ──────────────────────────────────────────────────
 
     /// Register a UDF module from a file (async).
-    #[pyo3(signature = (filename, udf_type=0, policy=None))]
+    #[pyo3(signature = (filename, udf_type=0, policy=None, wait=true))]
     fn udf_put<'py>(
         &self,
         py: Python<'py>,
         filename: &str,
         udf_type: u8,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         info!("Async registering UDF: filename={}", filename);
         let client = self.get_client()?;
-        let args = client_common::prepare_udf_put_args(filename, udf_type, policy)?;
+        let args = client_common::prepare_udf_put_args(filename, udf_type, policy, wait)?;
         future_into_py(
             py,
             async move { client_ops::do_udf_put(&client, args).await },
@@ -656,24 +1088,77 @@ impl PyAsyncClient {
     }
 
     /// Remove a UDF module (async).
-    #[pyo3(signature = (module, policy=None))]
+    #[pyo3(signature = (module, policy=None, wait=true))]
     fn udf_remove<'py>(
         &self,
         py: Python<'py>,
         module: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         info!("Async removing UDF: module={}", module);
         let client = self.get_client()?;
-        let args = client_common::prepare_udf_remove_args(module, policy)?;
+        let args = client_common::prepare_udf_remove_args(module, policy, wait)?;
         future_into_py(
             py,
             async move { client_ops::do_udf_remove(&client, args).await },
         )
     }
 
+    /// Whether a `udf_put()` (or, with `removed`, a `udf_remove()`) of
+    /// `module` has reached every node (async). Polled by `AsyncUdfTask`.
+    #[pyo3(signature = (module, removed=false, policy=None))]
+    fn _udf_done<'py>(
+        &self,
+        py: Python<'py>,
+        module: &str,
+        removed: bool,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args = client_common::prepare_udf_remove_args(module, policy, false)?;
+        future_into_py(py, async move {
+            client_ops::do_udf_done(&client, &args.admin_policy, &args.server_path, removed).await
+        })
+    }
+
+    /// List registered UDF modules (`info("udf-list")`, async).
+    #[pyo3(signature = (policy=None))]
+    fn udf_list<'py>(
+        &self,
+        py: Python<'py>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args = client_common::prepare_info_args("udf-list", policy)?;
+        future_into_py(py, async move {
+            let resp = client_ops::do_info_random_node(&client, &args).await?;
+            Ok(info_parsers::parse_udf_list(info_parsers::check_response(
+                &resp,
+            )?))
+        })
+    }
+
+    /// Return the Lua source of a registered UDF module (`info("udf-get")`, async).
+    #[pyo3(signature = (module, policy=None))]
+    fn udf_get<'py>(
+        &self,
+        py: Python<'py>,
+        module: &str,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let module = client_common::udf_server_path(module);
+        let args = client_common::prepare_info_args(&format!("udf-get:filename={module}"), policy)?;
+        future_into_py(py, async move {
+            let resp = client_ops::do_info_random_node(&client, &args).await?;
+            info_parsers::parse_udf_get(&resp, &module)
+        })
+    }
+
     /// Execute a UDF on a single record (async).
-    #[pyo3(signature = (key, module, function, args=None, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, module, function, args=None, policy=None, timeout=None))]
     fn apply<'py>(
         &self,
         py: Python<'py>,
@@ -682,6 +1167,7 @@ impl PyAsyncClient {
         function: &str,
         args: Option<&Bound<'_, PyList>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let client = self.get_client()?;
         let a = client_common::prepare_apply_args(key, module, function, args, policy)?;
@@ -690,7 +1176,7 @@ impl PyAsyncClient {
             a.key.namespace, a.key.set_name, a.module, a.function
         );
 
-        future_into_py_panic_safe(py, "AsyncClient.apply", async move {
+        future_into_py_with_timeout(py, "AsyncClient.apply", timeout, async move {
             let result = client_ops::do_apply(&client, &a).await?;
             Python::attach(|py| client_common::batch_udf_value_to_py(py, result.as_ref()))
         })
@@ -705,7 +1191,10 @@ impl PyAsyncClient {
     /// (just `Arc::new`). Call methods on the handle to access data:
     /// - `handle.as_dict()` — fastest, returns `dict[key, bins_dict]`
     /// - `handle.batch_records` — compat, returns `list[BatchRecord]`
-    #[pyo3(signature = (keys, bins=None, policy=None, _dtype=None))]
+    ///
+    /// With `output="arrow"` the future resolves to a `pyarrow.RecordBatch`.
+    #[pyo3(signature = (keys, bins=None, policy=None, _dtype=None, _null_mask=false, output=None, timeout=None))]
+    #[allow(clippy::too_many_arguments)]
     fn batch_read<'py>(
         &self,
         py: Python<'py>,
@@ -713,12 +1202,20 @@ impl PyAsyncClient {
         bins: Option<Vec<String>>,
         policy: Option<&Bound<'_, PyDict>>,
         _dtype: Option<&Bound<'_, PyAny>>,
+        _null_mask: bool,
+        output: Option<&str>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         debug!("async batch_read: keys_count={}", keys.len());
+        let allow_partial = parse_allow_partial_results(policy)?;
 
         // ── Stage: key_parse (GIL held) ──
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
+        client_common::check_batch_read_null_mask(_dtype, _null_mask)?;
+        let use_arrow = client_common::parse_batch_read_output(output, _dtype)?;
         let args = crate::stage_timer!("key_parse", "batch_read", {
             client_common::prepare_batch_read_args(py, keys, &bins, policy, &self.connection_info)?
         });
@@ -739,7 +1236,7 @@ impl PyAsyncClient {
         // for the recommended subtraction.
         let spawned_at = crate::metrics::maybe_now();
         crate::stage_timer!("future_into_py_setup", "batch_read", {
-            future_into_py_panic_safe(py, "AsyncClient.batch_read", async move {
+            future_into_py_with_timeout(py, "AsyncClient.batch_read", timeout, async move {
                 // ── (B) Tokio task scheduling delay ──
                 if let Some(t) = spawned_at {
                     crate::metrics::record_internal_stage_unchecked(
@@ -755,15 +1252,20 @@ impl PyAsyncClient {
                 });
 
                 // ── Stage: io (network round-trip) ──
-                let results = crate::stage_timer!("io", "batch_read", {
+                let mut results = crate::stage_timer!("io", "batch_read", {
                     client_ops::do_batch_read(&client, &args).await?
                 });
+                cipher.decrypt_batch(&mut results)?;
+                compression.decompress_batch(&mut results);
+                check_partial_results(&results, allow_partial)?;
 
                 // Handoff timestamp for spawn_blocking queue delay — only when
                 // profiling is ON (Option<Instant>).
                 let io_complete_at = crate::metrics::maybe_now();
 
-                if use_numpy {
+                if use_arrow {
+                    Ok(PendingBatchRead::Arrow { results, bins })
+                } else if use_numpy {
                     Ok(PendingBatchRead::Numpy {
                         results,
                         dtype: dtype_py.ok_or_else(|| {
@@ -771,6 +1273,7 @@ impl PyAsyncClient {
                                 "internal error: numpy path reached without dtype",
                             )
                         })?,
+                        null_mask: _null_mask,
                     })
                 } else {
                     Ok(PendingBatchRead::Handle {
@@ -782,18 +1285,150 @@ impl PyAsyncClient {
         })
     }
 
+    /// Read multiple records as concurrent sub-batches (async).
+    ///
+    /// Splits `keys` into chunks of `chunk_size` and keeps at most
+    /// `concurrency` chunks in flight. Resolves to a `BatchReadHandle` with
+    /// the merged results in key order.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (keys, chunk_size=client_ops::DEFAULT_BATCH_CHUNK_SIZE, concurrency=client_ops::DEFAULT_BATCH_CHUNK_CONCURRENCY, bins=None, policy=None, timeout=None))]
+    fn batch_read_chunked<'py>(
+        &self,
+        py: Python<'py>,
+        keys: &Bound<'_, PyList>,
+        chunk_size: usize,
+        concurrency: usize,
+        bins: Option<Vec<String>>,
+        policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if chunk_size == 0 || concurrency == 0 {
+            return Err(crate::errors::ParamError::new_err(
+                "chunk_size and concurrency must be at least 1",
+            ));
+        }
+        debug!(
+            "async batch_read_chunked: keys_count={} chunk_size={} concurrency={}",
+            keys.len(),
+            chunk_size,
+            concurrency
+        );
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.get_client()?;
+        let limiter = self.limiter.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
+        let args =
+            client_common::prepare_batch_read_args(py, keys, &bins, policy, &self.connection_info)?;
+
+        future_into_py_with_timeout(py, "AsyncClient.batch_read_chunked", timeout, async move {
+            let mut results = client_ops::do_batch_read_chunked(
+                &client,
+                &limiter,
+                &args,
+                chunk_size,
+                concurrency,
+            )
+            .await?;
+            cipher.decrypt_batch(&mut results)?;
+            compression.decompress_batch(&mut results);
+            check_partial_results(&results, allow_partial)?;
+            Ok(PendingBatchRead::Handle {
+                results,
+                io_complete_at: crate::metrics::maybe_now(),
+            })
+        })
+    }
+
+    /// Read multiple records into DataFrame column buffers (async).
+    ///
+    /// Resolves to `(index, columns)`; the Python wrapper builds the `pandas.DataFrame`.
+    #[pyo3(signature = (keys, columns=None, policy=None, timeout=None))]
+    fn batch_read_df<'py>(
+        &self,
+        py: Python<'py>,
+        keys: &Bound<'_, PyList>,
+        columns: Option<Vec<String>>,
+        policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        debug!("async batch_read_df: keys_count={}", keys.len());
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.get_client()?;
+        let limiter = self.limiter.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
+        let args = client_common::prepare_batch_read_args(
+            py,
+            keys,
+            &columns,
+            policy,
+            &self.connection_info,
+        )?;
+
+        future_into_py_with_timeout(py, "AsyncClient.batch_read_df", timeout, async move {
+            let _permit = limiter.acquire_named("batch_read").await?;
+            let mut results = client_ops::do_batch_read(&client, &args).await?;
+            cipher.decrypt_batch(&mut results)?;
+            compression.decompress_batch(&mut results);
+            check_partial_results(&results, allow_partial)?;
+            Ok(PendingBatchRead::Columns {
+                results,
+                bins: columns,
+            })
+        })
+    }
+
+    /// Check existence of multiple records with a header-only batch read (async).
+    ///
+    /// Resolves to `list[(key, meta | None)]`, or `(found, meta)` NumPy arrays
+    /// when a boolean `dtype` is provided.
+    #[pyo3(signature = (keys, policy=None, dtype=None, timeout=None))]
+    fn batch_exists<'py>(
+        &self,
+        py: Python<'py>,
+        keys: &Bound<'_, PyList>,
+        policy: Option<&Bound<'_, PyDict>>,
+        dtype: Option<&Bound<'_, PyAny>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        debug!("async batch_exists: keys_count={}", keys.len());
+        let client = self.get_client()?;
+        let limiter = self.limiter.clone();
+        if let Some(d) = dtype {
+            crate::numpy_support::check_exists_dtype(py, d)?;
+        }
+        let args =
+            client_common::prepare_batch_exists_args(py, keys, policy, &self.connection_info)?;
+        let use_numpy = dtype.is_some();
+
+        future_into_py_with_timeout(py, "AsyncClient.batch_exists", timeout, async move {
+            let _permit = limiter.acquire_named("batch_exists").await?;
+            let results = client_ops::do_batch_exists(&client, &args).await?;
+            if use_numpy {
+                Ok(PendingBatchExists::Numpy { results })
+            } else {
+                Ok(PendingBatchExists::List { results })
+            }
+        })
+    }
+
     /// Perform operations on multiple records (async).
-    #[pyo3(signature = (keys, ops, policy=None))]
+    #[pyo3(signature = (keys, ops, policy=None, timeout=None))]
     fn batch_operate<'py>(
         &self,
         py: Python<'py>,
         keys: &Bound<'_, PyList>,
         ops: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         debug!("async batch_operate: keys_count={}", keys.len());
+        let allow_partial = parse_allow_partial_results(policy)?;
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
         let args = client_common::prepare_batch_operate_args(
             py,
             keys,
@@ -802,36 +1437,52 @@ impl PyAsyncClient {
             &self.connection_info,
         )?;
 
-        future_into_py_panic_safe(py, "AsyncClient.batch_operate", async move {
+        future_into_py_with_timeout(py, "AsyncClient.batch_operate", timeout, async move {
             let _permit = limiter.acquire_named("batch_operate").await?;
-            let results = client_ops::do_batch_operate(&client, &args).await?;
-            Ok(PendingBatchRecords { results })
+            let mut results = client_ops::do_batch_operate(&client, &args).await?;
+            cipher.decrypt_batch(&mut results)?;
+            compression.decompress_batch(&mut results);
+            Ok(PendingBatchRecords {
+                results,
+                allow_partial,
+            })
         })
     }
 
     /// Write multiple records with per-record bins (async).
     #[allow(clippy::unit_arg)]
-    #[pyo3(signature = (records, policy=None, retry=0))]
+    #[pyo3(signature = (records, policy=None, retry=0, timeout=None))]
     fn batch_write<'py>(
         &self,
         py: Python<'py>,
         records: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
         retry: u32,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         debug!("async batch_write: records_count={}", records.len());
+        let allow_partial = parse_allow_partial_results(policy)?;
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
-        let args = client_common::prepare_batch_write_args(
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
+        let mut args = client_common::prepare_batch_write_args(
             py,
             records,
             policy,
             retry,
             &self.connection_info,
         )?;
+        for (_, bins, _) in &args.records {
+            self.bin_limits.check(bins)?;
+        }
 
-        future_into_py_panic_safe(py, "AsyncClient.batch_write", async move {
+        future_into_py_with_timeout(py, "AsyncClient.batch_write", timeout, async move {
             let _permit = limiter.acquire_named("batch_write").await?;
+            for (_, bins, _) in &mut args.records {
+                compression.compress_bins(bins);
+                cipher.encrypt_bins(bins)?;
+            }
             let results = client_ops::do_batch_write(
                 &client,
                 &args.batch_policy,
@@ -844,13 +1495,16 @@ impl PyAsyncClient {
                 "batch_write",
             )
             .await?;
-            Ok(PendingBatchRecords { results })
+            Ok(PendingBatchRecords {
+                results,
+                allow_partial,
+            })
         })
     }
 
     /// Write multiple records from a numpy structured array (async).
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (data, namespace, set_name, _dtype, key_field="_key", policy=None, retry=0))]
+    #[pyo3(signature = (data, namespace, set_name, _dtype, key_field="_key", policy=None, retry=0, timeout=None))]
     fn batch_write_numpy<'py>(
         &self,
         py: Python<'py>,
@@ -861,11 +1515,13 @@ impl PyAsyncClient {
         key_field: &str,
         policy: Option<&Bound<'_, PyDict>>,
         retry: u32,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         debug!(
             "async batch_write_numpy: namespace={}, set={}, retry={}",
             namespace, set_name, retry
         );
+        let allow_partial = parse_allow_partial_results(policy)?;
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
         let batch_policy = crate::policy::batch_policy::parse_batch_policy(policy)?;
@@ -890,7 +1546,7 @@ impl PyAsyncClient {
         let ns = namespace.to_string();
         let set = set_name.to_string();
 
-        future_into_py_panic_safe(py, "AsyncClient.batch_write_numpy", async move {
+        future_into_py_with_timeout(py, "AsyncClient.batch_write_numpy", timeout, async move {
             let _permit = limiter.acquire_named("batch_write_numpy").await?;
             let results = client_ops::do_batch_write(
                 &client,
@@ -904,33 +1560,102 @@ impl PyAsyncClient {
                 "batch_write_numpy",
             )
             .await?;
-            Ok(PendingBatchRecords { results })
+            Ok(PendingBatchRecords {
+                results,
+                allow_partial,
+            })
+        })
+    }
+
+    /// Write records from an iterable of bins dicts or `(key, bins)` tuples (async).
+    ///
+    /// Like the sync client, the iterable is read one wave of `concurrency`
+    /// chunks at a time, from the future (attaching to the GIL per wave), so
+    /// only one wave is held in memory. Per-record failures are counted in
+    /// the resulting summary instead of raised.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (records, namespace, set_name, key_fn=None, policy=None, chunk_size=client_ops::DEFAULT_BATCH_CHUNK_SIZE, concurrency=client_ops::DEFAULT_BATCH_CHUNK_CONCURRENCY, timeout=None))]
+    fn bulk_put<'py>(
+        &self,
+        py: Python<'py>,
+        records: &Bound<'_, PyAny>,
+        namespace: &str,
+        set_name: &str,
+        key_fn: Option<&Bound<'_, PyAny>>,
+        policy: Option<&Bound<'_, PyDict>>,
+        chunk_size: usize,
+        concurrency: usize,
+        timeout: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        debug!(
+            "async bulk_put: namespace={} set={} chunk_size={} concurrency={}",
+            namespace, set_name, chunk_size, concurrency
+        );
+        let args = client_common::prepare_bulk_put_args(
+            py,
+            namespace,
+            set_name,
+            key_fn,
+            policy,
+            chunk_size,
+            concurrency,
+            &self.connection_info,
+        )?;
+        let client = self.get_client()?;
+        let limiter = self.limiter.clone();
+        let bin_limits = self.bin_limits.clone();
+        let compression = self.compression.clone();
+        let cipher = self.cipher.clone();
+        let mut reader = client_common::BulkPutReader::new(records)?;
+
+        future_into_py_with_timeout(py, "AsyncClient.bulk_put", timeout, async move {
+            let mut summary = client_common::BulkPutResult::default();
+            while !reader.is_exhausted() {
+                let chunks = Python::attach(|py| {
+                    reader.next_chunks(py, &args, args.concurrency, &mut summary, |bins| {
+                        bin_limits.check(bins)?;
+                        compression.compress_bins(bins);
+                        cipher.encrypt_bins(bins)
+                    })
+                })?;
+                let results = client_ops::do_bulk_put(&client, &limiter, &args, &chunks).await;
+                for (chunk, result) in chunks.iter().zip(results) {
+                    summary.add_chunk(chunk, result);
+                }
+            }
+            Ok(summary.finish())
         })
     }
 
     /// Remove multiple records (async).
-    #[pyo3(signature = (keys, policy=None))]
+    #[pyo3(signature = (keys, policy=None, timeout=None))]
     fn batch_remove<'py>(
         &self,
         py: Python<'py>,
         keys: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         debug!("async batch_remove: keys_count={}", keys.len());
+        let allow_partial = parse_allow_partial_results(policy)?;
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
         let args =
             client_common::prepare_batch_remove_args(py, keys, policy, &self.connection_info)?;
 
-        future_into_py_panic_safe(py, "AsyncClient.batch_remove", async move {
+        future_into_py_with_timeout(py, "AsyncClient.batch_remove", timeout, async move {
             let _permit = limiter.acquire_named("batch_remove").await?;
             let results = client_ops::do_batch_remove(&client, &args).await?;
-            Ok(PendingBatchRecords { results })
+            Ok(PendingBatchRecords {
+                results,
+                allow_partial,
+            })
         })
     }
 
     /// Execute a UDF on multiple records in a single batch call (async).
-    #[pyo3(signature = (keys, module, function, args=None, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (keys, module, function, args=None, policy=None, timeout=None))]
     fn batch_apply<'py>(
         &self,
         py: Python<'py>,
@@ -939,6 +1664,7 @@ impl PyAsyncClient {
         function: &str,
         args: Option<&Bound<'_, PyList>>,
         policy: Option<&Bound<'_, PyDict>>,
+        timeout: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         debug!(
             "async batch_apply: keys_count={}, module={}, function={}",
@@ -946,6 +1672,7 @@ impl PyAsyncClient {
             module,
             function
         );
+        let allow_partial = parse_allow_partial_results(policy)?;
         let client = self.get_client()?;
         let limiter = self.limiter.clone();
         let args = client_common::prepare_batch_apply_args(
@@ -958,31 +1685,47 @@ impl PyAsyncClient {
             &self.connection_info,
         )?;
 
-        future_into_py_panic_safe(py, "AsyncClient.batch_apply", async move {
+        future_into_py_with_timeout(py, "AsyncClient.batch_apply", timeout, async move {
             let _permit = limiter.acquire_named("batch_apply").await?;
             let results = client_ops::do_batch_apply(&client, &args).await?;
-            Ok(PendingBatchRecords { results })
+            Ok(PendingBatchRecords {
+                results,
+                allow_partial,
+            })
         })
     }
 
     // ── Query ─────────────────────────────────────────────────
 
     /// Create a Query object.
-    fn query(&self, namespace: &str, set_name: &str) -> PyResult<crate::query::PyQuery> {
+    fn query(
+        &self,
+        namespace: &str,
+        set_name: &Bound<'_, PyAny>,
+    ) -> PyResult<crate::query::PyQuery> {
+        crate::types::key::validate_namespace(namespace)?;
+        let set_name = crate::types::key::py_to_set_name(set_name)?;
         debug!("Creating async query: ns={} set={}", namespace, set_name);
-        let client = self.get_client()?.clone();
         Ok(crate::query::PyQuery::new(
-            client,
             namespace.to_string(),
-            set_name.to_string(),
-            self.connection_info.clone(),
+            set_name,
+            Some(self.query_binding()?),
         ))
     }
 
+    /// Bind a copy of a `Query` (e.g. one built with `Query(...)`) to this client.
+    fn bind_query(
+        &self,
+        query: PyRef<'_, crate::query::PyQuery>,
+    ) -> PyResult<crate::query::PyQuery> {
+        Ok(query.bind(self.query_binding()?))
+    }
+
     // ── Index ─────────────────────────────────────────────────
 
     /// Create a secondary integer index (async).
-    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None, wait=true))]
     fn index_integer_create<'py>(
         &self,
         py: Python<'py>,
@@ -991,6 +1734,7 @@ impl PyAsyncClient {
         bin_name: &str,
         index_name: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         self.create_index_async(
             py,
@@ -1000,11 +1744,13 @@ impl PyAsyncClient {
             index_name,
             aerospike_core::IndexType::Numeric,
             policy,
+            wait,
         )
     }
 
     /// Create a secondary string index (async).
-    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None, wait=true))]
     fn index_string_create<'py>(
         &self,
         py: Python<'py>,
@@ -1013,6 +1759,7 @@ impl PyAsyncClient {
         bin_name: &str,
         index_name: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         self.create_index_async(
             py,
@@ -1022,11 +1769,13 @@ impl PyAsyncClient {
             index_name,
             aerospike_core::IndexType::String,
             policy,
+            wait,
         )
     }
 
     /// Create a secondary geo2dsphere index (async).
-    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None, wait=true))]
     fn index_geo2dsphere_create<'py>(
         &self,
         py: Python<'py>,
@@ -1035,6 +1784,7 @@ impl PyAsyncClient {
         bin_name: &str,
         index_name: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         self.create_index_async(
             py,
@@ -1044,6 +1794,40 @@ impl PyAsyncClient {
             index_name,
             aerospike_core::IndexType::Geo2DSphere,
             policy,
+            wait,
+        )
+    }
+
+    /// Build progress of a secondary index in percent (lowest across nodes, async).
+    #[pyo3(signature = (namespace, index_name, policy=None))]
+    fn index_status<'py>(
+        &self,
+        py: Python<'py>,
+        namespace: String,
+        index_name: String,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let admin_policy = client_common::prepare_admin_policy(policy)?;
+        future_into_py(py, async move {
+            client_ops::do_index_status(&client, &admin_policy, &namespace, &index_name).await
+        })
+    }
+
+    /// State of a scan / query job across the cluster (async).
+    #[pyo3(signature = (job_id, module, policy=None))]
+    fn job_info<'py>(
+        &self,
+        py: Python<'py>,
+        job_id: u64,
+        module: &str,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let args = client_common::prepare_job_info_args(job_id, module, policy)?;
+        future_into_py(
+            py,
+            async move { client_ops::do_job_info(&client, &args).await },
         )
     }
 
@@ -1217,6 +2001,22 @@ impl PyAsyncClient {
         })
     }
 
+    /// Query info about the user this client is authenticated as (async).
+    #[pyo3(signature = (policy=None))]
+    fn admin_whoami<'py>(
+        &self,
+        py: Python<'py>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let client = self.get_client()?;
+        let admin_policy = client_common::prepare_admin_policy(policy)?;
+
+        future_into_py(py, async move {
+            let user = client_ops::do_admin_whoami(&client, &admin_policy).await?;
+            Python::attach(|py| user_to_py(py, &user))
+        })
+    }
+
     // ── Admin: Role ──────────────────────────────────────────────
 
     /// Create a new role with the given privileges (async).
@@ -1396,13 +2196,27 @@ impl PyAsyncClient {
 }
 
 impl PyAsyncClient {
+    fn query_binding(&self) -> PyResult<crate::query::QueryBinding> {
+        Ok(crate::query::QueryBinding {
+            client: self.get_client()?,
+            connection_info: self.connection_info.clone(),
+            runtime: ClientRuntime::Shared,
+            compression: self.compression.clone(),
+            cipher: self.cipher.clone(),
+            event_loop_guard: true,
+        })
+    }
+
     /// Returns a cloned `Arc` to the connected client, or an error if not yet connected.
     ///
     /// Uses `load_full()` for a lock-free atomic load + Arc clone.
     fn get_client(&self) -> PyResult<Arc<AsClient>> {
-        self.inner.load_full().ok_or_else(|| {
+        crate::runtime::check_async_runtime_fork()?;
+        let client = self.inner.load_full().ok_or_else(|| {
             crate::errors::ClientError::new_err("Client is not connected. Call connect() first.")
-        })
+        })?;
+        self.cluster_name_guard.check()?;
+        Ok(client)
     }
 
     /// Shared pre-close step for `close()` and `__aexit__`.
@@ -1419,7 +2233,9 @@ impl PyAsyncClient {
     /// - `Ok(CloseOutcome::Proceed { client, state })` when state is
     ///   `CONNECTED` — the caller drives `client.close()` and stores
     ///   `DISCONNECTED` on completion.
+    /// - `Err(ForkError)` in a process forked after the async runtime started.
     fn prepare_close(&mut self) -> PyResult<CloseOutcome> {
+        crate::runtime::check_async_runtime_fork()?;
         let current = self.state.load(Ordering::SeqCst);
         match current {
             DISCONNECTED | CLOSING => Ok(CloseOutcome::Idempotent),
@@ -1428,9 +2244,16 @@ impl PyAsyncClient {
             )),
             CONNECTED => {
                 self.state.store(CLOSING, Ordering::SeqCst);
+                self.cluster_events.stop();
+                self.session_refresh.stop();
+                self.srv_refresh.stop();
+                self.cluster_name_guard.stop();
                 let client = self.inner.swap(None);
                 self.connection_info = Arc::new(crate::tracing::ConnectionInfo::default());
                 self.limiter = Arc::new(OperationLimiter::new(0, 0));
+                self.breaker = Arc::new(CircuitBreaker::default());
+                self.compression = Arc::new(BinCompression::default());
+                self.bin_limits = Arc::new(BinLimits::default());
                 Ok(CloseOutcome::Proceed {
                     client,
                     state: self.state.clone(),
@@ -1451,6 +2274,7 @@ impl PyAsyncClient {
         index_name: &str,
         index_type: aerospike_core::IndexType,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<Bound<'py, PyAny>> {
         info!(
             "Async creating index: ns={} set={} bin={} index={}",
@@ -1461,7 +2285,21 @@ impl PyAsyncClient {
             namespace, set_name, bin_name, index_name, index_type, policy,
         )?;
         future_into_py(py, async move {
-            client_ops::do_index_create(&client, args).await
+            client_ops::do_index_create(&client, args, wait).await
         })
     }
 }
+
+impl Drop for PyAsyncClient {
+    /// A client inherited across `os.fork()` is leaked: dropping it would
+    /// deregister sockets from the epoll instance shared with the parent.
+    fn drop(&mut self) {
+        if crate::runtime::async_runtime_forked() {
+            std::mem::forget(self.inner.swap(None));
+            self.cluster_events.forget_task();
+            self.session_refresh.forget_task();
+            self.srv_refresh.forget_task();
+            self.cluster_name_guard.forget_task();
+        }
+    }
+}
@@ -1,13 +1,16 @@
-//! Operation-level concurrency limiter for backpressure.
+//! Operation-level concurrency and rate limiter for backpressure.
 //!
 //! Wraps a `tokio::sync::Semaphore` to limit the number of concurrent
 //! in-flight operations, preventing the upstream connection pool from
-//! exhaustion (`NoMoreConnections` errors).
+//! exhaustion (`NoMoreConnections` errors). An optional token bucket caps
+//! how many operations start per second, so bulk jobs cannot saturate a
+//! shared cluster.
 //!
-//! When disabled (`max_concurrent == 0`), all methods are zero-cost no-ops.
+//! When disabled (`max_concurrent == 0`, no rate limit), all methods are
+//! zero-cost no-ops.
 
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use pyo3::prelude::*;
 use tokio::sync::{OwnedSemaphorePermit, Semaphore};
@@ -20,14 +23,58 @@ use crate::errors::BackpressureError;
 /// to proceed. When the limiter is disabled, this is `None` (zero cost).
 pub type OperationPermit = Option<OwnedSemaphorePermit>;
 
+/// Token bucket holding up to one second's worth of operations.
+///
+/// Implemented as a generic cell rate algorithm: `tat` is the theoretical
+/// arrival time of the next operation, so a reservation is a single
+/// timestamp update and waiting callers never hold the lock.
+struct RateLimiter {
+    ops_per_second: f64,
+    /// Time between two tokens.
+    interval: Duration,
+    /// How far `tat` may run ahead of now before callers must wait (burst - 1 tokens).
+    tolerance: Duration,
+    tat: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    fn new(ops_per_second: f64) -> Self {
+        let interval = Duration::from_secs_f64(1.0 / ops_per_second);
+        let burst = ops_per_second.floor().max(1.0) as u32;
+        Self {
+            ops_per_second,
+            interval,
+            tolerance: interval * (burst - 1),
+            tat: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Reserve the next token at `now` and return how long to wait for it.
+    ///
+    /// When the wait would exceed `max_wait`, nothing is reserved and the
+    /// required wait is returned as the error.
+    fn reserve(&self, now: Instant, max_wait: Option<Duration>) -> Result<Duration, Duration> {
+        let mut tat = self.tat.lock().unwrap_or_else(|e| e.into_inner());
+        let start = (*tat).max(now);
+        let wait = start.saturating_duration_since(now + self.tolerance);
+        if max_wait.is_some_and(|max| wait > max) {
+            return Err(wait);
+        }
+        *tat = start + self.interval;
+        Ok(wait)
+    }
+}
+
 /// Limits the number of concurrent in-flight operations per client.
 ///
 /// The upstream `aerospike-core` connection pool already handles connection
 /// reuse and idle timeout. This limiter prevents pool exhaustion by gating
-/// how many operations can be in-flight simultaneously.
+/// how many operations can be in-flight simultaneously, and optionally how
+/// many may start per second.
 #[derive(Clone)]
 pub struct OperationLimiter {
     semaphore: Option<Arc<Semaphore>>,
+    rate: Option<Arc<RateLimiter>>,
     max_concurrent: usize,
     timeout_ms: u64,
 }
@@ -47,19 +94,49 @@ impl OperationLimiter {
         };
         Self {
             semaphore,
+            rate: None,
             max_concurrent,
             timeout_ms,
         }
     }
 
+    /// Also cap the start rate at `ops_per_second` (`0` leaves it unlimited).
+    ///
+    /// Bursts of up to one second's worth of operations pass immediately;
+    /// beyond that, callers wait for the next token. With `timeout_ms > 0`,
+    /// a caller whose token is further away than the timeout fails with
+    /// `BackpressureError` without waiting.
+    pub fn with_rate_limit(mut self, ops_per_second: f64) -> Self {
+        self.rate = (ops_per_second > 0.0).then(|| Arc::new(RateLimiter::new(ops_per_second)));
+        self
+    }
+
     /// Acquire a permit for one operation.
     ///
-    /// Returns `None` when the limiter is disabled (zero overhead path).
+    /// Waits for a rate limit token first, when configured.
+    /// Returns `None` when the concurrency limit is disabled (zero overhead path).
     /// Returns `Some(permit)` when a slot is available.
     /// Raises `BackpressureError` if the timeout expires while waiting.
     ///
     /// The `operation` name is included in error messages for diagnostics.
     pub async fn acquire_named(&self, operation: &str) -> PyResult<OperationPermit> {
+        if let Some(rate) = &self.rate {
+            let max_wait = (self.timeout_ms > 0).then(|| Duration::from_millis(self.timeout_ms));
+            match rate.reserve(Instant::now(), max_wait) {
+                Ok(wait) if wait.is_zero() => {}
+                Ok(wait) => tokio::time::sleep(wait).await,
+                Err(wait) => {
+                    return Err(BackpressureError::new_err(format!(
+                        "Rate limit on '{}': next slot in {}ms exceeds {}ms timeout (max_ops_per_second={})",
+                        operation,
+                        wait.as_millis(),
+                        self.timeout_ms,
+                        rate.ops_per_second
+                    )))
+                }
+            }
+        }
+
         let sem = match &self.semaphore {
             None => return Ok(None),
             Some(s) => s.clone(),
@@ -136,6 +213,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_acquire_named_includes_op_in_error() {
+        Python::initialize();
         let limiter = OperationLimiter::new(1, 50);
         let _p = limiter.acquire_named("batch_read").await.unwrap();
 
@@ -147,4 +225,53 @@ mod tests {
             "Error message should include the operation name, got: {err_msg}"
         );
     }
+
+    #[test]
+    fn test_rate_limiter_allows_burst_then_spaces_tokens() {
+        let rate = RateLimiter::new(4.0);
+        let now = Instant::now();
+        *rate.tat.lock().unwrap() = now;
+        for _ in 0..4 {
+            assert_eq!(rate.reserve(now, None), Ok(Duration::ZERO));
+        }
+        assert_eq!(rate.reserve(now, None), Ok(Duration::from_millis(250)));
+        assert_eq!(rate.reserve(now, None), Ok(Duration::from_millis(500)));
+        // Tokens refill as time passes.
+        let later = now + Duration::from_secs(5);
+        assert_eq!(rate.reserve(later, None), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_rate_limiter_rejects_without_reserving() {
+        let rate = RateLimiter::new(1.0);
+        let now = Instant::now();
+        *rate.tat.lock().unwrap() = now;
+        assert_eq!(rate.reserve(now, None), Ok(Duration::ZERO));
+        let max_wait = Some(Duration::from_millis(100));
+        assert_eq!(rate.reserve(now, max_wait), Err(Duration::from_secs(1)));
+        assert_eq!(rate.reserve(now, max_wait), Err(Duration::from_secs(1)));
+        assert_eq!(rate.reserve(now, None), Ok(Duration::from_secs(1)));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_timeout_raises_backpressure_error() {
+        Python::initialize();
+        let limiter = OperationLimiter::new(0, 50).with_rate_limit(1.0);
+        assert!(limiter.acquire_named("get").await.unwrap().is_none());
+
+        let err = limiter.acquire_named("get").await.unwrap_err();
+        Python::attach(|py| assert!(err.is_instance_of::<BackpressureError>(py)));
+        assert!(err.to_string().contains("max_ops_per_second=1"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_waits_for_next_token() {
+        let limiter = OperationLimiter::new(0, 0).with_rate_limit(10.0);
+        let start = Instant::now();
+        // The first 10 fit in the burst; the 11th waits ~100ms for a token.
+        for _ in 0..11 {
+            limiter.acquire_named("put").await.unwrap();
+        }
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
 }
@@ -1,5 +1,6 @@
 //! Python-visible batch record types for all batch operations
-//! (`batch_read`, `batch_write`, `batch_operate`, `batch_remove`, `batch_write_numpy`).
+//! (`batch_read`, `batch_exists`, `batch_write`, `batch_operate`, `batch_remove`,
+//! `batch_write_numpy`).
 //!
 //! Uses **lazy conversion** for the `record` field: bins are NOT converted to
 //! Python until the user accesses `br.record`. This reduces GIL hold time by
@@ -7,12 +8,13 @@
 
 use std::sync::{Arc, Mutex};
 
-use aerospike_core::{BatchRecord, Record, ResultCode};
+use aerospike_core::{BatchRecord, Error as AsError, Record, ResultCode};
 use log::trace;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyTuple};
 
-use crate::errors::result_code_to_int;
+use crate::errors::{as_to_pyerr, result_code_to_int, with_key};
+use crate::record_helpers::record_to_meta;
 use crate::types::key::key_to_py;
 use crate::types::record::record_to_py_with_key;
 
@@ -79,13 +81,102 @@ impl PyBatchRecord {
     fn record(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         self.record_cell.lock().unwrap().to_python(py)
     }
+
+    /// The classic `(key, meta, bins)` record tuple; `meta` and `bins` are
+    /// `None` when the batch returned no record for this key.
+    fn as_tuple(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let record = self.record(py)?;
+        if !record.is_none(py) {
+            return Ok(record);
+        }
+        Ok(
+            PyTuple::new(py, [self.key.clone_ref(py), py.None(), py.None()])?
+                .into_any()
+                .unbind(),
+        )
+    }
+
+    /// Raise the exception mapped from `result` (e.g. `RecordNotFound`),
+    /// with `.key` set; do nothing when it is `0`.
+    fn raise_for_status(&self, py: Python<'_>) -> PyResult<()> {
+        if self.result == 0 {
+            return Ok(());
+        }
+        Err(crate::errors::result_code_error(
+            py,
+            self.result,
+            self.in_doubt,
+            self.key.bind(py),
+        ))
+    }
 }
 
 /// Container holding a list of [`PyBatchRecord`]s, exposed to Python.
+///
+/// Iterable and indexable by position; `get(key)` looks a record up by
+/// key digest.
 #[pyclass(name = "BatchRecords")]
 pub struct PyBatchRecords {
     #[pyo3(get)]
     batch_records: Vec<Py<PyBatchRecord>>,
+    /// `(namespace, digest)` of each record, in `batch_records` order.
+    keys: Vec<(String, [u8; 20])>,
+    results: Vec<i32>,
+}
+
+#[pymethods]
+impl PyBatchRecords {
+    fn __len__(&self) -> usize {
+        self.batch_records.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: isize) -> PyResult<Py<PyBatchRecord>> {
+        let len = self.batch_records.len() as isize;
+        let idx = if index < 0 { len + index } else { index };
+        if idx < 0 || idx >= len {
+            return Err(pyo3::exceptions::PyIndexError::new_err(
+                "BatchRecords index out of range",
+            ));
+        }
+        Ok(self.batch_records[idx as usize].clone_ref(py))
+    }
+
+    fn __iter__(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let list = PyList::new(py, &self.batch_records)?;
+        Ok(list.try_iter()?.into_any().unbind())
+    }
+
+    /// The record for `key` (a `(namespace, set, key)` tuple), matched by
+    /// digest, or `default` when the batch does not contain it.
+    #[pyo3(signature = (key, default=None))]
+    fn get(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        default: Option<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        let key = crate::types::key::py_to_key(key)?;
+        let found = self
+            .keys
+            .iter()
+            .position(|(namespace, digest)| *namespace == key.namespace && *digest == key.digest);
+        Ok(match found {
+            Some(i) => self.batch_records[i].clone_ref(py).into_any(),
+            None => default.unwrap_or_else(|| py.None()),
+        })
+    }
+
+    /// Number of records with result code `0`.
+    #[getter]
+    fn ok_count(&self) -> usize {
+        self.results.iter().filter(|&&rc| rc == 0).count()
+    }
+
+    /// Number of records with a non-zero result code.
+    #[getter]
+    fn error_count(&self) -> usize {
+        self.results.iter().filter(|&&rc| rc != 0).count()
+    }
 }
 
 // ── Deferred conversion types for async client ─────────────────────
@@ -101,6 +192,8 @@ pub struct PyBatchRecords {
 /// `batch_write`, `batch_write_numpy`, and `batch_remove`.
 pub struct PendingBatchRecords {
     pub results: Vec<BatchRecord>,
+    /// `allow_partial_results` of the call's batch policy.
+    pub allow_partial: bool,
 }
 
 impl<'py> IntoPyObject<'py> for PendingBatchRecords {
@@ -109,7 +202,7 @@ impl<'py> IntoPyObject<'py> for PendingBatchRecords {
     type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        let batch = batch_to_batch_records_py(py, self.results)?;
+        let batch = batch_to_batch_records_py(py, self.results, self.allow_partial)?;
         Ok(Py::new(py, batch)?.into_bound(py).into_any())
     }
 }
@@ -142,6 +235,17 @@ pub enum PendingBatchRead {
     Numpy {
         results: Vec<BatchRecord>,
         dtype: Py<PyAny>,
+        null_mask: bool,
+    },
+    /// Arrow: returns a `pyarrow.RecordBatch` (eager, columnar).
+    Arrow {
+        results: Vec<BatchRecord>,
+        bins: Option<Vec<String>>,
+    },
+    /// DataFrame columns: returns `(index, columns)` for `batch_read_df`.
+    Columns {
+        results: Vec<BatchRecord>,
+        bins: Option<Vec<String>>,
     },
 }
 
@@ -176,8 +280,52 @@ impl<'py> IntoPyObject<'py> for PendingBatchRead {
                     Ok(result)
                 })
             }
-            PendingBatchRead::Numpy { results, dtype } => {
-                crate::numpy_support::batch_to_numpy_py(py, &results, &dtype.into_bound(py))
+            PendingBatchRead::Numpy {
+                results,
+                dtype,
+                null_mask,
+            } => crate::numpy_support::batch_to_numpy_py(
+                py,
+                &results,
+                &dtype.into_bound(py),
+                null_mask,
+            )
+            .map(|obj| obj.into_bound(py)),
+            PendingBatchRead::Arrow { results, bins } => {
+                crate::arrow_support::batch_to_arrow_py(py, &results, bins.as_deref())
+                    .map(|obj| obj.into_bound(py))
+            }
+            PendingBatchRead::Columns { results, bins } => {
+                crate::pandas_support::batch_to_columns_py(py, &results, bins.as_deref())
+                    .map(|obj| obj.into_bound(py))
+            }
+        }
+    }
+}
+
+/// Deferred batch exists → Python conversion.
+///
+/// The list path builds one small `(key, meta)` tuple per key, so unlike
+/// `batch_read` there is no handle indirection.
+pub enum PendingBatchExists {
+    /// `list[(key, meta | None)]`.
+    List { results: Vec<BatchRecord> },
+    /// `(found: np.ndarray[bool], meta: np.ndarray[gen, ttl])`.
+    Numpy { results: Vec<BatchRecord> },
+}
+
+impl<'py> IntoPyObject<'py> for PendingBatchExists {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self {
+            PendingBatchExists::List { results } => {
+                batch_exists_to_list_py(py, &results).map(|list| list.into_any())
+            }
+            PendingBatchExists::Numpy { results } => {
+                crate::numpy_support::batch_exists_to_numpy_py(py, &results)
                     .map(|obj| obj.into_bound(py))
             }
         }
@@ -394,19 +542,75 @@ pub fn batch_to_dict_py<'py>(
     Ok(dict)
 }
 
+/// Convert `batch_exists` results to `list[(key, meta | None)]`.
+///
+/// `meta` is `None` for any key whose record was not returned (not found,
+/// filtered out, or a per-record error), mirroring single-key `exists`.
+pub fn batch_exists_to_list_py<'py>(
+    py: Python<'py>,
+    results: &[BatchRecord],
+) -> PyResult<Bound<'py, PyList>> {
+    let list = PyList::empty(py);
+    for br in results {
+        let key_py = key_to_py(py, &br.key)?;
+        let meta = match &br.record {
+            Some(record) if matches!(&br.result_code, None | Some(ResultCode::Ok)) => {
+                record_to_meta(py, record)?
+            }
+            _ => py.None(),
+        };
+        list.append(PyTuple::new(py, [key_py, meta])?)?;
+    }
+    Ok(list)
+}
+
+/// Whether a per-record result code is a hard error. A missing or
+/// filtered-out record is an expected outcome, not a failure.
+fn is_hard_error(result_code: &Option<ResultCode>) -> bool {
+    !matches!(
+        result_code,
+        None | Some(ResultCode::Ok | ResultCode::KeyNotFoundError | ResultCode::FilteredOut)
+    )
+}
+
+/// Apply `allow_partial_results`: with `false`, raise the first per-record
+/// hard error (with its key attached) instead of returning partial results.
+pub fn check_partial_results(results: &[BatchRecord], allow_partial: bool) -> PyResult<()> {
+    if allow_partial {
+        return Ok(());
+    }
+    match results.iter().find(|br| is_hard_error(&br.result_code)) {
+        Some(BatchRecord {
+            key,
+            result_code: Some(rc),
+            in_doubt,
+            ..
+        }) => Err(with_key(
+            as_to_pyerr(AsError::ServerError(*rc, *in_doubt, String::new())),
+            key,
+        )),
+        _ => Ok(()),
+    }
+}
+
 /// Convert `BatchRecord`s into a Python [`PyBatchRecords`] with **lazy bin conversion**.
 ///
 /// Only key and result_code are converted eagerly (lightweight).
 /// The record's `(key, meta, bins)` tuple is deferred until `br.record` is accessed.
+/// With `allow_partial` off, a per-record hard error is raised instead.
 pub fn batch_to_batch_records_py(
     py: Python<'_>,
     results: Vec<BatchRecord>,
+    allow_partial: bool,
 ) -> PyResult<PyBatchRecords> {
+    check_partial_results(&results, allow_partial)?;
     trace!(
         "Converting {} batch records to Python (lazy bins)",
         results.len()
     );
     let mut batch_records = Vec::with_capacity(results.len());
+    let mut keys = Vec::with_capacity(results.len());
+    let mut result_codes = Vec::with_capacity(results.len());
 
     for br in results {
         // Only convert key immediately (lightweight, always needed for routing)
@@ -434,7 +638,13 @@ pub fn batch_to_batch_records_py(
         };
 
         batch_records.push(Py::new(py, batch_record)?);
+        keys.push((br.key.namespace, br.key.digest));
+        result_codes.push(result_code);
     }
 
-    Ok(PyBatchRecords { batch_records })
+    Ok(PyBatchRecords {
+        batch_records,
+        keys,
+        results: result_codes,
+    })
 }
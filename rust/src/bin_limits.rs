@@ -0,0 +1,126 @@
+//! Client-side bin name length and bin count checks for writes.
+//!
+//! Servers before 7.0 reject bin names longer than 15 bytes; 7.0 and later
+//! accept up to 255. The limit starts at 15 and is raised on `connect()`
+//! when `info("build")` reports a 7.0+ server. Writes with too long a name
+//! raise `BinNameError`, and writes with more bins than a record can hold
+//! raise `ParamError`, before anything is sent.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aerospike_core::{AdminPolicy, Bin, Client as AsClient};
+use log::debug;
+use pyo3::prelude::*;
+
+use crate::errors::{BinNameError, ParamError};
+
+/// Bin name limit of servers before 7.0, and of an unknown server.
+pub const LEGACY_MAX_BIN_NAME_LEN: usize = 15;
+/// Bin name limit of 7.0+ servers (the wire format's one-byte length).
+pub const MAX_BIN_NAME_LEN: usize = 255;
+/// Most bins a single record can hold.
+pub const MAX_BINS_PER_RECORD: usize = 32_767;
+
+/// Bin limits of the cluster a client is connected to.
+#[derive(Debug)]
+pub struct BinLimits {
+    max_name_len: AtomicUsize,
+}
+
+impl Default for BinLimits {
+    fn default() -> Self {
+        Self {
+            max_name_len: AtomicUsize::new(LEGACY_MAX_BIN_NAME_LEN),
+        }
+    }
+}
+
+impl BinLimits {
+    /// Ask a node for its build and raise the name limit for 7.0+ servers.
+    /// Keeps the 15-byte limit when the node cannot be asked.
+    pub async fn detect(&self, client: &AsClient) {
+        let Ok(node) = client.cluster.get_random_node() else {
+            return;
+        };
+        match node.info(&AdminPolicy::default(), &["build"]).await {
+            Ok(info) => {
+                if let Some(build) = info.get("build") {
+                    self.set_server_build(build);
+                }
+            }
+            Err(e) => debug!("Server build unavailable, keeping 15-byte bin names: {e}"),
+        }
+    }
+
+    fn set_server_build(&self, build: &str) {
+        let major: u32 = build
+            .split('.')
+            .next()
+            .and_then(|m| m.trim().parse().ok())
+            .unwrap_or(0);
+        let limit = if major >= 7 {
+            MAX_BIN_NAME_LEN
+        } else {
+            LEGACY_MAX_BIN_NAME_LEN
+        };
+        self.max_name_len.store(limit, Ordering::Relaxed);
+    }
+
+    /// Check the bins of one record write.
+    pub fn check(&self, bins: &[Bin]) -> PyResult<()> {
+        if bins.len() > MAX_BINS_PER_RECORD {
+            return Err(ParamError::new_err(format!(
+                "A record can hold at most {MAX_BINS_PER_RECORD} bins, got {}",
+                bins.len()
+            )));
+        }
+        let limit = self.max_name_len.load(Ordering::Relaxed);
+        match bins.iter().find(|b| b.name.len() > limit) {
+            Some(bin) => Err(BinNameError::new_err(format!(
+                "Bin name '{}' exceeds the {limit}-byte limit ({} bytes)",
+                bin.name,
+                bin.name.len()
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use aerospike_core::Value;
+
+    fn bins(name_len: usize) -> Vec<Bin> {
+        vec![Bin::new("a".repeat(name_len), Value::Int(1))]
+    }
+
+    #[test]
+    fn name_limit_follows_server_build() {
+        Python::initialize();
+        let limits = BinLimits::default();
+        assert!(limits.check(&bins(15)).is_ok());
+        Python::attach(|py| {
+            let err = limits.check(&bins(16)).unwrap_err();
+            assert!(err.is_instance_of::<BinNameError>(py));
+        });
+
+        limits.set_server_build("7.1.0.2");
+        assert!(limits.check(&bins(200)).is_ok());
+        limits.set_server_build("6.4.0.0");
+        assert!(limits.check(&bins(16)).is_err());
+    }
+
+    #[test]
+    fn rejects_too_many_bins() {
+        Python::initialize();
+        let many: Vec<Bin> = (0..=MAX_BINS_PER_RECORD)
+            .map(|i| Bin::new(i.to_string(), Value::Int(1)))
+            .collect();
+        Python::attach(|py| {
+            let err = BinLimits::default().check(&many).unwrap_err();
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+}
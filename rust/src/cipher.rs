@@ -0,0 +1,253 @@
+//! Client-side encryption of designated bins through Python hooks.
+//!
+//! `set_bin_cipher(encrypt_fn, decrypt_fn, bins=[...])` registers two
+//! callables that map `bytes` to `bytes`. On write, each listed bin is
+//! encoded in its wire format (see [`crate::types::raw`]), passed to
+//! `encrypt_fn` and stored as a blob behind a marker; on read, the marker is
+//! stripped, the rest passed to `decrypt_fn` and the original value decoded.
+//! Bins that do not carry the marker (written before the cipher was
+//! registered) are returned unchanged.
+//!
+//! The hooks run with the GIL, on the calling thread for sync commands and
+//! on a runtime thread for `AsyncClient`. An exception raised by a hook fails
+//! the command, so a listed bin is never written in plaintext.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use aerospike_core::{BatchRecord, Bin, Record, Value};
+use arc_swap::ArcSwapOption;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::errors::ParamError;
+use crate::types::raw::{decode_particle, encode_particle};
+
+/// Prefix of an encrypted bin, followed by the particle type of the original
+/// value and the ciphertext returned by `encrypt_fn`.
+const MARKER: &[u8; 4] = b"\x00ASE";
+
+#[derive(Debug)]
+struct Hooks {
+    encrypt: Py<PyAny>,
+    decrypt: Py<PyAny>,
+    bins: HashSet<String>,
+}
+
+/// Cipher hooks registered via `set_bin_cipher()`, shared by a client and the
+/// queries and pipelines it creates.
+#[derive(Clone, Debug, Default)]
+pub struct BinCipher(Arc<ArcSwapOption<Hooks>>);
+
+impl BinCipher {
+    /// Validate the `set_bin_cipher()` arguments and replace the hooks;
+    /// `None` for both callables removes them.
+    pub fn set(
+        &self,
+        encrypt: Option<&Bound<'_, PyAny>>,
+        decrypt: Option<&Bound<'_, PyAny>>,
+        bins: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        let (encrypt, decrypt) = match (encrypt, decrypt) {
+            (None, None) => {
+                self.0.store(None);
+                return Ok(());
+            }
+            (Some(e), Some(d)) if e.is_callable() && d.is_callable() => (e, d),
+            _ => {
+                return Err(ParamError::new_err(
+                    "encrypt_fn and decrypt_fn must both be callables, or both None",
+                ))
+            }
+        };
+        let bins: HashSet<String> = bins.unwrap_or_default().into_iter().collect();
+        if bins.is_empty() {
+            return Err(ParamError::new_err(
+                "set_bin_cipher() needs at least one bin name in bins",
+            ));
+        }
+        self.0.store(Some(Arc::new(Hooks {
+            encrypt: encrypt.clone().unbind(),
+            decrypt: decrypt.clone().unbind(),
+            bins,
+        })));
+        Ok(())
+    }
+
+    /// Encrypt the listed bins of a write. `None` values (bin deletes) are
+    /// left alone.
+    pub fn encrypt_bins(&self, bins: &mut [Bin]) -> PyResult<()> {
+        let Some(hooks) = self.0.load_full() else {
+            return Ok(());
+        };
+        let mut targets = bins
+            .iter_mut()
+            .filter(|b| hooks.bins.contains(&b.name) && !matches!(b.value, Value::Nil))
+            .peekable();
+        if targets.peek().is_none() {
+            return Ok(());
+        }
+        Python::attach(|py| {
+            for bin in targets {
+                let (ptype, payload) = encode_particle(&bin.value)?;
+                let ciphertext = call_hook(py, &hooks.encrypt, "encrypt_fn", &payload)?;
+                let mut out = Vec::with_capacity(MARKER.len() + 1 + ciphertext.len());
+                out.extend_from_slice(MARKER);
+                out.push(ptype);
+                out.extend_from_slice(&ciphertext);
+                bin.value = Value::Blob(out);
+            }
+            Ok(())
+        })
+    }
+
+    /// Decrypt the listed bins of a record.
+    pub fn decrypt_record(&self, record: &mut Record) -> PyResult<()> {
+        let Some(hooks) = self.0.load_full() else {
+            return Ok(());
+        };
+        decrypt_bins(&hooks, &mut record.bins)
+    }
+
+    /// Decrypt the listed bins of every record of a batch.
+    pub fn decrypt_batch(&self, records: &mut [BatchRecord]) -> PyResult<()> {
+        let Some(hooks) = self.0.load_full() else {
+            return Ok(());
+        };
+        records
+            .iter_mut()
+            .filter_map(|br| br.record.as_mut())
+            .try_for_each(|record| decrypt_bins(&hooks, &mut record.bins))
+    }
+}
+
+fn decrypt_bins(hooks: &Hooks, bins: &mut HashMap<String, Value>) -> PyResult<()> {
+    let mut targets = bins
+        .iter_mut()
+        .filter(|(name, value)| {
+            hooks.bins.contains(*name) && matches!(value, Value::Blob(b) if b.starts_with(MARKER))
+        })
+        .peekable();
+    if targets.peek().is_none() {
+        return Ok(());
+    }
+    Python::attach(|py| {
+        for (name, value) in targets {
+            let Value::Blob(data) = value else {
+                continue;
+            };
+            let Some((&ptype, ciphertext)) = data[MARKER.len()..].split_first() else {
+                return Err(ParamError::new_err(format!(
+                    "encrypted bin '{name}' has no particle type"
+                )));
+            };
+            let payload = call_hook(py, &hooks.decrypt, "decrypt_fn", ciphertext)?;
+            *value = decode_particle(ptype, &payload)?;
+        }
+        Ok(())
+    })
+}
+
+/// Call `hook(data)` and return the `bytes` it produces.
+fn call_hook(py: Python<'_>, hook: &Py<PyAny>, name: &str, data: &[u8]) -> PyResult<Vec<u8>> {
+    let result = hook.bind(py).call1((PyBytes::new(py, data),))?;
+    match result.cast::<PyBytes>() {
+        Ok(bytes) => Ok(bytes.as_bytes().to_vec()),
+        Err(_) => Err(ParamError::new_err(format!(
+            "{name} must return bytes, got {}",
+            result.get_type().name()?
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use pyo3::types::PyDict;
+
+    /// A cipher that XORs every byte with 0x5a, applied to bin `secret`.
+    fn xor_cipher(py: Python<'_>) -> BinCipher {
+        let globals = PyDict::new(py);
+        py.run(
+            c"def xor(data):\n    return bytes(b ^ 0x5a for b in data)",
+            Some(&globals),
+            None,
+        )
+        .unwrap();
+        let xor = globals.get_item("xor").unwrap().unwrap();
+        let cipher = BinCipher::default();
+        cipher
+            .set(Some(&xor), Some(&xor), Some(vec!["secret".into()]))
+            .unwrap();
+        cipher
+    }
+
+    fn read_back(cipher: &BinCipher, bins: Vec<Bin>) -> PyResult<HashMap<String, Value>> {
+        let hooks = cipher.0.load_full().unwrap();
+        let mut bins = bins.into_iter().map(|b| (b.name, b.value)).collect();
+        decrypt_bins(&hooks, &mut bins)?;
+        Ok(bins)
+    }
+
+    #[test]
+    fn round_trips_listed_bins_only() {
+        Python::initialize();
+        Python::attach(|py| {
+            let cipher = xor_cipher(py);
+            let mut bins = vec![
+                Bin::new("secret".into(), Value::String("4111-1111".into())),
+                Bin::new("public".into(), Value::String("4111-1111".into())),
+            ];
+            cipher.encrypt_bins(&mut bins).unwrap();
+            assert!(matches!(&bins[0].value, Value::Blob(b) if b.starts_with(MARKER)));
+            assert_eq!(bins[1].value, Value::String("4111-1111".into()));
+
+            let read = py.detach(|| read_back(&cipher, bins)).unwrap();
+            assert_eq!(read["secret"], Value::String("4111-1111".into()));
+        });
+    }
+
+    #[test]
+    fn plaintext_bins_read_back_unchanged() {
+        Python::initialize();
+        Python::attach(|py| {
+            let cipher = xor_cipher(py);
+            let bins = vec![Bin::new("secret".into(), Value::Int(7))];
+            let read = read_back(&cipher, bins).unwrap();
+            assert_eq!(read["secret"], Value::Int(7));
+        });
+    }
+
+    #[test]
+    fn rejects_half_registration_and_empty_bins() {
+        Python::initialize();
+        Python::attach(|py| {
+            let len = py.eval(c"len", None, None).unwrap();
+            let cipher = BinCipher::default();
+            assert!(cipher
+                .set(Some(&len), None, Some(vec!["a".into()]))
+                .is_err());
+            assert!(cipher.set(Some(&len), Some(&len), Some(vec![])).is_err());
+            cipher.set(None, None, None).unwrap();
+        });
+    }
+
+    #[test]
+    fn non_bytes_hook_result_is_param_error() {
+        Python::initialize();
+        Python::attach(|py| {
+            let hex = py.eval(c"lambda data: data.hex()", None, None).unwrap();
+            let cipher = BinCipher::default();
+            cipher
+                .set(Some(&hex), Some(&hex), Some(vec!["secret".into()]))
+                .unwrap();
+            let mut bins = vec![Bin::new("secret".into(), Value::Int(7))];
+            let err = cipher.encrypt_bins(&mut bins).unwrap_err();
+            assert!(err.is_instance_of::<ParamError>(py));
+            assert!(err
+                .to_string()
+                .contains("encrypt_fn must return bytes, got str"));
+        });
+    }
+}
@@ -0,0 +1,395 @@
+//! Per-node circuit breaker for single-key commands.
+//!
+//! Counts consecutive node failures (timeouts, connection errors, device
+//! overload) per node. Once `failure_threshold` of them land within `window`,
+//! the node's circuit opens: commands whose key maps to that node fail fast
+//! with `CircuitOpenError` instead of piling retries onto a sick node. After
+//! `reset_timeout` a single trial command is let through (half-open); its
+//! outcome closes the circuit or opens it again.
+//!
+//! Keys are mapped to the master node of their partition. Batch, query and
+//! info commands span nodes and are not gated. When disabled (no
+//! `circuit_breaker` config), [`CircuitBreaker::check`] is a no-op.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use aerospike_core::{Client as AsClient, Error as AsError, Key};
+use log::warn;
+use pyo3::intern;
+use pyo3::prelude::*;
+
+use crate::errors::{error_code, CircuitOpenError, ERR_CONNECTION, ERR_INVALID_NODE, ERR_TIMEOUT};
+
+/// Result codes that count against a node: it timed out, could not be
+/// reached, or reported its storage as overloaded.
+const NODE_FAILURE_CODES: [i32; 4] = [ERR_TIMEOUT, ERR_CONNECTION, ERR_INVALID_NODE, 18];
+
+/// Thresholds parsed from the `circuit_breaker` client config.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CircuitBreakerConfig {
+    pub failure_threshold: u32,
+    pub window: Duration,
+    pub reset_timeout: Duration,
+}
+
+/// Circuit state of one node, as reported by `get_cluster_stats()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CircuitState::Closed => "closed",
+            CircuitState::Open => "open",
+            CircuitState::HalfOpen => "half_open",
+        }
+    }
+
+    /// Value of the `db_client_node_circuit_state` gauge.
+    pub fn gauge_value(self) -> i64 {
+        match self {
+            CircuitState::Closed => 0,
+            CircuitState::Open => 1,
+            CircuitState::HalfOpen => 2,
+        }
+    }
+}
+
+/// Whether a failed command counts as a failure of the node that served it.
+pub trait NodeFailure {
+    fn is_node_failure(&self) -> bool;
+}
+
+impl NodeFailure for AsError {
+    fn is_node_failure(&self) -> bool {
+        NODE_FAILURE_CODES.contains(&error_code(self).0)
+    }
+}
+
+impl NodeFailure for PyErr {
+    fn is_node_failure(&self) -> bool {
+        Python::attach(|py| {
+            self.value(py)
+                .getattr(intern!(py, "code"))
+                .and_then(|code| code.extract::<Option<i32>>())
+                .ok()
+                .flatten()
+                .is_some_and(|code| NODE_FAILURE_CODES.contains(&code))
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct NodeCircuit {
+    /// Consecutive failures since `first_failure`.
+    failures: u32,
+    first_failure: Option<Instant>,
+    /// Set while the circuit is open or half-open.
+    opened_at: Option<Instant>,
+    /// A half-open trial command is in flight.
+    probing: bool,
+    /// Times the circuit has opened.
+    trips: u64,
+}
+
+impl NodeCircuit {
+    fn state(&self, now: Instant, config: &CircuitBreakerConfig) -> CircuitState {
+        match self.opened_at {
+            None => CircuitState::Closed,
+            Some(at) if now.duration_since(at) < config.reset_timeout => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Admit a command. `Ok(true)` marks it as the half-open trial;
+    /// `Err(remaining)` rejects it while the circuit is open.
+    fn admit(&mut self, now: Instant, config: &CircuitBreakerConfig) -> Result<bool, Duration> {
+        match self.state(now, config) {
+            CircuitState::Closed => Ok(false),
+            CircuitState::HalfOpen if !self.probing => {
+                self.probing = true;
+                Ok(true)
+            }
+            CircuitState::HalfOpen => Err(Duration::ZERO),
+            CircuitState::Open => {
+                let opened_at = self.opened_at.unwrap_or(now);
+                Err(config
+                    .reset_timeout
+                    .saturating_sub(now.duration_since(opened_at)))
+            }
+        }
+    }
+
+    /// Record the outcome of an admitted command; returns `true` if it
+    /// opened the circuit. While open, only the trial's outcome counts:
+    /// commands admitted before the circuit opened are ignored.
+    fn on_result(
+        &mut self,
+        failed: bool,
+        probe: bool,
+        now: Instant,
+        config: &CircuitBreakerConfig,
+    ) -> bool {
+        if self.opened_at.is_some() && !probe {
+            return false;
+        }
+        if !failed {
+            self.failures = 0;
+            self.first_failure = None;
+            self.opened_at = None;
+            self.probing = false;
+            return false;
+        }
+        if probe {
+            // A failed trial re-opens the circuit for another reset_timeout.
+            self.opened_at = Some(now);
+            self.probing = false;
+            self.trips += 1;
+            return true;
+        }
+        if self
+            .first_failure
+            .is_none_or(|at| now.duration_since(at) > config.window)
+        {
+            self.failures = 0;
+            self.first_failure = Some(now);
+        }
+        self.failures += 1;
+        if self.failures < config.failure_threshold {
+            return false;
+        }
+        self.failures = 0;
+        self.first_failure = None;
+        self.opened_at = Some(now);
+        self.trips += 1;
+        true
+    }
+}
+
+/// Per-client circuit breaker, keyed by node name.
+#[derive(Debug, Default)]
+pub struct CircuitBreaker {
+    config: Option<CircuitBreakerConfig>,
+    nodes: Mutex<HashMap<String, NodeCircuit>>,
+}
+
+impl CircuitBreaker {
+    /// Create a breaker; `None` disables it.
+    pub fn new(config: Option<CircuitBreakerConfig>) -> Self {
+        Self {
+            config,
+            nodes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Admit a command on `key`, or raise `CircuitOpenError` if the key's
+    /// node has an open circuit. Record the outcome on the returned guard.
+    pub fn check(&self, client: &AsClient, key: &Key) -> PyResult<CircuitGuard<'_>> {
+        let Some(config) = &self.config else {
+            return Ok(CircuitGuard::disabled(self));
+        };
+        let partition_id = crate::types::key::partition_id(&key.digest);
+        // An unknown namespace fails in the command itself; nothing to gate.
+        let Ok(node) = client.cluster.get_master_node(&key.namespace, partition_id) else {
+            return Ok(CircuitGuard::disabled(self));
+        };
+        let node = node.name().to_string();
+        let probe = {
+            let mut nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+            nodes
+                .entry(node.clone())
+                .or_default()
+                .admit(Instant::now(), config)
+        };
+        match probe {
+            Ok(probe) => Ok(CircuitGuard {
+                breaker: self,
+                node: Some(node),
+                probe,
+            }),
+            Err(remaining) => Err(CircuitOpenError::new_err(format!(
+                "Circuit open for node {node}: retry in {}ms",
+                remaining.as_millis()
+            ))),
+        }
+    }
+
+    /// Circuit state and trip count of `node`.
+    pub fn node_state(&self, node: &str) -> (CircuitState, u64) {
+        let Some(config) = &self.config else {
+            return (CircuitState::Closed, 0);
+        };
+        let nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+        nodes
+            .get(node)
+            .map_or((CircuitState::Closed, 0), |circuit| {
+                (circuit.state(Instant::now(), config), circuit.trips)
+            })
+    }
+
+    fn record(&self, node: &str, failed: bool, probe: bool) {
+        let Some(config) = &self.config else {
+            return;
+        };
+        let mut nodes = self.nodes.lock().unwrap_or_else(|e| e.into_inner());
+        let circuit = nodes.entry(node.to_string()).or_default();
+        if circuit.on_result(failed, probe, Instant::now(), config) {
+            warn!(
+                "Circuit opened for node {node} for {}ms",
+                config.reset_timeout.as_millis()
+            );
+        }
+    }
+}
+
+/// Admission for one command; pass its result to [`CircuitGuard::record`].
+///
+/// Dropping the guard without recording (e.g. a cancelled future) frees the
+/// half-open trial slot so another command can probe the node.
+pub struct CircuitGuard<'a> {
+    breaker: &'a CircuitBreaker,
+    node: Option<String>,
+    probe: bool,
+}
+
+impl<'a> CircuitGuard<'a> {
+    fn disabled(breaker: &'a CircuitBreaker) -> Self {
+        Self {
+            breaker,
+            node: None,
+            probe: false,
+        }
+    }
+
+    /// Count `result` against the node and pass it through.
+    pub fn record<T, E: NodeFailure>(mut self, result: Result<T, E>) -> Result<T, E> {
+        if let Some(node) = self.node.take() {
+            let failed = result.as_ref().err().is_some_and(E::is_node_failure);
+            self.breaker.record(&node, failed, self.probe);
+        }
+        result
+    }
+}
+
+impl Drop for CircuitGuard<'_> {
+    fn drop(&mut self) {
+        let Some(node) = self.node.take() else {
+            return;
+        };
+        if self.probe {
+            let mut nodes = self.breaker.nodes.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(circuit) = nodes.get_mut(&node) {
+                circuit.probing = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> CircuitBreakerConfig {
+        CircuitBreakerConfig {
+            failure_threshold: 3,
+            window: Duration::from_secs(10),
+            reset_timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn opens_after_threshold_consecutive_failures() {
+        let config = config();
+        let now = Instant::now();
+        let mut circuit = NodeCircuit::default();
+        assert!(!circuit.on_result(true, false, now, &config));
+        assert!(!circuit.on_result(true, false, now, &config));
+        assert!(circuit.on_result(true, false, now, &config));
+        assert_eq!(circuit.state(now, &config), CircuitState::Open);
+        assert_eq!(circuit.trips, 1);
+        assert!(circuit.admit(now, &config).is_err());
+    }
+
+    #[test]
+    fn success_resets_failure_count() {
+        let config = config();
+        let now = Instant::now();
+        let mut circuit = NodeCircuit::default();
+        circuit.on_result(true, false, now, &config);
+        circuit.on_result(true, false, now, &config);
+        circuit.on_result(false, false, now, &config);
+        assert!(!circuit.on_result(true, false, now, &config));
+        assert_eq!(circuit.state(now, &config), CircuitState::Closed);
+    }
+
+    #[test]
+    fn failures_outside_window_start_a_new_count() {
+        let config = config();
+        let start = Instant::now();
+        let mut circuit = NodeCircuit::default();
+        circuit.on_result(true, false, start, &config);
+        circuit.on_result(true, false, start, &config);
+        let later = start + Duration::from_secs(11);
+        assert!(!circuit.on_result(true, false, later, &config));
+        assert_eq!(circuit.failures, 1);
+    }
+
+    #[test]
+    fn half_open_admits_one_trial() {
+        let config = config();
+        let start = Instant::now();
+        let mut circuit = NodeCircuit::default();
+        for _ in 0..3 {
+            circuit.on_result(true, false, start, &config);
+        }
+        let later = start + Duration::from_secs(6);
+        assert_eq!(circuit.state(later, &config), CircuitState::HalfOpen);
+        assert_eq!(circuit.admit(later, &config), Ok(true));
+        assert!(circuit.admit(later, &config).is_err());
+
+        // Late results of commands admitted before the circuit opened are ignored.
+        assert!(!circuit.on_result(false, false, later, &config));
+        assert_eq!(circuit.state(later, &config), CircuitState::HalfOpen);
+
+        // A failed trial re-opens the circuit.
+        assert!(circuit.on_result(true, true, later, &config));
+        assert_eq!(circuit.state(later, &config), CircuitState::Open);
+        assert_eq!(circuit.trips, 2);
+
+        // A successful trial closes it.
+        let retry = later + Duration::from_secs(6);
+        assert_eq!(circuit.admit(retry, &config), Ok(true));
+        assert!(!circuit.on_result(false, true, retry, &config));
+        assert_eq!(circuit.state(retry, &config), CircuitState::Closed);
+    }
+
+    #[test]
+    fn classifies_node_failures() {
+        assert!(AsError::Timeout("t".into()).is_node_failure());
+        assert!(AsError::Connection("c".into()).is_node_failure());
+        assert!(AsError::ServerError(
+            aerospike_core::ResultCode::DeviceOverload,
+            false,
+            String::new()
+        )
+        .is_node_failure());
+        assert!(!AsError::ServerError(
+            aerospike_core::ResultCode::KeyNotFoundError,
+            false,
+            String::new()
+        )
+        .is_node_failure());
+    }
+
+    #[test]
+    fn disabled_breaker_reports_closed() {
+        let breaker = CircuitBreaker::new(None);
+        assert_eq!(breaker.node_state("BB9"), (CircuitState::Closed, 0));
+    }
+}
@@ -14,23 +14,40 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
 use crate::backpressure::OperationLimiter;
-use crate::batch_types::{batch_to_batch_records_py, batch_to_dict_py};
+use crate::batch_types::{
+    batch_exists_to_list_py, batch_to_batch_records_py, batch_to_dict_py, check_partial_results,
+};
+use crate::bin_limits::BinLimits;
+use crate::cipher::BinCipher;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::cluster_events::ClusterEvents;
+use crate::cluster_name::ClusterNameGuard;
+use crate::compression::BinCompression;
+use crate::dns::SrvRefresh;
 use crate::errors::as_to_pyerr;
+use crate::info_parsers;
 use crate::panic_safety::catch_panic_sync;
 use crate::policy::admin_policy::{parse_privileges, role_to_py, user_to_py};
-use crate::policy::client_policy::{parse_backpressure_config, parse_client_policy};
-use crate::record_helpers::record_to_meta;
-use crate::runtime::RUNTIME;
+use crate::policy::batch_policy::parse_allow_partial_results;
+use crate::policy::client_policy::{
+    parse_backpressure_config, parse_bin_compression_config, parse_circuit_breaker_config,
+    parse_client_policy, parse_event_loop_guard, parse_refresh_config, parse_runtime_config,
+    validate_client_config,
+};
+use crate::record_helpers::{record_to_meta, PendingMeta, PendingValue};
+use crate::runtime::ClientRuntime;
+use crate::session::{self, SessionRefresh};
 use crate::types::host::parse_hosts_from_config;
 use crate::types::key::key_to_py;
-use crate::types::record::record_to_py_with_key;
+use crate::types::record::{raw_record_to_py_with_key, record_into_py_with_key};
 use crate::types::value::value_to_py;
 
 /// Synchronous Aerospike client exposed to Python as `Client`.
 ///
-/// Wraps `aerospike_core::Client` and uses a shared Tokio runtime
-/// ([`crate::runtime::RUNTIME`]) to block on async operations while
-/// releasing the GIL via `py.detach()`.
+/// Wraps `aerospike_core::Client` and blocks on async operations while
+/// releasing the GIL via `py.detach()`, using the shared Tokio runtime
+/// ([`crate::runtime::RUNTIME`]) or, with `rust_runtime.dedicated`, a runtime
+/// owned by this client.
 #[pyclass(name = "Client", subclass)]
 pub struct PyClient {
     /// The underlying async client, wrapped in `Arc` for cheap cloning.
@@ -42,20 +59,55 @@ pub struct PyClient {
     connection_info: Arc<crate::tracing::ConnectionInfo>,
     /// Operation concurrency limiter (disabled by default).
     limiter: Arc<OperationLimiter>,
+    /// Per-node circuit breaker (disabled by default).
+    breaker: Arc<CircuitBreaker>,
+    /// Bin compression (`bin_compression`, disabled by default).
+    compression: Arc<BinCompression>,
+    /// Bin name / bin count limits of the connected cluster.
+    bin_limits: Arc<BinLimits>,
+    /// Bin encryption hooks registered via `set_bin_cipher()`.
+    cipher: BinCipher,
+    /// Process that connected `inner`; any other pid is a forked child.
+    pid: u32,
     /// Lifecycle state: Disconnected(0) → Connecting(1) → Connected(2) → Closing(3).
     state: u8,
+    /// Runtime the client was connected on; shared until `connect()` builds a dedicated one.
+    runtime: ClientRuntime,
+    /// Cluster topology callback registered via `on_cluster_event()`.
+    cluster_events: ClusterEvents,
+    /// Periodic connection re-authentication (`refresh_seconds`).
+    session_refresh: SessionRefresh,
+    /// Periodic `hosts_srv` re-resolution (`hosts_srv_refresh_seconds`).
+    srv_refresh: SrvRefresh,
+    /// Tend-time `cluster_name` check.
+    cluster_name_guard: ClusterNameGuard,
+    /// Reject commands from a thread running an asyncio loop (see
+    /// [`crate::runtime::forbid_running_loop`]).
+    event_loop_guard: bool,
 }
 
 #[pymethods]
 impl PyClient {
     #[new]
-    fn new(config: Py<PyAny>) -> PyResult<Self> {
+    fn new(config: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let config = crate::types::host::config_arg(config)?;
         Ok(PyClient {
             inner: None,
             config,
             connection_info: Arc::new(crate::tracing::ConnectionInfo::default()),
             limiter: Arc::new(OperationLimiter::new(0, 0)),
+            breaker: Arc::new(CircuitBreaker::default()),
+            compression: Arc::new(BinCompression::default()),
+            bin_limits: Arc::new(BinLimits::default()),
+            cipher: BinCipher::default(),
+            pid: std::process::id(),
             state: DISCONNECTED,
+            runtime: ClientRuntime::Shared,
+            cluster_events: ClusterEvents::default(),
+            session_refresh: SessionRefresh::default(),
+            srv_refresh: SrvRefresh::default(),
+            cluster_name_guard: ClusterNameGuard::default(),
+            event_loop_guard: true,
         })
     }
 
@@ -67,6 +119,9 @@ impl PyClient {
         username: Option<&str>,
         password: Option<&str>,
     ) -> PyResult<()> {
+        if self.is_forked() {
+            self.abandon_after_fork();
+        }
         // Guard: only allow Disconnected → Connecting transition.
         if self.state != DISCONNECTED {
             let state_name = match self.state {
@@ -96,38 +151,93 @@ impl PyClient {
             effective_config.set_item("password", pass)?;
         }
 
+        validate_client_config(&effective_config, false)?;
         let parsed = parse_hosts_from_config(&effective_config)?;
-        let client_policy = parse_client_policy(&effective_config)?;
-        let (max_ops, timeout_ms) = parse_backpressure_config(&effective_config)?;
+        let mut client_policy = parse_client_policy(&effective_config)?;
+        // Checked by `cluster_name` rather than by aerospike_core (see there).
+        let expected_cluster = client_policy.cluster_name.take();
+        let (max_ops, timeout_ms, ops_per_second) = parse_backpressure_config(&effective_config)?;
+        let circuit_breaker = parse_circuit_breaker_config(&effective_config)?;
+        let bin_compression = parse_bin_compression_config(&effective_config)?;
+        let dedicated_workers = parse_runtime_config(&effective_config)?;
+        let refresh_interval = parse_refresh_config(&effective_config)?;
+        let event_loop_guard = parse_event_loop_guard(&effective_config)?;
 
         let cluster_name = client_common::extract_cluster_name(&effective_config)?;
 
         // Config parsed successfully — now transition to Connecting.
         self.state = CONNECTING;
 
+        // The cluster's tend task and connections live on the runtime that
+        // creates the client, so a dedicated runtime must exist before connect.
+        if let Some(workers) = dedicated_workers {
+            self.runtime = ClientRuntime::dedicated(workers).map_err(|e| {
+                self.state = DISCONNECTED;
+                crate::errors::ClientError::new_err(format!(
+                    "Failed to create dedicated Tokio runtime ({workers} workers): {e}"
+                ))
+            })?;
+        }
+
         self.connection_info = Arc::new(crate::tracing::ConnectionInfo {
             server_address: Arc::from(parsed.first_address.as_str()),
             server_port: parsed.first_port as i64,
             cluster_name: Arc::from(cluster_name.as_str()),
         });
 
-        let hosts_str = parsed.connection_string;
-        info!("Connecting to Aerospike cluster: {}", hosts_str);
+        info!(
+            "Connecting to Aerospike cluster: {}",
+            parsed.connection_string
+        );
+        let bin_limits = Arc::new(BinLimits::default());
         let result = py.detach(|| {
-            RUNTIME.block_on(async {
-                AsClient::new(
+            self.runtime.block_on(async {
+                let hosts_str = crate::dns::resolve_seeds(&parsed).await?;
+                let client = AsClient::new(
                     &client_policy,
                     &hosts_str as &(dyn aerospike_core::ToHosts + Send + Sync),
                 )
                 .await
-                .map_err(as_to_pyerr)
+                .map_err(as_to_pyerr)?;
+                if let Some(expected) = &expected_cluster {
+                    if let Err(e) = crate::cluster_name::verify(&client, expected).await {
+                        let _ = client.close().await;
+                        return Err(e);
+                    }
+                }
+                bin_limits.detect(&client).await;
+                Ok(client)
             })
         });
 
         match result {
             Ok(client) => {
-                self.inner = Some(Arc::new(client));
-                self.limiter = Arc::new(OperationLimiter::new(max_ops, timeout_ms));
+                let client = Arc::new(client);
+                self.cluster_events
+                    .start(client.clone(), &self.runtime.handle());
+                self.session_refresh.start(
+                    client.clone(),
+                    &self.runtime.handle(),
+                    refresh_interval,
+                );
+                self.srv_refresh
+                    .start(client.clone(), &self.runtime.handle(), &parsed);
+                self.cluster_name_guard.start(
+                    client.clone(),
+                    &self.runtime.handle(),
+                    expected_cluster,
+                );
+                let breaker = Arc::new(CircuitBreaker::new(circuit_breaker));
+                crate::metrics::register_client(&client, &breaker, &cluster_name);
+                self.inner = Some(client);
+                self.limiter = Arc::new(
+                    OperationLimiter::new(max_ops, timeout_ms).with_rate_limit(ops_per_second),
+                );
+                self.breaker = breaker;
+                self.compression = Arc::new(BinCompression::new(bin_compression));
+                self.bin_limits = bin_limits;
+                self.pid = std::process::id();
+                self.event_loop_guard = event_loop_guard;
                 self.state = CONNECTED;
                 info!("Connected to Aerospike cluster");
                 Ok(())
@@ -135,6 +245,7 @@ impl PyClient {
             Err(e) => {
                 // Revert to Disconnected so retry is possible.
                 self.state = DISCONNECTED;
+                self.runtime = ClientRuntime::Shared;
                 Err(e)
             }
         }
@@ -144,6 +255,7 @@ impl PyClient {
     fn is_connected(&self) -> bool {
         trace!("Checking client connection status");
         self.state == CONNECTED
+            && !self.is_forked()
             && match &self.inner {
                 Some(client) => client.is_connected(),
                 None => false,
@@ -152,15 +264,75 @@ impl PyClient {
 
     /// Lightweight health check: returns `True` if a random node responds.
     fn ping(&self, py: Python<'_>) -> bool {
+        if self.is_forked() {
+            return false;
+        }
         match &self.inner {
-            Some(client) => py.detach(|| RUNTIME.block_on(client_ops::do_ping(client))),
+            Some(client) => py.detach(|| self.runtime.block_on(client_ops::do_ping(client))),
             None => false,
         }
     }
 
+    /// Pre-open `conns_per_node` connections to each node; returns the total opened.
+    fn warm_up(&self, py: Python<'_>, conns_per_node: usize) -> PyResult<usize> {
+        client_common::validate_warm_up_conns(conns_per_node)?;
+        let client = self.blocking_client(py)?;
+        info!("Warming up connections: conns_per_node={}", conns_per_node);
+        catch_panic_sync("Client.warm_up", || {
+            py.detach(|| {
+                Ok(self
+                    .runtime
+                    .block_on(client_ops::do_warm_up(client, conns_per_node)))
+            })
+        })
+    }
+
+    /// Close idle pooled connections so the next commands log in again.
+    ///
+    /// Called by the Python wrapper after `SessionExpired`; returns the number
+    /// of connections closed.
+    fn _relogin(&self, py: Python<'_>) -> PyResult<usize> {
+        let client = self.blocking_client(py)?;
+        info!("Re-authenticating pooled connections");
+        Ok(py.detach(|| self.runtime.block_on(session::recycle_connections(client))))
+    }
+
+    /// Register a callback for node and partition map changes (`None` removes it).
+    #[pyo3(signature = (callback))]
+    fn on_cluster_event(&self, callback: Option<&Bound<'_, PyAny>>) -> PyResult<()> {
+        let callback = ClusterEvents::parse_callback(callback)?;
+        let forked = self.is_forked();
+        if forked {
+            // The watcher starts again on the next connect().
+            self.cluster_events.forget_task();
+        }
+        self.cluster_events.set_callback(callback);
+        if let Some(client) = self.inner.as_ref().filter(|_| !forked) {
+            self.cluster_events
+                .start(client.clone(), &self.runtime.handle());
+        }
+        Ok(())
+    }
+
+    /// Register `bytes -> bytes` hooks that encrypt `bins` on write and
+    /// decrypt them on read (`None` for both removes them).
+    #[pyo3(signature = (encrypt_fn, decrypt_fn, bins=None))]
+    fn set_bin_cipher(
+        &self,
+        encrypt_fn: Option<&Bound<'_, PyAny>>,
+        decrypt_fn: Option<&Bound<'_, PyAny>>,
+        bins: Option<Vec<String>>,
+    ) -> PyResult<()> {
+        self.cipher.set(encrypt_fn, decrypt_fn, bins)
+    }
+
     /// Close the connection to the cluster
     fn close(&mut self, py: Python<'_>) -> PyResult<()> {
         info!("Closing client connection");
+        if self.is_forked() {
+            self.abandon_after_fork();
+            return Ok(());
+        }
         if self.state == DISCONNECTED || self.state == CLOSING {
             // Already disconnected or closing — idempotent no-op.
             return Ok(());
@@ -172,8 +344,15 @@ impl PyClient {
         }
 
         self.state = CLOSING;
+        self.cluster_events.stop();
+        self.session_refresh.stop();
+        self.srv_refresh.stop();
+        self.cluster_name_guard.stop();
         let result = if let Some(client) = self.inner.take() {
-            py.detach(|| RUNTIME.block_on(async { client.close().await.map_err(as_to_pyerr) }))
+            py.detach(|| {
+                self.runtime
+                    .block_on(async { client.close().await.map_err(as_to_pyerr) })
+            })
         } else {
             Ok(())
         };
@@ -181,15 +360,77 @@ impl PyClient {
         // Always reset — inner is already None so no operations can proceed.
         self.connection_info = Arc::new(crate::tracing::ConnectionInfo::default());
         self.limiter = Arc::new(OperationLimiter::new(0, 0));
+        self.breaker = Arc::new(CircuitBreaker::default());
+        self.compression = Arc::new(BinCompression::default());
+        self.bin_limits = Arc::new(BinLimits::default());
+        self.runtime = ClientRuntime::Shared;
         self.state = DISCONNECTED;
         result
     }
 
+    /// Context manager entry.
+    fn __enter__(slf: Py<Self>) -> Py<Self> {
+        slf
+    }
+
+    /// Context manager exit: closes the client and never suppresses the exception.
+    ///
+    /// **Note:** the Python wrapper in `src/aerospike_py/_client.py` defines
+    /// its own `__exit__` that delegates to `self.close()`, so the wrapper's
+    /// `close()` bookkeeping (`auto_reconnect`) runs. This native method
+    /// serves callers that use the PyO3 class directly.
+    #[pyo3(signature = (_exc_type=None, _exc_val=None, _exc_tb=None))]
+    fn __exit__(
+        &mut self,
+        py: Python<'_>,
+        _exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_val: Option<&Bound<'_, PyAny>>,
+        _exc_tb: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        self.close(py)?;
+        Ok(false)
+    }
+
     /// Get node names in the cluster
     fn get_node_names(&self) -> PyResult<Vec<String>> {
         Ok(self.get_client()?.node_names())
     }
 
+    /// Name, address, port, state and rack of every node.
+    fn get_nodes(&self, py: Python<'_>) -> PyResult<Vec<client_common::NodeInfo>> {
+        let client = self.blocking_client(py)?;
+        catch_panic_sync("Client.get_nodes", || {
+            py.detach(|| Ok(self.runtime.block_on(client_ops::do_get_nodes(client))))
+        })
+    }
+
+    /// Master node (name, address, port) of a key's partition.
+    fn get_node_for_key(&self, key: &Bound<'_, PyAny>) -> PyResult<client_common::KeyNode> {
+        let key = crate::types::key::py_to_key(key)?;
+        client_ops::node_for_key(self.get_client()?, &key)
+    }
+
+    /// Partition (0-4095) a key belongs to; computed client-side.
+    fn get_partition_id(&self, key: &Bound<'_, PyAny>) -> PyResult<usize> {
+        let key = crate::types::key::py_to_key(key)?;
+        Ok(crate::types::key::partition_id(&key.digest))
+    }
+
+    /// Per-node client-side stats keyed by node name.
+    fn get_cluster_stats(
+        &self,
+        py: Python<'_>,
+    ) -> PyResult<std::collections::BTreeMap<String, client_common::NodeStats>> {
+        let client = self.blocking_client(py)?;
+        catch_panic_sync("Client.get_cluster_stats", || {
+            py.detach(|| {
+                Ok(self
+                    .runtime
+                    .block_on(client_ops::do_get_cluster_stats(client, &self.breaker)))
+            })
+        })
+    }
+
     // ── Info ─────────────────────────────────────────────────────
 
     /// Send an info command to all nodes in the cluster.
@@ -201,9 +442,12 @@ impl PyClient {
         command: &str,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Vec<(String, i32, String)>> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args = client_common::prepare_info_args(command, policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_info_all(client, &args)))
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_info_all(client, &args))
+        })
     }
 
     /// Send an info command to a random node in the cluster.
@@ -215,13 +459,80 @@ impl PyClient {
         command: &str,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<String> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args = client_common::prepare_info_args(command, policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_info_random_node(client, &args)))
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_info_random_node(client, &args))
+        })
     }
 
-    /// Write a record
-    #[pyo3(signature = (key, bins, meta=None, policy=None))]
+    /// Send an info command to one node, chosen by node name or `host:port`.
+    #[pyo3(signature = (command, node, policy=None))]
+    fn info_single_node(
+        &self,
+        py: Python<'_>,
+        command: &str,
+        node: &str,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<String> {
+        let client = self.blocking_client(py)?;
+        let args = client_common::prepare_info_args(command, policy)?;
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_info_single_node(client, &args, node))
+        })
+    }
+
+    /// List namespaces (`info("namespaces")`).
+    #[pyo3(signature = (node=None, policy=None))]
+    fn info_namespaces(
+        &self,
+        py: Python<'_>,
+        node: Option<&str>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<String>> {
+        let resp = self.info_text(py, "namespaces", node, policy)?;
+        Ok(info_parsers::parse_list(info_parsers::check_response(
+            &resp,
+        )?))
+    }
+
+    /// List sets of a namespace with their statistics (`info("sets/<ns>")`).
+    #[pyo3(signature = (namespace, node=None, policy=None))]
+    fn info_sets(
+        &self,
+        py: Python<'_>,
+        namespace: &str,
+        node: Option<&str>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<info_parsers::InfoRecord>> {
+        let resp = self.info_text(py, &format!("sets/{namespace}"), node, policy)?;
+        Ok(info_parsers::parse_records(
+            info_parsers::check_response(&resp)?,
+            ':',
+        ))
+    }
+
+    /// List secondary indexes, optionally of one namespace (`info("sindex-list")`).
+    #[pyo3(signature = (namespace=None, node=None, policy=None))]
+    fn info_sindex(
+        &self,
+        py: Python<'_>,
+        namespace: Option<&str>,
+        node: Option<&str>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<info_parsers::InfoRecord>> {
+        let command = client_common::sindex_list_command(namespace);
+        let resp = self.info_text(py, &command, node, policy)?;
+        Ok(info_parsers::parse_records(
+            info_parsers::check_response(&resp)?,
+            ':',
+        ))
+    }
+
+    /// Write a record; with `return_meta`, return its meta after the write
+    #[pyo3(signature = (key, bins, meta=None, policy=None, return_meta=false))]
     fn put(
         &self,
         py: Python<'_>,
@@ -229,20 +540,28 @@ impl PyClient {
         bins: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
-    ) -> PyResult<()> {
-        let args =
+        return_meta: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let mut args =
             client_common::prepare_put_args(py, key, bins, meta, policy, &self.connection_info)?;
-        let client = self.get_client()?;
+        args.return_meta = return_meta;
+        self.bin_limits.check(&args.bins)?;
+        let client = self.blocking_client(py)?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         debug!("put: ns={} set={}", args.key.namespace, args.key.set_name);
-        catch_panic_sync("Client.put", || {
+        let record = catch_panic_sync("Client.put", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.compression.compress_bins(&mut args.bins);
+                self.cipher.encrypt_bins(&mut args.bins)?;
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("put").await?;
-                    client_ops::do_put(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_put(client, args).await)
                 })
             })
-        })
+        })?;
+        Ok(PendingMeta(record).into_pyobject(py)?.unbind())
     }
 
     /// Read a record
@@ -253,20 +572,25 @@ impl PyClient {
         key: &Bound<'_, PyAny>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let args = client_common::prepare_get_args(py, key, policy, &self.connection_info)?;
         debug!("get: ns={} set={}", args.key.namespace, args.key.set_name);
         let key_py = key_to_py(py, &args.key)?;
         let record = catch_panic_sync("Client.get", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("get").await?;
-                    client_ops::do_get(client, &args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    let mut record = circuit.record(client_ops::do_get(client, &args).await)?;
+                    self.cipher.decrypt_record(&mut record)?;
+                    self.compression.decompress_record(&mut record);
+                    Ok(record)
                 })
             })
         })?;
-        record_to_py_with_key(py, &record, key_py)
+        record_into_py_with_key(py, record, key_py)
     }
 
     /// Read specific bins of a record
@@ -278,7 +602,7 @@ impl PyClient {
         bins: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args =
             client_common::prepare_select_args(py, key, bins, policy, &self.connection_info)?;
         debug!(
@@ -287,15 +611,87 @@ impl PyClient {
         );
         let key_py = key_to_py(py, &args.key)?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let record = catch_panic_sync("Client.select", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("select").await?;
-                    client_ops::do_select(client, &args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    let mut record = circuit.record(client_ops::do_select(client, &args).await)?;
+                    self.cipher.decrypt_record(&mut record)?;
+                    self.compression.decompress_record(&mut record);
+                    Ok(record)
+                })
+            })
+        })?;
+        record_into_py_with_key(py, record, key_py)
+    }
+
+    /// Read a record with bins as `(particle_type, payload)` wire-format pairs
+    #[pyo3(signature = (key, policy=None))]
+    fn get_raw(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        let client = self.blocking_client(py)?;
+        let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        let args = client_common::prepare_get_args(py, key, policy, &self.connection_info)?;
+        debug!(
+            "get_raw: ns={} set={}",
+            args.key.namespace, args.key.set_name
+        );
+        let key_py = key_to_py(py, &args.key)?;
+        let record = catch_panic_sync("Client.get_raw", || {
+            py.detach(|| {
+                self.runtime.block_on(async {
+                    let _permit = limiter.acquire_named("get_raw").await?;
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_get(client, &args).await)
                 })
             })
         })?;
-        record_to_py_with_key(py, &record, key_py)
+        raw_record_to_py_with_key(py, &record, key_py)
+    }
+
+    /// Write a record from `(particle_type, payload)` wire-format bins
+    #[pyo3(signature = (key, bins, meta=None, policy=None))]
+    fn put_raw(
+        &self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        bins: &Bound<'_, PyDict>,
+        meta: Option<&Bound<'_, PyDict>>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let args = client_common::prepare_put_raw_args(
+            py,
+            key,
+            bins,
+            meta,
+            policy,
+            &self.connection_info,
+        )?;
+        self.bin_limits.check(&args.bins)?;
+        let client = self.blocking_client(py)?;
+        let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
+        debug!(
+            "put_raw: ns={} set={}",
+            args.key.namespace, args.key.set_name
+        );
+        catch_panic_sync("Client.put_raw", || {
+            py.detach(|| {
+                self.runtime.block_on(async {
+                    let _permit = limiter.acquire_named("put_raw").await?;
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_put(client, args).await)
+                })
+            })
+        })
+        .map(|_| ())
     }
 
     /// Check if a record exists. Returns (key, meta) or (key, None)
@@ -306,7 +702,7 @@ impl PyClient {
         key: &Bound<'_, PyAny>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let args = client_common::prepare_exists_args(py, key, policy, &self.connection_info)?;
         debug!(
             "exists: ns={} set={}",
@@ -314,11 +710,15 @@ impl PyClient {
         );
         let key_py = key_to_py(py, &args.key)?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let result = catch_panic_sync("Client.exists", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("exists").await?;
-                    Ok::<_, pyo3::PyErr>(client_ops::do_exists(&client, &args).await)
+                    let circuit = breaker.check(&client, &args.key)?;
+                    Ok::<_, pyo3::PyErr>(
+                        circuit.record(client_ops::do_exists(&client, &args).await),
+                    )
                 })
             })
         })?;
@@ -346,7 +746,7 @@ impl PyClient {
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args =
             client_common::prepare_remove_args(py, key, meta, policy, &self.connection_info)?;
         debug!(
@@ -354,11 +754,13 @@ impl PyClient {
             args.key.namespace, args.key.set_name
         );
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         catch_panic_sync("Client.remove", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("remove").await?;
-                    client_ops::do_remove(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_remove(client, args).await)
                 })
             })
         })
@@ -374,23 +776,26 @@ impl PyClient {
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args =
             client_common::prepare_touch_args(py, key, val, meta, policy, &self.connection_info)?;
         debug!("touch: ns={} set={}", args.key.namespace, args.key.set_name);
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         catch_panic_sync("Client.touch", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("touch").await?;
-                    client_ops::do_touch(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_touch(client, args).await)
                 })
             })
         })
     }
 
-    /// Append a string to a bin
-    #[pyo3(signature = (key, bin, val, meta=None, policy=None))]
+    /// Append a string to a bin; with `return_value`, return the bin's new value
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, bin, val, meta=None, policy=None, return_value=false))]
     fn append(
         &self,
         py: Python<'_>,
@@ -399,9 +804,10 @@ impl PyClient {
         val: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
-    ) -> PyResult<()> {
-        let client = self.get_client()?;
-        let args = client_common::prepare_single_bin_write_args(
+        return_value: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let client = self.blocking_client(py)?;
+        let mut args = client_common::prepare_single_bin_write_args(
             py,
             key,
             bin,
@@ -410,23 +816,28 @@ impl PyClient {
             policy,
             &self.connection_info,
         )?;
+        args.return_value = return_value;
         debug!(
             "append: ns={} set={} bin={}",
             args.key.namespace, args.key.set_name, bin
         );
         let limiter = self.limiter.clone();
-        catch_panic_sync("Client.append", || {
+        let breaker = self.breaker.clone();
+        let value = catch_panic_sync("Client.append", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("append").await?;
-                    client_ops::do_append(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_append(client, args).await)
                 })
             })
-        })
+        })?;
+        Ok(PendingValue(value).into_pyobject(py)?.unbind())
     }
 
-    /// Prepend a string to a bin
-    #[pyo3(signature = (key, bin, val, meta=None, policy=None))]
+    /// Prepend a string to a bin; with `return_value`, return the bin's new value
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, bin, val, meta=None, policy=None, return_value=false))]
     fn prepend(
         &self,
         py: Python<'_>,
@@ -435,9 +846,10 @@ impl PyClient {
         val: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
-    ) -> PyResult<()> {
-        let client = self.get_client()?;
-        let args = client_common::prepare_single_bin_write_args(
+        return_value: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let client = self.blocking_client(py)?;
+        let mut args = client_common::prepare_single_bin_write_args(
             py,
             key,
             bin,
@@ -446,23 +858,28 @@ impl PyClient {
             policy,
             &self.connection_info,
         )?;
+        args.return_value = return_value;
         debug!(
             "prepend: ns={} set={} bin={}",
             args.key.namespace, args.key.set_name, bin
         );
         let limiter = self.limiter.clone();
-        catch_panic_sync("Client.prepend", || {
+        let breaker = self.breaker.clone();
+        let value = catch_panic_sync("Client.prepend", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("prepend").await?;
-                    client_ops::do_prepend(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_prepend(client, args).await)
                 })
             })
-        })
+        })?;
+        Ok(PendingValue(value).into_pyobject(py)?.unbind())
     }
 
-    /// Increment an integer bin
-    #[pyo3(signature = (key, bin, offset, meta=None, policy=None))]
+    /// Increment an integer bin; with `return_value`, return the bin's new value
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (key, bin, offset, meta=None, policy=None, return_value=false))]
     fn increment(
         &self,
         py: Python<'_>,
@@ -471,9 +888,10 @@ impl PyClient {
         offset: &Bound<'_, PyAny>,
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
-    ) -> PyResult<()> {
-        let client = self.get_client()?;
-        let args = client_common::prepare_increment_args(
+        return_value: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let client = self.blocking_client(py)?;
+        let mut args = client_common::prepare_increment_args(
             py,
             key,
             bin,
@@ -482,19 +900,23 @@ impl PyClient {
             policy,
             &self.connection_info,
         )?;
+        args.return_value = return_value;
         debug!(
             "increment: ns={} set={} bin={}",
             args.key.namespace, args.key.set_name, bin
         );
         let limiter = self.limiter.clone();
-        catch_panic_sync("Client.increment", || {
+        let breaker = self.breaker.clone();
+        let value = catch_panic_sync("Client.increment", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("increment").await?;
-                    client_ops::do_increment(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_increment(client, args).await)
                 })
             })
-        })
+        })?;
+        Ok(PendingValue(value).into_pyobject(py)?.unbind())
     }
 
     /// Remove bins from a record by setting them to nil
@@ -507,7 +929,7 @@ impl PyClient {
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args = client_common::prepare_remove_bin_args(
             py,
             key,
@@ -517,11 +939,13 @@ impl PyClient {
             &self.connection_info,
         )?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         catch_panic_sync("Client.remove_bin", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("remove_bin").await?;
-                    client_ops::do_remove_bin(client, args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    circuit.record(client_ops::do_remove_bin(client, args).await)
                 })
             })
         })
@@ -537,7 +961,7 @@ impl PyClient {
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args =
             client_common::prepare_operate_args(py, key, ops, meta, policy, &self.connection_info)?;
         debug!(
@@ -548,15 +972,20 @@ impl PyClient {
         );
         let key_py = key_to_py(py, &args.key)?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let record = catch_panic_sync("Client.operate", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("operate").await?;
-                    client_ops::do_operate(client, &args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    let mut record = circuit.record(client_ops::do_operate(client, &args).await)?;
+                    self.cipher.decrypt_record(&mut record)?;
+                    self.compression.decompress_record(&mut record);
+                    Ok(record)
                 })
             })
         })?;
-        record_to_py_with_key(py, &record, key_py)
+        record_into_py_with_key(py, record, key_py)
     }
 
     /// Perform multiple operations on a single record, returning ordered results
@@ -569,7 +998,7 @@ impl PyClient {
         meta: Option<&Bound<'_, PyDict>>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?;
+        let client = self.blocking_client(py)?;
         let args =
             client_common::prepare_operate_args(py, key, ops, meta, policy, &self.connection_info)?;
         debug!(
@@ -578,22 +1007,23 @@ impl PyClient {
             args.key.set_name,
             args.ops.len()
         );
-        let pre_key_py = key_to_py(py, &args.key)?;
+        let key_py = key_to_py(py, &args.key)?;
         let limiter = self.limiter.clone();
+        let breaker = self.breaker.clone();
         let record = catch_panic_sync("Client.operate_ordered", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("operate_ordered").await?;
-                    client_ops::do_operate_ordered(client, &args).await
+                    let circuit = breaker.check(client, &args.key)?;
+                    let mut record =
+                        circuit.record(client_ops::do_operate_ordered(client, &args).await)?;
+                    self.cipher.decrypt_record(&mut record)?;
+                    self.compression.decompress_record(&mut record);
+                    Ok(record)
                 })
             })
         })?;
 
-        let key_py = match &record.key {
-            Some(k) => key_to_py(py, k)?,
-            None => pre_key_py,
-        };
-
         let meta_dict_obj = record_to_meta(py, &record)?;
 
         let bin_items: Vec<Py<PyAny>> = record
@@ -622,19 +1052,56 @@ impl PyClient {
     // ── Query / Index ─────────────────────────────────────
 
     /// Create a Query object for the given namespace and set.
-    fn query(&self, namespace: &str, set_name: &str) -> PyResult<crate::query::PyQuery> {
+    fn query(
+        &self,
+        namespace: &str,
+        set_name: &Bound<'_, PyAny>,
+    ) -> PyResult<crate::query::PyQuery> {
+        crate::types::key::validate_namespace(namespace)?;
+        let set_name = crate::types::key::py_to_set_name(set_name)?;
         debug!("Creating query: ns={} set={}", namespace, set_name);
-        let client = self.get_client()?.clone();
         Ok(crate::query::PyQuery::new(
-            client,
             namespace.to_string(),
-            set_name.to_string(),
+            set_name,
+            Some(self.query_binding()?),
+        ))
+    }
+
+    /// Bind a copy of a `Query` (e.g. one built with `Query(...)`) to this client.
+    fn bind_query(
+        &self,
+        query: PyRef<'_, crate::query::PyQuery>,
+    ) -> PyResult<crate::query::PyQuery> {
+        Ok(query.bind(self.query_binding()?))
+    }
+
+    /// Create a Pipeline that runs queued operations with bounded concurrency.
+    #[pyo3(signature = (max_concurrency=crate::pipeline::DEFAULT_PIPELINE_CONCURRENCY))]
+    fn pipeline(&self, max_concurrency: usize) -> PyResult<crate::pipeline::PyPipeline> {
+        if max_concurrency == 0 {
+            return Err(crate::errors::ParamError::new_err(
+                "max_concurrency must be at least 1",
+            ));
+        }
+        debug!("Creating pipeline: max_concurrency={}", max_concurrency);
+        let client = self.get_client()?.clone();
+        Ok(crate::pipeline::PyPipeline::new(
+            client,
+            self.limiter.clone(),
+            self.breaker.clone(),
+            self.compression.clone(),
+            self.bin_limits.clone(),
+            self.cipher.clone(),
+            self.runtime.clone(),
             self.connection_info.clone(),
+            max_concurrency,
+            self.event_loop_guard,
         ))
     }
 
     /// Create a secondary integer index.
-    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None, wait=true))]
     fn index_integer_create(
         &self,
         py: Python<'_>,
@@ -643,6 +1110,7 @@ impl PyClient {
         bin_name: &str,
         index_name: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<()> {
         self.create_index(
             py,
@@ -652,11 +1120,13 @@ impl PyClient {
             index_name,
             aerospike_core::IndexType::Numeric,
             policy,
+            wait,
         )
     }
 
     /// Create a secondary string index.
-    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None, wait=true))]
     fn index_string_create(
         &self,
         py: Python<'_>,
@@ -665,6 +1135,7 @@ impl PyClient {
         bin_name: &str,
         index_name: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<()> {
         self.create_index(
             py,
@@ -674,11 +1145,13 @@ impl PyClient {
             index_name,
             aerospike_core::IndexType::String,
             policy,
+            wait,
         )
     }
 
     /// Create a secondary geo2dsphere index.
-    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, bin_name, index_name, policy=None, wait=true))]
     fn index_geo2dsphere_create(
         &self,
         py: Python<'_>,
@@ -687,6 +1160,7 @@ impl PyClient {
         bin_name: &str,
         index_name: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<()> {
         self.create_index(
             py,
@@ -696,9 +1170,48 @@ impl PyClient {
             index_name,
             aerospike_core::IndexType::Geo2DSphere,
             policy,
+            wait,
         )
     }
 
+    /// Build progress of a secondary index in percent (lowest across nodes).
+    #[pyo3(signature = (namespace, index_name, policy=None))]
+    fn index_status(
+        &self,
+        py: Python<'_>,
+        namespace: &str,
+        index_name: &str,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<u32> {
+        let client = self.blocking_client(py)?.clone();
+        let admin_policy = client_common::prepare_admin_policy(policy)?;
+        py.detach(|| {
+            self.runtime.block_on(client_ops::do_index_status(
+                &client,
+                &admin_policy,
+                namespace,
+                index_name,
+            ))
+        })
+    }
+
+    /// State of a scan / query job across the cluster.
+    #[pyo3(signature = (job_id, module, policy=None))]
+    fn job_info(
+        &self,
+        py: Python<'_>,
+        job_id: u64,
+        module: &str,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<client_common::JobInfo> {
+        let client = self.blocking_client(py)?.clone();
+        let args = client_common::prepare_job_info_args(job_id, module, policy)?;
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_job_info(&client, &args))
+        })
+    }
+
     /// Remove a secondary index.
     #[pyo3(signature = (namespace, index_name, policy=None))]
     fn index_remove(
@@ -709,15 +1222,19 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Removing index: ns={} index={}", namespace, index_name);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let args = client_common::prepare_index_remove_args(namespace, index_name, policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_index_remove(&client, args)))
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_index_remove(&client, args))
+        })
     }
 
     // ── Truncate ──────────────────────────────────────────────────
 
     /// Remove records in specified namespace/set efficiently.
-    #[pyo3(signature = (namespace, set_name, nanos=0, policy=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (namespace, set_name, nanos=0, policy=None, wait=false, timeout=None))]
     fn truncate(
         &self,
         py: Python<'_>,
@@ -725,42 +1242,122 @@ impl PyClient {
         set_name: &str,
         nanos: i64,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
+        timeout: Option<f64>,
     ) -> PyResult<()> {
         warn!("Truncating: ns={} set={}", namespace, set_name);
-        let client = self.get_client()?.clone();
-        let args = client_common::prepare_truncate_args(namespace, set_name, nanos, policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_truncate(&client, args)))
+        let client = self.blocking_client(py)?.clone();
+        let args = client_common::prepare_truncate_args(
+            namespace, set_name, nanos, policy, wait, timeout,
+        )?;
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_truncate(&client, args))
+        })
+    }
+
+    /// Whether a truncate of `namespace`/`set_name` has finished on every
+    /// node. Polled by `TruncateTask`.
+    #[pyo3(signature = (namespace, set_name, nanos=0, policy=None))]
+    fn _truncate_done(
+        &self,
+        py: Python<'_>,
+        namespace: &str,
+        set_name: &str,
+        nanos: i64,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<bool> {
+        let client = self.blocking_client(py)?.clone();
+        let args =
+            client_common::prepare_truncate_args(namespace, set_name, nanos, policy, false, None)?;
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_truncate_done(&client, &args))
+        })
     }
 
     // ── UDF ───────────────────────────────────────────────────────
 
     /// Register a UDF module from a file.
-    #[pyo3(signature = (filename, udf_type=0, policy=None))]
+    #[pyo3(signature = (filename, udf_type=0, policy=None, wait=true))]
     fn udf_put(
         &self,
         py: Python<'_>,
         filename: &str,
         udf_type: u8,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<()> {
         info!("Registering UDF: filename={}", filename);
-        let client = self.get_client()?.clone();
-        let args = client_common::prepare_udf_put_args(filename, udf_type, policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_udf_put(&client, args)))
+        let client = self.blocking_client(py)?.clone();
+        let args = client_common::prepare_udf_put_args(filename, udf_type, policy, wait)?;
+        py.detach(|| self.runtime.block_on(client_ops::do_udf_put(&client, args)))
     }
 
     /// Remove a UDF module.
-    #[pyo3(signature = (module, policy=None))]
+    #[pyo3(signature = (module, policy=None, wait=true))]
     fn udf_remove(
         &self,
         py: Python<'_>,
         module: &str,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<()> {
         info!("Removing UDF: module={}", module);
-        let client = self.get_client()?.clone();
-        let args = client_common::prepare_udf_remove_args(module, policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_udf_remove(&client, args)))
+        let client = self.blocking_client(py)?.clone();
+        let args = client_common::prepare_udf_remove_args(module, policy, wait)?;
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_udf_remove(&client, args))
+        })
+    }
+
+    /// Whether a `udf_put()` (or, with `removed`, a `udf_remove()`) of
+    /// `module` has reached every node. Polled by `UdfTask`.
+    #[pyo3(signature = (module, removed=false, policy=None))]
+    fn _udf_done(
+        &self,
+        py: Python<'_>,
+        module: &str,
+        removed: bool,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<bool> {
+        let client = self.blocking_client(py)?.clone();
+        let args = client_common::prepare_udf_remove_args(module, policy, false)?;
+        py.detach(|| {
+            self.runtime.block_on(client_ops::do_udf_done(
+                &client,
+                &args.admin_policy,
+                &args.server_path,
+                removed,
+            ))
+        })
+    }
+
+    /// List registered UDF modules (`info("udf-list")`).
+    #[pyo3(signature = (policy=None))]
+    fn udf_list(
+        &self,
+        py: Python<'_>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<info_parsers::InfoRecord>> {
+        let resp = self.info_text(py, "udf-list", None, policy)?;
+        Ok(info_parsers::parse_udf_list(info_parsers::check_response(
+            &resp,
+        )?))
+    }
+
+    /// Return the Lua source of a registered UDF module (`info("udf-get")`).
+    #[pyo3(signature = (module, policy=None))]
+    fn udf_get(
+        &self,
+        py: Python<'_>,
+        module: &str,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<String> {
+        let module = client_common::udf_server_path(module);
+        let resp = self.info_text(py, &format!("udf-get:filename={module}"), None, policy)?;
+        info_parsers::parse_udf_get(&resp, &module)
     }
 
     /// Execute a UDF on a single record.
@@ -774,14 +1371,14 @@ impl PyClient {
         args: Option<&Bound<'_, PyList>>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let a = client_common::prepare_apply_args(key, module, function, args, policy)?;
         debug!(
             "apply UDF: ns={} set={} module={} function={}",
             a.key.namespace, a.key.set_name, a.module, a.function
         );
         let result = catch_panic_sync("Client.apply", || {
-            py.detach(|| RUNTIME.block_on(client_ops::do_apply(&client, &a)))
+            py.detach(|| self.runtime.block_on(client_ops::do_apply(&client, &a)))
         })?;
         client_common::batch_udf_value_to_py(py, result.as_ref())
     }
@@ -799,10 +1396,10 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Creating user: username={}", username);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_create_user(
+            self.runtime.block_on(client_ops::do_admin_create_user(
                 &client,
                 &admin_policy,
                 username,
@@ -821,10 +1418,10 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Dropping user: username={}", username);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_drop_user(
+            self.runtime.block_on(client_ops::do_admin_drop_user(
                 &client,
                 &admin_policy,
                 username,
@@ -842,10 +1439,10 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Changing password for user: username={}", username);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_change_password(
+            self.runtime.block_on(client_ops::do_admin_change_password(
                 &client,
                 &admin_policy,
                 username,
@@ -864,10 +1461,10 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Granting roles to user: username={}", username);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_grant_roles(
+            self.runtime.block_on(client_ops::do_admin_grant_roles(
                 &client,
                 &admin_policy,
                 username,
@@ -886,10 +1483,10 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Revoking roles from user: username={}", username);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_revoke_roles(
+            self.runtime.block_on(client_ops::do_admin_revoke_roles(
                 &client,
                 &admin_policy,
                 username,
@@ -906,11 +1503,11 @@ impl PyClient {
         username: &str,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         let username = username.to_string();
         let users = py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_query_users(
+            self.runtime.block_on(client_ops::do_admin_query_users(
                 &client,
                 &admin_policy,
                 Some(&username),
@@ -934,10 +1531,10 @@ impl PyClient {
         py: Python<'_>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         let users = py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_query_users(
+            self.runtime.block_on(client_ops::do_admin_query_users(
                 &client,
                 &admin_policy,
                 None,
@@ -951,6 +1548,22 @@ impl PyClient {
         Ok(list.into_any().unbind())
     }
 
+    /// Query info about the user this client is authenticated as.
+    #[pyo3(signature = (policy=None))]
+    fn admin_whoami(
+        &self,
+        py: Python<'_>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        let client = self.blocking_client(py)?.clone();
+        let admin_policy = client_common::prepare_admin_policy(policy)?;
+        let user = py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_admin_whoami(&client, &admin_policy))
+        })?;
+        user_to_py(py, &user)
+    }
+
     /// Create a new role with the given privileges.
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (role, privileges, policy=None, whitelist=None, read_quota=0, write_quota=0))]
@@ -965,7 +1578,7 @@ impl PyClient {
         write_quota: u32,
     ) -> PyResult<()> {
         info!("Creating role: role={}", role);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let args = client_common::prepare_create_role_args(
             role,
             privileges,
@@ -974,7 +1587,10 @@ impl PyClient {
             read_quota,
             write_quota,
         )?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_admin_create_role(&client, args)))
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_admin_create_role(&client, args))
+        })
     }
 
     /// Drop (delete) a role.
@@ -986,9 +1602,12 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
         info!("Dropping role: role={}", role);
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_admin_drop_role(&client, &admin_policy, role)))
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_admin_drop_role(&client, &admin_policy, role))
+        })
     }
 
     /// Grant privileges to a role.
@@ -1000,11 +1619,11 @@ impl PyClient {
         privileges: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         let rust_privileges = parse_privileges(privileges)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_grant_privileges(
+            self.runtime.block_on(client_ops::do_admin_grant_privileges(
                 &client,
                 &admin_policy,
                 role,
@@ -1022,16 +1641,17 @@ impl PyClient {
         privileges: &Bound<'_, PyList>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         let rust_privileges = parse_privileges(privileges)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_revoke_privileges(
-                &client,
-                &admin_policy,
-                role,
-                &rust_privileges,
-            ))
+            self.runtime
+                .block_on(client_ops::do_admin_revoke_privileges(
+                    &client,
+                    &admin_policy,
+                    role,
+                    &rust_privileges,
+                ))
         })
     }
 
@@ -1043,11 +1663,11 @@ impl PyClient {
         role: &str,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         let role_name = role.to_string();
         let roles = py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_query_roles(
+            self.runtime.block_on(client_ops::do_admin_query_roles(
                 &client,
                 &admin_policy,
                 Some(&role_name),
@@ -1071,10 +1691,10 @@ impl PyClient {
         py: Python<'_>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         let roles = py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_query_roles(
+            self.runtime.block_on(client_ops::do_admin_query_roles(
                 &client,
                 &admin_policy,
                 None,
@@ -1097,10 +1717,10 @@ impl PyClient {
         whitelist: Vec<String>,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_set_whitelist(
+            self.runtime.block_on(client_ops::do_admin_set_whitelist(
                 &client,
                 &admin_policy,
                 role,
@@ -1119,10 +1739,10 @@ impl PyClient {
         write_quota: u32,
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let admin_policy = client_common::prepare_admin_policy(policy)?;
         py.detach(|| {
-            RUNTIME.block_on(client_ops::do_admin_set_quotas(
+            self.runtime.block_on(client_ops::do_admin_set_quotas(
                 &client,
                 &admin_policy,
                 role,
@@ -1134,8 +1754,10 @@ impl PyClient {
 
     // ── Batch operations ──────────────────────────────────────────
 
-    /// Read multiple records. Returns BatchRecords, or NumpyBatchRecords when dtype is provided.
-    #[pyo3(signature = (keys, bins=None, policy=None, _dtype=None))]
+    /// Read multiple records. Returns BatchRecords, NumpyBatchRecords when dtype is provided,
+    /// or a `pyarrow.RecordBatch` when `output="arrow"`.
+    #[pyo3(signature = (keys, bins=None, policy=None, _dtype=None, _null_mask=false, output=None))]
+    #[allow(clippy::too_many_arguments)]
     fn batch_read(
         &self,
         py: Python<'_>,
@@ -1143,23 +1765,35 @@ impl PyClient {
         bins: Option<Vec<String>>,
         policy: Option<&Bound<'_, PyDict>>,
         _dtype: Option<&Bound<'_, PyAny>>,
+        _null_mask: bool,
+        output: Option<&str>,
     ) -> PyResult<Py<PyAny>> {
         debug!("batch_read: keys_count={}", keys.len());
-        let client = self.get_client()?.clone();
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
+        client_common::check_batch_read_null_mask(_dtype, _null_mask)?;
+        let use_arrow = client_common::parse_batch_read_output(output, _dtype)?;
         let args =
             client_common::prepare_batch_read_args(py, keys, &bins, policy, &self.connection_info)?;
         let limiter = self.limiter.clone();
         let results = catch_panic_sync("Client.batch_read", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("batch_read").await?;
-                    client_ops::do_batch_read(&client, &args).await
+                    let mut results = client_ops::do_batch_read(&client, &args).await?;
+                    self.cipher.decrypt_batch(&mut results)?;
+                    self.compression.decompress_batch(&mut results);
+                    Ok(results)
                 })
             })
         })?;
+        check_partial_results(&results, allow_partial)?;
 
+        if use_arrow {
+            return crate::arrow_support::batch_to_arrow_py(py, &results, bins.as_deref());
+        }
         match _dtype {
-            Some(d) => crate::numpy_support::batch_to_numpy_py(py, &results, d),
+            Some(d) => crate::numpy_support::batch_to_numpy_py(py, &results, d, _null_mask),
             None => {
                 let dict = batch_to_dict_py(py, &results)?;
                 Ok(dict.unbind().into_any())
@@ -1167,6 +1801,79 @@ impl PyClient {
         }
     }
 
+    /// Read multiple records into DataFrame column buffers.
+    ///
+    /// Returns `(index, columns)`; the Python wrapper builds the `pandas.DataFrame`.
+    #[pyo3(signature = (keys, columns=None, policy=None))]
+    fn batch_read_df(
+        &self,
+        py: Python<'_>,
+        keys: &Bound<'_, PyList>,
+        columns: Option<Vec<String>>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        debug!("batch_read_df: keys_count={}", keys.len());
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
+        let args = client_common::prepare_batch_read_args(
+            py,
+            keys,
+            &columns,
+            policy,
+            &self.connection_info,
+        )?;
+        let limiter = self.limiter.clone();
+        let results = catch_panic_sync("Client.batch_read_df", || {
+            py.detach(|| {
+                self.runtime.block_on(async {
+                    let _permit = limiter.acquire_named("batch_read").await?;
+                    let mut results = client_ops::do_batch_read(&client, &args).await?;
+                    self.cipher.decrypt_batch(&mut results)?;
+                    self.compression.decompress_batch(&mut results);
+                    Ok(results)
+                })
+            })
+        })?;
+        check_partial_results(&results, allow_partial)?;
+
+        crate::pandas_support::batch_to_columns_py(py, &results, columns.as_deref())
+    }
+
+    /// Check existence of multiple records with a header-only batch read.
+    ///
+    /// Returns `list[(key, meta | None)]`, or `(found, meta)` NumPy arrays
+    /// when a boolean `dtype` is provided.
+    #[pyo3(signature = (keys, policy=None, dtype=None))]
+    fn batch_exists(
+        &self,
+        py: Python<'_>,
+        keys: &Bound<'_, PyList>,
+        policy: Option<&Bound<'_, PyDict>>,
+        dtype: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        debug!("batch_exists: keys_count={}", keys.len());
+        let client = self.blocking_client(py)?.clone();
+        if let Some(d) = dtype {
+            crate::numpy_support::check_exists_dtype(py, d)?;
+        }
+        let args =
+            client_common::prepare_batch_exists_args(py, keys, policy, &self.connection_info)?;
+        let limiter = self.limiter.clone();
+        let results = catch_panic_sync("Client.batch_exists", || {
+            py.detach(|| {
+                self.runtime.block_on(async {
+                    let _permit = limiter.acquire_named("batch_exists").await?;
+                    client_ops::do_batch_exists(&client, &args).await
+                })
+            })
+        })?;
+
+        match dtype {
+            Some(_) => crate::numpy_support::batch_exists_to_numpy_py(py, &results),
+            None => Ok(batch_exists_to_list_py(py, &results)?.unbind().into_any()),
+        }
+    }
+
     /// Perform operations on multiple records. Returns list of (key, meta, bins) tuples.
     #[pyo3(signature = (keys, ops, policy=None))]
     fn batch_operate(
@@ -1177,7 +1884,8 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
         debug!("batch_operate: keys_count={}", keys.len());
-        let client = self.get_client()?.clone();
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
         let args = client_common::prepare_batch_operate_args(
             py,
             keys,
@@ -1188,13 +1896,16 @@ impl PyClient {
         let limiter = self.limiter.clone();
         let results = catch_panic_sync("Client.batch_operate", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("batch_operate").await?;
-                    client_ops::do_batch_operate(&client, &args).await
+                    let mut results = client_ops::do_batch_operate(&client, &args).await?;
+                    self.cipher.decrypt_batch(&mut results)?;
+                    self.compression.decompress_batch(&mut results);
+                    Ok(results)
                 })
             })
         })?;
-        let batch = batch_to_batch_records_py(py, results)?;
+        let batch = batch_to_batch_records_py(py, results, allow_partial)?;
         Ok(Py::new(py, batch)?.into_any())
     }
 
@@ -1212,18 +1923,26 @@ impl PyClient {
         retry: u32,
     ) -> PyResult<Py<PyAny>> {
         debug!("batch_write: records_count={}", records.len());
-        let client = self.get_client()?.clone();
-        let args = client_common::prepare_batch_write_args(
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
+        let mut args = client_common::prepare_batch_write_args(
             py,
             records,
             policy,
             retry,
             &self.connection_info,
         )?;
+        for (_, bins, _) in &args.records {
+            self.bin_limits.check(bins)?;
+        }
         let limiter = self.limiter.clone();
         let results = catch_panic_sync("Client.batch_write", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                for (_, bins, _) in &mut args.records {
+                    self.compression.compress_bins(bins);
+                    self.cipher.encrypt_bins(bins)?;
+                }
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("batch_write").await?;
                     client_ops::do_batch_write(
                         &client,
@@ -1240,7 +1959,7 @@ impl PyClient {
                 })
             })
         })?;
-        let batch = batch_to_batch_records_py(py, results)?;
+        let batch = batch_to_batch_records_py(py, results, allow_partial)?;
         Ok(Py::new(py, batch)?.into_any())
     }
 
@@ -1266,7 +1985,8 @@ impl PyClient {
             "batch_write_numpy: namespace={}, set={}, retry={}",
             namespace, set_name, retry
         );
-        let client = self.get_client()?.clone();
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
         let batch_policy = crate::policy::batch_policy::parse_batch_policy(policy)?;
         #[allow(clippy::let_unit_value)]
         let parent_ctx = client_common::extract_parent_context(py);
@@ -1292,7 +2012,7 @@ impl PyClient {
         let limiter = self.limiter.clone();
         let results = catch_panic_sync("Client.batch_write_numpy", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("batch_write_numpy").await?;
                     client_ops::do_batch_write(
                         &client,
@@ -1310,10 +2030,69 @@ impl PyClient {
             })
         })?;
 
-        let batch = batch_to_batch_records_py(py, results)?;
+        let batch = batch_to_batch_records_py(py, results, allow_partial)?;
         Ok(Py::new(py, batch)?.into_any())
     }
 
+    /// Write records from an iterable of bins dicts or `(key, bins)` tuples.
+    ///
+    /// The iterable is read one wave of `concurrency` chunks at a time; each
+    /// chunk of `chunk_size` records is written as one batch, with the wave's
+    /// batches in flight together. Per-record failures are counted in the
+    /// returned summary instead of raised.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (records, namespace, set_name, key_fn=None, policy=None, chunk_size=client_ops::DEFAULT_BATCH_CHUNK_SIZE, concurrency=client_ops::DEFAULT_BATCH_CHUNK_CONCURRENCY))]
+    fn bulk_put(
+        &self,
+        py: Python<'_>,
+        records: &Bound<'_, PyAny>,
+        namespace: &str,
+        set_name: &str,
+        key_fn: Option<&Bound<'_, PyAny>>,
+        policy: Option<&Bound<'_, PyDict>>,
+        chunk_size: usize,
+        concurrency: usize,
+    ) -> PyResult<client_common::BulkPutResult> {
+        debug!(
+            "bulk_put: namespace={} set={} chunk_size={} concurrency={}",
+            namespace, set_name, chunk_size, concurrency
+        );
+        let args = client_common::prepare_bulk_put_args(
+            py,
+            namespace,
+            set_name,
+            key_fn,
+            policy,
+            chunk_size,
+            concurrency,
+            &self.connection_info,
+        )?;
+        let client = self.blocking_client(py)?.clone();
+        let mut reader = client_common::BulkPutReader::new(records)?;
+        let mut summary = client_common::BulkPutResult::default();
+        while !reader.is_exhausted() {
+            let chunks = reader.next_chunks(py, &args, concurrency, &mut summary, |bins| {
+                self.bin_limits.check(bins)?;
+                self.compression.compress_bins(bins);
+                self.cipher.encrypt_bins(bins)
+            })?;
+            let results = catch_panic_sync("Client.bulk_put", || {
+                py.detach(|| {
+                    Ok(self.runtime.block_on(client_ops::do_bulk_put(
+                        &client,
+                        &self.limiter,
+                        &args,
+                        &chunks,
+                    )))
+                })
+            })?;
+            for (chunk, result) in chunks.iter().zip(results) {
+                summary.add_chunk(chunk, result);
+            }
+        }
+        Ok(summary.finish())
+    }
+
     /// Remove multiple records.
     #[pyo3(signature = (keys, policy=None))]
     fn batch_remove(
@@ -1323,19 +2102,20 @@ impl PyClient {
         policy: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<Py<PyAny>> {
         debug!("batch_remove: keys_count={}", keys.len());
-        let client = self.get_client()?.clone();
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
         let args =
             client_common::prepare_batch_remove_args(py, keys, policy, &self.connection_info)?;
         let limiter = self.limiter.clone();
         let results = catch_panic_sync("Client.batch_remove", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("batch_remove").await?;
                     client_ops::do_batch_remove(&client, &args).await
                 })
             })
         })?;
-        let batch = batch_to_batch_records_py(py, results)?;
+        let batch = batch_to_batch_records_py(py, results, allow_partial)?;
         Ok(Py::new(py, batch)?.into_any())
     }
 
@@ -1356,7 +2136,8 @@ impl PyClient {
             module,
             function
         );
-        let client = self.get_client()?.clone();
+        let allow_partial = parse_allow_partial_results(policy)?;
+        let client = self.blocking_client(py)?.clone();
         let args = client_common::prepare_batch_apply_args(
             py,
             keys,
@@ -1369,23 +2150,102 @@ impl PyClient {
         let limiter = self.limiter.clone();
         let results = catch_panic_sync("Client.batch_apply", || {
             py.detach(|| {
-                RUNTIME.block_on(async {
+                self.runtime.block_on(async {
                     let _permit = limiter.acquire_named("batch_apply").await?;
                     client_ops::do_batch_apply(&client, &args).await
                 })
             })
         })?;
-        let batch = batch_to_batch_records_py(py, results)?;
+        let batch = batch_to_batch_records_py(py, results, allow_partial)?;
         Ok(Py::new(py, batch)?.into_any())
     }
 }
 
 impl PyClient {
+    /// Raw response of an info command sent to `node` or a random node.
+    fn info_text(
+        &self,
+        py: Python<'_>,
+        command: &str,
+        node: Option<&str>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<String> {
+        let client = self.blocking_client(py)?;
+        let args = client_common::prepare_info_args(command, policy)?;
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_info_node(client, &args, node))
+        })
+    }
+
     /// Returns a reference to the connected client, or an error if not yet connected.
+    fn query_binding(&self) -> PyResult<crate::query::QueryBinding> {
+        Ok(crate::query::QueryBinding {
+            client: self.get_client()?.clone(),
+            connection_info: self.connection_info.clone(),
+            runtime: self.runtime.clone(),
+            compression: self.compression.clone(),
+            cipher: self.cipher.clone(),
+            event_loop_guard: self.event_loop_guard,
+        })
+    }
+
     fn get_client(&self) -> PyResult<&Arc<AsClient>> {
-        self.inner.as_ref().ok_or_else(|| {
+        if self.is_forked() {
+            return Err(crate::errors::ForkError::new_err(format!(
+                "Client was connected in process {} before os.fork(). Call connect() \
+                 (or close()) in the child process (pid {}) before using it.",
+                self.pid,
+                std::process::id()
+            )));
+        }
+        let client = self.inner.as_ref().ok_or_else(|| {
             crate::errors::ClientError::new_err("Client is not connected. Call connect() first.")
-        })
+        })?;
+        self.cluster_name_guard.check()?;
+        Ok(client)
+    }
+
+    /// [`Self::get_client`] for a command that blocks on the runtime.
+    fn blocking_client(&self, py: Python<'_>) -> PyResult<&Arc<AsClient>> {
+        let client = self.get_client()?;
+        if self.event_loop_guard {
+            crate::runtime::forbid_running_loop(py)?;
+        }
+        Ok(client)
+    }
+
+    /// `true` in a child forked from the process that connected this client.
+    fn is_forked(&self) -> bool {
+        self.inner.is_some() && self.pid != std::process::id()
+    }
+
+    /// Discard the connection inherited across `os.fork()` without closing it.
+    ///
+    /// The parent still uses those sockets and the runtime they are registered
+    /// with; closing or even dropping them here would tear down the parent's
+    /// connections, so they are leaked instead.
+    fn abandon_after_fork(&mut self) {
+        info!(
+            "Discarding the connection inherited from process {} across os.fork()",
+            self.pid
+        );
+        self.forget_inherited();
+        self.connection_info = Arc::new(crate::tracing::ConnectionInfo::default());
+        self.limiter = Arc::new(OperationLimiter::new(0, 0));
+        self.breaker = Arc::new(CircuitBreaker::default());
+        self.compression = Arc::new(BinCompression::default());
+        self.bin_limits = Arc::new(BinLimits::default());
+        self.state = DISCONNECTED;
+    }
+
+    fn forget_inherited(&mut self) {
+        std::mem::forget(self.inner.take());
+        std::mem::forget(std::mem::take(&mut self.runtime));
+        self.cluster_events.forget_task();
+        self.session_refresh.forget_task();
+        self.srv_refresh.forget_task();
+        self.cluster_name_guard.forget_task();
     }
 
     /// Internal helper for index creation
@@ -1399,15 +2259,29 @@ impl PyClient {
         index_name: &str,
         index_type: aerospike_core::IndexType,
         policy: Option<&Bound<'_, PyDict>>,
+        wait: bool,
     ) -> PyResult<()> {
         info!(
             "Creating index: ns={} set={} bin={} index={}",
             namespace, set_name, bin_name, index_name
         );
-        let client = self.get_client()?.clone();
+        let client = self.blocking_client(py)?.clone();
         let args = client_common::prepare_index_create_args(
             namespace, set_name, bin_name, index_name, index_type, policy,
         )?;
-        py.detach(|| RUNTIME.block_on(client_ops::do_index_create(&client, args)))
+        py.detach(|| {
+            self.runtime
+                .block_on(client_ops::do_index_create(&client, args, wait))
+        })
+    }
+}
+
+impl Drop for PyClient {
+    /// A client garbage-collected in a forked child must not drop the
+    /// parent's connections either (see [`PyClient::abandon_after_fork`]).
+    fn drop(&mut self) {
+        if self.is_forked() {
+            self.forget_inherited();
+        }
     }
 }
@@ -16,16 +16,19 @@ use aerospike_core::{
 };
 use pyo3::prelude::*;
 use pyo3::types::PyAnyMethods;
-use pyo3::types::{PyDict, PyList, PyTuple};
+use pyo3::types::{PyDict, PyIterator, PyList, PyTuple};
 
-use crate::operations::py_ops_to_rust;
+use crate::operations::{apply_record_op_options, check_increment_offset, py_ops_to_rust};
 use crate::policy::admin_policy::parse_admin_policy;
 use crate::policy::batch_policy::parse_batch_policy;
+use crate::policy::operate_policy::parse_operate_policy;
 use crate::policy::read_policy::{parse_read_policy, DEFAULT_READ_POLICY};
+use crate::policy::retry_policy::{parse_retry_policy, RetryPolicy};
 use crate::policy::write_policy::parse_write_policy;
 use crate::tracing::ConnectionInfo;
 use crate::types::bin::py_dict_to_bins;
 use crate::types::key::{py_to_key, py_to_keys};
+use crate::types::raw::py_raw_bins_to_bins;
 
 // ── OTel context extraction ──────────────────────────────────────────────────
 
@@ -84,18 +87,32 @@ pub fn extract_cluster_name(config: &Bound<'_, PyDict>) -> PyResult<String> {
             .name()
             .map(|n| n.to_string())
             .unwrap_or_else(|_| "unknown".to_string());
-        pyo3::exceptions::PyTypeError::new_err(format!(
+        crate::errors::ParamError::new_err(format!(
             "cluster_name must be str or None, got {type_name}"
         ))
     })
 }
 
+/// Validate the `conns_per_node` argument of `warm_up()`.
+pub fn validate_warm_up_conns(conns_per_node: usize) -> PyResult<()> {
+    if conns_per_node == 0 {
+        return Err(crate::errors::ParamError::new_err(
+            "conns_per_node must be at least 1",
+        ));
+    }
+    Ok(())
+}
+
 // ── put ──────────────────────────────────────────────────────────────────────
 
 pub struct PutArgs {
     pub key: Key,
     pub bins: Vec<Bin>,
     pub policy: PutPolicy,
+    /// `put(key, None)` with `clear_on_none`: delete every bin (and so the record).
+    pub clear_bins: bool,
+    /// `put(..., return_meta=True)`: read the record header back in the same command.
+    pub return_meta: bool,
     pub otel: OtelContext,
 }
 
@@ -112,17 +129,62 @@ pub fn prepare_put_args(
     policy: Option<&Bound<'_, PyDict>>,
     conn_info: &Arc<ConnectionInfo>,
 ) -> PyResult<PutArgs> {
+    if bins.is_none() {
+        if !clear_on_none(policy)? {
+            return Err(crate::errors::ParamError::new_err(
+                "bins argument must be a dict, got None \
+                 (set policy={'clear_on_none': True} to delete all bins)",
+            ));
+        }
+        let args = put_args_from_bins(py, key, Vec::new(), meta, policy, conn_info)?;
+        return Ok(PutArgs {
+            clear_bins: true,
+            ..args
+        });
+    }
     let type_name = bins
         .get_type()
         .name()
         .map(|n| n.to_string())
         .unwrap_or_else(|_| "unknown".to_string());
     let bins_dict = bins.cast::<PyDict>().map_err(|_| {
-        pyo3::exceptions::PyTypeError::new_err(format!(
-            "bins argument must be a dict, got {type_name}"
-        ))
+        crate::errors::ParamError::new_err(format!("bins argument must be a dict, got {type_name}"))
     })?;
     let rust_bins = py_dict_to_bins(bins_dict)?;
+    put_args_from_bins(py, key, rust_bins, meta, policy, conn_info)
+}
+
+/// Read the put-only `clear_on_none` policy flag (default `false`).
+fn clear_on_none(policy: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    match policy {
+        Some(dict) => dict
+            .get_item("clear_on_none")?
+            .map_or(Ok(false), |val| val.extract::<bool>()),
+        None => Ok(false),
+    }
+}
+
+/// Parse arguments for `put_raw`: bins are `{name: (particle_type, payload)}`.
+pub fn prepare_put_raw_args(
+    py: Python<'_>,
+    key: &Bound<'_, PyAny>,
+    bins: &Bound<'_, PyDict>,
+    meta: Option<&Bound<'_, PyDict>>,
+    policy: Option<&Bound<'_, PyDict>>,
+    conn_info: &Arc<ConnectionInfo>,
+) -> PyResult<PutArgs> {
+    let rust_bins = py_raw_bins_to_bins(bins)?;
+    put_args_from_bins(py, key, rust_bins, meta, policy, conn_info)
+}
+
+fn put_args_from_bins(
+    py: Python<'_>,
+    key: &Bound<'_, PyAny>,
+    rust_bins: Vec<Bin>,
+    meta: Option<&Bound<'_, PyDict>>,
+    policy: Option<&Bound<'_, PyDict>>,
+    conn_info: &Arc<ConnectionInfo>,
+) -> PyResult<PutArgs> {
     let rust_key = py_to_key(key)?;
 
     let put_policy = if policy.is_none() && meta.is_none() {
@@ -135,6 +197,8 @@ pub fn prepare_put_args(
         key: rust_key,
         bins: rust_bins,
         policy: put_policy,
+        clear_bins: false,
+        return_meta: false,
         otel: OtelContext::new(py, conn_info),
     })
 }
@@ -144,6 +208,7 @@ pub fn prepare_put_args(
 pub struct GetArgs {
     pub key: Key,
     pub policy: ReadPolicyChoice,
+    pub retry: Option<RetryPolicy>,
     pub otel: OtelContext,
 }
 
@@ -152,6 +217,18 @@ pub enum ReadPolicyChoice {
     Custom(ReadPolicy),
 }
 
+/// Parse an optional read policy dict together with its retry fields.
+fn parse_read_policy_choice(
+    policy: Option<&Bound<'_, PyDict>>,
+) -> PyResult<(ReadPolicyChoice, Option<RetryPolicy>)> {
+    if policy.is_none() {
+        return Ok((ReadPolicyChoice::Default, None));
+    }
+    let mut read_policy = parse_read_policy(policy)?;
+    let retry = parse_retry_policy(policy, &mut read_policy.base_policy)?;
+    Ok((ReadPolicyChoice::Custom(read_policy), retry))
+}
+
 pub fn prepare_get_args(
     py: Python<'_>,
     key: &Bound<'_, PyAny>,
@@ -159,15 +236,12 @@ pub fn prepare_get_args(
     conn_info: &Arc<ConnectionInfo>,
 ) -> PyResult<GetArgs> {
     let rust_key = py_to_key(key)?;
-    let read_policy = if policy.is_none() {
-        ReadPolicyChoice::Default
-    } else {
-        ReadPolicyChoice::Custom(parse_read_policy(policy)?)
-    };
+    let (read_policy, retry) = parse_read_policy_choice(policy)?;
 
     Ok(GetArgs {
         key: rust_key,
         policy: read_policy,
+        retry,
         otel: OtelContext::new(py, conn_info),
     })
 }
@@ -187,6 +261,7 @@ pub struct SelectArgs {
     pub key: Key,
     pub bin_names: Vec<String>,
     pub policy: ReadPolicyChoice,
+    pub retry: Option<RetryPolicy>,
     pub otel: OtelContext,
 }
 
@@ -199,16 +274,13 @@ pub fn prepare_select_args(
 ) -> PyResult<SelectArgs> {
     let rust_key = py_to_key(key)?;
     let bin_names: Vec<String> = bins.extract()?;
-    let read_policy = if policy.is_none() {
-        ReadPolicyChoice::Default
-    } else {
-        ReadPolicyChoice::Custom(parse_read_policy(policy)?)
-    };
+    let (read_policy, retry) = parse_read_policy_choice(policy)?;
 
     Ok(SelectArgs {
         key: rust_key,
         bin_names,
         policy: read_policy,
+        retry,
         otel: OtelContext::new(py, conn_info),
     })
 }
@@ -232,6 +304,7 @@ impl SelectArgs {
 pub struct ExistsArgs {
     pub key: Key,
     pub read_policy: ReadPolicy,
+    pub retry: Option<RetryPolicy>,
     pub otel: OtelContext,
 }
 
@@ -242,15 +315,17 @@ pub fn prepare_exists_args(
     conn_info: &Arc<ConnectionInfo>,
 ) -> PyResult<ExistsArgs> {
     let rust_key = py_to_key(key)?;
-    let read_policy = if policy.is_none() {
+    let mut read_policy = if policy.is_none() {
         DEFAULT_READ_POLICY.clone()
     } else {
         parse_read_policy(policy)?
     };
+    let retry = parse_retry_policy(policy, &mut read_policy.base_policy)?;
 
     Ok(ExistsArgs {
         key: rust_key,
         read_policy,
+        retry,
         otel: OtelContext::new(py, conn_info),
     })
 }
@@ -315,6 +390,8 @@ pub struct SingleBinWriteArgs {
     pub key: Key,
     pub write_policy: WritePolicy,
     pub bins: Vec<Bin>,
+    /// Read the bin back in the same command (`return_value=True`).
+    pub return_value: bool,
     pub otel: OtelContext,
 }
 
@@ -336,6 +413,7 @@ pub fn prepare_single_bin_write_args(
         key: rust_key,
         write_policy,
         bins,
+        return_value: false,
         otel: OtelContext::new(py, conn_info),
     })
 }
@@ -354,12 +432,14 @@ pub fn prepare_increment_args(
     let rust_key = py_to_key(key)?;
     let write_policy = parse_write_policy(policy, meta)?;
     let value = crate::types::value::py_to_value(offset)?;
+    check_increment_offset(&value)?;
     let bins = vec![Bin::new(bin.to_string(), value)];
 
     Ok(SingleBinWriteArgs {
         key: rust_key,
         write_policy,
         bins,
+        return_value: false,
         otel: OtelContext::new(py, conn_info),
     })
 }
@@ -412,8 +492,13 @@ pub fn prepare_operate_args(
     conn_info: &Arc<ConnectionInfo>,
 ) -> PyResult<OperateArgs> {
     let rust_key = py_to_key(key)?;
-    let write_policy = parse_write_policy(policy, meta)?;
+    let mut write_policy = parse_operate_policy(policy, meta)?;
     let rust_ops = py_ops_to_rust(ops)?;
+    apply_record_op_options(
+        ops,
+        &mut write_policy.expiration,
+        &mut write_policy.durable_delete,
+    )?;
 
     Ok(OperateArgs {
         key: rust_key,
@@ -432,6 +517,7 @@ pub struct BatchReadArgs {
     pub bins_selector: Bins,
     pub batch_ns: String,
     pub batch_set: String,
+    pub retry: Option<RetryPolicy>,
     pub otel: OtelContext,
 }
 
@@ -442,7 +528,8 @@ pub fn prepare_batch_read_args(
     policy: Option<&Bound<'_, PyDict>>,
     conn_info: &Arc<ConnectionInfo>,
 ) -> PyResult<BatchReadArgs> {
-    let batch_policy = parse_batch_policy(policy)?;
+    let mut batch_policy = parse_batch_policy(policy)?;
+    let retry = parse_retry_policy(policy, &mut batch_policy.base_policy)?;
     let read_policy = parse_batch_read_policy(policy)?;
     let bins_selector = match bins {
         None => Bins::All,
@@ -467,10 +554,43 @@ pub fn prepare_batch_read_args(
         bins_selector,
         batch_ns,
         batch_set,
+        retry,
         otel: OtelContext::new(py, conn_info),
     })
 }
 
+/// `_null_mask` is only meaningful for the numpy path, so reject it without `_dtype`.
+pub fn check_batch_read_null_mask(
+    dtype: Option<&Bound<'_, PyAny>>,
+    null_mask: bool,
+) -> PyResult<()> {
+    if null_mask && dtype.is_none() {
+        return Err(crate::errors::ParamError::new_err(
+            "_null_mask=True requires _dtype",
+        ));
+    }
+    Ok(())
+}
+
+/// Parse the `output` argument of `batch_read`, returning `true` for Arrow.
+///
+/// Arrow output infers its own schema, so it cannot be combined with `_dtype`.
+pub fn parse_batch_read_output(
+    output: Option<&str>,
+    dtype: Option<&Bound<'_, PyAny>>,
+) -> PyResult<bool> {
+    match output {
+        None => Ok(false),
+        Some("arrow") if dtype.is_some() => Err(crate::errors::ParamError::new_err(
+            "output='arrow' cannot be combined with _dtype",
+        )),
+        Some("arrow") => Ok(true),
+        Some(other) => Err(crate::errors::ParamError::new_err(format!(
+            "Unknown batch_read output '{other}', expected 'arrow'"
+        ))),
+    }
+}
+
 impl BatchReadArgs {
     pub fn to_batch_ops(&self) -> Vec<BatchOperation> {
         self.rust_keys
@@ -480,11 +600,25 @@ impl BatchReadArgs {
     }
 }
 
+// ── batch_exists ─────────────────────────────────────────────────────────────
+
+/// Header-only batch read (`Bins::None`): only generation and TTL come back.
+pub fn prepare_batch_exists_args(
+    py: Python<'_>,
+    keys: &Bound<'_, PyList>,
+    policy: Option<&Bound<'_, PyDict>>,
+    conn_info: &Arc<ConnectionInfo>,
+) -> PyResult<BatchReadArgs> {
+    prepare_batch_read_args(py, keys, &Some(Vec::new()), policy, conn_info)
+}
+
 // ── batch_operate ────────────────────────────────────────────────────────────
 
 pub struct BatchOperateArgs {
     pub rust_keys: Vec<Key>,
     pub batch_policy: aerospike_core::BatchPolicy,
+    /// Write-side fields (`key`, `ttl`, `gen`, ...) of the same policy dict.
+    pub write_policy: BatchWritePolicy,
     pub ops: Vec<Operation>,
     pub batch_ns: String,
     pub batch_set: String,
@@ -499,7 +633,13 @@ pub fn prepare_batch_operate_args(
     conn_info: &Arc<ConnectionInfo>,
 ) -> PyResult<BatchOperateArgs> {
     let batch_policy = parse_batch_policy(policy)?;
+    let mut write_policy = parse_batch_write_policy(policy)?;
     let rust_ops = py_ops_to_rust(ops)?;
+    apply_record_op_options(
+        ops,
+        &mut write_policy.expiration,
+        &mut write_policy.durable_delete,
+    )?;
     let rust_keys = py_to_keys(keys)?;
 
     let (batch_ns, batch_set) = rust_keys
@@ -510,6 +650,7 @@ pub fn prepare_batch_operate_args(
     Ok(BatchOperateArgs {
         rust_keys,
         batch_policy,
+        write_policy,
         ops: rust_ops,
         batch_ns,
         batch_set,
@@ -519,10 +660,9 @@ pub fn prepare_batch_operate_args(
 
 impl BatchOperateArgs {
     pub fn to_batch_ops(&self) -> Vec<BatchOperation> {
-        let write_policy = BatchWritePolicy::default();
         self.rust_keys
             .iter()
-            .map(|k| BatchOperation::write(&write_policy, k.clone(), self.ops.clone()))
+            .map(|k| BatchOperation::write(&self.write_policy, k.clone(), self.ops.clone()))
             .collect()
     }
 }
@@ -578,7 +718,7 @@ pub fn prepare_batch_remove_args(
             let meta_obj = tuple.get_item(1)?;
             let meta_dict = meta_obj
                 .cast::<PyDict>()
-                .map_err(|_| pyo3::exceptions::PyTypeError::new_err("meta must be a dict"))?;
+                .map_err(|_| crate::errors::ParamError::new_err("meta must be a dict"))?;
             let key = py_to_key(&key_obj)?;
             let policy = Arc::new(apply_record_meta_for_delete(
                 &base_delete_policy,
@@ -699,7 +839,7 @@ pub fn prepare_batch_apply_args(
             let meta_obj = tuple.get_item(1)?;
             let meta_dict = meta_obj
                 .cast::<PyDict>()
-                .map_err(|_| pyo3::exceptions::PyTypeError::new_err("meta must be a dict"))?;
+                .map_err(|_| crate::errors::ParamError::new_err("meta must be a dict"))?;
             let key = py_to_key(&key_obj)?;
 
             let policy = Arc::new(apply_record_meta_for_apply(&base_udf_policy, meta_dict)?);
@@ -716,7 +856,7 @@ pub fn prepare_batch_apply_args(
             let rec_args: Option<Vec<Value>> = match meta_dict.get_item("args")? {
                 Some(v) if !v.is_none() => {
                     let list = v.cast::<PyList>().map_err(|_| {
-                        pyo3::exceptions::PyTypeError::new_err("meta 'args' must be a list")
+                        crate::errors::ParamError::new_err("meta 'args' must be a list")
                     })?;
                     let mut out = Vec::with_capacity(list.len());
                     for it in list.iter() {
@@ -794,10 +934,10 @@ pub fn prepare_batch_write_args(
 
     for item in records.iter() {
         let tuple = item.cast::<PyTuple>().map_err(|_| {
-            pyo3::exceptions::PyTypeError::new_err("Each record must be a tuple of (key, bins)")
+            crate::errors::ParamError::new_err("Each record must be a tuple of (key, bins)")
         })?;
         if tuple.len() < 2 {
-            return Err(pyo3::exceptions::PyValueError::new_err(
+            return Err(crate::errors::ParamError::new_err(
                 "Each record tuple must have at least 2 elements: (key, bins)",
             ));
         }
@@ -805,7 +945,7 @@ pub fn prepare_batch_write_args(
         let bins_obj = tuple.get_item(1)?;
         let bins_dict = bins_obj
             .cast::<PyDict>()
-            .map_err(|_| pyo3::exceptions::PyTypeError::new_err("bins element must be a dict"))?;
+            .map_err(|_| crate::errors::ParamError::new_err("bins element must be a dict"))?;
         let bins = py_dict_to_bins(bins_dict)?;
 
         // Per-record meta (3rd tuple element) overrides batch-level TTL.
@@ -813,9 +953,9 @@ pub fn prepare_batch_write_args(
         // share a refcounted clone of the base policy.
         let write_policy = if tuple.len() >= 3 {
             let meta_obj = tuple.get_item(2)?;
-            let meta_dict = meta_obj.cast::<PyDict>().map_err(|_| {
-                pyo3::exceptions::PyTypeError::new_err("meta element must be a dict")
-            })?;
+            let meta_dict = meta_obj
+                .cast::<PyDict>()
+                .map_err(|_| crate::errors::ParamError::new_err("meta element must be a dict"))?;
             Arc::new(apply_record_meta(&base_write_policy, meta_dict)?)
         } else {
             Arc::clone(&base_write_policy)
@@ -839,6 +979,233 @@ pub fn prepare_batch_write_args(
     })
 }
 
+// ── bulk_put ────────────────────────────────────────────────────────────────
+
+pub struct BulkPutArgs {
+    pub batch_policy: aerospike_core::BatchPolicy,
+    pub write_policy: Arc<BatchWritePolicy>,
+    pub namespace: String,
+    pub set_name: String,
+    pub key_fn: Option<Py<PyAny>>,
+    pub chunk_size: usize,
+    pub concurrency: usize,
+    pub otel: OtelContext,
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn prepare_bulk_put_args(
+    py: Python<'_>,
+    namespace: &str,
+    set_name: &str,
+    key_fn: Option<&Bound<'_, PyAny>>,
+    policy: Option<&Bound<'_, PyDict>>,
+    chunk_size: usize,
+    concurrency: usize,
+    conn_info: &Arc<ConnectionInfo>,
+) -> PyResult<BulkPutArgs> {
+    if chunk_size == 0 || concurrency == 0 {
+        return Err(crate::errors::ParamError::new_err(
+            "chunk_size and concurrency must be at least 1",
+        ));
+    }
+    if let Some(key_fn) = key_fn {
+        if !key_fn.is_callable() {
+            return Err(crate::errors::ParamError::new_err(
+                "key_fn must be callable",
+            ));
+        }
+    }
+    Ok(BulkPutArgs {
+        batch_policy: parse_batch_policy(policy)?,
+        write_policy: Arc::new(parse_batch_write_policy(policy)?),
+        namespace: namespace.to_string(),
+        set_name: set_name.to_string(),
+        key_fn: key_fn.map(|f| f.clone().unbind()),
+        chunk_size,
+        concurrency,
+        otel: OtelContext::new(py, conn_info),
+    })
+}
+
+/// Up to `chunk_size` converted records, written as one batch.
+#[derive(Default)]
+pub struct BulkPutChunk {
+    /// Position of each record in the input iterable.
+    pub indexes: Vec<usize>,
+    pub records: Vec<(Key, Vec<Bin>, Arc<BatchWritePolicy>)>,
+}
+
+/// Convert one `bulk_put` input item: a bins dict (keyed by its `_key`
+/// entry or by `key_fn`) or a `(key, bins)` tuple.
+fn bulk_put_record(item: &Bound<'_, PyAny>, args: &BulkPutArgs) -> PyResult<(Key, Vec<Bin>)> {
+    let py = item.py();
+    let (key, bins) = if let Ok(dict) = item.cast::<PyDict>() {
+        let mut bins = py_dict_to_bins(dict)?;
+        let key = match &args.key_fn {
+            Some(key_fn) => key_fn.bind(py).call1((dict,))?,
+            None => {
+                bins.retain(|bin| bin.name != "_key");
+                dict.get_item("_key")?.ok_or_else(|| {
+                    crate::errors::ParamError::new_err(
+                        "Record dict has no '_key' entry; pass key_fn to derive the key",
+                    )
+                })?
+            }
+        };
+        (key, bins)
+    } else {
+        let tuple = item
+            .cast::<PyTuple>()
+            .ok()
+            .filter(|t| t.len() == 2)
+            .ok_or_else(|| {
+                crate::errors::ParamError::new_err(
+                    "Each record must be a bins dict or a (key, bins) tuple",
+                )
+            })?;
+        let bins_obj = tuple.get_item(1)?;
+        let bins_dict = bins_obj
+            .cast::<PyDict>()
+            .map_err(|_| crate::errors::ParamError::new_err("bins element must be a dict"))?;
+        (tuple.get_item(0)?, py_dict_to_bins(bins_dict)?)
+    };
+    // A full (namespace, set, key) tuple overrides the call's namespace / set.
+    let key = if key.is_instance_of::<PyTuple>() {
+        py_to_key(&key)?
+    } else {
+        py_to_key(
+            &(args.namespace.as_str(), args.set_name.as_str(), key)
+                .into_pyobject(py)?
+                .into_any(),
+        )?
+    };
+    Ok((key, bins))
+}
+
+/// Pulls `bulk_put` records from a Python iterable and converts them in chunks.
+///
+/// Holds no GIL token, so the async client can read one wave per GIL
+/// attach from inside its future.
+pub struct BulkPutReader {
+    iter: Py<PyIterator>,
+    next_index: usize,
+    exhausted: bool,
+}
+
+impl BulkPutReader {
+    pub fn new(records: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Ok(Self {
+            iter: records.try_iter()?.unbind(),
+            next_index: 0,
+            exhausted: false,
+        })
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Convert records into at most `max_chunks` chunks.
+    ///
+    /// `prepare` runs on each record's bins (limits, compression, encryption).
+    /// A record that fails to convert or prepare is counted in `summary`
+    /// instead of aborting the call; an error raised by the iterable itself
+    /// is returned.
+    pub fn next_chunks(
+        &mut self,
+        py: Python<'_>,
+        args: &BulkPutArgs,
+        max_chunks: usize,
+        summary: &mut BulkPutResult,
+        prepare: impl Fn(&mut Vec<Bin>) -> PyResult<()>,
+    ) -> PyResult<Vec<BulkPutChunk>> {
+        let mut iter = self.iter.bind(py).clone();
+        let mut chunks = Vec::new();
+        while chunks.len() < max_chunks && !self.exhausted {
+            let mut chunk = BulkPutChunk::default();
+            while chunk.records.len() < args.chunk_size {
+                let Some(item) = iter.next() else {
+                    self.exhausted = true;
+                    break;
+                };
+                let item = item?;
+                let index = self.next_index;
+                self.next_index += 1;
+                let record = bulk_put_record(&item, args).and_then(|(key, mut bins)| {
+                    prepare(&mut bins)?;
+                    Ok((key, bins))
+                });
+                match record {
+                    Ok((key, bins)) => {
+                        chunk.indexes.push(index);
+                        chunk
+                            .records
+                            .push((key, bins, Arc::clone(&args.write_policy)));
+                    }
+                    Err(e) => {
+                        summary.add_error(index, crate::errors::pyerr_code(&e), e.to_string())
+                    }
+                }
+            }
+            if !chunk.records.is_empty() {
+                chunks.push(chunk);
+            }
+        }
+        Ok(chunks)
+    }
+}
+
+/// Outcome of `bulk_put()`.
+#[derive(IntoPyObject, Debug, Default, PartialEq, Eq)]
+pub struct BulkPutResult {
+    pub written: usize,
+    pub failed: usize,
+    /// `(index, result_code, message)` of each failed record, `index` being
+    /// its position in the input iterable.
+    pub errors: Vec<(usize, i32, String)>,
+}
+
+impl BulkPutResult {
+    fn add_error(&mut self, index: usize, code: i32, message: String) {
+        self.failed += 1;
+        self.errors.push((index, code, message));
+    }
+
+    /// Count the outcome of writing `chunk`; a failed batch fails all its records.
+    pub fn add_chunk(
+        &mut self,
+        chunk: &BulkPutChunk,
+        result: PyResult<Vec<aerospike_core::BatchRecord>>,
+    ) {
+        match result {
+            Ok(records) => {
+                for (&index, br) in chunk.indexes.iter().zip(&records) {
+                    match &br.result_code {
+                        None | Some(aerospike_core::ResultCode::Ok) => self.written += 1,
+                        Some(rc) => {
+                            let code = crate::errors::result_code_to_int(rc);
+                            self.add_error(index, code, format!("AEROSPIKE_ERR ({code}): {rc}"));
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                let code = crate::errors::pyerr_code(&e);
+                let message = e.to_string();
+                for &index in &chunk.indexes {
+                    self.add_error(index, code, message.clone());
+                }
+            }
+        }
+    }
+
+    /// Order `errors` by input position once every chunk is counted.
+    pub fn finish(mut self) -> Self {
+        self.errors.sort_unstable_by_key(|(index, _, _)| *index);
+        self
+    }
+}
+
 // ── info_all / info_random_node ──────────────────────────────────────────────
 
 pub struct InfoArgs {
@@ -854,6 +1221,48 @@ pub fn prepare_info_args(command: &str, policy: Option<&Bound<'_, PyDict>>) -> P
     })
 }
 
+// ── job_info ─────────────────────────────────────────────────────────────────
+
+pub struct JobInfoArgs {
+    pub admin_policy: aerospike_core::AdminPolicy,
+    pub job_id: u64,
+    /// `JOB_SCAN` rather than `JOB_QUERY`; only servers before 6.0 tell them apart.
+    pub scan: bool,
+}
+
+pub fn prepare_job_info_args(
+    job_id: u64,
+    module: &str,
+    policy: Option<&Bound<'_, PyDict>>,
+) -> PyResult<JobInfoArgs> {
+    let scan = match module {
+        "scan" => true,
+        "query" => false,
+        _ => {
+            return Err(crate::errors::ParamError::new_err(format!(
+                "module must be JOB_SCAN ('scan') or JOB_QUERY ('query'), got '{module}'"
+            )))
+        }
+    };
+    Ok(JobInfoArgs {
+        admin_policy: parse_admin_policy(policy)?,
+        job_id,
+        scan,
+    })
+}
+
+/// Cluster-wide state of a scan / query job, returned by `job_info()`.
+#[derive(IntoPyObject, Debug, PartialEq, Eq)]
+pub struct JobInfo {
+    /// `JOB_STATUS_UNDEF` (no node knows the job), `JOB_STATUS_INPROGRESS`
+    /// or `JOB_STATUS_COMPLETED`.
+    pub status: i32,
+    /// Lowest progress across the nodes running the job.
+    pub progress_pct: u32,
+    /// Records read so far, summed across nodes.
+    pub records_read: u64,
+}
+
 // ── info result helpers ──────────────────────────────────────────────────────
 
 pub fn info_node_result(
@@ -878,6 +1287,69 @@ pub fn info_node_result(
     }
 }
 
+/// `sindex-list` command, filtered to `namespace` when given.
+pub fn sindex_list_command(namespace: Option<&str>) -> String {
+    match namespace {
+        Some(ns) => format!("sindex-list:ns={ns}"),
+        None => "sindex-list:".to_string(),
+    }
+}
+
+/// Client-side view of one node, returned by `get_cluster_stats()`.
+#[derive(IntoPyObject)]
+pub struct NodeStats {
+    /// `host:port` the client connects to.
+    pub address: String,
+    pub active: bool,
+    /// Consecutive tend failures (reset after a successful refresh).
+    pub failures: usize,
+    pub partition_generation: isize,
+    pub rebalance_generation: isize,
+    /// Master partitions owned per namespace, from the client's partition map.
+    pub partitions: std::collections::BTreeMap<String, usize>,
+    /// Connection pool capacity (`max_conns_per_node`).
+    pub max_conns: usize,
+    /// Circuit breaker state: `"closed"`, `"open"` or `"half_open"`.
+    pub circuit_state: &'static str,
+    /// Times the node's circuit has opened.
+    pub circuit_trips: u64,
+}
+
+/// One cluster node, returned by `get_nodes()`.
+#[derive(IntoPyObject)]
+pub struct NodeInfo {
+    pub name: String,
+    /// Host name or IP address the client connects to.
+    pub address: String,
+    pub port: u16,
+    pub active: bool,
+    pub partition_generation: isize,
+    /// Rack of the node, from `info("rack-ids")`.
+    pub rack_id: Option<u64>,
+}
+
+/// Master node of a key's partition, returned by `get_node_for_key()`.
+#[derive(IntoPyObject)]
+pub struct KeyNode {
+    pub name: String,
+    /// Host name or IP address the client connects to.
+    pub address: String,
+    pub port: u16,
+    pub partition_id: usize,
+}
+
+/// Parse a `rack-ids` response (`ns1:1;ns2:1`) into the rack shared by all
+/// of the node's namespaces; `None` if it is empty, malformed, or the
+/// namespaces are configured with different racks.
+pub fn parse_rack_id(response: &str) -> Option<u64> {
+    let mut racks = response
+        .split(';')
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| entry.split_once(':')?.1.trim().parse::<u64>().ok());
+    let first = racks.next()??;
+    racks.all(|rack| rack == Some(first)).then_some(first)
+}
+
 // ── truncate ─────────────────────────────────────────────────────────────────
 
 pub struct TruncateArgs {
@@ -885,6 +1357,8 @@ pub struct TruncateArgs {
     pub namespace: String,
     pub set_name: String,
     pub nanos: i64,
+    /// `Some(timeout)` to wait for completion; `Some(None)` waits indefinitely.
+    pub wait: Option<Option<std::time::Duration>>,
 }
 
 pub fn prepare_truncate_args(
@@ -892,16 +1366,38 @@ pub fn prepare_truncate_args(
     set_name: &str,
     nanos: i64,
     policy: Option<&Bound<'_, PyDict>>,
+    wait: bool,
+    timeout: Option<f64>,
 ) -> PyResult<TruncateArgs> {
     let admin_policy = parse_admin_policy(policy)?;
+    let timeout = timeout
+        .map(|secs| {
+            std::time::Duration::try_from_secs_f64(secs).map_err(|_| {
+                crate::errors::ParamError::new_err("timeout must be a non-negative number")
+            })
+        })
+        .transpose()?;
     Ok(TruncateArgs {
         admin_policy,
         namespace: namespace.to_string(),
         set_name: set_name.to_string(),
         nanos,
+        wait: wait.then_some(timeout),
     })
 }
 
+/// Parse the per-call `timeout=` of an async operation (seconds).
+pub fn parse_call_timeout(timeout: Option<f64>) -> PyResult<Option<std::time::Duration>> {
+    timeout
+        .map(|secs| match std::time::Duration::try_from_secs_f64(secs) {
+            Ok(limit) if !limit.is_zero() => Ok(limit),
+            _ => Err(crate::errors::ParamError::new_err(format!(
+                "timeout must be a positive number of seconds, got {secs}"
+            ))),
+        })
+        .transpose()
+}
+
 // ── UDF ──────────────────────────────────────────────────────────────────────
 
 pub struct UdfPutArgs {
@@ -909,18 +1405,21 @@ pub struct UdfPutArgs {
     pub language: UDFLang,
     pub udf_body: Vec<u8>,
     pub server_path: String,
+    /// Wait until every node has the module.
+    pub wait: bool,
 }
 
 pub fn prepare_udf_put_args(
     filename: &str,
     udf_type: u8,
     policy: Option<&Bound<'_, PyDict>>,
+    wait: bool,
 ) -> PyResult<UdfPutArgs> {
     let admin_policy = parse_admin_policy(policy)?;
     let language = match udf_type {
         0 => UDFLang::Lua,
         _ => {
-            return Err(crate::errors::InvalidArgError::new_err(
+            return Err(crate::errors::ParamError::new_err(
                 "Only Lua UDF (udf_type=0) is supported.",
             ))
         }
@@ -942,31 +1441,39 @@ pub fn prepare_udf_put_args(
         language,
         udf_body,
         server_path,
+        wait,
     })
 }
 
 pub struct UdfRemoveArgs {
     pub admin_policy: aerospike_core::AdminPolicy,
     pub server_path: String,
+    /// Wait until no node has the module.
+    pub wait: bool,
 }
 
 pub fn prepare_udf_remove_args(
     module: &str,
     policy: Option<&Bound<'_, PyDict>>,
+    wait: bool,
 ) -> PyResult<UdfRemoveArgs> {
     let admin_policy = parse_admin_policy(policy)?;
-    let server_path = if module.ends_with(".lua") {
-        module.to_string()
-    } else {
-        format!("{}.lua", module)
-    };
-
     Ok(UdfRemoveArgs {
         admin_policy,
-        server_path,
+        server_path: udf_server_path(module),
+        wait,
     })
 }
 
+/// Registered file name of a UDF module: `"my_udf"` → `"my_udf.lua"`.
+pub fn udf_server_path(module: &str) -> String {
+    if module.ends_with(".lua") {
+        module.to_string()
+    } else {
+        format!("{}.lua", module)
+    }
+}
+
 pub struct ApplyArgs {
     pub key: Key,
     pub write_policy: WritePolicy,
@@ -1110,8 +1617,8 @@ pub fn prepare_create_role_args(
 
 #[cfg(test)]
 mod tests {
-    use super::extract_cluster_name;
-    use pyo3::exceptions::PyTypeError;
+    use super::{extract_cluster_name, parse_rack_id};
+    use crate::errors::ParamError;
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
 
@@ -1154,7 +1661,191 @@ mod tests {
             let config = PyDict::new(py);
             config.set_item("cluster_name", 123).unwrap();
             let err = extract_cluster_name(&config).expect_err("non-string should fail");
-            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+
+    #[test]
+    fn parse_rack_id_requires_one_rack() {
+        assert_eq!(parse_rack_id("test:2;bar:2"), Some(2));
+        assert_eq!(parse_rack_id("test:0"), Some(0));
+        assert_eq!(parse_rack_id("test:1;bar:2"), None);
+        assert_eq!(parse_rack_id(""), None);
+        assert_eq!(parse_rack_id("test"), None);
+    }
+
+    fn bulk_put_args(py: Python<'_>, key_fn: Option<&Bound<'_, PyAny>>) -> super::BulkPutArgs {
+        let conn_info = std::sync::Arc::new(crate::tracing::ConnectionInfo::default());
+        super::prepare_bulk_put_args(py, "test", "demo", key_fn, None, 2, 8, &conn_info).unwrap()
+    }
+
+    fn read_all(
+        py: Python<'_>,
+        args: &super::BulkPutArgs,
+        records: &std::ffi::CStr,
+    ) -> (Vec<super::BulkPutChunk>, super::BulkPutResult) {
+        let records = py.eval(records, None, None).unwrap();
+        let mut summary = super::BulkPutResult::default();
+        let mut reader = super::BulkPutReader::new(&records).unwrap();
+        let chunks = reader
+            .next_chunks(py, args, usize::MAX, &mut summary, |_| Ok(()))
+            .unwrap();
+        assert!(reader.is_exhausted());
+        (chunks, summary)
+    }
+
+    fn bin_names(bins: &[aerospike_core::Bin]) -> Vec<&str> {
+        bins.iter().map(|b| b.name.as_str()).collect()
+    }
+
+    #[test]
+    fn bulk_put_reader_converts_each_record_form() {
+        use aerospike_core::Value;
+
+        Python::initialize();
+        Python::attach(|py| {
+            let args = bulk_put_args(py, None);
+            let (chunks, summary) = read_all(
+                py,
+                &args,
+                c"[{'_key': 1, 'a': 1}, ('k2', {'b': 2}), (('other', 'set', 'k3'), {'c': 3})]",
+            );
+            assert_eq!(summary, super::BulkPutResult::default());
+            assert_eq!(chunks.len(), 2);
+            assert_eq!(chunks[0].indexes, [0, 1]);
+            assert_eq!(chunks[1].indexes, [2]);
+
+            // `_key` is the key, not a bin.
+            let (key, bins, _) = &chunks[0].records[0];
+            assert_eq!(
+                (key.namespace.as_str(), key.set_name.as_str()),
+                ("test", "demo")
+            );
+            assert_eq!(key.user_key, Some(Value::Int(1)));
+            assert_eq!(bin_names(bins), ["a"]);
+
+            let (key, bins, _) = &chunks[0].records[1];
+            assert_eq!(key.user_key, Some(Value::from("k2")));
+            assert_eq!(bin_names(bins), ["b"]);
+
+            // A full key tuple overrides the call's namespace / set.
+            let (key, _, _) = &chunks[1].records[0];
+            assert_eq!(
+                (key.namespace.as_str(), key.set_name.as_str()),
+                ("other", "set")
+            );
         });
     }
+
+    #[test]
+    fn bulk_put_reader_keys_dicts_with_key_fn() {
+        use aerospike_core::Value;
+
+        Python::initialize();
+        Python::attach(|py| {
+            let key_fn = py.eval(c"lambda r: r['id']", None, None).unwrap();
+            let args = bulk_put_args(py, Some(&key_fn));
+            let (chunks, summary) =
+                read_all(py, &args, c"[{'id': 'u1', 'name': 'a'}, {'name': 'b'}]");
+            assert_eq!(chunks.len(), 1);
+            let (key, bins, _) = &chunks[0].records[0];
+            assert_eq!(key.user_key, Some(Value::from("u1")));
+            // With key_fn every entry is a bin, `id` included.
+            assert_eq!(bin_names(bins), ["id", "name"]);
+            // key_fn raising KeyError fails only that record.
+            assert_eq!(summary.failed, 1);
+            assert_eq!(summary.errors[0].0, 1);
+            assert!(summary.errors[0].2.contains("KeyError"));
+        });
+    }
+
+    #[test]
+    fn bulk_put_reader_counts_bad_records_and_skips_them() {
+        Python::initialize();
+        Python::attach(|py| {
+            let args = bulk_put_args(py, None);
+            let (chunks, summary) = read_all(
+                py,
+                &args,
+                c"[{'a': 1}, 42, ('k', 'not bins'), ('k', {'a': 1}, 'extra'), {'_key': 5, 'a': 5}]",
+            );
+            assert_eq!(chunks.len(), 1);
+            assert_eq!(chunks[0].indexes, [4]);
+            assert_eq!(summary.written, 0);
+            assert_eq!(summary.failed, 4);
+            let messages: Vec<&str> = summary.errors.iter().map(|e| e.2.as_str()).collect();
+            assert!(messages[0].contains("no '_key' entry"));
+            assert!(messages[1].contains("bins dict or a (key, bins) tuple"));
+            assert!(messages[2].contains("bins element must be a dict"));
+            assert!(messages[3].contains("bins dict or a (key, bins) tuple"));
+        });
+    }
+
+    #[test]
+    fn bulk_put_reader_propagates_iterable_errors() {
+        Python::initialize();
+        Python::attach(|py| {
+            let args = bulk_put_args(py, None);
+            let records = py.eval(c"(1 // x for x in [1, 0])", None, None).unwrap();
+            let mut summary = super::BulkPutResult::default();
+            let mut reader = super::BulkPutReader::new(&records).unwrap();
+            let err = reader
+                .next_chunks(py, &args, usize::MAX, &mut summary, |_| Ok(()))
+                .err()
+                .unwrap();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyZeroDivisionError>(py));
+        });
+    }
+
+    #[test]
+    fn bulk_put_result_counts_chunks_and_sorts_errors() {
+        use aerospike_core::{BatchOperation, BatchWritePolicy, Key, ResultCode, Value};
+        use std::sync::Arc;
+
+        let policy = Arc::new(BatchWritePolicy::default());
+        let chunk = |indexes: &[usize]| super::BulkPutChunk {
+            indexes: indexes.to_vec(),
+            records: indexes
+                .iter()
+                .map(|&i| {
+                    let key = Key::new("test", "demo", Value::from(i as i64)).unwrap();
+                    (key, Vec::new(), Arc::clone(&policy))
+                })
+                .collect(),
+        };
+        let results = |chunk: &super::BulkPutChunk, codes: &[Option<ResultCode>]| {
+            chunk
+                .records
+                .iter()
+                .zip(codes)
+                .map(|((key, _, policy), code)| {
+                    let mut br =
+                        BatchOperation::write(policy, key.clone(), Vec::new()).batch_record();
+                    br.result_code = *code;
+                    br
+                })
+                .collect::<Vec<_>>()
+        };
+
+        Python::initialize();
+        let mut summary = super::BulkPutResult::default();
+        summary.add_error(1, -2, "bad record".to_string());
+        let late = chunk(&[5, 6]);
+        summary.add_chunk(&late, Err(ParamError::new_err("batch failed")));
+        let early = chunk(&[0, 2, 3]);
+        let codes = [Some(ResultCode::Ok), None, Some(ResultCode::KeyExistsError)];
+        summary.add_chunk(&early, Ok(results(&early, &codes)));
+        let summary = summary.finish();
+
+        assert_eq!(summary.written, 2);
+        assert_eq!(summary.failed, 4);
+        assert_eq!(
+            summary.errors.iter().map(|e| e.0).collect::<Vec<_>>(),
+            [1, 3, 5, 6]
+        );
+        assert_eq!(summary.errors[1].1, 5);
+        assert!(summary.errors[1].2.starts_with("AEROSPIKE_ERR (5): "));
+        assert!(summary.errors[2].2.contains("batch failed"));
+        assert_eq!(summary.errors[2].2, summary.errors[3].2);
+    }
 }
@@ -8,19 +8,24 @@
 use std::sync::Arc;
 
 use aerospike_core::{
-    BatchOperation, BatchRecord, BatchWritePolicy, Bins, Client as AsClient, Error as AsError,
-    Record, Task, Value,
+    operations, operations::Operation, BatchOperation, BatchRecord, BatchWritePolicy, Bin, Bins,
+    Client as AsClient, Error as AsError, Record, Task, Value,
 };
 
+use futures::{StreamExt, TryStreamExt};
 use pyo3::PyResult;
 
+use crate::backpressure::OperationLimiter;
+use crate::circuit_breaker::CircuitBreaker;
 use crate::client_common::{
-    self, BatchApplyArgs, BatchOperateArgs, BatchReadArgs, BatchRemoveArgs, ExistsArgs, GetArgs,
-    IndexCreateArgs, IndexRemoveArgs, InfoArgs, OperateArgs, PutArgs, PutPolicy, RemoveArgs,
-    RemoveBinArgs, SelectArgs, SingleBinWriteArgs, TouchArgs, TruncateArgs, UdfPutArgs,
-    UdfRemoveArgs,
+    self, BatchApplyArgs, BatchOperateArgs, BatchReadArgs, BatchRemoveArgs, BulkPutArgs,
+    BulkPutChunk, ExistsArgs, GetArgs, IndexCreateArgs, IndexRemoveArgs, InfoArgs, OperateArgs,
+    PutArgs, PutPolicy, RemoveArgs, RemoveBinArgs, SelectArgs, SingleBinWriteArgs, TouchArgs,
+    TruncateArgs, UdfPutArgs, UdfRemoveArgs,
 };
-use crate::errors::as_to_pyerr;
+use crate::errors::{as_to_pyerr, with_key, with_udf_call};
+use crate::info_parsers;
+use crate::policy::retry_policy::{compute_backoff_ms, with_retry};
 use crate::policy::write_policy::DEFAULT_WRITE_POLICY;
 use crate::traced_exists_op;
 use crate::traced_op;
@@ -28,30 +33,55 @@ use crate::traced_op;
 // ── CRUD ────────────────────────────────────────────────────────────────────
 
 /// Write a record to the cluster.
-pub async fn do_put(client: &AsClient, args: PutArgs) -> PyResult<()> {
-    match args.policy {
-        PutPolicy::Default => {
-            let wp = &*DEFAULT_WRITE_POLICY;
-            traced_op!(
-                "put",
-                &args.key.namespace,
-                &args.key.set_name,
-                args.otel.parent_ctx,
-                args.otel.conn_info,
-                client.put(wp, &args.key, &args.bins).await
-            )
-        }
-        PutPolicy::Custom(ref wp) => {
-            traced_op!(
-                "put",
-                &args.key.namespace,
-                &args.key.set_name,
-                args.otel.parent_ctx,
-                args.otel.conn_info,
-                client.put(wp, &args.key, &args.bins).await
-            )
-        }
+///
+/// With `return_meta`, returns the record's header (generation and TTL)
+/// after the write. A `clear_bins` put removes the record and returns `None`.
+pub async fn do_put(client: &AsClient, args: PutArgs) -> PyResult<Option<Record>> {
+    if args.clear_bins {
+        return do_clear_bins(client, args).await.map(|()| None);
     }
+    let wp = match args.policy {
+        PutPolicy::Default => &*DEFAULT_WRITE_POLICY,
+        PutPolicy::Custom(ref wp) => wp,
+    };
+    traced_op!(
+        "put",
+        &args.key.namespace,
+        &args.key.set_name,
+        args.otel.parent_ctx,
+        args.otel.conn_info,
+        request: crate::tracing::PayloadStats::single(&args.bins),
+        {
+            if args.return_meta {
+                let mut ops: Vec<Operation> = args.bins.iter().map(operations::put).collect();
+                ops.push(operations::get_header());
+                client.operate(wp, &args.key, &ops).await.map(Some)
+            } else {
+                client.put(wp, &args.key, &args.bins).await.map(|()| None)
+            }
+        }
+    )
+    .map_err(|e| with_key(e, &args.key))
+}
+
+/// Delete every bin of a record. The server removes a record left without
+/// bins, so this is a delete that succeeds whether or not the record exists.
+async fn do_clear_bins(client: &AsClient, args: PutArgs) -> PyResult<()> {
+    let wp = match args.policy {
+        PutPolicy::Default => &*DEFAULT_WRITE_POLICY,
+        PutPolicy::Custom(ref wp) => wp,
+    };
+    traced_op!(
+        "put",
+        &args.key.namespace,
+        &args.key.set_name,
+        args.otel.parent_ctx,
+        args.otel.conn_info,
+        request: crate::tracing::PayloadStats::key(),
+        client.delete(wp, &args.key).await
+    )
+    .map(|_existed| ())
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Read all bins of a record.
@@ -63,22 +93,28 @@ pub async fn do_get(client: &AsClient, args: &GetArgs) -> PyResult<Record> {
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
-        client.get(rp, &args.key, Bins::All).await
+        request: crate::tracing::PayloadStats::key(),
+        with_retry(args.retry.as_ref(), "get", || client.get(rp, &args.key, Bins::All)).await
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Read selected bins of a record.
 pub async fn do_select(client: &AsClient, args: &SelectArgs) -> PyResult<Record> {
     let rp = args.read_policy();
-    let bins_selector = args.bins_selector();
     traced_op!(
         "select",
         &args.key.namespace,
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
-        client.get(rp, &args.key, bins_selector).await
+        request: crate::tracing::PayloadStats::key(),
+        with_retry(args.retry.as_ref(), "select", || {
+            client.get(rp, &args.key, args.bins_selector())
+        })
+        .await
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Check if a record exists. Returns the raw Result so callers can handle
@@ -90,7 +126,10 @@ pub async fn do_exists(client: &AsClient, args: &ExistsArgs) -> Result<Record, A
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
-        client.get(&args.read_policy, &args.key, Bins::None).await
+        with_retry(args.retry.as_ref(), "exists", || {
+            client.get(&args.read_policy, &args.key, Bins::None)
+        })
+        .await
     )
 }
 
@@ -102,12 +141,15 @@ pub async fn do_remove(client: &AsClient, args: RemoveArgs) -> PyResult<()> {
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::key(),
         client.delete(&args.write_policy, &args.key).await
-    )?;
+    )
+    .map_err(|e| with_key(e, &args.key))?;
 
     if !existed {
-        return Err(crate::errors::RecordNotFound::new_err(
-            "AEROSPIKE_ERR (2): Record not found",
+        return Err(with_key(
+            crate::errors::RecordNotFound::new_err("AEROSPIKE_ERR (2): Record not found"),
+            &args.key,
         ));
     }
     Ok(())
@@ -121,52 +163,98 @@ pub async fn do_touch(client: &AsClient, args: TouchArgs) -> PyResult<()> {
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::key(),
         client.touch(&args.write_policy, &args.key).await
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Append string values to bins.
-pub async fn do_append(client: &AsClient, args: SingleBinWriteArgs) -> PyResult<()> {
+///
+/// With `return_value`, returns the bin's value after the write.
+pub async fn do_append(client: &AsClient, args: SingleBinWriteArgs) -> PyResult<Option<Value>> {
     traced_op!(
         "append",
         &args.key.namespace,
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::single(&args.bins),
         {
-            client
-                .append(&args.write_policy, &args.key, &args.bins)
-                .await
+            if args.return_value {
+                write_then_read(client, &args, operations::append).await
+            } else {
+                client
+                    .append(&args.write_policy, &args.key, &args.bins)
+                    .await
+                    .map(|()| None)
+            }
         }
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Prepend string values to bins.
-pub async fn do_prepend(client: &AsClient, args: SingleBinWriteArgs) -> PyResult<()> {
+///
+/// With `return_value`, returns the bin's value after the write.
+pub async fn do_prepend(client: &AsClient, args: SingleBinWriteArgs) -> PyResult<Option<Value>> {
     traced_op!(
         "prepend",
         &args.key.namespace,
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::single(&args.bins),
         {
-            client
-                .prepend(&args.write_policy, &args.key, &args.bins)
-                .await
+            if args.return_value {
+                write_then_read(client, &args, operations::prepend).await
+            } else {
+                client
+                    .prepend(&args.write_policy, &args.key, &args.bins)
+                    .await
+                    .map(|()| None)
+            }
         }
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Increment/add to numeric bins.
-pub async fn do_increment(client: &AsClient, args: SingleBinWriteArgs) -> PyResult<()> {
+///
+/// With `return_value`, returns the bin's value after the write.
+pub async fn do_increment(client: &AsClient, args: SingleBinWriteArgs) -> PyResult<Option<Value>> {
     traced_op!(
         "increment",
         &args.key.namespace,
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
-        client.add(&args.write_policy, &args.key, &args.bins).await
+        request: crate::tracing::PayloadStats::single(&args.bins),
+        {
+            if args.return_value {
+                write_then_read(client, &args, operations::add).await
+            } else {
+                client
+                    .add(&args.write_policy, &args.key, &args.bins)
+                    .await
+                    .map(|()| None)
+            }
+        }
     )
+    .map_err(|e| with_key(e, &args.key))
+}
+
+/// Apply `write` to the single bin of `args` and read the bin back in the
+/// same `operate()` command, so the value returned is the one just written.
+async fn write_then_read(
+    client: &AsClient,
+    args: &SingleBinWriteArgs,
+    write: fn(&Bin) -> Operation,
+) -> Result<Option<Value>, AsError> {
+    let bin = &args.bins[0];
+    let ops = [write(bin), operations::get_bin(&bin.name)];
+    let mut record = client.operate(&args.write_policy, &args.key, &ops).await?;
+    Ok(Some(record.bins.remove(&bin.name).unwrap_or(Value::Nil)))
 }
 
 /// Remove bins from a record by setting them to nil.
@@ -177,8 +265,10 @@ pub async fn do_remove_bin(client: &AsClient, args: RemoveBinArgs) -> PyResult<(
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::single(&args.bins),
         client.put(&args.write_policy, &args.key, &args.bins).await
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 // ── Multi-operation ─────────────────────────────────────────────────────────
@@ -191,12 +281,14 @@ pub async fn do_operate(client: &AsClient, args: &OperateArgs) -> PyResult<Recor
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::key(),
         {
             client
                 .operate(&args.write_policy, &args.key, &args.ops)
                 .await
         }
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 /// Perform multiple operations on a single record (ordered variant).
@@ -208,12 +300,14 @@ pub async fn do_operate_ordered(client: &AsClient, args: &OperateArgs) -> PyResu
         &args.key.set_name,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::key(),
         {
             client
                 .operate(&args.write_policy, &args.key, &args.ops)
                 .await
         }
     )
+    .map_err(|e| with_key(e, &args.key))
 }
 
 // ── Batch ───────────────────────────────────────────────────────────────────
@@ -227,7 +321,112 @@ pub async fn do_batch_read(client: &AsClient, args: &BatchReadArgs) -> PyResult<
         &args.batch_set,
         args.otel.parent_ctx,
         args.otel.conn_info,
-        client.batch(&args.batch_policy, &ops).await
+        request: crate::tracing::PayloadStats::batch(ops.len()),
+        with_retry(args.retry.as_ref(), "batch_read", || {
+            client.batch(&args.batch_policy, &ops)
+        })
+        .await
+    )
+}
+
+/// Default keys per sub-batch for `batch_read_chunked`.
+pub const DEFAULT_BATCH_CHUNK_SIZE: usize = 1000;
+/// Default number of `batch_read_chunked` sub-batches in flight at once.
+pub const DEFAULT_BATCH_CHUNK_CONCURRENCY: usize = 8;
+
+/// Read a batch as sub-batches of `chunk_size` keys, at most `concurrency` in flight.
+///
+/// Each sub-batch takes its own limiter slot. Results are returned in key order;
+/// the first failed sub-batch fails the whole call.
+pub async fn do_batch_read_chunked(
+    client: &AsClient,
+    limiter: &OperationLimiter,
+    args: &BatchReadArgs,
+    chunk_size: usize,
+    concurrency: usize,
+) -> PyResult<Vec<BatchRecord>> {
+    let ops = args.to_batch_ops();
+    let ops = &ops;
+    // Iterate chunk offsets rather than `ops.chunks()` so the per-chunk future
+    // borrows `ops` with one concrete lifetime (keeps the future `Send`).
+    let chunks: Vec<Vec<BatchRecord>> = futures::stream::iter((0..ops.len()).step_by(chunk_size))
+        .map(|start| async move {
+            let chunk = &ops[start..ops.len().min(start + chunk_size)];
+            let _permit = limiter.acquire_named("batch_read").await?;
+            traced_op!(
+                "batch_read",
+                &args.batch_ns,
+                &args.batch_set,
+                args.otel.parent_ctx,
+                args.otel.conn_info,
+                request: crate::tracing::PayloadStats::batch(chunk.len()),
+                with_retry(args.retry.as_ref(), "batch_read", || {
+                    client.batch(&args.batch_policy, chunk)
+                })
+                .await
+            )
+        })
+        .buffered(concurrency)
+        .try_collect()
+        .await?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// Write `bulk_put` chunks as batches, at most `args.concurrency` in flight.
+///
+/// Each batch takes its own limiter slot. Returns one result per chunk, in
+/// chunk order; a failed batch does not stop the others.
+pub async fn do_bulk_put(
+    client: &AsClient,
+    limiter: &OperationLimiter,
+    args: &BulkPutArgs,
+    chunks: &[BulkPutChunk],
+) -> Vec<PyResult<Vec<BatchRecord>>> {
+    // Iterate chunk indexes for the same reason as `do_batch_read_chunked`.
+    futures::stream::iter(0..chunks.len())
+        .map(|i| async move {
+            let chunk = &chunks[i];
+            let _permit = limiter.acquire_named("bulk_put").await?;
+            let batch_ops: Vec<BatchOperation> = chunk
+                .records
+                .iter()
+                .map(|(key, bins, write_policy)| {
+                    let ops = bins.iter().map(aerospike_core::operations::put).collect();
+                    BatchOperation::write(write_policy, key.clone(), ops)
+                })
+                .collect();
+            traced_op!(
+                "bulk_put",
+                &args.namespace,
+                &args.set_name,
+                args.otel.parent_ctx,
+                args.otel.conn_info,
+                request: crate::tracing::PayloadStats::batch_write(&chunk.records),
+                client.batch(&args.batch_policy, &batch_ops).await
+            )
+        })
+        .buffered(args.concurrency)
+        .collect()
+        .await
+}
+
+/// Check existence of multiple records in a batch (header-only read).
+pub async fn do_batch_exists(
+    client: &AsClient,
+    args: &BatchReadArgs,
+) -> PyResult<Vec<BatchRecord>> {
+    let ops = args.to_batch_ops();
+    traced_op!(
+        "batch_exists",
+        &args.batch_ns,
+        &args.batch_set,
+        args.otel.parent_ctx,
+        args.otel.conn_info,
+        request: crate::tracing::PayloadStats::batch(ops.len()),
+        with_retry(args.retry.as_ref(), "batch_exists", || {
+            client.batch(&args.batch_policy, &ops)
+        })
+        .await
     )
 }
 
@@ -243,6 +442,7 @@ pub async fn do_batch_operate(
         &args.batch_set,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::batch(batch_ops.len()),
         client.batch(&args.batch_policy, &batch_ops).await
     )
 }
@@ -259,6 +459,7 @@ pub async fn do_batch_remove(
         &args.batch_set,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::batch(ops.len()),
         client.batch(&args.batch_policy, &ops).await
     )
 }
@@ -275,6 +476,7 @@ pub async fn do_batch_apply(
         &args.batch_set,
         args.otel.parent_ctx,
         args.otel.conn_info,
+        request: crate::tracing::PayloadStats::batch(ops.len()),
         client.batch(&args.batch_policy, &ops).await
     )
 }
@@ -296,17 +498,6 @@ fn is_retryable_result_code(rc: &aerospike_core::ResultCode) -> bool {
     )
 }
 
-/// Compute backoff duration in milliseconds using Full Jitter strategy.
-///
-/// Returns a random value in `[0, min(cap_ms, base_ms * 2^attempt)]`.
-/// The shift exponent is capped at 6 to prevent overflow (`10 * 2^6 = 640 > 500`).
-fn compute_backoff_ms(attempt: u32, base_ms: u64, cap_ms: u64) -> u64 {
-    use rand::RngExt;
-    let capped_attempt = std::cmp::min(attempt, 6);
-    let max_backoff = std::cmp::min(base_ms * (1u64 << capped_attempt), cap_ms);
-    rand::rng().random_range(0..=max_backoff)
-}
-
 /// Collect indices of batch records with retryable error codes into `out`.
 ///
 /// Clears `out` first, then appends indices of records whose `result_code`
@@ -372,6 +563,7 @@ pub async fn do_batch_write(
             set,
             parent_ctx,
             conn_info,
+            request: crate::tracing::PayloadStats::batch_write(records),
             client.batch(batch_policy, &batch_ops).await
         );
     }
@@ -397,6 +589,7 @@ pub async fn do_batch_write(
         set,
         parent_ctx,
         conn_info,
+        request: crate::tracing::PayloadStats::batch_write(records),
         client.batch(batch_policy, &batch_ops).await
     )?;
 
@@ -458,6 +651,7 @@ pub async fn do_batch_write(
             set,
             parent_ctx,
             conn_info,
+            request: crate::tracing::PayloadStats::batch(retry_ops.len()).with_retry(attempt + 1),
             client.batch(batch_policy, &retry_ops).await
         ) {
             Ok(r) => r,
@@ -490,17 +684,35 @@ pub async fn do_batch_write(
 
 // ── Info ────────────────────────────────────────────────────────────────────
 
-/// Send an info command to all nodes.
+/// Per-node `info_all` timeout when the admin policy sets none (core's default).
+const DEFAULT_INFO_TIMEOUT_MS: u32 = 3_000;
+
+/// Send an info command to all nodes concurrently.
+///
+/// Each node gets the admin policy `timeout` for the whole request, connection
+/// checkout included, so one slow node costs at most one timeout instead of
+/// delaying the others. Results keep the `client.nodes()` order.
 pub async fn do_info_all(
     client: &AsClient,
     args: &InfoArgs,
 ) -> PyResult<Vec<(String, i32, String)>> {
     let nodes = client.nodes();
-    let mut results = Vec::new();
-    for node in &nodes {
-        let r = node.info(&args.admin_policy, &[&args.command]).await;
-        results.push(client_common::info_node_result(node, &args.command, r));
-    }
+    let timeout_ms = match args.admin_policy.timeout {
+        0 => DEFAULT_INFO_TIMEOUT_MS,
+        ms => ms,
+    };
+    let timeout = std::time::Duration::from_millis(u64::from(timeout_ms));
+    let results = futures::future::join_all(nodes.iter().map(|node| async move {
+        match tokio::time::timeout(timeout, node.info(&args.admin_policy, &[&args.command])).await {
+            Ok(r) => client_common::info_node_result(node, &args.command, r),
+            Err(_) => (
+                node.name().to_string(),
+                crate::errors::ERR_TIMEOUT,
+                format!("Info command timed out after {timeout_ms} ms"),
+            ),
+        }
+    }))
+    .await;
     Ok(results)
 }
 
@@ -514,6 +726,144 @@ pub async fn do_info_random_node(client: &AsClient, args: &InfoArgs) -> PyResult
     Ok(map.get(&args.command).cloned().unwrap_or_default())
 }
 
+/// Find a node by name, or by `host:port` (its address or any alias).
+fn find_node(client: &AsClient, node: &str) -> PyResult<Arc<aerospike_core::Node>> {
+    client
+        .nodes()
+        .into_iter()
+        .find(|n| {
+            n.name() == node
+                || n.address() == node
+                || n.host().to_string() == node
+                || n.aliases().iter().any(|h| h.to_string() == node)
+        })
+        .ok_or_else(|| {
+            as_to_pyerr(AsError::InvalidNode(format!(
+                "no node named or listening on '{node}'"
+            )))
+        })
+}
+
+/// Send an info command to one node, chosen by name or `host:port`.
+pub async fn do_info_single_node(
+    client: &AsClient,
+    args: &InfoArgs,
+    node: &str,
+) -> PyResult<String> {
+    let node = find_node(client, node)?;
+    let mut map = node
+        .info(&args.admin_policy, &[&args.command])
+        .await
+        .map_err(as_to_pyerr)?;
+    Ok(map.remove(&args.command).unwrap_or_default())
+}
+
+/// Send an info command to `node` (name or `host:port`), or a random node.
+pub async fn do_info_node(
+    client: &AsClient,
+    args: &InfoArgs,
+    node: Option<&str>,
+) -> PyResult<String> {
+    match node {
+        Some(node) => do_info_single_node(client, args, node).await,
+        None => do_info_random_node(client, args).await,
+    }
+}
+
+/// Collect per-node stats; namespaces come from `info("namespaces")` on each node.
+///
+/// A node that fails the info call is still reported, with no partitions.
+pub async fn do_get_cluster_stats(
+    client: &AsClient,
+    breaker: &CircuitBreaker,
+) -> std::collections::BTreeMap<String, client_common::NodeStats> {
+    let policy = aerospike_core::AdminPolicy::default();
+    let max_conns = client.cluster.client_policy().max_conns_per_node;
+    let nodes = client.nodes();
+    let stats = futures::future::join_all(nodes.iter().map(|node| {
+        let policy = &policy;
+        async move {
+            let namespaces = node
+                .info(policy, &["namespaces"])
+                .await
+                .ok()
+                .and_then(|mut map| map.remove("namespaces"))
+                .unwrap_or_default();
+            let partitions = namespaces
+                .split(';')
+                .filter(|ns| !ns.is_empty())
+                .map(|ns| {
+                    let count = client.cluster.node_partitions(node, ns).len();
+                    (ns.to_string(), count)
+                })
+                .collect();
+            let (circuit_state, circuit_trips) = breaker.node_state(node.name());
+            let stats = client_common::NodeStats {
+                address: node.host().to_string(),
+                active: node.is_active(),
+                failures: node.failures(),
+                partition_generation: node.partition_generation(),
+                rebalance_generation: node.rebalance_generation(),
+                partitions,
+                max_conns,
+                circuit_state: circuit_state.as_str(),
+                circuit_trips,
+            };
+            (node.name().to_string(), stats)
+        }
+    }))
+    .await;
+    stats.into_iter().collect()
+}
+
+/// List the cluster's nodes; the rack comes from one `info("rack-ids")` per node.
+///
+/// A node that fails the info call is still listed, with no rack.
+pub async fn do_get_nodes(client: &AsClient) -> Vec<client_common::NodeInfo> {
+    let policy = aerospike_core::AdminPolicy::default();
+    let nodes = client.nodes();
+    futures::future::join_all(nodes.iter().map(|node| {
+        let policy = &policy;
+        async move {
+            let rack_id = node
+                .info(policy, &["rack-ids"])
+                .await
+                .ok()
+                .and_then(|mut map| map.remove("rack-ids"))
+                .and_then(|racks| client_common::parse_rack_id(&racks));
+            let host = node.host();
+            client_common::NodeInfo {
+                name: node.name().to_string(),
+                address: host.name,
+                port: host.port,
+                active: node.is_active(),
+                partition_generation: node.partition_generation(),
+                rack_id,
+            }
+        }
+    }))
+    .await
+}
+
+/// Master node of `key`'s partition in the client's current partition map.
+pub fn node_for_key(
+    client: &AsClient,
+    key: &aerospike_core::Key,
+) -> PyResult<client_common::KeyNode> {
+    let partition_id = crate::types::key::partition_id(&key.digest);
+    let node = client
+        .cluster
+        .get_master_node(&key.namespace, partition_id)
+        .map_err(as_to_pyerr)?;
+    let host = node.host();
+    Ok(client_common::KeyNode {
+        name: node.name().to_string(),
+        address: host.name,
+        port: host.port,
+        partition_id,
+    })
+}
+
 /// Lightweight health check: send `info("build")` to a random node.
 /// Returns `true` if the node responds, `false` otherwise.
 pub async fn do_ping(client: &AsClient) -> bool {
@@ -525,10 +875,52 @@ pub async fn do_ping(client: &AsClient) -> bool {
     node.info(&policy, &["build"]).await.is_ok()
 }
 
+/// Open up to `conns_per_node` pooled connections to every node.
+///
+/// Connections are checked out concurrently and held until all are open, so
+/// the pool creates new ones instead of handing back the same idle one;
+/// dropping them returns them to the pool. Returns the number of connections
+/// held across all nodes (already-idle ones included).
+pub async fn do_warm_up(client: &AsClient, conns_per_node: usize) -> usize {
+    let nodes = client.nodes();
+    let per_node = nodes.iter().map(|node| async move {
+        let conns = futures::future::join_all(
+            (0..conns_per_node).map(|i| node.get_connection((i % 256) as u8)),
+        )
+        .await;
+        let opened = conns.iter().filter(|c| c.is_ok()).count();
+        if opened < conns_per_node {
+            log::warn!(
+                "warm_up: node {} opened {}/{} connections",
+                node.name(),
+                opened,
+                conns_per_node
+            );
+        }
+        opened
+    });
+    futures::future::join_all(per_node).await.into_iter().sum()
+}
+
 // ── Truncate ────────────────────────────────────────────────────────────────
 
 /// Truncate records in a namespace/set.
 pub async fn do_truncate(client: &AsClient, args: TruncateArgs) -> PyResult<()> {
+    let set_exists = match &args.wait {
+        Some(_) if !args.set_name.is_empty() => {
+            let stats = set_stats(client, &args.admin_policy, &args.namespace).await?;
+            stats
+                .iter()
+                .any(|sets| find_set(sets, &args.set_name).is_some())
+        }
+        _ => true,
+    };
+    if !set_exists {
+        return Err(crate::errors::ParamError::new_err(format!(
+            "Set does not exist: {}.{}",
+            args.namespace, args.set_name
+        )));
+    }
     client
         .truncate(
             &args.admin_policy,
@@ -537,7 +929,92 @@ pub async fn do_truncate(client: &AsClient, args: TruncateArgs) -> PyResult<()>
             args.nanos,
         )
         .await
-        .map_err(as_to_pyerr)
+        .map_err(as_to_pyerr)?;
+    if let Some(timeout) = args.wait {
+        wait_for_truncate(client, &args, timeout).await?;
+    }
+    Ok(())
+}
+
+/// Interval between set-statistics polls while waiting for a truncate.
+const TRUNCATE_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+
+/// `sets/<ns>` parsed on every node.
+async fn set_stats(
+    client: &AsClient,
+    admin_policy: &aerospike_core::AdminPolicy,
+    namespace: &str,
+) -> PyResult<Vec<Vec<info_parsers::InfoRecord>>> {
+    let command = format!("sets/{namespace}");
+    let commands = [command.as_str()];
+    let nodes = client.nodes();
+    let responses =
+        futures::future::join_all(nodes.iter().map(|node| node.info(admin_policy, &commands)))
+            .await;
+    responses
+        .into_iter()
+        .map(|r| {
+            let mut map = r.map_err(as_to_pyerr)?;
+            let resp = map.remove(&command).unwrap_or_default();
+            Ok(info_parsers::parse_records(
+                info_parsers::check_response(&resp)?,
+                ':',
+            ))
+        })
+        .collect()
+}
+
+fn find_set<'a>(
+    sets: &'a [info_parsers::InfoRecord],
+    set_name: &str,
+) -> Option<&'a info_parsers::InfoRecord> {
+    sets.iter().find(|set| {
+        matches!(set.get("set"), Some(info_parsers::InfoValue::Str(name)) if name == set_name)
+    })
+}
+
+/// Whether every node has finished truncating `set_name` (all sets when empty).
+///
+/// A set is done once it no longer reports `truncating=true` and, for a full
+/// truncate (`nanos == 0`), holds no objects.
+fn truncate_done(node_sets: &[Vec<info_parsers::InfoRecord>], set_name: &str, nanos: i64) -> bool {
+    use info_parsers::InfoValue;
+    node_sets.iter().flatten().all(|set| {
+        let in_scope = set_name.is_empty()
+            || matches!(set.get("set"), Some(InfoValue::Str(name)) if name == set_name);
+        let truncating = matches!(set.get("truncating"), Some(InfoValue::Str(v)) if v == "true");
+        let emptied = nanos != 0 || matches!(set.get("objects"), Some(InfoValue::Int(0)) | None);
+        !in_scope || (!truncating && emptied)
+    })
+}
+
+/// Whether a truncate started with `args` has finished on every node.
+pub async fn do_truncate_done(client: &AsClient, args: &TruncateArgs) -> PyResult<bool> {
+    let stats = set_stats(client, &args.admin_policy, &args.namespace).await?;
+    Ok(truncate_done(&stats, &args.set_name, args.nanos))
+}
+
+async fn wait_for_truncate(
+    client: &AsClient,
+    args: &TruncateArgs,
+    timeout: Option<std::time::Duration>,
+) -> PyResult<()> {
+    let start = std::time::Instant::now();
+    loop {
+        let stats = set_stats(client, &args.admin_policy, &args.namespace).await?;
+        if truncate_done(&stats, &args.set_name, args.nanos) {
+            return Ok(());
+        }
+        if timeout.is_some_and(|t| start.elapsed() >= t) {
+            return Err(as_to_pyerr(AsError::Timeout(format!(
+                "truncate of {}.{} did not complete within {:?}",
+                args.namespace,
+                args.set_name,
+                start.elapsed()
+            ))));
+        }
+        tokio::time::sleep(TRUNCATE_POLL_INTERVAL).await;
+    }
 }
 
 // ── UDF ─────────────────────────────────────────────────────────────────────
@@ -553,9 +1030,11 @@ pub async fn do_udf_put(client: &AsClient, args: UdfPutArgs) -> PyResult<()> {
         )
         .await
         .map_err(as_to_pyerr)?;
-    task.wait_till_complete(None::<std::time::Duration>)
-        .await
-        .map_err(as_to_pyerr)?;
+    if args.wait {
+        task.wait_till_complete(None::<std::time::Duration>)
+            .await
+            .map_err(as_to_pyerr)?;
+    }
     Ok(())
 }
 
@@ -565,12 +1044,44 @@ pub async fn do_udf_remove(client: &AsClient, args: UdfRemoveArgs) -> PyResult<(
         .remove_udf(&args.admin_policy, &args.server_path)
         .await
         .map_err(as_to_pyerr)?;
-    task.wait_till_complete(None::<std::time::Duration>)
-        .await
-        .map_err(as_to_pyerr)?;
+    if args.wait {
+        task.wait_till_complete(None::<std::time::Duration>)
+            .await
+            .map_err(as_to_pyerr)?;
+    }
     Ok(())
 }
 
+/// Whether a UDF registration (or, with `removed`, a removal) has reached
+/// every node: each node's `udf-list` has (or lacks) `server_path`.
+pub async fn do_udf_done(
+    client: &AsClient,
+    admin_policy: &aerospike_core::AdminPolicy,
+    server_path: &str,
+    removed: bool,
+) -> PyResult<bool> {
+    let nodes = client.nodes();
+    let responses = futures::future::join_all(
+        nodes
+            .iter()
+            .map(|node| node.info(admin_policy, &["udf-list"])),
+    )
+    .await;
+    for response in responses {
+        let mut map = response.map_err(as_to_pyerr)?;
+        let resp = map.remove("udf-list").unwrap_or_default();
+        let registered = info_parsers::parse_udf_list(info_parsers::check_response(&resp)?)
+            .iter()
+            .any(|udf| {
+                matches!(udf.get("name"), Some(info_parsers::InfoValue::Str(name)) if name == server_path)
+            });
+        if registered == removed {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
 /// Execute a UDF on a single record.
 pub async fn do_apply(
     client: &AsClient,
@@ -585,13 +1096,16 @@ pub async fn do_apply(
             args.args.as_deref(),
         )
         .await
-        .map_err(as_to_pyerr)
+        .map_err(|e| {
+            let err = with_udf_call(as_to_pyerr(e), &args.module, &args.function);
+            with_key(err, &args.key)
+        })
 }
 
 // ── Index ───────────────────────────────────────────────────────────────────
 
-/// Create a secondary index.
-pub async fn do_index_create(client: &AsClient, args: IndexCreateArgs) -> PyResult<()> {
+/// Create a secondary index, waiting for the build to finish when `wait` is set.
+pub async fn do_index_create(client: &AsClient, args: IndexCreateArgs, wait: bool) -> PyResult<()> {
     let task = client
         .create_index_on_bin(
             &args.admin_policy,
@@ -605,12 +1119,119 @@ pub async fn do_index_create(client: &AsClient, args: IndexCreateArgs) -> PyResu
         )
         .await
         .map_err(as_to_pyerr)?;
-    task.wait_till_complete(None::<std::time::Duration>)
-        .await
-        .map_err(as_to_pyerr)?;
+    if wait {
+        task.wait_till_complete(None::<std::time::Duration>)
+            .await
+            .map_err(as_to_pyerr)?;
+    }
     Ok(())
 }
 
+/// Build progress of a secondary index: the lowest `load_pct` across nodes.
+pub async fn do_index_status(
+    client: &AsClient,
+    admin_policy: &aerospike_core::AdminPolicy,
+    namespace: &str,
+    index_name: &str,
+) -> PyResult<u32> {
+    let nodes = client.nodes();
+    if nodes.is_empty() {
+        return Err(as_to_pyerr(AsError::Connection(
+            "No connected node".to_string(),
+        )));
+    }
+    let results = futures::future::join_all(nodes.iter().map(|node| async move {
+        // Servers before 8.1 only know the legacy `sindex/<ns>/<name>` form.
+        let command = if node.version() >= &aerospike_core::Version::new(8, 1, 0, 0) {
+            format!("sindex-stat:namespace={namespace};indexname={index_name}")
+        } else {
+            format!("sindex/{namespace}/{index_name}")
+        };
+        let mut map = node
+            .info(admin_policy, &[&command])
+            .await
+            .map_err(as_to_pyerr)?;
+        info_parsers::parse_load_pct(&map.remove(&command).unwrap_or_default(), index_name)
+    }))
+    .await;
+    results
+        .into_iter()
+        .try_fold(100, |pct, node_pct| Ok(pct.min(node_pct?)))
+}
+
+// ── Jobs ────────────────────────────────────────────────────────────────────
+
+// Job status codes, as in the official client.
+const JOB_STATUS_UNDEF: i32 = 0;
+const JOB_STATUS_INPROGRESS: i32 = 1;
+const JOB_STATUS_COMPLETED: i32 = 2;
+
+/// The job monitor command for `node`'s server version.
+fn job_show_command(version: &aerospike_core::Version, job_id: u64, scan: bool) -> String {
+    let id_key = if version >= &aerospike_core::Version::new(8, 1, 0, 0) {
+        "id"
+    } else {
+        "trid"
+    };
+    // Servers from 6.0 track scans and queries in one monitor.
+    let module = if scan && !version.supports_partition_query() {
+        "scan"
+    } else {
+        "query"
+    };
+    if version.supports_query_show() {
+        format!("{module}-show:{id_key}={job_id}")
+    } else {
+        format!("jobs:module={module};cmd=get-job;{id_key}={job_id}")
+    }
+}
+
+/// Combine per-node job states: in progress while any node runs the job.
+fn merge_jobs(jobs: impl IntoIterator<Item = info_parsers::NodeJob>) -> client_common::JobInfo {
+    let mut info = client_common::JobInfo {
+        status: JOB_STATUS_UNDEF,
+        progress_pct: 100,
+        records_read: 0,
+    };
+    for job in jobs {
+        if !job.done {
+            info.status = JOB_STATUS_INPROGRESS;
+        } else if info.status == JOB_STATUS_UNDEF {
+            info.status = JOB_STATUS_COMPLETED;
+        }
+        info.progress_pct = info.progress_pct.min(job.progress_pct);
+        info.records_read += job.records_read;
+    }
+    if info.status == JOB_STATUS_UNDEF {
+        info.progress_pct = 0;
+    }
+    info
+}
+
+/// State of a scan / query job across the cluster.
+pub async fn do_job_info(
+    client: &AsClient,
+    args: &client_common::JobInfoArgs,
+) -> PyResult<client_common::JobInfo> {
+    let nodes = client.nodes();
+    if nodes.is_empty() {
+        return Err(as_to_pyerr(AsError::Connection(
+            "No connected node".to_string(),
+        )));
+    }
+    let results = futures::future::join_all(nodes.iter().map(|node| async move {
+        let command = job_show_command(node.version(), args.job_id, args.scan);
+        let mut map = node
+            .info(&args.admin_policy, &[&command])
+            .await
+            .map_err(as_to_pyerr)?;
+        info_parsers::parse_job(&map.remove(&command).unwrap_or_default())
+    }))
+    .await;
+    let jobs = results.into_iter().collect::<PyResult<Vec<_>>>()?;
+    Ok(merge_jobs(jobs.into_iter().flatten()))
+}
+
 /// Remove a secondary index.
 pub async fn do_index_remove(client: &AsClient, args: IndexRemoveArgs) -> PyResult<()> {
     client
@@ -702,6 +1323,31 @@ pub async fn do_admin_query_users(
         .map_err(as_to_pyerr)
 }
 
+/// Query the user this client authenticated as.
+///
+/// The username comes from the client's `auth_mode`; clients connected without
+/// credentials (or via PKI, where the server derives the user from the
+/// certificate) have no session user to look up.
+pub async fn do_admin_whoami(
+    client: &AsClient,
+    admin_policy: &aerospike_core::AdminPolicy,
+) -> PyResult<aerospike_core::User> {
+    let username = match client.cluster.client_policy().auth_mode {
+        aerospike_core::AuthMode::Internal(user, _)
+        | aerospike_core::AuthMode::External(user, _) => user,
+        _ => {
+            return Err(crate::errors::AdminError::new_err(
+                "No authenticated user: the client was connected without security credentials",
+            ))
+        }
+    };
+    let users = do_admin_query_users(client, admin_policy, Some(&username)).await?;
+    users
+        .into_iter()
+        .next()
+        .ok_or_else(|| crate::errors::AdminError::new_err(format!("User '{}' not found", username)))
+}
+
 // ── Admin: Role ─────────────────────────────────────────────────────────────
 
 /// Create a new role.
@@ -889,6 +1535,72 @@ mod tests {
         assert!(val <= 500);
     }
 
+    // ── job_info tests ─────────────────────────────────────────────────────
+
+    fn node_job(done: bool, progress_pct: u32, records_read: u64) -> info_parsers::NodeJob {
+        info_parsers::NodeJob {
+            done,
+            progress_pct,
+            records_read,
+        }
+    }
+
+    #[test]
+    fn test_merge_jobs() {
+        let info = merge_jobs([node_job(true, 100, 10), node_job(false, 40, 5)]);
+        assert_eq!(
+            info,
+            client_common::JobInfo {
+                status: JOB_STATUS_INPROGRESS,
+                progress_pct: 40,
+                records_read: 15,
+            }
+        );
+        assert_eq!(
+            merge_jobs([node_job(true, 100, 3)]).status,
+            JOB_STATUS_COMPLETED
+        );
+        let unknown = merge_jobs([]);
+        assert_eq!(
+            (unknown.status, unknown.progress_pct),
+            (JOB_STATUS_UNDEF, 0)
+        );
+    }
+
+    #[test]
+    fn test_job_show_command_by_server_version() {
+        use aerospike_core::Version;
+        assert_eq!(
+            job_show_command(&Version::new(8, 1, 0, 0), 7, true),
+            "query-show:id=7"
+        );
+        assert_eq!(
+            job_show_command(&Version::new(7, 0, 0, 0), 7, true),
+            "query-show:trid=7"
+        );
+        assert_eq!(
+            job_show_command(&Version::new(5, 7, 0, 0), 7, true),
+            "scan-show:trid=7"
+        );
+    }
+
+    // ── truncate_done tests ────────────────────────────────────────────────
+
+    #[test]
+    fn test_truncate_done_waits_for_objects_and_flag() {
+        let busy =
+            info_parsers::parse_records("set=a:objects=5:truncating=false;set=b:objects=3", ':');
+        assert!(!truncate_done(std::slice::from_ref(&busy), "a", 0));
+        assert!(truncate_done(std::slice::from_ref(&busy), "c", 0));
+        let flagged = info_parsers::parse_records("set=a:objects=0:truncating=true", ':');
+        assert!(!truncate_done(&[flagged], "a", 0));
+        let done = info_parsers::parse_records("set=a:objects=0:truncating=false", ':');
+        let other = info_parsers::parse_records("set=b:objects=3", ':');
+        assert!(truncate_done(&[done, other], "a", 0));
+        // With a cutoff newer records survive, so only the flag counts.
+        assert!(truncate_done(&[busy], "a", 1));
+    }
+
     // ── collect_retryable_indices tests ────────────────────────────────────
 
     /// Create a minimal `BatchRecord` for testing.
@@ -0,0 +1,267 @@
+//! Cluster topology event listener.
+//!
+//! `aerospike_core` exposes no hooks into its tend loop, so [`ClusterEvents`]
+//! runs a watcher task that snapshots the client's node list once per tend
+//! interval and reports the differences to a Python callback:
+//!
+//! - `node_added` / `node_removed` when a node joins or leaves the cluster view,
+//! - `partition_map_changed` when a node's partition generation moves.
+//!
+//! The first snapshot taken after `connect()` (or after registering the
+//! callback on a connected client) is the baseline and produces no events.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use aerospike_core::Client as AsClient;
+use log::{debug, warn};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+/// Per-node state compared between two snapshots.
+#[derive(Debug, Clone, PartialEq)]
+struct NodeSnapshot {
+    host: String,
+    partition_generation: isize,
+}
+
+type Snapshot = BTreeMap<String, NodeSnapshot>;
+
+/// One topology change reported to the callback.
+#[derive(Debug, PartialEq)]
+enum ClusterEvent {
+    NodeAdded {
+        node: String,
+        host: String,
+    },
+    NodeRemoved {
+        node: String,
+        host: String,
+    },
+    PartitionMapChanged {
+        node: String,
+        host: String,
+        generation: isize,
+    },
+}
+
+impl ClusterEvent {
+    fn to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        let (kind, node, host) = match self {
+            ClusterEvent::NodeAdded { node, host } => ("node_added", node, host),
+            ClusterEvent::NodeRemoved { node, host } => ("node_removed", node, host),
+            ClusterEvent::PartitionMapChanged {
+                node,
+                host,
+                generation,
+            } => {
+                dict.set_item("partition_generation", *generation)?;
+                ("partition_map_changed", node, host)
+            }
+        };
+        dict.set_item("event", kind)?;
+        dict.set_item("node", node)?;
+        dict.set_item("host", host)?;
+        Ok(dict)
+    }
+}
+
+fn snapshot(client: &AsClient) -> Snapshot {
+    client
+        .nodes()
+        .iter()
+        .map(|node| {
+            (
+                node.name().to_string(),
+                NodeSnapshot {
+                    host: node.host().to_string(),
+                    partition_generation: node.partition_generation(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Events that turn `prev` into `next`, ordered by node name.
+fn diff(prev: &Snapshot, next: &Snapshot) -> Vec<ClusterEvent> {
+    let mut events = Vec::new();
+    for (name, node) in next {
+        match prev.get(name) {
+            None => events.push(ClusterEvent::NodeAdded {
+                node: name.clone(),
+                host: node.host.clone(),
+            }),
+            Some(old) if old.partition_generation != node.partition_generation => {
+                events.push(ClusterEvent::PartitionMapChanged {
+                    node: name.clone(),
+                    host: node.host.clone(),
+                    generation: node.partition_generation,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    for (name, node) in prev {
+        if !next.contains_key(name) {
+            events.push(ClusterEvent::NodeRemoved {
+                node: name.clone(),
+                host: node.host.clone(),
+            });
+        }
+    }
+    events
+}
+
+#[derive(Default)]
+struct ListenerState {
+    callback: Option<Arc<Py<PyAny>>>,
+    task: Option<JoinHandle<()>>,
+}
+
+/// Cluster event callback and its watcher task, shared by a client's
+/// `connect()`, `close()` and `on_cluster_event()`.
+#[derive(Clone, Default)]
+pub struct ClusterEvents(Arc<Mutex<ListenerState>>);
+
+impl ClusterEvents {
+    fn state(&self) -> std::sync::MutexGuard<'_, ListenerState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Validate the `callback` argument of `on_cluster_event()`.
+    pub fn parse_callback(callback: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Py<PyAny>>> {
+        match callback {
+            Some(cb) if !cb.is_callable() => Err(crate::errors::ParamError::new_err(
+                "callback must be callable or None",
+            )),
+            cb => Ok(cb.map(|cb| cb.clone().unbind())),
+        }
+    }
+
+    /// Replace the callback; `None` also stops the watcher.
+    pub fn set_callback(&self, callback: Option<Py<PyAny>>) {
+        let mut state = self.state();
+        state.callback = callback.map(Arc::new);
+        if state.callback.is_none() {
+            if let Some(task) = state.task.take() {
+                task.abort();
+            }
+        }
+    }
+
+    /// (Re)start the watcher for `client` on `handle` if a callback is set.
+    pub fn start(&self, client: Arc<AsClient>, handle: &Handle) {
+        let mut state = self.state();
+        if let Some(task) = state.task.take() {
+            task.abort();
+        }
+        if state.callback.is_none() {
+            return;
+        }
+        let interval = Duration::from_millis(u64::from(
+            client.cluster.client_policy().tend_interval.max(1),
+        ));
+        debug!("Starting cluster event watcher: interval={:?}", interval);
+        state.task = Some(handle.spawn(watch(client, Arc::downgrade(&self.0), interval)));
+    }
+
+    /// Stop the watcher; the callback stays registered for the next `connect()`.
+    pub fn stop(&self) {
+        if let Some(task) = self.state().task.take() {
+            task.abort();
+        }
+    }
+
+    /// Discard a watcher inherited across `os.fork()` without aborting it:
+    /// its runtime has no threads in this process.
+    pub fn forget_task(&self) {
+        std::mem::forget(self.state().task.take());
+    }
+}
+
+/// Poll `client` every `interval` until the owning client is dropped.
+async fn watch(client: Arc<AsClient>, state: Weak<Mutex<ListenerState>>, interval: Duration) {
+    let mut prev = snapshot(&client);
+    loop {
+        tokio::time::sleep(interval).await;
+        let Some(state) = state.upgrade() else {
+            return;
+        };
+        let next = snapshot(&client);
+        let events = diff(&prev, &next);
+        prev = next;
+        if events.is_empty() {
+            continue;
+        }
+        let callback = state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .callback
+            .clone();
+        let Some(callback) = callback else {
+            return;
+        };
+        Python::attach(|py| {
+            for event in &events {
+                let result = event.to_py(py).and_then(|dict| callback.call1(py, (dict,)));
+                if let Err(e) = result {
+                    warn!("Cluster event callback raised: {e}");
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snap(nodes: &[(&str, isize)]) -> Snapshot {
+        nodes
+            .iter()
+            .map(|(name, generation)| {
+                (
+                    name.to_string(),
+                    NodeSnapshot {
+                        host: format!("{name}:3000"),
+                        partition_generation: *generation,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn diff_reports_added_removed_and_partition_changes() {
+        let prev = snap(&[("A", 1), ("B", 1)]);
+        let next = snap(&[("A", 2), ("C", 1)]);
+        assert_eq!(
+            diff(&prev, &next),
+            vec![
+                ClusterEvent::PartitionMapChanged {
+                    node: "A".into(),
+                    host: "A:3000".into(),
+                    generation: 2,
+                },
+                ClusterEvent::NodeAdded {
+                    node: "C".into(),
+                    host: "C:3000".into(),
+                },
+                ClusterEvent::NodeRemoved {
+                    node: "B".into(),
+                    host: "B:3000".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_of_identical_snapshots_is_empty() {
+        let s = snap(&[("A", 3)]);
+        assert!(diff(&s, &s).is_empty());
+    }
+}
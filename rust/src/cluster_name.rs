@@ -0,0 +1,160 @@
+//! `cluster_name` enforcement.
+//!
+//! `aerospike_core` drops nodes whose `cluster-name` differs from the
+//! configured one but only logs why, so a client pointed at the wrong
+//! cluster fails `connect()` with a generic connection error and a foreign
+//! node met during tend is skipped silently. The clients therefore connect
+//! with the name unset in the core policy and check it here instead:
+//!
+//! - `connect()` asks every node for `cluster-name` and raises `ClusterError`
+//!   (closing the new client) when one reports another name;
+//! - [`ClusterNameGuard`] asks each node that joins later, once per tend
+//!   interval. A mismatch is logged and every later command of the client
+//!   raises `ClusterError` until it is reconnected.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aerospike_core::{AdminPolicy, Client as AsClient, Node};
+use log::{debug, error};
+use pyo3::prelude::*;
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+use crate::errors::ClusterError;
+
+/// The mismatch message for a node reporting `reported`, or `None` if it matches.
+fn mismatch(expected: &str, node: &str, host: &str, reported: Option<&str>) -> Option<String> {
+    match reported {
+        Some(name) if name == expected => None,
+        reported => Some(format!(
+            "Cluster name mismatch: expected '{expected}', node {node} at {host} reports '{}'",
+            reported.unwrap_or("")
+        )),
+    }
+}
+
+/// Ask `node` for its cluster name. `Ok(false)` if it could not be asked
+/// (it is asked again at the next tend).
+async fn check_node(node: &Node, expected: &str) -> Result<bool, String> {
+    match node.info(&AdminPolicy::default(), &["cluster-name"]).await {
+        Ok(info) => {
+            let reported = info.get("cluster-name").map(String::as_str);
+            match mismatch(expected, node.name(), &node.host().to_string(), reported) {
+                Some(message) => Err(message),
+                None => Ok(true),
+            }
+        }
+        Err(e) => {
+            debug!("cluster-name of node {} unavailable: {e}", node.name());
+            Ok(false)
+        }
+    }
+}
+
+/// Check every node of a freshly connected client.
+pub async fn verify(client: &AsClient, expected: &str) -> PyResult<()> {
+    for node in client.nodes() {
+        check_node(&node, expected)
+            .await
+            .map_err(ClusterError::new_err)?;
+    }
+    Ok(())
+}
+
+#[derive(Default)]
+struct GuardState {
+    task: Option<JoinHandle<()>>,
+    mismatch: Option<String>,
+}
+
+/// Tend-time `cluster_name` check, shared by a client's `connect()`, `close()`
+/// and command paths.
+#[derive(Clone, Default)]
+pub struct ClusterNameGuard(Arc<Mutex<GuardState>>);
+
+impl ClusterNameGuard {
+    fn state(&self) -> std::sync::MutexGuard<'_, GuardState> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// (Re)start the check for `client`; `None` (no `cluster_name`) leaves it stopped.
+    pub fn start(&self, client: Arc<AsClient>, handle: &Handle, expected: Option<String>) {
+        let mut state = self.state();
+        state.mismatch = None;
+        if let Some(old) = state.task.take() {
+            old.abort();
+        }
+        let Some(expected) = expected else {
+            return;
+        };
+        let interval = Duration::from_millis(u64::from(
+            client.cluster.client_policy().tend_interval.max(1),
+        ));
+        debug!("Starting cluster name check: expected={expected}, interval={interval:?}");
+        let guard = Arc::downgrade(&self.0);
+        state.task = Some(handle.spawn(async move {
+            let mut checked = HashSet::new();
+            loop {
+                tokio::time::sleep(interval).await;
+                for node in client.nodes() {
+                    if checked.contains(node.name()) {
+                        continue;
+                    }
+                    match check_node(&node, &expected).await {
+                        Ok(true) => {
+                            checked.insert(node.name().to_string());
+                        }
+                        Ok(false) => {}
+                        Err(message) => {
+                            error!("{message}; failing further commands");
+                            if let Some(guard) = guard.upgrade() {
+                                guard.lock().unwrap_or_else(|e| e.into_inner()).mismatch =
+                                    Some(message);
+                            }
+                            return;
+                        }
+                    }
+                }
+            }
+        }));
+    }
+
+    /// Raise `ClusterError` once a node of another cluster has been seen.
+    pub fn check(&self) -> PyResult<()> {
+        match &self.state().mismatch {
+            Some(message) => Err(ClusterError::new_err(message.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Stop the check and forget any mismatch.
+    pub fn stop(&self) {
+        let mut state = self.state();
+        state.mismatch = None;
+        if let Some(task) = state.task.take() {
+            task.abort();
+        }
+    }
+
+    /// Discard a task inherited across `os.fork()` without aborting it.
+    pub fn forget_task(&self) {
+        std::mem::forget(self.state().task.take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mismatch_names_node_and_reported_cluster() {
+        assert_eq!(mismatch("prod", "BB9", "10.0.0.5:3000", Some("prod")), None);
+        assert_eq!(
+            mismatch("prod", "BB9", "10.0.0.5:3000", Some("staging")).as_deref(),
+            Some("Cluster name mismatch: expected 'prod', node BB9 at 10.0.0.5:3000 reports 'staging'")
+        );
+        assert!(mismatch("prod", "BB9", "10.0.0.5:3000", None).is_some());
+    }
+}
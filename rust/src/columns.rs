@@ -0,0 +1,175 @@
+//! Bin column layout shared by the pandas and Arrow result builders.
+//!
+//! Both build one column per bin straight from `aerospike_core` records. This
+//! module picks the columns and infers the kind of value each one holds; the
+//! builders decide how a kind is stored, or reject it.
+
+use std::collections::{BTreeSet, HashMap};
+
+use aerospike_core::Value;
+
+/// Bins of one result row; `None` for a failed or missing record.
+pub type Row<'a> = Option<&'a HashMap<String, Value>>;
+
+/// Kind of value held by a bin column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    /// Every row is missing or `Nil`.
+    Null,
+    Int,
+    Float,
+    Bool,
+    /// Strings and GeoJSON.
+    Utf8,
+    /// Blobs and HLLs.
+    Binary,
+    List,
+    Map,
+    /// `Infinity` / `Wildcard`.
+    Other,
+}
+
+impl ColumnKind {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Nil => ColumnKind::Null,
+            Value::Int(_) => ColumnKind::Int,
+            Value::Float(_) => ColumnKind::Float,
+            Value::Bool(_) => ColumnKind::Bool,
+            Value::String(_) | Value::GeoJSON(_) => ColumnKind::Utf8,
+            Value::Blob(_) | Value::HLL(_) => ColumnKind::Binary,
+            Value::List(_) | Value::MultiResult(_) => ColumnKind::List,
+            Value::HashMap(_) | Value::OrderedMap(_) | Value::KeyValueList(_) => ColumnKind::Map,
+            Value::Infinity | Value::Wildcard => ColumnKind::Other,
+        }
+    }
+
+    /// Name of the kind for error messages.
+    #[cfg(feature = "arrow")]
+    pub fn label(self) -> &'static str {
+        match self {
+            ColumnKind::Null => "null",
+            ColumnKind::Int => "int",
+            ColumnKind::Float => "float",
+            ColumnKind::Bool => "bool",
+            ColumnKind::Utf8 => "str",
+            ColumnKind::Binary => "bytes",
+            ColumnKind::List => "list",
+            ColumnKind::Map => "map",
+            ColumnKind::Other => "non-scalar",
+        }
+    }
+}
+
+/// Infer the kind of `bin` over all rows.
+///
+/// Missing and `Nil` values are skipped and a mix of ints and floats widens
+/// to `Float`. Any other mix returns the first two conflicting kinds.
+pub fn infer_column_kind(
+    bin: &str,
+    rows: &[Row<'_>],
+) -> Result<ColumnKind, (ColumnKind, ColumnKind)> {
+    let mut kind = ColumnKind::Null;
+    for value in rows.iter().flatten().filter_map(|bins| bins.get(bin)) {
+        kind = match (kind, ColumnKind::of(value)) {
+            (k, ColumnKind::Null) => k,
+            (ColumnKind::Null, n) => n,
+            (k, n) if k == n => k,
+            (ColumnKind::Int, ColumnKind::Float) | (ColumnKind::Float, ColumnKind::Int) => {
+                ColumnKind::Float
+            }
+            (k, n) => return Err((k, n)),
+        };
+    }
+    Ok(kind)
+}
+
+/// Selected bins in order, or the sorted union of bin names across rows.
+pub fn column_names(bins: Option<&[String]>, rows: &[Row<'_>]) -> Vec<String> {
+    match bins {
+        Some(b) if !b.is_empty() => b.to_vec(),
+        _ => rows
+            .iter()
+            .flatten()
+            .flat_map(|m| m.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .cloned()
+            .collect(),
+    }
+}
+
+/// Non-nil value of `bin` in each row.
+pub fn column_values<'a>(
+    bin: &'a str,
+    rows: &'a [Row<'a>],
+) -> impl Iterator<Item = Option<&'a Value>> {
+    rows.iter()
+        .map(move |bins| bins.and_then(|b| b.get(bin)))
+        .map(|v| v.filter(|v| !matches!(v, Value::Nil)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aerospike_core::FloatValue;
+
+    fn row(pairs: &[(&str, Value)]) -> HashMap<String, Value> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn int_and_float_widen_to_float() {
+        let a = row(&[("x", Value::Int(1))]);
+        let b = row(&[("x", Value::Float(FloatValue::from(0.5)))]);
+        assert_eq!(
+            infer_column_kind("x", &[Some(&a), None, Some(&b)]),
+            Ok(ColumnKind::Float)
+        );
+    }
+
+    #[test]
+    fn nil_and_missing_do_not_change_kind() {
+        let a = row(&[("x", Value::Nil)]);
+        let b = row(&[("x", Value::Bool(true))]);
+        let c = row(&[]);
+        assert_eq!(
+            infer_column_kind("x", &[Some(&a), Some(&b), Some(&c)]),
+            Ok(ColumnKind::Bool)
+        );
+        assert_eq!(infer_column_kind("y", &[Some(&a)]), Ok(ColumnKind::Null));
+    }
+
+    #[test]
+    fn geojson_shares_utf8() {
+        let a = row(&[("s", Value::String("a".into()))]);
+        let b = row(&[("s", Value::GeoJSON("{}".into()))]);
+        assert_eq!(
+            infer_column_kind("s", &[Some(&a), Some(&b)]),
+            Ok(ColumnKind::Utf8)
+        );
+    }
+
+    #[test]
+    fn mixed_types_return_the_conflict() {
+        let a = row(&[("x", Value::Int(1))]);
+        let b = row(&[("x", Value::String("one".into()))]);
+        assert_eq!(
+            infer_column_kind("x", &[Some(&a), Some(&b)]),
+            Err((ColumnKind::Int, ColumnKind::Utf8))
+        );
+    }
+
+    #[test]
+    fn column_names_follow_selection_or_sort() {
+        let a = row(&[("b", Value::Int(1)), ("a", Value::Int(2))]);
+        let c = row(&[("c", Value::Int(3))]);
+        let rows = [Some(&a), Some(&c)];
+        assert_eq!(column_names(None, &rows), vec!["a", "b", "c"]);
+        let sel = vec!["c".to_string(), "a".to_string()];
+        assert_eq!(column_names(Some(&sel), &rows), vec!["c", "a"]);
+    }
+}
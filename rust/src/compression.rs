@@ -0,0 +1,252 @@
+//! Client-side compression of large string and blob bins.
+//!
+//! With the `bin_compression` client config, writes (`put()`,
+//! `batch_write()`, pipelined puts) store each top-level string or blob bin
+//! of at least `min_size` bytes as a zstd-compressed blob behind a marker,
+//! and reads (`get()`, `select()`, `operate()`, batch reads, queries) restore
+//! the original value. Values that do not shrink are stored as-is. Nested
+//! list / map values are never compressed.
+//!
+//! Only clients with the option decompress: any other reader sees the
+//! compressed blob as `bytes`. When disabled, both directions are no-ops.
+
+use std::collections::HashMap;
+use std::io::Read;
+
+use aerospike_core::{Bin, Record, Value};
+use log::warn;
+
+/// Prefix of a compressed bin, followed by the [`Kind`] byte of the
+/// original value and a zstd frame.
+const MARKER: &[u8; 4] = b"\x00ASZ";
+
+/// Largest value a compressed bin may restore to. Larger values are stored
+/// uncompressed, and a frame that inflates past it is left compressed, so a
+/// corrupt or hostile bin cannot exhaust memory on read.
+const MAX_DECOMPRESSED_SIZE: usize = 128 * 1024 * 1024;
+
+/// Type of the value a compressed bin restores to.
+#[derive(Clone, Copy)]
+#[repr(u8)]
+enum Kind {
+    String = b's',
+    Blob = b'b',
+}
+
+/// Settings parsed from the `bin_compression` client config.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BinCompressionConfig {
+    /// Smallest string / blob (in bytes) that is compressed.
+    pub min_size: usize,
+    /// zstd compression level.
+    pub level: i32,
+}
+
+/// Compresses bins on write and restores them on read.
+#[derive(Debug, Default)]
+pub struct BinCompression {
+    config: Option<BinCompressionConfig>,
+}
+
+impl BinCompression {
+    pub fn new(config: Option<BinCompressionConfig>) -> Self {
+        Self { config }
+    }
+
+    /// Compress the large string and blob bins of a write.
+    pub fn compress_bins(&self, bins: &mut [Bin]) {
+        let Some(config) = &self.config else {
+            return;
+        };
+        for bin in bins {
+            if let Some(value) = compress_value(&bin.value, config) {
+                bin.value = value;
+            }
+        }
+    }
+
+    /// Restore the compressed bins of a record.
+    pub fn decompress_record(&self, record: &mut Record) {
+        self.decompress_bins(&mut record.bins);
+    }
+
+    fn decompress_bins(&self, bins: &mut HashMap<String, Value>) {
+        if self.config.is_none() {
+            return;
+        }
+        for (name, value) in bins.iter_mut() {
+            let Value::Blob(data) = value else {
+                continue;
+            };
+            if !data.starts_with(MARKER) {
+                continue;
+            }
+            match decompress(data) {
+                Some(restored) => *value = restored,
+                None => warn!("bin '{name}' has the compression marker but does not decompress"),
+            }
+        }
+    }
+
+    /// Restore the compressed bins of every record of a batch read.
+    pub fn decompress_batch(&self, records: &mut [aerospike_core::BatchRecord]) {
+        if self.config.is_none() {
+            return;
+        }
+        for record in records.iter_mut().filter_map(|br| br.record.as_mut()) {
+            self.decompress_record(record);
+        }
+    }
+}
+
+/// The compressed form of `value`, or `None` to store it unchanged.
+fn compress_value(value: &Value, config: &BinCompressionConfig) -> Option<Value> {
+    let (kind, data) = match value {
+        Value::String(s) => (Kind::String, s.as_bytes()),
+        Value::Blob(b) => (Kind::Blob, b.as_slice()),
+        _ => return None,
+    };
+    // A blob that already starts with the marker is always wrapped, so it
+    // reads back unchanged instead of being taken for a compressed bin.
+    let marked = matches!(kind, Kind::Blob) && data.starts_with(MARKER);
+    if (data.len() < config.min_size || data.len() > MAX_DECOMPRESSED_SIZE) && !marked {
+        return None;
+    }
+    let frame = match zstd::bulk::compress(data, config.level) {
+        Ok(frame) => frame,
+        Err(e) => {
+            warn!("zstd compression failed, storing the bin uncompressed: {e}");
+            return None;
+        }
+    };
+    if MARKER.len() + 1 + frame.len() >= data.len() && !marked {
+        return None;
+    }
+    let mut out = Vec::with_capacity(MARKER.len() + 1 + frame.len());
+    out.extend_from_slice(MARKER);
+    out.push(kind as u8);
+    out.extend_from_slice(&frame);
+    Some(Value::Blob(out))
+}
+
+/// The original value of a compressed bin (`data` starts with [`MARKER`]).
+fn decompress(data: &[u8]) -> Option<Value> {
+    decompress_limited(data, MAX_DECOMPRESSED_SIZE)
+}
+
+/// [`decompress`] that gives up once the output would exceed `limit` bytes.
+fn decompress_limited(data: &[u8], limit: usize) -> Option<Value> {
+    let (&kind, frame) = data[MARKER.len()..].split_first()?;
+    let mut raw = Vec::new();
+    zstd::stream::read::Decoder::new(frame)
+        .ok()?
+        .take(limit as u64 + 1)
+        .read_to_end(&mut raw)
+        .ok()?;
+    if raw.len() > limit {
+        return None;
+    }
+    match kind {
+        k if k == Kind::String as u8 => String::from_utf8(raw).ok().map(Value::String),
+        k if k == Kind::Blob as u8 => Some(Value::Blob(raw)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled(min_size: usize) -> BinCompression {
+        BinCompression::new(Some(BinCompressionConfig { min_size, level: 3 }))
+    }
+
+    fn read_back(compression: &BinCompression, bins: Vec<Bin>) -> HashMap<String, Value> {
+        let mut bins = bins.into_iter().map(|b| (b.name, b.value)).collect();
+        compression.decompress_bins(&mut bins);
+        bins
+    }
+
+    #[test]
+    fn round_trips_large_strings_and_blobs() {
+        let text = "log line\n".repeat(1000);
+        let blob = vec![7u8; 10_000];
+        let mut bins = vec![
+            Bin::new("text".into(), Value::String(text.clone())),
+            Bin::new("blob".into(), Value::Blob(blob.clone())),
+            Bin::new("small".into(), Value::String("short".into())),
+            Bin::new("n".into(), Value::Int(1)),
+        ];
+        let compression = enabled(4096);
+        compression.compress_bins(&mut bins);
+
+        let Value::Blob(stored) = &bins[0].value else {
+            panic!("string bin was not compressed: {:?}", bins[0].value);
+        };
+        assert!(stored.starts_with(MARKER) && stored.len() < text.len());
+        assert!(matches!(&bins[1].value, Value::Blob(b) if b.starts_with(MARKER)));
+        assert_eq!(bins[2].value, Value::String("short".into()));
+        assert_eq!(bins[3].value, Value::Int(1));
+
+        let read = read_back(&compression, bins);
+        assert_eq!(read["text"], Value::String(text));
+        assert_eq!(read["blob"], Value::Blob(blob));
+        assert_eq!(read["small"], Value::String("short".into()));
+    }
+
+    #[test]
+    fn keeps_values_that_do_not_shrink() {
+        // Pseudo-random bytes do not compress.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let noise: Vec<u8> = (0..8192)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect();
+        let mut bins = vec![Bin::new("noise".into(), Value::Blob(noise.clone()))];
+        enabled(16).compress_bins(&mut bins);
+        assert_eq!(bins[0].value, Value::Blob(noise));
+    }
+
+    #[test]
+    fn blob_with_marker_prefix_reads_back_unchanged() {
+        let mut data = MARKER.to_vec();
+        data.extend_from_slice(b"sabc");
+        let mut bins = vec![Bin::new("b".into(), Value::Blob(data.clone()))];
+        let compression = enabled(4096);
+        compression.compress_bins(&mut bins);
+        assert_ne!(bins[0].value, Value::Blob(data.clone()));
+
+        assert_eq!(read_back(&compression, bins)["b"], Value::Blob(data));
+    }
+
+    #[test]
+    fn stops_inflating_past_the_limit() {
+        let mut bins = vec![Bin::new("b".into(), Value::Blob(vec![0u8; 64 * 1024]))];
+        enabled(16).compress_bins(&mut bins);
+        let Value::Blob(stored) = &bins[0].value else {
+            unreachable!();
+        };
+        assert!(decompress_limited(stored, 64 * 1024).is_some());
+        assert!(decompress_limited(stored, 64 * 1024 - 1).is_none());
+    }
+
+    #[test]
+    fn disabled_is_a_no_op() {
+        let text = "x".repeat(10_000);
+        let mut bins = vec![Bin::new("text".into(), Value::String(text.clone()))];
+        BinCompression::default().compress_bins(&mut bins);
+        assert_eq!(bins[0].value, Value::String(text));
+
+        let mut compressed = bins.clone();
+        enabled(1).compress_bins(&mut compressed);
+        let stored = compressed[0].value.clone();
+        assert_eq!(
+            read_back(&BinCompression::default(), compressed)["text"],
+            stored
+        );
+    }
+}
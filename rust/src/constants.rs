@@ -1,10 +1,12 @@
 //! Aerospike operation codes and Python-visible constants.
 //!
-//! Internal operation codes (`OP_*`) are used by [`crate::operations`] to
-//! dispatch Python operation dicts. Public constants (policy, index, result
-//! codes, etc.) are registered on the native module for use from Python.
+//! Operation codes (`OP_*`) are used by [`crate::operations`] to dispatch
+//! Python operation dicts; they are registered on the native module too, so
+//! the Python op-builder modules never hardcode them. Public constants
+//! (policy, index, result codes, etc.) are registered for use from Python.
 
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
 
 // ── Basic operation type constants ──────────────────────────────
 pub const OP_READ: i32 = 1;
@@ -47,6 +49,9 @@ pub const OP_LIST_REMOVE_BY_RANK_RANGE: i32 = 1028;
 pub const OP_LIST_INCREMENT: i32 = 1029;
 pub const OP_LIST_SORT: i32 = 1030;
 pub const OP_LIST_SET_ORDER: i32 = 1031;
+pub const OP_LIST_CREATE: i32 = 1032;
+pub const OP_LIST_GET_BY_VALUE_REL_RANK_RANGE: i32 = 1033;
+pub const OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE: i32 = 1034;
 
 // ── Map CDT operation codes ─────────────────────────────────────
 pub const OP_MAP_SET_ORDER: i32 = 2001;
@@ -76,6 +81,11 @@ pub const OP_MAP_GET_BY_RANK: i32 = 2024;
 pub const OP_MAP_GET_BY_RANK_RANGE: i32 = 2025;
 pub const OP_MAP_GET_BY_KEY_LIST: i32 = 2026;
 pub const OP_MAP_GET_BY_VALUE_LIST: i32 = 2027;
+pub const OP_MAP_CREATE: i32 = 2028;
+pub const OP_MAP_GET_BY_KEY_REL_INDEX_RANGE: i32 = 2029;
+pub const OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE: i32 = 2030;
+pub const OP_MAP_GET_BY_VALUE_REL_RANK_RANGE: i32 = 2031;
+pub const OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE: i32 = 2032;
 
 // ── HLL CDT operation codes ──────────────────────────────────────
 pub const OP_HLL_INIT: i32 = 3001;
@@ -109,11 +119,22 @@ pub const OP_BIT_LSCAN: i32 = 4052;
 pub const OP_BIT_RSCAN: i32 = 4053;
 pub const OP_BIT_GET_INT: i32 = 4054;
 
+/// Flag OR-ed into a `LIST_RETURN_*` / `MAP_RETURN_*` value to select the
+/// items outside the matched range instead.
+pub const RETURN_INVERTED: i64 = 0x10000;
+
+// ── List write flags (bits of `list_policy["flags"]`) ───────────
+pub const LIST_WRITE_ADD_UNIQUE: u8 = 1;
+pub const LIST_WRITE_INSERT_BOUNDED: u8 = 2;
+pub const LIST_WRITE_NO_FAIL: u8 = 4;
+pub const LIST_WRITE_PARTIAL: u8 = 8;
+
 /// Register all Aerospike constants onto the native Python module.
 ///
-/// Groups: policy keys/exists/gen/replica/commit, TTL, auth mode, operators,
-/// index types, log levels, serializers, list/map return types and flags,
-/// privilege codes, and server result codes.
+/// Groups: policy keys/exists/gen/replica/commit, TTL, auth mode, operators
+/// and CDT operation codes, index types, expression value types, log levels,
+/// serializers, list/map return types and flags, privilege codes, and server
+/// result codes, plus the `OPERATORS` name → code dict.
 pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
     // --- Policy Key ---
     m.add("POLICY_KEY_DIGEST", 0)?;
@@ -145,6 +166,12 @@ pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("POLICY_READ_MODE_AP_ONE", 0)?;
     m.add("POLICY_READ_MODE_AP_ALL", 1)?;
 
+    // --- Policy Read Mode SC ---
+    m.add("POLICY_READ_MODE_SC_SESSION", 0)?;
+    m.add("POLICY_READ_MODE_SC_LINEARIZE", 1)?;
+    m.add("POLICY_READ_MODE_SC_ALLOW_REPLICA", 2)?;
+    m.add("POLICY_READ_MODE_SC_ALLOW_UNAVAILABLE", 3)?;
+
     // --- Batch Concurrency ---
     m.add("BATCH_CONCURRENCY_SEQUENTIAL", 0u32)?;
     m.add("BATCH_CONCURRENCY_PARALLEL", 1u32)?;
@@ -178,18 +205,175 @@ pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("OPERATOR_TOUCH", 11)?;
     m.add("OPERATOR_DELETE", 12)?;
 
+    // --- List CDT Operation Codes ---
+    m.add("OP_LIST_APPEND", OP_LIST_APPEND)?;
+    m.add("OP_LIST_APPEND_ITEMS", OP_LIST_APPEND_ITEMS)?;
+    m.add("OP_LIST_INSERT", OP_LIST_INSERT)?;
+    m.add("OP_LIST_INSERT_ITEMS", OP_LIST_INSERT_ITEMS)?;
+    m.add("OP_LIST_POP", OP_LIST_POP)?;
+    m.add("OP_LIST_POP_RANGE", OP_LIST_POP_RANGE)?;
+    m.add("OP_LIST_REMOVE", OP_LIST_REMOVE)?;
+    m.add("OP_LIST_REMOVE_RANGE", OP_LIST_REMOVE_RANGE)?;
+    m.add("OP_LIST_SET", OP_LIST_SET)?;
+    m.add("OP_LIST_TRIM", OP_LIST_TRIM)?;
+    m.add("OP_LIST_CLEAR", OP_LIST_CLEAR)?;
+    m.add("OP_LIST_SIZE", OP_LIST_SIZE)?;
+    m.add("OP_LIST_GET", OP_LIST_GET)?;
+    m.add("OP_LIST_GET_RANGE", OP_LIST_GET_RANGE)?;
+    m.add("OP_LIST_GET_BY_VALUE", OP_LIST_GET_BY_VALUE)?;
+    m.add("OP_LIST_GET_BY_INDEX", OP_LIST_GET_BY_INDEX)?;
+    m.add("OP_LIST_GET_BY_INDEX_RANGE", OP_LIST_GET_BY_INDEX_RANGE)?;
+    m.add("OP_LIST_GET_BY_RANK", OP_LIST_GET_BY_RANK)?;
+    m.add("OP_LIST_GET_BY_RANK_RANGE", OP_LIST_GET_BY_RANK_RANGE)?;
+    m.add("OP_LIST_GET_BY_VALUE_LIST", OP_LIST_GET_BY_VALUE_LIST)?;
+    m.add("OP_LIST_GET_BY_VALUE_RANGE", OP_LIST_GET_BY_VALUE_RANGE)?;
+    m.add("OP_LIST_REMOVE_BY_VALUE", OP_LIST_REMOVE_BY_VALUE)?;
+    m.add("OP_LIST_REMOVE_BY_VALUE_LIST", OP_LIST_REMOVE_BY_VALUE_LIST)?;
+    m.add(
+        "OP_LIST_REMOVE_BY_VALUE_RANGE",
+        OP_LIST_REMOVE_BY_VALUE_RANGE,
+    )?;
+    m.add("OP_LIST_REMOVE_BY_INDEX", OP_LIST_REMOVE_BY_INDEX)?;
+    m.add(
+        "OP_LIST_REMOVE_BY_INDEX_RANGE",
+        OP_LIST_REMOVE_BY_INDEX_RANGE,
+    )?;
+    m.add("OP_LIST_REMOVE_BY_RANK", OP_LIST_REMOVE_BY_RANK)?;
+    m.add("OP_LIST_REMOVE_BY_RANK_RANGE", OP_LIST_REMOVE_BY_RANK_RANGE)?;
+    m.add("OP_LIST_INCREMENT", OP_LIST_INCREMENT)?;
+    m.add("OP_LIST_SORT", OP_LIST_SORT)?;
+    m.add("OP_LIST_SET_ORDER", OP_LIST_SET_ORDER)?;
+    m.add("OP_LIST_CREATE", OP_LIST_CREATE)?;
+    m.add(
+        "OP_LIST_GET_BY_VALUE_REL_RANK_RANGE",
+        OP_LIST_GET_BY_VALUE_REL_RANK_RANGE,
+    )?;
+    m.add(
+        "OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE",
+        OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE,
+    )?;
+
+    // --- Map CDT Operation Codes ---
+    m.add("OP_MAP_SET_ORDER", OP_MAP_SET_ORDER)?;
+    m.add("OP_MAP_PUT", OP_MAP_PUT)?;
+    m.add("OP_MAP_PUT_ITEMS", OP_MAP_PUT_ITEMS)?;
+    m.add("OP_MAP_INCREMENT", OP_MAP_INCREMENT)?;
+    m.add("OP_MAP_DECREMENT", OP_MAP_DECREMENT)?;
+    m.add("OP_MAP_CLEAR", OP_MAP_CLEAR)?;
+    m.add("OP_MAP_REMOVE_BY_KEY", OP_MAP_REMOVE_BY_KEY)?;
+    m.add("OP_MAP_REMOVE_BY_KEY_LIST", OP_MAP_REMOVE_BY_KEY_LIST)?;
+    m.add("OP_MAP_REMOVE_BY_KEY_RANGE", OP_MAP_REMOVE_BY_KEY_RANGE)?;
+    m.add("OP_MAP_REMOVE_BY_VALUE", OP_MAP_REMOVE_BY_VALUE)?;
+    m.add("OP_MAP_REMOVE_BY_VALUE_LIST", OP_MAP_REMOVE_BY_VALUE_LIST)?;
+    m.add("OP_MAP_REMOVE_BY_VALUE_RANGE", OP_MAP_REMOVE_BY_VALUE_RANGE)?;
+    m.add("OP_MAP_REMOVE_BY_INDEX", OP_MAP_REMOVE_BY_INDEX)?;
+    m.add("OP_MAP_REMOVE_BY_INDEX_RANGE", OP_MAP_REMOVE_BY_INDEX_RANGE)?;
+    m.add("OP_MAP_REMOVE_BY_RANK", OP_MAP_REMOVE_BY_RANK)?;
+    m.add("OP_MAP_REMOVE_BY_RANK_RANGE", OP_MAP_REMOVE_BY_RANK_RANGE)?;
+    m.add("OP_MAP_SIZE", OP_MAP_SIZE)?;
+    m.add("OP_MAP_GET_BY_KEY", OP_MAP_GET_BY_KEY)?;
+    m.add("OP_MAP_GET_BY_KEY_RANGE", OP_MAP_GET_BY_KEY_RANGE)?;
+    m.add("OP_MAP_GET_BY_VALUE", OP_MAP_GET_BY_VALUE)?;
+    m.add("OP_MAP_GET_BY_VALUE_RANGE", OP_MAP_GET_BY_VALUE_RANGE)?;
+    m.add("OP_MAP_GET_BY_INDEX", OP_MAP_GET_BY_INDEX)?;
+    m.add("OP_MAP_GET_BY_INDEX_RANGE", OP_MAP_GET_BY_INDEX_RANGE)?;
+    m.add("OP_MAP_GET_BY_RANK", OP_MAP_GET_BY_RANK)?;
+    m.add("OP_MAP_GET_BY_RANK_RANGE", OP_MAP_GET_BY_RANK_RANGE)?;
+    m.add("OP_MAP_GET_BY_KEY_LIST", OP_MAP_GET_BY_KEY_LIST)?;
+    m.add("OP_MAP_GET_BY_VALUE_LIST", OP_MAP_GET_BY_VALUE_LIST)?;
+    m.add("OP_MAP_CREATE", OP_MAP_CREATE)?;
+    m.add(
+        "OP_MAP_GET_BY_KEY_REL_INDEX_RANGE",
+        OP_MAP_GET_BY_KEY_REL_INDEX_RANGE,
+    )?;
+    m.add(
+        "OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE",
+        OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE,
+    )?;
+    m.add(
+        "OP_MAP_GET_BY_VALUE_REL_RANK_RANGE",
+        OP_MAP_GET_BY_VALUE_REL_RANK_RANGE,
+    )?;
+    m.add(
+        "OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE",
+        OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE,
+    )?;
+
+    // --- HLL Operation Codes ---
+    m.add("OP_HLL_INIT", OP_HLL_INIT)?;
+    m.add("OP_HLL_ADD", OP_HLL_ADD)?;
+    m.add("OP_HLL_GET_COUNT", OP_HLL_GET_COUNT)?;
+    m.add("OP_HLL_GET_UNION", OP_HLL_GET_UNION)?;
+    m.add("OP_HLL_GET_UNION_COUNT", OP_HLL_GET_UNION_COUNT)?;
+    m.add("OP_HLL_GET_INTERSECT_COUNT", OP_HLL_GET_INTERSECT_COUNT)?;
+    m.add("OP_HLL_GET_SIMILARITY", OP_HLL_GET_SIMILARITY)?;
+    m.add("OP_HLL_DESCRIBE", OP_HLL_DESCRIBE)?;
+    m.add("OP_HLL_FOLD", OP_HLL_FOLD)?;
+    m.add("OP_HLL_SET_UNION", OP_HLL_SET_UNION)?;
+
+    // --- Bitwise Operation Codes ---
+    m.add("OP_BIT_RESIZE", OP_BIT_RESIZE)?;
+    m.add("OP_BIT_INSERT", OP_BIT_INSERT)?;
+    m.add("OP_BIT_REMOVE", OP_BIT_REMOVE)?;
+    m.add("OP_BIT_SET", OP_BIT_SET)?;
+    m.add("OP_BIT_OR", OP_BIT_OR)?;
+    m.add("OP_BIT_XOR", OP_BIT_XOR)?;
+    m.add("OP_BIT_AND", OP_BIT_AND)?;
+    m.add("OP_BIT_NOT", OP_BIT_NOT)?;
+    m.add("OP_BIT_LSHIFT", OP_BIT_LSHIFT)?;
+    m.add("OP_BIT_RSHIFT", OP_BIT_RSHIFT)?;
+    m.add("OP_BIT_ADD", OP_BIT_ADD)?;
+    m.add("OP_BIT_SUBTRACT", OP_BIT_SUBTRACT)?;
+    m.add("OP_BIT_SET_INT", OP_BIT_SET_INT)?;
+    m.add("OP_BIT_GET", OP_BIT_GET)?;
+    m.add("OP_BIT_COUNT", OP_BIT_COUNT)?;
+    m.add("OP_BIT_LSCAN", OP_BIT_LSCAN)?;
+    m.add("OP_BIT_RSCAN", OP_BIT_RSCAN)?;
+    m.add("OP_BIT_GET_INT", OP_BIT_GET_INT)?;
+
     // --- Index Type ---
     m.add("INDEX_NUMERIC", 0)?;
     m.add("INDEX_STRING", 1)?;
     m.add("INDEX_BLOB", 2)?;
     m.add("INDEX_GEO2DSPHERE", 3)?;
 
+    // --- Raw Bin Particle Type (get_raw / put_raw) ---
+    m.add("AS_BYTES_UNDEF", 0)?;
+    m.add("AS_BYTES_INTEGER", 1)?;
+    m.add("AS_BYTES_DOUBLE", 2)?;
+    m.add("AS_BYTES_STRING", 3)?;
+    m.add("AS_BYTES_BLOB", 4)?;
+    m.add("AS_BYTES_BOOL", 17)?;
+    m.add("AS_BYTES_HLL", 18)?;
+    m.add("AS_BYTES_MAP", 19)?;
+    m.add("AS_BYTES_LIST", 20)?;
+    m.add("AS_BYTES_GEOJSON", 23)?;
+
     // --- Index Collection Type ---
     m.add("INDEX_TYPE_DEFAULT", 0)?;
     m.add("INDEX_TYPE_LIST", 1)?;
     m.add("INDEX_TYPE_MAPKEYS", 2)?;
     m.add("INDEX_TYPE_MAPVALUES", 3)?;
 
+    // --- Expression Value Type (exp.key / exp.*_bin value types) ---
+    m.add("EXP_TYPE_NIL", 0)?;
+    m.add("EXP_TYPE_BOOL", 1)?;
+    m.add("EXP_TYPE_INT", 2)?;
+    m.add("EXP_TYPE_STRING", 3)?;
+    m.add("EXP_TYPE_LIST", 4)?;
+    m.add("EXP_TYPE_MAP", 5)?;
+    m.add("EXP_TYPE_BLOB", 6)?;
+    m.add("EXP_TYPE_FLOAT", 7)?;
+    m.add("EXP_TYPE_GEO", 8)?;
+    m.add("EXP_TYPE_HLL", 9)?;
+
+    // --- Job ---
+    m.add("JOB_SCAN", "scan")?;
+    m.add("JOB_QUERY", "query")?;
+    m.add("JOB_STATUS_UNDEF", 0)?;
+    m.add("JOB_STATUS_INPROGRESS", 1)?;
+    m.add("JOB_STATUS_COMPLETED", 2)?;
+
     // --- Log Level ---
     m.add("LOG_LEVEL_OFF", -1)?;
     m.add("LOG_LEVEL_ERROR", 0)?;
@@ -212,6 +396,7 @@ pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("LIST_RETURN_COUNT", 5)?;
     m.add("LIST_RETURN_VALUE", 7)?;
     m.add("LIST_RETURN_EXISTS", 13)?;
+    m.add("LIST_RETURN_INVERTED", 65536)?;
 
     // --- List Order ---
     m.add("LIST_UNORDERED", 0)?;
@@ -219,6 +404,7 @@ pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // --- List Sort Flags ---
     m.add("LIST_SORT_DEFAULT", 0)?;
+    m.add("LIST_SORT_DESCENDING", 1)?;
     m.add("LIST_SORT_DROP_DUPLICATES", 2)?;
 
     // --- List Write Flags ---
@@ -239,6 +425,7 @@ pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("MAP_RETURN_VALUE", 7)?;
     m.add("MAP_RETURN_KEY_VALUE", 8)?;
     m.add("MAP_RETURN_EXISTS", 13)?;
+    m.add("MAP_RETURN_INVERTED", 65536)?;
 
     // --- Map Order ---
     m.add("MAP_UNORDERED", 0)?;
@@ -351,5 +538,16 @@ pub fn register_constants(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("AEROSPIKE_ERR_INVALID_HOST", -4)?;
     m.add("AEROSPIKE_ERR_NO_MORE_CONNECTIONS", -7)?;
 
+    // --- Operation Code Lookup ---
+    // `OPERATORS` maps every `OPERATOR_*` / `OP_*` name registered above to its code.
+    let operators = PyDict::new(m.py());
+    for (name, value) in m.dict().iter() {
+        let name: String = name.extract()?;
+        if name.starts_with("OPERATOR_") || name.starts_with("OP_") {
+            operators.set_item(name, value)?;
+        }
+    }
+    m.add("OPERATORS", operators)?;
+
     Ok(())
 }
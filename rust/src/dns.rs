@@ -0,0 +1,689 @@
+//! Seed host discovery: DNS SRV lookups and address family selection.
+//!
+//! With `hosts_srv` in the client config, the seed hosts come from the SRV
+//! records of that name (e.g. a Kubernetes headless service), looked up at
+//! `connect()` and again every `hosts_srv_refresh_seconds` so nodes that
+//! joined since are known seeds if the cluster has to be re-seeded.
+//! `address_family` restricts (`"ipv4"` / `"ipv6"`) or orders
+//! (`"prefer_ipv4"` / `"prefer_ipv6"`) the addresses seed host names resolve
+//! to. Only seeds are affected: peers are announced by the cluster itself.
+//!
+//! `aerospike_core` and std only resolve A / AAAA records, so SRV queries go
+//! straight to the `nameserver`s of `/etc/resolv.conf`, over UDP with a TCP
+//! retry for truncated answers. Its `search` / `domain` list and `ndots`
+//! option are applied like the system resolver does, so a short Kubernetes
+//! service name resolves from inside its cluster.
+
+use std::collections::HashSet;
+use std::io;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aerospike_core::{Client as AsClient, Host};
+use log::{debug, info, warn};
+use pyo3::prelude::*;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, UdpSocket};
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+use crate::errors::{ClusterError, ParamError};
+use crate::types::host::{host_port, split_host_port, ParsedHosts};
+
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+const DNS_PORT: u16 = 53;
+const QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+const UDP_ATTEMPTS: usize = 2;
+/// `ndots` when `/etc/resolv.conf` sets none (as in glibc).
+const DEFAULT_NDOTS: usize = 1;
+const TYPE_SRV: u16 = 33;
+const CLASS_IN: u16 = 1;
+
+/// Which address families seed host names may resolve to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AddressFamily {
+    /// Leave host names to `aerospike_core`.
+    #[default]
+    Any,
+    Ipv4,
+    Ipv6,
+    /// IPv4 addresses when a name has any, else IPv6.
+    PreferIpv4,
+    /// IPv6 addresses when a name has any, else IPv4.
+    PreferIpv6,
+}
+
+impl AddressFamily {
+    /// Parse the `address_family` config value.
+    pub fn parse(value: &str) -> PyResult<Self> {
+        match value {
+            "any" => Ok(Self::Any),
+            "ipv4" => Ok(Self::Ipv4),
+            "ipv6" => Ok(Self::Ipv6),
+            "prefer_ipv4" => Ok(Self::PreferIpv4),
+            "prefer_ipv6" => Ok(Self::PreferIpv6),
+            other => Err(ParamError::new_err(format!(
+                "address_family must be 'any', 'ipv4', 'ipv6', 'prefer_ipv4' or 'prefer_ipv6', got '{other}'"
+            ))),
+        }
+    }
+
+    fn allows(self, ip: &IpAddr) -> bool {
+        match self {
+            Self::Ipv4 => ip.is_ipv4(),
+            Self::Ipv6 => ip.is_ipv6(),
+            _ => true,
+        }
+    }
+
+    /// Pick the addresses of one host name.
+    fn select(self, addrs: Vec<IpAddr>) -> Vec<IpAddr> {
+        let preferred = match self {
+            Self::PreferIpv4 => IpAddr::is_ipv4,
+            Self::PreferIpv6 => IpAddr::is_ipv6,
+            _ => return addrs.into_iter().filter(|ip| self.allows(ip)).collect(),
+        };
+        let (first, rest): (Vec<_>, Vec<_>) = addrs.into_iter().partition(preferred);
+        if first.is_empty() {
+            rest
+        } else {
+            first
+        }
+    }
+}
+
+/// Build the seed connection string for `connect()`.
+///
+/// Without `hosts_srv` and with `address_family="any"` this is the
+/// configured hosts string unchanged.
+pub async fn resolve_seeds(parsed: &ParsedHosts) -> PyResult<String> {
+    if parsed.srv_name.is_none() && parsed.family == AddressFamily::Any {
+        return Ok(parsed.connection_string.clone());
+    }
+    let (verbatim, seeds) = seed_hosts(parsed)
+        .await
+        .map_err(|e| ClusterError::new_err(e.to_string()))?;
+    if verbatim.is_empty() && seeds.is_empty() {
+        return Err(ClusterError::new_err(format!(
+            "No seed hosts left for address_family {:?}",
+            parsed.family
+        )));
+    }
+    let connection_string = verbatim
+        .into_iter()
+        .chain(seeds.iter().map(|(host, port)| host_port(host, *port)))
+        .collect::<Vec<_>>()
+        .join(",");
+    info!("Resolved seed hosts: {connection_string}");
+    Ok(connection_string)
+}
+
+/// Whether a hosts entry has the `host:tls_name:port` form (`host` may be a
+/// bracketed IPv6 address).
+fn has_tls_name(entry: &str) -> bool {
+    let rest = match entry.strip_prefix('[') {
+        Some(bracketed) => bracketed.split_once(']').map_or("", |(_, after)| after),
+        None => entry,
+    };
+    rest.matches(':').count() == 2
+}
+
+/// The configured hosts plus the SRV targets, filtered by address family.
+///
+/// Entries with a TLS name (or that do not parse) are returned separately,
+/// verbatim, for `aerospike_core` to parse.
+async fn seed_hosts(parsed: &ParsedHosts) -> io::Result<(Vec<String>, Vec<(String, u16)>)> {
+    let mut verbatim = Vec::new();
+    let mut hosts = Vec::new();
+    for entry in parsed
+        .connection_string
+        .split(',')
+        .filter(|e| !e.is_empty())
+    {
+        match split_host_port(entry) {
+            Ok(host) if !has_tls_name(entry) => hosts.push(host),
+            _ => verbatim.push(entry.to_string()),
+        }
+    }
+    if let Some(name) = &parsed.srv_name {
+        let targets = lookup_srv(name).await?;
+        debug!("SRV {} returned {} targets", name, targets.len());
+        hosts.extend(targets);
+    }
+    Ok((verbatim, apply_family(hosts, parsed.family).await?))
+}
+
+async fn apply_family(
+    hosts: Vec<(String, u16)>,
+    family: AddressFamily,
+) -> io::Result<Vec<(String, u16)>> {
+    let mut seen = HashSet::new();
+    let mut out = Vec::new();
+    for (host, port) in hosts {
+        let resolved = if family == AddressFamily::Any {
+            vec![(host, port)]
+        } else if let Ok(ip) = host.parse::<IpAddr>() {
+            if family.allows(&ip) {
+                vec![(host, port)]
+            } else {
+                debug!("Skipping seed {host}: not {family:?}");
+                vec![]
+            }
+        } else {
+            let addrs: Vec<IpAddr> = match tokio::net::lookup_host((host.as_str(), port)).await {
+                Ok(addrs) => addrs.map(|a| a.ip()).collect(),
+                Err(e) => {
+                    warn!("Could not resolve seed host {host}: {e}");
+                    vec![]
+                }
+            };
+            family
+                .select(addrs)
+                .into_iter()
+                .map(|ip| (ip.to_string(), port))
+                .collect()
+        };
+        for seed in resolved {
+            if seen.insert(seed.clone()) {
+                out.push(seed);
+            }
+        }
+    }
+    Ok(out)
+}
+
+// ── SRV lookup ─────────────────────────────────────────────
+
+/// The parts of `/etc/resolv.conf` an SRV lookup uses.
+#[derive(Debug, PartialEq, Eq)]
+struct ResolvConf {
+    nameservers: Vec<SocketAddr>,
+    search: Vec<String>,
+    ndots: usize,
+}
+
+impl ResolvConf {
+    fn read() -> io::Result<Self> {
+        let conf = Self::parse(&std::fs::read_to_string(RESOLV_CONF)?);
+        if conf.nameservers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No nameserver in {RESOLV_CONF}"),
+            ));
+        }
+        Ok(conf)
+    }
+
+    fn parse(text: &str) -> Self {
+        let mut conf = Self {
+            nameservers: Vec::new(),
+            search: Vec::new(),
+            ndots: DEFAULT_NDOTS,
+        };
+        for line in text.lines() {
+            let mut words = line.split_whitespace();
+            match words.next() {
+                Some("nameserver") => {
+                    if let Some(ip) = words.next().and_then(|a| a.parse::<IpAddr>().ok()) {
+                        conf.nameservers.push(SocketAddr::new(ip, DNS_PORT));
+                    }
+                }
+                // The last `search` or `domain` line wins.
+                Some("search") | Some("domain") => {
+                    conf.search = words.map(|d| d.trim_end_matches('.').to_string()).collect();
+                }
+                Some("options") => {
+                    for option in words {
+                        if let Some(n) = option.strip_prefix("ndots:").and_then(|n| n.parse().ok())
+                        {
+                            conf.ndots = n;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        conf
+    }
+
+    /// The names to query for `name`, in order: a fully qualified name
+    /// (trailing dot) as is, otherwise the name itself first when it has at
+    /// least `ndots` dots, and after the search-list expansions when not.
+    fn candidates(&self, name: &str) -> Vec<String> {
+        if let Some(absolute) = name.strip_suffix('.') {
+            return vec![absolute.to_string()];
+        }
+        let expanded = self.search.iter().map(|domain| format!("{name}.{domain}"));
+        if name.matches('.').count() >= self.ndots {
+            std::iter::once(name.to_string()).chain(expanded).collect()
+        } else {
+            expanded.chain(std::iter::once(name.to_string())).collect()
+        }
+    }
+}
+
+/// Look up the `(target, port)` pairs of an SRV name, by ascending priority
+/// and then descending weight.
+pub async fn lookup_srv(name: &str) -> io::Result<Vec<(String, u16)>> {
+    let conf = ResolvConf::read()?;
+    let mut last_err = None;
+    for candidate in conf.candidates(name) {
+        match query_srv(&conf.nameservers, &candidate).await {
+            Ok(targets) if !targets.is_empty() => return Ok(targets),
+            Ok(_) => debug!("SRV {candidate} has no records"),
+            Err(e) => {
+                debug!("SRV lookup of {candidate} failed: {e}");
+                last_err = Some(e);
+            }
+        }
+    }
+    match last_err {
+        // A timeout or server failure says more than a later NXDOMAIN.
+        Some(e) => Err(e),
+        None => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("No SRV records for {name}"),
+        )),
+    }
+}
+
+/// Query the nameservers in turn until one answers; an answer (including
+/// NXDOMAIN) from one server is final.
+async fn query_srv(servers: &[SocketAddr], name: &str) -> io::Result<Vec<(String, u16)>> {
+    let mut last_err = None;
+    for &server in servers {
+        match query_server(server, name).await {
+            Ok(targets) => return Ok(targets),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(e),
+            Err(e) => {
+                debug!("SRV query of {name} to {server} failed: {e}");
+                last_err = Some(e);
+            }
+        }
+    }
+    Err(last_err.unwrap_or_else(|| io::Error::other("No nameserver configured")))
+}
+
+async fn query_server(server: SocketAddr, name: &str) -> io::Result<Vec<(String, u16)>> {
+    let id: u16 = rand::random();
+    let query = build_query(id, name)?;
+    let mut response = None;
+    for _ in 0..UDP_ATTEMPTS {
+        match tokio::time::timeout(QUERY_TIMEOUT, udp_exchange(server, &query)).await {
+            Ok(Ok(r)) => {
+                response = Some(r);
+                break;
+            }
+            Ok(Err(e)) => debug!("SRV query to {server} failed: {e}"),
+            Err(_) => debug!("SRV query to {server} timed out"),
+        }
+    }
+    let mut response = response.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::TimedOut,
+            format!("No answer from DNS server {server} for SRV {name}"),
+        )
+    })?;
+    if response.len() > 2 && response[2] & 0x02 != 0 {
+        debug!("SRV answer for {name} truncated, retrying over TCP");
+        response = tokio::time::timeout(QUERY_TIMEOUT, tcp_exchange(server, &query))
+            .await
+            .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "DNS over TCP timed out"))??;
+    }
+    parse_srv_response(id, name, &response)
+}
+
+async fn udp_exchange(server: SocketAddr, query: &[u8]) -> io::Result<Vec<u8>> {
+    let bind: SocketAddr = if server.is_ipv4() {
+        ([0, 0, 0, 0], 0).into()
+    } else {
+        ([0u16; 8], 0).into()
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    socket.connect(server).await?;
+    socket.send(query).await?;
+    let mut buf = vec![0u8; 4096];
+    let n = socket.recv(&mut buf).await?;
+    buf.truncate(n);
+    Ok(buf)
+}
+
+async fn tcp_exchange(server: SocketAddr, query: &[u8]) -> io::Result<Vec<u8>> {
+    let mut stream = TcpStream::connect(server).await?;
+    let len = u16::try_from(query.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "DNS query too long"))?;
+    stream.write_all(&len.to_be_bytes()).await?;
+    stream.write_all(query).await?;
+    let len = stream.read_u16().await?;
+    let mut buf = vec![0u8; usize::from(len)];
+    stream.read_exact(&mut buf).await?;
+    Ok(buf)
+}
+
+/// Encode a recursive SRV query for `name`.
+fn build_query(id: u16, name: &str) -> io::Result<Vec<u8>> {
+    let mut msg = Vec::with_capacity(18 + name.len());
+    msg.extend_from_slice(&id.to_be_bytes());
+    // Flags: recursion desired; one question.
+    msg.extend_from_slice(&[0x01, 0x00, 0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in name.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Invalid SRV name '{name}'"),
+            ));
+        }
+        msg.push(label.len() as u8);
+        msg.extend_from_slice(label.as_bytes());
+    }
+    msg.push(0);
+    msg.extend_from_slice(&TYPE_SRV.to_be_bytes());
+    msg.extend_from_slice(&CLASS_IN.to_be_bytes());
+    Ok(msg)
+}
+
+fn malformed(name: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Malformed DNS answer for SRV {name}"),
+    )
+}
+
+fn read_u16(msg: &[u8], pos: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([*msg.get(pos)?, *msg.get(pos + 1)?]))
+}
+
+/// Read the (possibly compressed) domain name at `pos`; returns it and the
+/// position after it.
+fn read_name(msg: &[u8], mut pos: usize) -> Option<(String, usize)> {
+    let mut labels: Vec<String> = Vec::new();
+    let mut end = None;
+    for _ in 0..128 {
+        let len = *msg.get(pos)?;
+        match len {
+            0 => {
+                return Some((labels.join("."), end.unwrap_or(pos + 1)));
+            }
+            l if l & 0xC0 == 0xC0 => {
+                let target = usize::from(read_u16(msg, pos)? & 0x3FFF);
+                end.get_or_insert(pos + 2);
+                pos = target;
+            }
+            l => {
+                let label = msg.get(pos + 1..pos + 1 + usize::from(l))?;
+                labels.push(String::from_utf8_lossy(label).into_owned());
+                pos += 1 + usize::from(l);
+            }
+        }
+    }
+    None
+}
+
+fn parse_srv_response(id: u16, name: &str, msg: &[u8]) -> io::Result<Vec<(String, u16)>> {
+    let bad = || malformed(name);
+    if read_u16(msg, 0).ok_or_else(bad)? != id {
+        return Err(bad());
+    }
+    let flags = read_u16(msg, 2).ok_or_else(bad)?;
+    match flags & 0x000F {
+        0 => {}
+        3 => {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("SRV name {name} does not exist"),
+            ))
+        }
+        rcode => {
+            return Err(io::Error::other(format!(
+                "DNS lookup of SRV {name} failed with rcode {rcode}"
+            )))
+        }
+    }
+    let questions = read_u16(msg, 4).ok_or_else(bad)?;
+    let answers = read_u16(msg, 6).ok_or_else(bad)?;
+    let mut pos = 12;
+    for _ in 0..questions {
+        pos = read_name(msg, pos).ok_or_else(bad)?.1 + 4;
+    }
+    let mut records = Vec::new();
+    for _ in 0..answers {
+        pos = read_name(msg, pos).ok_or_else(bad)?.1;
+        let rtype = read_u16(msg, pos).ok_or_else(bad)?;
+        let rdlen = usize::from(read_u16(msg, pos + 8).ok_or_else(bad)?);
+        let rdata = pos + 10;
+        if msg.len() < rdata + rdlen {
+            return Err(bad());
+        }
+        if rtype == TYPE_SRV {
+            let priority = read_u16(msg, rdata).ok_or_else(bad)?;
+            let weight = read_u16(msg, rdata + 2).ok_or_else(bad)?;
+            let port = read_u16(msg, rdata + 4).ok_or_else(bad)?;
+            let (target, _) = read_name(msg, rdata + 6).ok_or_else(bad)?;
+            // A target of "." means the service is not available there.
+            if !target.is_empty() {
+                records.push((priority, weight, target, port));
+            }
+        }
+        pos = rdata + rdlen;
+    }
+    records.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+    Ok(records
+        .into_iter()
+        .map(|(_, _, target, port)| (target, port))
+        .collect())
+}
+
+// ── Periodic re-resolution ─────────────────────────────────
+
+/// Periodic SRV re-resolution task, shared by a client's `connect()` and `close()`.
+#[derive(Clone, Default)]
+pub struct SrvRefresh(Arc<Mutex<Option<JoinHandle<()>>>>);
+
+impl SrvRefresh {
+    fn task(&self) -> std::sync::MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// (Re)start the task for `client`; it only runs with `hosts_srv` and a
+    /// refresh interval set.
+    pub fn start(&self, client: Arc<AsClient>, handle: &Handle, parsed: &ParsedHosts) {
+        let mut task = self.task();
+        if let Some(old) = task.take() {
+            old.abort();
+        }
+        let (Some(name), Some(interval)) = (parsed.srv_name.clone(), parsed.srv_refresh) else {
+            return;
+        };
+        let family = parsed.family;
+        debug!("Starting SRV refresh of {name}: interval={interval:?}");
+        *task = Some(handle.spawn(async move {
+            let mut known = HashSet::new();
+            loop {
+                tokio::time::sleep(interval).await;
+                let seeds = match lookup_srv(&name).await {
+                    Ok(targets) => apply_family(targets, family).await,
+                    Err(e) => Err(e),
+                };
+                match seeds {
+                    Ok(seeds) => {
+                        let new: Vec<Host> = seeds
+                            .into_iter()
+                            .filter(|seed| known.insert(seed.clone()))
+                            .map(|(host, port)| Host::new(&host, port))
+                            .collect();
+                        if !new.is_empty() {
+                            info!("SRV refresh of {name}: {} new seed(s)", new.len());
+                            client.cluster.add_seeds(&new);
+                        }
+                    }
+                    Err(e) => warn!("SRV refresh of {name} failed: {e}"),
+                }
+            }
+        }));
+    }
+
+    /// Stop the task.
+    pub fn stop(&self) {
+        if let Some(task) = self.task().take() {
+            task.abort();
+        }
+    }
+
+    /// Discard a task inherited across `os.fork()` without aborting it.
+    pub fn forget_task(&self) {
+        std::mem::forget(self.task().take());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An answer to `build_query(7, "_aerospike._tcp.db")` with SRV records
+    /// `(priority, weight, port, target)`; targets after the first are
+    /// compressed against it.
+    fn answer(records: &[(u16, u16, u16, &str)]) -> Vec<u8> {
+        let mut msg = build_query(7, "_aerospike._tcp.db").unwrap();
+        msg[2] = 0x81;
+        msg[3] = 0x80;
+        msg[7] = records.len() as u8;
+        let mut first_target = None;
+        for (priority, weight, port, target) in records {
+            msg.extend_from_slice(&[0xC0, 12]);
+            msg.extend_from_slice(&TYPE_SRV.to_be_bytes());
+            msg.extend_from_slice(&CLASS_IN.to_be_bytes());
+            msg.extend_from_slice(&30u32.to_be_bytes());
+            let mut rdata = Vec::new();
+            for v in [priority, weight, port] {
+                rdata.extend_from_slice(&v.to_be_bytes());
+            }
+            let (label, rest) = target.split_once('.').unwrap();
+            rdata.push(label.len() as u8);
+            rdata.extend_from_slice(label.as_bytes());
+            match first_target {
+                Some(offset) => rdata.extend_from_slice(&[0xC0, offset]),
+                None => {
+                    first_target = Some((msg.len() + 2 + 6 + 1 + label.len()) as u8);
+                    for part in rest.split('.') {
+                        rdata.push(part.len() as u8);
+                        rdata.extend_from_slice(part.as_bytes());
+                    }
+                    rdata.push(0);
+                }
+            }
+            msg.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+            msg.extend_from_slice(&rdata);
+        }
+        msg
+    }
+
+    #[test]
+    fn parses_srv_answers_in_priority_order() {
+        let msg = answer(&[
+            (20, 0, 3000, "c.svc.local"),
+            (10, 1, 3000, "a.svc.local"),
+            (10, 5, 3001, "b.svc.local"),
+        ]);
+        let targets = parse_srv_response(7, "_aerospike._tcp.db", &msg).unwrap();
+        assert_eq!(
+            targets,
+            vec![
+                ("b.svc.local".to_string(), 3001),
+                ("a.svc.local".to_string(), 3000),
+                ("c.svc.local".to_string(), 3000),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_bad_answers() {
+        let msg = answer(&[(10, 1, 3000, "a.svc.local")]);
+        assert!(parse_srv_response(8, "n", &msg).is_err());
+        assert!(parse_srv_response(7, "n", &msg[..msg.len() - 3]).is_err());
+        let mut nxdomain = msg.clone();
+        nxdomain[3] |= 3;
+        let err = parse_srv_response(7, "n", &nxdomain).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(build_query(1, "a..b").is_err());
+    }
+
+    #[test]
+    fn address_family_filters_and_prefers() {
+        let v4: IpAddr = "10.0.0.1".parse().unwrap();
+        let v6: IpAddr = "fd00::1".parse().unwrap();
+        assert_eq!(AddressFamily::Ipv4.select(vec![v6, v4]), vec![v4]);
+        assert!(AddressFamily::Ipv6.select(vec![v4]).is_empty());
+        assert_eq!(AddressFamily::PreferIpv6.select(vec![v4, v6]), vec![v6]);
+        assert_eq!(AddressFamily::PreferIpv6.select(vec![v4]), vec![v4]);
+        assert!(AddressFamily::parse("ipv5").is_err());
+    }
+
+    #[test]
+    fn resolv_conf_search_list_and_ndots() {
+        let conf = ResolvConf::parse(
+            "# k8s pod\nnameserver 10.96.0.10\nnameserver fd00::a\nnameserver bogus\n\
+             search db.svc.cluster.local svc.cluster.local.\noptions ndots:5 timeout:1\n",
+        );
+        assert_eq!(
+            conf.nameservers,
+            vec![
+                SocketAddr::new("10.96.0.10".parse().unwrap(), DNS_PORT),
+                SocketAddr::new("fd00::a".parse().unwrap(), DNS_PORT),
+            ]
+        );
+        assert_eq!(conf.ndots, 5);
+        assert_eq!(
+            conf.candidates("_aerospike._tcp.aerospike"),
+            vec![
+                "_aerospike._tcp.aerospike.db.svc.cluster.local",
+                "_aerospike._tcp.aerospike.svc.cluster.local",
+                "_aerospike._tcp.aerospike",
+            ]
+        );
+        assert_eq!(
+            conf.candidates("_a._tcp.example.com."),
+            vec!["_a._tcp.example.com"]
+        );
+
+        let conf = ResolvConf::parse("nameserver 1.1.1.1\ndomain corp.example\n");
+        assert_eq!(conf.ndots, DEFAULT_NDOTS);
+        assert_eq!(
+            conf.candidates("_a._tcp.db"),
+            vec!["_a._tcp.db", "_a._tcp.db.corp.example"]
+        );
+    }
+
+    #[tokio::test]
+    async fn tls_named_hosts_pass_through_verbatim() {
+        assert!(has_tls_name("h:tlsname:3000"));
+        assert!(has_tls_name("[fd00::1]:tlsname:3000"));
+        assert!(!has_tls_name("h:3000"));
+        assert!(!has_tls_name("[fd00::1]:3000"));
+        for family in [AddressFamily::Any, AddressFamily::Ipv4] {
+            let parsed = ParsedHosts {
+                connection_string: "h:tlsname:3000,10.0.0.1:3000".to_string(),
+                family,
+                ..ParsedHosts::default()
+            };
+            let (verbatim, seeds) = seed_hosts(&parsed).await.unwrap();
+            assert_eq!(verbatim, vec!["h:tlsname:3000"]);
+            assert_eq!(seeds, vec![("10.0.0.1".to_string(), 3000)]);
+            assert_eq!(
+                resolve_seeds(&parsed).await.unwrap(),
+                "h:tlsname:3000,10.0.0.1:3000"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn apply_family_keeps_matching_literals() {
+        let hosts = vec![
+            ("10.0.0.1".to_string(), 3000),
+            ("fd00::1".to_string(), 3000),
+            ("10.0.0.1".to_string(), 3000),
+        ];
+        let seeds = apply_family(hosts, AddressFamily::Ipv6).await.unwrap();
+        assert_eq!(seeds, vec![("fd00::1".to_string(), 3000)]);
+    }
+}
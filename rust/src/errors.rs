@@ -5,22 +5,46 @@
 //! ```text
 //! AerospikeError (base)
 //!   +-- ClientError          (connection, config, internal)
+//!   |     +-- ParamError    (invalid argument; alias InvalidArgError)
+//!   |     +-- BackpressureError / RustPanicError / ForkError
 //!   +-- ServerError          (server-side errors)
 //!   |     +-- AerospikeIndexError
 //!   |     |     +-- IndexNotFound / IndexFoundError
 //!   |     +-- QueryError / QueryAbortedError
 //!   |     +-- AdminError / UDFError
+//!   |     |     +-- SessionExpired / QuotaExceeded
+//!   |     +-- AlwaysForbidden / ForbiddenError / DeviceOverload
+//!   |     +-- UnsupportedFeature / OpNotApplicable / LostConflict
 //!   +-- RecordError          (record-level)
 //!   |     +-- RecordNotFound / RecordExistsError / RecordGenerationError / ...
+//!   |     +-- RecordBusy / ElementNotFoundError / ElementExistsError
 //!   +-- ClusterError         (node/connectivity)
+//!   |     +-- CircuitOpenError
 //!   +-- AerospikeTimeoutError
-//!   +-- InvalidArgError
 //! ```
+//!
+//! Transient errors (timeouts, cluster / connection errors, backpressure, hot
+//! keys, device overload, temporarily forbidden operations) also derive from
+//! the `RetryableError` mixin; see [`is_retryable`].
+//!
+//! Every exception carries `.code` (result code), `.msg`, `.in_doubt`, and
+//! `.key` / `.bin` (`None` when unknown), like the official client. The
+//! classes provide defaults, so exceptions raised from Python have them too.
 
-use aerospike_core::{Error as AsError, ResultCode};
+use aerospike_core::{Error as AsError, Key, ResultCode};
 use log::debug;
 use pyo3::exceptions::PyException;
+use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+// Client-side result codes (negative, as in the official client).
+const ERR_CLIENT: i32 = -1;
+const ERR_PARAM: i32 = -2;
+const ERR_NO_MORE_CONNECTIONS: i32 = -7;
+pub(crate) const ERR_INVALID_NODE: i32 = -8;
+pub(crate) const ERR_CONNECTION: i32 = -10;
+pub(crate) const ERR_TIMEOUT: i32 = 9;
 
 // Base exceptions
 pyo3::create_exception!(
@@ -61,9 +85,15 @@ pyo3::create_exception!(
 );
 pyo3::create_exception!(
     aerospike,
-    InvalidArgError,
-    AerospikeError,
-    "Invalid argument passed to an operation."
+    ParamError,
+    ClientError,
+    "Invalid argument passed to an operation (also exported as `InvalidArgError`)."
+);
+pyo3::create_exception!(
+    aerospike,
+    RetryableError,
+    PyException,
+    "Mixin base for transient errors: the same call may succeed if retried."
 );
 pyo3::create_exception!(
     aerospike,
@@ -71,6 +101,19 @@ pyo3::create_exception!(
     ClientError,
     "Maximum concurrent operations exceeded; retry after backoff."
 );
+pyo3::create_exception!(
+    aerospike,
+    CircuitOpenError,
+    ClusterError,
+    "The key's node has an open circuit breaker; the command was not sent."
+);
+pyo3::create_exception!(
+    aerospike,
+    ForkError,
+    ClientError,
+    "The client was connected before os.fork() and cannot be used in the child \
+     process until it reconnects."
+);
 pyo3::create_exception!(
     aerospike,
     RustPanicError,
@@ -136,6 +179,62 @@ pyo3::create_exception!(
     RecordError,
     "Record filtered out by expression filter (result code 27)."
 );
+pyo3::create_exception!(
+    aerospike,
+    RecordBusy,
+    RecordError,
+    "Too many concurrent requests for one record, a hot key (result code 14)."
+);
+pyo3::create_exception!(
+    aerospike,
+    ElementNotFoundError,
+    RecordError,
+    "CDT element not found (result code 23)."
+);
+pyo3::create_exception!(
+    aerospike,
+    ElementExistsError,
+    RecordError,
+    "CDT element already exists (result code 24)."
+);
+
+// Server-level exceptions
+pyo3::create_exception!(
+    aerospike,
+    AlwaysForbidden,
+    ServerError,
+    "Operation is never allowed in this namespace configuration (result code 10)."
+);
+pyo3::create_exception!(
+    aerospike,
+    UnsupportedFeature,
+    ServerError,
+    "Feature not supported or not enabled on the server (result code 16, 25)."
+);
+pyo3::create_exception!(
+    aerospike,
+    DeviceOverload,
+    ServerError,
+    "Storage device is not keeping up with writes (result code 18)."
+);
+pyo3::create_exception!(
+    aerospike,
+    ForbiddenError,
+    ServerError,
+    "Operation is currently forbidden, e.g. during migrations (result code 22)."
+);
+pyo3::create_exception!(
+    aerospike,
+    OpNotApplicable,
+    ServerError,
+    "Operation cannot be applied to the current bin value (result code 26)."
+);
+pyo3::create_exception!(
+    aerospike,
+    LostConflict,
+    ServerError,
+    "Write lost a conflict resolution (XDR) (result code 28)."
+);
 
 // Index exceptions
 pyo3::create_exception!(
@@ -173,6 +272,18 @@ pyo3::create_exception!(
     ServerError,
     "Admin or security operation error."
 );
+pyo3::create_exception!(
+    aerospike,
+    SessionExpired,
+    AdminError,
+    "Login session expired (result code 66)."
+);
+pyo3::create_exception!(
+    aerospike,
+    QuotaExceeded,
+    AdminError,
+    "User quota exceeded (result code 83)."
+);
 pyo3::create_exception!(
     aerospike,
     UDFError,
@@ -180,9 +291,80 @@ pyo3::create_exception!(
     "User-Defined Function (UDF) execution error."
 );
 
+/// Result codes of transient server conditions. Covers the codes of the
+/// `RetryableError` classes plus those that surface as a plain `ServerError`.
+pub(crate) const RETRYABLE_CODES: [i32; 10] = [
+    ERR_TIMEOUT,
+    11,  // partition unavailable
+    14,  // key busy
+    18,  // device overload
+    22,  // fail forbidden
+    151, // batch max requests exceeded
+    152, // batch queues full
+    211, // query queue full
+    ERR_CONNECTION,
+    ERR_NO_MORE_CONNECTIONS,
+];
+
+/// Whether `exc` is a transient Aerospike error worth retrying.
+///
+/// True for `RetryableError` instances and for any `AerospikeError` whose
+/// `code` is a transient result code. Retrying a write whose `in_doubt` is
+/// true may apply it twice unless the write is idempotent.
+#[pyfunction]
+pub fn is_retryable(exc: &Bound<'_, PyAny>) -> bool {
+    let py = exc.py();
+    if exc
+        .is_instance(&py.get_type::<RetryableError>())
+        .unwrap_or(false)
+    {
+        return true;
+    }
+    if !exc
+        .is_instance(&py.get_type::<AerospikeError>())
+        .unwrap_or(false)
+    {
+        return false;
+    }
+    exc.getattr(intern!(py, "code"))
+        .and_then(|code| code.extract::<Option<i32>>())
+        .ok()
+        .flatten()
+        .is_some_and(|code| RETRYABLE_CODES.contains(&code))
+}
+
+/// The `code` of an Aerospike exception, `ERR_CLIENT` for any other exception.
+pub(crate) fn pyerr_code(err: &PyErr) -> i32 {
+    Python::attach(|py| {
+        err.value(py)
+            .getattr(intern!(py, "code"))
+            .and_then(|code| code.extract::<Option<i32>>())
+            .ok()
+            .flatten()
+            .unwrap_or(ERR_CLIENT)
+    })
+}
+
+/// Add `RetryableError` as a second base of the transient exception classes.
+fn mark_retryable(py: Python<'_>) -> PyResult<()> {
+    let retryable = py.get_type::<RetryableError>();
+    for ty in [
+        py.get_type::<AerospikeTimeoutError>(),
+        py.get_type::<ClusterError>(),
+        py.get_type::<BackpressureError>(),
+        py.get_type::<RecordBusy>(),
+        py.get_type::<DeviceOverload>(),
+        py.get_type::<ForbiddenError>(),
+    ] {
+        let bases = PyTuple::new(py, [ty.getattr("__base__")?, retryable.clone().into_any()])?;
+        ty.setattr("__bases__", bases)?;
+    }
+    Ok(())
+}
+
 /// Map an `aerospike_core::ResultCode` to its integer wire-protocol value.
 ///
-/// Unknown variants are passed through; truly unrecognized variants return `-1`.
+/// Codes the core client does not name (`ResultCode::Unknown`) are passed through.
 pub(crate) fn result_code_to_int(rc: &ResultCode) -> i32 {
     match rc {
         ResultCode::Ok => 0,
@@ -218,20 +400,192 @@ pub(crate) fn result_code_to_int(rc: &ResultCode) -> i32 {
         ResultCode::QueryEnd => 50,
         ResultCode::SecurityNotSupported => 51,
         ResultCode::SecurityNotEnabled => 52,
+        ResultCode::SecuritySchemeNotSupported => 53,
+        ResultCode::InvalidCommand => 54,
+        ResultCode::InvalidField => 55,
+        ResultCode::IllegalState => 56,
         ResultCode::InvalidUser => 60,
+        ResultCode::UserAlreadyExists => 61,
+        ResultCode::InvalidPassword => 62,
+        ResultCode::ExpiredPassword => 63,
+        ResultCode::ForbiddenPassword => 64,
+        ResultCode::InvalidCredential => 65,
+        ResultCode::ExpiredSession => 66,
+        ResultCode::InvalidRole => 70,
+        ResultCode::RoleAlreadyExists => 71,
+        ResultCode::InvalidPrivilege => 72,
+        ResultCode::InvalidAllowlist => 73,
+        ResultCode::QuotasNotEnabled => 74,
+        ResultCode::InvalidQuota => 75,
         ResultCode::NotAuthenticated => 80,
         ResultCode::RoleViolation => 81,
+        ResultCode::NotAllowlisted => 82,
+        ResultCode::QuotaExceeded => 83,
         ResultCode::UdfBadResponse => 100,
         ResultCode::BatchDisabled => 150,
+        ResultCode::BatchMaxRequestsExceeded => 151,
+        ResultCode::BatchQueuesFull => 152,
+        ResultCode::InvalidGeojson => 160,
         ResultCode::IndexFound => 200,
         ResultCode::IndexNotFound => 201,
+        ResultCode::IndexOom => 202,
+        ResultCode::IndexNotReadable => 203,
+        ResultCode::IndexGeneric => 204,
+        ResultCode::IndexNameMaxLen => 205,
+        ResultCode::IndexMaxCount => 206,
         ResultCode::QueryAborted => 210,
-        ResultCode::InvalidGeojson => 160,
+        ResultCode::QueryQueueFull => 211,
+        ResultCode::QueryTimeout => 212,
+        ResultCode::QueryGeneric => 213,
+        ResultCode::QueryNetioErr => 214,
+        ResultCode::QueryDuplicate => 215,
         ResultCode::Unknown(code) => *code as i32,
-        _ => -1,
     }
 }
 
+/// Result code and in-doubt flag for an `aerospike_core::Error`.
+pub(crate) fn error_code(err: &AsError) -> (i32, bool) {
+    match err {
+        AsError::ServerError(rc, in_doubt, _) => (result_code_to_int(rc), *in_doubt),
+        AsError::Connection(_) => (ERR_CONNECTION, false),
+        AsError::Timeout(_) => (ERR_TIMEOUT, false),
+        AsError::InvalidArgument(_) => (ERR_PARAM, false),
+        AsError::InvalidNode(_) => (ERR_INVALID_NODE, false),
+        AsError::NoMoreConnections => (ERR_NO_MORE_CONNECTIONS, false),
+        AsError::UdfBadResponse(_) => (result_code_to_int(&ResultCode::UdfBadResponse), false),
+        _ => (ERR_CLIENT, false),
+    }
+}
+
+/// Set `.code` and `.in_doubt` on the exception instance.
+fn with_details(err: PyErr, code: i32, in_doubt: bool) -> PyErr {
+    Python::attach(|py| {
+        let value = err.value(py);
+        let _ = value.setattr(intern!(py, "code"), code);
+        let _ = value.setattr(intern!(py, "in_doubt"), in_doubt);
+    });
+    err
+}
+
+/// Set `.key` on an exception raised by a single-key command.
+pub fn with_key(err: PyErr, key: &Key) -> PyErr {
+    Python::attach(|py| {
+        if let Ok(key) = crate::types::key::key_to_py(py, key) {
+            let _ = err.value(py).setattr(intern!(py, "key"), key);
+        }
+    });
+    err
+}
+
+/// Location and user message of a Lua error string, as returned by the
+/// server in the `FAILURE` bin: `/opt/aerospike/usr/udf/lua/mod.lua:12: msg`.
+/// Errors raised without a position (`error(msg, 0)`) only have a message.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct LuaError<'a> {
+    pub module: Option<&'a str>,
+    pub line: Option<u32>,
+    pub message: &'a str,
+}
+
+pub(crate) fn parse_lua_error(reason: &str) -> LuaError<'_> {
+    let unparsed = LuaError {
+        module: None,
+        line: None,
+        message: reason.trim(),
+    };
+    let Some(ext) = reason.find(".lua:") else {
+        return unparsed;
+    };
+    let path_start = reason[..ext]
+        .rfind(char::is_whitespace)
+        .map_or(0, |i| i + 1);
+    let module = reason[path_start..ext].rsplit('/').next().unwrap_or("");
+    let rest = &reason[ext + ".lua:".len()..];
+    let Some((line, message)) = rest.split_once(':') else {
+        return unparsed;
+    };
+    match line.parse() {
+        Ok(line) if !module.is_empty() => LuaError {
+            module: Some(module),
+            line: Some(line),
+            message: message.trim(),
+        },
+        _ => unparsed,
+    }
+}
+
+/// `UDFError` for a failed UDF, with `.module`, `.line` and `.message`
+/// parsed from the Lua error string.
+fn udf_error(reason: &str) -> PyErr {
+    let err = UDFError::new_err(format!("AEROSPIKE_ERR (100): UDF error: {reason}"));
+    let lua = parse_lua_error(reason);
+    Python::attach(|py| {
+        let value = err.value(py);
+        let _ = value.setattr(intern!(py, "module"), lua.module);
+        let _ = value.setattr(intern!(py, "line"), lua.line);
+        let _ = value.setattr(intern!(py, "message"), lua.message);
+    });
+    err
+}
+
+/// Set `.function` on a `UDFError` raised by `apply()`, and `.module` when
+/// the Lua error string did not name one.
+pub fn with_udf_call(err: PyErr, module: &str, function: &str) -> PyErr {
+    Python::attach(|py| {
+        if !err.is_instance_of::<UDFError>(py) {
+            return;
+        }
+        let value = err.value(py);
+        let _ = value.setattr(intern!(py, "function"), function);
+        if value
+            .getattr(intern!(py, "module"))
+            .is_ok_and(|m| m.is_none())
+        {
+            let _ = value.setattr(intern!(py, "module"), module);
+        }
+    });
+    err
+}
+
+/// The exception for a per-record result code (e.g. of a batch record),
+/// with `.key` set. Codes outside the server range map to `ClientError`.
+pub(crate) fn result_code_error(
+    py: Python<'_>,
+    code: i32,
+    in_doubt: bool,
+    key: &Bound<'_, PyAny>,
+) -> PyErr {
+    let err = match u8::try_from(code) {
+        Ok(rc) => map_error(AsError::ServerError(
+            ResultCode::from(rc),
+            in_doubt,
+            String::new(),
+        )),
+        Err(_) => ClientError::new_err(format!("AEROSPIKE_ERR ({code})")),
+    };
+    let err = with_details(err, code, in_doubt);
+    let _ = err.value(py).setattr(intern!(py, "key"), key);
+    err
+}
+
+/// Raise the exception mapped from a non-zero per-record result code.
+///
+/// Backs `BatchRecord.raise_for_status()` of the typed Python wrappers.
+#[pyfunction]
+#[pyo3(name = "_raise_for_result", signature = (code, in_doubt=false, key=None))]
+pub fn raise_for_result(
+    py: Python<'_>,
+    code: i32,
+    in_doubt: bool,
+    key: Option<Bound<'_, PyAny>>,
+) -> PyResult<()> {
+    if code == 0 {
+        return Ok(());
+    }
+    let key = key.unwrap_or_else(|| py.None().into_bound(py));
+    Err(result_code_error(py, code, in_doubt, &key))
+}
+
 /// Convert an `aerospike_core::Error` into the appropriate Python exception.
 ///
 /// Maps each error variant to the most specific exception subclass
@@ -239,12 +593,15 @@ pub(crate) fn result_code_to_int(rc: &ResultCode) -> i32 {
 /// broader categories like `ServerError` or `ClientError`.
 pub fn as_to_pyerr(err: AsError) -> PyErr {
     debug!("Mapping aerospike error: {}", err);
+    let (code, in_doubt) = error_code(&err);
+    with_details(map_error(err), code, in_doubt)
+}
+
+fn map_error(err: AsError) -> PyErr {
     match &err {
         AsError::Connection(msg) => ClusterError::new_err(format!("Connection error: {msg}")),
         AsError::Timeout(msg) => AerospikeTimeoutError::new_err(format!("Timeout: {msg}")),
-        AsError::InvalidArgument(msg) => {
-            InvalidArgError::new_err(format!("Invalid argument: {msg}"))
-        }
+        AsError::InvalidArgument(msg) => ParamError::new_err(format!("Invalid argument: {msg}")),
         AsError::ServerError(rc, in_doubt, _node) => {
             let code = result_code_to_int(rc);
             let doubt_suffix = if *in_doubt { " [in_doubt]" } else { "" };
@@ -260,9 +617,19 @@ pub fn as_to_pyerr(err: AsError) -> PyErr {
                 ResultCode::BinNotFound => BinNotFound::new_err(msg),
                 ResultCode::BinTypeError => BinTypeError::new_err(msg),
                 ResultCode::FilteredOut => FilteredOut::new_err(msg),
-                ResultCode::ElementNotFound | ResultCode::ElementExists => {
-                    RecordError::new_err(msg)
+                ResultCode::KeyBusy | ResultCode::XDRKeyBusy => RecordBusy::new_err(msg),
+                ResultCode::ElementNotFound => ElementNotFoundError::new_err(msg),
+                ResultCode::ElementExists => ElementExistsError::new_err(msg),
+                // Server-level
+                ResultCode::Timeout => AerospikeTimeoutError::new_err(msg),
+                ResultCode::AlwaysForbidden => AlwaysForbidden::new_err(msg),
+                ResultCode::UnsupportedFeature | ResultCode::EnterpriseOnly => {
+                    UnsupportedFeature::new_err(msg)
                 }
+                ResultCode::DeviceOverload => DeviceOverload::new_err(msg),
+                ResultCode::FailForbidden => ForbiddenError::new_err(msg),
+                ResultCode::OpNotApplicable => OpNotApplicable::new_err(msg),
+                ResultCode::LostConflict => LostConflict::new_err(msg),
                 // Index
                 ResultCode::IndexFound => IndexFoundError::new_err(msg),
                 ResultCode::IndexNotFound => IndexNotFound::new_err(msg),
@@ -271,11 +638,29 @@ pub fn as_to_pyerr(err: AsError) -> PyErr {
                 // UDF
                 ResultCode::UdfBadResponse => UDFError::new_err(msg),
                 // Admin / Security
-                ResultCode::InvalidUser
+                ResultCode::ExpiredSession => SessionExpired::new_err(msg),
+                ResultCode::QuotaExceeded => QuotaExceeded::new_err(msg),
+                ResultCode::SecurityNotSupported
+                | ResultCode::SecurityNotEnabled
+                | ResultCode::SecuritySchemeNotSupported
+                | ResultCode::InvalidCommand
+                | ResultCode::InvalidField
+                | ResultCode::IllegalState
+                | ResultCode::InvalidUser
+                | ResultCode::UserAlreadyExists
+                | ResultCode::InvalidPassword
+                | ResultCode::ExpiredPassword
+                | ResultCode::ForbiddenPassword
+                | ResultCode::InvalidCredential
+                | ResultCode::InvalidRole
+                | ResultCode::RoleAlreadyExists
+                | ResultCode::InvalidPrivilege
+                | ResultCode::InvalidAllowlist
+                | ResultCode::QuotasNotEnabled
+                | ResultCode::InvalidQuota
                 | ResultCode::NotAuthenticated
                 | ResultCode::RoleViolation
-                | ResultCode::SecurityNotSupported
-                | ResultCode::SecurityNotEnabled => AdminError::new_err(msg),
+                | ResultCode::NotAllowlisted => AdminError::new_err(msg),
                 // Default server error
                 _ => {
                     log::warn!(
@@ -289,6 +674,7 @@ pub fn as_to_pyerr(err: AsError) -> PyErr {
         }
         AsError::InvalidNode(msg) => ClusterError::new_err(format!("Invalid node: {msg}")),
         AsError::NoMoreConnections => ClusterError::new_err("No more connections available"),
+        AsError::UdfBadResponse(reason) => udf_error(reason),
         _ => {
             crate::bug_report::log_unexpected_error(
                 "errors::as_to_pyerr",
@@ -308,14 +694,18 @@ pub fn register_exceptions(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("ServerError", py.get_type::<ServerError>())?;
     m.add("RecordError", py.get_type::<RecordError>())?;
     m.add("ClusterError", py.get_type::<ClusterError>())?;
+    m.add("CircuitOpenError", py.get_type::<CircuitOpenError>())?;
     m.add(
         "AerospikeTimeoutError",
         py.get_type::<AerospikeTimeoutError>(),
     )?;
     m.add("TimeoutError", py.get_type::<AerospikeTimeoutError>())?; // backward compat
-    m.add("InvalidArgError", py.get_type::<InvalidArgError>())?;
+    m.add("ParamError", py.get_type::<ParamError>())?;
+    m.add("InvalidArgError", py.get_type::<ParamError>())?; // backward compat
+    m.add("RetryableError", py.get_type::<RetryableError>())?;
     m.add("BackpressureError", py.get_type::<BackpressureError>())?;
     m.add("RustPanicError", py.get_type::<RustPanicError>())?;
+    m.add("ForkError", py.get_type::<ForkError>())?;
     // Record-level exceptions
     m.add("RecordNotFound", py.get_type::<RecordNotFound>())?;
     m.add("RecordExistsError", py.get_type::<RecordExistsError>())?;
@@ -329,6 +719,19 @@ pub fn register_exceptions(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("BinNotFound", py.get_type::<BinNotFound>())?;
     m.add("BinTypeError", py.get_type::<BinTypeError>())?;
     m.add("FilteredOut", py.get_type::<FilteredOut>())?;
+    m.add("RecordBusy", py.get_type::<RecordBusy>())?;
+    m.add(
+        "ElementNotFoundError",
+        py.get_type::<ElementNotFoundError>(),
+    )?;
+    m.add("ElementExistsError", py.get_type::<ElementExistsError>())?;
+    // Server-level exceptions
+    m.add("AlwaysForbidden", py.get_type::<AlwaysForbidden>())?;
+    m.add("UnsupportedFeature", py.get_type::<UnsupportedFeature>())?;
+    m.add("DeviceOverload", py.get_type::<DeviceOverload>())?;
+    m.add("ForbiddenError", py.get_type::<ForbiddenError>())?;
+    m.add("OpNotApplicable", py.get_type::<OpNotApplicable>())?;
+    m.add("LostConflict", py.get_type::<LostConflict>())?;
     // Index exceptions
     m.add("AerospikeIndexError", py.get_type::<AerospikeIndexError>())?;
     m.add("IndexError", py.get_type::<AerospikeIndexError>())?; // backward compat
@@ -339,8 +742,58 @@ pub fn register_exceptions(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("QueryAbortedError", py.get_type::<QueryAbortedError>())?;
     // Admin / UDF exceptions
     m.add("AdminError", py.get_type::<AdminError>())?;
+    m.add("SessionExpired", py.get_type::<SessionExpired>())?;
+    m.add("QuotaExceeded", py.get_type::<QuotaExceeded>())?;
     m.add("UDFError", py.get_type::<UDFError>())?;
-    Ok(())
+
+    // Attribute defaults for exceptions not built by `as_to_pyerr`.
+    let base = py.get_type::<AerospikeError>();
+    base.setattr("code", py.None())?;
+    base.setattr(
+        "msg",
+        py.eval(c"property(lambda self: str(self))", None, None)?,
+    )?;
+    base.setattr("in_doubt", false)?;
+    base.setattr("key", py.None())?;
+    base.setattr("bin", py.None())?;
+    let udf = py.get_type::<UDFError>();
+    for attr in ["module", "function", "line", "message"] {
+        udf.setattr(attr, py.None())?;
+    }
+    for (name, code) in [
+        ("ClientError", ERR_CLIENT),
+        ("ClusterError", ERR_CONNECTION),
+        ("CircuitOpenError", ERR_INVALID_NODE),
+        ("AerospikeTimeoutError", ERR_TIMEOUT),
+        ("ParamError", ERR_PARAM),
+        ("RecordNotFound", 2),
+        ("RecordGenerationError", 3),
+        ("RecordExistsError", 5),
+        ("BinExistsError", 6),
+        ("BinTypeError", 12),
+        ("RecordTooBig", 13),
+        ("BinNotFound", 17),
+        ("BinNameError", 21),
+        ("FilteredOut", 27),
+        ("RecordBusy", 14),
+        ("ElementNotFoundError", 23),
+        ("ElementExistsError", 24),
+        ("AlwaysForbidden", 10),
+        ("UnsupportedFeature", 16),
+        ("DeviceOverload", 18),
+        ("ForbiddenError", 22),
+        ("OpNotApplicable", 26),
+        ("LostConflict", 28),
+        ("SessionExpired", 66),
+        ("QuotaExceeded", 83),
+        ("UDFError", 100),
+        ("IndexFoundError", 200),
+        ("IndexNotFound", 201),
+        ("QueryAbortedError", 210),
+    ] {
+        m.getattr(name)?.setattr("code", code)?;
+    }
+    mark_retryable(py)
 }
 
 #[cfg(test)]
@@ -382,6 +835,212 @@ mod tests {
         assert_eq!(result_code_to_int(&ResultCode::QueryAborted), 210);
     }
 
+    #[test]
+    fn test_result_code_to_int_expired_session() {
+        assert_eq!(result_code_to_int(&ResultCode::ExpiredSession), 66);
+    }
+
+    #[test]
+    fn test_expired_session_maps_to_session_expired() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = as_to_pyerr(AsError::ServerError(
+                ResultCode::ExpiredSession,
+                false,
+                String::new(),
+            ));
+            assert!(err.is_instance_of::<SessionExpired>(py));
+            assert!(err.is_instance_of::<AdminError>(py));
+        });
+    }
+
+    #[test]
+    fn test_server_error_carries_code_and_in_doubt() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = as_to_pyerr(AsError::ServerError(
+                ResultCode::GenerationError,
+                true,
+                String::new(),
+            ));
+            let value = err.value(py);
+            let code: i32 = value.getattr("code").unwrap().extract().unwrap();
+            let in_doubt: bool = value.getattr("in_doubt").unwrap().extract().unwrap();
+            assert_eq!(code, 3);
+            assert!(in_doubt);
+        });
+    }
+
+    #[test]
+    fn test_client_errors_carry_negative_codes() {
+        Python::initialize();
+        Python::attach(|py| {
+            for (err, expected) in [
+                (AsError::Connection("refused".into()), ERR_CONNECTION),
+                (AsError::InvalidArgument("bad".into()), ERR_PARAM),
+                (AsError::Timeout("slow".into()), ERR_TIMEOUT),
+                (AsError::ClientError("oops".into()), ERR_CLIENT),
+            ] {
+                let err = as_to_pyerr(err);
+                let code: i32 = err.value(py).getattr("code").unwrap().extract().unwrap();
+                assert_eq!(code, expected);
+            }
+        });
+    }
+
+    #[test]
+    fn test_with_key_sets_key_tuple() {
+        Python::initialize();
+        Python::attach(|py| {
+            let key = aerospike_core::as_key!("test", "demo", "k1");
+            let err = with_key(ClientError::new_err("boom"), &key);
+            let value = err.value(py).getattr("key").unwrap();
+            let (ns, set): (String, String) = (
+                value.get_item(0).unwrap().extract().unwrap(),
+                value.get_item(1).unwrap().extract().unwrap(),
+            );
+            assert_eq!((ns.as_str(), set.as_str()), ("test", "demo"));
+        });
+    }
+
+    #[test]
+    fn test_result_codes_map_to_dedicated_exceptions() {
+        Python::initialize();
+        Python::attach(|py| {
+            let map = |rc| as_to_pyerr(AsError::ServerError(rc, false, String::new()));
+            assert!(map(ResultCode::AlwaysForbidden).is_instance_of::<AlwaysForbidden>(py));
+            assert!(map(ResultCode::FailForbidden).is_instance_of::<ForbiddenError>(py));
+            assert!(map(ResultCode::DeviceOverload).is_instance_of::<DeviceOverload>(py));
+            assert!(map(ResultCode::KeyBusy).is_instance_of::<RecordBusy>(py));
+            assert!(map(ResultCode::QuotaExceeded).is_instance_of::<QuotaExceeded>(py));
+            assert!(map(ResultCode::QuotaExceeded).is_instance_of::<AdminError>(py));
+            assert!(map(ResultCode::EnterpriseOnly).is_instance_of::<UnsupportedFeature>(py));
+            assert!(map(ResultCode::OpNotApplicable).is_instance_of::<OpNotApplicable>(py));
+            assert!(map(ResultCode::LostConflict).is_instance_of::<LostConflict>(py));
+            assert!(map(ResultCode::ElementNotFound).is_instance_of::<ElementNotFoundError>(py));
+            assert!(map(ResultCode::ElementExists).is_instance_of::<RecordError>(py));
+            assert!(map(ResultCode::Timeout).is_instance_of::<AerospikeTimeoutError>(py));
+        });
+    }
+
+    #[test]
+    fn test_result_code_to_int_covers_security_and_index_codes() {
+        assert_eq!(result_code_to_int(&ResultCode::QuotaExceeded), 83);
+        assert_eq!(result_code_to_int(&ResultCode::NotAllowlisted), 82);
+        assert_eq!(result_code_to_int(&ResultCode::IndexOom), 202);
+        assert_eq!(result_code_to_int(&ResultCode::QueryDuplicate), 215);
+    }
+
+    #[test]
+    fn test_transient_errors_are_retryable() {
+        Python::initialize();
+        Python::attach(|py| {
+            mark_retryable(py).unwrap();
+            let retryable = |err: PyErr| is_retryable(err.value(py));
+            let server = |rc| as_to_pyerr(AsError::ServerError(rc, false, String::new()));
+            assert!(retryable(server(ResultCode::KeyBusy)));
+            assert!(retryable(server(ResultCode::DeviceOverload)));
+            assert!(retryable(server(ResultCode::PartitionUnavailable)));
+            assert!(retryable(as_to_pyerr(AsError::Timeout("t".into()))));
+            assert!(retryable(as_to_pyerr(AsError::Connection("c".into()))));
+            assert!(retryable(BackpressureError::new_err("full")));
+            assert!(!retryable(server(ResultCode::KeyNotFoundError)));
+            assert!(!retryable(server(ResultCode::GenerationError)));
+            assert!(!retryable(as_to_pyerr(AsError::InvalidArgument(
+                "a".into()
+            ))));
+
+            let busy = py.get_type::<RecordBusy>();
+            assert!(busy.is_subclass_of::<RecordError>().unwrap());
+            assert!(busy.is_subclass_of::<RetryableError>().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_result_code_error_maps_code_and_key() {
+        Python::initialize();
+        Python::attach(|py| {
+            let key = PyTuple::new(py, ["test", "demo", "k"]).unwrap().into_any();
+            let err = result_code_error(py, 2, false, &key);
+            assert!(err.is_instance_of::<RecordNotFound>(py));
+            let value = err.value(py);
+            assert_eq!(value.getattr("code").unwrap().extract::<i32>().unwrap(), 2);
+            assert!(value.getattr("key").unwrap().eq(&key).unwrap());
+
+            let err = result_code_error(py, -1, true, &py.None().into_bound(py));
+            assert!(err.is_instance_of::<ClientError>(py));
+            assert!(raise_for_result(py, 0, false, None).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_parse_lua_error() {
+        assert_eq!(
+            parse_lua_error("/opt/aerospike/usr/udf/lua/test_udf.lua:24: boom: bad input"),
+            LuaError {
+                module: Some("test_udf"),
+                line: Some(24),
+                message: "boom: bad input",
+            }
+        );
+        assert_eq!(
+            parse_lua_error("UDF Execution Error: test_udf.lua:3: attempt to index a nil value"),
+            LuaError {
+                module: Some("test_udf"),
+                line: Some(3),
+                message: "attempt to index a nil value",
+            }
+        );
+        for reason in ["function not found", "see mod.lua: for details"] {
+            assert_eq!(
+                parse_lua_error(reason),
+                LuaError {
+                    module: None,
+                    line: None,
+                    message: reason,
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_udf_bad_response_maps_to_udf_error() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = as_to_pyerr(AsError::UdfBadResponse(
+                "/opt/aerospike/usr/udf/lua/test_udf.lua:7: boom".into(),
+            ));
+            let err = with_udf_call(err, "test_udf", "fail");
+            assert!(err.is_instance_of::<UDFError>(py));
+            let value = err.value(py);
+            assert_eq!(
+                value.getattr("code").unwrap().extract::<i32>().unwrap(),
+                100
+            );
+            let attr = |name: &str| value.getattr(name).unwrap();
+            assert_eq!(attr("module").extract::<String>().unwrap(), "test_udf");
+            assert_eq!(attr("function").extract::<String>().unwrap(), "fail");
+            assert_eq!(attr("line").extract::<u32>().unwrap(), 7);
+            assert_eq!(attr("message").extract::<String>().unwrap(), "boom");
+
+            let err = with_udf_call(
+                as_to_pyerr(AsError::UdfBadResponse("function not found".into())),
+                "test_udf",
+                "missing",
+            );
+            let value = err.value(py);
+            assert_eq!(
+                value
+                    .getattr("module")
+                    .unwrap()
+                    .extract::<String>()
+                    .unwrap(),
+                "test_udf"
+            );
+            assert!(value.getattr("line").unwrap().is_none());
+        });
+    }
+
     #[test]
     fn test_result_code_to_int_unknown() {
         assert_eq!(result_code_to_int(&ResultCode::Unknown(250)), 250);
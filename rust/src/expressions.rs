@@ -4,18 +4,81 @@
 //! identifying the expression type (e.g. `"eq"`, `"int_bin"`, `"and"`).
 //! The `aerospike_py.exp` Python module provides builder functions that produce
 //! these dicts; this module recursively converts them to Rust `Expression` values.
+//! [`PyCompiledExpression`] holds an already converted tree so hot paths can
+//! skip the walk.
 
-use aerospike_core::expressions::{self, ExpType, Expression};
+use aerospike_core::expressions::{
+    self, bitwise as bit_exp, hll as hll_exp, lists as list_exp, maps as map_exp, ExpType,
+    Expression,
+};
+use aerospike_core::operations::cdt_context::{self as cdt, CdtContext};
+use aerospike_core::operations::lists::ToListReturnTypeBitmask;
+use aerospike_core::operations::maps::ToMapReturnTypeBitmask;
 use aerospike_core::Value;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyTuple};
 
+use crate::constants::RETURN_INVERTED;
+use crate::errors::ParamError;
 use crate::types::value::py_to_value;
 
+/// An expression tree converted once and reusable across calls.
+///
+/// Accepted anywhere an expression dict is: as a policy `filter_expression`
+/// or as a sub-expression of a larger tree.
+#[pyclass(name = "CompiledExpression", module = "aerospike_py", frozen)]
+pub struct PyCompiledExpression {
+    expr: Expression,
+    /// Root `__expr__` name, for `repr()`.
+    op: String,
+}
+
+impl PyCompiledExpression {
+    /// Wrap an expression built in Rust; `op` is shown by `repr()`.
+    pub(crate) fn from_expression(expr: Expression, op: &str) -> Self {
+        Self {
+            expr,
+            op: op.to_string(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyCompiledExpression {
+    #[new]
+    fn new(expr: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let compiled = py_to_expression(expr)?;
+        let op = match expr.cast::<PyCompiledExpression>() {
+            Ok(c) => c.get().op.clone(),
+            Err(_) => expr.get_item("__expr__")?.extract()?,
+        };
+        Ok(Self { expr: compiled, op })
+    }
+
+    fn __repr__(&self) -> String {
+        format!("CompiledExpression({})", self.op)
+    }
+}
+
 /// Convert a Python expression dict tree into an aerospike-core Expression.
+///
+/// The tree is type-checked first (see [`check_types`]), so obviously
+/// mismatched operands fail here with the path of the offending node.
 pub fn py_to_expression(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
+    if let Ok(compiled) = obj.cast::<PyCompiledExpression>() {
+        return Ok(compiled.get().expr.clone());
+    }
+    check_types(obj)?;
+    convert(obj)
+}
+
+/// Recursively convert an already type-checked expression tree.
+fn convert(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
+    if let Ok(compiled) = obj.cast::<PyCompiledExpression>() {
+        return Ok(compiled.get().expr.clone());
+    }
     let dict = obj.cast::<PyDict>().map_err(|_| {
-        pyo3::exceptions::PyTypeError::new_err(
+        ParamError::new_err(
             "Expression must be a dict with '__expr__' key (use aerospike_py.exp builder functions)",
         )
     })?;
@@ -23,7 +86,7 @@ pub fn py_to_expression(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
     let op: String = dict
         .get_item("__expr__")?
         .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(
+            ParamError::new_err(
                 "Expression dict missing '__expr__' key. Use aerospike_py.exp builder functions.",
             )
         })?
@@ -79,9 +142,7 @@ pub fn py_to_expression(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
 
         // ── Variadic operations (take Vec<Expression>) ──
         "and" | "or" | "xor" | "num_add" | "num_sub" | "num_mul" | "num_div" | "min" | "max"
-        | "int_and" | "int_or" | "int_xor" | "cond" | "let" => {
-            convert_variadic_op(op.as_str(), dict)
-        }
+        | "int_and" | "int_or" | "int_xor" => convert_variadic_op(op.as_str(), dict),
 
         // ── Unary operations (take single Expression from "exprs" list) ──
         "num_abs" | "num_floor" | "num_ceil" | "to_int" | "to_float" | "int_not" | "int_count" => {
@@ -100,7 +161,46 @@ pub fn py_to_expression(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
             Ok(expressions::regex_compare(regex, flags, bin_expr))
         }
 
+        // ── List CDT reads ──
+        "list_size"
+        | "list_get_by_value"
+        | "list_get_by_value_range"
+        | "list_get_by_value_list"
+        | "list_get_by_value_rel_rank_range"
+        | "list_get_by_value_rel_rank_range_count"
+        | "list_get_by_index"
+        | "list_get_by_index_range"
+        | "list_get_by_index_range_count"
+        | "list_get_by_rank"
+        | "list_get_by_rank_range"
+        | "list_get_by_rank_range_count" => convert_list_read(op.as_str(), dict),
+
+        // ── Map CDT reads ──
+        "map_size"
+        | "map_get_by_key"
+        | "map_get_by_key_range"
+        | "map_get_by_key_list"
+        | "map_get_by_value"
+        | "map_get_by_value_range"
+        | "map_get_by_value_list" => convert_map_read(op.as_str(), dict),
+
+        // ── Blob bit reads ──
+        "bit_get" | "bit_count" | "bit_lscan" | "bit_rscan" | "bit_get_int" => {
+            convert_bit_read(op.as_str(), dict)
+        }
+
+        // ── HLL reads ──
+        "hll_get_count" => Ok(hll_exp::get_count(parse_sub_expr(dict, "bin")?)),
+        "hll_describe" => Ok(hll_exp::describe(parse_sub_expr(dict, "bin")?)),
+        "hll_get_union"
+        | "hll_get_union_count"
+        | "hll_get_intersect_count"
+        | "hll_get_similarity"
+        | "hll_may_contain" => convert_hll_read(op.as_str(), dict),
+
         // ── Control flow ──
+        "cond" => convert_cond(dict),
+        "let" => convert_let(dict),
         "var" => Ok(expressions::var(get_required::<String>(dict, "name")?)),
         "def" => {
             let name: String = get_required(dict, "name")?;
@@ -108,7 +208,7 @@ pub fn py_to_expression(obj: &Bound<'_, PyAny>) -> PyResult<Expression> {
             Ok(expressions::def(name, value))
         }
 
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+        _ => Err(ParamError::new_err(format!(
             "Unknown expression type: '{op}'. Use aerospike_py.exp builder functions."
         ))),
     }
@@ -173,8 +273,6 @@ fn convert_variadic_op(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expressio
         "int_and" => Ok(expressions::int_and(exprs)),
         "int_or" => Ok(expressions::int_or(exprs)),
         "int_xor" => Ok(expressions::int_xor(exprs)),
-        "cond" => Ok(expressions::cond(exprs)),
-        "let" => Ok(expressions::exp_let(exprs)),
         _ => crate::bug_report::internal_bug!(
             "expressions::convert_variadic_op",
             "unexpected op: {op}"
@@ -186,9 +284,7 @@ fn convert_variadic_op(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expressio
 fn convert_unary_op(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
     let exprs = parse_sub_expr_list(dict, "exprs")?;
     let expr = exprs.into_iter().next().ok_or_else(|| {
-        pyo3::exceptions::PyValueError::new_err(format!(
-            "{op} requires at least 1 expression in 'exprs'"
-        ))
+        ParamError::new_err(format!("{op} requires at least 1 expression in 'exprs'"))
     })?;
     match op {
         "num_abs" => Ok(expressions::num_abs(expr)),
@@ -208,18 +304,18 @@ fn convert_unary_op(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression>
 fn convert_binary_pair_op(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
     let exprs = parse_sub_expr_list(dict, "exprs")?;
     if exprs.len() != 2 {
-        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "{op} requires exactly 2 expressions, got {}",
             exprs.len()
         )));
     }
     let mut iter = exprs.into_iter();
-    let first = iter.next().ok_or_else(|| {
-        pyo3::exceptions::PyValueError::new_err(format!("{op}: missing first expression"))
-    })?;
-    let second = iter.next().ok_or_else(|| {
-        pyo3::exceptions::PyValueError::new_err(format!("{op}: missing second expression"))
-    })?;
+    let first = iter
+        .next()
+        .ok_or_else(|| ParamError::new_err(format!("{op}: missing first expression")))?;
+    let second = iter
+        .next()
+        .ok_or_else(|| ParamError::new_err(format!("{op}: missing second expression")))?;
     match op {
         "num_mod" => Ok(expressions::num_mod(first, second)),
         "num_pow" => Ok(expressions::num_pow(first, second)),
@@ -236,6 +332,596 @@ fn convert_binary_pair_op(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expres
     }
 }
 
+/// Convert `cond`, given either as flat "exprs" (`bool1, action1, ..., default`)
+/// or as "cases" (`(bool, action)` pairs) plus "default".
+fn convert_cond(dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
+    let Some(cases) = dict.get_item("cases")? else {
+        let exprs = parse_sub_expr_list(dict, "exprs")?;
+        if exprs.len() < 3 || exprs.len() % 2 == 0 {
+            return Err(ParamError::new_err(format!(
+                "cond takes condition/value pairs followed by a default value, got {} \
+                 expressions",
+                exprs.len()
+            )));
+        }
+        return Ok(expressions::cond(exprs));
+    };
+    let cases: Vec<Bound<'_, PyAny>> = cases
+        .extract()
+        .map_err(|_| ParamError::new_err("'cases' must be a list of (condition, value) pairs"))?;
+    if cases.is_empty() {
+        return Err(ParamError::new_err(
+            "cond needs at least one (condition, value) pair",
+        ));
+    }
+    let default = get_required_any(dict, "default")?;
+    if !is_expression(&default) {
+        return Err(ParamError::new_err(
+            "cond needs a default value expression after the (condition, value) pairs",
+        ));
+    }
+    let mut exprs = Vec::with_capacity(cases.len() * 2 + 1);
+    for (i, case) in cases.iter().enumerate() {
+        let pair: Vec<Bound<'_, PyAny>> = case.extract().unwrap_or_default();
+        let [condition, value] = pair.as_slice() else {
+            return Err(ParamError::new_err(format!(
+                "cond case {i} must be a (condition, value) pair"
+            )));
+        };
+        exprs.push(convert(condition)?);
+        exprs.push(convert(value)?);
+    }
+    exprs.push(convert(&default)?);
+    Ok(expressions::cond(exprs))
+}
+
+/// Convert `let`: one or more `def` bindings followed by the scope expression.
+fn convert_let(dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
+    let obj = get_required_any(dict, "exprs")?;
+    let list = obj
+        .cast::<PyList>()
+        .map_err(|_| ParamError::new_err("'exprs' must be a list of expressions"))?;
+    let n = list.len();
+    if n < 2 {
+        return Err(ParamError::new_err(
+            "let_ takes one or more def_() bindings followed by a scope expression",
+        ));
+    }
+    for (i, item) in list.iter().enumerate() {
+        let is_def = matches!(as_expr_dict(&item)?, Some((_, op)) if op == "def");
+        if i < n - 1 && !is_def {
+            return Err(ParamError::new_err(format!(
+                "let_ argument {i} must be a def_() binding"
+            )));
+        }
+        if i == n - 1 && is_def {
+            return Err(ParamError::new_err(
+                "the last let_ argument must be the scope expression, not a def_() binding",
+            ));
+        }
+    }
+    Ok(expressions::exp_let(parse_sub_expr_list(dict, "exprs")?))
+}
+
+/// Convert list CDT read operations on the "bin" expression, located by "ctx".
+fn convert_list_read(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
+    let bin = parse_sub_expr(dict, "bin")?;
+    let ctx = parse_ctx(dict)?;
+    if op == "list_size" {
+        return Ok(list_exp::size(bin, &ctx));
+    }
+    let rt = get_list_return_type(dict)?;
+    match op {
+        "list_get_by_value" => Ok(list_exp::get_by_value(
+            rt,
+            parse_sub_expr(dict, "value")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_value_range" => Ok(list_exp::get_by_value_range(
+            rt,
+            parse_optional_sub_expr(dict, "value_begin")?,
+            parse_optional_sub_expr(dict, "value_end")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_value_list" => Ok(list_exp::get_by_value_list(
+            rt,
+            parse_sub_expr(dict, "values")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_value_rel_rank_range" => Ok(list_exp::get_by_value_relative_rank_range(
+            rt,
+            parse_sub_expr(dict, "value")?,
+            parse_sub_expr(dict, "rank")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_value_rel_rank_range_count" => {
+            Ok(list_exp::get_by_value_relative_rank_range_count(
+                rt,
+                parse_sub_expr(dict, "value")?,
+                parse_sub_expr(dict, "rank")?,
+                parse_sub_expr(dict, "count")?,
+                bin,
+                &ctx,
+            ))
+        }
+        "list_get_by_index" => Ok(list_exp::get_by_index(
+            rt,
+            int_to_exp_type(get_required(dict, "value_type")?)?,
+            parse_sub_expr(dict, "index")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_index_range" => Ok(list_exp::get_by_index_range(
+            rt,
+            parse_sub_expr(dict, "index")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_index_range_count" => Ok(list_exp::get_by_index_range_count(
+            rt,
+            parse_sub_expr(dict, "index")?,
+            parse_sub_expr(dict, "count")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_rank" => Ok(list_exp::get_by_rank(
+            rt,
+            int_to_exp_type(get_required(dict, "value_type")?)?,
+            parse_sub_expr(dict, "rank")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_rank_range" => Ok(list_exp::get_by_rank_range(
+            rt,
+            parse_sub_expr(dict, "rank")?,
+            bin,
+            &ctx,
+        )),
+        "list_get_by_rank_range_count" => Ok(list_exp::get_by_rank_range_count(
+            rt,
+            parse_sub_expr(dict, "rank")?,
+            parse_sub_expr(dict, "count")?,
+            bin,
+            &ctx,
+        )),
+        _ => crate::bug_report::internal_bug!(
+            "expressions::convert_list_read",
+            "unexpected op: {op}"
+        ),
+    }
+}
+
+/// Convert map CDT read operations on the "bin" expression, located by "ctx".
+fn convert_map_read(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
+    let bin = parse_sub_expr(dict, "bin")?;
+    let ctx = parse_ctx(dict)?;
+    if op == "map_size" {
+        return Ok(map_exp::size(bin, &ctx));
+    }
+    let rt = get_map_return_type(dict)?;
+    match op {
+        "map_get_by_key" => Ok(map_exp::get_by_key(
+            rt,
+            int_to_exp_type(get_required(dict, "value_type")?)?,
+            parse_sub_expr(dict, "key")?,
+            bin,
+            &ctx,
+        )),
+        "map_get_by_key_range" => Ok(map_exp::get_by_key_range(
+            rt,
+            parse_optional_sub_expr(dict, "key_begin")?,
+            parse_optional_sub_expr(dict, "key_end")?,
+            bin,
+            &ctx,
+        )),
+        "map_get_by_key_list" => Ok(map_exp::get_by_key_list(
+            rt,
+            parse_sub_expr(dict, "keys")?,
+            bin,
+            &ctx,
+        )),
+        "map_get_by_value" => Ok(map_exp::get_by_value(
+            rt,
+            parse_sub_expr(dict, "value")?,
+            bin,
+            &ctx,
+        )),
+        "map_get_by_value_range" => Ok(map_exp::get_by_value_range(
+            rt,
+            parse_optional_sub_expr(dict, "value_begin")?,
+            parse_optional_sub_expr(dict, "value_end")?,
+            bin,
+            &ctx,
+        )),
+        "map_get_by_value_list" => Ok(map_exp::get_by_value_list(
+            rt,
+            parse_sub_expr(dict, "values")?,
+            bin,
+            &ctx,
+        )),
+        _ => {
+            crate::bug_report::internal_bug!("expressions::convert_map_read", "unexpected op: {op}")
+        }
+    }
+}
+
+/// Convert blob bit reads over `[bit_offset, bit_offset + bit_size)` of "bin".
+fn convert_bit_read(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
+    let bit_offset = parse_sub_expr(dict, "bit_offset")?;
+    let bit_size = parse_sub_expr(dict, "bit_size")?;
+    let bin = parse_sub_expr(dict, "bin")?;
+    match op {
+        "bit_get" => Ok(bit_exp::get(bit_offset, bit_size, bin)),
+        "bit_count" => Ok(bit_exp::count(bit_offset, bit_size, bin)),
+        "bit_lscan" => Ok(bit_exp::lscan(
+            bit_offset,
+            bit_size,
+            parse_sub_expr(dict, "value")?,
+            bin,
+        )),
+        "bit_rscan" => Ok(bit_exp::rscan(
+            bit_offset,
+            bit_size,
+            parse_sub_expr(dict, "value")?,
+            bin,
+        )),
+        "bit_get_int" => Ok(bit_exp::get_int(
+            bit_offset,
+            bit_size,
+            get_required(dict, "signed")?,
+            bin,
+        )),
+        _ => {
+            crate::bug_report::internal_bug!("expressions::convert_bit_read", "unexpected op: {op}")
+        }
+    }
+}
+
+/// Convert HLL reads that compare "bin" with the HLL values in "list".
+fn convert_hll_read(op: &str, dict: &Bound<'_, PyDict>) -> PyResult<Expression> {
+    let list = parse_sub_expr(dict, "list")?;
+    let bin = parse_sub_expr(dict, "bin")?;
+    match op {
+        "hll_get_union" => Ok(hll_exp::get_union(list, bin)),
+        "hll_get_union_count" => Ok(hll_exp::get_union_count(list, bin)),
+        "hll_get_intersect_count" => Ok(hll_exp::get_intersect_count(list, bin)),
+        "hll_get_similarity" => Ok(hll_exp::get_similarity(list, bin)),
+        "hll_may_contain" => Ok(hll_exp::may_contain(list, bin)),
+        _ => {
+            crate::bug_report::internal_bug!("expressions::convert_hll_read", "unexpected op: {op}")
+        }
+    }
+}
+
+// ── CDT helpers ───────────────────────────────────────────────────
+
+/// A validated `LIST_RETURN_*` value, passed through to aerospike-core as is.
+#[derive(Clone, Copy)]
+struct ListReturn(i64);
+
+impl ToListReturnTypeBitmask for ListReturn {
+    fn to_bitmask(self) -> i64 {
+        self.0
+    }
+}
+
+/// Read "return_type", rejecting values aerospike-core cannot map to a
+/// result type (it panics on those, e.g. `LIST_RETURN_NONE`).
+fn get_list_return_type(dict: &Bound<'_, PyDict>) -> PyResult<ListReturn> {
+    let rt: i64 = get_required(dict, "return_type")?;
+    match rt & !RETURN_INVERTED {
+        1..=5 | 7 | 13 => Ok(ListReturn(rt)),
+        _ => Err(ParamError::new_err(format!(
+            "Invalid list return_type for an expression: {rt}. Use a LIST_RETURN_* constant \
+             other than LIST_RETURN_NONE."
+        ))),
+    }
+}
+
+/// A validated `MAP_RETURN_*` value, passed through to aerospike-core as is.
+#[derive(Clone, Copy)]
+struct MapReturn(i64);
+
+impl ToMapReturnTypeBitmask for MapReturn {
+    fn to_bitmask(self) -> i64 {
+        self.0
+    }
+}
+
+/// Read "return_type", rejecting values aerospike-core cannot map to a
+/// result type (it panics on those, e.g. `MAP_RETURN_NONE`).
+fn get_map_return_type(dict: &Bound<'_, PyDict>) -> PyResult<MapReturn> {
+    let rt: i64 = get_required(dict, "return_type")?;
+    match rt & !RETURN_INVERTED {
+        1..=8 | 13 => Ok(MapReturn(rt)),
+        _ => Err(ParamError::new_err(format!(
+            "Invalid map return_type for an expression: {rt}. Use a MAP_RETURN_* constant \
+             other than MAP_RETURN_NONE."
+        ))),
+    }
+}
+
+/// Parse the optional "ctx" list built with `exp.ctx_*` helpers.
+fn parse_ctx(dict: &Bound<'_, PyDict>) -> PyResult<Vec<CdtContext>> {
+    let Some(obj) = dict.get_item("ctx")? else {
+        return Ok(Vec::new());
+    };
+    if obj.is_none() {
+        return Ok(Vec::new());
+    }
+    let list = obj
+        .cast::<PyList>()
+        .map_err(|_| ParamError::new_err("'ctx' must be a list of exp.ctx_* items"))?;
+    list.iter().map(|item| parse_ctx_item(&item)).collect()
+}
+
+fn parse_ctx_item(item: &Bound<'_, PyAny>) -> PyResult<CdtContext> {
+    let invalid =
+        || ParamError::new_err("ctx items must be built with aerospike_py.exp ctx_* functions");
+    let dict = item.cast::<PyDict>().map_err(|_| invalid())?;
+    let kind: String = dict.get_item("__ctx__")?.ok_or_else(invalid)?.extract()?;
+    let val = get_required_any(dict, "val")?;
+    match kind.as_str() {
+        "list_index" => Ok(cdt::ctx_list_index(val.extract()?)),
+        "list_rank" => Ok(cdt::ctx_list_rank(val.extract()?)),
+        "list_value" => Ok(cdt::ctx_list_value(py_to_value(&val)?)),
+        "map_index" => Ok(cdt::ctx_map_index(py_to_value(&val)?)),
+        "map_rank" => Ok(cdt::ctx_map_rank(val.extract()?)),
+        "map_key" => Ok(cdt::ctx_map_key(py_to_value(&val)?)),
+        "map_value" => Ok(cdt::ctx_map_value(py_to_value(&val)?)),
+        _ => Err(ParamError::new_err(format!("Unknown ctx type: '{kind}'"))),
+    }
+}
+
+// ── Type checking ─────────────────────────────────────────────────
+
+/// Reject trees whose operand types obviously cannot match.
+///
+/// The server would fail such a filter with an opaque parameter error; this
+/// names the node instead, as a path of dict keys from the root (e.g.
+/// `and.exprs[1]`). Only types known from the tree alone are compared, so
+/// anything involving variables, `nil()` or CDT reads passes through.
+fn check_types(obj: &Bound<'_, PyAny>) -> PyResult<()> {
+    match as_expr_dict(obj)? {
+        Some((dict, op)) => check_node(&dict, &op, &op),
+        // Not an expression dict: conversion reports the shape error.
+        None => Ok(()),
+    }
+}
+
+/// `(dict, op)` when `obj` is an expression dict with a string `__expr__`.
+fn as_expr_dict<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<(Bound<'py, PyDict>, String)>> {
+    let Ok(dict) = obj.cast::<PyDict>() else {
+        return Ok(None);
+    };
+    match dict.get_item("__expr__")? {
+        Some(op) => match op.extract::<String>() {
+            Ok(op) => Ok(Some((dict.clone(), op))),
+            Err(_) => Ok(None),
+        },
+        None => Ok(None),
+    }
+}
+
+fn check_node(dict: &Bound<'_, PyDict>, op: &str, path: &str) -> PyResult<()> {
+    for (key, val) in dict.iter() {
+        let Ok(key) = key.extract::<String>() else {
+            continue;
+        };
+        if key == "__expr__" {
+            continue;
+        }
+        if let Some((child, child_op)) = as_expr_dict(&val)? {
+            check_node(&child, &child_op, &format!("{path}.{key}"))?;
+        } else if let Ok(list) = val.cast::<PyList>() {
+            for (i, item) in list.iter().enumerate() {
+                if let Some((child, child_op)) = as_expr_dict(&item)? {
+                    check_node(&child, &child_op, &format!("{path}.{key}[{i}]"))?;
+                } else if let Ok(pair) = item.cast::<PyTuple>() {
+                    // cond "cases"
+                    for (j, item) in pair.iter().enumerate() {
+                        if let Some((child, child_op)) = as_expr_dict(&item)? {
+                            check_node(&child, &child_op, &format!("{path}.{key}[{i}][{j}]"))?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    match op {
+        "eq" | "ne" | "gt" | "ge" | "lt" | "le" => {
+            let (Some(left), Some(right)) = (operand(dict, "left")?, operand(dict, "right")?)
+            else {
+                return Ok(());
+            };
+            if left.1 != right.1 {
+                return Err(type_mismatch(
+                    path,
+                    format!(
+                        "{op} compares {} ({:?}) with {} ({:?})",
+                        left.0, left.1, right.0, right.1
+                    ),
+                ));
+            }
+        }
+        "and" | "or" | "xor" | "not" => {
+            for (name, ty) in operands(dict)? {
+                if ty != ExpType::BOOL {
+                    return Err(type_mismatch(
+                        path,
+                        format!("{op} needs boolean operands, got {name} ({ty:?})"),
+                    ));
+                }
+            }
+        }
+        "cond" => {
+            for (name, ty) in cond_conditions(dict)? {
+                if ty != ExpType::BOOL {
+                    return Err(type_mismatch(
+                        path,
+                        format!("cond conditions must be boolean, got {name} ({ty:?})"),
+                    ));
+                }
+            }
+        }
+        "num_add" | "num_sub" | "num_mul" | "num_div" | "num_mod" | "num_pow" | "num_log"
+        | "min" | "max" => {
+            let operands = operands(dict)?;
+            if let Some((name, ty)) = operands
+                .iter()
+                .find(|(_, ty)| *ty != ExpType::INT && *ty != ExpType::FLOAT)
+            {
+                return Err(type_mismatch(
+                    path,
+                    format!("{op} needs numeric operands, got {name} ({ty:?})"),
+                ));
+            }
+            if let [first, rest @ ..] = operands.as_slice() {
+                if let Some(other) = rest.iter().find(|(_, ty)| *ty != first.1) {
+                    return Err(type_mismatch(
+                        path,
+                        format!(
+                            "{op} mixes {} ({:?}) with {} ({:?}); convert with to_int() or \
+                             to_float()",
+                            first.0, first.1, other.0, other.1
+                        ),
+                    ));
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn type_mismatch(path: &str, detail: String) -> PyErr {
+    ParamError::new_err(format!("Expression type mismatch at {path}: {detail}"))
+}
+
+/// `(op, type)` of the sub-expression under `key`, when its type is known.
+fn operand(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<(String, ExpType)>> {
+    let Some(val) = dict.get_item(key)? else {
+        return Ok(None);
+    };
+    match as_expr_dict(&val)? {
+        Some((child, op)) => Ok(static_type(&child, &op)?.map(|ty| (op, ty))),
+        None => Ok(None),
+    }
+}
+
+/// Operands under "expr" or "exprs" whose type is known.
+fn operands(dict: &Bound<'_, PyDict>) -> PyResult<Vec<(String, ExpType)>> {
+    if let Some(single) = operand(dict, "expr")? {
+        return Ok(vec![single]);
+    }
+    let mut known = Vec::new();
+    if let Some(list) = dict.get_item("exprs")? {
+        if let Ok(list) = list.cast::<PyList>() {
+            for item in list.iter() {
+                if let Some((child, op)) = as_expr_dict(&item)? {
+                    if let Some(ty) = static_type(&child, &op)? {
+                        known.push((op, ty));
+                    }
+                }
+            }
+        }
+    }
+    Ok(known)
+}
+
+/// Conditions of a `cond` (flat or paired form) whose type is known.
+fn cond_conditions(dict: &Bound<'_, PyDict>) -> PyResult<Vec<(String, ExpType)>> {
+    let mut known = Vec::new();
+    let mut push = |item: &Bound<'_, PyAny>| -> PyResult<()> {
+        if let Some((child, op)) = as_expr_dict(item)? {
+            if let Some(ty) = static_type(&child, &op)? {
+                known.push((op, ty));
+            }
+        }
+        Ok(())
+    };
+    if let Some(cases) = dict.get_item("cases")? {
+        if let Ok(cases) = cases.cast::<PyList>() {
+            for case in cases.iter() {
+                if let Ok(pair) = case.cast::<PyTuple>() {
+                    if let Ok(condition) = pair.get_item(0) {
+                        push(&condition)?;
+                    }
+                }
+            }
+        }
+    } else if let Some(exprs) = dict.get_item("exprs")? {
+        if let Ok(exprs) = exprs.cast::<PyList>() {
+            // Every even position except the trailing default.
+            let n = exprs.len();
+            for i in (0..n.saturating_sub(1)).step_by(2) {
+                push(&exprs.get_item(i)?)?;
+            }
+        }
+    }
+    Ok(known)
+}
+
+/// Result type of a node when it follows from the node alone (or from its
+/// already checked operands, for arithmetic).
+fn static_type(dict: &Bound<'_, PyDict>, op: &str) -> PyResult<Option<ExpType>> {
+    Ok(match op {
+        "int_val"
+        | "int_bin"
+        | "bin_type"
+        | "record_size"
+        | "last_update"
+        | "since_update"
+        | "void_time"
+        | "ttl"
+        | "digest_modulo"
+        | "to_int"
+        | "int_and"
+        | "int_or"
+        | "int_xor"
+        | "int_not"
+        | "int_lshift"
+        | "int_rshift"
+        | "int_arshift"
+        | "int_count"
+        | "int_lscan"
+        | "int_rscan"
+        | "list_size"
+        | "map_size"
+        | "bit_count"
+        | "bit_lscan"
+        | "bit_rscan"
+        | "bit_get_int"
+        | "hll_get_count"
+        | "hll_get_union_count"
+        | "hll_get_intersect_count"
+        | "hll_may_contain" => Some(ExpType::INT),
+        "float_val" | "float_bin" | "to_float" | "hll_get_similarity" => Some(ExpType::FLOAT),
+        "string_val" | "string_bin" | "set_name" => Some(ExpType::STRING),
+        "bool_val" | "bool_bin" | "bin_exists" | "key_exists" | "is_tombstone" | "eq" | "ne"
+        | "gt" | "ge" | "lt" | "le" | "and" | "or" | "xor" | "not" | "regex_compare"
+        | "geo_compare" => Some(ExpType::BOOL),
+        "blob_val" | "blob_bin" | "bit_get" => Some(ExpType::BLOB),
+        "list_val" | "list_bin" | "hll_describe" => Some(ExpType::LIST),
+        "map_val" | "map_bin" => Some(ExpType::MAP),
+        "geo_val" | "geo_bin" => Some(ExpType::GEO),
+        "hll_bin" | "hll_get_union" => Some(ExpType::HLL),
+        "key" => match dict.get_item("exp_type")?.map(|t| t.extract::<i64>()) {
+            Some(Ok(t)) => int_to_exp_type(t).ok(),
+            _ => None,
+        },
+        "num_add" | "num_sub" | "num_mul" | "num_div" | "num_mod" | "num_abs" | "num_floor"
+        | "num_ceil" | "min" | "max" => operands(dict)?.first().map(|(_, ty)| *ty),
+        "num_pow" | "num_log" => Some(ExpType::FLOAT),
+        _ => None,
+    })
+}
+
 // ── Field extraction helpers ──────────────────────────────────────
 
 fn get_required<'py, T: for<'a> FromPyObject<'a, 'py, Error = PyErr>>(
@@ -243,35 +929,36 @@ fn get_required<'py, T: for<'a> FromPyObject<'a, 'py, Error = PyErr>>(
     key: &str,
 ) -> PyResult<T> {
     dict.get_item(key)?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err(format!(
-                "Expression missing required field: '{key}'"
-            ))
-        })?
+        .ok_or_else(|| ParamError::new_err(format!("Expression missing required field: '{key}'")))?
         .extract()
 }
 
 fn get_required_any<'py>(dict: &Bound<'py, PyDict>, key: &str) -> PyResult<Bound<'py, PyAny>> {
-    dict.get_item(key)?.ok_or_else(|| {
-        pyo3::exceptions::PyValueError::new_err(format!(
-            "Expression missing required field: '{key}'"
-        ))
-    })
+    dict.get_item(key)?
+        .ok_or_else(|| ParamError::new_err(format!("Expression missing required field: '{key}'")))
 }
 
 fn parse_sub_expr(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Expression> {
     let obj = get_required_any(dict, key)?;
-    py_to_expression(&obj)
+    convert(&obj)
+}
+
+/// Parse an optional sub-expression; a missing key or `None` yields `None`.
+fn parse_optional_sub_expr(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<Expression>> {
+    match dict.get_item(key)? {
+        Some(obj) if !obj.is_none() => convert(&obj).map(Some),
+        _ => Ok(None),
+    }
 }
 
 fn parse_sub_expr_list(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Vec<Expression>> {
     let obj = get_required_any(dict, key)?;
-    let list = obj.cast::<PyList>().map_err(|_| {
-        pyo3::exceptions::PyTypeError::new_err(format!("'{key}' must be a list of expressions"))
-    })?;
+    let list = obj
+        .cast::<PyList>()
+        .map_err(|_| ParamError::new_err(format!("'{key}' must be a list of expressions")))?;
     let mut result = Vec::with_capacity(list.len());
     for item in list.iter() {
-        result.push(py_to_expression(&item)?);
+        result.push(convert(&item)?);
     }
     Ok(result)
 }
@@ -289,7 +976,7 @@ fn int_to_exp_type(val: i64) -> PyResult<ExpType> {
         7 => Ok(ExpType::FLOAT),
         8 => Ok(ExpType::GEO),
         9 => Ok(ExpType::HLL),
-        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+        _ => Err(ParamError::new_err(format!(
             "Invalid ExpType value: {val}. Expected 0-9."
         ))),
     }
@@ -298,7 +985,7 @@ fn int_to_exp_type(val: i64) -> PyResult<ExpType> {
 fn py_list_to_values(obj: &Bound<'_, PyAny>) -> PyResult<Vec<Value>> {
     let list = obj
         .cast::<PyList>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a list for list_val"))?;
+        .map_err(|_| ParamError::new_err("Expected a list for list_val"))?;
     let mut values = Vec::with_capacity(list.len());
     for item in list.iter() {
         values.push(py_to_value(&item)?);
@@ -309,7 +996,7 @@ fn py_list_to_values(obj: &Bound<'_, PyAny>) -> PyResult<Vec<Value>> {
 fn py_dict_to_hashmap(obj: &Bound<'_, PyAny>) -> PyResult<std::collections::HashMap<Value, Value>> {
     let dict = obj
         .cast::<PyDict>()
-        .map_err(|_| pyo3::exceptions::PyTypeError::new_err("Expected a dict for map_val"))?;
+        .map_err(|_| ParamError::new_err("Expected a dict for map_val"))?;
     let mut map = std::collections::HashMap::new();
     for (k, v) in dict.iter() {
         map.insert(py_to_value(&k)?, py_to_value(&v)?);
@@ -317,11 +1004,244 @@ fn py_dict_to_hashmap(obj: &Bound<'_, PyAny>) -> PyResult<std::collections::Hash
     Ok(map)
 }
 
-/// Check if a Python object is an expression dict (has "__expr__" key).
+/// Check if a Python object is an expression dict (has "__expr__" key) or a
+/// [`PyCompiledExpression`].
 pub fn is_expression(obj: &Bound<'_, PyAny>) -> bool {
-    if let Ok(dict) = obj.cast::<PyDict>() {
+    if obj.is_instance_of::<PyCompiledExpression>() {
+        true
+    } else if let Ok(dict) = obj.cast::<PyDict>() {
         dict.get_item("__expr__").ok().flatten().is_some()
     } else {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    fn expr<'py>(py: Python<'py>, op: &str) -> Bound<'py, PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("__expr__", op).unwrap();
+        d
+    }
+
+    fn ctx<'py>(py: Python<'py>, kind: &str, val: i64) -> Bound<'py, PyDict> {
+        let d = PyDict::new(py);
+        d.set_item("__ctx__", kind).unwrap();
+        d.set_item("val", val).unwrap();
+        d
+    }
+
+    fn list_bin<'py>(py: Python<'py>) -> Bound<'py, PyDict> {
+        let bin = expr(py, "list_bin");
+        bin.set_item("name", "tags").unwrap();
+        bin
+    }
+
+    #[test]
+    fn list_read_with_ctx_converts() {
+        Python::initialize();
+        Python::attach(|py| {
+            let index = expr(py, "int_val");
+            index.set_item("val", 0).unwrap();
+            let read = expr(py, "list_get_by_index");
+            read.set_item("return_type", 7).unwrap();
+            read.set_item("value_type", 2).unwrap();
+            read.set_item("index", index).unwrap();
+            read.set_item("bin", list_bin(py)).unwrap();
+            read.set_item("ctx", vec![ctx(py, "list_index", 1)])
+                .unwrap();
+            assert!(py_to_expression(&read).is_ok());
+
+            let size = expr(py, "list_size");
+            size.set_item("bin", list_bin(py)).unwrap();
+            assert!(py_to_expression(&size).is_ok());
+        });
+    }
+
+    #[test]
+    fn list_read_rejects_return_type_none() {
+        Python::initialize();
+        Python::attach(|py| {
+            let rank = expr(py, "int_val");
+            rank.set_item("val", 0).unwrap();
+            let read = expr(py, "list_get_by_rank_range");
+            read.set_item("return_type", 0).unwrap();
+            read.set_item("rank", rank).unwrap();
+            read.set_item("bin", list_bin(py)).unwrap();
+            let err = py_to_expression(&read).unwrap_err();
+            assert!(err.to_string().contains("return_type"));
+        });
+    }
+
+    #[test]
+    fn map_read_converts_and_rejects_return_type_none() {
+        Python::initialize();
+        Python::attach(|py| {
+            let bin = expr(py, "map_bin");
+            bin.set_item("name", "attrs").unwrap();
+            let key = expr(py, "string_val");
+            key.set_item("val", "tier").unwrap();
+            let read = expr(py, "map_get_by_key");
+            read.set_item("return_type", 7).unwrap();
+            read.set_item("value_type", 3).unwrap();
+            read.set_item("key", key).unwrap();
+            read.set_item("bin", bin).unwrap();
+            read.set_item("ctx", vec![ctx(py, "map_index", 0)]).unwrap();
+            assert!(py_to_expression(&read).is_ok());
+
+            read.set_item("return_type", 0).unwrap();
+            let err = py_to_expression(&read).unwrap_err();
+            assert!(err.to_string().contains("return_type"));
+        });
+    }
+
+    #[test]
+    fn bit_and_hll_reads_convert() {
+        Python::initialize();
+        Python::attach(|py| {
+            let int_val = |v: i64| {
+                let d = expr(py, "int_val");
+                d.set_item("val", v).unwrap();
+                d
+            };
+            let blob = expr(py, "blob_bin");
+            blob.set_item("name", "flags").unwrap();
+            let read = expr(py, "bit_get_int");
+            read.set_item("bit_offset", int_val(0)).unwrap();
+            read.set_item("bit_size", int_val(8)).unwrap();
+            read.set_item("signed", false).unwrap();
+            read.set_item("bin", blob).unwrap();
+            assert!(py_to_expression(&read).is_ok());
+
+            let hll = expr(py, "hll_bin");
+            hll.set_item("name", "visitors").unwrap();
+            let list = expr(py, "list_val");
+            list.set_item("val", vec!["a", "b"]).unwrap();
+            let read = expr(py, "hll_may_contain");
+            read.set_item("list", list).unwrap();
+            read.set_item("bin", hll).unwrap();
+            assert!(py_to_expression(&read).is_ok());
+        });
+    }
+
+    #[test]
+    fn compiled_expression_matches_dict_tree() {
+        Python::initialize();
+        Python::attach(|py| {
+            let bin = expr(py, "int_bin");
+            bin.set_item("name", "age").unwrap();
+            let val = expr(py, "int_val");
+            val.set_item("val", 21).unwrap();
+            let ge = expr(py, "ge");
+            ge.set_item("left", bin).unwrap();
+            ge.set_item("right", val).unwrap();
+
+            let compiled = Bound::new(py, PyCompiledExpression::new(&ge).unwrap()).unwrap();
+            assert!(is_expression(compiled.as_any()));
+            assert_eq!(
+                py_to_expression(compiled.as_any()).unwrap(),
+                py_to_expression(&ge).unwrap()
+            );
+            assert_eq!(compiled.get().__repr__(), "CompiledExpression(ge)");
+
+            // Compiled trees nest inside dict trees.
+            let not = expr(py, "not");
+            not.set_item("expr", &compiled).unwrap();
+            assert!(py_to_expression(&not).is_ok());
+
+            assert!(PyCompiledExpression::new(PyDict::new(py).as_any()).is_err());
+        });
+    }
+
+    fn leaf<'py>(py: Python<'py>, op: &str, key: &str, val: &str) -> Bound<'py, PyDict> {
+        let d = expr(py, op);
+        d.set_item(key, val).unwrap();
+        d
+    }
+
+    #[test]
+    fn type_check_reports_mismatch_path() {
+        Python::initialize();
+        Python::attach(|py| {
+            let int_val = expr(py, "int_val");
+            int_val.set_item("val", 1).unwrap();
+            let eq = expr(py, "eq");
+            eq.set_item("left", leaf(py, "string_bin", "name", "name"))
+                .unwrap();
+            eq.set_item("right", int_val).unwrap();
+            let and = expr(py, "and");
+            and.set_item("exprs", vec![expr(py, "key_exists"), eq])
+                .unwrap();
+
+            let err = py_to_expression(&and).unwrap_err();
+            assert!(err.is_instance_of::<ParamError>(py));
+            assert_eq!(
+                err.value(py).to_string(),
+                "Expression type mismatch at and.exprs[1]: eq compares string_bin (STRING) \
+                 with int_val (INT)"
+            );
+        });
+    }
+
+    #[test]
+    fn type_check_rejects_non_bool_logic_and_mixed_numbers() {
+        Python::initialize();
+        Python::attach(|py| {
+            let not = expr(py, "not");
+            not.set_item("expr", leaf(py, "int_bin", "name", "age"))
+                .unwrap();
+            let err = py_to_expression(&not).unwrap_err();
+            assert!(err.to_string().contains("not needs boolean operands"));
+
+            let add = expr(py, "num_add");
+            add.set_item(
+                "exprs",
+                vec![
+                    leaf(py, "int_bin", "name", "a"),
+                    leaf(py, "float_bin", "name", "b"),
+                ],
+            )
+            .unwrap();
+            let err = py_to_expression(&add).unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("num_add mixes int_bin (INT) with float_bin"));
+        });
+    }
+
+    #[test]
+    fn type_check_allows_unknown_and_matching_types() {
+        Python::initialize();
+        Python::attach(|py| {
+            // var() has no static type, so the comparison is left to the server.
+            let eq = expr(py, "eq");
+            eq.set_item("left", leaf(py, "var", "name", "x")).unwrap();
+            eq.set_item("right", leaf(py, "string_val", "val", "a"))
+                .unwrap();
+            assert!(check_types(&eq).is_ok());
+
+            let key = expr(py, "key");
+            key.set_item("exp_type", 3).unwrap();
+            let eq = expr(py, "eq");
+            eq.set_item("left", key).unwrap();
+            eq.set_item("right", leaf(py, "string_val", "val", "a"))
+                .unwrap();
+            assert!(py_to_expression(&eq).is_ok());
+        });
+    }
+
+    #[test]
+    fn unknown_ctx_is_rejected() {
+        Python::initialize();
+        Python::attach(|py| {
+            let size = expr(py, "list_size");
+            size.set_item("bin", list_bin(py)).unwrap();
+            size.set_item("ctx", vec![ctx(py, "list_nowhere", 0)])
+                .unwrap();
+            assert!(py_to_expression(&size).is_err());
+        });
+    }
+}
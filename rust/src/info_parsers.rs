@@ -0,0 +1,249 @@
+//! Parsers for info protocol responses.
+//!
+//! Info responses are plain text: a list of records separated by `;`, each
+//! record a list of `key=value` fields separated by `:` (sets, secondary
+//! indexes) or `,` (UDF modules). These helpers turn them into values that
+//! convert directly to Python lists and dicts.
+
+use std::collections::BTreeMap;
+
+use base64::Engine;
+use pyo3::prelude::*;
+
+use crate::errors::{IndexNotFound, ServerError, UDFError};
+
+/// A field value: integers are parsed, everything else stays text.
+#[derive(Debug, Clone, PartialEq, IntoPyObject)]
+pub enum InfoValue {
+    Int(i64),
+    Str(String),
+}
+
+impl From<&str> for InfoValue {
+    fn from(s: &str) -> Self {
+        s.parse()
+            .map(InfoValue::Int)
+            .unwrap_or_else(|_| InfoValue::Str(s.to_string()))
+    }
+}
+
+pub type InfoRecord = BTreeMap<String, InfoValue>;
+
+/// Raise `ServerError` for `ERROR...` / `FAIL...` responses.
+pub fn check_response(resp: &str) -> PyResult<&str> {
+    let trimmed = resp.trim();
+    if trimmed.starts_with("ERROR") || trimmed.starts_with("FAIL") {
+        return Err(ServerError::new_err(format!(
+            "Info command failed: {trimmed}"
+        )));
+    }
+    Ok(trimmed)
+}
+
+/// `"test;bar"` → `["test", "bar"]`.
+pub fn parse_list(resp: &str) -> Vec<String> {
+    resp.split(';')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// `"a=1:b=x;a=2:b=y"` → `[{"a": 1, "b": "x"}, {"a": 2, "b": "y"}]`.
+///
+/// Fields without `=` are ignored; values keep any `=` after the first.
+pub fn parse_records(resp: &str, field_sep: char) -> Vec<InfoRecord> {
+    parse_list(resp)
+        .iter()
+        .map(|record| {
+            record
+                .split(field_sep)
+                .filter_map(|field| field.split_once('='))
+                .map(|(k, v)| (k.to_string(), InfoValue::from(v)))
+                .collect::<InfoRecord>()
+        })
+        .filter(|record| !record.is_empty())
+        .collect()
+}
+
+/// Parse `udf-list`, renaming `filename` to `name`.
+pub fn parse_udf_list(resp: &str) -> Vec<InfoRecord> {
+    parse_records(resp, ',')
+        .into_iter()
+        .map(|mut record| {
+            if let Some(name) = record.remove("filename") {
+                record.insert("name".to_string(), name);
+            }
+            record
+        })
+        .collect()
+}
+
+/// Decode the Lua source from a `udf-get:filename=<module>` response
+/// (`gen=...;type=LUA;content=<base64>`).
+pub fn parse_udf_get(resp: &str, module: &str) -> PyResult<String> {
+    let fields: BTreeMap<&str, &str> = resp
+        .trim()
+        .split(';')
+        .filter_map(|field| field.split_once('='))
+        .collect();
+    if let Some(err) = fields.get("error") {
+        return Err(UDFError::new_err(format!(
+            "Failed to get UDF module {module}: {err}"
+        )));
+    }
+    let content = fields
+        .get("content")
+        .ok_or_else(|| UDFError::new_err(format!("UDF module {module} not found")))?;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(content)
+        .map_err(|e| UDFError::new_err(format!("Invalid UDF content for {module}: {e}")))?;
+    String::from_utf8(bytes)
+        .map_err(|e| UDFError::new_err(format!("UDF module {module} is not UTF-8: {e}")))
+}
+
+/// Build progress (`load_pct`, 0-100) from a `sindex-stat` response.
+///
+/// A response without `load_pct` means the index is not readable yet (0%).
+pub fn parse_load_pct(resp: &str, index_name: &str) -> PyResult<u32> {
+    let resp = resp.trim();
+    if resp.starts_with("FAIL:201") {
+        return Err(IndexNotFound::new_err(format!(
+            "Index not found: {index_name}"
+        )));
+    }
+    check_response(resp)?;
+    Ok(resp
+        .split([';', ':'])
+        .filter_map(|field| field.split_once('='))
+        .find(|(k, _)| *k == "load_pct")
+        .and_then(|(_, v)| v.parse::<u32>().ok())
+        .unwrap_or(0)
+        .min(100))
+}
+
+/// One node's view of a scan / query job, from a `query-show` / `scan-show`
+/// response.
+#[derive(Debug, PartialEq)]
+pub struct NodeJob {
+    pub done: bool,
+    pub progress_pct: u32,
+    pub records_read: u64,
+}
+
+/// Parse a `query-show:trid=<id>` response (`trid=..:status=done(ok):job-progress=100.00:...`).
+///
+/// `None` means the node does not know the job (`ERROR:2`): it never ran
+/// there, or finished and was pruned from the job monitor.
+pub fn parse_job(resp: &str) -> PyResult<Option<NodeJob>> {
+    let resp = resp.trim();
+    if resp.is_empty() || resp.starts_with("ERROR:2") {
+        return Ok(None);
+    }
+    let resp = check_response(resp)?;
+    let field = |key: &str| {
+        resp.split([';', ':'])
+            .filter_map(|field| field.split_once('='))
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    };
+    let progress = field("job-progress")
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(0.0);
+    Ok(Some(NodeJob {
+        done: field("status").is_some_and(|s| s.starts_with("done")),
+        progress_pct: progress.clamp(0.0, 100.0) as u32,
+        // Servers before 6.0 report `recs-read`.
+        records_read: field("recs-succeeded")
+            .or_else(|| field("recs-read"))
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_set_records_with_numeric_values() {
+        let resp = "ns=test:set=demo:objects=10:index_populating=false;ns=test:set=x:objects=0;";
+        let records = parse_records(resp, ':');
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["set"], InfoValue::Str("demo".into()));
+        assert_eq!(records[0]["objects"], InfoValue::Int(10));
+        assert_eq!(
+            records[0]["index_populating"],
+            InfoValue::Str("false".into())
+        );
+        assert_eq!(records[1]["objects"], InfoValue::Int(0));
+    }
+
+    #[test]
+    fn udf_list_renames_filename() {
+        let records = parse_udf_list("filename=a.lua,hash=abc=,type=LUA;");
+        assert_eq!(records[0]["name"], InfoValue::Str("a.lua".into()));
+        assert_eq!(records[0]["hash"], InfoValue::Str("abc=".into()));
+        assert!(!records[0].contains_key("filename"));
+    }
+
+    #[test]
+    fn udf_get_decodes_content() {
+        let src = parse_udf_get("gen=qX9d;type=LUA;content=cmV0dXJuIDE=;", "m.lua").unwrap();
+        assert_eq!(src, "return 1");
+    }
+
+    #[test]
+    fn udf_get_missing_module() {
+        Python::initialize();
+        assert!(parse_udf_get("error=not_found", "m.lua").is_err());
+        assert!(parse_udf_get("", "m.lua").is_err());
+    }
+
+    #[test]
+    fn load_pct_from_sindex_stat() {
+        Python::initialize();
+        assert_eq!(
+            parse_load_pct("keys=10;load_pct=42;stat_gc_recs=0", "i").unwrap(),
+            42
+        );
+        assert_eq!(parse_load_pct("keys=0", "i").unwrap(), 0);
+        assert!(parse_load_pct("FAIL:201:no index", "i").is_err());
+    }
+
+    #[test]
+    fn parses_job_show() {
+        let resp = "trid=42:job-type=basic:ns=test:set=demo:status=active(ok):\
+                    job-progress=37.50:recs-throttled=0:recs-succeeded=1200:recs-failed=0";
+        assert_eq!(
+            parse_job(resp).unwrap(),
+            Some(NodeJob {
+                done: false,
+                progress_pct: 37,
+                records_read: 1200,
+            })
+        );
+        let legacy = "trid=42:status=done(ok):job-progress=100.00:recs-read=7";
+        assert_eq!(
+            parse_job(legacy).unwrap(),
+            Some(NodeJob {
+                done: true,
+                progress_pct: 100,
+                records_read: 7,
+            })
+        );
+        assert_eq!(parse_job("ERROR:2:job not found").unwrap(), None);
+        assert_eq!(parse_job("").unwrap(), None);
+        Python::initialize();
+        assert!(parse_job("ERROR:4:bad trid").is_err());
+    }
+
+    #[test]
+    fn empty_and_error_responses() {
+        assert!(parse_list("").is_empty());
+        assert!(parse_records(";", ':').is_empty());
+        Python::initialize();
+        assert!(check_response("ERROR::unknown namespace").is_err());
+        assert_eq!(check_response("test;bar\n").unwrap(), "test;bar");
+    }
+}
@@ -7,26 +7,39 @@
 use log::info;
 use pyo3::prelude::*;
 
+mod arrow_support;
 mod async_client;
 mod backpressure;
 mod batch_types;
+mod bin_limits;
 mod bug_report;
+mod cipher;
+mod circuit_breaker;
 mod client;
 mod client_common;
 mod client_ops;
+mod cluster_events;
+mod cluster_name;
+mod columns;
+mod compression;
 mod constants;
+mod dns;
 mod errors;
 pub mod expressions;
+mod info_parsers;
 mod logging;
 pub mod metrics;
 #[deny(unsafe_op_in_unsafe_fn)]
 mod numpy_support;
 mod operations;
+mod pandas_support;
 pub mod panic_safety;
+mod pipeline;
 mod policy;
 pub mod query;
 mod record_helpers;
 mod runtime;
+mod session;
 pub mod tracing;
 mod types;
 
@@ -36,6 +49,18 @@ fn get_metrics_text() -> String {
     metrics::get_text()
 }
 
+/// Return current metric samples as a list of `{name, labels, value}` dicts.
+#[pyfunction]
+fn get_metrics_samples() -> Vec<metrics::Sample> {
+    metrics::get_samples()
+}
+
+/// Clear recorded operation histograms (cluster gauges are re-sampled).
+#[pyfunction]
+fn reset_metrics() {
+    metrics::reset();
+}
+
 /// Enable or disable Prometheus metrics collection.
 ///
 /// When disabled, operation timers are skipped entirely (~1ns atomic check).
@@ -51,6 +76,15 @@ fn is_metrics_enabled() -> bool {
     metrics::is_metrics_enabled()
 }
 
+/// Set the operation histogram buckets (seconds) and/or a metric name prefix.
+///
+/// Must be called before the first operation or `get_metrics()`.
+#[pyfunction]
+#[pyo3(signature = (buckets=None, prefix=None))]
+fn configure_metrics(buckets: Option<Vec<f64>>, prefix: Option<String>) -> PyResult<()> {
+    metrics::configure(buckets, prefix)
+}
+
 /// Enable or disable internal stage profiling metrics
 /// (`db_client_internal_stage_seconds`).
 ///
@@ -69,15 +103,47 @@ fn is_internal_stage_metrics_enabled() -> bool {
     metrics::is_internal_stage_enabled()
 }
 
-/// Return the number of log messages dropped because the Python GIL
-/// was unavailable (e.g. during interpreter shutdown).
+/// Return the number of log messages dropped because the forwarding queue
+/// was full or the Python GIL was unavailable (e.g. during interpreter shutdown).
 #[pyfunction]
 fn dropped_log_count() -> u64 {
     logging::dropped_log_count()
 }
 
+/// Set the Rust-side level filter for a log target (`""` for the default).
+#[pyfunction]
+fn set_log_target_level(target: &str, level: i32) -> PyResult<()> {
+    let filter = logging::level_filter_from_constant(level).ok_or_else(|| {
+        errors::ParamError::new_err(format!(
+            "Invalid log level {level}; expected one of LOG_LEVEL_OFF (-1) .. LOG_LEVEL_TRACE (4)"
+        ))
+    })?;
+    logging::set_target_level(target, filter);
+    Ok(())
+}
+
+/// Wait until queued Rust log records have been handed to Python `logging`.
+#[pyfunction]
+#[pyo3(signature = (timeout=1.0))]
+fn flush_logs(py: Python<'_>, timeout: f64) -> PyResult<bool> {
+    let timeout = std::time::Duration::try_from_secs_f64(timeout.max(0.0)).map_err(|_| {
+        errors::ParamError::new_err(format!(
+            "flush_logs timeout must be a finite number of seconds, got {timeout}"
+        ))
+    })?;
+    Ok(py.detach(|| logging::flush(timeout)))
+}
+
 /// Native Aerospike Python client module
-#[pymodule(gil_used = true)]
+///
+/// With the `free-threaded` feature the module is declared `gil_used = false`.
+/// Module-level statics are atomics, `OnceLock`/`LazyLock` or `Mutex`, and no
+/// `#[pyclass]` is `unsendable`, so PyO3 requires each to be `Send + Sync`.
+/// Beyond that, tests only cover the import and calls from many threads to
+/// an unconnected client and module-level setters; commands issued
+/// concurrently on a free-threaded build have not been exercised.
+#[cfg_attr(feature = "free-threaded", pymodule(gil_used = false))]
+#[cfg_attr(not(feature = "free-threaded"), pymodule(gil_used = true))]
 fn _aerospike(m: &Bound<'_, PyModule>) -> PyResult<()> {
     logging::init();
 
@@ -94,18 +160,33 @@ fn _aerospike(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<client::PyClient>()?;
     m.add_class::<async_client::PyAsyncClient>()?;
     m.add_class::<query::PyQuery>()?;
+    m.add_class::<pipeline::PyPipeline>()?;
     m.add_class::<types::partition_filter::PyPartitionFilter>()?;
     m.add_class::<batch_types::PyBatchRecord>()?;
     m.add_class::<batch_types::PyBatchRecords>()?;
     m.add_class::<batch_types::PyBatchReadHandle>()?;
+    m.add_class::<types::blob::PyBlobView>()?;
+    m.add_class::<expressions::PyCompiledExpression>()?;
 
     // Register functions
     m.add_function(wrap_pyfunction!(get_metrics_text, m)?)?;
+    m.add_function(wrap_pyfunction!(get_metrics_samples, m)?)?;
+    m.add_function(wrap_pyfunction!(reset_metrics, m)?)?;
     m.add_function(wrap_pyfunction!(set_metrics_enabled, m)?)?;
     m.add_function(wrap_pyfunction!(is_metrics_enabled, m)?)?;
+    m.add_function(wrap_pyfunction!(configure_metrics, m)?)?;
     m.add_function(wrap_pyfunction!(set_internal_stage_metrics_enabled, m)?)?;
     m.add_function(wrap_pyfunction!(is_internal_stage_metrics_enabled, m)?)?;
     m.add_function(wrap_pyfunction!(dropped_log_count, m)?)?;
+    m.add_function(wrap_pyfunction!(set_log_target_level, m)?)?;
+    m.add_function(wrap_pyfunction!(flush_logs, m)?)?;
+    m.add_function(wrap_pyfunction!(types::key::calc_digest, m)?)?;
+    m.add_function(wrap_pyfunction!(types::host::parse_config_url, m)?)?;
+    m.add_function(wrap_pyfunction!(errors::is_retryable, m)?)?;
+    m.add_function(wrap_pyfunction!(errors::raise_for_result, m)?)?;
+    m.add_function(wrap_pyfunction!(runtime::runtime_stats, m)?)?;
+    m.add_function(wrap_pyfunction!(types::blob::set_blob_view_threshold, m)?)?;
+    m.add_function(wrap_pyfunction!(types::blob::get_blob_view_threshold, m)?)?;
     m.add_function(wrap_pyfunction!(
         types::partition_filter::partition_filter_all,
         m
@@ -1,13 +1,22 @@
-//! Minimal Rust `log` → Python `logging` bridge.
+//! Rust `log` → Python `logging` bridge.
 //!
-//! Implements the `log::Log` trait to forward Rust log messages
-//! to Python's `logging` module via PyO3. Falls back to stderr
-//! when the Python GIL is unavailable (e.g. during shutdown).
+//! Implements the `log::Log` trait to forward Rust log messages to Python's
+//! `logging` module. Records are filtered per target, queued without touching
+//! the GIL, and forwarded by a background drain thread, so Tokio workers never
+//! block on the GIL to log. Rust targets map to dotted Python logger names
+//! (`aerospike_core::cluster` → `aerospike_core.cluster`) so the standard
+//! logger hierarchy, levels and handlers apply. Falls back to stderr when the
+//! Python GIL is unavailable (e.g. during shutdown).
 
 use log::{Level, LevelFilter, Log, Metadata, Record};
 use pyo3::prelude::*;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::OnceLock;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError};
+use std::sync::{OnceLock, RwLock};
+use std::time::Duration;
+
+/// Records queued for the drain thread before new ones are dropped.
+const QUEUE_CAPACITY: usize = 8192;
 
 /// Maps Rust log levels to Python logging levels.
 fn rust_to_python_level(level: Level) -> u32 {
@@ -20,12 +29,104 @@ fn rust_to_python_level(level: Level) -> u32 {
     }
 }
 
-/// A `log::Log` implementation that forwards to Python's `logging` module.
-struct PyLogger;
+/// Python logger name for a Rust log target.
+fn python_logger_name(target: &str) -> String {
+    target.replace("::", ".")
+}
+
+/// Per-target level filters. The longest matching target prefix wins;
+/// targets match on `::` (or `.`) boundaries.
+#[derive(Debug)]
+struct Filters {
+    default: LevelFilter,
+    /// Sorted by descending target length so the first match is the most specific.
+    targets: Vec<(String, LevelFilter)>,
+}
+
+impl Filters {
+    const fn new() -> Self {
+        Self {
+            default: LevelFilter::Trace,
+            targets: Vec::new(),
+        }
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .find(|(prefix, _)| target_matches(target, prefix))
+            .map_or(self.default, |(_, level)| *level)
+    }
+
+    fn set(&mut self, target: &str, level: LevelFilter) {
+        let target = target.replace('.', "::");
+        if target.is_empty() {
+            self.default = level;
+            return;
+        }
+        self.targets.retain(|(t, _)| *t != target);
+        self.targets.push((target, level));
+        self.targets
+            .sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+    }
+
+    fn max_level(&self) -> LevelFilter {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Ord::max)
+    }
+}
+
+fn target_matches(target: &str, prefix: &str) -> bool {
+    target
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+static FILTERS: RwLock<Filters> = RwLock::new(Filters::new());
+
+/// Map a `LOG_LEVEL_*` constant (`-1` off … `4` trace) to a level filter.
+pub fn level_filter_from_constant(level: i32) -> Option<LevelFilter> {
+    match level {
+        -1 => Some(LevelFilter::Off),
+        0 => Some(LevelFilter::Error),
+        1 => Some(LevelFilter::Warn),
+        2 => Some(LevelFilter::Info),
+        3 => Some(LevelFilter::Debug),
+        4 => Some(LevelFilter::Trace),
+        _ => None,
+    }
+}
+
+/// Set the level for `target` and its sub-targets (`""` sets the default).
+///
+/// Accepts Rust (`aerospike_core::cluster`) or Python (`aerospike_core.cluster`)
+/// spelling.
+pub fn set_target_level(target: &str, level: LevelFilter) {
+    let mut filters = FILTERS.write().unwrap_or_else(|e| e.into_inner());
+    filters.set(target, level);
+    log::set_max_level(filters.max_level());
+}
+
+enum Message {
+    Record {
+        level: Level,
+        target: String,
+        message: String,
+    },
+    /// Acknowledged once every record queued before it has been forwarded.
+    Flush(SyncSender<()>),
+}
+
+/// A `log::Log` implementation that queues records for the drain thread.
+struct PyLogger {
+    queue: SyncSender<Message>,
+}
 
 static LOGGER: OnceLock<PyLogger> = OnceLock::new();
 
-/// Counter tracking how many log messages were dropped (GIL unavailable).
+/// Counter tracking how many log messages were dropped (queue full or GIL unavailable).
 static DROPPED_LOG_COUNT: AtomicU64 = AtomicU64::new(0);
 
 /// Return the number of log messages dropped since process start.
@@ -33,9 +134,17 @@ pub fn dropped_log_count() -> u64 {
     DROPPED_LOG_COUNT.load(Ordering::Relaxed)
 }
 
+fn drop_record(level: Level, target: &str, message: &str) {
+    DROPPED_LOG_COUNT.fetch_add(1, Ordering::Relaxed);
+    if level <= Level::Warn {
+        eprintln!("[aerospike-py/{level}] {target}: {message}");
+    }
+}
+
 impl Log for PyLogger {
-    fn enabled(&self, _metadata: &Metadata) -> bool {
-        true
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        let filters = FILTERS.read().unwrap_or_else(|e| e.into_inner());
+        metadata.level() <= filters.level_for(metadata.target())
     }
 
     fn log(&self, record: &Record) {
@@ -43,44 +152,140 @@ impl Log for PyLogger {
             return;
         }
 
-        let level = rust_to_python_level(record.level());
-        let target = record.target();
-        let message = format!("{}", record.args());
-
-        // Try to acquire the GIL and forward to Python.
-        // If we can't (e.g., during shutdown), fall back to stderr for
-        // WARN/ERROR messages so critical diagnostics are not lost.
-        match Python::try_attach(|py| -> PyResult<()> {
-            let logging = py.import("logging")?;
-            let logger = logging.call_method1("getLogger", (target,))?;
-            logger.call_method1("log", (level, &message))?;
-            Ok(())
-        }) {
-            Some(Ok(())) => {} // Successfully forwarded to Python
-            None => {
-                // GIL genuinely unavailable (interpreter shutdown)
-                DROPPED_LOG_COUNT.fetch_add(1, Ordering::Relaxed);
-                if record.level() <= Level::Warn {
-                    eprintln!("[aerospike-py/{}] {}: {}", record.level(), target, message);
-                }
-            }
-            Some(Err(_)) => {
-                // GIL acquired but Python logging call failed
-                // (e.g. misconfigured handler). Always emit to stderr.
-                eprintln!("[aerospike-py/LOGGING-ERROR] {}: {}", target, message);
-            }
+        let message = Message::Record {
+            level: record.level(),
+            target: record.target().to_string(),
+            message: format!("{}", record.args()),
+        };
+        match self.queue.try_send(message) {
+            Ok(()) => {}
+            Err(TrySendError::Full(Message::Record {
+                level,
+                target,
+                message,
+            }))
+            | Err(TrySendError::Disconnected(Message::Record {
+                level,
+                target,
+                message,
+            })) => drop_record(level, &target, &message),
+            Err(_) => {}
         }
     }
 
+    /// No-op: callers may hold the GIL, which the drain thread needs.
+    /// Use [`flush`] with the GIL released instead.
     fn flush(&self) {}
 }
 
-/// Initialize the Rust → Python logging bridge.
+/// Forward one record to Python's `logging` module.
+fn forward(level: Level, target: &str, message: &str) {
+    // If we can't acquire the GIL (e.g., during shutdown), fall back to
+    // stderr for WARN/ERROR messages so critical diagnostics are not lost.
+    match Python::try_attach(|py| -> PyResult<()> {
+        let logging = py.import("logging")?;
+        let logger = logging.call_method1("getLogger", (python_logger_name(target),))?;
+        logger.call_method1("log", (rust_to_python_level(level), message))?;
+        Ok(())
+    }) {
+        Some(Ok(())) => {} // Successfully forwarded to Python
+        None => drop_record(level, target, message),
+        Some(Err(_)) => {
+            // GIL acquired but Python logging call failed
+            // (e.g. misconfigured handler). Always emit to stderr.
+            eprintln!("[aerospike-py/LOGGING-ERROR] {target}: {message}");
+        }
+    }
+}
+
+fn drain(queue: Receiver<Message>) {
+    while let Ok(message) = queue.recv() {
+        match message {
+            Message::Record {
+                level,
+                target,
+                message,
+            } => forward(level, &target, &message),
+            Message::Flush(ack) => {
+                let _ = ack.send(());
+            }
+        }
+    }
+}
+
+/// Block until records queued so far reach Python, or `timeout` elapses.
+///
+/// Returns `false` on timeout. Must not be called while holding the GIL:
+/// the drain thread needs it to forward records.
+pub fn flush(timeout: Duration) -> bool {
+    let Some(logger) = LOGGER.get() else {
+        return true;
+    };
+    let (ack, done) = mpsc::sync_channel(1);
+    if logger.queue.send(Message::Flush(ack)).is_err() {
+        return false;
+    }
+    !matches!(done.recv_timeout(timeout), Err(RecvTimeoutError::Timeout))
+}
+
+/// Initialize the Rust → Python logging bridge and its drain thread.
 ///
 /// Call this once at module init time. Subsequent calls are no-ops.
 pub fn init() {
-    let logger = LOGGER.get_or_init(|| PyLogger);
+    let logger = LOGGER.get_or_init(|| {
+        let (queue, records) = mpsc::sync_channel(QUEUE_CAPACITY);
+        std::thread::Builder::new()
+            .name("aerospike-py-log".to_string())
+            .spawn(move || drain(records))
+            .expect("failed to spawn log drain thread");
+        PyLogger { queue }
+    });
     // set_logger may fail if called more than once; that's fine.
     let _ = log::set_logger(logger);
-    log::set_max_level(LevelFilter::Trace);
+    let filters = FILTERS.read().unwrap_or_else(|e| e.into_inner());
+    log::set_max_level(filters.max_level());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_target_wins() {
+        let mut filters = Filters::new();
+        filters.set("", LevelFilter::Warn);
+        filters.set("aerospike_core", LevelFilter::Error);
+        filters.set("aerospike_core.cluster", LevelFilter::Debug);
+        assert_eq!(filters.level_for("_aerospike::client"), LevelFilter::Warn);
+        assert_eq!(filters.level_for("aerospike_core"), LevelFilter::Error);
+        assert_eq!(
+            filters.level_for("aerospike_core::batch"),
+            LevelFilter::Error
+        );
+        assert_eq!(
+            filters.level_for("aerospike_core::cluster::node"),
+            LevelFilter::Debug
+        );
+        // Prefix must end on a module boundary.
+        assert_eq!(filters.level_for("aerospike_core_ext"), LevelFilter::Warn);
+        assert_eq!(filters.max_level(), LevelFilter::Debug);
+    }
+
+    #[test]
+    fn setting_a_target_again_replaces_it() {
+        let mut filters = Filters::new();
+        filters.set("aerospike_core", LevelFilter::Error);
+        filters.set("aerospike_core", LevelFilter::Off);
+        assert_eq!(filters.targets.len(), 1);
+        assert_eq!(filters.level_for("aerospike_core"), LevelFilter::Off);
+    }
+
+    #[test]
+    fn logger_names_are_dotted() {
+        assert_eq!(
+            python_logger_name("aerospike_core::cluster::node"),
+            "aerospike_core.cluster.node"
+        );
+        assert_eq!(python_logger_name("_aerospike"), "_aerospike");
+    }
 }
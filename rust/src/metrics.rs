@@ -2,11 +2,15 @@
 //!
 //! Tracks `db_client_operation_duration_seconds` as a histogram, labeled by
 //! system, namespace, collection (set), operation name, and error type.
-//! Metrics are exposed in Prometheus text format via [`get_text`].
+//! Cluster gauges (node count, per-node partition generation, activity,
+//! connection pool capacity and circuit breaker state) are sampled from every
+//! connected client at scrape time. Metrics are exposed in Prometheus text format via [`get_text`].
+//! Operations that ran inside a sampled OTel span attach its trace ID to the
+//! histogram bucket as an OpenMetrics exemplar.
 
 use std::borrow::Cow;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{LazyLock, Mutex};
+use std::sync::{Arc, LazyLock, Mutex, OnceLock, Weak};
 use std::time::Instant;
 
 /// Global toggle for operational metrics collection (`db_client_operation_duration_seconds`).
@@ -72,15 +76,108 @@ pub fn init_internal_stage_from_env() {
     INTERNAL_STAGE_ENABLED.store(enabled, Ordering::Relaxed);
 }
 
-use aerospike_core::{Error as AsError, ResultCode};
+use aerospike_core::{Client as AsClient, Error as AsError, ResultCode};
 use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::exemplar::HistogramWithExemplars;
 use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
 use prometheus_client::metrics::histogram::Histogram;
 use prometheus_client::registry::Registry;
 
-/// Histogram bucket boundaries (in seconds) for operation duration.
+use crate::circuit_breaker::CircuitBreaker;
+
+/// Default histogram bucket boundaries (in seconds) for operation duration.
 const HISTOGRAM_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0];
 
+/// Overrides set by [`configure`] before the registry is built.
+#[derive(Default)]
+struct MetricsConfig {
+    buckets: Option<Vec<f64>>,
+    prefix: Option<String>,
+}
+
+/// `configure()` overrides plus whether [`METRICS`] has already read them.
+/// Both live under one lock so a configure racing first use either lands
+/// before the registry is built or is rejected.
+static METRICS_CONFIG: Mutex<(MetricsConfig, bool)> = Mutex::new((
+    MetricsConfig {
+        buckets: None,
+        prefix: None,
+    },
+    false,
+));
+
+/// Operation histogram buckets in effect, fixed when [`METRICS`] is built.
+static OP_BUCKETS: OnceLock<Vec<f64>> = OnceLock::new();
+
+fn op_histogram() -> HistogramWithExemplars<TraceExemplar> {
+    HistogramWithExemplars::new(op_buckets().iter().cloned())
+}
+
+/// Operation histogram bucket boundaries. Builds the registry on first call,
+/// which fixes any [`configure`] overrides.
+pub fn op_buckets() -> &'static [f64] {
+    LazyLock::force(&METRICS);
+    OP_BUCKETS.get().map_or(HISTOGRAM_BUCKETS, Vec::as_slice)
+}
+
+/// Set operation histogram buckets and/or a metric name prefix.
+///
+/// Must run before the first metric is recorded or scraped; afterwards the
+/// registry is fixed and this raises `ClientError`. Buckets must be non-empty,
+/// finite, positive and strictly increasing and the prefix a valid Prometheus
+/// metric name, else `ParamError`.
+pub fn configure(buckets: Option<Vec<f64>>, prefix: Option<String>) -> pyo3::PyResult<()> {
+    use crate::errors::{ClientError, ParamError};
+
+    if let Some(b) = &buckets {
+        validate_buckets(b).map_err(ParamError::new_err)?;
+    }
+    if let Some(p) = &prefix {
+        validate_prefix(p).map_err(ParamError::new_err)?;
+    }
+    let mut config = METRICS_CONFIG.lock().unwrap_or_else(|e| e.into_inner());
+    if config.1 {
+        return Err(ClientError::new_err(
+            "metrics are already in use; call configure_metrics() before the first \
+                    operation or get_metrics()",
+        ));
+    }
+    if buckets.is_some() {
+        config.0.buckets = buckets;
+    }
+    if prefix.is_some() {
+        config.0.prefix = prefix;
+    }
+    Ok(())
+}
+
+fn validate_buckets(buckets: &[f64]) -> Result<(), String> {
+    if buckets.is_empty() {
+        return Err("buckets must not be empty".to_string());
+    }
+    if let Some(b) = buckets.iter().find(|b| !b.is_finite() || **b <= 0.0) {
+        return Err(format!("buckets must be positive finite numbers, got {b}"));
+    }
+    if buckets.windows(2).any(|w| w[0] >= w[1]) {
+        return Err("buckets must be strictly increasing".to_string());
+    }
+    Ok(())
+}
+
+fn validate_prefix(prefix: &str) -> Result<(), String> {
+    let mut chars = prefix.chars();
+    let valid_first = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    if !valid_first || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!(
+            "prefix must match [a-zA-Z_][a-zA-Z0-9_]*, got {prefix:?}"
+        ));
+    }
+    Ok(())
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
 struct OperationLabels {
     db_system_name: Cow<'static, str>,
@@ -90,6 +187,12 @@ struct OperationLabels {
     error_type: Cow<'static, str>,
 }
 
+/// Exemplar label linking a histogram bucket to the OTel trace that produced it.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct TraceExemplar {
+    trace_id: String,
+}
+
 /// Internal stage labels for batch_read breakdown metrics.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
 struct InternalStageLabels {
@@ -97,12 +200,37 @@ struct InternalStageLabels {
     db_operation_name: Cow<'static, str>,
 }
 
+/// Cluster-level gauge labels.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct ClusterLabels {
+    db_aerospike_cluster_name: String,
+}
+
+/// Per-node gauge labels.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct NodeLabels {
+    db_aerospike_cluster_name: String,
+    db_aerospike_node_name: String,
+}
+
 struct MetricsState {
     registry: Mutex<Registry>,
-    op_duration: Family<OperationLabels, Histogram>,
+    op_duration: Family<OperationLabels, HistogramWithExemplars<TraceExemplar>>,
     internal_stage: Family<InternalStageLabels, Histogram>,
+    cluster_nodes: Family<ClusterLabels, Gauge>,
+    node_partition_generation: Family<NodeLabels, Gauge>,
+    node_active: Family<NodeLabels, Gauge>,
+    node_max_connections: Family<NodeLabels, Gauge>,
+    node_circuit_state: Family<NodeLabels, Gauge>,
+    /// Connected clients (with their circuit breakers and configured cluster
+    /// names) sampled by [`refresh_cluster_gauges`].
+    clients: Mutex<Vec<RegisteredClient>>,
 }
 
+/// A client registered by [`register_client`]: the client, its circuit
+/// breaker and its configured cluster name.
+type RegisteredClient = (Weak<AsClient>, Weak<CircuitBreaker>, String);
+
 /// Fine-grained bucket boundaries for sub-millisecond internal stages.
 ///
 /// Extended range starting at 1μs to precisely capture sub-microsecond stages
@@ -113,10 +241,22 @@ const INTERNAL_BUCKETS: &[f64] = &[
 ];
 
 static METRICS: LazyLock<MetricsState> = LazyLock::new(|| {
-    let mut registry = Registry::default();
-    let op_duration = Family::<OperationLabels, Histogram>::new_with_constructor(|| {
-        Histogram::new(HISTOGRAM_BUCKETS.iter().cloned())
-    });
+    let prefix = {
+        let mut config = METRICS_CONFIG.lock().unwrap_or_else(|e| e.into_inner());
+        config.1 = true;
+        if let Some(buckets) = config.0.buckets.take() {
+            let _ = OP_BUCKETS.set(buckets);
+        }
+        config.0.prefix.take()
+    };
+    let mut registry = match prefix {
+        Some(prefix) => Registry::with_prefix(prefix),
+        None => Registry::default(),
+    };
+    let op_duration =
+        Family::<OperationLabels, HistogramWithExemplars<TraceExemplar>>::new_with_constructor(
+            op_histogram,
+        );
     registry.register(
         "db_client_operation_duration_seconds",
         "Duration of database client operations",
@@ -130,10 +270,46 @@ static METRICS: LazyLock<MetricsState> = LazyLock::new(|| {
         "Internal stage durations within a database operation (key_parse, limiter_wait, io, into_pyobject, as_dict)",
         internal_stage.clone(),
     );
+    let cluster_nodes = Family::<ClusterLabels, Gauge>::default();
+    registry.register(
+        "db_client_cluster_nodes",
+        "Number of nodes in the client's cluster view",
+        cluster_nodes.clone(),
+    );
+    let node_partition_generation = Family::<NodeLabels, Gauge>::default();
+    registry.register(
+        "db_client_node_partition_generation",
+        "Partition map generation last seen for the node",
+        node_partition_generation.clone(),
+    );
+    let node_active = Family::<NodeLabels, Gauge>::default();
+    registry.register(
+        "db_client_node_active",
+        "Whether the node is active in the client's cluster view (1) or not (0)",
+        node_active.clone(),
+    );
+    let node_max_connections = Family::<NodeLabels, Gauge>::default();
+    registry.register(
+        "db_client_node_connections_max",
+        "Connection pool capacity for the node, summed over connected clients",
+        node_max_connections.clone(),
+    );
+    let node_circuit_state = Family::<NodeLabels, Gauge>::default();
+    registry.register(
+        "db_client_node_circuit_state",
+        "Circuit breaker state for the node: 0 closed, 1 open, 2 half-open",
+        node_circuit_state.clone(),
+    );
     MetricsState {
         registry: Mutex::new(registry),
         op_duration,
         internal_stage,
+        cluster_nodes,
+        node_partition_generation,
+        node_active,
+        node_max_connections,
+        node_circuit_state,
+        clients: Mutex::new(Vec::new()),
     }
 });
 
@@ -162,7 +338,20 @@ impl<'a> OperationTimer<'a> {
     }
 
     pub fn finish(self, error_type: &str) {
+        self.finish_traced(error_type, None);
+    }
+
+    /// Like [`finish`](Self::finish), attaching `trace_id` as the bucket exemplar.
+    pub fn finish_traced(self, error_type: &str, trace_id: Option<String>) {
         let duration = self.start.elapsed().as_secs_f64();
+        #[cfg(feature = "otel")]
+        crate::tracing::otel_impl::record_operation_duration(
+            self.op_name,
+            self.namespace,
+            self.set_name,
+            error_type,
+            duration,
+        );
         let labels = OperationLabels {
             db_system_name: Cow::Borrowed("aerospike"),
             db_namespace: Cow::Owned(self.namespace.to_string()),
@@ -174,7 +363,11 @@ impl<'a> OperationTimer<'a> {
                 Cow::Owned(error_type.to_string())
             },
         };
-        METRICS.op_duration.get_or_create(&labels).observe(duration);
+        METRICS.op_duration.get_or_create(&labels).observe(
+            duration,
+            trace_id.map(|trace_id| TraceExemplar { trace_id }),
+            None,
+        );
     }
 }
 
@@ -252,8 +445,67 @@ pub fn maybe_now() -> Option<Instant> {
     }
 }
 
+/// Include `client` in the cluster gauges until it is dropped or disconnected.
+pub fn register_client(client: &Arc<AsClient>, breaker: &Arc<CircuitBreaker>, cluster_name: &str) {
+    let mut clients = METRICS.clients.lock().unwrap_or_else(|e| e.into_inner());
+    clients.retain(|(c, _, _)| c.strong_count() > 0);
+    clients.push((
+        Arc::downgrade(client),
+        Arc::downgrade(breaker),
+        cluster_name.to_string(),
+    ));
+}
+
+/// Re-sample the cluster gauges from every connected client.
+///
+/// Several clients of the same cluster share one node count and partition
+/// generation (the largest reported wins); their pool capacities add up.
+fn refresh_cluster_gauges() {
+    let clients: Vec<(Arc<AsClient>, Option<Arc<CircuitBreaker>>, String)> = {
+        let mut clients = METRICS.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients.retain(|(c, _, _)| c.strong_count() > 0);
+        clients
+            .iter()
+            .filter_map(|(c, b, name)| Some((c.upgrade()?, b.upgrade(), name.clone())))
+            .collect()
+    };
+    METRICS.cluster_nodes.clear();
+    METRICS.node_partition_generation.clear();
+    METRICS.node_active.clear();
+    METRICS.node_max_connections.clear();
+    METRICS.node_circuit_state.clear();
+    for (client, breaker, cluster) in clients.iter().filter(|(c, _, _)| c.is_connected()) {
+        let max_conns = client.cluster.client_policy().max_conns_per_node as i64;
+        let nodes = client.nodes();
+        let count = METRICS.cluster_nodes.get_or_create(&ClusterLabels {
+            db_aerospike_cluster_name: cluster.clone(),
+        });
+        count.set(count.get().max(nodes.len() as i64));
+        for node in &nodes {
+            let labels = NodeLabels {
+                db_aerospike_cluster_name: cluster.clone(),
+                db_aerospike_node_name: node.name().to_string(),
+            };
+            let generation = METRICS.node_partition_generation.get_or_create(&labels);
+            generation.set(generation.get().max(node.partition_generation() as i64));
+            let active = METRICS.node_active.get_or_create(&labels);
+            active.set(active.get().max(i64::from(node.is_active())));
+            METRICS
+                .node_max_connections
+                .get_or_create(&labels)
+                .inc_by(max_conns);
+            let circuit = breaker
+                .as_ref()
+                .map_or(0, |b| b.node_state(node.name()).0.gauge_value());
+            let state = METRICS.node_circuit_state.get_or_create(&labels);
+            state.set(state.get().max(circuit));
+        }
+    }
+}
+
 /// Encode all registered metrics in Prometheus text exposition format.
 pub fn get_text() -> String {
+    refresh_cluster_gauges();
     let mut buf = String::new();
     let registry = match METRICS.registry.lock() {
         Ok(r) => r,
@@ -273,6 +525,88 @@ pub fn get_text() -> String {
     buf
 }
 
+/// One sample line of the Prometheus text output.
+///
+/// Histograms expand into `<name>_bucket` (with an `le` label), `<name>_sum`
+/// and `<name>_count` samples, as in the text format.
+#[derive(Debug, PartialEq, pyo3::IntoPyObject)]
+pub struct Sample {
+    pub name: String,
+    pub labels: std::collections::BTreeMap<String, String>,
+    pub value: f64,
+}
+
+/// Current metric samples, in the order [`get_text`] emits them.
+pub fn get_samples() -> Vec<Sample> {
+    parse_samples(&get_text())
+}
+
+/// Parse Prometheus text exposition lines into [`Sample`]s.
+///
+/// Comment lines (`# HELP`, `# TYPE`, `# EOF`) and lines that fail to parse
+/// are skipped; exemplars (`... # {trace_id="..."} 1.0`) are dropped.
+fn parse_samples(text: &str) -> Vec<Sample> {
+    text.lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(parse_sample_line)
+        .collect()
+}
+
+fn parse_sample_line(line: &str) -> Option<Sample> {
+    let line = line.split_once(" # ").map_or(line, |(sample, _)| sample);
+    let (name, rest) = match line.find(['{', ' ']) {
+        Some(i) => line.split_at(i),
+        None => return None,
+    };
+    let mut labels = std::collections::BTreeMap::new();
+    let value_part = if let Some(body) = rest.strip_prefix('{') {
+        let mut chars = body.char_indices();
+        let mut key = String::new();
+        let end = loop {
+            let (i, c) = chars.next()?;
+            match c {
+                '}' => break i,
+                ',' | ' ' => {}
+                '=' => {
+                    if chars.next()?.1 != '"' {
+                        return None;
+                    }
+                    let mut value = String::new();
+                    loop {
+                        match chars.next()?.1 {
+                            '"' => break,
+                            '\\' => match chars.next()?.1 {
+                                'n' => value.push('\n'),
+                                other => value.push(other),
+                            },
+                            other => value.push(other),
+                        }
+                    }
+                    labels.insert(std::mem::take(&mut key), value);
+                }
+                other => key.push(other),
+            }
+        };
+        &body[end + 1..]
+    } else {
+        rest
+    };
+    let value = value_part.split_whitespace().next()?.parse().ok()?;
+    Some(Sample {
+        name: name.to_string(),
+        labels,
+        value,
+    })
+}
+
+/// Clear recorded operation and internal-stage histograms.
+///
+/// Cluster gauges are re-sampled on every scrape, so they are unaffected.
+pub fn reset() {
+    METRICS.op_duration.clear();
+    METRICS.internal_stage.clear();
+}
+
 /// Wrap a code block with internal-stage timing.
 ///
 /// When [`is_internal_stage_enabled`] is `false`, the expression runs with no
@@ -333,3 +667,62 @@ macro_rules! timed_op {
         }
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_validation() {
+        assert!(validate_buckets(&[0.000_1, 0.000_5, 0.001]).is_ok());
+        assert!(validate_buckets(&[]).is_err());
+        assert!(validate_buckets(&[0.0, 0.1]).is_err());
+        assert!(validate_buckets(&[0.1, f64::INFINITY]).is_err());
+        assert!(validate_buckets(&[0.1, 0.1]).is_err());
+        assert!(validate_buckets(&[0.5, 0.1]).is_err());
+    }
+
+    #[test]
+    fn parse_plain_and_labeled_samples() {
+        let text = "# HELP x Help.\n# TYPE x gauge\nx 3\n\
+                    y_bucket{le=\"0.5\",op=\"g\\\"et\"} 2 # {trace_id=\"ab\"} 0.1\n# EOF\n";
+        let samples = parse_samples(text);
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[0].name, "x");
+        assert!(samples[0].labels.is_empty());
+        assert_eq!(samples[0].value, 3.0);
+        assert_eq!(samples[1].name, "y_bucket");
+        assert_eq!(samples[1].labels["le"], "0.5");
+        assert_eq!(samples[1].labels["op"], "g\"et");
+        assert_eq!(samples[1].value, 2.0);
+    }
+
+    #[test]
+    fn parse_inf_bucket() {
+        let samples = parse_samples("h_bucket{le=\"+Inf\"} 7\n");
+        assert_eq!(samples[0].labels["le"], "+Inf");
+        assert_eq!(samples[0].value, 7.0);
+    }
+
+    #[test]
+    fn trace_id_becomes_bucket_exemplar() {
+        OperationTimer::start("get", "exemplar_ns", "s")
+            .finish_traced("", Some("4bf92f3577b34da6a3ce929d0e0e4736".to_string()));
+        let text = get_text();
+        let bucket = text
+            .lines()
+            .find(|l| l.contains("exemplar_ns") && l.contains("# {trace_id="))
+            .expect("exemplar line");
+        assert!(bucket.contains("trace_id=\"4bf92f3577b34da6a3ce929d0e0e4736\""));
+        assert_eq!(parse_sample_line(bucket).unwrap().value, 1.0);
+    }
+
+    #[test]
+    fn prefix_validation() {
+        assert!(validate_prefix("myapp_aerospike").is_ok());
+        assert!(validate_prefix("_x1").is_ok());
+        assert!(validate_prefix("").is_err());
+        assert!(validate_prefix("1app").is_err());
+        assert!(validate_prefix("my-app").is_err());
+    }
+}
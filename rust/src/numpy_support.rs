@@ -18,11 +18,11 @@ use std::ptr;
 use aerospike_core::{BatchRecord, Bin, FloatValue, Key, Value};
 use half::f16;
 use log::{debug, warn};
-use pyo3::exceptions::{PyOverflowError, PyTypeError, PyValueError};
+use pyo3::exceptions::{PyOverflowError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::errors::result_code_to_int;
+use crate::errors::{result_code_to_int, ParamError};
 use crate::record_helpers::record_ttl_seconds;
 use crate::types::key::compute_bytes_key_digest;
 use crate::types::value::value_to_py;
@@ -37,6 +37,28 @@ pub enum DtypeKind {
     Float,
     FixedBytes,
     VoidBytes,
+    /// NumPy `bool` (one byte, 0 or 1).
+    Bool,
+    /// NumPy fixed-width unicode (`U<n>`), stored as UCS4 code points.
+    Unicode,
+    /// NumPy `datetime64[s|ms|us|ns]`; the bin holds the raw int64 epoch
+    /// count in the field's own unit.
+    Datetime,
+}
+
+impl DtypeKind {
+    /// Short human-readable label used in conversion error messages.
+    fn label(self) -> &'static str {
+        match self {
+            DtypeKind::Int => "int",
+            DtypeKind::Uint => "uint",
+            DtypeKind::Float => "float",
+            DtypeKind::FixedBytes | DtypeKind::VoidBytes => "bytes",
+            DtypeKind::Bool => "bool",
+            DtypeKind::Unicode => "unicode",
+            DtypeKind::Datetime => "datetime64",
+        }
+    }
 }
 
 /// Metadata for a single field within a NumPy structured dtype.
@@ -82,10 +104,16 @@ fn parse_dtype_fields(dtype: &Bound<'_, PyAny>) -> PyResult<(Vec<FieldInfo>, usi
             "f" => DtypeKind::Float,
             "S" => DtypeKind::FixedBytes,
             "V" => DtypeKind::VoidBytes,
+            "b" => DtypeKind::Bool,
+            "U" => DtypeKind::Unicode,
+            "M" => {
+                check_datetime_unit(name, &base)?;
+                DtypeKind::Datetime
+            }
             other => {
                 warn!("Unsupported dtype kind '{}' for field '{}'", other, name);
-                return Err(PyTypeError::new_err(format!(
-                    "dtype field '{}' must be numeric (int/float) or fixed-length bytes, got {} (kind='{}')",
+                return Err(ParamError::new_err(format!(
+                    "dtype field '{}' must be numeric (int/float/bool) or fixed-length bytes/unicode, got {} (kind='{}')",
                     name, field_dtype, other,
                 )));
             }
@@ -94,9 +122,16 @@ fn parse_dtype_fields(dtype: &Bound<'_, PyAny>) -> PyResult<(Vec<FieldInfo>, usi
         let itemsize: usize = field_dtype.getattr("itemsize")?.extract()?;
         let base_itemsize: usize = base.getattr("itemsize")?.extract()?;
 
+        if kind == DtypeKind::Unicode && itemsize != base_itemsize {
+            return Err(ParamError::new_err(format!(
+                "dtype field '{}': sub-array unicode fields are not supported, got {}",
+                name, field_dtype,
+            )));
+        }
+
         // Bounds check: field must fit within row stride
         if offset + itemsize > row_stride {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "dtype field '{}' exceeds row boundary: offset({}) + itemsize({}) = {} > row_stride({})",
                 name, offset, itemsize, offset + itemsize, row_stride,
             )));
@@ -114,6 +149,30 @@ fn parse_dtype_fields(dtype: &Bound<'_, PyAny>) -> PyResult<(Vec<FieldInfo>, usi
     Ok((fields, row_stride))
 }
 
+/// NumPy's "not a time" sentinel for `datetime64`.
+const NAT: i64 = i64::MIN;
+
+/// Epoch units accepted for `datetime64` fields.
+const DATETIME_UNITS: [&str; 4] = ["s", "ms", "us", "ns"];
+
+/// Ensure a `datetime64` field has an explicit second-or-finer unit.
+///
+/// The bin value is the raw epoch count, so generic `datetime64` (no unit)
+/// and calendar units (`D`, `M`, `Y`, ...) are rejected.
+fn check_datetime_unit(name: &str, base: &Bound<'_, PyAny>) -> PyResult<()> {
+    let dtype_str: String = base.getattr("str")?.extract()?;
+    let unit = dtype_str
+        .split_once('[')
+        .and_then(|(_, rest)| rest.strip_suffix(']'));
+    match unit {
+        Some(u) if DATETIME_UNITS.contains(&u) => Ok(()),
+        _ => Err(ParamError::new_err(format!(
+            "dtype field '{}': datetime64 must use one of the units {:?}, got {}",
+            name, DATETIME_UNITS, dtype_str,
+        ))),
+    }
+}
+
 // ── raw pointer from numpy array ────────────────────────────────
 
 /// Extract the raw data pointer from a writable numpy array via `__array_interface__`.
@@ -124,16 +183,16 @@ fn parse_dtype_fields(dtype: &Bound<'_, PyAny>) -> PyResult<(Vec<FieldInfo>, usi
 /// reallocated. Callers must ensure:
 /// - The array outlives all writes through the returned pointer.
 /// - No concurrent Python code resizes or replaces the array's buffer.
-fn get_array_data_ptr(array: &Bound<'_, PyAny>) -> PyResult<*mut u8> {
+pub(crate) fn get_array_data_ptr(array: &Bound<'_, PyAny>) -> PyResult<*mut u8> {
     let iface = array.getattr("__array_interface__")?;
     let data_tuple = iface.get_item("data")?;
     let ptr_int: usize = data_tuple.get_item(0)?.extract()?;
     let readonly: bool = data_tuple.get_item(1)?.extract()?;
     if readonly {
-        return Err(PyValueError::new_err("numpy array is read-only"));
+        return Err(ParamError::new_err("numpy array is read-only"));
     }
     if ptr_int == 0 {
-        return Err(PyValueError::new_err("numpy array data pointer is null"));
+        return Err(ParamError::new_err("numpy array data pointer is null"));
     }
     Ok(ptr_int as *mut u8)
 }
@@ -199,7 +258,7 @@ unsafe fn write_int_to_buffer(row_ptr: *mut u8, field: &FieldInfo, val: i64) ->
         // SAFETY: dst points to at least 8 bytes of writable memory
         8 => unsafe { ptr::write_unaligned(dst as *mut i64, val) },
         s => {
-            return Err(PyTypeError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "unsupported int size: {} bytes",
                 s
             )));
@@ -261,7 +320,7 @@ unsafe fn write_uint_to_buffer(row_ptr: *mut u8, field: &FieldInfo, val: u64) ->
         // SAFETY: dst points to at least 8 bytes of writable memory
         8 => unsafe { ptr::write_unaligned(dst as *mut u64, val) },
         s => {
-            return Err(PyTypeError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "unsupported uint size: {} bytes",
                 s
             )));
@@ -312,7 +371,7 @@ unsafe fn write_float_to_buffer(row_ptr: *mut u8, field: &FieldInfo, val: f64) -
             unsafe { ptr::write_unaligned(dst as *mut u16, h.to_bits()) };
         }
         s => {
-            return Err(PyTypeError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "unsupported float size: {} bytes",
                 s
             )));
@@ -354,6 +413,54 @@ unsafe fn write_bytes_to_buffer(row_ptr: *mut u8, field: &FieldInfo, data: &[u8]
     Ok(())
 }
 
+/// Write a bool into the row buffer at the field's offset as a single byte.
+///
+/// # Safety
+///
+/// Same preconditions as [`write_int_to_buffer`].
+unsafe fn write_bool_to_buffer(row_ptr: *mut u8, field: &FieldInfo, val: bool) -> PyResult<()> {
+    debug_assert!(!row_ptr.is_null());
+    if row_ptr.is_null() {
+        return Err(PyValueError::new_err(
+            "null buffer pointer in write_bool_to_buffer",
+        ));
+    }
+    // SAFETY: caller guarantees row_ptr + field.offset is valid and within bounds
+    unsafe { ptr::write(row_ptr.add(field.offset), val as u8) };
+    Ok(())
+}
+
+/// Write a string into a fixed-width unicode (`U<n>`) field as UCS4 code points.
+///
+/// Copies at most `field.itemsize / 4` characters (truncating longer strings,
+/// matching NumPy's own assignment semantics). The remaining code points are
+/// left zero-initialized from `np.zeros`.
+///
+/// # Safety
+///
+/// Same preconditions as [`write_int_to_buffer`].
+unsafe fn write_unicode_to_buffer(row_ptr: *mut u8, field: &FieldInfo, s: &str) -> PyResult<()> {
+    debug_assert!(!row_ptr.is_null());
+    debug_assert!(
+        field.offset.checked_add(field.itemsize).is_some(),
+        "field '{}': offset + itemsize overflows",
+        field.name
+    );
+    if row_ptr.is_null() {
+        return Err(PyValueError::new_err(
+            "null buffer pointer in write_unicode_to_buffer",
+        ));
+    }
+    // SAFETY: caller guarantees row_ptr + field.offset is valid and within bounds
+    let dst = unsafe { row_ptr.add(field.offset) };
+    let max_chars = field.itemsize / 4;
+    for (i, ch) in s.chars().take(max_chars).enumerate() {
+        // SAFETY: i < max_chars, so the write stays within field.itemsize bytes
+        unsafe { ptr::write_unaligned(dst.add(i * 4) as *mut u32, ch as u32) };
+    }
+    Ok(())
+}
+
 // ── value → buffer dispatch ─────────────────────────────────────
 
 /// Dispatch an Aerospike [`Value`] to the appropriate buffer write function.
@@ -370,7 +477,7 @@ unsafe fn write_value_to_buffer(
 ) -> PyResult<()> {
     fn non_negative_u64(v: i64, field: &FieldInfo) -> PyResult<u64> {
         if v < 0 {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "cannot write negative integer {} to unsigned field '{}'",
                 v, field.name
             )));
@@ -379,19 +486,19 @@ unsafe fn write_value_to_buffer(
     }
     fn non_negative_f64_to_u64(v: f64, field: &FieldInfo) -> PyResult<u64> {
         if !v.is_finite() {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "cannot write non-finite float {} to unsigned field '{}'",
                 v, field.name
             )));
         }
         if v < 0.0 {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "cannot write negative float {} to unsigned field '{}'",
                 v, field.name
             )));
         }
         if v >= u64::MAX as f64 {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "cannot write out-of-range float {} to unsigned field '{}'",
                 v, field.name
             )));
@@ -400,13 +507,13 @@ unsafe fn write_value_to_buffer(
     }
     fn finite_f64_to_i64(v: f64, field: &FieldInfo) -> PyResult<i64> {
         if !v.is_finite() {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "cannot write non-finite float {} to integer field '{}'",
                 v, field.name
             )));
         }
         if v < i64::MIN as f64 || v >= i64::MAX as f64 {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "cannot write out-of-range float {} to integer field '{}'",
                 v, field.name
             )));
@@ -417,13 +524,17 @@ unsafe fn write_value_to_buffer(
     match value {
         Value::Int(v) => match field.kind {
             // SAFETY: forwarding caller's safety guarantees to write_*_to_buffer
-            DtypeKind::Int => unsafe { write_int_to_buffer(row_ptr, field, *v) },
+            DtypeKind::Int | DtypeKind::Datetime => unsafe {
+                write_int_to_buffer(row_ptr, field, *v)
+            },
             DtypeKind::Uint => unsafe {
                 write_uint_to_buffer(row_ptr, field, non_negative_u64(*v, field)?)
             },
             DtypeKind::Float => unsafe { write_float_to_buffer(row_ptr, field, *v as f64) },
-            _ => Err(PyTypeError::new_err(format!(
-                "cannot write integer to bytes field '{}'",
+            DtypeKind::Bool => unsafe { write_bool_to_buffer(row_ptr, field, *v != 0) },
+            _ => Err(ParamError::new_err(format!(
+                "cannot write integer to {} field '{}'",
+                field.kind.label(),
                 field.name
             ))),
         },
@@ -438,8 +549,10 @@ unsafe fn write_value_to_buffer(
                 DtypeKind::Uint => unsafe {
                     write_uint_to_buffer(row_ptr, field, non_negative_f64_to_u64(v, field)?)
                 },
-                _ => Err(PyTypeError::new_err(format!(
-                    "cannot write float to bytes field '{}'",
+                DtypeKind::Bool => unsafe { write_bool_to_buffer(row_ptr, field, v != 0.0) },
+                _ => Err(ParamError::new_err(format!(
+                    "cannot write float to {} field '{}'",
+                    field.kind.label(),
                     field.name
                 ))),
             }
@@ -451,8 +564,10 @@ unsafe fn write_value_to_buffer(
                 DtypeKind::Int => unsafe { write_int_to_buffer(row_ptr, field, iv) },
                 DtypeKind::Uint => unsafe { write_uint_to_buffer(row_ptr, field, iv as u64) },
                 DtypeKind::Float => unsafe { write_float_to_buffer(row_ptr, field, iv as f64) },
-                _ => Err(PyTypeError::new_err(format!(
-                    "cannot write bool to bytes field '{}'",
+                DtypeKind::Bool => unsafe { write_bool_to_buffer(row_ptr, field, *b) },
+                _ => Err(ParamError::new_err(format!(
+                    "cannot write bool to {} field '{}'",
+                    field.kind.label(),
                     field.name
                 ))),
             }
@@ -463,14 +578,15 @@ unsafe fn write_value_to_buffer(
                 unsafe { write_bytes_to_buffer(row_ptr, field, bytes) }
             }
             // sub-array: bytes blob written directly to buffer
-            DtypeKind::Float | DtypeKind::Int | DtypeKind::Uint
+            DtypeKind::Float | DtypeKind::Int | DtypeKind::Uint | DtypeKind::Bool
                 if field.itemsize > field.base_itemsize =>
             {
                 // SAFETY: forwarding caller's safety guarantees to write_bytes_to_buffer
                 unsafe { write_bytes_to_buffer(row_ptr, field, bytes) }
             }
-            _ => Err(PyTypeError::new_err(format!(
-                "cannot write bytes to numeric field '{}'",
+            _ => Err(ParamError::new_err(format!(
+                "cannot write bytes to {} field '{}'",
+                field.kind.label(),
                 field.name
             ))),
         },
@@ -479,13 +595,18 @@ unsafe fn write_value_to_buffer(
                 // SAFETY: forwarding caller's safety guarantees to write_bytes_to_buffer
                 unsafe { write_bytes_to_buffer(row_ptr, field, s.as_bytes()) }
             }
-            _ => Err(PyTypeError::new_err(format!(
-                "cannot write string to numeric field '{}'",
+            DtypeKind::Unicode => {
+                // SAFETY: forwarding caller's safety guarantees to write_unicode_to_buffer
+                unsafe { write_unicode_to_buffer(row_ptr, field, s) }
+            }
+            _ => Err(ParamError::new_err(format!(
+                "cannot write string to {} field '{}'",
+                field.kind.label(),
                 field.name
             ))),
         },
         Value::Nil => Ok(()), // skip, buffer is already zero-initialized
-        _ => Err(PyTypeError::new_err(format!(
+        _ => Err(ParamError::new_err(format!(
             "unsupported Aerospike value type for numpy field '{}'",
             field.name
         ))),
@@ -493,13 +614,69 @@ unsafe fn write_value_to_buffer(
 }
 
 /// Convert an `aerospike_core::FloatValue` (stored as raw bits) to `f64`.
-fn float_value_to_f64(fv: &FloatValue) -> f64 {
+pub(crate) fn float_value_to_f64(fv: &FloatValue) -> f64 {
     match fv {
         FloatValue::F64(bits) => f64::from_bits(*bits),
         FloatValue::F32(bits) => f32::from_bits(*bits) as f64,
     }
 }
 
+// ── meta array helpers ──────────────────────────────────────────
+
+/// Row stride of the meta array: gen(u4) + ttl(u4) = 8 bytes.
+const META_STRIDE: usize = 8;
+
+/// Build the `[("gen", "u4"), ("ttl", "u4")]` dtype descriptor for meta arrays.
+fn meta_dtype_descr(py: Python<'_>) -> PyResult<Bound<'_, pyo3::types::PyList>> {
+    pyo3::types::PyList::new(
+        py,
+        &[
+            pyo3::types::PyTuple::new(
+                py,
+                &[
+                    "gen".into_pyobject(py)?.into_any(),
+                    "u4".into_pyobject(py)?.into_any(),
+                ],
+            )?,
+            pyo3::types::PyTuple::new(
+                py,
+                &[
+                    "ttl".into_pyobject(py)?.into_any(),
+                    "u4".into_pyobject(py)?.into_any(),
+                ],
+            )?,
+        ],
+    )
+}
+
+/// Build the null-mask dtype descriptor: one `bool` column per data field.
+fn null_mask_dtype_descr<'py>(
+    py: Python<'py>,
+    fields: &[FieldInfo],
+) -> PyResult<Bound<'py, pyo3::types::PyList>> {
+    let items = fields
+        .iter()
+        .map(|f| pyo3::types::PyTuple::new(py, [f.name.as_str(), "?"]))
+        .collect::<PyResult<Vec<_>>>()?;
+    pyo3::types::PyList::new(py, items)
+}
+
+/// Write a record's generation and TTL into row `index` of a meta array.
+///
+/// # Safety
+///
+/// `meta_ptr` must point to a writable buffer of at least
+/// `(index + 1) * META_STRIDE` bytes, allocated via `np.zeros` with
+/// [`meta_dtype_descr`].
+unsafe fn write_meta_row(meta_ptr: *mut u8, index: usize, record: &aerospike_core::Record) {
+    // SAFETY: caller guarantees row `index` lies within the meta buffer
+    unsafe {
+        let meta_row = meta_ptr.add(index * META_STRIDE);
+        ptr::write_unaligned(meta_row as *mut u32, record.generation);
+        ptr::write_unaligned(meta_row.add(4) as *mut u32, record_ttl_seconds(record));
+    }
+}
+
 // ── main entry point ────────────────────────────────────────────
 
 /// Convert batch results into a `NumpyBatchRecords` Python object.
@@ -507,10 +684,15 @@ fn float_value_to_f64(fv: &FloatValue) -> f64 {
 /// Allocates three NumPy arrays (data, meta, result_codes) and writes
 /// Aerospike values directly into the data buffer via raw pointers,
 /// avoiding per-element Python object allocation.
+///
+/// When `with_null_mask` is set, a fourth structured array with one `bool`
+/// column per dtype field is returned as `NumpyBatchRecords.null_mask`;
+/// an entry is `True` when the bin was missing, `Nil`, or the record failed.
 pub fn batch_to_numpy_py(
     py: Python<'_>,
     results: &[BatchRecord],
     dtype_obj: &Bound<'_, PyAny>,
+    with_null_mask: bool,
 ) -> PyResult<Py<PyAny>> {
     debug!("Converting batch to numpy: records_count={}", results.len());
     let np = py.import("numpy")?;
@@ -521,7 +703,7 @@ pub fn batch_to_numpy_py(
 
     // Overflow check: ensure n * row_stride does not overflow usize
     if n.checked_mul(row_stride).is_none() {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "buffer size overflow: {} rows * {} bytes/row exceeds usize",
             n, row_stride,
         )));
@@ -530,41 +712,34 @@ pub fn batch_to_numpy_py(
     // 2. Allocate numpy arrays
     let data_array = np.call_method1("zeros", (n, dtype_obj))?;
 
-    let meta_dtype_list = pyo3::types::PyList::new(
-        py,
-        &[
-            pyo3::types::PyTuple::new(
-                py,
-                &[
-                    "gen".into_pyobject(py)?.into_any(),
-                    "u4".into_pyobject(py)?.into_any(),
-                ],
-            )?,
-            pyo3::types::PyTuple::new(
-                py,
-                &[
-                    "ttl".into_pyobject(py)?.into_any(),
-                    "u4".into_pyobject(py)?.into_any(),
-                ],
-            )?,
-        ],
-    )?;
-    let meta_array = np.call_method1("zeros", (n, meta_dtype_list))?;
+    let meta_array = np.call_method1("zeros", (n, meta_dtype_descr(py)?))?;
 
     let int32_dtype = np.getattr("int32")?;
     let result_codes_array = np.call_method1("zeros", (n, int32_dtype))?;
 
+    // Null mask starts all-True; cleared per field as values are written.
+    // One packed bool byte per field, so field `j` lives at row offset `j`.
+    let null_mask_array = if with_null_mask {
+        Some(np.call_method1("ones", (n, null_mask_dtype_descr(py, &fields)?))?)
+    } else {
+        None
+    };
+
     // 3. Get raw data pointers
     let data_ptr = get_array_data_ptr(&data_array)?;
     let meta_ptr = get_array_data_ptr(&meta_array)?;
     let rc_ptr = get_array_data_ptr(&result_codes_array)?;
+    let mask_ptr = null_mask_array
+        .as_ref()
+        .map(get_array_data_ptr)
+        .transpose()?;
 
-    // meta stride: gen(u4) + ttl(u4) = 8 bytes
-    let meta_stride: usize = 8;
-
-    // 4. Build field name → FieldInfo lookup
-    let field_map: HashMap<&str, &FieldInfo> =
-        fields.iter().map(|f| (f.name.as_str(), f)).collect();
+    // 4. Build field name → (field index, FieldInfo) lookup
+    let field_map: HashMap<&str, (usize, &FieldInfo)> = fields
+        .iter()
+        .enumerate()
+        .map(|(j, f)| (f.name.as_str(), (j, f)))
+        .collect();
 
     // 5. Build key_map and fill arrays
     let key_map = PyDict::new(py);
@@ -595,22 +770,24 @@ pub fn batch_to_numpy_py(
         if result_code == 0 {
             if let Some(record) = &br.record {
                 // Write meta: generation and ttl
-                let gen = record.generation;
-                let ttl: u32 = record_ttl_seconds(record);
-
                 unsafe {
-                    let meta_row = meta_ptr.add(i * meta_stride);
-                    ptr::write_unaligned(meta_row as *mut u32, gen);
-                    ptr::write_unaligned(meta_row.add(4) as *mut u32, ttl);
+                    write_meta_row(meta_ptr, i, record);
                 }
 
                 // Write bin values directly into numpy buffer
                 let row_ptr = unsafe { data_ptr.add(i * row_stride) };
                 for (bin_name, value) in &record.bins {
-                    if let Some(field) = field_map.get(bin_name.as_str()) {
+                    if let Some(&(j, field)) = field_map.get(bin_name.as_str()) {
                         unsafe {
                             write_value_to_buffer(row_ptr, field, value)?;
                         }
+                        if let Some(mask_ptr) = mask_ptr {
+                            if !matches!(value, Value::Nil) {
+                                // SAFETY: mask array has `n` rows of `fields.len()`
+                                // bytes, `i < n` and `j < fields.len()`
+                                unsafe { ptr::write(mask_ptr.add(i * fields.len() + j), 0u8) };
+                            }
+                        }
                     }
                     // bins not in dtype are silently ignored
                 }
@@ -626,11 +803,176 @@ pub fn batch_to_numpy_py(
     // 6. Construct NumpyBatchRecords Python object
     let numpy_batch_mod = py.import("aerospike_py.numpy_batch")?;
     let cls = numpy_batch_mod.getattr("NumpyBatchRecords")?;
-    let result = cls.call1((&data_array, &meta_array, &result_codes_array, &key_map))?;
+    let result = cls.call1((
+        &data_array,
+        &meta_array,
+        &result_codes_array,
+        &key_map,
+        null_mask_array,
+    ))?;
 
     Ok(result.unbind())
 }
 
+// ── batch_exists → numpy ────────────────────────────────────────
+
+/// Validate the `dtype` argument of `batch_exists`.
+///
+/// Only boolean dtypes (`bool`, `np.bool_`, `"?"`) are accepted, since the
+/// result is a found/not-found mask. Called before any I/O so a bad dtype
+/// fails fast instead of after the batch round-trip.
+pub fn check_exists_dtype(py: Python<'_>, dtype_obj: &Bound<'_, PyAny>) -> PyResult<()> {
+    let np = py.import("numpy")?;
+    let dtype = np.call_method1("dtype", (dtype_obj,))?;
+    let kind: String = dtype.getattr("kind")?.extract()?;
+    if kind != "b" {
+        return Err(ParamError::new_err(format!(
+            "batch_exists dtype must be a boolean dtype, got '{}'",
+            dtype.str()?
+        )));
+    }
+    Ok(())
+}
+
+/// Convert `batch_exists` results into a `(found, meta)` tuple of NumPy arrays.
+///
+/// `found` is a `bool` array with one entry per key; `meta` is a structured
+/// `[("gen", "u4"), ("ttl", "u4")]` array, zero-filled for missing records.
+pub fn batch_exists_to_numpy_py(py: Python<'_>, results: &[BatchRecord]) -> PyResult<Py<PyAny>> {
+    debug!(
+        "Converting batch_exists to numpy: records_count={}",
+        results.len()
+    );
+    let np = py.import("numpy")?;
+    let n = results.len();
+
+    let found_array = np.call_method1("zeros", (n, np.getattr("bool_")?))?;
+    let meta_array = np.call_method1("zeros", (n, meta_dtype_descr(py)?))?;
+
+    let found_ptr = get_array_data_ptr(&found_array)?;
+    let meta_ptr = get_array_data_ptr(&meta_array)?;
+
+    for (i, br) in results.iter().enumerate() {
+        let result_code = match &br.result_code {
+            Some(rc) => result_code_to_int(rc),
+            None => 0,
+        };
+        if result_code != 0 {
+            continue;
+        }
+        if let Some(record) = &br.record {
+            // SAFETY: both arrays were allocated above with `n` rows and
+            // `i < n`; numpy bools are one byte each.
+            unsafe {
+                ptr::write(found_ptr.add(i), 1u8);
+                write_meta_row(meta_ptr, i, record);
+            }
+        }
+    }
+
+    let tuple = pyo3::types::PyTuple::new(py, [found_array, meta_array])?;
+    Ok(tuple.into_any().unbind())
+}
+
+// ── query/scan → numpy ──────────────────────────────────────────
+
+/// Accumulates streamed query/scan records into growable row buffers.
+///
+/// Rows are written into plain `Vec<u8>` buffers as records arrive, so the
+/// stream never holds more than one `Record` at a time and `push` does not
+/// need the GIL. [`RecordNumpyWriter::finish`] copies the buffers into NumPy
+/// arrays once the final row count is known.
+pub struct RecordNumpyWriter {
+    fields: Vec<FieldInfo>,
+    row_stride: usize,
+    field_index: HashMap<String, usize>,
+    data: Vec<u8>,
+    meta: Vec<u8>,
+    user_keys: Vec<Option<Value>>,
+}
+
+impl RecordNumpyWriter {
+    pub fn new(dtype_obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let (fields, row_stride) = parse_dtype_fields(dtype_obj)?;
+        let field_index = fields
+            .iter()
+            .enumerate()
+            .map(|(j, f)| (f.name.clone(), j))
+            .collect();
+        Ok(Self {
+            fields,
+            row_stride,
+            field_index,
+            data: Vec::new(),
+            meta: Vec::new(),
+            user_keys: Vec::new(),
+        })
+    }
+
+    /// Append one record as a new row.
+    pub fn push(&mut self, record: &aerospike_core::Record) -> PyResult<()> {
+        let i = self.user_keys.len();
+        let row_start = self.data.len();
+        self.data.resize(row_start + self.row_stride, 0);
+        self.meta.resize((i + 1) * META_STRIDE, 0);
+
+        // SAFETY: both buffers were just grown to hold row `i`
+        unsafe {
+            write_meta_row(self.meta.as_mut_ptr(), i, record);
+        }
+        let row_ptr = unsafe { self.data.as_mut_ptr().add(row_start) };
+        for (bin_name, value) in &record.bins {
+            if let Some(&j) = self.field_index.get(bin_name) {
+                // SAFETY: `row_ptr` points at `row_stride` writable bytes and
+                // `parse_dtype_fields` bounds every field within the stride
+                unsafe {
+                    write_value_to_buffer(row_ptr, &self.fields[j], value)?;
+                }
+            }
+        }
+        self.user_keys
+            .push(record.key.as_ref().and_then(|k| k.user_key.clone()));
+        Ok(())
+    }
+
+    /// Copy the accumulated rows into a `NumpyBatchRecords`.
+    ///
+    /// `result_codes` are all zero; `key_map` only covers records whose user
+    /// key was stored on the server (`POLICY_KEY_SEND`).
+    pub fn finish(self, py: Python<'_>, dtype_obj: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let n = self.user_keys.len();
+        debug!("Converting query to numpy: records_count={}", n);
+        let np = py.import("numpy")?;
+
+        let data_array = np.call_method1("zeros", (n, dtype_obj))?;
+        let meta_array = np.call_method1("zeros", (n, meta_dtype_descr(py)?))?;
+        let result_codes_array = np.call_method1("zeros", (n, np.getattr("int32")?))?;
+
+        if n > 0 {
+            let data_ptr = get_array_data_ptr(&data_array)?;
+            let meta_ptr = get_array_data_ptr(&meta_array)?;
+            // SAFETY: the arrays were allocated with `n` rows of `row_stride`
+            // and `META_STRIDE` bytes, matching the source buffer lengths
+            unsafe {
+                ptr::copy_nonoverlapping(self.data.as_ptr(), data_ptr, self.data.len());
+                ptr::copy_nonoverlapping(self.meta.as_ptr(), meta_ptr, self.meta.len());
+            }
+        }
+
+        let key_map = PyDict::new(py);
+        for (i, user_key) in self.user_keys.iter().enumerate() {
+            if let Some(v) = user_key {
+                key_map.set_item(value_to_py(py, v)?, i)?;
+            }
+        }
+
+        let numpy_batch_mod = py.import("aerospike_py.numpy_batch")?;
+        let cls = numpy_batch_mod.getattr("NumpyBatchRecords")?;
+        let result = cls.call1((&data_array, &meta_array, &result_codes_array, &key_map))?;
+        Ok(result.unbind())
+    }
+}
+
 // ── numpy → records (for batch_write) ───────────────────────────
 
 /// Read a single value from a numpy buffer row at the given field offset.
@@ -666,7 +1008,7 @@ unsafe fn read_value_from_buffer(row_ptr: *const u8, field: &FieldInfo) -> PyRes
                 4 => (unsafe { ptr::read_unaligned(src as *const i32) }) as i64,
                 8 => unsafe { ptr::read_unaligned(src as *const i64) },
                 s => {
-                    return Err(PyTypeError::new_err(format!(
+                    return Err(ParamError::new_err(format!(
                         "unsupported int size: {} bytes",
                         s
                     )));
@@ -682,7 +1024,7 @@ unsafe fn read_value_from_buffer(row_ptr: *const u8, field: &FieldInfo) -> PyRes
                 4 => (unsafe { ptr::read_unaligned(src as *const u32) }) as i64,
                 8 => uint_to_i64(unsafe { ptr::read_unaligned(src as *const u64) }, field)?,
                 s => {
-                    return Err(PyTypeError::new_err(format!(
+                    return Err(ParamError::new_err(format!(
                         "unsupported uint size: {} bytes",
                         s
                     )));
@@ -701,7 +1043,7 @@ unsafe fn read_value_from_buffer(row_ptr: *const u8, field: &FieldInfo) -> PyRes
                 4 => (unsafe { ptr::read_unaligned(src as *const f32) }) as f64,
                 8 => unsafe { ptr::read_unaligned(src as *const f64) },
                 s => {
-                    return Err(PyTypeError::new_err(format!(
+                    return Err(ParamError::new_err(format!(
                         "unsupported float size: {} bytes",
                         s
                     )));
@@ -715,6 +1057,41 @@ unsafe fn read_value_from_buffer(row_ptr: *const u8, field: &FieldInfo) -> PyRes
             unsafe { ptr::copy_nonoverlapping(src, buf.as_mut_ptr(), field.itemsize) };
             Ok(Value::Blob(buf))
         }
+        DtypeKind::Datetime => {
+            // SAFETY: src points to at least 8 bytes of readable memory
+            let v = unsafe { ptr::read_unaligned(src as *const i64) };
+            // NaT has no epoch value; treat it as an absent bin
+            if v == NAT {
+                Ok(Value::Nil)
+            } else {
+                Ok(Value::Int(v))
+            }
+        }
+        DtypeKind::Bool => {
+            // SAFETY: src points to at least 1 byte of readable memory
+            let b = unsafe { ptr::read(src) };
+            Ok(Value::Bool(b != 0))
+        }
+        DtypeKind::Unicode => {
+            let max_chars = field.itemsize / 4;
+            let mut out = String::with_capacity(max_chars);
+            for i in 0..max_chars {
+                // SAFETY: i < max_chars, so the read stays within field.itemsize bytes
+                let cp = unsafe { ptr::read_unaligned(src.add(i * 4) as *const u32) };
+                // NumPy pads unicode fields with NUL code points
+                if cp == 0 {
+                    break;
+                }
+                let ch = char::from_u32(cp).ok_or_else(|| {
+                    ParamError::new_err(format!(
+                        "invalid UCS4 code point 0x{:X} in unicode field '{}'",
+                        cp, field.name
+                    ))
+                })?;
+                out.push(ch);
+            }
+            Ok(Value::String(out))
+        }
     }
 }
 
@@ -731,7 +1108,7 @@ fn get_array_data_ptr_readonly(array: &Bound<'_, PyAny>) -> PyResult<*const u8>
     let data_tuple = iface.get_item("data")?;
     let ptr_int: usize = data_tuple.get_item(0)?.extract()?;
     if ptr_int == 0 {
-        return Err(PyValueError::new_err("numpy array data pointer is null"));
+        return Err(ParamError::new_err("numpy array data pointer is null"));
     }
     Ok(ptr_int as *const u8)
 }
@@ -745,14 +1122,14 @@ fn get_array_row_stride(array: &Bound<'_, PyAny>, row_size: usize) -> PyResult<i
     let iface = array.getattr("__array_interface__")?;
     let shape: Vec<usize> = iface.get_item("shape")?.extract()?;
     if shape.len() != 1 {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "numpy structured array must be 1-dimensional, got shape {:?}",
             shape
         )));
     }
 
     let row_size = isize::try_from(row_size).map_err(|_| {
-        PyValueError::new_err(format!(
+        ParamError::new_err(format!(
             "dtype itemsize {} exceeds supported pointer stride range",
             row_size
         ))
@@ -765,7 +1142,7 @@ fn get_array_row_stride(array: &Bound<'_, PyAny>, row_size: usize) -> PyResult<i
 
     let strides: Vec<isize> = strides_obj.extract()?;
     if strides.len() != 1 {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "numpy structured array must be 1-dimensional, got strides {:?}",
             strides
         )));
@@ -774,13 +1151,13 @@ fn get_array_row_stride(array: &Bound<'_, PyAny>, row_size: usize) -> PyResult<i
     let stride = strides[0];
     if stride != 0 {
         let abs_stride = stride.checked_abs().ok_or_else(|| {
-            PyValueError::new_err(format!(
+            ParamError::new_err(format!(
                 "numpy structured array row stride {} is not supported",
                 stride
             ))
         })?;
         if abs_stride < row_size {
-            return Err(PyValueError::new_err(format!(
+            return Err(ParamError::new_err(format!(
                 "numpy structured array row stride {} is smaller than dtype itemsize {}",
                 stride, row_size
             )));
@@ -792,35 +1169,76 @@ fn get_array_row_stride(array: &Bound<'_, PyAny>, row_size: usize) -> PyResult<i
 
 fn checked_row_offset(index: usize, row_stride: isize) -> PyResult<isize> {
     let index = isize::try_from(index).map_err(|_| {
-        PyValueError::new_err(format!(
+        ParamError::new_err(format!(
             "array index {} exceeds supported pointer offset range",
             index
         ))
     })?;
 
     index.checked_mul(row_stride).ok_or_else(|| {
-        PyValueError::new_err(format!(
+        ParamError::new_err(format!(
             "buffer offset overflow: index {} * stride {} exceeds isize",
             index, row_stride
         ))
     })
 }
 
+/// Validate that a `_namespace` / `_set` field holds text.
+fn check_name_field(field: &FieldInfo) -> PyResult<()> {
+    match field.kind {
+        DtypeKind::FixedBytes | DtypeKind::VoidBytes | DtypeKind::Unicode => Ok(()),
+        other => Err(ParamError::new_err(format!(
+            "field '{}' must be a bytes (S) or unicode (U) field, got {}",
+            field.name,
+            other.label()
+        ))),
+    }
+}
+
+/// Read a per-row `_namespace` / `_set` value, falling back to `default`
+/// when the field is blank (all NUL bytes or an empty string).
+///
+/// # Safety
+///
+/// Same contract as [`read_value_from_buffer`].
+unsafe fn read_name_field(
+    row_ptr: *const u8,
+    field: &FieldInfo,
+    default: &str,
+) -> PyResult<String> {
+    let name = match unsafe { read_value_from_buffer(row_ptr, field)? } {
+        Value::Blob(b) => {
+            // Trim trailing null bytes for fixed-length fields
+            let trimmed = &b[..b.iter().rposition(|&x| x != 0).map_or(0, |p| p + 1)];
+            String::from_utf8_lossy(trimmed).into_owned()
+        }
+        Value::String(s) => s,
+        _ => String::new(),
+    };
+    Ok(if name.is_empty() {
+        default.to_string()
+    } else {
+        name
+    })
+}
+
 /// Convert a numpy structured array into a list of ``(Key, Vec<Bin>)`` pairs
 /// suitable for batch_write operations.
 ///
-/// The dtype must contain special fields named ``_namespace``, ``_set``, and ``_key``
-/// for key construction, plus any number of bin data fields.
-/// Alternatively, ``namespace``, ``set_name``, and ``key`` can be passed as
-/// separate arguments when all rows share the same namespace/set.
+/// The dtype must contain the key field (``_key`` by default) plus any number
+/// of bin data fields. Optional ``_namespace`` / ``_set`` text fields override
+/// `namespace` / `set_name` per row; a blank value keeps the default. When
+/// either override field is present, rows that resolve to the same record
+/// (namespace + digest) are rejected, since their write order in one batch is
+/// undefined.
 ///
 /// # Arguments
 ///
 /// * `py` - Python GIL token
 /// * `data_array` - numpy structured array with record data
 /// * `dtype_obj` - the numpy dtype describing the array layout
-/// * `namespace` - default namespace (used when ``_namespace`` field is absent)
-/// * `set_name` - default set name (used when ``_set`` field is absent)
+/// * `namespace` - default namespace (used when ``_namespace`` is absent or blank)
+/// * `set_name` - default set name (used when ``_set`` is absent or blank)
 /// * `key_field` - name of the dtype field to use as the user key (default: ``"_key"``)
 pub fn numpy_to_records(
     _py: Python<'_>,
@@ -840,7 +1258,7 @@ pub fn numpy_to_records(
 
     // Overflow check: ensure n * row_stride does not overflow usize
     if n.checked_mul(row_size).is_none() {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "buffer size overflow: {} rows * {} bytes/row exceeds usize",
             n, row_size,
         )));
@@ -857,15 +1275,22 @@ pub fn numpy_to_records(
         .collect();
 
     let key_fi = key_field_info.ok_or_else(|| {
-        PyValueError::new_err(format!(
+        ParamError::new_err(format!(
             "dtype must contain a '{}' field for the record key",
             key_field
         ))
     })?;
 
-    // Check for optional _namespace and _set fields
+    // Optional per-row _namespace / _set overrides (heterogeneous mode)
     let ns_field = fields.iter().find(|f| f.name == "_namespace");
     let set_field = fields.iter().find(|f| f.name == "_set");
+    for fi in [ns_field, set_field].into_iter().flatten() {
+        check_name_field(fi)?;
+    }
+    // In heterogeneous mode two rows can resolve to the same record through
+    // different field values; track (namespace, digest) to reject collisions.
+    let mut seen: Option<HashMap<(String, [u8; 20]), usize>> =
+        (ns_field.is_some() || set_field.is_some()).then(HashMap::new);
 
     let mut result = Vec::with_capacity(n);
 
@@ -890,33 +1315,19 @@ pub fn numpy_to_records(
             _ => key_value,
         };
 
-        // Extract namespace (from field or default)
-        let ns = if let Some(ns_fi) = ns_field {
-            match unsafe { read_value_from_buffer(row_ptr, ns_fi)? } {
-                Value::Blob(b) => {
-                    // Trim trailing null bytes for fixed-length fields
-                    let trimmed = &b[..b.iter().rposition(|&x| x != 0).map_or(0, |p| p + 1)];
-                    String::from_utf8_lossy(trimmed).into_owned()
-                }
-                Value::String(s) => s,
-                _ => namespace.to_string(),
-            }
-        } else {
-            namespace.to_string()
+        // Extract namespace / set name (per-row field, or the default)
+        let ns = match ns_field {
+            Some(ns_fi) => unsafe { read_name_field(row_ptr, ns_fi, namespace)? },
+            None => namespace.to_string(),
         };
-
-        // Extract set name (from field or default)
-        let set = if let Some(set_fi) = set_field {
-            match unsafe { read_value_from_buffer(row_ptr, set_fi)? } {
-                Value::Blob(b) => {
-                    let trimmed = &b[..b.iter().rposition(|&x| x != 0).map_or(0, |p| p + 1)];
-                    String::from_utf8_lossy(trimmed).into_owned()
-                }
-                Value::String(s) => s,
-                _ => set_name.to_string(),
-            }
-        } else {
-            set_name.to_string()
+        if ns.is_empty() {
+            return Err(ParamError::new_err(format!(
+                "row {i} has an empty namespace: set '_namespace' or pass a default namespace"
+            )));
+        }
+        let set = match set_field {
+            Some(set_fi) => unsafe { read_name_field(row_ptr, set_fi, set_name)? },
+            None => set_name.to_string(),
         };
 
         // Build the Key with a properly computed digest.
@@ -934,9 +1345,24 @@ pub fn numpy_to_records(
                 }
             }
             _ => Key::new(ns, set, key_value)
-                .map_err(|e| PyValueError::new_err(format!("Invalid key at row {}: {}", i, e)))?,
+                .map_err(|e| ParamError::new_err(format!("Invalid key at row {}: {}", i, e)))?,
         };
 
+        if let Some(seen) = seen.as_mut() {
+            if let Some(prev) = seen.insert((key.namespace.clone(), key.digest), i) {
+                return Err(ParamError::new_err(format!(
+                    "rows {prev} and {i} resolve to the same record ({}/{}/{:?}); \
+                     per-row _namespace/_set writes must target distinct records",
+                    key.namespace,
+                    key.set_name,
+                    key.user_key
+                        .as_ref()
+                        .map(|v| v.to_string())
+                        .unwrap_or_default(),
+                )));
+            }
+        }
+
         // Extract bin values
         let mut bins = Vec::with_capacity(bin_fields.len());
         for field in &bin_fields {
@@ -1531,6 +1957,177 @@ def make_reverse_slice():
         }
     }
 
+    #[test]
+    fn test_roundtrip_write_read_unicode() {
+        let mut buf = [0u8; 16];
+        let field = FieldInfo {
+            name: "name".to_string(),
+            offset: 0,
+            itemsize: 16,
+            base_itemsize: 16,
+            kind: DtypeKind::Unicode,
+        };
+        unsafe {
+            write_value_to_buffer(buf.as_mut_ptr(), &field, &Value::String("hé".to_string()))
+                .expect("write unicode should succeed");
+            assert_eq!(
+                ptr::read_unaligned(buf.as_ptr().add(4) as *const u32),
+                'é' as u32
+            );
+            let val =
+                read_value_from_buffer(buf.as_ptr(), &field).expect("read unicode should succeed");
+            assert_eq!(val, Value::String("hé".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_write_unicode_truncation() {
+        let mut buf = [0u8; 12];
+        let field = FieldInfo {
+            name: "name".to_string(),
+            offset: 0,
+            itemsize: 8,
+            base_itemsize: 8,
+            kind: DtypeKind::Unicode,
+        };
+        unsafe {
+            write_value_to_buffer(buf.as_mut_ptr(), &field, &Value::String("abcd".to_string()))
+                .expect("write unicode should succeed");
+            // bytes past the 2-char field must stay untouched
+            assert_eq!(&buf[8..], &[0u8; 4]);
+            let val =
+                read_value_from_buffer(buf.as_ptr(), &field).expect("read unicode should succeed");
+            assert_eq!(val, Value::String("ab".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_read_unicode_invalid_code_point_rejected() {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(&0xD800u32.to_ne_bytes());
+        let field = FieldInfo {
+            name: "name".to_string(),
+            offset: 0,
+            itemsize: 4,
+            base_itemsize: 4,
+            kind: DtypeKind::Unicode,
+        };
+        unsafe {
+            let err = read_value_from_buffer(buf.as_ptr(), &field)
+                .expect_err("surrogate code point should be rejected");
+            assert!(err.to_string().contains("invalid UCS4 code point"));
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_write_read_bool() {
+        let mut buf = [0u8; 2];
+        let field = FieldInfo {
+            name: "flag".to_string(),
+            offset: 1,
+            itemsize: 1,
+            base_itemsize: 1,
+            kind: DtypeKind::Bool,
+        };
+        unsafe {
+            write_value_to_buffer(buf.as_mut_ptr(), &field, &Value::Bool(true))
+                .expect("write bool should succeed");
+            assert_eq!(buf, [0, 1]);
+            let val =
+                read_value_from_buffer(buf.as_ptr(), &field).expect("read bool should succeed");
+            assert_eq!(val, Value::Bool(true));
+
+            write_value_to_buffer(buf.as_mut_ptr(), &field, &Value::Int(0))
+                .expect("write int to bool should succeed");
+            assert_eq!(buf, [0, 0]);
+        }
+    }
+
+    #[test]
+    fn test_write_string_to_bool_rejected() {
+        let mut buf = [0u8; 1];
+        let field = FieldInfo {
+            name: "flag".to_string(),
+            offset: 0,
+            itemsize: 1,
+            base_itemsize: 1,
+            kind: DtypeKind::Bool,
+        };
+        unsafe {
+            let err = write_value_to_buffer(buf.as_mut_ptr(), &field, &Value::String("x".into()))
+                .expect_err("string into bool field should fail");
+            assert!(err
+                .to_string()
+                .contains("cannot write string to bool field 'flag'"));
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_write_read_datetime() {
+        let mut buf = [0u8; 8];
+        let field = FieldInfo {
+            name: "ts".to_string(),
+            offset: 0,
+            itemsize: 8,
+            base_itemsize: 8,
+            kind: DtypeKind::Datetime,
+        };
+        unsafe {
+            write_value_to_buffer(buf.as_mut_ptr(), &field, &Value::Int(1_700_000_000_123))
+                .expect("write datetime should succeed");
+            let val =
+                read_value_from_buffer(buf.as_ptr(), &field).expect("read datetime should succeed");
+            assert_eq!(val, Value::Int(1_700_000_000_123));
+        }
+    }
+
+    #[test]
+    fn test_read_datetime_nat_is_nil() {
+        let buf = NAT.to_ne_bytes();
+        let field = FieldInfo {
+            name: "ts".to_string(),
+            offset: 0,
+            itemsize: 8,
+            base_itemsize: 8,
+            kind: DtypeKind::Datetime,
+        };
+        unsafe {
+            let val =
+                read_value_from_buffer(buf.as_ptr(), &field).expect("read NaT should succeed");
+            assert_eq!(val, Value::Nil);
+        }
+    }
+
+    #[test]
+    fn test_check_datetime_unit() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = pyo3::types::PyModule::from_code(
+                py,
+                c"
+class FakeBase:
+    def __init__(self, s):
+        self.str = s
+",
+                c"fake_datetime_dtype.py",
+                c"fake_datetime_dtype",
+            )
+            .expect("fake module should compile");
+            let make = |s: &str| {
+                module
+                    .getattr("FakeBase")
+                    .and_then(|cls| cls.call1((s,)))
+                    .expect("fake dtype should construct")
+            };
+            for ok in ["<M8[s]", "<M8[ms]", "<M8[us]", "<M8[ns]"] {
+                assert!(check_datetime_unit("ts", &make(ok)).is_ok(), "{ok}");
+            }
+            for bad in ["<M8", "<M8[D]", "<M8[ps]"] {
+                assert!(check_datetime_unit("ts", &make(bad)).is_err(), "{bad}");
+            }
+        });
+    }
+
     #[test]
     fn test_numpy_to_records_reads_positive_stride_slice() {
         Python::initialize();
@@ -1600,6 +2197,108 @@ def make_reverse_slice():
         });
     }
 
+    /// Fake `[("_namespace", "S4"), ("_key", "i4"), ("value", "i4")]` array
+    /// built from `rows` of `(namespace_bytes, key, value)`.
+    fn fake_namespace_rows_module<'py>(
+        py: Python<'py>,
+    ) -> PyResult<Bound<'py, pyo3::types::PyModule>> {
+        pyo3::types::PyModule::from_code(
+            py,
+            c"
+import ctypes
+import struct
+
+class FakeFieldDtype:
+    def __init__(self, kind, itemsize):
+        self.kind = kind
+        self.itemsize = itemsize
+        self.base = self
+
+class FakeDtype:
+    def __init__(self, ns_kind):
+        self.names = ('_namespace', '_key', 'value')
+        self.fields = {
+            '_namespace': (FakeFieldDtype(ns_kind, 4), 0),
+            '_key': (FakeFieldDtype('i', 4), 4),
+            'value': (FakeFieldDtype('i', 4), 8),
+        }
+        self.itemsize = 12
+
+class FakeArray:
+    def __init__(self, rows):
+        self._buf = ctypes.create_string_buffer(12 * len(rows))
+        for i, (ns, key, value) in enumerate(rows):
+            struct.pack_into('<4sii', self._buf, 12 * i, ns, key, value)
+        self._length = len(rows)
+        self.__array_interface__ = {
+            'data': (ctypes.addressof(self._buf), False),
+            'shape': (len(rows),),
+            'strides': None,
+        }
+
+    def __len__(self):
+        return self._length
+",
+            c"fake_namespace_rows.py",
+            c"fake_namespace_rows",
+        )
+    }
+
+    #[test]
+    fn test_numpy_to_records_per_row_namespace_overrides_default() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = fake_namespace_rows_module(py).expect("helper module should compile");
+            let dtype = module.getattr("FakeDtype").unwrap().call1(("S",)).unwrap();
+            let rows = vec![(b"ns1".to_vec(), 1, 10), (Vec::new(), 2, 20)];
+            let array = module.getattr("FakeArray").unwrap().call1((rows,)).unwrap();
+
+            let records = numpy_to_records(py, &array, &dtype, "test", "demo", "_key")
+                .expect("per-row namespaces should convert");
+            assert_eq!(records[0].0.namespace, "ns1");
+            assert_eq!(records[1].0.namespace, "test", "blank keeps the default");
+            assert_eq!(
+                records[0].1,
+                vec![Bin::new("value".to_string(), Value::Int(10))],
+                "_namespace is not written as a bin"
+            );
+        });
+    }
+
+    #[test]
+    fn test_numpy_to_records_rejects_per_row_collision() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = fake_namespace_rows_module(py).expect("helper module should compile");
+            let dtype = module.getattr("FakeDtype").unwrap().call1(("S",)).unwrap();
+            // Row 1 falls back to "test", colliding with row 0's explicit "test".
+            let rows = vec![(b"test".to_vec(), 7, 1), (Vec::new(), 7, 2)];
+            let array = module.getattr("FakeArray").unwrap().call1((rows,)).unwrap();
+
+            let err = numpy_to_records(py, &array, &dtype, "test", "demo", "_key")
+                .expect_err("colliding rows should be rejected");
+            assert!(err.to_string().contains("rows 0 and 1"), "{err}");
+        });
+    }
+
+    #[test]
+    fn test_numpy_to_records_rejects_non_text_namespace_field() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = fake_namespace_rows_module(py).expect("helper module should compile");
+            let dtype = module.getattr("FakeDtype").unwrap().call1(("i",)).unwrap();
+            let array = module
+                .getattr("FakeArray")
+                .unwrap()
+                .call1((vec![(vec![0u8; 4], 1, 1)],))
+                .unwrap();
+
+            let err = numpy_to_records(py, &array, &dtype, "test", "demo", "_key")
+                .expect_err("int _namespace field should be rejected");
+            assert!(err.to_string().contains("'_namespace'"), "{err}");
+        });
+    }
+
     #[test]
     fn test_bytes_key_trailing_null_trim() {
         let padded = Value::Blob(b"alice\x00\x00\x00\x00\x00".to_vec());
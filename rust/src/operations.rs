@@ -3,6 +3,9 @@
 //! Each operation is represented as a Python dict with at minimum an `"op"` key
 //! (integer operation code). This module dispatches on that code to construct
 //! the corresponding Rust `Operation` for basic CRUD, List CDT, and Map CDT ops.
+//! Objects defining `__op_dict__()` are accepted too and converted through it.
+
+use std::collections::HashMap;
 
 use aerospike_core::{
     operations,
@@ -10,30 +13,37 @@ use aerospike_core::{
     operations::hll::{self as hll_ops, HLLPolicy},
     operations::lists::{
         self as list_ops, ListOrderType, ListPolicy, ListReturnType, ListSortFlags,
+        ToListReturnTypeBitmask,
+    },
+    operations::maps::{
+        self as map_ops, MapOrder, MapPolicy, MapReturnType, MapWriteFlags, MapWriteMode,
+        ToMapReturnTypeBitmask,
     },
-    operations::maps::{self as map_ops, MapOrder, MapPolicy, MapReturnType, MapWriteMode},
     operations::Operation,
-    Bin, Value,
+    Bin, Expiration, Value,
 };
 use log::trace;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
 use crate::constants::*;
+use crate::errors::{BinTypeError, ParamError};
+use crate::policy::write_policy::apply_ttl;
 use crate::types::value::py_to_value;
 
 // ── Helper functions ────────────────────────────────────────────
 
 /// Require a bin name, returning a descriptive error if absent.
 fn require_bin(bin_name: &Option<String>, op_name: &str) -> PyResult<String> {
-    bin_name.clone().ok_or_else(|| {
-        pyo3::exceptions::PyValueError::new_err(format!("{op_name} operation requires 'bin'"))
-    })
+    bin_name
+        .clone()
+        .ok_or_else(|| ParamError::new_err(format!("{op_name} operation requires 'bin'")))
 }
 
 fn get_index(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
     dict.get_item("index")?
-        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Operation requires 'index'"))?
+        .ok_or_else(|| ParamError::new_err("Operation requires 'index'"))?
         .extract()
 }
 
@@ -43,9 +53,7 @@ fn get_rank(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
         return v.extract();
     }
     dict.get_item("index")?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Operation requires 'rank' or 'index'")
-        })?
+        .ok_or_else(|| ParamError::new_err("Operation requires 'rank' or 'index'"))?
         .extract()
 }
 
@@ -58,14 +66,14 @@ fn get_count(dict: &Bound<'_, PyDict>) -> PyResult<Option<i64>> {
 
 fn get_return_type(dict: &Bound<'_, PyDict>) -> PyResult<i32> {
     dict.get_item("return_type")?
-        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Operation requires 'return_type'"))?
+        .ok_or_else(|| ParamError::new_err("Operation requires 'return_type'"))?
         .extract()
 }
 
 fn get_map_key(dict: &Bound<'_, PyDict>) -> PyResult<Value> {
     let v = dict
         .get_item("map_key")?
-        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Operation requires 'map_key'"))?;
+        .ok_or_else(|| ParamError::new_err("Operation requires 'map_key'"))?;
     py_to_value(&v)
 }
 
@@ -77,9 +85,45 @@ fn get_val_end(dict: &Bound<'_, PyDict>) -> PyResult<Value> {
         .map(|v| v.unwrap_or(Value::Infinity))
 }
 
-/// Map a Python integer to a [`ListReturnType`] enum variant.
-fn int_to_list_return_type(v: i32) -> ListReturnType {
-    match v {
+/// A `LIST_RETURN_*` value, optionally with [`RETURN_INVERTED`] set.
+#[derive(Clone, Copy)]
+struct ListReturn {
+    base: ListReturnType,
+    inverted: bool,
+}
+
+impl ToListReturnTypeBitmask for ListReturn {
+    fn to_bitmask(self) -> i64 {
+        let base = self.base.to_bitmask();
+        if self.inverted {
+            base | RETURN_INVERTED
+        } else {
+            base
+        }
+    }
+}
+
+/// A `MAP_RETURN_*` value, optionally with [`RETURN_INVERTED`] set.
+#[derive(Clone, Copy)]
+struct MapReturn {
+    base: MapReturnType,
+    inverted: bool,
+}
+
+impl ToMapReturnTypeBitmask for MapReturn {
+    fn to_bitmask(self) -> i64 {
+        let base = self.base.to_bitmask();
+        if self.inverted {
+            base | RETURN_INVERTED
+        } else {
+            base
+        }
+    }
+}
+
+/// Map a Python integer to a list return type, keeping the inverted flag.
+fn int_to_list_return_type(v: i32) -> ListReturn {
+    let base = match i64::from(v) & !RETURN_INVERTED {
         0 => ListReturnType::None,
         1 => ListReturnType::Index,
         2 => ListReturnType::ReverseIndex,
@@ -89,12 +133,16 @@ fn int_to_list_return_type(v: i32) -> ListReturnType {
         7 => ListReturnType::Values,
         13 => ListReturnType::Exists,
         _ => ListReturnType::None,
+    };
+    ListReturn {
+        base,
+        inverted: i64::from(v) & RETURN_INVERTED != 0,
     }
 }
 
-/// Map a Python integer to a [`MapReturnType`] enum variant.
-fn int_to_map_return_type(v: i32) -> MapReturnType {
-    match v {
+/// Map a Python integer to a map return type, keeping the inverted flag.
+fn int_to_map_return_type(v: i32) -> MapReturn {
+    let base = match i64::from(v) & !RETURN_INVERTED {
         0 => MapReturnType::None,
         1 => MapReturnType::Index,
         2 => MapReturnType::ReverseIndex,
@@ -106,6 +154,10 @@ fn int_to_map_return_type(v: i32) -> MapReturnType {
         8 => MapReturnType::KeyValue,
         13 => MapReturnType::Exists,
         _ => MapReturnType::None,
+    };
+    MapReturn {
+        base,
+        inverted: i64::from(v) & RETURN_INVERTED != 0,
     }
 }
 
@@ -126,6 +178,15 @@ fn parse_list_policy(dict: &Bound<'_, PyDict>) -> PyResult<ListPolicy> {
             .map(|v| v.extract())
             .transpose()?
             .unwrap_or(0);
+        let known = LIST_WRITE_ADD_UNIQUE
+            | LIST_WRITE_INSERT_BOUNDED
+            | LIST_WRITE_NO_FAIL
+            | LIST_WRITE_PARTIAL;
+        if flags & !known != 0 {
+            return Err(ParamError::new_err(format!(
+                "list_policy flags must combine LIST_WRITE_* constants, got {flags}"
+            )));
+        }
         let order_type = match order {
             1 => ListOrderType::Ordered,
             _ => ListOrderType::Unordered,
@@ -156,11 +217,34 @@ fn parse_map_policy(dict: &Bound<'_, PyDict>) -> PyResult<MapPolicy> {
             .map(|v| v.extract())
             .transpose()?
             .unwrap_or(0);
+        let flags: u8 = policy_dict
+            .get_item("flags")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(0);
         let map_order = match order {
             1 => MapOrder::KeyOrdered,
             3 => MapOrder::KeyValueOrdered,
             _ => MapOrder::Unordered,
         };
+        // `flags` (server 4.3+) supersede the legacy `write_mode`.
+        if flags != MapWriteFlags::DEFAULT {
+            let known = MapWriteFlags::CREATE_ONLY
+                | MapWriteFlags::UPDATE_ONLY
+                | MapWriteFlags::NO_FAIL
+                | MapWriteFlags::PARTIAL;
+            if flags & !known != 0 {
+                return Err(ParamError::new_err(format!(
+                    "map_policy flags must combine MAP_WRITE_FLAGS_* constants, got {flags}"
+                )));
+            }
+            if write_mode != 0 {
+                return Err(ParamError::new_err(
+                    "map_policy accepts either 'flags' or 'write_mode', not both",
+                ));
+            }
+            return Ok(MapPolicy::new_with_flags(map_order, flags));
+        }
         let mode = match write_mode {
             1 => MapWriteMode::CreateOnly,
             2 => MapWriteMode::UpdateOnly,
@@ -205,41 +289,31 @@ fn parse_bit_policy(dict: &Bound<'_, PyDict>) -> PyResult<BitPolicy> {
 
 fn get_bit_offset(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
     dict.get_item("bit_offset")?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Bit operation requires 'bit_offset'")
-        })?
+        .ok_or_else(|| ParamError::new_err("Bit operation requires 'bit_offset'"))?
         .extract()
 }
 
 fn get_bit_size(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
     dict.get_item("bit_size")?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Bit operation requires 'bit_size'")
-        })?
+        .ok_or_else(|| ParamError::new_err("Bit operation requires 'bit_size'"))?
         .extract()
 }
 
 fn get_byte_size(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
     dict.get_item("byte_size")?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Bit operation requires 'byte_size'")
-        })?
+        .ok_or_else(|| ParamError::new_err("Bit operation requires 'byte_size'"))?
         .extract()
 }
 
 fn get_byte_offset(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
     dict.get_item("byte_offset")?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Bit operation requires 'byte_offset'")
-        })?
+        .ok_or_else(|| ParamError::new_err("Bit operation requires 'byte_offset'"))?
         .extract()
 }
 
 fn get_shift(dict: &Bound<'_, PyDict>) -> PyResult<i64> {
     dict.get_item("shift")?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Bit shift operation requires 'shift'")
-        })?
+        .ok_or_else(|| ParamError::new_err("Bit shift operation requires 'shift'"))?
         .extract()
 }
 
@@ -280,9 +354,7 @@ fn get_scan_value(dict: &Bound<'_, PyDict>) -> PyResult<bool> {
     dict.get_item("val")?
         .map(|v| v.extract())
         .transpose()?
-        .ok_or_else(|| {
-            pyo3::exceptions::PyValueError::new_err("Bit scan operation requires 'val' (bool)")
-        })
+        .ok_or_else(|| ParamError::new_err("Bit scan operation requires 'val' (bool)"))
 }
 
 /// Unwrap a `Value::List` into its inner `Vec`, or wrap a single value in a `Vec`.
@@ -300,30 +372,92 @@ fn parse_i32_flag(val: &Option<Value>, op_name: &str, field_name: &str) -> PyRes
     match val {
         None | Some(Value::Nil) => Ok(0),
         Some(Value::Int(i)) => i32::try_from(*i).map_err(|_| {
-            pyo3::exceptions::PyValueError::new_err(format!(
+            ParamError::new_err(format!(
                 "{op_name} operation '{field_name}' must fit in i32 range, got {i}"
             ))
         }),
-        Some(other) => Err(pyo3::exceptions::PyTypeError::new_err(format!(
+        Some(other) => Err(ParamError::new_err(format!(
             "{op_name} operation '{field_name}' must be int, got {other:?}"
         ))),
     }
 }
 
+/// Check that an increment offset is an int or a float.
+pub fn check_increment_offset(offset: &Value) -> PyResult<()> {
+    match offset {
+        Value::Int(_) | Value::Float(_) => Ok(()),
+        other => Err(ParamError::new_err(format!(
+            "increment offset must be an int or float, got {other:?}"
+        ))),
+    }
+}
+
+/// Numeric type a bin is known to hold from an earlier write or increment in
+/// the same operate() call.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum NumKind {
+    Int,
+    Float,
+    Other,
+}
+
+impl NumKind {
+    fn of(value: &Value) -> Self {
+        match value {
+            Value::Int(_) => NumKind::Int,
+            Value::Float(_) => NumKind::Float,
+            _ => NumKind::Other,
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            NumKind::Int => "an int",
+            NumKind::Float => "a float",
+            NumKind::Other => "a non-numeric value",
+        }
+    }
+}
+
+/// The operation dict for `item`: the dict itself, or the result of its
+/// `__op_dict__()` method for op-builder objects.
+fn op_dict<'py>(item: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+    if let Ok(dict) = item.cast::<PyDict>() {
+        return Ok(dict.clone());
+    }
+    let py = item.py();
+    if !item.hasattr(intern!(py, "__op_dict__"))? {
+        return Err(ParamError::new_err(format!(
+            "Operation must be a dict or define __op_dict__(), got {}",
+            item.get_type().name()?
+        )));
+    }
+    let converted = item.call_method0(intern!(py, "__op_dict__"))?;
+    if let Ok(dict) = converted.cast::<PyDict>() {
+        return Ok(dict.clone());
+    }
+    Err(ParamError::new_err(format!(
+        "__op_dict__() must return a dict, got {}",
+        converted.get_type().name()?
+    )))
+}
+
 // ── Main conversion ─────────────────────────────────────────────
 
 /// Convert a Python list of operation dicts to Rust Operations.
-/// Each operation is a dict: {"op": int, "bin": str, "val": any, ...}
+/// Each operation is a dict: {"op": int, "bin": str, "val": any, ...}, or an
+/// object whose `__op_dict__()` returns one.
 pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>> {
     trace!("Converting {} Python operations to Rust", ops_list.len());
     let mut rust_ops: Vec<Operation> = Vec::with_capacity(ops_list.len());
+    let mut known_kinds: HashMap<String, NumKind> = HashMap::new();
 
     for item in ops_list.iter() {
-        let dict = item.cast::<PyDict>()?;
+        let dict = &op_dict(&item)?;
 
         let op_code: i32 = dict
             .get_item("op")?
-            .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("Operation must have 'op' key"))?
+            .ok_or_else(|| ParamError::new_err("Operation must have 'op' key"))?
             .extract()?;
 
         let bin_name: Option<String> = dict
@@ -350,12 +484,30 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
             OP_WRITE => {
                 let name = require_bin(&bin_name, "Write")?;
                 let v = val.unwrap_or(Value::Nil);
+                if v == Value::Nil {
+                    known_kinds.remove(&name);
+                } else {
+                    known_kinds.insert(name.clone(), NumKind::of(&v));
+                }
                 let bin = Bin::new(name, v);
                 operations::put(&bin)
             }
             OP_INCR => {
                 let name = require_bin(&bin_name, "Increment")?;
                 let v = val.unwrap_or(Value::Int(1));
+                check_increment_offset(&v)?;
+                let kind = NumKind::of(&v);
+                match known_kinds.insert(name.clone(), kind) {
+                    Some(prev) if prev != kind => {
+                        return Err(BinTypeError::new_err(format!(
+                            "bin '{name}' holds {} earlier in this operate() call; \
+                             cannot increment it by {}",
+                            prev.describe(),
+                            kind.describe()
+                        )));
+                    }
+                    _ => {}
+                }
                 let bin = Bin::new(name, v);
                 operations::add(&bin)
             }
@@ -566,6 +718,7 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 let name = require_bin(&bin_name, "list_sort")?;
                 let flags = parse_i32_flag(&val, "list_sort", "val")?;
                 let sort_flags = match flags {
+                    1 => ListSortFlags::Descending,
                     2 => ListSortFlags::DropDuplicates,
                     _ => ListSortFlags::Default,
                 };
@@ -580,6 +733,44 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 };
                 list_ops::set_order(&name, order_type)
             }
+            OP_LIST_CREATE => {
+                let name = require_bin(&bin_name, "list_create")?;
+                let order = parse_i32_flag(&val, "list_create", "val")?;
+                let order_type = match order {
+                    1 => ListOrderType::Ordered,
+                    _ => ListOrderType::Unordered,
+                };
+                let pad: bool = dict
+                    .get_item("pad")?
+                    .map(|v| v.extract())
+                    .transpose()?
+                    .unwrap_or(false);
+                list_ops::create(&name, order_type, pad)
+            }
+            OP_LIST_GET_BY_VALUE_REL_RANK_RANGE => {
+                let name = require_bin(&bin_name, "list_get_by_value_rel_rank_range")?;
+                let v = val.unwrap_or(Value::Nil);
+                let rank = get_rank(dict)?;
+                let rt = int_to_list_return_type(get_return_type(dict)?);
+                match get_count(dict)? {
+                    Some(count) => {
+                        list_ops::get_by_value_relative_rank_range_count(&name, v, rank, count, rt)
+                    }
+                    None => list_ops::get_by_value_relative_rank_range(&name, v, rank, rt),
+                }
+            }
+            OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE => {
+                let name = require_bin(&bin_name, "list_remove_by_value_rel_rank_range")?;
+                let v = val.unwrap_or(Value::Nil);
+                let rank = get_rank(dict)?;
+                let rt = int_to_list_return_type(get_return_type(dict)?);
+                match get_count(dict)? {
+                    Some(count) => list_ops::remove_by_value_relative_rank_range_count(
+                        &name, rt, v, rank, count,
+                    ),
+                    None => list_ops::remove_by_value_relative_rank_range(&name, rt, v, rank),
+                }
+            }
 
             // ── Map CDT operations ───────────────────────────
             OP_MAP_SET_ORDER => {
@@ -592,6 +783,64 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 };
                 map_ops::set_order(&name, map_order)
             }
+            OP_MAP_CREATE => {
+                let name = require_bin(&bin_name, "map_create")?;
+                let order = parse_i32_flag(&val, "map_create", "val")?;
+                let map_order = match order {
+                    1 => MapOrder::KeyOrdered,
+                    3 => MapOrder::KeyValueOrdered,
+                    _ => MapOrder::Unordered,
+                };
+                map_ops::create(&name, map_order, vec![])
+            }
+            OP_MAP_GET_BY_KEY_REL_INDEX_RANGE => {
+                let name = require_bin(&bin_name, "map_get_by_key_rel_index_range")?;
+                let key = get_map_key(dict)?;
+                let index = get_index(dict)?;
+                let rt = int_to_map_return_type(get_return_type(dict)?);
+                match get_count(dict)? {
+                    Some(count) => {
+                        map_ops::get_by_key_relative_index_range_count(&name, key, index, count, rt)
+                    }
+                    None => map_ops::get_by_key_relative_index_range(&name, key, index, rt),
+                }
+            }
+            OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE => {
+                let name = require_bin(&bin_name, "map_remove_by_key_rel_index_range")?;
+                let key = get_map_key(dict)?;
+                let index = get_index(dict)?;
+                let rt = int_to_map_return_type(get_return_type(dict)?);
+                match get_count(dict)? {
+                    Some(count) => map_ops::remove_by_key_relative_index_range_count(
+                        &name, key, index, count, rt,
+                    ),
+                    None => map_ops::remove_by_key_relative_index_range(&name, key, index, rt),
+                }
+            }
+            OP_MAP_GET_BY_VALUE_REL_RANK_RANGE => {
+                let name = require_bin(&bin_name, "map_get_by_value_rel_rank_range")?;
+                let v = val.unwrap_or(Value::Nil);
+                let rank = get_rank(dict)?;
+                let rt = int_to_map_return_type(get_return_type(dict)?);
+                match get_count(dict)? {
+                    Some(count) => {
+                        map_ops::get_by_value_relative_rank_range_count(&name, v, rank, count, rt)
+                    }
+                    None => map_ops::get_by_value_relative_rank_range(&name, v, rank, rt),
+                }
+            }
+            OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE => {
+                let name = require_bin(&bin_name, "map_remove_by_value_rel_rank_range")?;
+                let v = val.unwrap_or(Value::Nil);
+                let rank = get_rank(dict)?;
+                let rt = int_to_map_return_type(get_return_type(dict)?);
+                match get_count(dict)? {
+                    Some(count) => map_ops::remove_by_value_relative_rank_range_count(
+                        &name, v, rank, count, rt,
+                    ),
+                    None => map_ops::remove_by_value_relative_rank_range(&name, v, rank, rt),
+                }
+            }
             OP_MAP_PUT => {
                 let name = require_bin(&bin_name, "map_put")?;
                 let policy = parse_map_policy(dict)?;
@@ -606,11 +855,7 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 // Convert Value::HashMap to HashMap
                 match v {
                     Value::HashMap(map) => map_ops::put_items(&policy, &name, map),
-                    _ => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(
-                            "map_put_items requires a dict value",
-                        ))
-                    }
+                    _ => return Err(ParamError::new_err("map_put_items requires a dict value")),
                 }
             }
             OP_MAP_INCREMENT => {
@@ -770,11 +1015,7 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 let policy = parse_hll_policy(dict)?;
                 let index_bit_count: i64 = dict
                     .get_item("index_bit_count")?
-                    .ok_or_else(|| {
-                        pyo3::exceptions::PyValueError::new_err(
-                            "hll_init requires 'index_bit_count'",
-                        )
-                    })?
+                    .ok_or_else(|| ParamError::new_err("hll_init requires 'index_bit_count'"))?
                     .extract()?;
                 let minhash_bit_count: i64 = dict
                     .get_item("minhash_bit_count")?
@@ -838,11 +1079,7 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 let name = require_bin(&bin_name, "hll_fold")?;
                 let index_bit_count: i64 = dict
                     .get_item("index_bit_count")?
-                    .ok_or_else(|| {
-                        pyo3::exceptions::PyValueError::new_err(
-                            "hll_fold requires 'index_bit_count'",
-                        )
-                    })?
+                    .ok_or_else(|| ParamError::new_err("hll_fold requires 'index_bit_count'"))?
                     .extract()?;
                 hll_ops::fold(&name, index_bit_count)
             }
@@ -937,13 +1174,13 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 let value_int: i64 = match &val {
                     Some(Value::Int(i)) => *i,
                     Some(other) => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        return Err(ParamError::new_err(format!(
                             "bit operation requires an integer value, got {:?}",
                             other
                         )))
                     }
                     None => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(
+                        return Err(ParamError::new_err(
                             "bit operation requires a 'val' parameter",
                         ))
                     }
@@ -962,13 +1199,13 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 let value_int: i64 = match &val {
                     Some(Value::Int(i)) => *i,
                     Some(other) => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        return Err(ParamError::new_err(format!(
                             "bit operation requires an integer value, got {:?}",
                             other
                         )))
                     }
                     None => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(
+                        return Err(ParamError::new_err(
                             "bit operation requires a 'val' parameter",
                         ))
                     }
@@ -987,13 +1224,13 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
                 let value_int: i64 = match &val {
                     Some(Value::Int(i)) => *i,
                     Some(other) => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                        return Err(ParamError::new_err(format!(
                             "bit operation requires an integer value, got {:?}",
                             other
                         )))
                     }
                     None => {
-                        return Err(pyo3::exceptions::PyValueError::new_err(
+                        return Err(ParamError::new_err(
                             "bit operation requires a 'val' parameter",
                         ))
                     }
@@ -1036,11 +1273,11 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
             }
 
             _ => {
-                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                return Err(ParamError::new_err(format!(
                     "Unsupported operation code: {op_code}. Supported codes: \
                      READ={OP_READ}, WRITE={OP_WRITE}, INCR={OP_INCR}, \
                      APPEND={OP_APPEND}, PREPEND={OP_PREPEND}, TOUCH={OP_TOUCH}, DELETE={OP_DELETE}, \
-                     List CDT=1001-1031, Map CDT=2001-2027, HLL CDT=3001-3010, Bit CDT=4001-4054"
+                     List CDT=1001-1034, Map CDT=2001-2032, HLL CDT=3001-3010, Bit CDT=4001-4054"
                 )));
             }
         };
@@ -1051,11 +1288,66 @@ pub fn py_ops_to_rust(ops_list: &Bound<'_, PyList>) -> PyResult<Vec<Operation>>
     Ok(rust_ops)
 }
 
+/// Apply the record-level options of touch and delete operation dicts to the
+/// command's policy: `"ttl"` on an `OPERATOR_TOUCH` op sets the record TTL
+/// (overriding `meta` / policy `ttl`), and `"durable_delete": True` on an
+/// `OPERATOR_DELETE` op leaves a tombstone.
+pub fn apply_record_op_options(
+    ops_list: &Bound<'_, PyList>,
+    expiration: &mut Expiration,
+    durable_delete: &mut bool,
+) -> PyResult<()> {
+    for item in ops_list.iter() {
+        let dict = &op_dict(&item)?;
+        let Some(op_code) = dict.get_item("op")? else {
+            continue;
+        };
+        match op_code.extract::<i32>()? {
+            OP_TOUCH => {
+                if let Some(ttl) = dict.get_item("ttl")?.filter(|v| !v.is_none()) {
+                    apply_ttl(expiration, ttl.extract()?)?;
+                }
+            }
+            OP_DELETE => {
+                if let Some(flag) = dict.get_item("durable_delete")?.filter(|v| !v.is_none()) {
+                    *durable_delete |= flag.extract::<bool>().map_err(|_| {
+                        ParamError::new_err("delete operation durable_delete must be a bool")
+                    })?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Whether `op` only reads the record.
+///
+/// `aerospike_core` keeps an operation's type private; its `Debug` output
+/// (`Operation { op: CdtRead, .. }`) is the only place it shows.
+pub fn is_read_op(op: &Operation) -> bool {
+    let debug = format!("{op:?}");
+    let kind = debug
+        .split_once("op: ")
+        .and_then(|(_, rest)| rest.split([',', ' ']).next())
+        .unwrap_or_default();
+    matches!(kind, "Read" | "CdtRead" | "ExpRead" | "BitRead" | "HllRead")
+}
+
 #[cfg(test)]
 mod tests {
-    use super::parse_i32_flag;
+    use super::{
+        apply_record_op_options, int_to_list_return_type, int_to_map_return_type, is_read_op,
+        parse_i32_flag, parse_list_policy, parse_map_policy, py_ops_to_rust,
+    };
+    use crate::errors::{BinTypeError, ParamError};
+    use aerospike_core::operations::lists::ToListReturnTypeBitmask;
+    use aerospike_core::operations::maps::ToMapReturnTypeBitmask;
+    use aerospike_core::Expiration;
     use aerospike_core::Value;
-    use pyo3::{exceptions::PyTypeError, exceptions::PyValueError, PyErr, Python};
+    use pyo3::prelude::*;
+    use pyo3::types::{PyList, PyModule};
+    use pyo3::{PyErr, Python};
 
     #[test]
     fn parse_i32_flag_defaults_to_zero_for_missing_or_nil() {
@@ -1086,7 +1378,7 @@ mod tests {
         )
         .expect_err("out-of-range int should fail");
         Python::attach(|py| {
-            assert!(err.is_instance_of::<PyValueError>(py));
+            assert!(err.is_instance_of::<ParamError>(py));
         });
     }
 
@@ -1096,7 +1388,175 @@ mod tests {
             .expect_err("non-int should fail");
         Python::initialize();
         Python::attach(|py| {
-            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+
+    #[test]
+    fn py_ops_to_rust_accepts_op_dict_protocol() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::from_code(
+                py,
+                c"
+class Write:
+    def __op_dict__(self):
+        return {'op': 2, 'bin': 'n', 'val': 1}
+
+class Broken:
+    def __op_dict__(self):
+        return 'not a dict'
+",
+                c"fake_ops.py",
+                c"fake_ops",
+            )
+            .expect("fake module should compile");
+            let write = module.getattr("Write").unwrap().call0().unwrap();
+            let read = pyo3::types::PyDict::new(py);
+            read.set_item("op", 1).unwrap();
+            let ops = PyList::new(py, [write, read.into_any()]).unwrap();
+            assert_eq!(py_ops_to_rust(&ops).expect("ops should convert").len(), 2);
+
+            for bad in [
+                module.getattr("Broken").unwrap().call0().unwrap(),
+                5i32.into_pyobject(py).unwrap().into_any(),
+            ] {
+                let err = py_ops_to_rust(&PyList::new(py, [bad]).unwrap())
+                    .expect_err("non-dict op should fail");
+                assert!(err.is_instance_of::<ParamError>(py));
+            }
+        });
+    }
+
+    #[test]
+    fn increment_rejects_non_numeric_offsets_and_mixed_types() {
+        Python::initialize();
+        Python::attach(|py| {
+            let op = |code: i32, val: &Bound<'_, PyAny>| {
+                let d = pyo3::types::PyDict::new(py);
+                d.set_item("op", code).unwrap();
+                d.set_item("bin", "n").unwrap();
+                d.set_item("val", val).unwrap();
+                d
+            };
+            let one = 1i64.into_pyobject(py).unwrap().into_any();
+            let half = 0.5f64.into_pyobject(py).unwrap().into_any();
+            let text = "a".into_pyobject(py).unwrap().into_any();
+
+            let floats = PyList::new(py, [op(5, &half), op(5, &half)]).unwrap();
+            assert_eq!(py_ops_to_rust(&floats).unwrap().len(), 2);
+
+            let bad = PyList::new(py, [op(5, &text)]).unwrap();
+            let err = py_ops_to_rust(&bad).expect_err("string offset should fail");
+            assert!(err.is_instance_of::<ParamError>(py));
+
+            for ops in [
+                [op(2, &one), op(5, &half)],
+                [op(5, &half), op(5, &one)],
+                [op(2, &text), op(5, &one)],
+            ] {
+                let err = py_ops_to_rust(&PyList::new(py, ops).unwrap())
+                    .expect_err("mixed int / float should fail");
+                assert!(err.is_instance_of::<BinTypeError>(py));
+            }
         });
     }
+
+    #[test]
+    fn apply_record_op_options_reads_touch_ttl_and_durable_delete() {
+        Python::initialize();
+        Python::attach(|py| {
+            let touch = pyo3::types::PyDict::new(py);
+            touch.set_item("op", 11).unwrap();
+            touch.set_item("ttl", 3600).unwrap();
+            let delete = pyo3::types::PyDict::new(py);
+            delete.set_item("op", 12).unwrap();
+            delete.set_item("durable_delete", true).unwrap();
+            let ops = PyList::new(py, [touch, delete]).unwrap();
+
+            let mut expiration = Expiration::NamespaceDefault;
+            let mut durable_delete = false;
+            apply_record_op_options(&ops, &mut expiration, &mut durable_delete).unwrap();
+            assert!(matches!(expiration, Expiration::Seconds(3600)));
+            assert!(durable_delete);
+
+            let bad = pyo3::types::PyDict::new(py);
+            bad.set_item("op", 12).unwrap();
+            bad.set_item("durable_delete", "yes").unwrap();
+            let err = apply_record_op_options(
+                &PyList::new(py, [bad]).unwrap(),
+                &mut expiration,
+                &mut durable_delete,
+            )
+            .expect_err("non-bool durable_delete should fail");
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+
+    #[test]
+    fn parse_list_policy_keeps_write_flags_and_rejects_unknown_bits() {
+        Python::initialize();
+        Python::attach(|py| {
+            let policy = pyo3::types::PyDict::new(py);
+            policy.set_item("flags", 1 | 4).unwrap();
+            let op = pyo3::types::PyDict::new(py);
+            op.set_item("list_policy", &policy).unwrap();
+            assert_eq!(parse_list_policy(&op).unwrap().flags, 5);
+
+            policy.set_item("flags", 16).unwrap();
+            let err = parse_list_policy(&op).expect_err("unknown flag bit should fail");
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+
+    #[test]
+    fn parse_map_policy_uses_write_flags_over_write_mode() {
+        Python::initialize();
+        Python::attach(|py| {
+            let policy = pyo3::types::PyDict::new(py);
+            policy.set_item("flags", 1 | 4).unwrap();
+            let op = pyo3::types::PyDict::new(py);
+            op.set_item("map_policy", &policy).unwrap();
+            assert_eq!(parse_map_policy(&op).unwrap().flags, 5);
+
+            policy.set_item("write_mode", 2).unwrap();
+            let err = parse_map_policy(&op).expect_err("flags with write_mode should fail");
+            assert!(err.is_instance_of::<ParamError>(py));
+
+            policy.del_item("write_mode").unwrap();
+            policy.set_item("flags", 32).unwrap();
+            let err = parse_map_policy(&op).expect_err("unknown flag bit should fail");
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+
+    #[test]
+    fn return_types_keep_the_inverted_flag() {
+        assert_eq!(int_to_list_return_type(7).to_bitmask(), 7);
+        assert_eq!(int_to_list_return_type(7 | 0x10000).to_bitmask(), 0x10007);
+        assert_eq!(int_to_list_return_type(0x10000).to_bitmask(), 0x10000);
+        assert_eq!(int_to_map_return_type(8 | 0x10000).to_bitmask(), 0x10008);
+        // Unknown base values still fall back to NONE.
+        assert_eq!(int_to_map_return_type(99).to_bitmask(), 0);
+    }
+
+    #[test]
+    fn is_read_op_tells_reads_from_writes() {
+        use aerospike_core::operations::{self, lists};
+        use aerospike_core::{Bin, Value};
+
+        assert!(is_read_op(&operations::get_bin("a")));
+        assert!(is_read_op(&operations::get_header()));
+        assert!(is_read_op(&lists::size("l")));
+        assert!(!is_read_op(&operations::put(&Bin::new(
+            "a".into(),
+            Value::Int(1)
+        ))));
+        assert!(!is_read_op(&operations::add(&Bin::new(
+            "a".into(),
+            Value::Int(1)
+        ))));
+        assert!(!is_read_op(&lists::clear("l")));
+        assert!(!is_read_op(&operations::delete()));
+    }
 }
@@ -0,0 +1,160 @@
+//! Column buffers for the pandas DataFrame helpers.
+//!
+//! `batch_read_df` and `Query.to_df` build one column per bin directly from
+//! `aerospike_core` records instead of materialising a dict per row. Integer,
+//! float and bool bins are written straight into NumPy arrays; every other
+//! bin becomes a plain Python list. `aerospike_py.dataframe` then wraps the
+//! columns into a `pandas.DataFrame`.
+//!
+//! Missing bins, `Nil` values and failed batch rows are nulls: `NaN` in float
+//! columns, a separate bool mask for int / bool columns (turned into pandas
+//! nullable `Int64` / `boolean` arrays), and `None` in list columns. A bin that
+//! mixes ints and floats is widened to `float64`; any other mix falls back to a
+//! list column.
+
+use std::ptr;
+
+use aerospike_core::{BatchRecord, Record, Value};
+use log::debug;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::columns::{column_names, column_values, infer_column_kind, ColumnKind, Row};
+use crate::errors::result_code_to_int;
+use crate::numpy_support::{float_value_to_f64, get_array_data_ptr};
+use crate::types::value::value_to_py;
+
+/// Build `(values, mask | None)` for one column.
+fn build_column(
+    py: Python<'_>,
+    np: &Bound<'_, PyModule>,
+    bin: &str,
+    rows: &[Row<'_>],
+) -> PyResult<Py<PyTuple>> {
+    let n = rows.len();
+    // Mixed kinds fall back to a list column.
+    let kind = infer_column_kind(bin, rows).unwrap_or(ColumnKind::Other);
+    let (values, mask) = match kind {
+        ColumnKind::Float => {
+            let array = np.call_method1("empty", (n, "float64"))?;
+            let data = get_array_data_ptr(&array)? as *mut f64;
+            for (i, v) in column_values(bin, rows).enumerate() {
+                let f = match v {
+                    Some(Value::Float(f)) => float_value_to_f64(f),
+                    Some(Value::Int(x)) => *x as f64,
+                    _ => f64::NAN,
+                };
+                // SAFETY: `array` holds `n` aligned f64 slots and `i < n`
+                unsafe { ptr::write(data.add(i), f) };
+            }
+            (array, None)
+        }
+        ColumnKind::Int | ColumnKind::Bool => {
+            let dtype = if kind == ColumnKind::Int {
+                "int64"
+            } else {
+                "bool"
+            };
+            let array = np.call_method1("zeros", (n, dtype))?;
+            let data = get_array_data_ptr(&array)?;
+            let mut missing = Vec::new();
+            for (i, v) in column_values(bin, rows).enumerate() {
+                // SAFETY: `array` holds `n` aligned slots of `dtype` and `i < n`
+                match v {
+                    Some(Value::Int(x)) => unsafe { ptr::write((data as *mut i64).add(i), *x) },
+                    Some(Value::Bool(b)) => unsafe { ptr::write(data.add(i), u8::from(*b)) },
+                    _ => missing.push(i),
+                }
+            }
+            let mask = if missing.is_empty() {
+                None
+            } else {
+                let mask = np.call_method1("zeros", (n, "bool"))?;
+                let mask_ptr = get_array_data_ptr(&mask)?;
+                for i in missing {
+                    // SAFETY: `mask` holds `n` bool bytes and `i < n`
+                    unsafe { ptr::write(mask_ptr.add(i), 1u8) };
+                }
+                Some(mask)
+            };
+            (array, mask)
+        }
+        ColumnKind::Null
+        | ColumnKind::Utf8
+        | ColumnKind::Binary
+        | ColumnKind::List
+        | ColumnKind::Map
+        | ColumnKind::Other => {
+            let items = column_values(bin, rows)
+                .map(|v| match v {
+                    Some(v) => value_to_py(py, v),
+                    None => Ok(py.None()),
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            (PyList::new(py, items)?.into_any(), None)
+        }
+    };
+    Ok(PyTuple::new(py, [Some(values), mask])?.unbind())
+}
+
+fn build_columns<'py>(
+    py: Python<'py>,
+    bins: Option<&[String]>,
+    rows: &[Row<'_>],
+) -> PyResult<Bound<'py, PyDict>> {
+    let np = py.import("numpy")?;
+    let columns = PyDict::new(py);
+    for name in column_names(bins, rows) {
+        let column = build_column(py, &np, &name, rows)?;
+        columns.set_item(name, column)?;
+    }
+    Ok(columns)
+}
+
+/// Convert batch read results into `(index, columns)` for `batch_read_df`.
+///
+/// `index` holds the user key of every requested key (`None` when the key was
+/// digest-only); rows of failed or missing records are all-null.
+pub fn batch_to_columns_py(
+    py: Python<'_>,
+    results: &[BatchRecord],
+    bins: Option<&[String]>,
+) -> PyResult<Py<PyAny>> {
+    debug!(
+        "Converting batch to columns: records_count={}",
+        results.len()
+    );
+    let rows: Vec<Row<'_>> = results
+        .iter()
+        .map(|br| {
+            let ok = br.result_code.as_ref().map_or(0, result_code_to_int) == 0;
+            br.record.as_ref().filter(|_| ok).map(|r| &r.bins)
+        })
+        .collect();
+    let index = results
+        .iter()
+        .map(|br| match &br.key.user_key {
+            Some(v) => value_to_py(py, v),
+            None => Ok(py.None()),
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let columns = build_columns(py, bins, &rows)?;
+    let result = PyTuple::new(py, [PyList::new(py, index)?.into_any(), columns.into_any()])?;
+    Ok(result.into_any().unbind())
+}
+
+/// Convert query/scan records into `(None, columns)` for `Query.to_df`.
+pub fn records_to_columns_py(
+    py: Python<'_>,
+    records: &[Record],
+    bins: &[String],
+) -> PyResult<Py<PyAny>> {
+    debug!(
+        "Converting records to columns: records_count={}",
+        records.len()
+    );
+    let rows: Vec<Row<'_>> = records.iter().map(|r| Some(&r.bins)).collect();
+    let columns = build_columns(py, Some(bins), &rows)?;
+    let result = PyTuple::new(py, [py.None().into_bound(py), columns.into_any()])?;
+    Ok(result.into_any().unbind())
+}
@@ -12,18 +12,22 @@
 //!   → wrap with [`catch_panic_sync`].
 //! - async `AsyncClient` methods funnel through
 //!   `pyo3_async_runtimes::tokio::future_into_py(...)` → use
-//!   [`future_into_py_panic_safe`] as a drop-in replacement.
+//!   [`future_into_py_panic_safe`] as a drop-in replacement, or
+//!   [`future_into_py_with_timeout`] for operations taking `timeout=`.
 
 use std::any::Any;
 use std::future::Future;
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
 
 use futures::FutureExt;
+use log::debug;
 use pyo3::prelude::*;
 use pyo3_async_runtimes::tokio::future_into_py;
 
 use crate::bug_report::log_unexpected_error;
-use crate::errors::RustPanicError;
+use crate::client_common::parse_call_timeout;
+use crate::errors::{as_to_pyerr, RustPanicError};
 
 /// Best-effort extraction of a human-readable message from a panic payload.
 fn panic_msg(payload: &(dyn Any + Send)) -> String {
@@ -55,8 +59,28 @@ where
     }
 }
 
+/// Logs when an async operation is dropped before it completes.
+struct CancelGuard {
+    op: &'static str,
+    done: bool,
+}
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if !self.done {
+            debug!("{} cancelled; in-flight command aborted", self.op);
+        }
+    }
+}
+
 /// Drop-in replacement for `future_into_py` that catches panics from the
 /// inner future and surfaces them as `RustPanicError`.
+///
+/// Cancelling the returned asyncio future (`task.cancel()`,
+/// `asyncio.wait_for` timeout) drops `fut` at its next await point: a
+/// queued limiter slot is released, and a command already on the wire has
+/// its connection closed by `aerospike-core` instead of returned to the pool.
+/// A write that reached the server before the drop may still be applied.
 pub fn future_into_py_panic_safe<'py, F, R>(
     py: Python<'py>,
     op: &'static str,
@@ -67,13 +91,54 @@ where
     R: for<'a> IntoPyObject<'a> + Send + 'static,
 {
     future_into_py(py, async move {
-        match AssertUnwindSafe(fut).catch_unwind().await {
+        let mut guard = CancelGuard { op, done: false };
+        let result = match AssertUnwindSafe(fut).catch_unwind().await {
             Ok(result) => result,
             Err(payload) => Err(payload_to_pyerr(op, payload)),
-        }
+        };
+        guard.done = true;
+        result
     })
 }
 
+/// [`future_into_py_panic_safe`] bounded by a per-call `timeout` in seconds.
+///
+/// When the limit elapses `fut` is dropped, with the same effect as a
+/// cancellation, and the awaitable raises `AerospikeTimeoutError`. The limit
+/// covers the whole call, including time spent waiting for a limiter slot.
+pub fn future_into_py_with_timeout<'py, F, R>(
+    py: Python<'py>,
+    op: &'static str,
+    timeout: Option<f64>,
+    fut: F,
+) -> PyResult<Bound<'py, PyAny>>
+where
+    F: Future<Output = PyResult<R>> + Send + 'static,
+    R: for<'a> IntoPyObject<'a> + Send + 'static,
+{
+    let timeout = parse_call_timeout(timeout)?;
+    future_into_py_panic_safe(py, op, with_timeout(op, timeout, fut))
+}
+
+async fn with_timeout<F, R>(op: &'static str, timeout: Option<Duration>, fut: F) -> PyResult<R>
+where
+    F: Future<Output = PyResult<R>>,
+{
+    let Some(limit) = timeout else {
+        return fut.await;
+    };
+    match tokio::time::timeout(limit, fut).await {
+        Ok(result) => result,
+        Err(_) => {
+            debug!("{op} exceeded its {}ms call timeout", limit.as_millis());
+            Err(as_to_pyerr(aerospike_core::Error::Timeout(format!(
+                "{op} did not complete within {}ms",
+                limit.as_millis()
+            ))))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +196,37 @@ mod tests {
         });
     }
 
+    #[tokio::test]
+    async fn with_timeout_raises_timeout_error() {
+        ensure_python();
+        let slow = async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        };
+        let err = with_timeout("slow_op", Some(Duration::from_millis(10)), slow)
+            .await
+            .unwrap_err();
+        pyo3::Python::attach(|py| {
+            assert!(err.is_instance_of::<crate::errors::AerospikeTimeoutError>(py));
+            assert!(err.value(py).to_string().contains("slow_op"));
+        });
+        let fast = with_timeout("fast_op", Some(Duration::from_secs(5)), async { Ok(7) });
+        assert_eq!(fast.await.unwrap(), 7);
+    }
+
+    #[test]
+    fn parse_call_timeout_rejects_non_positive() {
+        ensure_python();
+        assert_eq!(parse_call_timeout(None).unwrap(), None);
+        assert_eq!(
+            parse_call_timeout(Some(0.25)).unwrap(),
+            Some(Duration::from_millis(250))
+        );
+        for bad in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(parse_call_timeout(Some(bad)).is_err(), "{bad}");
+        }
+    }
+
     #[test]
     fn catch_panic_sync_string_payload_preserved() {
         ensure_python();
@@ -0,0 +1,237 @@
+//! Client-side operation pipelining for the sync client.
+//!
+//! Provides [`PyPipeline`], a Python-visible class that queues single-record
+//! `put` / `get` / `operate` calls and runs them together on the client's
+//! runtime with bounded concurrency. Arguments are parsed when an operation is
+//! queued, so invalid keys or bins raise immediately; I/O happens only in
+//! `execute()`, which releases the GIL once for the whole pipeline and returns
+//! one result per queued operation, in queue order.
+
+use std::sync::Arc;
+
+use aerospike_core::{Client as AsClient, Key, Record};
+use futures::StreamExt;
+use log::debug;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::backpressure::OperationLimiter;
+use crate::bin_limits::BinLimits;
+use crate::cipher::BinCipher;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::client_common::{self, GetArgs, OperateArgs, PutArgs};
+use crate::client_ops;
+use crate::compression::BinCompression;
+use crate::panic_safety::catch_panic_sync;
+use crate::runtime::ClientRuntime;
+use crate::types::key::key_to_py;
+use crate::types::record::record_into_py_with_key;
+
+/// Default number of queued operations in flight at once.
+pub const DEFAULT_PIPELINE_CONCURRENCY: usize = 16;
+
+/// One queued operation with its pre-parsed arguments.
+enum PipelineOp {
+    Put(PutArgs),
+    Get(GetArgs),
+    Operate(OperateArgs),
+}
+
+impl PipelineOp {
+    fn name(&self) -> &'static str {
+        match self {
+            PipelineOp::Put(_) => "put",
+            PipelineOp::Get(_) => "get",
+            PipelineOp::Operate(_) => "operate",
+        }
+    }
+
+    fn key(&self) -> &Key {
+        match self {
+            PipelineOp::Put(args) => &args.key,
+            PipelineOp::Get(args) => &args.key,
+            PipelineOp::Operate(args) => &args.key,
+        }
+    }
+}
+
+/// Run one queued operation under the client's limiter and circuit breaker.
+async fn run_op(
+    client: &AsClient,
+    limiter: &OperationLimiter,
+    breaker: &CircuitBreaker,
+    compression: &BinCompression,
+    cipher: &BinCipher,
+    op: PipelineOp,
+) -> PyResult<Option<Record>> {
+    let _permit = limiter.acquire_named(op.name()).await?;
+    let circuit = breaker.check(client, op.key())?;
+    let mut record = circuit.record(match op {
+        PipelineOp::Put(mut args) => {
+            compression.compress_bins(&mut args.bins);
+            cipher.encrypt_bins(&mut args.bins)?;
+            client_ops::do_put(client, args).await.map(|_| None)
+        }
+        PipelineOp::Get(args) => client_ops::do_get(client, &args).await.map(Some),
+        PipelineOp::Operate(args) => client_ops::do_operate(client, &args).await.map(Some),
+    })?;
+    if let Some(record) = &mut record {
+        cipher.decrypt_record(record)?;
+        compression.decompress_record(record);
+    }
+    Ok(record)
+}
+
+/// Python-visible operation pipeline exposed as `Pipeline`.
+///
+/// Created by `Client.pipeline()`. Queue operations with `put()`, `get()` and
+/// `operate()`, then call `execute()`; the queue is emptied so the pipeline
+/// can be reused.
+#[pyclass(name = "Pipeline")]
+pub struct PyPipeline {
+    client: Arc<AsClient>,
+    limiter: Arc<OperationLimiter>,
+    breaker: Arc<CircuitBreaker>,
+    compression: Arc<BinCompression>,
+    bin_limits: Arc<BinLimits>,
+    cipher: BinCipher,
+    runtime: ClientRuntime,
+    connection_info: Arc<crate::tracing::ConnectionInfo>,
+    max_concurrency: usize,
+    event_loop_guard: bool,
+    /// Queued operations, each with the Python key of a record-returning op.
+    ops: Vec<(PipelineOp, Option<Py<PyAny>>)>,
+}
+
+impl PyPipeline {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        client: Arc<AsClient>,
+        limiter: Arc<OperationLimiter>,
+        breaker: Arc<CircuitBreaker>,
+        compression: Arc<BinCompression>,
+        bin_limits: Arc<BinLimits>,
+        cipher: BinCipher,
+        runtime: ClientRuntime,
+        connection_info: Arc<crate::tracing::ConnectionInfo>,
+        max_concurrency: usize,
+        event_loop_guard: bool,
+    ) -> Self {
+        Self {
+            client,
+            limiter,
+            breaker,
+            compression,
+            bin_limits,
+            cipher,
+            runtime,
+            connection_info,
+            max_concurrency,
+            event_loop_guard,
+            ops: vec![],
+        }
+    }
+}
+
+#[pymethods]
+impl PyPipeline {
+    /// Queue a write of `bins` to `key`.
+    #[pyo3(signature = (key, bins, meta=None, policy=None))]
+    fn put(
+        &mut self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        bins: &Bound<'_, PyAny>,
+        meta: Option<&Bound<'_, PyDict>>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let args =
+            client_common::prepare_put_args(py, key, bins, meta, policy, &self.connection_info)?;
+        self.bin_limits.check(&args.bins)?;
+        self.ops.push((PipelineOp::Put(args), None));
+        Ok(())
+    }
+
+    /// Queue a read of all bins of `key`.
+    #[pyo3(signature = (key, policy=None))]
+    fn get(
+        &mut self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let args = client_common::prepare_get_args(py, key, policy, &self.connection_info)?;
+        let key_py = key_to_py(py, &args.key)?;
+        self.ops.push((PipelineOp::Get(args), Some(key_py)));
+        Ok(())
+    }
+
+    /// Queue an `operate` call on `key`.
+    #[pyo3(signature = (key, ops, meta=None, policy=None))]
+    fn operate(
+        &mut self,
+        py: Python<'_>,
+        key: &Bound<'_, PyAny>,
+        ops: &Bound<'_, PyList>,
+        meta: Option<&Bound<'_, PyDict>>,
+        policy: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<()> {
+        let args =
+            client_common::prepare_operate_args(py, key, ops, meta, policy, &self.connection_info)?;
+        let key_py = key_to_py(py, &args.key)?;
+        self.ops.push((PipelineOp::Operate(args), Some(key_py)));
+        Ok(())
+    }
+
+    /// Run all queued operations and return their results in queue order.
+    ///
+    /// Each item is `None` for a put, a `(key, meta, bins)` tuple for a get or
+    /// operate, or the exception instance if that operation failed.
+    fn execute(&mut self, py: Python<'_>) -> PyResult<Py<PyList>> {
+        if self.event_loop_guard {
+            crate::runtime::forbid_running_loop(py)?;
+        }
+        let (ops, keys): (Vec<_>, Vec<_>) = std::mem::take(&mut self.ops).into_iter().unzip();
+        debug!(
+            "pipeline execute: ops_count={} max_concurrency={}",
+            ops.len(),
+            self.max_concurrency
+        );
+        let client = &self.client;
+        let limiter = &self.limiter;
+        let breaker = &self.breaker;
+        let compression = &self.compression;
+        let cipher = &self.cipher;
+        let results = catch_panic_sync("Pipeline.execute", || {
+            py.detach(|| {
+                self.runtime.block_on(async {
+                    Ok(futures::stream::iter(ops)
+                        .map(|op| run_op(client, limiter, breaker, compression, cipher, op))
+                        .buffered(self.max_concurrency)
+                        .collect::<Vec<_>>()
+                        .await)
+                })
+            })
+        })?;
+
+        let items = keys
+            .into_iter()
+            .zip(results)
+            .map(|(key_py, result)| match (key_py, result) {
+                (_, Err(e)) => Ok(e.into_value(py).into_any()),
+                (Some(key_py), Ok(Some(record))) => record_into_py_with_key(py, record, key_py),
+                _ => Ok(py.None()),
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new(py, items)?.unbind())
+    }
+
+    /// Drop all queued operations without running them.
+    fn clear(&mut self) {
+        self.ops.clear();
+    }
+
+    fn __len__(&self) -> usize {
+        self.ops.len()
+    }
+}
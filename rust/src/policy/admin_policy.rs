@@ -34,7 +34,7 @@ fn code_to_privilege_code(code: u8) -> PyResult<PrivilegeCode> {
         12 => Ok(PrivilegeCode::ReadWriteUDF),
         13 => Ok(PrivilegeCode::Write),
         14 => Ok(PrivilegeCode::Truncate),
-        _ => Err(crate::errors::InvalidArgError::new_err(format!(
+        _ => Err(crate::errors::ParamError::new_err(format!(
             "Unknown privilege code: {}",
             code
         ))),
@@ -59,7 +59,7 @@ fn name_to_privilege_code(name: &str) -> PyResult<PrivilegeCode> {
         "read-write-udf" => Ok(PrivilegeCode::ReadWriteUDF),
         "write" => Ok(PrivilegeCode::Write),
         "truncate" => Ok(PrivilegeCode::Truncate),
-        _ => Err(crate::errors::InvalidArgError::new_err(format!(
+        _ => Err(crate::errors::ParamError::new_err(format!(
             "Unknown privilege name: {:?}. Expected one of: read, read-write, \
              read-write-udf, write, truncate, user-admin, sys-admin, data-admin, \
              udf-admin, sindex-admin",
@@ -97,7 +97,7 @@ pub fn parse_privileges(privileges: &Bound<'_, PyList>) -> PyResult<Vec<Privileg
     for item in privileges.iter() {
         let dict = item.cast::<PyDict>()?;
         let code_obj = dict.get_item("code")?.ok_or_else(|| {
-            crate::errors::InvalidArgError::new_err("Privilege dict must have 'code' key")
+            crate::errors::ParamError::new_err("Privilege dict must have 'code' key")
         })?;
         let priv_code = if let Ok(code_int) = code_obj.extract::<u8>() {
             code_to_privilege_code(code_int)?
@@ -109,7 +109,7 @@ pub fn parse_privileges(privileges: &Bound<'_, PyList>) -> PyResult<Vec<Privileg
                 .name()
                 .map(|n| n.to_string())
                 .unwrap_or_else(|_| "unknown".to_string());
-            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            return Err(crate::errors::ParamError::new_err(format!(
                 "privilege 'code' must be int or str, got {type_name}"
             )));
         };
@@ -129,7 +129,7 @@ fn extract_optional_string(dict: &Bound<'_, PyDict>, field_name: &str) -> PyResu
                 .name()
                 .map(|n| n.to_string())
                 .unwrap_or_else(|_| "unknown".to_string());
-            pyo3::exceptions::PyTypeError::new_err(format!(
+            crate::errors::ParamError::new_err(format!(
                 "privilege '{field_name}' must be str or None, got {type_name}"
             ))
         }),
@@ -188,7 +188,7 @@ pub fn role_to_py(py: Python<'_>, role: &aerospike_core::Role) -> PyResult<Py<Py
 #[cfg(test)]
 mod tests {
     use super::*;
-    use pyo3::exceptions::PyTypeError;
+    use crate::errors::ParamError;
 
     #[test]
     fn parse_privileges_accepts_string_ns_and_set() {
@@ -219,7 +219,7 @@ mod tests {
             privileges.append(dict).unwrap();
 
             let err = parse_privileges(&privileges).expect_err("non-string ns must be rejected");
-            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert!(err.is_instance_of::<ParamError>(py));
         });
     }
 
@@ -252,7 +252,7 @@ mod tests {
             privileges.append(dict).unwrap();
 
             let err = parse_privileges(&privileges).expect_err("non-string set must be rejected");
-            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert!(err.is_instance_of::<ParamError>(py));
         });
     }
 
@@ -337,8 +337,8 @@ mod tests {
             privileges.append(dict).unwrap();
 
             let err = parse_privileges(&privileges)
-                .expect_err("float code must be rejected as TypeError");
-            assert!(err.is_instance_of::<PyTypeError>(py));
+                .expect_err("float code must be rejected as ParamError");
+            assert!(err.is_instance_of::<ParamError>(py));
         });
     }
 }
@@ -5,15 +5,16 @@ use aerospike_core::{
     GenerationPolicy,
 };
 use log::trace;
-use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict};
 
-use super::write_policy::parse_ttl;
+use super::write_policy::apply_ttl;
 use super::{
     extract_filter_expression, extract_policy_fields, parse_commit_level, parse_consistency_level,
     parse_generation_policy, parse_read_touch_ttl, parse_record_exists_action, parse_replica,
+    parse_send_key,
 };
+use crate::errors::ParamError;
 
 /// Parse a Python policy dict into a BatchPolicy
 pub fn parse_batch_policy(policy_dict: Option<&Bound<'_, PyDict>>) -> PyResult<BatchPolicy> {
@@ -54,17 +55,39 @@ pub fn parse_batch_policy(policy_dict: Option<&Bound<'_, PyDict>>) -> PyResult<B
     Ok(policy)
 }
 
+/// Read the client-side ``allow_partial_results`` flag (default ``True``).
+///
+/// With ``False``, a batch call raises the first per-record hard error
+/// instead of returning the records with their result codes.
+pub fn parse_allow_partial_results(policy_dict: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    let Some(val) = policy_dict
+        .map(|d| d.get_item("allow_partial_results"))
+        .transpose()?
+        .flatten()
+    else {
+        return Ok(true);
+    };
+    val.cast::<PyBool>().map(|b| b.is_true()).map_err(|_| {
+        ParamError::new_err(format!(
+            "allow_partial_results must be a bool, got {}",
+            val.get_type()
+                .name()
+                .map_or_else(|_| "?".into(), |n| n.to_string())
+        ))
+    })
+}
+
 /// Map a Python ``int`` to an [`aerospike_core::Concurrency`] variant.
 ///
 /// Mapping: ``0 -> Sequential``, ``1 -> Parallel``. Any other value
-/// (including negatives and ``n >= 2``) is rejected with [`PyValueError`].
+/// (including negatives and ``n >= 2``) is rejected with [`ParamError`].
 /// Note that aerospike-core 2.0's ``Concurrency`` enum only supports the
 /// two variants — there is no ``MaxThreads(n)`` variant in this version.
 fn parse_concurrency(value: i64) -> PyResult<Concurrency> {
     match value {
         0 => Ok(Concurrency::Sequential),
         1 => Ok(Concurrency::Parallel),
-        _ => Err(PyValueError::new_err(format!(
+        _ => Err(ParamError::new_err(format!(
             "Invalid concurrency value: {value}. Use BATCH_CONCURRENCY_SEQUENTIAL (0) or BATCH_CONCURRENCY_PARALLEL (1)"
         ))),
     }
@@ -92,7 +115,7 @@ pub fn parse_batch_write_policy(
 
     // Key (send_key) — POLICY_KEY_DIGEST(0) | POLICY_KEY_SEND(1)
     if let Some(val) = dict.get_item("key")? {
-        policy.send_key = val.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(val.extract::<i32>()?)?;
     }
 
     // Exists (record_exists_action) — POLICY_EXISTS_*
@@ -112,7 +135,7 @@ pub fn parse_batch_write_policy(
 
     // TTL / expiration
     if let Some(val) = dict.get_item("ttl")? {
-        policy.expiration = parse_ttl(val.extract::<i64>()?)?;
+        apply_ttl(&mut policy.expiration, val.extract::<i64>()?)?;
     }
 
     // Filter expression
@@ -168,7 +191,7 @@ pub fn parse_batch_delete_policy(
     });
 
     if let Some(val) = dict.get_item("key")? {
-        policy.send_key = val.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(val.extract::<i32>()?)?;
     }
     if let Some(val) = dict.get_item("gen")? {
         policy.generation_policy = parse_generation_policy(val.extract::<i32>()?);
@@ -200,7 +223,7 @@ pub fn apply_record_meta_for_delete(
         policy.generation_policy = GenerationPolicy::ExpectGenEqual;
     }
     if let Some(key) = meta.get_item("key")? {
-        policy.send_key = key.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(key.extract::<i32>()?)?;
     }
     if let Some(commit_level) = meta.get_item("commit_level")? {
         policy.commit_level = parse_commit_level(commit_level.extract::<i32>()?);
@@ -233,13 +256,13 @@ pub fn parse_batch_udf_policy(policy_dict: Option<&Bound<'_, PyDict>>) -> PyResu
     });
 
     if let Some(val) = dict.get_item("key")? {
-        policy.send_key = val.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(val.extract::<i32>()?)?;
     }
     if let Some(val) = dict.get_item("commit_level")? {
         policy.commit_level = parse_commit_level(val.extract::<i32>()?);
     }
     if let Some(val) = dict.get_item("ttl")? {
-        policy.expiration = parse_ttl(val.extract::<i64>()?)?;
+        apply_ttl(&mut policy.expiration, val.extract::<i64>()?)?;
     }
 
     policy.filter_expression = extract_filter_expression(dict)?;
@@ -262,10 +285,10 @@ pub fn apply_record_meta_for_apply(
     let mut policy = base.clone();
 
     if let Some(ttl) = meta.get_item("ttl")? {
-        policy.expiration = parse_ttl(ttl.extract::<i64>()?)?;
+        apply_ttl(&mut policy.expiration, ttl.extract::<i64>()?)?;
     }
     if let Some(key) = meta.get_item("key")? {
-        policy.send_key = key.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(key.extract::<i32>()?)?;
     }
     if let Some(commit_level) = meta.get_item("commit_level")? {
         policy.commit_level = parse_commit_level(commit_level.extract::<i32>()?);
@@ -291,14 +314,14 @@ pub fn apply_record_meta(
     let mut policy = base.clone();
 
     if let Some(ttl) = meta.get_item("ttl")? {
-        policy.expiration = parse_ttl(ttl.extract::<i64>()?)?;
+        apply_ttl(&mut policy.expiration, ttl.extract::<i64>()?)?;
     }
     if let Some(gen) = meta.get_item("gen")? {
         policy.generation = gen.extract::<u32>()?;
         policy.generation_policy = GenerationPolicy::ExpectGenEqual;
     }
     if let Some(key) = meta.get_item("key")? {
-        policy.send_key = key.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(key.extract::<i32>()?)?;
     }
     if let Some(exists) = meta.get_item("exists")? {
         policy.record_exists_action = parse_record_exists_action(exists.extract::<i32>()?);
@@ -388,6 +411,24 @@ mod tests {
         });
     }
 
+    #[test]
+    fn allow_partial_results_defaults_to_true_and_must_be_bool() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert!(parse_allow_partial_results(None).unwrap());
+            assert!(parse_allow_partial_results(Some(&PyDict::new(py))).unwrap());
+            let d = build_dict(py, |d| {
+                d.set_item("allow_partial_results", false).unwrap();
+            });
+            assert!(!parse_allow_partial_results(Some(&d)).unwrap());
+            let d = build_dict(py, |d| {
+                d.set_item("allow_partial_results", 0i32).unwrap();
+            });
+            let err = parse_allow_partial_results(Some(&d)).unwrap_err();
+            assert!(err.is_instance_of::<ParamError>(py));
+        });
+    }
+
     #[test]
     fn parse_batch_write_policy_default_when_dict_is_none() {
         let p = parse_batch_write_policy(None).expect("parse ok");
@@ -448,6 +489,28 @@ mod tests {
         });
     }
 
+    #[test]
+    fn apply_record_meta_ttl_client_default_keeps_batch_ttl() {
+        Python::initialize();
+        Python::attach(|py| {
+            let base = BatchWritePolicy {
+                expiration: Expiration::Seconds(60),
+                ..BatchWritePolicy::default()
+            };
+            let meta = build_dict(py, |d| {
+                d.set_item("ttl", -3i64).unwrap();
+            });
+            let kept = apply_record_meta(&base, &meta).expect("apply ok");
+            assert!(matches!(kept.expiration, Expiration::Seconds(60)));
+
+            let meta = build_dict(py, |d| {
+                d.set_item("ttl", -2i64).unwrap();
+            });
+            let overridden = apply_record_meta(&base, &meta).expect("apply ok");
+            assert!(matches!(overridden.expiration, Expiration::DontUpdate));
+        });
+    }
+
     #[test]
     fn parse_batch_policy_with_replica_master() {
         Python::initialize();
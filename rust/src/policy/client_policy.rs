@@ -1,11 +1,17 @@
 //! Client-level policy parsing, including authentication and cluster settings.
 
+use std::time::Duration;
+
 use aerospike_core::{AuthMode, ClientPolicy};
 use log::trace;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyDict, PyList};
 
 use super::extract_policy_fields;
+use crate::circuit_breaker::CircuitBreakerConfig;
+use crate::compression::BinCompressionConfig;
+use crate::errors::ParamError;
+use crate::runtime::{ClientRuntime, MAX_WORKERS};
 
 /// Parse a Python config dict into a ClientPolicy
 pub fn parse_client_policy(config: &Bound<'_, PyDict>) -> PyResult<ClientPolicy> {
@@ -57,16 +63,595 @@ pub fn parse_client_policy(config: &Bound<'_, PyDict>) -> PyResult<ClientPolicy>
 
 /// Parse backpressure configuration from a Python config dict.
 ///
-/// Returns `(max_concurrent_operations, operation_queue_timeout_ms)`.
-/// Both default to 0 (disabled / no timeout).
-pub fn parse_backpressure_config(config: &Bound<'_, PyDict>) -> PyResult<(usize, u64)> {
-    let max_ops: usize = config
+/// Returns `(max_concurrent_operations, operation_queue_timeout_ms, max_ops_per_second)`.
+/// All default to 0 (disabled / no timeout). `max_concurrent_requests` is
+/// accepted as an alias of `max_concurrent_operations`.
+pub fn parse_backpressure_config(config: &Bound<'_, PyDict>) -> PyResult<(usize, u64, f64)> {
+    let max_ops: Option<usize> = config
         .get_item("max_concurrent_operations")?
         .map(|v| v.extract())
-        .unwrap_or(Ok(0))?;
+        .transpose()?;
+    let max_requests: Option<usize> = config
+        .get_item("max_concurrent_requests")?
+        .map(|v| v.extract())
+        .transpose()?;
+    let max_ops = match (max_ops, max_requests) {
+        (Some(ops), Some(requests)) if ops != requests => {
+            return Err(ParamError::new_err(format!(
+                "max_concurrent_requests ({requests}) conflicts with \
+                 max_concurrent_operations ({ops}); set only one"
+            )));
+        }
+        (ops, requests) => ops.or(requests).unwrap_or(0),
+    };
     let timeout_ms: u64 = config
         .get_item("operation_queue_timeout_ms")?
         .map(|v| v.extract())
         .unwrap_or(Ok(0))?;
-    Ok((max_ops, timeout_ms))
+    let ops_per_second: f64 = config
+        .get_item("max_ops_per_second")?
+        .map(|v| v.extract())
+        .unwrap_or(Ok(0.0))?;
+    if !ops_per_second.is_finite() || ops_per_second < 0.0 {
+        return Err(ParamError::new_err(format!(
+            "max_ops_per_second must be a non-negative number, got {ops_per_second}"
+        )));
+    }
+    if ops_per_second > 0.0 && Duration::try_from_secs_f64(1.0 / ops_per_second).is_err() {
+        return Err(ParamError::new_err(format!(
+            "max_ops_per_second {ops_per_second:e} is too small; use 0 to disable rate limiting"
+        )));
+    }
+    Ok((max_ops, timeout_ms, ops_per_second))
+}
+
+/// Parse the `circuit_breaker` config dict.
+///
+/// Returns `None` when the key is absent or `None` (breaker disabled).
+/// Missing fields default to 5 failures within 10 s, reset after 5 s.
+pub fn parse_circuit_breaker_config(
+    config: &Bound<'_, PyDict>,
+) -> PyResult<Option<CircuitBreakerConfig>> {
+    let Some(cb) = config.get_item("circuit_breaker")? else {
+        return Ok(None);
+    };
+    if cb.is_none() {
+        return Ok(None);
+    }
+    let cb = cb
+        .cast::<PyDict>()
+        .map_err(|_| ParamError::new_err("circuit_breaker must be a dict"))?;
+    for key in cb.keys() {
+        let key: String = key.extract()?;
+        if !matches!(
+            key.as_str(),
+            "failure_threshold" | "window_ms" | "reset_timeout_ms"
+        ) {
+            return Err(ParamError::new_err(format!(
+                "Unknown circuit_breaker option '{key}' \
+                 (expected 'failure_threshold', 'window_ms' or 'reset_timeout_ms')"
+            )));
+        }
+    }
+    let mut failure_threshold: u32 = 5;
+    let mut window_ms: u64 = 10_000;
+    let mut reset_timeout_ms: u64 = 5_000;
+    extract_policy_fields!(cb, {
+        "failure_threshold" => failure_threshold;
+        "window_ms" => window_ms;
+        "reset_timeout_ms" => reset_timeout_ms
+    });
+    if failure_threshold == 0 || window_ms == 0 || reset_timeout_ms == 0 {
+        return Err(ParamError::new_err(
+            "circuit_breaker failure_threshold, window_ms and reset_timeout_ms must be positive",
+        ));
+    }
+    Ok(Some(CircuitBreakerConfig {
+        failure_threshold,
+        window: Duration::from_millis(window_ms),
+        reset_timeout: Duration::from_millis(reset_timeout_ms),
+    }))
+}
+
+/// Parse the `bin_compression` config dict.
+///
+/// Returns `None` when the key is absent or `None` (no compression).
+/// Missing fields default to codec `"zstd"`, `min_size` 4096 and level 3.
+pub fn parse_bin_compression_config(
+    config: &Bound<'_, PyDict>,
+) -> PyResult<Option<BinCompressionConfig>> {
+    let Some(bc) = config.get_item("bin_compression")? else {
+        return Ok(None);
+    };
+    if bc.is_none() {
+        return Ok(None);
+    }
+    let bc = bc
+        .cast::<PyDict>()
+        .map_err(|_| ParamError::new_err("bin_compression must be a dict"))?;
+    for key in bc.keys() {
+        let key: String = key.extract()?;
+        if !matches!(key.as_str(), "codec" | "min_size" | "level") {
+            return Err(ParamError::new_err(format!(
+                "Unknown bin_compression option '{key}' (expected 'codec', 'min_size' or 'level')"
+            )));
+        }
+    }
+    let mut codec = String::from("zstd");
+    let mut min_size: usize = 4096;
+    let mut level: i32 = 3;
+    extract_policy_fields!(bc, {
+        "codec" => codec;
+        "min_size" => min_size;
+        "level" => level
+    });
+    if codec != "zstd" {
+        return Err(ParamError::new_err(format!(
+            "bin_compression codec must be 'zstd', got '{codec}'"
+        )));
+    }
+    if !zstd::compression_level_range().contains(&level) {
+        let range = zstd::compression_level_range();
+        return Err(ParamError::new_err(format!(
+            "bin_compression level must be between {} and {}, got {level}",
+            range.start(),
+            range.end()
+        )));
+    }
+    Ok(Some(BinCompressionConfig { min_size, level }))
+}
+
+/// Parse `refresh_seconds`: how often pooled connections are re-authenticated.
+///
+/// Returns `None` when the key is absent, `None` or `0` (no periodic refresh).
+pub fn parse_refresh_config(config: &Bound<'_, PyDict>) -> PyResult<Option<Duration>> {
+    let secs: f64 = match config.get_item("refresh_seconds")? {
+        Some(v) if !v.is_none() => v.extract()?,
+        _ => return Ok(None),
+    };
+    if !secs.is_finite() || secs < 0.0 {
+        return Err(ParamError::new_err(format!(
+            "refresh_seconds must be a non-negative number, got {secs}"
+        )));
+    }
+    if secs == 0.0 {
+        return Ok(None);
+    }
+    Duration::try_from_secs_f64(secs)
+        .map(Some)
+        .map_err(|_| ParamError::new_err(format!("refresh_seconds {secs:e} is too large")))
+}
+
+/// Parse `allow_blocking_in_event_loop`: `true` lets a sync client run
+/// commands from a thread with a running asyncio event loop.
+pub fn parse_event_loop_guard(config: &Bound<'_, PyDict>) -> PyResult<bool> {
+    let allow = match config.get_item("allow_blocking_in_event_loop")? {
+        Some(v) if !v.is_none() => v
+            .extract::<bool>()
+            .map_err(|_| ParamError::new_err("allow_blocking_in_event_loop must be a bool"))?,
+        _ => false,
+    };
+    Ok(!allow)
+}
+
+/// Parse the `rust_runtime` config dict.
+///
+/// Returns `Some(worker_threads)` when `{"dedicated": True}` asks for a
+/// per-client Tokio runtime, `None` to use the shared runtime.
+pub fn parse_runtime_config(config: &Bound<'_, PyDict>) -> PyResult<Option<usize>> {
+    let Some(rt) = config.get_item("rust_runtime")? else {
+        return Ok(None);
+    };
+    if rt.is_none() {
+        return Ok(None);
+    }
+    let rt = rt
+        .cast::<PyDict>()
+        .map_err(|_| ParamError::new_err("rust_runtime must be a dict"))?;
+    for key in rt.keys() {
+        let key: String = key.extract()?;
+        if key != "dedicated" && key != "worker_threads" {
+            return Err(ParamError::new_err(format!(
+                "Unknown rust_runtime option '{key}' (expected 'dedicated' or 'worker_threads')"
+            )));
+        }
+    }
+    let dedicated: bool = rt
+        .get_item("dedicated")?
+        .map(|v| v.extract())
+        .unwrap_or(Ok(false))?;
+    let workers: Option<usize> = match rt.get_item("worker_threads")? {
+        Some(v) if !v.is_none() => Some(v.extract()?),
+        _ => None,
+    };
+    if !dedicated {
+        if workers.is_some() {
+            return Err(ParamError::new_err(
+                "rust_runtime.worker_threads requires dedicated=True; \
+                 the shared runtime is sized by AEROSPIKE_RUNTIME_WORKERS",
+            ));
+        }
+        return Ok(None);
+    }
+    let workers = workers.unwrap_or_else(ClientRuntime::default_workers);
+    if !(1..=MAX_WORKERS).contains(&workers) {
+        return Err(ParamError::new_err(format!(
+            "rust_runtime.worker_threads must be between 1 and {MAX_WORKERS}, got {workers}"
+        )));
+    }
+    Ok(Some(workers))
+}
+
+/// Expected type of a client config value.
+#[derive(Clone, Copy)]
+enum Kind {
+    /// Any int.
+    Int,
+    /// Int from 0 to the given maximum.
+    UInt(u64),
+    /// Finite, non-negative int or float.
+    Number,
+    Bool,
+    Str,
+    /// One of the listed strings.
+    Choice(&'static [&'static str]),
+    /// `(host, port)` tuples or `"host:port"` strings.
+    Hosts,
+    /// Dict with the listed keys.
+    Dict(&'static [(&'static str, Kind)]),
+    Callable,
+    /// `None` or the inner kind.
+    Opt(&'static Kind),
+}
+
+const ANY_UINT: u64 = u64::MAX;
+const U32: u64 = u32::MAX as u64;
+
+/// Every key `connect()` and the Python wrappers read from the config dict.
+const CLIENT_CONFIG_KEYS: &[(&str, Kind)] = &[
+    ("hosts", Kind::Hosts),
+    ("hosts_srv", Kind::Opt(&Kind::Str)),
+    (
+        "hosts_srv_refresh_seconds",
+        Kind::Opt(&Kind::UInt(ANY_UINT)),
+    ),
+    (
+        "address_family",
+        Kind::Opt(&Kind::Choice(&[
+            "any",
+            "ipv4",
+            "ipv6",
+            "prefer_ipv4",
+            "prefer_ipv6",
+        ])),
+    ),
+    ("cluster_name", Kind::Opt(&Kind::Str)),
+    ("auth_mode", Kind::UInt(2)),
+    ("user", Kind::Opt(&Kind::Str)),
+    ("password", Kind::Str),
+    ("timeout", Kind::UInt(U32)),
+    ("idle_timeout", Kind::UInt(U32)),
+    ("max_conns_per_node", Kind::UInt(ANY_UINT)),
+    ("min_conns_per_node", Kind::UInt(ANY_UINT)),
+    ("conn_pools_per_node", Kind::UInt(u8::MAX as u64)),
+    ("tend_interval", Kind::UInt(U32)),
+    ("use_services_alternate", Kind::Bool),
+    ("max_concurrent_operations", Kind::UInt(ANY_UINT)),
+    ("max_concurrent_requests", Kind::UInt(ANY_UINT)),
+    ("max_ops_per_second", Kind::Number),
+    ("operation_queue_timeout_ms", Kind::UInt(ANY_UINT)),
+    ("refresh_seconds", Kind::Opt(&Kind::Number)),
+    (
+        "rust_runtime",
+        Kind::Opt(&Kind::Dict(&[
+            ("dedicated", Kind::Bool),
+            ("worker_threads", Kind::Opt(&Kind::UInt(ANY_UINT))),
+        ])),
+    ),
+    (
+        "circuit_breaker",
+        Kind::Opt(&Kind::Dict(&[
+            ("failure_threshold", Kind::UInt(U32)),
+            ("window_ms", Kind::UInt(ANY_UINT)),
+            ("reset_timeout_ms", Kind::UInt(ANY_UINT)),
+        ])),
+    ),
+    (
+        "bin_compression",
+        Kind::Opt(&Kind::Dict(&[
+            ("codec", Kind::Choice(&["zstd"])),
+            ("min_size", Kind::UInt(ANY_UINT)),
+            ("level", Kind::Int),
+        ])),
+    ),
+    ("allow_blocking_in_event_loop", Kind::Opt(&Kind::Bool)),
+    // Read by the Python wrappers.
+    ("auto_reconnect", Kind::Bool),
+    ("slow_log_ms", Kind::Opt(&Kind::Number)),
+    ("slow_log_callback", Kind::Opt(&Kind::Callable)),
+    ("strict_params", Kind::Bool),
+    ("compat", Kind::Choice(&["asyncio", "anyio"])),
+];
+
+/// Check a whole client config dict before `connect()` parses it.
+///
+/// Unknown keys (with a "did you mean" hint), values of the wrong type and
+/// conflicting options are collected into one `ParamError` instead of
+/// surfacing one at a time from the individual parsers.
+pub fn validate_client_config(config: &Bound<'_, PyDict>, for_async: bool) -> PyResult<()> {
+    let mut problems = Vec::new();
+    check_dict(config, "", CLIENT_CONFIG_KEYS, &mut problems)?;
+
+    let get = |key: &str| -> PyResult<Option<Bound<'_, PyAny>>> {
+        Ok(config.get_item(key)?.filter(|v| !v.is_none()))
+    };
+    let hosts = config.get_item("hosts")?;
+    let srv = get("hosts_srv")?;
+    match (&hosts, &srv) {
+        (None, None) => problems.push("'hosts' or 'hosts_srv' is required".to_string()),
+        (Some(hosts), None) if hosts.len().is_ok_and(|n| n == 0) => {
+            problems.push("hosts must not be empty".to_string())
+        }
+        _ => {}
+    }
+    if srv.is_none() && get("hosts_srv_refresh_seconds")?.is_some() {
+        problems.push("hosts_srv_refresh_seconds requires hosts_srv".to_string());
+    }
+    if get("user")?.is_none() && get("password")?.is_some() {
+        problems.push("password requires user".to_string());
+    }
+    if let (Some(ops), Some(requests)) = (
+        get("max_concurrent_operations")?,
+        get("max_concurrent_requests")?,
+    ) {
+        if !ops.eq(&requests)? {
+            problems.push(format!(
+                "max_concurrent_requests ({requests}) conflicts with \
+                 max_concurrent_operations ({ops}); set only one"
+            ));
+        }
+    }
+    if get("slow_log_ms")?.is_none() && get("slow_log_callback")?.is_some() {
+        problems.push("slow_log_callback requires slow_log_ms".to_string());
+    }
+    if let Some(rt) = get("rust_runtime")? {
+        if let Ok(rt) = rt.cast::<PyDict>() {
+            let dedicated = rt
+                .get_item("dedicated")?
+                .is_some_and(|d| d.is_truthy().unwrap_or(false));
+            if for_async && dedicated {
+                problems.push(
+                    "rust_runtime.dedicated is only supported by the sync Client; \
+                     AsyncClient runs on the shared pyo3-async-runtimes runtime"
+                        .to_string(),
+                );
+            } else if !dedicated && rt.get_item("worker_threads")?.is_some_and(|w| !w.is_none()) {
+                problems.push(
+                    "rust_runtime.worker_threads requires dedicated=True; \
+                     the shared runtime is sized by AEROSPIKE_RUNTIME_WORKERS"
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    match problems.len() {
+        0 => Ok(()),
+        1 => Err(ParamError::new_err(format!(
+            "Invalid client config: {}",
+            problems[0]
+        ))),
+        n => Err(ParamError::new_err(format!(
+            "Invalid client config ({n} problems):\n- {}",
+            problems.join("\n- ")
+        ))),
+    }
+}
+
+fn check_dict(
+    dict: &Bound<'_, PyDict>,
+    prefix: &str,
+    known: &[(&str, Kind)],
+    problems: &mut Vec<String>,
+) -> PyResult<()> {
+    for (key, value) in dict.iter() {
+        let Ok(key) = key.extract::<String>() else {
+            problems.push(format!("{prefix}keys must be str, got {}", type_name(&key)));
+            continue;
+        };
+        match known.iter().find(|(k, _)| *k == key) {
+            Some((_, kind)) => check_value(&value, &format!("{prefix}{key}"), *kind, problems)?,
+            None => {
+                let hint = closest_key(&key, known)
+                    .map(|k| format!(" (did you mean '{prefix}{k}'?)"))
+                    .unwrap_or_default();
+                problems.push(format!("unknown key '{prefix}{key}'{hint}"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_value(
+    value: &Bound<'_, PyAny>,
+    name: &str,
+    kind: Kind,
+    problems: &mut Vec<String>,
+) -> PyResult<()> {
+    let is_bool = value.is_instance_of::<PyBool>();
+    let expected = match kind {
+        Kind::Opt(inner) => {
+            return match value.is_none() {
+                true => Ok(()),
+                false => check_value(value, name, *inner, problems),
+            };
+        }
+        Kind::Int => {
+            if !is_bool && value.extract::<i32>().is_ok() {
+                return Ok(());
+            }
+            "an int".to_string()
+        }
+        Kind::UInt(max) => {
+            if !is_bool && value.extract::<u64>().is_ok_and(|n| n <= max) {
+                return Ok(());
+            }
+            match max {
+                ANY_UINT => "a non-negative int".to_string(),
+                _ => format!("an int from 0 to {max}"),
+            }
+        }
+        Kind::Number => {
+            let ok = !is_bool
+                && value
+                    .extract::<f64>()
+                    .is_ok_and(|n| n.is_finite() && n >= 0.0);
+            if ok {
+                return Ok(());
+            }
+            "a non-negative number".to_string()
+        }
+        Kind::Bool => {
+            if is_bool {
+                return Ok(());
+            }
+            "a bool".to_string()
+        }
+        Kind::Str => {
+            if value.extract::<String>().is_ok() {
+                return Ok(());
+            }
+            "a str".to_string()
+        }
+        Kind::Choice(choices) => {
+            if value
+                .extract::<String>()
+                .is_ok_and(|s| choices.contains(&s.as_str()))
+            {
+                return Ok(());
+            }
+            let quoted: Vec<String> = choices.iter().map(|c| format!("'{c}'")).collect();
+            format!("one of {}", quoted.join(", "))
+        }
+        Kind::Hosts => {
+            let ok = value.cast::<PyList>().is_ok_and(|hosts| {
+                hosts.iter().all(|h| {
+                    h.extract::<String>().is_ok()
+                        || h.extract::<(String, u16)>().is_ok()
+                        || h.extract::<(String,)>().is_ok()
+                })
+            });
+            if ok {
+                return Ok(());
+            }
+            "a list of (host, port) tuples or 'host:port' strings".to_string()
+        }
+        Kind::Dict(keys) => match value.cast::<PyDict>() {
+            Ok(dict) => return check_dict(dict, &format!("{name}."), keys, problems),
+            Err(_) => "a dict".to_string(),
+        },
+        Kind::Callable => {
+            if value.is_callable() {
+                return Ok(());
+            }
+            "callable".to_string()
+        }
+    };
+    let got = match kind {
+        Kind::Int | Kind::UInt(_) | Kind::Number | Kind::Choice(_) if !is_bool => {
+            value.repr()?.to_string()
+        }
+        _ => type_name(value),
+    };
+    problems.push(format!("{name} must be {expected}, got {got}"));
+    Ok(())
+}
+
+fn type_name(value: &Bound<'_, PyAny>) -> String {
+    value
+        .get_type()
+        .name()
+        .map(|n| n.to_string())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// The known key closest to a misspelt one, if any is within two edits.
+fn closest_key<'k>(key: &str, known: &[(&'k str, Kind)]) -> Option<&'k str> {
+    known
+        .iter()
+        .map(|(k, _)| (edit_distance(key, k), *k))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, k)| k)
+}
+
+/// Levenshtein distance.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let sub = diag + usize::from(ca != *cb);
+            diag = row[j + 1];
+            row[j + 1] = sub.min(row[j] + 1).min(diag + 1);
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_edits() {
+        assert_eq!(edit_distance("hosts", "hosts"), 0);
+        assert_eq!(edit_distance("hots", "hosts"), 1);
+        assert_eq!(edit_distance("timeuot", "timeout"), 2);
+        assert_eq!(
+            closest_key("max_conn_per_node", CLIENT_CONFIG_KEYS),
+            Some("max_conns_per_node")
+        );
+        assert_eq!(closest_key("policies", CLIENT_CONFIG_KEYS), None);
+    }
+
+    #[test]
+    fn collects_every_problem() {
+        Python::initialize();
+        Python::attach(|py| {
+            let config = PyDict::new(py);
+            config.set_item("hots", PyList::empty(py)).unwrap();
+            config.set_item("timeout", "1s").unwrap();
+            config.set_item("use_services_alternate", 1).unwrap();
+            let cb = PyDict::new(py);
+            cb.set_item("windows_ms", 100).unwrap();
+            config.set_item("circuit_breaker", cb).unwrap();
+            config.set_item("max_concurrent_operations", 8).unwrap();
+            config.set_item("max_concurrent_requests", 16).unwrap();
+            let err = validate_client_config(&config, false).unwrap_err();
+            assert!(err.is_instance_of::<ParamError>(py));
+            let msg = err.to_string();
+            for part in [
+                "6 problems",
+                "unknown key 'hots' (did you mean 'hosts'?)",
+                "timeout must be an int from 0 to 4294967295, got '1s'",
+                "use_services_alternate must be a bool, got int",
+                "unknown key 'circuit_breaker.windows_ms' (did you mean 'circuit_breaker.window_ms'?)",
+                "'hosts' or 'hosts_srv' is required",
+                "max_concurrent_requests (16) conflicts",
+            ] {
+                assert!(msg.contains(part), "{part} not in {msg}");
+            }
+
+            let valid = PyDict::new(py);
+            let hosts = PyList::empty(py);
+            hosts.append(("127.0.0.1", 3000)).unwrap();
+            valid.set_item("hosts", hosts).unwrap();
+            valid.set_item("cluster_name", py.None()).unwrap();
+            valid.set_item("max_ops_per_second", 2.5).unwrap();
+            assert!(validate_client_config(&valid, false).is_ok());
+        });
+    }
 }
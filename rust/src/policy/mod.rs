@@ -1,8 +1,10 @@
 pub mod admin_policy;
 pub mod batch_policy;
 pub mod client_policy;
+pub mod operate_policy;
 pub mod query_policy;
 pub mod read_policy;
+pub mod retry_policy;
 pub mod write_policy;
 
 use aerospike_core::expressions::Expression;
@@ -128,7 +130,7 @@ pub fn parse_partition_filter(dict: &Bound<'_, PyDict>) -> PyResult<Option<Parti
         return Ok(None);
     };
     let pf: PyPartitionFilter = val.extract().map_err(|_| {
-        pyo3::exceptions::PyTypeError::new_err(
+        crate::errors::ParamError::new_err(
             "policy['partition_filter'] must be a PartitionFilter instance \
              returned by aerospike_py.partition_filter_all/_by_id/_by_range",
         )
@@ -136,17 +138,32 @@ pub fn parse_partition_filter(dict: &Bound<'_, PyDict>) -> PyResult<Option<Parti
     Ok(Some(pf.clone_inner()))
 }
 
+/// Map a `POLICY_KEY_*` integer constant to the `send_key` flag.
+///
+/// `POLICY_KEY_DIGEST` (0) stores only the digest; `POLICY_KEY_SEND` (1) also
+/// sends and stores the user key. Any other value returns `ParamError` so a
+/// typo does not silently fall back to digest-only writes.
+pub(crate) fn parse_send_key(val: i32) -> PyResult<bool> {
+    match val {
+        0 => Ok(false),
+        1 => Ok(true),
+        n => Err(crate::errors::ParamError::new_err(format!(
+            "key policy out of range: {n} (valid: 0=POLICY_KEY_DIGEST, 1=POLICY_KEY_SEND)"
+        ))),
+    }
+}
+
 /// Convert a `read_touch_ttl_percent` integer to a [`ReadTouchTTL`] enum.
 ///
 /// Special values: `0` = `ServerDefault`, `-1` = `DontReset`, `1..=100` = `Percent(N)`.
-/// Out-of-range values return an `InvalidArgError` rather than silently clamping —
+/// Out-of-range values return an `ParamError` rather than silently clamping —
 /// this surfaces config typos early and matches the strictness of `parse_ttl`.
 pub(crate) fn parse_read_touch_ttl(val: i64) -> PyResult<ReadTouchTTL> {
     match val {
         0 => Ok(ReadTouchTTL::ServerDefault),
         -1 => Ok(ReadTouchTTL::DontReset),
         n if (1..=100).contains(&n) => Ok(ReadTouchTTL::Percent(n as u8)),
-        n => Err(crate::errors::InvalidArgError::new_err(format!(
+        n => Err(crate::errors::ParamError::new_err(format!(
             "read_touch_ttl_percent out of range: {n} (valid: 0=ServerDefault, -1=DontReset, 1-100=Percent)"
         ))),
     }
@@ -208,11 +225,22 @@ mod tests {
         Python::initialize();
         Python::attach(|py| {
             let err = parse_read_touch_ttl(-100).expect_err("must reject -100");
-            assert!(err.is_instance_of::<crate::errors::InvalidArgError>(py));
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
             let err = parse_read_touch_ttl(200).expect_err("must reject 200");
-            assert!(err.is_instance_of::<crate::errors::InvalidArgError>(py));
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
             let err = parse_read_touch_ttl(101).expect_err("boundary 101 must reject");
-            assert!(err.is_instance_of::<crate::errors::InvalidArgError>(py));
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
+        });
+    }
+
+    #[test]
+    fn parse_send_key_accepts_only_key_constants() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert!(!parse_send_key(0).unwrap());
+            assert!(parse_send_key(1).unwrap());
+            let err = parse_send_key(2).expect_err("must reject 2");
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
         });
     }
 }
@@ -0,0 +1,103 @@
+//! Operate policy parsing from Python dicts.
+//!
+//! `operate()` mixes reads and writes in one command, so its policy dict takes
+//! the write fields of [`parse_write_policy`] plus read-side fields. The
+//! underlying `aerospike_core` operate command always routes to the master and
+//! always reads in SC session mode, so `replica` and `read_mode_sc` accept only
+//! those values; anything else raises instead of being silently ignored.
+
+use aerospike_core::WritePolicy;
+use log::trace;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use super::extract_policy_fields;
+use super::write_policy::parse_write_policy;
+use crate::errors::ParamError;
+
+/// `POLICY_REPLICA_MASTER`: the only replica `operate()` can be routed to.
+const REPLICA_MASTER: i32 = 0;
+/// `POLICY_READ_MODE_SC_SESSION`: the only SC read mode `operate()` supports.
+const READ_MODE_SC_SESSION: i32 = 0;
+
+/// Parse a Python policy dict into the `WritePolicy` used by `operate()`.
+pub fn parse_operate_policy(
+    policy_dict: Option<&Bound<'_, PyDict>>,
+    meta: Option<&Bound<'_, PyDict>>,
+) -> PyResult<WritePolicy> {
+    trace!("Parsing operate policy");
+    let mut policy = parse_write_policy(policy_dict, meta)?;
+
+    let dict = match policy_dict {
+        Some(d) => d,
+        None => return Ok(policy),
+    };
+
+    extract_policy_fields!(dict, {
+        "sleep_between_retries" => policy.base_policy.sleep_between_retries;
+        "respond_all_ops" => policy.respond_per_each_op
+    });
+
+    if let Some(val) = dict.get_item("replica")? {
+        let replica = val.extract::<i32>()?;
+        if replica != REPLICA_MASTER {
+            return Err(ParamError::new_err(format!(
+                "operate() policy replica={replica} is not supported; \
+                 operate is always routed to the master (POLICY_REPLICA_MASTER)"
+            )));
+        }
+    }
+    if let Some(val) = dict.get_item("read_mode_sc")? {
+        let mode = val.extract::<i32>()?;
+        if mode != READ_MODE_SC_SESSION {
+            return Err(ParamError::new_err(format!(
+                "operate() policy read_mode_sc={mode} is not supported; \
+                 only POLICY_READ_MODE_SC_SESSION is available"
+            )));
+        }
+    }
+
+    Ok(policy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aerospike_core::{ConsistencyLevel, Expiration};
+
+    #[test]
+    fn read_and_write_fields_combine() {
+        Python::initialize();
+        Python::attach(|py| {
+            let d = PyDict::new(py);
+            d.set_item("ttl", 60i64).unwrap();
+            d.set_item("read_mode_ap", 1i32).unwrap();
+            d.set_item("replica", 0i32).unwrap();
+            d.set_item("read_mode_sc", 0i32).unwrap();
+            d.set_item("respond_all_ops", true).unwrap();
+            d.set_item("sleep_between_retries", 5u32).unwrap();
+            let p = parse_operate_policy(Some(&d), None).unwrap();
+            assert!(matches!(p.expiration, Expiration::Seconds(60)));
+            assert_eq!(
+                p.base_policy.consistency_level,
+                ConsistencyLevel::ConsistencyAll
+            );
+            assert!(p.respond_per_each_op);
+            assert_eq!(p.base_policy.sleep_between_retries, 5);
+        });
+    }
+
+    #[test]
+    fn unsupported_replica_and_sc_mode_rejected() {
+        Python::initialize();
+        Python::attach(|py| {
+            for (field, val) in [("replica", 1i32), ("read_mode_sc", 1i32)] {
+                let d = PyDict::new(py);
+                d.set_item(field, val).unwrap();
+                let err = parse_operate_policy(Some(&d), None).expect_err(field);
+                assert!(err.is_instance_of::<ParamError>(py));
+                assert!(err.to_string().contains(field));
+            }
+        });
+    }
+}
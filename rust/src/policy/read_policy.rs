@@ -113,7 +113,7 @@ mod tests {
                 d.set_item("read_touch_ttl_percent", 200i64).unwrap();
             });
             let err = parse_read_policy(Some(&d)).expect_err("must error");
-            assert!(err.is_instance_of::<crate::errors::InvalidArgError>(py));
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
         });
     }
 
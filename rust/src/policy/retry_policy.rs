@@ -0,0 +1,287 @@
+//! Client-side retry with exponential backoff for idempotent reads.
+//!
+//! Opt-in: a read, batch or query policy dict that sets `backoff_base_ms`,
+//! `backoff_cap_ms` or `retry_on` gets a [`RetryPolicy`]. Its `max_retries`
+//! then counts client-side retries, and the core client's own retries are
+//! disabled so the two do not compound. Retries sleep on the Tokio runtime,
+//! so the GIL is never held while backing off.
+
+use std::future::Future;
+use std::time::Duration;
+
+use aerospike_core::policy::BasePolicy;
+use aerospike_core::Error as AsError;
+use log::{debug, trace};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::{error_code, ParamError, RETRYABLE_CODES};
+
+/// Backoff of the first retry when only `retry_on` or `backoff_cap_ms` is set.
+const DEFAULT_BACKOFF_BASE_MS: u64 = 10;
+/// Upper bound of a single backoff when `backoff_cap_ms` is not set.
+const DEFAULT_BACKOFF_CAP_MS: u64 = 1000;
+
+/// Retry settings parsed from a policy dict.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub backoff_base_ms: u64,
+    pub backoff_cap_ms: u64,
+    /// Result codes that trigger a retry.
+    pub retry_on: Vec<i32>,
+}
+
+impl RetryPolicy {
+    /// Backoff before retry number `attempt + 1` after `err`, or `None` when
+    /// `err` is not retried (unlisted code, or retries exhausted).
+    pub fn backoff_after(&self, attempt: u32, err: &AsError) -> Option<Duration> {
+        if attempt >= self.max_retries || !self.retry_on.contains(&error_code(err).0) {
+            return None;
+        }
+        Some(Duration::from_millis(compute_backoff_ms(
+            attempt,
+            self.backoff_base_ms,
+            self.backoff_cap_ms,
+        )))
+    }
+}
+
+/// Compute backoff duration in milliseconds using Full Jitter strategy.
+///
+/// Returns a random value in `[0, min(cap_ms, base_ms * 2^attempt)]`.
+/// The exponential term saturates instead of overflowing for large attempts.
+pub(crate) fn compute_backoff_ms(attempt: u32, base_ms: u64, cap_ms: u64) -> u64 {
+    use rand::RngExt;
+    let factor = 1u64.checked_shl(attempt).unwrap_or(u64::MAX);
+    let max_backoff = std::cmp::min(base_ms.saturating_mul(factor), cap_ms);
+    rand::rng().random_range(0..=max_backoff)
+}
+
+fn extract_ms(dict: &Bound<'_, PyDict>, key: &str) -> PyResult<Option<u64>> {
+    let Some(val) = dict.get_item(key)? else {
+        return Ok(None);
+    };
+    val.extract::<u64>().map(Some).map_err(|_| {
+        ParamError::new_err(format!("{key} must be a non-negative integer, got {val}"))
+    })
+}
+
+/// Parse the retry fields of a policy dict.
+///
+/// Returns `Ok(None)` unless `backoff_base_ms`, `backoff_cap_ms` or `retry_on`
+/// is present. When engaged, `max_retries` is taken from `base` (already
+/// parsed from the same dict) and `base.max_retries` is reset to `0`.
+/// `retry_on` defaults to the transient codes reported by `is_retryable()`.
+pub fn parse_retry_policy(
+    policy_dict: Option<&Bound<'_, PyDict>>,
+    base: &mut BasePolicy,
+) -> PyResult<Option<RetryPolicy>> {
+    let Some(dict) = policy_dict else {
+        return Ok(None);
+    };
+    let base_ms = extract_ms(dict, "backoff_base_ms")?;
+    let cap_ms = extract_ms(dict, "backoff_cap_ms")?;
+    let retry_on = dict.get_item("retry_on")?;
+    if base_ms.is_none() && cap_ms.is_none() && retry_on.is_none() {
+        return Ok(None);
+    }
+    trace!("Parsing retry policy");
+
+    let backoff_base_ms = base_ms.unwrap_or(DEFAULT_BACKOFF_BASE_MS);
+    let backoff_cap_ms = cap_ms.unwrap_or_else(|| DEFAULT_BACKOFF_CAP_MS.max(backoff_base_ms));
+    if backoff_cap_ms < backoff_base_ms {
+        return Err(ParamError::new_err(format!(
+            "backoff_cap_ms ({backoff_cap_ms}) must not be less than backoff_base_ms ({backoff_base_ms})"
+        )));
+    }
+    let retry_on = match retry_on {
+        Some(codes) => codes
+            .extract::<Vec<i32>>()
+            .map_err(|_| ParamError::new_err("retry_on must be a list of integer result codes"))?,
+        None => RETRYABLE_CODES.to_vec(),
+    };
+
+    let max_retries = u32::try_from(base.max_retries).unwrap_or(u32::MAX);
+    base.max_retries = 0;
+    Ok(Some(RetryPolicy {
+        max_retries,
+        backoff_base_ms,
+        backoff_cap_ms,
+        retry_on,
+    }))
+}
+
+/// Run `attempt` until it succeeds or `retry` declines the error.
+///
+/// Without a policy the operation runs exactly once.
+pub async fn with_retry<T, F, Fut>(
+    retry: Option<&RetryPolicy>,
+    op: &str,
+    mut attempt: F,
+) -> Result<T, AsError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, AsError>>,
+{
+    let Some(retry) = retry else {
+        return attempt().await;
+    };
+    let mut retries = 0;
+    loop {
+        match attempt().await {
+            Err(e) => match retry.backoff_after(retries, &e) {
+                Some(backoff) => {
+                    retries += 1;
+                    debug!(
+                        "{op} retry {retries}/{} after {e}, backoff {}ms",
+                        retry.max_retries,
+                        backoff.as_millis()
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+                None => return Err(e),
+            },
+            ok => return ok,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aerospike_core::{ReadPolicy, ResultCode};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn parse(
+        build: impl FnOnce(&Bound<'_, PyDict>),
+    ) -> (PyResult<Option<RetryPolicy>>, BasePolicy) {
+        Python::initialize();
+        Python::attach(|py| {
+            let d = PyDict::new(py);
+            build(&d);
+            let mut base = ReadPolicy::default().base_policy;
+            if let Some(n) = d.get_item("max_retries").unwrap() {
+                base.max_retries = n.extract().unwrap();
+            }
+            (parse_retry_policy(Some(&d), &mut base), base)
+        })
+    }
+
+    fn policy(max_retries: u32, retry_on: Vec<i32>) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            backoff_base_ms: 0,
+            backoff_cap_ms: 0,
+            retry_on,
+        }
+    }
+
+    #[test]
+    fn not_engaged_without_backoff_fields() {
+        let (retry, base) = parse(|d| d.set_item("max_retries", 5).unwrap());
+        assert_eq!(retry.unwrap(), None);
+        assert_eq!(base.max_retries, 5);
+    }
+
+    #[test]
+    fn engaged_policy_takes_over_max_retries() {
+        let (retry, base) = parse(|d| {
+            d.set_item("max_retries", 4).unwrap();
+            d.set_item("backoff_base_ms", 20).unwrap();
+            d.set_item("backoff_cap_ms", 200).unwrap();
+            d.set_item("retry_on", vec![9, 14]).unwrap();
+        });
+        assert_eq!(
+            retry.unwrap(),
+            Some(RetryPolicy {
+                max_retries: 4,
+                backoff_base_ms: 20,
+                backoff_cap_ms: 200,
+                retry_on: vec![9, 14],
+            })
+        );
+        assert_eq!(base.max_retries, 0);
+    }
+
+    #[test]
+    fn defaults_apply_to_missing_fields() {
+        let (retry, _) = parse(|d| d.set_item("backoff_base_ms", 5).unwrap());
+        let retry = retry.unwrap().unwrap();
+        assert_eq!(retry.max_retries, 2);
+        assert_eq!(retry.backoff_cap_ms, DEFAULT_BACKOFF_CAP_MS);
+        assert_eq!(retry.retry_on, RETRYABLE_CODES.to_vec());
+    }
+
+    #[test]
+    fn rejects_invalid_fields() {
+        for build in [
+            |d: &Bound<'_, PyDict>| {
+                d.set_item("backoff_base_ms", 100).unwrap();
+                d.set_item("backoff_cap_ms", 10).unwrap();
+            },
+            |d: &Bound<'_, PyDict>| d.set_item("backoff_base_ms", -1).unwrap(),
+            |d: &Bound<'_, PyDict>| d.set_item("retry_on", "timeout").unwrap(),
+        ] {
+            let (retry, _) = parse(build);
+            let err = retry.expect_err("must reject");
+            Python::attach(|py| assert!(err.is_instance_of::<ParamError>(py)));
+        }
+    }
+
+    #[test]
+    fn backoff_after_respects_codes_and_limit() {
+        let retry = policy(2, vec![14]);
+        let busy = AsError::ServerError(ResultCode::KeyBusy, false, String::new());
+        let missing = AsError::ServerError(ResultCode::KeyNotFoundError, false, String::new());
+        assert!(retry.backoff_after(0, &busy).is_some());
+        assert!(retry.backoff_after(1, &busy).is_some());
+        assert!(retry.backoff_after(2, &busy).is_none());
+        assert!(retry.backoff_after(0, &missing).is_none());
+    }
+
+    #[test]
+    fn backoff_is_capped_and_overflow_safe() {
+        for attempt in [0, 3, 40, 64, u32::MAX] {
+            assert!(compute_backoff_ms(attempt, 10, 300) <= 300);
+        }
+        // Must not overflow or panic.
+        compute_backoff_ms(u32::MAX, u64::MAX, u64::MAX);
+    }
+
+    #[tokio::test]
+    async fn with_retry_retries_until_success() {
+        let calls = AtomicU32::new(0);
+        let retry = policy(3, vec![14]);
+        let result = with_retry(Some(&retry), "get", || async {
+            if calls.fetch_add(1, Ordering::Relaxed) < 2 {
+                Err(AsError::ServerError(
+                    ResultCode::KeyBusy,
+                    false,
+                    String::new(),
+                ))
+            } else {
+                Ok(7)
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn with_retry_without_policy_runs_once() {
+        let calls = AtomicU32::new(0);
+        let result: Result<(), AsError> = with_retry(None, "get", || async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Err(AsError::ServerError(
+                ResultCode::KeyBusy,
+                false,
+                String::new(),
+            ))
+        })
+        .await;
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+    }
+}
@@ -9,12 +9,15 @@ use pyo3::types::PyDict;
 
 use super::{
     extract_filter_expression, extract_policy_fields, parse_commit_level, parse_consistency_level,
-    parse_generation_policy, parse_read_touch_ttl, parse_record_exists_action,
+    parse_generation_policy, parse_read_touch_ttl, parse_record_exists_action, parse_send_key,
 };
 
 /// Lazily-initialized default write policy used when no policy dict is provided.
 pub static DEFAULT_WRITE_POLICY: LazyLock<WritePolicy> = LazyLock::new(WritePolicy::default);
 
+/// `TTL_CLIENT_DEFAULT`: keep the expiration of the enclosing policy.
+pub(crate) const TTL_CLIENT_DEFAULT: i64 = -3;
+
 /// Convert a TTL integer value to an [`Expiration`] enum.
 ///
 /// Special values: `0` = namespace default, `-1` = never expire, `-2` = don't update.
@@ -24,16 +27,27 @@ pub(crate) fn parse_ttl(ttl_val: i64) -> PyResult<Expiration> {
         -1 => Ok(Expiration::Never),
         -2 => Ok(Expiration::DontUpdate),
         t if t > 0 && t <= u32::MAX as i64 => Ok(Expiration::Seconds(t as u32)),
-        t if t > u32::MAX as i64 => Err(crate::errors::InvalidArgError::new_err(format!(
+        t if t > u32::MAX as i64 => Err(crate::errors::ParamError::new_err(format!(
             "ttl out of range: {t} (max: {})",
             u32::MAX
         ))),
-        t => Err(crate::errors::InvalidArgError::new_err(format!(
-            "ttl out of range: {t} (only 0, -1, -2, or positive seconds are valid)"
+        t => Err(crate::errors::ParamError::new_err(format!(
+            "ttl out of range: {t} (only 0, -1, -2, -3, or positive seconds are valid)"
         ))),
     }
 }
 
+/// Apply a `ttl` value over `expiration`.
+///
+/// [`TTL_CLIENT_DEFAULT`] keeps the current value (the policy's `ttl`, or the
+/// namespace default); any other value replaces it via [`parse_ttl`].
+pub(crate) fn apply_ttl(expiration: &mut Expiration, ttl_val: i64) -> PyResult<()> {
+    if ttl_val != TTL_CLIENT_DEFAULT {
+        *expiration = parse_ttl(ttl_val)?;
+    }
+    Ok(())
+}
+
 /// Parse a Python policy dict into a WritePolicy
 pub fn parse_write_policy(
     policy_dict: Option<&Bound<'_, PyDict>>,
@@ -42,22 +56,32 @@ pub fn parse_write_policy(
     trace!("Parsing write policy");
     let mut policy = WritePolicy::default();
 
-    // Apply meta (gen, ttl) first
+    // Apply meta gen first; meta ttl and key are applied last so they win
+    // over the policy's `ttl` and `key`.
     if let Some(meta_dict) = meta {
         if let Some(gen) = meta_dict.get_item("gen")? {
             policy.generation = gen.extract::<u32>()?;
             policy.generation_policy = GenerationPolicy::ExpectGenEqual;
         }
+    }
+
+    if let Some(dict) = policy_dict {
+        apply_write_policy_dict(&mut policy, dict)?;
+    }
+
+    if let Some(meta_dict) = meta {
         if let Some(ttl) = meta_dict.get_item("ttl")? {
-            policy.expiration = parse_ttl(ttl.extract::<i64>()?)?;
+            apply_ttl(&mut policy.expiration, ttl.extract::<i64>()?)?;
+        }
+        if let Some(key) = meta_dict.get_item("key")? {
+            policy.send_key = parse_send_key(key.extract::<i32>()?)?;
         }
     }
 
-    let dict = match policy_dict {
-        Some(d) => d,
-        None => return Ok(policy),
-    };
+    Ok(policy)
+}
 
+fn apply_write_policy_dict(policy: &mut WritePolicy, dict: &Bound<'_, PyDict>) -> PyResult<()> {
     extract_policy_fields!(dict, {
         "socket_timeout" => policy.base_policy.socket_timeout;
         "total_timeout" => policy.base_policy.total_timeout;
@@ -68,7 +92,7 @@ pub fn parse_write_policy(
 
     // Key (send_key)
     if let Some(val) = dict.get_item("key")? {
-        policy.send_key = val.extract::<i32>()? == 1;
+        policy.send_key = parse_send_key(val.extract::<i32>()?)?;
     }
 
     // Exists (record_exists_action)
@@ -88,7 +112,7 @@ pub fn parse_write_policy(
 
     // TTL / expiration
     if let Some(val) = dict.get_item("ttl")? {
-        policy.expiration = parse_ttl(val.extract::<i64>()?)?;
+        apply_ttl(&mut policy.expiration, val.extract::<i64>()?)?;
     }
 
     // Read mode AP (BasePolicy field — operate() with read ops can use this)
@@ -103,7 +127,7 @@ pub fn parse_write_policy(
     // Filter expression
     policy.base_policy.filter_expression = extract_filter_expression(dict)?;
 
-    Ok(policy)
+    Ok(())
 }
 
 #[cfg(test)]
@@ -124,7 +148,7 @@ mod tests {
         Python::attach(|py| {
             let ttl = u32::MAX as i64 + 1;
             let err = parse_ttl(ttl).expect_err("ttl above u32::MAX must fail");
-            assert!(err.is_instance_of::<crate::errors::InvalidArgError>(py));
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
             assert!(err.to_string().contains("ttl out of range"));
         });
     }
@@ -140,12 +164,58 @@ mod tests {
         });
     }
 
+    #[test]
+    fn parse_ttl_maps_sentinels() {
+        assert!(matches!(
+            parse_ttl(0).unwrap(),
+            Expiration::NamespaceDefault
+        ));
+        assert!(matches!(parse_ttl(-1).unwrap(), Expiration::Never));
+        assert!(matches!(parse_ttl(-2).unwrap(), Expiration::DontUpdate));
+    }
+
+    #[test]
+    fn parse_write_policy_meta_ttl_overrides_policy_ttl() {
+        Python::initialize();
+        Python::attach(|py| {
+            let policy = PyDict::new(py);
+            policy.set_item("ttl", 60).unwrap();
+            let meta = PyDict::new(py);
+            meta.set_item("ttl", -1).unwrap();
+            let p = parse_write_policy(Some(&policy), Some(&meta)).unwrap();
+            assert!(matches!(p.expiration, Expiration::Never));
+
+            meta.set_item("ttl", TTL_CLIENT_DEFAULT).unwrap();
+            let p = parse_write_policy(Some(&policy), Some(&meta)).unwrap();
+            assert!(matches!(p.expiration, Expiration::Seconds(60)));
+
+            let p = parse_write_policy(None, Some(&meta)).unwrap();
+            assert!(matches!(p.expiration, Expiration::NamespaceDefault));
+        });
+    }
+
+    #[test]
+    fn parse_write_policy_meta_key_overrides_policy_key() {
+        Python::initialize();
+        Python::attach(|py| {
+            let policy = PyDict::new(py);
+            policy.set_item("key", 0).unwrap();
+            let meta = PyDict::new(py);
+            meta.set_item("key", 1).unwrap();
+            let p = parse_write_policy(Some(&policy), Some(&meta)).unwrap();
+            assert!(p.send_key);
+
+            let p = parse_write_policy(Some(&policy), None).unwrap();
+            assert!(!p.send_key);
+        });
+    }
+
     #[test]
     fn parse_ttl_rejects_unknown_negative_values() {
         Python::initialize();
         Python::attach(|py| {
             let err = parse_ttl(-100).expect_err("unknown negative ttl must fail");
-            assert!(err.is_instance_of::<crate::errors::InvalidArgError>(py));
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
             assert!(err.to_string().contains("ttl out of range"));
         });
     }
@@ -3,22 +3,37 @@
 //! Provides [`PyQuery`], a Python-visible class that collects predicates and
 //! selected bins, then executes them against the cluster as either a secondary
 //! index query or a full scan (when no predicates are set).
+//!
+//! The server takes a single secondary index filter per query, so only the
+//! first index-capable predicate becomes the filter; the others (and those no
+//! index can serve, such as string ranges and `in_list`) are AND-ed into the
+//! policy's `filter_expression`.
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
-use aerospike_core::query::Filter;
+use aerospike_core::expressions::{self, lists as list_exp, maps as map_exp, ExpType, Expression};
+use aerospike_core::query::{Filter, PartitionFilter};
 use aerospike_core::{
-    Bins, Client as AsClient, CollectionIndexType, Error as AsError, Statement, Value,
+    BatchOperation, BatchPolicy, BatchReadPolicy, Bins, Client as AsClient, CollectionIndexType,
+    Error as AsError, ListReturnType, MapReturnType, Statement, Value,
 };
 use futures::StreamExt;
 use log::{debug, trace};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
+use crate::cipher::BinCipher;
+use crate::client_common::parse_call_timeout;
+use crate::compression::BinCompression;
 use crate::errors::as_to_pyerr;
+use crate::expressions::{is_expression, py_to_expression, PyCompiledExpression};
 use crate::panic_safety::catch_panic_sync;
+use crate::policy::operate_policy::parse_operate_policy;
 use crate::policy::query_policy::parse_query_policy;
-use crate::runtime::RUNTIME;
+use crate::policy::retry_policy::parse_retry_policy;
+use crate::runtime::ClientRuntime;
 use crate::types::record::record_to_py;
 use crate::types::value::py_to_value;
 
@@ -37,6 +52,18 @@ enum Predicate {
         min: i64,
         max: i64,
     },
+    /// String range; no secondary index serves it, so it always runs as an
+    /// expression.
+    BetweenString {
+        bin: String,
+        min: String,
+        max: String,
+    },
+    /// Bin equals any of `vals`; always runs as an expression.
+    InList {
+        bin: String,
+        vals: Vec<Value>,
+    },
     ContainsString {
         bin: String,
         val: String,
@@ -69,7 +96,7 @@ enum Predicate {
 /// Parse a Python predicate tuple (from `aerospike_py.predicates`) into a [`Predicate`].
 fn parse_predicate(pred: &Bound<'_, PyTuple>) -> PyResult<Predicate> {
     if pred.len() < 3 {
-        return Err(crate::errors::InvalidArgError::new_err(format!(
+        return Err(crate::errors::ParamError::new_err(format!(
             "Predicate tuple must have at least 3 elements (kind, bin, value, ...), got {}",
             pred.len()
         )));
@@ -85,9 +112,30 @@ fn parse_predicate(pred: &Bound<'_, PyTuple>) -> PyResult<Predicate> {
         }
         "between" => {
             ensure_predicate_min_len(pred, "between", 4)?;
-            let min: i64 = pred.get_item(2)?.extract()?;
-            let max: i64 = pred.get_item(3)?.extract()?;
-            Ok(Predicate::Between { bin, min, max })
+            let (min, max) = (pred.get_item(2)?, pred.get_item(3)?);
+            if let (Ok(min), Ok(max)) = (min.extract::<i64>(), max.extract::<i64>()) {
+                Ok(Predicate::Between { bin, min, max })
+            } else if let (Ok(min), Ok(max)) = (min.extract::<String>(), max.extract::<String>()) {
+                Ok(Predicate::BetweenString { bin, min, max })
+            } else {
+                Err(crate::errors::ParamError::new_err(format!(
+                    "Predicate 'between' bounds must both be integers or both be strings, got \
+                     {min} and {max}"
+                )))
+            }
+        }
+        "in_list" => {
+            let vals = pred
+                .get_item(2)?
+                .try_iter()?
+                .map(|v| py_to_value(&v?))
+                .collect::<PyResult<Vec<_>>>()?;
+            if vals.is_empty() {
+                return Err(crate::errors::ParamError::new_err(
+                    "Predicate 'in_list' requires at least one value",
+                ));
+            }
+            Ok(Predicate::InList { bin, vals })
         }
         "contains" => {
             ensure_predicate_min_len(pred, "contains", 4)?;
@@ -128,7 +176,7 @@ fn parse_predicate(pred: &Bound<'_, PyTuple>) -> PyResult<Predicate> {
             let geojson: String = pred.get_item(2)?.extract()?;
             Ok(Predicate::GeoContainsPoint { bin, geojson })
         }
-        _ => Err(crate::errors::InvalidArgError::new_err(format!(
+        _ => Err(crate::errors::ParamError::new_err(format!(
             "Unknown predicate type: {kind}"
         ))),
     }
@@ -136,7 +184,7 @@ fn parse_predicate(pred: &Bound<'_, PyTuple>) -> PyResult<Predicate> {
 
 fn ensure_predicate_min_len(pred: &Bound<'_, PyTuple>, kind: &str, min_len: usize) -> PyResult<()> {
     if pred.len() < min_len {
-        return Err(crate::errors::InvalidArgError::new_err(format!(
+        return Err(crate::errors::ParamError::new_err(format!(
             "Predicate '{kind}' requires at least {min_len} elements, got {}",
             pred.len()
         )));
@@ -144,13 +192,142 @@ fn ensure_predicate_min_len(pred: &Bound<'_, PyTuple>, kind: &str, min_len: usiz
     Ok(())
 }
 
+impl Predicate {
+    /// The secondary index filter for this predicate, if an index can serve it.
+    fn to_filter(&self) -> PyResult<Option<Filter>> {
+        Ok(match self {
+            Predicate::Equals { bin, val } => Some(Filter::equal(bin.as_str(), val.clone())),
+            Predicate::Between { bin, min, max } => Some(Filter::range(bin.as_str(), *min, *max)),
+            Predicate::ContainsString { bin, val, col_type } => {
+                let ct = int_to_collection_index_type(*col_type);
+                Some(Filter::contains(bin.as_str(), val.as_str(), ct))
+            }
+            Predicate::ContainsInteger { bin, val, col_type } => {
+                let ct = int_to_collection_index_type(*col_type);
+                Some(Filter::contains(bin.as_str(), *val, ct))
+            }
+            Predicate::BetweenString { .. } | Predicate::InList { .. } => None,
+            Predicate::GeoWithinRegion { .. }
+            | Predicate::GeoWithinRadius { .. }
+            | Predicate::GeoContainsPoint { .. } => {
+                return Err(crate::errors::ClientError::new_err(
+                    "Geo filters are not yet supported in this version",
+                ));
+            }
+        })
+    }
+
+    /// The same predicate as a filter expression, for when it cannot be the
+    /// query's secondary index filter.
+    fn to_expression(&self) -> PyResult<Expression> {
+        match self {
+            Predicate::Equals { bin, val } => bin_equals(bin, val),
+            Predicate::Between { bin, min, max } => {
+                let bin = || expressions::int_bin(bin.clone());
+                Ok(expressions::and(vec![
+                    expressions::ge(bin(), expressions::int_val(*min)),
+                    expressions::le(bin(), expressions::int_val(*max)),
+                ]))
+            }
+            Predicate::BetweenString { bin, min, max } => {
+                let bin = || expressions::string_bin(bin.clone());
+                Ok(expressions::and(vec![
+                    expressions::ge(bin(), expressions::string_val(min.clone())),
+                    expressions::le(bin(), expressions::string_val(max.clone())),
+                ]))
+            }
+            Predicate::InList { bin, vals } => {
+                let mut any = vals
+                    .iter()
+                    .map(|val| bin_equals(bin, val))
+                    .collect::<PyResult<Vec<_>>>()?;
+                Ok(if any.len() == 1 {
+                    any.remove(0)
+                } else {
+                    expressions::or(any)
+                })
+            }
+            Predicate::ContainsString { bin, val, col_type } => {
+                contains_expression(bin, expressions::string_val(val.clone()), *col_type)
+            }
+            Predicate::ContainsInteger { bin, val, col_type } => {
+                contains_expression(bin, expressions::int_val(*val), *col_type)
+            }
+            Predicate::GeoWithinRegion { .. }
+            | Predicate::GeoWithinRadius { .. }
+            | Predicate::GeoContainsPoint { .. } => Err(crate::errors::ClientError::new_err(
+                "Geo filters are not yet supported in this version",
+            )),
+        }
+    }
+}
+
+/// `bin == val` with the bin accessor matching the value's type.
+fn bin_equals(bin: &str, val: &Value) -> PyResult<Expression> {
+    let bin = bin.to_string();
+    let (bin, val) = match val {
+        Value::Int(v) => (expressions::int_bin(bin), expressions::int_val(*v)),
+        Value::String(v) => (
+            expressions::string_bin(bin),
+            expressions::string_val(v.clone()),
+        ),
+        Value::Blob(v) => (expressions::blob_bin(bin), expressions::blob_val(v.clone())),
+        Value::Float(v) => (
+            expressions::float_bin(bin),
+            expressions::float_val(f64::from(v)),
+        ),
+        Value::Bool(v) => (expressions::bool_bin(bin), expressions::bool_val(*v)),
+        other => {
+            return Err(crate::errors::ParamError::new_err(format!(
+                "Cannot filter bin '{bin}' on a {} value; use an integer, string, float, bool \
+                 or bytes",
+                other.particle_type()
+            )))
+        }
+    };
+    Ok(expressions::eq(bin, val))
+}
+
+/// Collection membership of `val` in the list / map bin `bin`.
+fn contains_expression(bin: &str, val: Expression, col_type: i32) -> PyResult<Expression> {
+    let bin = bin.to_string();
+    match int_to_collection_index_type(col_type) {
+        CollectionIndexType::List => Ok(list_exp::get_by_value(
+            ListReturnType::Exists,
+            val,
+            expressions::list_bin(bin),
+            &[],
+        )),
+        CollectionIndexType::MapKeys => Ok(map_exp::get_by_key(
+            MapReturnType::Exists,
+            ExpType::BOOL,
+            val,
+            expressions::map_bin(bin),
+            &[],
+        )),
+        CollectionIndexType::MapValues => Ok(map_exp::get_by_value(
+            MapReturnType::Exists,
+            val,
+            expressions::map_bin(bin),
+            &[],
+        )),
+        _ => Err(crate::errors::ParamError::new_err(format!(
+            "Predicate 'contains' on '{bin}' needs INDEX_TYPE_LIST, INDEX_TYPE_MAPKEYS or \
+             INDEX_TYPE_MAPVALUES to be combined with another where() predicate"
+        ))),
+    }
+}
+
 /// Build an `aerospike_core::Statement` from namespace, set, bins, and predicates.
+///
+/// Returns the statement, carrying the first index-capable predicate as its
+/// filter, and the remaining predicates AND-ed into one expression.
 fn build_statement(
     namespace: &str,
     set_name: &str,
     bins: &[String],
     predicates: &[Predicate],
-) -> PyResult<Statement> {
+) -> PyResult<(Statement, Option<Expression>)> {
     let bins_selector = if bins.is_empty() {
         Bins::All
     } else {
@@ -159,31 +336,74 @@ fn build_statement(
     };
 
     let mut stmt = Statement::new(namespace, set_name, bins_selector);
+    let mut rest = Vec::new();
 
     for pred in predicates {
-        let filter = match pred {
-            Predicate::Equals { bin, val } => Filter::equal(bin.as_str(), val.clone()),
-            Predicate::Between { bin, min, max } => Filter::range(bin.as_str(), *min, *max),
-            Predicate::ContainsString { bin, val, col_type } => {
-                let ct = int_to_collection_index_type(*col_type);
-                Filter::contains(bin.as_str(), val.as_str(), ct)
-            }
-            Predicate::ContainsInteger { bin, val, col_type } => {
-                let ct = int_to_collection_index_type(*col_type);
-                Filter::contains(bin.as_str(), *val, ct)
-            }
-            Predicate::GeoWithinRegion { .. }
-            | Predicate::GeoWithinRadius { .. }
-            | Predicate::GeoContainsPoint { .. } => {
-                return Err(crate::errors::ClientError::new_err(
-                    "Geo filters are not yet supported in this version",
-                ));
-            }
-        };
-        stmt.add_filter(filter);
+        match pred.to_filter()? {
+            Some(filter) if stmt.filters.is_none() => stmt.add_filter(filter),
+            _ => rest.push(pred.to_expression()?),
+        }
     }
 
-    Ok(stmt)
+    let rest = match rest.len() {
+        0 => None,
+        1 => rest.pop(),
+        _ => Some(expressions::and(rest)),
+    };
+    Ok((stmt, rest))
+}
+
+/// Merge a `filter_expression=` argument into the query policy dict.
+///
+/// The argument takes precedence over a `filter_expression` set in `policy`;
+/// the caller's dict is copied, never modified.
+fn policy_with_filter<'py>(
+    py: Python<'py>,
+    policy: Option<&Bound<'py, PyDict>>,
+    filter_expression: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let Some(expr) = filter_expression else {
+        return Ok(policy.cloned());
+    };
+    if !is_expression(expr) {
+        return Err(crate::errors::ParamError::new_err(
+            "filter_expression must be an expression built with aerospike_py.exp",
+        ));
+    }
+    let merged = match policy {
+        Some(policy) => policy.copy()?,
+        None => PyDict::new(py),
+    };
+    merged.set_item("filter_expression", expr)?;
+    Ok(Some(merged))
+}
+
+/// AND the predicates that did not become the index filter into the policy's
+/// `filter_expression`.
+///
+/// The combined tree is stored as a [`PyCompiledExpression`] in a copy of the
+/// policy dict.
+fn policy_with_predicates<'py>(
+    py: Python<'py>,
+    policy: Option<Bound<'py, PyDict>>,
+    predicates: Option<Expression>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let Some(predicates) = predicates else {
+        return Ok(policy);
+    };
+    let merged = match &policy {
+        Some(policy) => policy.copy()?,
+        None => PyDict::new(py),
+    };
+    let combined = match merged.get_item("filter_expression")? {
+        Some(user) if is_expression(&user) => {
+            expressions::and(vec![py_to_expression(&user)?, predicates])
+        }
+        _ => predicates,
+    };
+    let compiled = Bound::new(py, PyCompiledExpression::from_expression(combined, "where"))?;
+    merged.set_item("filter_expression", compiled)?;
+    Ok(Some(merged))
 }
 
 /// Map a Python integer to a [`CollectionIndexType`] for contains-predicates.
@@ -196,20 +416,177 @@ fn int_to_collection_index_type(val: i32) -> CollectionIndexType {
     }
 }
 
-/// Execute a query/scan, collect all records, with metrics and OTel span.
+/// Output names for bins renamed through a `select()` dict, as
+/// `(bin, alias)` pairs. Bins selected under their own name are not listed.
+#[derive(Debug, Default)]
+struct BinAliases(Vec<(String, String)>);
+
+impl BinAliases {
+    /// Rename the entries of `bins`. All renamed bins are taken out before
+    /// any is re-inserted, so swapping two names is safe.
+    fn apply(&self, bins: &mut HashMap<String, Value>) {
+        let values: Vec<_> = self
+            .0
+            .iter()
+            .map(|(bin, alias)| (alias, bins.remove(bin)))
+            .collect();
+        for (alias, value) in values {
+            if let Some(value) = value {
+                bins.insert(alias.clone(), value);
+            }
+        }
+    }
+}
+
+/// Client-side query settings that are neither statement nor policy fields.
+#[derive(Debug, Default)]
+struct QuerySettings {
+    aliases: BinAliases,
+    compression: Arc<BinCompression>,
+    cipher: BinCipher,
+    /// Restrict the query to partitions mastered by these nodes; empty
+    /// means all nodes.
+    nodes: Vec<String>,
+    /// Reject the query from a thread running an asyncio loop.
+    event_loop_guard: bool,
+    /// Per-call `timeout=`, shared by every step of the call.
+    deadline: Option<CallDeadline>,
+}
+
+/// Deadline of a per-call `timeout=`, with the limit it was set from.
+#[derive(Debug, Clone, Copy)]
+struct CallDeadline {
+    at: tokio::time::Instant,
+    limit: Duration,
+}
+
+impl CallDeadline {
+    fn from_timeout(timeout: Option<f64>) -> PyResult<Option<Self>> {
+        Ok(parse_call_timeout(timeout)?.map(|limit| Self {
+            at: tokio::time::Instant::now() + limit,
+            limit,
+        }))
+    }
+}
+
+/// Run `fut`, failing with `Error::Timeout` once `deadline` has passed.
+async fn before_deadline<T>(
+    op: &str,
+    deadline: Option<CallDeadline>,
+    fut: impl std::future::Future<Output = Result<T, AsError>>,
+) -> Result<T, AsError> {
+    let Some(deadline) = deadline else {
+        return fut.await;
+    };
+    match tokio::time::timeout_at(deadline.at, fut).await {
+        Ok(result) => result,
+        Err(_) => {
+            let ms = deadline.limit.as_millis();
+            debug!("{op} exceeded its {ms}ms call timeout");
+            Err(AsError::Timeout(format!(
+                "{op} did not complete within {ms}ms"
+            )))
+        }
+    }
+}
+
+/// Split `begin..begin + count` into the `(begin, count)` runs of
+/// partitions for which `owned` holds.
+fn partition_runs(
+    begin: usize,
+    count: usize,
+    owned: impl Fn(usize) -> bool,
+) -> Vec<(usize, usize)> {
+    let mut runs = Vec::new();
+    let mut start = None;
+    for pid in begin..begin + count {
+        match (owned(pid), start) {
+            (true, None) => start = Some(pid),
+            (false, Some(s)) => {
+                runs.push((s, pid - s));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        runs.push((s, begin + count - s));
+    }
+    runs
+}
+
+/// Narrow `filter` to the partitions whose master is one of `nodes`, as
+/// one partition-range filter per contiguous run.
+fn node_partition_filters(
+    client: &AsClient,
+    namespace: &str,
+    filter: PartitionFilter,
+    nodes: &[String],
+) -> PyResult<Vec<PartitionFilter>> {
+    if nodes.is_empty() {
+        return Ok(vec![filter]);
+    }
+    let known: Vec<String> = client
+        .cluster
+        .nodes()
+        .iter()
+        .map(|node| node.name().to_string())
+        .collect();
+    if let Some(unknown) = nodes.iter().find(|node| !known.contains(node)) {
+        return Err(crate::errors::ParamError::new_err(format!(
+            "Unknown node '{unknown}'; cluster nodes are {known:?}"
+        )));
+    }
+    if filter.digest.is_some() {
+        return Err(crate::errors::ParamError::new_err(
+            "set_nodes() cannot be combined with a key-based partition_filter",
+        ));
+    }
+    let runs = partition_runs(filter.begin, filter.count, |pid| {
+        client
+            .cluster
+            .get_master_node(namespace, pid)
+            .is_ok_and(|node| nodes.iter().any(|name| name == node.name()))
+    });
+    trace!("Query restricted to {} partition ranges", runs.len());
+    Ok(runs
+        .into_iter()
+        .map(|(begin, count)| PartitionFilter::by_range(begin, count))
+        .collect())
+}
+
+/// Execute a query/scan, feeding each record to `sink` as it streams in,
+/// with metrics and OTel span.
+///
+/// `sink` runs without the GIL. If it fails, the stream is abandoned and
+/// its error returned once the span has been recorded. With a retry policy,
+/// a query is retried only when it fails before yielding its first record,
+/// so `sink` never sees a record twice.
 #[allow(unused, clippy::too_many_arguments)]
-fn execute_query_collect(
+fn execute_query_each<F>(
     py: Python<'_>,
     client: &Arc<AsClient>,
+    runtime: &ClientRuntime,
     statement: Statement,
+    settings: &QuerySettings,
     policy: Option<&Bound<'_, PyDict>>,
     op_name: &str,
     namespace: &str,
     set_name: &str,
     conn_info: &crate::tracing::ConnectionInfo,
-) -> PyResult<Vec<aerospike_core::Record>> {
+    mut sink: F,
+) -> PyResult<()>
+where
+    F: FnMut(aerospike_core::Record) -> PyResult<()> + Send,
+{
+    if settings.event_loop_guard {
+        crate::runtime::forbid_running_loop(py)?;
+    }
     let client = client.clone();
-    let (query_policy, partition_filter) = parse_query_policy(policy)?;
+    let (mut query_policy, partition_filter) = parse_query_policy(policy)?;
+    let partition_filters =
+        node_partition_filters(&client, namespace, partition_filter, &settings.nodes)?;
+    let retry = parse_retry_policy(policy, &mut query_policy.base_policy)?;
     debug!("Executing {}", op_name);
 
     let timer = crate::metrics::OperationTimer::start(op_name, namespace, set_name);
@@ -218,19 +595,49 @@ fn execute_query_collect(
         "query" => "Query.query",
         _ => "Query.execute",
     };
-    let result: Result<Vec<_>, AsError> = catch_panic_sync(panic_op, || {
+    let mut sink_err: Option<PyErr> = None;
+    let result: Result<(), AsError> = catch_panic_sync(panic_op, || {
         Ok(py.detach(|| {
-            RUNTIME.block_on(async {
-                let rs = client
-                    .query(&query_policy, partition_filter, statement)
-                    .await?;
-                let mut stream = rs.into_stream();
-                let mut results = Vec::new();
-                while let Some(result) = stream.next().await {
-                    results.push(result?);
+            runtime.block_on(before_deadline(panic_op, settings.deadline, async {
+                let mut retries = 0;
+                loop {
+                    let mut streamed = false;
+                    let result: Result<(), AsError> = async {
+                        for partition_filter in &partition_filters {
+                            let rs = client
+                                .query(&query_policy, partition_filter.clone(), statement.clone())
+                                .await?;
+                            let mut stream = rs.into_stream();
+                            while let Some(result) = stream.next().await {
+                                let mut record = result?;
+                                streamed = true;
+                                let sunk =
+                                    settings.cipher.decrypt_record(&mut record).and_then(|()| {
+                                        settings.compression.decompress_record(&mut record);
+                                        settings.aliases.apply(&mut record.bins);
+                                        sink(record)
+                                    });
+                                if let Err(e) = sunk {
+                                    sink_err = Some(e);
+                                    return Ok(());
+                                }
+                            }
+                        }
+                        Ok(())
+                    }
+                    .await;
+                    let backoff = match (&retry, &result) {
+                        (Some(retry), Err(e)) if !streamed => retry.backoff_after(retries, e),
+                        _ => None,
+                    };
+                    let Some(backoff) = backoff else {
+                        return result;
+                    };
+                    retries += 1;
+                    debug!("{} retry {} after {:?}", op_name, retries, result);
+                    tokio::time::sleep(backoff).await;
                 }
-                Ok(results)
-            })
+            }))
         }))
     })?;
 
@@ -244,7 +651,7 @@ fn execute_query_collect(
         use opentelemetry::trace::{SpanKind, TraceContextExt, Tracer};
         use opentelemetry::KeyValue;
         let tracer = crate::tracing::otel_impl::get_tracer();
-        let span_name = format!("{} {}.{}", op_name.to_uppercase(), namespace, set_name);
+        let span_name = crate::tracing::span_name(&op_name.to_uppercase(), namespace, set_name);
         let span = tracer
             .span_builder(span_name)
             .with_kind(SpanKind::Client)
@@ -266,7 +673,45 @@ fn execute_query_collect(
         span_ref.end();
     }
 
-    result.map_err(as_to_pyerr)
+    result.map_err(as_to_pyerr)?;
+    match sink_err {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+/// Execute a query/scan and collect all records.
+#[allow(unused, clippy::too_many_arguments)]
+fn execute_query_collect(
+    py: Python<'_>,
+    client: &Arc<AsClient>,
+    runtime: &ClientRuntime,
+    statement: Statement,
+    settings: &QuerySettings,
+    policy: Option<&Bound<'_, PyDict>>,
+    op_name: &str,
+    namespace: &str,
+    set_name: &str,
+    conn_info: &crate::tracing::ConnectionInfo,
+) -> PyResult<Vec<aerospike_core::Record>> {
+    let mut records = Vec::new();
+    execute_query_each(
+        py,
+        client,
+        runtime,
+        statement,
+        settings,
+        policy,
+        op_name,
+        namespace,
+        set_name,
+        conn_info,
+        |record| {
+            records.push(record);
+            Ok(())
+        },
+    )?;
+    Ok(records)
 }
 
 /// Execute a query/scan and collect all results as a Python list.
@@ -274,7 +719,9 @@ fn execute_query_collect(
 fn execute_query(
     py: Python<'_>,
     client: &Arc<AsClient>,
+    runtime: &ClientRuntime,
     statement: Statement,
+    settings: &QuerySettings,
     policy: Option<&Bound<'_, PyDict>>,
     op_name: &str,
     namespace: &str,
@@ -282,7 +729,7 @@ fn execute_query(
     conn_info: &crate::tracing::ConnectionInfo,
 ) -> PyResult<Py<PyAny>> {
     let records = execute_query_collect(
-        py, client, statement, policy, op_name, namespace, set_name, conn_info,
+        py, client, runtime, statement, settings, policy, op_name, namespace, set_name, conn_info,
     )?;
     debug!("{} returned {} records", op_name, records.len());
     let py_records: Vec<Py<PyAny>> = records
@@ -293,12 +740,83 @@ fn execute_query(
     Ok(py_list.into_any().unbind())
 }
 
+/// Convert the `ops` of `Query.results()`, which must all be reads.
+fn read_ops(ops: &Bound<'_, PyList>) -> PyResult<Vec<aerospike_core::operations::Operation>> {
+    let ops = crate::operations::py_ops_to_rust(ops)?;
+    if ops.iter().any(|op| !crate::operations::is_read_op(op)) {
+        return Err(crate::errors::ParamError::new_err(
+            "Query.results() ops must be read operations",
+        ));
+    }
+    Ok(ops)
+}
+
+/// Run read `ops` on each query record in follow-up batches and merge the
+/// bins they return into the record.
+///
+/// A foreground query only carries bin names, so operations cannot ride on
+/// the query command itself. Records removed since the query saw them keep
+/// their query bins.
+fn apply_read_ops(
+    py: Python<'_>,
+    client: &Arc<AsClient>,
+    runtime: &ClientRuntime,
+    settings: &QuerySettings,
+    records: &mut [aerospike_core::Record],
+    ops: Vec<aerospike_core::operations::Operation>,
+) -> PyResult<()> {
+    if ops.is_empty() {
+        return Ok(());
+    }
+    let read_policy = BatchReadPolicy::default();
+    let batch_ops: Vec<(usize, BatchOperation)> = records
+        .iter()
+        .enumerate()
+        .filter_map(|(i, record)| {
+            let key = record.key.clone()?;
+            Some((i, BatchOperation::read_ops(&read_policy, key, ops.clone())))
+        })
+        .collect();
+    debug!(
+        "Applying {} ops to {} query records",
+        ops.len(),
+        batch_ops.len()
+    );
+    let client = client.clone();
+    let policy = BatchPolicy::default();
+    for chunk in batch_ops.chunks(crate::client_ops::DEFAULT_BATCH_CHUNK_SIZE) {
+        let (indices, chunk_ops): (Vec<usize>, Vec<BatchOperation>) = chunk.iter().cloned().unzip();
+        let results = catch_panic_sync("Query.results", || {
+            py.detach(|| {
+                runtime.block_on(before_deadline(
+                    "Query.results",
+                    settings.deadline,
+                    client.batch(&policy, &chunk_ops),
+                ))
+            })
+            .map_err(as_to_pyerr)
+        })?;
+        crate::batch_types::check_partial_results(&results, false)?;
+        for (i, br) in indices.into_iter().zip(results) {
+            let Some(mut op_record) = br.record else {
+                continue;
+            };
+            settings.cipher.decrypt_record(&mut op_record)?;
+            settings.compression.decompress_record(&mut op_record);
+            records[i].bins.extend(op_record.bins);
+        }
+    }
+    Ok(())
+}
+
 /// Execute a query/scan and call a callback for each record.
 #[allow(clippy::too_many_arguments, unused)]
 fn execute_foreach(
     py: Python<'_>,
     client: &Arc<AsClient>,
+    runtime: &ClientRuntime,
     statement: Statement,
+    settings: &QuerySettings,
     callback: &Bound<'_, PyAny>,
     policy: Option<&Bound<'_, PyDict>>,
     op_name: &str,
@@ -307,7 +825,7 @@ fn execute_foreach(
     conn_info: &crate::tracing::ConnectionInfo,
 ) -> PyResult<()> {
     let records = execute_query_collect(
-        py, client, statement, policy, op_name, namespace, set_name, conn_info,
+        py, client, runtime, statement, settings, policy, op_name, namespace, set_name, conn_info,
     )?;
     for record in &records {
         let py_record = record_to_py(py, record, None)?;
@@ -322,44 +840,160 @@ fn execute_foreach(
 
 // ── Query class ──────────────────────────────────────────
 
+/// Client state a query runs with.
+#[derive(Clone)]
+pub struct QueryBinding {
+    pub client: Arc<AsClient>,
+    pub connection_info: Arc<crate::tracing::ConnectionInfo>,
+    pub runtime: ClientRuntime,
+    pub compression: Arc<BinCompression>,
+    pub cipher: BinCipher,
+    pub event_loop_guard: bool,
+}
+
 /// Python-visible query builder exposed as `Query`.
 ///
-/// Created by `Client.query()` / `AsyncClient.query()`. Users add predicates
-/// via `where()`, select bins via `select()`, then execute via `results()` or
-/// `foreach()`.
-#[pyclass(name = "Query")]
+/// Created by `Client.query()` / `AsyncClient.query()`, or detached from any
+/// client with `Query(namespace, set_name)` and run by `Client.execute()`.
+/// Users add predicates via `where()`, select bins via `select()`, then
+/// execute via `results()` or `foreach()`.
+#[pyclass(name = "Query", skip_from_py_object)]
+#[derive(Clone)]
 pub struct PyQuery {
-    client: Arc<AsClient>,
     namespace: String,
     set_name: String,
     bins: Vec<String>,
+    /// Output name of each entry in `bins`.
+    columns: Vec<String>,
     predicates: Vec<Predicate>,
-    connection_info: Arc<crate::tracing::ConnectionInfo>,
+    max_records: Option<u64>,
+    records_per_second: Option<u32>,
+    max_concurrent_nodes: Option<u32>,
+    nodes: Vec<String>,
+    /// `None` for a detached query.
+    binding: Option<QueryBinding>,
 }
 
 impl PyQuery {
-    pub fn new(
-        client: Arc<AsClient>,
-        namespace: String,
-        set_name: String,
-        connection_info: Arc<crate::tracing::ConnectionInfo>,
-    ) -> Self {
+    pub fn new(namespace: String, set_name: String, binding: Option<QueryBinding>) -> Self {
         Self {
-            client,
             namespace,
             set_name,
             bins: vec![],
+            columns: vec![],
             predicates: vec![],
-            connection_info,
+            max_records: None,
+            records_per_second: None,
+            max_concurrent_nodes: None,
+            nodes: vec![],
+            binding,
+        }
+    }
+
+    /// A copy of this query that runs on `binding`'s client.
+    pub fn bind(&self, binding: QueryBinding) -> Self {
+        Self {
+            binding: Some(binding),
+            ..self.clone()
+        }
+    }
+
+    fn binding(&self) -> PyResult<&QueryBinding> {
+        self.binding.as_ref().ok_or_else(|| {
+            crate::errors::ClientError::new_err(
+                "Query is not bound to a client; run it with client.execute(query)",
+            )
+        })
+    }
+
+    /// Select `bin`, returned under `alias`.
+    fn add_bin(&mut self, bin: String, alias: String) -> PyResult<()> {
+        if let Some(i) = self.columns.iter().position(|c| *c == alias) {
+            if self.bins[i] == bin {
+                return Ok(());
+            }
+            return Err(crate::errors::ParamError::new_err(format!(
+                "select(): output name '{alias}' is used for both '{}' and '{bin}'",
+                self.bins[i]
+            )));
+        }
+        if self.bins.contains(&bin) {
+            return Err(crate::errors::ParamError::new_err(format!(
+                "select(): bin '{bin}' is selected under more than one name"
+            )));
+        }
+        self.bins.push(bin);
+        self.columns.push(alias);
+        Ok(())
+    }
+
+    /// Fill the `max_records()` / `records_per_second()` /
+    /// `max_concurrent_nodes()` limits into a copy
+    /// of the policy dict; fields set in the dict itself take precedence.
+    fn policy_with_limits<'py>(
+        &self,
+        py: Python<'py>,
+        policy: Option<Bound<'py, PyDict>>,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        if self.max_records.is_none()
+            && self.records_per_second.is_none()
+            && self.max_concurrent_nodes.is_none()
+        {
+            return Ok(policy);
+        }
+        let merged = match &policy {
+            Some(policy) => policy.copy()?,
+            None => PyDict::new(py),
+        };
+        if let Some(n) = self.max_records {
+            if !merged.contains("max_records")? {
+                merged.set_item("max_records", n)?;
+            }
+        }
+        if let Some(n) = self.records_per_second {
+            if !merged.contains("records_per_second")? {
+                merged.set_item("records_per_second", n)?;
+            }
         }
+        if let Some(n) = self.max_concurrent_nodes {
+            if !merged.contains("max_concurrent_nodes")? {
+                merged.set_item("max_concurrent_nodes", n)?;
+            }
+        }
+        Ok(Some(merged))
+    }
+
+    fn settings(&self, binding: &QueryBinding, timeout: Option<f64>) -> PyResult<QuerySettings> {
+        let aliases = self
+            .bins
+            .iter()
+            .zip(&self.columns)
+            .filter(|(bin, alias)| bin != alias)
+            .map(|(bin, alias)| (bin.clone(), alias.clone()))
+            .collect();
+        Ok(QuerySettings {
+            aliases: BinAliases(aliases),
+            compression: binding.compression.clone(),
+            cipher: binding.cipher.clone(),
+            nodes: self.nodes.clone(),
+            event_loop_guard: binding.event_loop_guard,
+            deadline: CallDeadline::from_timeout(timeout)?,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::parse_predicate;
+    use super::{
+        before_deadline, build_statement, parse_predicate, partition_runs, policy_with_filter,
+        policy_with_predicates, BinAliases, CallDeadline,
+    };
+    use aerospike_core::Error as AsError;
+    use aerospike_core::Value;
     use pyo3::prelude::*;
-    use pyo3::types::PyTuple;
+    use pyo3::types::{PyDict, PyTuple};
+    use std::collections::HashMap;
+    use std::time::Duration;
 
     #[test]
     fn parse_predicate_rejects_short_equals_tuple() {
@@ -370,7 +1004,7 @@ mod tests {
                 Ok(_) => panic!("expected short equals predicate to fail"),
                 Err(err) => {
                     let msg = err.to_string();
-                    assert!(msg.contains("InvalidArgError"));
+                    assert!(msg.contains("ParamError"));
                     assert!(msg.contains("at least 3 elements"));
                 }
             }
@@ -386,7 +1020,7 @@ mod tests {
                 Ok(_) => panic!("expected short between predicate to fail"),
                 Err(err) => {
                     let msg = err.to_string();
-                    assert!(msg.contains("InvalidArgError"));
+                    assert!(msg.contains("ParamError"));
                     assert!(msg.contains("between"));
                 }
             }
@@ -402,7 +1036,7 @@ mod tests {
                 Ok(_) => panic!("expected short contains predicate to fail"),
                 Err(err) => {
                     let msg = err.to_string();
-                    assert!(msg.contains("InvalidArgError"));
+                    assert!(msg.contains("ParamError"));
                     assert!(msg.contains("contains"));
                 }
             }
@@ -418,25 +1052,228 @@ mod tests {
                 Ok(_) => panic!("expected short geo_within_radius predicate to fail"),
                 Err(err) => {
                     let msg = err.to_string();
-                    assert!(msg.contains("InvalidArgError"));
+                    assert!(msg.contains("ParamError"));
                     assert!(msg.contains("geo_within_radius"));
                 }
             }
         });
     }
+
+    #[test]
+    fn policy_with_filter_overrides_without_mutating_policy() {
+        Python::initialize();
+        Python::attach(|py| {
+            let policy = PyDict::new(py);
+            policy.set_item("filter_expression", "old").unwrap();
+            policy.set_item("total_timeout", 500).unwrap();
+            let expr = PyDict::new(py);
+            expr.set_item("__expr__", "eq").unwrap();
+
+            let merged = policy_with_filter(py, Some(&policy), Some(expr.as_any()))
+                .unwrap()
+                .unwrap();
+            assert!(merged
+                .get_item("filter_expression")
+                .unwrap()
+                .unwrap()
+                .is(&expr));
+            assert_eq!(
+                merged
+                    .get_item("total_timeout")
+                    .unwrap()
+                    .unwrap()
+                    .extract::<u32>()
+                    .unwrap(),
+                500
+            );
+            let original = policy.get_item("filter_expression").unwrap().unwrap();
+            assert_eq!(original.extract::<String>().unwrap(), "old");
+
+            let unchanged = policy_with_filter(py, Some(&policy), None)
+                .unwrap()
+                .unwrap();
+            assert!(unchanged.is(&policy));
+        });
+    }
+
+    #[test]
+    fn only_first_index_capable_predicate_becomes_the_filter() {
+        Python::initialize();
+        Python::attach(|py| {
+            let preds = [
+                ("between", "name", "a", "m").into_pyobject(py).unwrap(),
+                ("equals", "group", "A").into_pyobject(py).unwrap(),
+                ("between", "age", 1, 9).into_pyobject(py).unwrap(),
+            ];
+            let preds: Vec<_> = preds.iter().map(|p| parse_predicate(p).unwrap()).collect();
+            let (stmt, rest) = build_statement("test", "demo", &[], &preds).unwrap();
+            let filters = stmt.filters.unwrap();
+            assert_eq!(filters.len(), 1);
+            assert!(rest.is_some());
+
+            let (stmt, rest) = build_statement("test", "demo", &[], &preds[1..2]).unwrap();
+            assert_eq!(stmt.filters.unwrap().len(), 1);
+            assert!(rest.is_none());
+        });
+    }
+
+    #[test]
+    fn predicates_are_anded_into_policy_filter() {
+        Python::initialize();
+        Python::attach(|py| {
+            let pred = ("in_list", "name", vec!["a", "b"])
+                .into_pyobject(py)
+                .unwrap();
+            let pred = parse_predicate(&pred).unwrap();
+            let (_, rest) = build_statement("test", "demo", &[], &[pred]).unwrap();
+
+            let policy = PyDict::new(py);
+            policy.set_item("total_timeout", 500).unwrap();
+            let merged = policy_with_predicates(py, Some(policy.clone()), rest)
+                .unwrap()
+                .unwrap();
+            let filter = merged.get_item("filter_expression").unwrap().unwrap();
+            assert!(filter.is_instance_of::<crate::expressions::PyCompiledExpression>());
+            assert!(policy.get_item("filter_expression").unwrap().is_none());
+
+            assert!(policy_with_predicates(py, None, None).unwrap().is_none());
+        });
+    }
+
+    #[test]
+    fn bin_aliases_rename_and_swap() {
+        let mut bins = HashMap::from([
+            ("a".to_string(), Value::from(1)),
+            ("b".to_string(), Value::from(2)),
+            ("c".to_string(), Value::from(3)),
+        ]);
+        let aliases = BinAliases(vec![
+            ("a".into(), "b".into()),
+            ("b".into(), "a".into()),
+            ("c".into(), "z".into()),
+            ("missing".into(), "m".into()),
+        ]);
+        aliases.apply(&mut bins);
+        assert_eq!(bins.len(), 3);
+        assert_eq!(bins["a"], Value::from(2));
+        assert_eq!(bins["b"], Value::from(1));
+        assert_eq!(bins["z"], Value::from(3));
+    }
+
+    #[test]
+    fn partition_runs_group_contiguous_partitions() {
+        assert_eq!(
+            partition_runs(0, 10, |pid| pid % 4 < 2),
+            vec![(0, 2), (4, 2), (8, 2)]
+        );
+        assert_eq!(partition_runs(100, 5, |_| true), vec![(100, 5)]);
+        assert_eq!(partition_runs(0, 4096, |pid| pid >= 4090), vec![(4090, 6)]);
+        assert!(partition_runs(0, 8, |_| false).is_empty());
+    }
+
+    #[tokio::test]
+    async fn before_deadline_times_out_the_whole_call() {
+        let deadline = Some(CallDeadline {
+            at: tokio::time::Instant::now() + Duration::from_millis(10),
+            limit: Duration::from_millis(10),
+        });
+        let slow = async {
+            tokio::time::sleep(Duration::from_secs(5)).await;
+            Ok(())
+        };
+        match before_deadline("Query.query", deadline, slow).await {
+            Err(AsError::Timeout(msg)) => assert!(msg.contains("Query.query"), "{msg}"),
+            other => panic!("expected a timeout, got {other:?}"),
+        }
+        let fast = before_deadline("Query.query", None, async { Ok(7) });
+        assert_eq!(fast.await.unwrap(), 7);
+    }
+
+    #[test]
+    fn policy_with_filter_rejects_non_expression() {
+        Python::initialize();
+        Python::attach(|py| {
+            let not_expr = PyDict::new(py);
+            let err = policy_with_filter(py, None, Some(not_expr.as_any())).unwrap_err();
+            assert!(err.is_instance_of::<crate::errors::ParamError>(py));
+        });
+    }
 }
 
 #[pymethods]
 impl PyQuery {
+    /// Build a query not bound to any client, run later by `Client.execute()`.
+    #[new]
+    #[pyo3(signature = (namespace, set_name=None))]
+    fn py_new(namespace: &str, set_name: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        crate::types::key::validate_namespace(namespace)?;
+        let set_name = match set_name {
+            Some(set_name) => crate::types::key::py_to_set_name(set_name)?,
+            None => String::new(),
+        };
+        Ok(Self::new(namespace.to_string(), set_name, None))
+    }
+
     /// Select specific bins to return in query results.
+    ///
+    /// A dict argument maps output names to bin names; those bins are
+    /// returned under their output names.
     #[pyo3(signature = (*bins))]
     fn select(&mut self, bins: &Bound<'_, PyTuple>) -> PyResult<()> {
-        for bin in bins.iter() {
-            self.bins.push(bin.extract::<String>()?);
+        for arg in bins.iter() {
+            if let Ok(renames) = arg.cast::<PyDict>() {
+                for (alias, bin) in renames.iter() {
+                    self.add_bin(bin.extract()?, alias.extract()?)?;
+                }
+            } else {
+                let bin: String = arg.extract()?;
+                self.add_bin(bin.clone(), bin)?;
+            }
         }
         Ok(())
     }
 
+    /// Stop after roughly `n` records (spread across nodes); `0` means all.
+    fn max_records(&mut self, n: i64) -> PyResult<()> {
+        let n = u64::try_from(n).map_err(|_| {
+            crate::errors::ParamError::new_err(format!(
+                "max_records must be a non-negative integer, got {n}"
+            ))
+        })?;
+        self.max_records = Some(n);
+        Ok(())
+    }
+
+    /// Limit each node to `n` records per second; `0` means unlimited.
+    fn records_per_second(&mut self, n: i64) -> PyResult<()> {
+        let n = u32::try_from(n).map_err(|_| {
+            crate::errors::ParamError::new_err(format!(
+                "records_per_second must be between 0 and {}, got {n}",
+                u32::MAX
+            ))
+        })?;
+        self.records_per_second = Some(n);
+        Ok(())
+    }
+
+    /// Query at most `n` nodes at a time; `0` queries all nodes in parallel.
+    fn max_concurrent_nodes(&mut self, n: i64) -> PyResult<()> {
+        let n = u32::try_from(n).map_err(|_| {
+            crate::errors::ParamError::new_err(format!(
+                "max_concurrent_nodes must be between 0 and {}, got {n}",
+                u32::MAX
+            ))
+        })?;
+        self.max_concurrent_nodes = Some(n);
+        Ok(())
+    }
+
+    /// Restrict the query to the partitions mastered by the named nodes.
+    /// An empty list queries all nodes again.
+    fn set_nodes(&mut self, nodes: Vec<String>) {
+        self.nodes = nodes;
+    }
+
     /// Add a filter predicate (secondary index query).
     #[pyo3(name = "where")]
     fn where_(&mut self, predicate: &Bound<'_, PyTuple>) -> PyResult<()> {
@@ -445,51 +1282,279 @@ impl PyQuery {
         Ok(())
     }
 
-    /// Execute the query and return all results as a list of (key, meta, bins).
-    #[pyo3(signature = (policy=None))]
-    fn results(&self, py: Python<'_>, policy: Option<&Bound<'_, PyDict>>) -> PyResult<Py<PyAny>> {
-        let stmt = build_statement(
+    /// Execute the query and return all results as a list of (key, meta, bins),
+    /// or a `NumpyBatchRecords` when `dtype` is provided.
+    ///
+    /// `ops` are read operations run on every matching record after the
+    /// query; the bins they return are added to (or replace) the record's bins.
+    #[pyo3(signature = (policy=None, dtype=None, filter_expression=None, ops=None, timeout=None))]
+    fn results(
+        &self,
+        py: Python<'_>,
+        policy: Option<&Bound<'_, PyDict>>,
+        dtype: Option<&Bound<'_, PyAny>>,
+        filter_expression: Option<&Bound<'_, PyAny>>,
+        ops: Option<&Bound<'_, PyList>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Py<PyAny>> {
+        let binding = self.binding()?;
+        let ops = ops.map(read_ops).transpose()?;
+        let (stmt, predicates) = build_statement(
             &self.namespace,
             &self.set_name,
             &self.bins,
             &self.predicates,
         )?;
+        let policy = policy_with_filter(py, policy, filter_expression)?;
+        let policy = policy_with_predicates(py, policy, predicates)?;
+        let policy = self.policy_with_limits(py, policy)?;
+        let policy = policy.as_ref();
+        let settings = self.settings(binding, timeout)?;
+        if let Some(ops) = ops {
+            let mut records = execute_query_collect(
+                py,
+                &binding.client,
+                &binding.runtime,
+                stmt,
+                &settings,
+                policy,
+                "query",
+                &self.namespace,
+                &self.set_name,
+                &binding.connection_info,
+            )?;
+            apply_read_ops(
+                py,
+                &binding.client,
+                &binding.runtime,
+                &settings,
+                &mut records,
+                ops,
+            )?;
+            if let Some(dtype) = dtype {
+                let mut writer = crate::numpy_support::RecordNumpyWriter::new(dtype)?;
+                for record in &records {
+                    writer.push(record)?;
+                }
+                return writer.finish(py, dtype);
+            }
+            let py_records: Vec<Py<PyAny>> = records
+                .iter()
+                .map(|record| record_to_py(py, record, None))
+                .collect::<PyResult<_>>()?;
+            return Ok(PyList::new(py, &py_records)?.into_any().unbind());
+        }
+        if let Some(dtype) = dtype {
+            let mut writer = crate::numpy_support::RecordNumpyWriter::new(dtype)?;
+            execute_query_each(
+                py,
+                &binding.client,
+                &binding.runtime,
+                stmt,
+                &settings,
+                policy,
+                "query",
+                &self.namespace,
+                &self.set_name,
+                &binding.connection_info,
+                |record| writer.push(&record),
+            )?;
+            return writer.finish(py, dtype);
+        }
         execute_query(
             py,
-            &self.client,
+            &binding.client,
+            &binding.runtime,
             stmt,
+            &settings,
             policy,
             "query",
             &self.namespace,
             &self.set_name,
-            &self.connection_info,
+            &binding.connection_info,
         )
     }
 
+    /// Apply write `ops` to every matching record server-side and return the
+    /// background job's id, which `job_info(id, JOB_QUERY)` reports on.
+    ///
+    /// Records are not sent back; the call returns once every node has
+    /// accepted the job. Predicates beyond the index filter and
+    /// `filter_expression` limit which records are written.
+    #[pyo3(signature = (ops, policy=None, filter_expression=None, timeout=None))]
+    fn execute_background(
+        &self,
+        py: Python<'_>,
+        ops: &Bound<'_, PyList>,
+        policy: Option<&Bound<'_, PyDict>>,
+        filter_expression: Option<&Bound<'_, PyAny>>,
+        timeout: Option<f64>,
+    ) -> PyResult<u64> {
+        let binding = self.binding()?;
+        let deadline = CallDeadline::from_timeout(timeout)?;
+        if binding.event_loop_guard {
+            crate::runtime::forbid_running_loop(py)?;
+        }
+        let rust_ops = crate::operations::py_ops_to_rust(ops)?;
+        if rust_ops.is_empty() {
+            return Err(crate::errors::ParamError::new_err(
+                "execute_background() requires at least one operation",
+            ));
+        }
+        if rust_ops.iter().any(crate::operations::is_read_op) {
+            return Err(crate::errors::ParamError::new_err(
+                "execute_background() ops must be write operations",
+            ));
+        }
+        let (stmt, predicates) =
+            build_statement(&self.namespace, &self.set_name, &[], &self.predicates)?;
+        let policy = policy_with_filter(py, policy, filter_expression)?;
+        let policy = policy_with_predicates(py, policy, predicates)?;
+        let mut write_policy = parse_operate_policy(policy.as_ref(), None)?;
+        crate::operations::apply_record_op_options(
+            ops,
+            &mut write_policy.expiration,
+            &mut write_policy.durable_delete,
+        )?;
+        debug!(
+            "Starting background query on {}.{} with {} ops",
+            self.namespace,
+            self.set_name,
+            rust_ops.len()
+        );
+
+        let client = binding.client.clone();
+        let timer = crate::metrics::OperationTimer::start(
+            "query_background",
+            &self.namespace,
+            &self.set_name,
+        );
+        let result = catch_panic_sync("Query.execute_background", || {
+            Ok(py.detach(|| {
+                binding.runtime.block_on(before_deadline(
+                    "Query.execute_background",
+                    deadline,
+                    client.query_operate(&write_policy, stmt, &rust_ops),
+                ))
+            }))
+        })?;
+        match &result {
+            Ok(_) => timer.finish(""),
+            Err(e) => timer.finish(&crate::metrics::error_type_from_aerospike_error(e)),
+        }
+        Ok(result.map_err(as_to_pyerr)?.task_id())
+    }
+
+    /// Execute the query and return all results as a `pyarrow.RecordBatch`.
+    ///
+    /// Requires the `arrow` feature and `pyarrow` at runtime.
+    #[pyo3(signature = (policy=None, filter_expression=None, timeout=None))]
+    fn results_arrow(
+        &self,
+        py: Python<'_>,
+        policy: Option<&Bound<'_, PyDict>>,
+        filter_expression: Option<&Bound<'_, PyAny>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Py<PyAny>> {
+        let binding = self.binding()?;
+        let (stmt, predicates) = build_statement(
+            &self.namespace,
+            &self.set_name,
+            &self.bins,
+            &self.predicates,
+        )?;
+        let policy = policy_with_filter(py, policy, filter_expression)?;
+        let policy = policy_with_predicates(py, policy, predicates)?;
+        let policy = self.policy_with_limits(py, policy)?;
+        let policy = policy.as_ref();
+        let settings = self.settings(binding, timeout)?;
+        let records = execute_query_collect(
+            py,
+            &binding.client,
+            &binding.runtime,
+            stmt,
+            &settings,
+            policy,
+            "query",
+            &self.namespace,
+            &self.set_name,
+            &binding.connection_info,
+        )?;
+        debug!("query returned {} records", records.len());
+        crate::arrow_support::records_to_arrow_py(py, &records, &self.columns)
+    }
+
+    /// Execute the query and return `(None, columns)` DataFrame column buffers.
+    #[pyo3(signature = (policy=None, filter_expression=None, timeout=None))]
+    fn results_columns(
+        &self,
+        py: Python<'_>,
+        policy: Option<&Bound<'_, PyDict>>,
+        filter_expression: Option<&Bound<'_, PyAny>>,
+        timeout: Option<f64>,
+    ) -> PyResult<Py<PyAny>> {
+        let binding = self.binding()?;
+        let (stmt, predicates) = build_statement(
+            &self.namespace,
+            &self.set_name,
+            &self.bins,
+            &self.predicates,
+        )?;
+        let policy = policy_with_filter(py, policy, filter_expression)?;
+        let policy = policy_with_predicates(py, policy, predicates)?;
+        let policy = self.policy_with_limits(py, policy)?;
+        let policy = policy.as_ref();
+        let settings = self.settings(binding, timeout)?;
+        let records = execute_query_collect(
+            py,
+            &binding.client,
+            &binding.runtime,
+            stmt,
+            &settings,
+            policy,
+            "query",
+            &self.namespace,
+            &self.set_name,
+            &binding.connection_info,
+        )?;
+        debug!("query returned {} records", records.len());
+        crate::pandas_support::records_to_columns_py(py, &records, &self.columns)
+    }
+
     /// Execute the query and call callback for each record.
-    #[pyo3(signature = (callback, policy=None))]
+    #[pyo3(signature = (callback, policy=None, filter_expression=None, timeout=None))]
     fn foreach(
         &self,
         py: Python<'_>,
         callback: &Bound<'_, PyAny>,
         policy: Option<&Bound<'_, PyDict>>,
+        filter_expression: Option<&Bound<'_, PyAny>>,
+        timeout: Option<f64>,
     ) -> PyResult<()> {
-        let stmt = build_statement(
+        let binding = self.binding()?;
+        let (stmt, predicates) = build_statement(
             &self.namespace,
             &self.set_name,
             &self.bins,
             &self.predicates,
         )?;
+        let policy = policy_with_filter(py, policy, filter_expression)?;
+        let policy = policy_with_predicates(py, policy, predicates)?;
+        let policy = self.policy_with_limits(py, policy)?;
+        let policy = policy.as_ref();
+        let settings = self.settings(binding, timeout)?;
         execute_foreach(
             py,
-            &self.client,
+            &binding.client,
+            &binding.runtime,
             stmt,
+            &settings,
             callback,
             policy,
             "query",
             &self.namespace,
             &self.set_name,
-            &self.connection_info,
+            &binding.connection_info,
         )
     }
 }
@@ -1,14 +1,13 @@
 //! Helpers for converting Aerospike records and batch results to Python objects.
 
-use aerospike_core::{Error as AsError, Record, ResultCode};
+use aerospike_core::{Error as AsError, Record, ResultCode, Value};
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::errors::as_to_pyerr;
-use crate::types::key::key_to_py;
-use crate::types::record::record_to_py_with_key;
+use crate::types::record::{raw_record_to_py_with_key, record_into_py_with_key};
 use crate::types::value::value_to_py;
 
 /// Extract the TTL from a Record as seconds (u32).
@@ -29,12 +28,40 @@ pub fn ttl_from_duration(ttl: Option<Duration>) -> u32 {
     }
 }
 
-/// Extract meta dict from a Record.
-pub fn record_to_meta(py: Python<'_>, record: &aerospike_core::Record) -> PyResult<Py<PyAny>> {
+/// Absolute expiry of a Record in seconds since the Unix epoch.
+///
+/// Returns `0` when the record never expires.
+pub fn record_void_time(record: &aerospike_core::Record) -> u64 {
+    void_time_from_ttl(record.time_to_live(), SystemTime::now())
+}
+
+/// Convert a remaining TTL, measured at `now`, to an absolute Unix expiry.
+///
+/// `None` (never-expire) maps to `0`, the server's own void-time convention.
+pub fn void_time_from_ttl(ttl: Option<Duration>, now: SystemTime) -> u64 {
+    match ttl {
+        Some(duration) => (now + duration)
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        None => 0,
+    }
+}
+
+/// Build the meta dict `{"gen", "ttl", "void_time"}` for a Record.
+pub fn record_meta_dict<'py>(
+    py: Python<'py>,
+    record: &aerospike_core::Record,
+) -> PyResult<Bound<'py, PyDict>> {
     let meta = PyDict::new(py);
     meta.set_item(intern!(py, "gen"), record.generation)?;
     meta.set_item(intern!(py, "ttl"), record_ttl_seconds(record))?;
-    Ok(meta.into_any().unbind())
+    meta.set_item(intern!(py, "void_time"), record_void_time(record))?;
+    Ok(meta)
+}
+
+/// Extract meta dict from a Record.
+pub fn record_to_meta(py: Python<'_>, record: &aerospike_core::Record) -> PyResult<Py<PyAny>> {
+    Ok(record_meta_dict(py, record)?.into_any().unbind())
 }
 
 // ── Deferred conversion types for async client ─────────────────────
@@ -57,7 +84,57 @@ impl<'py> IntoPyObject<'py> for PendingRecord {
     type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        record_to_py_with_key(py, &self.record, self.key_py).map(|obj| obj.into_bound(py))
+        record_into_py_with_key(py, self.record, self.key_py).map(|obj| obj.into_bound(py))
+    }
+}
+
+/// Deferred raw record → Python conversion for `get_raw`.
+pub struct PendingRawRecord {
+    pub record: Record,
+    pub key_py: Py<PyAny>,
+}
+
+impl<'py> IntoPyObject<'py> for PendingRawRecord {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        raw_record_to_py_with_key(py, &self.record, self.key_py).map(|obj| obj.into_bound(py))
+    }
+}
+
+/// Deferred bin value → Python conversion for `append`, `prepend` and
+/// `increment`: the bin's value with `return_value=True`, `None` otherwise.
+pub struct PendingValue(pub Option<Value>);
+
+impl<'py> IntoPyObject<'py> for PendingValue {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self.0 {
+            Some(value) => value_to_py(py, &value).map(|obj| obj.into_bound(py)),
+            None => Ok(py.None().into_bound(py)),
+        }
+    }
+}
+
+/// Deferred meta → Python conversion for `put`: the record's meta dict with
+/// `return_meta=True`, `None` otherwise.
+pub struct PendingMeta(pub Option<Record>);
+
+impl<'py> IntoPyObject<'py> for PendingMeta {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        match self.0 {
+            Some(record) => Ok(record_meta_dict(py, &record)?.into_any()),
+            None => Ok(py.None().into_bound(py)),
+        }
     }
 }
 
@@ -104,10 +181,7 @@ impl<'py> IntoPyObject<'py> for PendingOrderedRecord {
     type Error = PyErr;
 
     fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
-        let key_py = match &self.record.key {
-            Some(k) => key_to_py(py, k)?,
-            None => self.key_py,
-        };
+        let key_py = self.key_py;
         let meta = record_to_meta(py, &self.record)?;
         let bin_items: Vec<Py<PyAny>> = self
             .record
@@ -132,8 +206,8 @@ impl<'py> IntoPyObject<'py> for PendingOrderedRecord {
 
 #[cfg(test)]
 mod tests {
-    use super::ttl_from_duration;
-    use std::time::Duration;
+    use super::{ttl_from_duration, void_time_from_ttl};
+    use std::time::{Duration, UNIX_EPOCH};
 
     #[test]
     fn ttl_from_duration_none_maps_to_never_expire() {
@@ -145,6 +219,21 @@ mod tests {
         assert_eq!(ttl_from_duration(Some(Duration::from_secs(123))), 123_u32);
     }
 
+    #[test]
+    fn void_time_from_ttl_adds_ttl_to_now() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(
+            void_time_from_ttl(Some(Duration::from_secs(300)), now),
+            1_700_000_300
+        );
+    }
+
+    #[test]
+    fn void_time_from_ttl_never_expire_is_zero() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        assert_eq!(void_time_from_ttl(None, now), 0);
+    }
+
     #[test]
     fn ttl_from_duration_clamps_above_u32_max() {
         let overflow = u32::MAX as u64 + 42;
@@ -11,23 +11,60 @@
 //!    contention.
 //!
 //! Both default to 2 worker threads (configurable via `AEROSPIKE_RUNTIME_WORKERS`).
+//!
 //! Fewer Tokio workers means fewer threads competing for the GIL after async I/O
 //! completes, which significantly reduces contention under high concurrency.
 //!
+//! A sync client configured with `{"rust_runtime": {"dedicated": True}}` instead
+//! gets its own multi-threaded runtime ([`ClientRuntime::Dedicated`]), so a busy
+//! client cannot starve others in the same process.
+//!
+//! # Fork safety
+//!
+//! Worker threads do not survive `os.fork()`, so a child process that inherits
+//! a started runtime would hang on its first `block_on`. [`RUNTIME`] remembers
+//! the process that built it and builds a fresh runtime in a forked child. The
+//! inherited runtime is leaked, never dropped: dropping it would deregister
+//! sockets from an epoll instance the parent still uses. The `pyo3-async-runtimes`
+//! runtime cannot be replaced; [`check_async_runtime_fork`] reports that case.
+//!
+//! # Introspection
+//!
+//! [`runtime_stats`] reports worker count, alive tasks and global queue depth
+//! of both shared runtimes, plus how many threads are blocked in a sync
+//! client's `block_on`. A sync `Client` called from inside an asyncio loop
+//! shows up there as a blocked thread while the loop itself makes no progress.
+//!
+//! # Event loop guard
+//!
+//! A sync client call blocks its thread until the command completes. Made
+//! from a coroutine, it stalls the whole asyncio loop, so sync operations
+//! first call [`forbid_running_loop`] and raise `ClientError` instead
+//! (opt out with the `allow_blocking_in_event_loop` client config).
+//!
 //! # Why `panic!` instead of `Result`
 //!
-//! [`LazyLock<T>`] requires `T` (not `Result<T, E>`), so the initializer
-//! closure must return a valid `Runtime` or abort.  Runtime creation failure
+//! [`RUNTIME`] derefs to a `Runtime` (not `Result<Runtime, E>`), so building
+//! it must produce a valid `Runtime` or abort.  Runtime creation failure
 //! is an unrecoverable environment issue (e.g. OS thread-limit exhaustion)
 //! that cannot be meaningfully handled at the call-site, so panicking with a
 //! descriptive message is the appropriate strategy here.
 
-use std::sync::LazyLock;
+use std::future::Future;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 
+use arc_swap::ArcSwapOption;
 use log::{info, warn};
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::PyDict;
+
+use crate::errors::{ClientError, ForkError};
 
 /// Maximum allowed worker threads to prevent accidental resource exhaustion.
-const MAX_WORKERS: usize = 32;
+pub const MAX_WORKERS: usize = 32;
 
 /// Read the configured worker count from `AEROSPIKE_RUNTIME_WORKERS` env var.
 /// Defaults to 2, minimum 1, maximum [`MAX_WORKERS`].
@@ -55,9 +92,71 @@ fn configured_workers() -> usize {
 /// cooperative scheduling. This minimizes CPU overhead from native threads, which is
 /// important when colocated with CPU-intensive workloads (e.g. PyTorch inference).
 ///
+/// Built on first use, and again in each forked child (see the module docs).
+pub static RUNTIME: SharedRuntime = SharedRuntime {
+    current: ArcSwapOption::const_empty(),
+    init: Mutex::new(()),
+};
+
+/// The shared runtime of one process.
+struct ProcessRuntime {
+    pid: u32,
+    runtime: &'static tokio::runtime::Runtime,
+}
+
+/// Lazily built, fork-aware runtime behind [`RUNTIME`].
+pub struct SharedRuntime {
+    current: ArcSwapOption<ProcessRuntime>,
+    /// Serializes building so concurrent first calls create one runtime.
+    init: Mutex<()>,
+}
+
+impl SharedRuntime {
+    /// The runtime of this process, if it was already built.
+    fn started(&self) -> Option<&'static tokio::runtime::Runtime> {
+        let pid = std::process::id();
+        self.current
+            .load()
+            .as_ref()
+            .filter(|current| current.pid == pid)
+            .map(|current| current.runtime)
+    }
+
+    fn get(&self) -> &'static tokio::runtime::Runtime {
+        let pid = std::process::id();
+        if let Some(current) = self.current.load().as_ref() {
+            if current.pid == pid {
+                return current.runtime;
+            }
+        }
+        let _init = self.init.lock().unwrap_or_else(PoisonError::into_inner);
+        let inherited = match self.current.load_full() {
+            Some(current) if current.pid == pid => return current.runtime,
+            Some(_) => true,
+            None => false,
+        };
+        if inherited {
+            warn!("os.fork() detected: starting a new sync Tokio runtime in process {pid}");
+        }
+        // Leaked so that neither this runtime nor an inherited one is ever dropped.
+        let runtime: &'static _ = Box::leak(Box::new(build_shared_runtime()));
+        self.current
+            .store(Some(Arc::new(ProcessRuntime { pid, runtime })));
+        runtime
+    }
+}
+
+impl Deref for SharedRuntime {
+    type Target = tokio::runtime::Runtime;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
 /// Uses `enable_io()` + `enable_time()` instead of `enable_all()` to avoid the
 /// signal driver, which can conflict with Python's own signal handling.
-pub static RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
+fn build_shared_runtime() -> tokio::runtime::Runtime {
     let workers = configured_workers();
 
     info!("Initializing sync Tokio runtime with {} workers", workers);
@@ -82,11 +181,11 @@ pub static RUNTIME: LazyLock<tokio::runtime::Runtime> = LazyLock::new(|| {
                  2. Check thread limits — ulimit -u  (nproc)\n\
                  3. On Linux containers, verify /proc/sys/kernel/threads-max\n\
                  \n\
-                 This panic is intentional: LazyLock<Runtime> cannot propagate \
-                 errors, and a missing Tokio runtime is unrecoverable."
+                 This panic is intentional: RUNTIME derefs to a Runtime and cannot \
+                 propagate errors, and a missing Tokio runtime is unrecoverable."
             )
         })
-});
+}
 
 /// Configure the `pyo3-async-runtimes` Tokio runtime used by `AsyncClient`.
 ///
@@ -107,3 +206,254 @@ pub fn init_async_runtime() {
     builder.worker_threads(workers).enable_all();
     pyo3_async_runtimes::tokio::init(builder);
 }
+
+/// Process that started the `pyo3-async-runtimes` runtime (`0` = not yet).
+static ASYNC_RUNTIME_PID: AtomicU32 = AtomicU32::new(0);
+
+fn async_fork_error(owner: u32, pid: u32) -> pyo3::PyErr {
+    ForkError::new_err(format!(
+        "AsyncClient was used in process {owner} before os.fork(); the async runtime \
+         does not survive a fork. Use AsyncClient only in processes that did not \
+         inherit it (this is pid {pid})."
+    ))
+}
+
+/// Claim the async runtime for this process (on `AsyncClient.connect()`), or
+/// raise `ForkError` when a parent started it before `os.fork()`: its worker
+/// threads are gone and every awaitable would hang.
+pub fn claim_async_runtime() -> PyResult<()> {
+    let pid = std::process::id();
+    match ASYNC_RUNTIME_PID.compare_exchange(0, pid, Ordering::AcqRel, Ordering::Acquire) {
+        Ok(_) => Ok(()),
+        Err(owner) if owner == pid => Ok(()),
+        Err(owner) => Err(async_fork_error(owner, pid)),
+    }
+}
+
+/// Raise `ForkError` when the async runtime was started by a parent process.
+pub fn check_async_runtime_fork() -> PyResult<()> {
+    let owner = ASYNC_RUNTIME_PID.load(Ordering::Acquire);
+    if async_runtime_forked() {
+        return Err(async_fork_error(owner, std::process::id()));
+    }
+    Ok(())
+}
+
+/// `true` when the async runtime was started by a parent of this process.
+pub fn async_runtime_forked() -> bool {
+    let owner = ASYNC_RUNTIME_PID.load(Ordering::Acquire);
+    owner != 0 && owner != std::process::id()
+}
+
+/// Runtime owned by a single client.
+///
+/// Dropped with `shutdown_background()` so the last `Client` / `Query`
+/// reference never blocks (with the GIL held) on worker threads that may be
+/// waiting for the GIL themselves, e.g. to emit a log record.
+pub struct DedicatedRuntime(Option<tokio::runtime::Runtime>);
+
+impl Drop for DedicatedRuntime {
+    fn drop(&mut self) {
+        if let Some(rt) = self.0.take() {
+            rt.shutdown_background();
+        }
+    }
+}
+
+/// The runtime a sync client and its queries block on.
+#[derive(Clone, Default)]
+pub enum ClientRuntime {
+    /// The process-wide [`RUNTIME`].
+    #[default]
+    Shared,
+    /// A runtime created for one client by `rust_runtime.dedicated`.
+    Dedicated(Arc<DedicatedRuntime>),
+}
+
+impl ClientRuntime {
+    /// Build a dedicated multi-threaded runtime with `workers` worker threads.
+    pub fn dedicated(workers: usize) -> std::io::Result<Self> {
+        info!("Initializing dedicated client Tokio runtime with {workers} workers");
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(workers)
+            .thread_name("aerospike-py-client")
+            .enable_io()
+            .enable_time()
+            .build()?;
+        Ok(ClientRuntime::Dedicated(Arc::new(DedicatedRuntime(Some(
+            rt,
+        )))))
+    }
+
+    /// Default worker count for a dedicated runtime (`AEROSPIKE_RUNTIME_WORKERS`).
+    pub fn default_workers() -> usize {
+        configured_workers()
+    }
+
+    pub fn is_dedicated(&self) -> bool {
+        matches!(self, ClientRuntime::Dedicated(_))
+    }
+
+    fn runtime(&self) -> &tokio::runtime::Runtime {
+        match self {
+            ClientRuntime::Shared => &RUNTIME,
+            ClientRuntime::Dedicated(rt) => {
+                rt.0.as_ref()
+                    .expect("dedicated runtime is only taken on drop")
+            }
+        }
+    }
+
+    /// Handle for spawning background tasks on this runtime.
+    pub fn handle(&self) -> tokio::runtime::Handle {
+        self.runtime().handle().clone()
+    }
+
+    /// Run a future to completion on this runtime.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        let _blocked = BlockedThread::enter();
+        self.runtime().block_on(future)
+    }
+}
+
+/// Raise `ClientError` when the calling thread runs an asyncio event loop.
+///
+/// One call to `asyncio._get_running_loop()`, which only reads the loop
+/// stored for the current thread.
+pub fn forbid_running_loop(py: Python<'_>) -> PyResult<()> {
+    static GET_RUNNING_LOOP: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+    let running = GET_RUNNING_LOOP
+        .import(py, "asyncio", "_get_running_loop")?
+        .call0()?;
+    if running.is_none() {
+        return Ok(());
+    }
+    Err(ClientError::new_err(
+        "Sync Client called from a running asyncio event loop: the call would block \
+         the loop until it completes. Use AsyncClient in async code, or run the call \
+         in a worker thread (asyncio.to_thread). Set allow_blocking_in_event_loop=True \
+         in the client config to allow it anyway.",
+    ))
+}
+
+/// Threads currently inside [`ClientRuntime::block_on`].
+static BLOCKED_THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// [`ClientRuntime::block_on`] calls since process start.
+static BLOCK_ON_CALLS: AtomicU64 = AtomicU64::new(0);
+
+/// Counts a thread as blocked until dropped, including on panic.
+struct BlockedThread;
+
+impl BlockedThread {
+    fn enter() -> Self {
+        BLOCK_ON_CALLS.fetch_add(1, Ordering::Relaxed);
+        BLOCKED_THREADS.fetch_add(1, Ordering::Relaxed);
+        BlockedThread
+    }
+}
+
+impl Drop for BlockedThread {
+    fn drop(&mut self) {
+        BLOCKED_THREADS.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// `{"workers", "alive_tasks", "queued_tasks"}` of one runtime.
+fn runtime_metrics<'py>(
+    py: Python<'py>,
+    runtime: &tokio::runtime::Runtime,
+) -> PyResult<Bound<'py, PyDict>> {
+    let metrics = runtime.metrics();
+    let dict = PyDict::new(py);
+    dict.set_item("workers", metrics.num_workers())?;
+    dict.set_item("alive_tasks", metrics.num_alive_tasks())?;
+    dict.set_item("queued_tasks", metrics.global_queue_depth())?;
+    Ok(dict)
+}
+
+/// Snapshot of the shared Tokio runtimes and of sync `block_on` calls.
+///
+/// `sync_runtime` / `async_runtime` are `None` until the runtime is started in this process
+/// (first sync operation / `AsyncClient.connect()`). Dedicated client
+/// runtimes are not listed, but their `block_on` calls are counted.
+#[pyfunction]
+pub fn runtime_stats(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let stats = PyDict::new(py);
+    let sync = RUNTIME
+        .started()
+        .map(|rt| runtime_metrics(py, rt))
+        .transpose()?;
+    stats.set_item("sync_runtime", sync)?;
+    let claimed = ASYNC_RUNTIME_PID.load(Ordering::Acquire) == std::process::id();
+    let async_rt = claimed
+        .then(|| runtime_metrics(py, pyo3_async_runtimes::tokio::get_runtime()))
+        .transpose()?;
+    stats.set_item("async_runtime", async_rt)?;
+    stats.set_item("blocked_threads", BLOCKED_THREADS.load(Ordering::Relaxed))?;
+    stats.set_item("block_on_calls", BLOCK_ON_CALLS.load(Ordering::Relaxed))?;
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedicated_runtime_runs_on_its_own_threads() {
+        let rt = ClientRuntime::dedicated(1).unwrap();
+        assert!(rt.is_dedicated());
+        let name = rt.block_on(async {
+            tokio::spawn(async { std::thread::current().name().map(str::to_owned) })
+                .await
+                .unwrap()
+        });
+        assert_eq!(name.as_deref(), Some("aerospike-py-client"));
+    }
+
+    #[test]
+    fn shared_is_default() {
+        assert!(!ClientRuntime::default().is_dedicated());
+        assert_eq!(ClientRuntime::Shared.block_on(async { 1 + 1 }), 2);
+    }
+
+    #[test]
+    fn block_on_counts_blocked_threads() {
+        let calls = BLOCK_ON_CALLS.load(Ordering::Relaxed);
+        let blocked =
+            ClientRuntime::Shared.block_on(async { BLOCKED_THREADS.load(Ordering::Relaxed) });
+        assert!(blocked >= 1);
+        assert!(BLOCK_ON_CALLS.load(Ordering::Relaxed) > calls);
+    }
+
+    #[test]
+    fn forbid_running_loop_rejects_thread_with_running_loop() {
+        Python::initialize();
+        Python::attach(|py| {
+            assert!(forbid_running_loop(py).is_ok());
+
+            let asyncio = py.import("asyncio").unwrap();
+            let event_loop = asyncio.call_method0("new_event_loop").unwrap();
+            asyncio
+                .call_method1("_set_running_loop", (&event_loop,))
+                .unwrap();
+            let err = forbid_running_loop(py).expect_err("running loop must be rejected");
+            asyncio
+                .call_method1("_set_running_loop", (py.None(),))
+                .unwrap();
+            event_loop.call_method0("close").unwrap();
+
+            assert!(err.is_instance_of::<ClientError>(py));
+            assert!(err.to_string().contains("AsyncClient"));
+        });
+    }
+
+    #[test]
+    fn shared_runtime_is_built_once_per_process() {
+        let first: *const tokio::runtime::Runtime = &*RUNTIME;
+        let second: *const tokio::runtime::Runtime = &*RUNTIME;
+        assert_eq!(first, second);
+        assert!(!async_runtime_forked());
+        assert!(check_async_runtime_fork().is_ok());
+    }
+}
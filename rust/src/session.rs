@@ -0,0 +1,109 @@
+//! Re-authentication of pooled connections.
+//!
+//! `aerospike_core` keeps no login session token: every pooled connection logs
+//! in with the configured credentials when it is opened and reuses that login
+//! for its whole lifetime. Re-authenticating therefore means retiring the idle
+//! pooled connections so the next commands open fresh, freshly logged-in ones.
+//!
+//! [`SessionRefresh`] does this every `refresh_seconds` so no connection
+//! outlives the server's session TTL, and `_relogin()` does it on demand after
+//! a command fails with `SessionExpired`.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use aerospike_core::Client as AsClient;
+use log::{debug, info};
+use tokio::runtime::Handle;
+use tokio::task::JoinHandle;
+
+/// Close the idle pooled connections of every node; returns how many were closed.
+///
+/// Connections checked out by in-flight commands are left alone. Each pool
+/// queue is reached through a single node checkout and then drained with
+/// `Queue::get`, which never opens a connection. A node checkout only opens
+/// (and logs in) a new connection when none of the node's queues has an idle
+/// one; a node is abandoned as soon as a checkout finds its queue empty, so at
+/// most one connection per node is opened, and only once nothing idle is left.
+/// At most `max_conns_per_node` connections are retired per node, so
+/// connections returned to the pool while this runs cannot keep the loop going.
+pub async fn recycle_connections(client: &AsClient) -> usize {
+    let policy = client.cluster.client_policy();
+    let pools = policy.conn_pools_per_node.max(1);
+    let limit = policy.max_conns_per_node;
+    let mut recycled = 0;
+    for node in client.nodes() {
+        let mut node_recycled = 0;
+        for hint in 0..pools {
+            if node_recycled >= limit {
+                break;
+            }
+            let Ok(mut conn) = node.get_connection(hint).await else {
+                break;
+            };
+            let queue = conn.queue.clone();
+            let idle_left = queue.num_conns();
+            conn.invalidate();
+            drop(conn);
+            node_recycled += 1;
+            if idle_left == 0 {
+                break;
+            }
+            while node_recycled < limit {
+                let Ok(mut conn) = queue.get() else {
+                    break;
+                };
+                conn.invalidate();
+                node_recycled += 1;
+            }
+        }
+        debug!(
+            "Recycled {} connection(s) on node {}",
+            node_recycled,
+            node.name()
+        );
+        recycled += node_recycled;
+    }
+    recycled
+}
+
+/// Periodic re-authentication task, shared by a client's `connect()` and `close()`.
+#[derive(Clone, Default)]
+pub struct SessionRefresh(Arc<Mutex<Option<JoinHandle<()>>>>);
+
+impl SessionRefresh {
+    fn task(&self) -> std::sync::MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// (Re)start the refresh task for `client`; `None` leaves it stopped.
+    pub fn start(&self, client: Arc<AsClient>, handle: &Handle, interval: Option<Duration>) {
+        let mut task = self.task();
+        if let Some(old) = task.take() {
+            old.abort();
+        }
+        let Some(interval) = interval else {
+            return;
+        };
+        debug!("Starting session refresh: interval={:?}", interval);
+        *task = Some(handle.spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let recycled = recycle_connections(&client).await;
+                info!("Session refresh: re-authenticating {recycled} pooled connection(s)");
+            }
+        }));
+    }
+
+    /// Stop the refresh task.
+    pub fn stop(&self) {
+        if let Some(task) = self.task().take() {
+            task.abort();
+        }
+    }
+
+    /// Discard a task inherited across `os.fork()` without aborting it.
+    pub fn forget_task(&self) {
+        std::mem::forget(self.task().take());
+    }
+}
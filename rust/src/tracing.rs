@@ -2,14 +2,23 @@
 //
 // When the `otel` feature is enabled, this module provides:
 // - Lazy initialization of an OTLP trace exporter (controlled by OTEL_* env vars)
+// - An OTLP metric exporter for `db.client.operation.duration`, with the
+//   Prometheus histogram carrying trace IDs as exemplars
 // - W3C TraceContext propagation from Python → Rust
 // - `traced_op!` macro that wraps each DB operation in a span **and** records metrics
+// - Request/response payload attributes and a `retry` event on client-side retries
+// - `init_tracing()` options: head sampling ratio, service name and span name template
 //
 // When the `otel` feature is disabled, `traced_op!` falls back to `timed_op!`.
 
 use std::borrow::Cow;
 use std::sync::Arc;
 
+use aerospike_core::{BatchRecord, Bin, Record, Value};
+use arc_swap::ArcSwapOption;
+
+use crate::errors::ParamError;
+
 /// Connection metadata attached to every OTel span and used for metric labels.
 ///
 /// Populated during `connect()` from the first host in the config.
@@ -70,6 +79,283 @@ pub fn op_to_upper(op: &str) -> Cow<'static, str> {
     }
 }
 
+// ── Span naming ─────────────────────────────────────────────────────────────
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SpanNameSegment {
+    Literal(String),
+    Operation,
+    Namespace,
+    Set,
+}
+
+/// Parsed `span_name_format` template with `{operation}`, `{namespace}` and
+/// `{set}` placeholders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpanNameFormat(Vec<SpanNameSegment>);
+
+impl SpanNameFormat {
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut rest = template;
+        while let Some(open) = rest.find(['{', '}']) {
+            if rest[open..].starts_with('}') {
+                return Err(format!("unmatched '}}' in span_name_format {template:?}"));
+            }
+            if open > 0 {
+                segments.push(SpanNameSegment::Literal(rest[..open].to_string()));
+            }
+            let close = rest[open..]
+                .find('}')
+                .ok_or_else(|| format!("unclosed '{{' in span_name_format {template:?}"))?;
+            segments.push(match &rest[open + 1..open + close] {
+                "operation" => SpanNameSegment::Operation,
+                "namespace" => SpanNameSegment::Namespace,
+                "set" => SpanNameSegment::Set,
+                other => {
+                    return Err(format!(
+                        "unknown placeholder {{{other}}} in span_name_format; \
+                         expected {{operation}}, {{namespace}} or {{set}}"
+                    ))
+                }
+            });
+            rest = &rest[open + close + 1..];
+        }
+        if !rest.is_empty() {
+            segments.push(SpanNameSegment::Literal(rest.to_string()));
+        }
+        if segments.is_empty() {
+            return Err("span_name_format must not be empty".to_string());
+        }
+        Ok(Self(segments))
+    }
+
+    pub fn render(&self, operation: &str, namespace: &str, set: &str) -> String {
+        let mut name = String::new();
+        for segment in &self.0 {
+            name.push_str(match segment {
+                SpanNameSegment::Literal(s) => s,
+                SpanNameSegment::Operation => operation,
+                SpanNameSegment::Namespace => namespace,
+                SpanNameSegment::Set => set,
+            });
+        }
+        name
+    }
+}
+
+/// Template set by `init_tracing(span_name_format=...)`; `None` is the default
+/// `{operation} {namespace}.{set}`.
+static SPAN_NAME_FORMAT: ArcSwapOption<SpanNameFormat> = ArcSwapOption::const_empty();
+
+/// Span name for an operation (`operation` is already uppercased).
+pub fn span_name(operation: &str, namespace: &str, set: &str) -> String {
+    match SPAN_NAME_FORMAT.load().as_deref() {
+        Some(format) => format.render(operation, namespace, set),
+        None => format!("{operation} {namespace}.{set}"),
+    }
+}
+
+/// Validate `init_tracing()` options and install the span name template.
+///
+/// Returns the sampling ratio to configure on the tracer provider.
+fn apply_tracing_options(
+    sample_ratio: Option<f64>,
+    span_name_format: Option<&str>,
+) -> pyo3::PyResult<Option<f64>> {
+    if let Some(ratio) = sample_ratio {
+        if !(0.0..=1.0).contains(&ratio) {
+            return Err(ParamError::new_err(format!(
+                "sample_ratio must be between 0.0 and 1.0, got {ratio}"
+            )));
+        }
+    }
+    let format = span_name_format
+        .map(SpanNameFormat::parse)
+        .transpose()
+        .map_err(ParamError::new_err)?;
+    SPAN_NAME_FORMAT.store(format.map(Arc::new));
+    Ok(sample_ratio)
+}
+
+// ── Payload statistics ──────────────────────────────────────────────────────
+
+/// Request or response payload summary recorded as span attributes.
+///
+/// Byte counts are estimates (bin names plus particle sizes, no protocol
+/// headers) because `aerospike_core` does not expose the wire size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PayloadStats {
+    /// Keys in the request, or records returned in the response.
+    pub keys: Option<usize>,
+    pub bins: Option<usize>,
+    pub bytes: Option<usize>,
+    /// 1-based attempt number on spans for a client-side retry.
+    pub retry_attempt: Option<u32>,
+}
+
+impl PayloadStats {
+    /// Single-record request without bin payload (reads, deletes, touches).
+    pub fn key() -> Self {
+        Self {
+            keys: Some(1),
+            ..Self::default()
+        }
+    }
+
+    /// Single-record request writing `bins`.
+    pub fn single(bins: &[Bin]) -> Self {
+        Self {
+            keys: Some(1),
+            bins: Some(bins.len()),
+            bytes: Some(
+                bins.iter()
+                    .map(|b| b.name.len() + value_size(&b.value))
+                    .sum(),
+            ),
+            retry_attempt: None,
+        }
+    }
+
+    /// Batch request over `keys` records.
+    pub fn batch(keys: usize) -> Self {
+        Self {
+            keys: Some(keys),
+            ..Self::default()
+        }
+    }
+
+    /// Batch write request over `(key, bins, policy)` records.
+    pub fn batch_write<K, P>(records: &[(K, Vec<Bin>, P)]) -> Self {
+        let mut stats = Self::batch(records.len());
+        stats.bins = Some(records.iter().map(|(_, bins, _)| bins.len()).sum());
+        stats.bytes = Some(
+            records
+                .iter()
+                .flat_map(|(_, bins, _)| bins)
+                .map(|b| b.name.len() + value_size(&b.value))
+                .sum(),
+        );
+        stats
+    }
+
+    pub fn with_retry(mut self, attempt: u32) -> Self {
+        self.retry_attempt = Some(attempt);
+        self
+    }
+}
+
+/// Estimated serialized size of a value in bytes.
+pub fn value_size(value: &Value) -> usize {
+    match value {
+        Value::Nil | Value::Infinity | Value::Wildcard => 0,
+        Value::Bool(_) => 1,
+        Value::Int(_) | Value::Float(_) => 8,
+        Value::String(s) => s.len(),
+        Value::GeoJSON(s) => 3 + s.len(),
+        Value::Blob(b) | Value::HLL(b) => b.len(),
+        // msgpack: one header byte per element plus a collection header
+        Value::List(items) | Value::MultiResult(items) => {
+            1 + items.iter().map(|v| 1 + value_size(v)).sum::<usize>()
+        }
+        Value::HashMap(map) => {
+            1 + map
+                .iter()
+                .map(|(k, v)| 2 + value_size(k) + value_size(v))
+                .sum::<usize>()
+        }
+        Value::OrderedMap(map) => {
+            1 + map
+                .iter()
+                .map(|(k, v)| 2 + value_size(k) + value_size(v))
+                .sum::<usize>()
+        }
+        Value::KeyValueList(pairs) => {
+            1 + pairs
+                .iter()
+                .map(|(k, v)| 2 + value_size(k) + value_size(v))
+                .sum::<usize>()
+        }
+    }
+}
+
+fn record_size(record: &Record) -> usize {
+    record
+        .bins
+        .iter()
+        .map(|(name, v)| name.len() + value_size(v))
+        .sum()
+}
+
+/// Response payload of a traced operation's successful result.
+pub trait ResponseStats {
+    fn response_stats(&self) -> PayloadStats;
+}
+
+impl ResponseStats for () {
+    fn response_stats(&self) -> PayloadStats {
+        PayloadStats::default()
+    }
+}
+
+impl ResponseStats for bool {
+    fn response_stats(&self) -> PayloadStats {
+        PayloadStats::default()
+    }
+}
+
+/// The bin value returned by `append` / `prepend` / `increment` with
+/// `return_value=True`.
+impl ResponseStats for Option<Value> {
+    fn response_stats(&self) -> PayloadStats {
+        match self {
+            Some(value) => PayloadStats {
+                keys: Some(1),
+                bins: Some(1),
+                bytes: Some(value_size(value)),
+                retry_attempt: None,
+            },
+            None => PayloadStats::default(),
+        }
+    }
+}
+
+/// The record header returned by `put` with `return_meta=True`.
+impl ResponseStats for Option<Record> {
+    fn response_stats(&self) -> PayloadStats {
+        match self {
+            Some(record) => record.response_stats(),
+            None => PayloadStats::default(),
+        }
+    }
+}
+
+impl ResponseStats for Record {
+    fn response_stats(&self) -> PayloadStats {
+        PayloadStats {
+            keys: Some(1),
+            bins: Some(self.bins.len()),
+            bytes: Some(record_size(self)),
+            retry_attempt: None,
+        }
+    }
+}
+
+impl ResponseStats for Vec<BatchRecord> {
+    fn response_stats(&self) -> PayloadStats {
+        let records = self.iter().filter_map(|br| br.record.as_ref());
+        let (found, bins, bytes) = records.fold((0, 0, 0), |(n, b, s), r| {
+            (n + 1, b + r.bins.len(), s + record_size(r))
+        });
+        PayloadStats {
+            keys: Some(found),
+            bins: Some(bins),
+            bytes: Some(bytes),
+            retry_attempt: None,
+        }
+    }
+}
+
 // ── Feature-gated implementation ────────────────────────────────────────────
 
 #[cfg(feature = "otel")]
@@ -79,11 +365,13 @@ pub(crate) mod otel_impl {
     use std::sync::{LazyLock, Mutex, OnceLock};
 
     use log::warn;
+    use opentelemetry::metrics::Histogram;
     use opentelemetry::propagation::TextMapPropagator;
-    use opentelemetry::trace::Status;
+    use opentelemetry::trace::{Status, TraceContextExt};
     use opentelemetry::{global, Context, KeyValue};
+    use opentelemetry_sdk::metrics::SdkMeterProvider;
     use opentelemetry_sdk::propagation::TraceContextPropagator;
-    use opentelemetry_sdk::trace::SdkTracerProvider;
+    use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
     use opentelemetry_sdk::Resource;
     use pyo3::intern;
     use pyo3::prelude::*;
@@ -98,6 +386,16 @@ pub(crate) mod otel_impl {
     /// Avoids Python calls and OTel span creation when tracing is not active.
     static OTEL_ACTIVE: AtomicBool = AtomicBool::new(false);
 
+    /// Global meter provider – set by [`init_meter_provider`].
+    static METER_PROVIDER: LazyLock<Mutex<Option<SdkMeterProvider>>> =
+        LazyLock::new(|| Mutex::new(None));
+
+    /// `db.client.operation.duration` instrument, built with the first meter provider.
+    static OP_DURATION: OnceLock<Histogram<f64>> = OnceLock::new();
+
+    /// Fast-path flag: true only while the meter provider is installed.
+    static OTEL_METRICS_ACTIVE: AtomicBool = AtomicBool::new(false);
+
     /// Cached Python module for `opentelemetry.propagate` (process-lifetime).
     static PROPAGATE_MODULE: OnceLock<Py<pyo3::types::PyModule>> = OnceLock::new();
 
@@ -107,6 +405,14 @@ pub(crate) mod otel_impl {
         OTEL_ACTIVE.load(Ordering::Acquire)
     }
 
+    /// Resource `service.name`: the `init_tracing()` argument, else
+    /// `OTEL_SERVICE_NAME`, else `aerospike-py`.
+    fn resolve_service_name(service_name: Option<&str>) -> String {
+        service_name.map(str::to_string).unwrap_or_else(|| {
+            std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "aerospike-py".to_string())
+        })
+    }
+
     /// Initialise the OTLP tracer provider.
     ///
     /// Respects the standard OTEL environment variables:
@@ -115,7 +421,11 @@ pub(crate) mod otel_impl {
     ///   OTEL_EXPORTER_OTLP_ENDPOINT     → gRPC endpoint (default localhost:4317)
     ///   OTEL_SERVICE_NAME               → resource service.name
     ///   … and many more (handled by the SDK / OTLP crate automatically)
-    pub fn init_tracer_provider() {
+    ///
+    /// `sample_ratio` installs a parent-based trace-ID ratio sampler: root
+    /// spans are kept with that probability and child spans follow the
+    /// caller's sampling decision.
+    pub fn init_tracer_provider(sample_ratio: Option<f64>, service_name: Option<&str>) {
         // Check kill-switches
         if std::env::var("OTEL_SDK_DISABLED")
             .map(|v| v.eq_ignore_ascii_case("true"))
@@ -147,15 +457,19 @@ pub(crate) mod otel_impl {
             }
         };
 
-        let service_name =
-            std::env::var("OTEL_SERVICE_NAME").unwrap_or_else(|_| "aerospike-py".to_string());
-
-        let resource = Resource::builder().with_service_name(service_name).build();
+        let resource = Resource::builder()
+            .with_service_name(resolve_service_name(service_name))
+            .build();
 
-        let provider = SdkTracerProvider::builder()
+        let mut builder = SdkTracerProvider::builder()
             .with_batch_exporter(exporter)
-            .with_resource(resource)
-            .build();
+            .with_resource(resource);
+        if let Some(ratio) = sample_ratio {
+            builder = builder.with_sampler(Sampler::ParentBased(Box::new(
+                Sampler::TraceIdRatioBased(ratio),
+            )));
+        }
+        let provider = builder.build();
 
         global::set_tracer_provider(provider.clone());
 
@@ -166,6 +480,120 @@ pub(crate) mod otel_impl {
         log::info!("OTel tracer provider initialised");
     }
 
+    /// Initialise the OTLP meter provider.
+    ///
+    /// Exports `db.client.operation.duration` (seconds, same bucket boundaries
+    /// as the Prometheus histogram) through the periodic OTLP exporter.
+    /// Respects `OTEL_SDK_DISABLED`, `OTEL_METRICS_EXPORTER=none` and
+    /// `OTEL_METRIC_EXPORT_INTERVAL`, plus the shared OTLP endpoint variables.
+    pub fn init_meter_provider(service_name: Option<&str>) {
+        if std::env::var("OTEL_SDK_DISABLED")
+            .map(|v| v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false)
+        {
+            return;
+        }
+        if std::env::var("OTEL_METRICS_EXPORTER")
+            .map(|v| v.eq_ignore_ascii_case("none"))
+            .unwrap_or(false)
+        {
+            log::info!("OTel metrics exporter set to none");
+            return;
+        }
+
+        let _rt_guard = crate::runtime::RUNTIME.enter();
+
+        let exporter = match opentelemetry_otlp::MetricExporter::builder()
+            .with_tonic()
+            .build()
+        {
+            Ok(exp) => exp,
+            Err(e) => {
+                warn!("Failed to create OTLP metric exporter: {e}. OTel metrics disabled.");
+                return;
+            }
+        };
+
+        let resource = Resource::builder()
+            .with_service_name(resolve_service_name(service_name))
+            .build();
+
+        let provider = SdkMeterProvider::builder()
+            .with_periodic_exporter(exporter)
+            .with_resource(resource)
+            .build();
+
+        global::set_meter_provider(provider.clone());
+        // Instruments are bound to the provider they were created from, so
+        // the histogram is built from the first provider only.
+        OP_DURATION.get_or_init(|| {
+            global::meter(INSTRUMENTATION_NAME)
+                .f64_histogram("db.client.operation.duration")
+                .with_unit("s")
+                .with_description("Duration of database client operations")
+                .with_boundaries(crate::metrics::op_buckets().to_vec())
+                .build()
+        });
+
+        let mut guard = METER_PROVIDER.lock().unwrap_or_else(|e| e.into_inner());
+        *guard = Some(provider);
+
+        OTEL_METRICS_ACTIVE.store(true, Ordering::Release);
+        log::info!("OTel meter provider initialised");
+    }
+
+    /// Record one operation on the OTel duration histogram, if metrics export is active.
+    #[inline]
+    pub fn record_operation_duration(
+        op_name: &str,
+        namespace: &str,
+        set_name: &str,
+        error_type: &str,
+        duration: f64,
+    ) {
+        if !OTEL_METRICS_ACTIVE.load(Ordering::Acquire) {
+            return;
+        }
+        let Some(histogram) = OP_DURATION.get() else {
+            return;
+        };
+        let mut attributes = vec![
+            KeyValue::new("db.system.name", "aerospike"),
+            KeyValue::new("db.namespace", namespace.to_string()),
+            KeyValue::new("db.collection.name", set_name.to_string()),
+            KeyValue::new("db.operation.name", op_name.to_string()),
+        ];
+        if !error_type.is_empty() {
+            attributes.push(KeyValue::new("error.type", error_type.to_string()));
+        }
+        histogram.record(duration, &attributes);
+    }
+
+    /// Trace ID of the span in `cx`, if it is valid and sampled.
+    ///
+    /// Used as the exemplar on the Prometheus operation histogram.
+    pub fn sampled_trace_id(cx: &Context) -> Option<String> {
+        let span = cx.span();
+        let span_context = span.span_context();
+        (span_context.is_valid() && span_context.is_sampled())
+            .then(|| span_context.trace_id().to_string())
+    }
+
+    /// Shut down the meter provider, exporting any pending measurements.
+    pub fn shutdown_meter_provider() {
+        OTEL_METRICS_ACTIVE.store(false, Ordering::Release);
+
+        let mut guard = METER_PROVIDER.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(provider) = guard.take() {
+            let _rt_guard = crate::runtime::RUNTIME.enter();
+            if let Err(e) = provider.shutdown() {
+                warn!("OTel meter provider shutdown error: {e}");
+            } else {
+                log::info!("OTel meter provider shut down");
+            }
+        }
+    }
+
     /// Shut down the tracer provider, flushing any pending spans.
     pub fn shutdown_tracer_provider() {
         OTEL_ACTIVE.store(false, Ordering::Release);
@@ -235,6 +663,54 @@ pub(crate) mod otel_impl {
         }
     }
 
+    /// Append `db.aerospike.<direction>.{keys,bins,bytes}` attributes for the
+    /// fields of `stats` that are set, plus `db.aerospike.retry.attempt`.
+    pub fn push_payload_attributes(
+        direction: &str,
+        stats: &super::PayloadStats,
+        attributes: &mut Vec<KeyValue>,
+    ) {
+        for (field, value) in [
+            ("keys", stats.keys),
+            ("bins", stats.bins),
+            ("bytes", stats.bytes),
+        ] {
+            if let Some(v) = value {
+                attributes.push(KeyValue::new(
+                    format!("db.aerospike.{direction}.{field}"),
+                    v as i64,
+                ));
+            }
+        }
+        if let Some(attempt) = stats.retry_attempt {
+            attributes.push(KeyValue::new("db.aerospike.retry.attempt", attempt as i64));
+        }
+    }
+
+    /// Add a `retry` event to a client-side retry span.
+    pub fn record_retry_event<S: opentelemetry::trace::Span>(
+        span: &mut S,
+        stats: &super::PayloadStats,
+    ) {
+        if let Some(attempt) = stats.retry_attempt {
+            let mut attributes = vec![KeyValue::new("retry.attempt", attempt as i64)];
+            if let Some(keys) = stats.keys {
+                attributes.push(KeyValue::new("retry.keys", keys as i64));
+            }
+            span.add_event("retry", attributes);
+        }
+    }
+
+    /// Record the response payload of a successful operation on its span.
+    pub fn record_response<T: super::ResponseStats>(
+        span: &opentelemetry::trace::SpanRef<'_>,
+        value: &T,
+    ) {
+        let mut attributes = Vec::new();
+        push_payload_attributes("response", &value.response_stats(), &mut attributes);
+        span.set_attributes(attributes);
+    }
+
     /// Record an error on a span following OTel semantic conventions.
     pub fn record_error_on_span(
         span: &opentelemetry::trace::SpanRef<'_>,
@@ -257,14 +733,23 @@ use pyo3::prelude::*;
 
 #[cfg(feature = "otel")]
 #[pyfunction]
-pub fn init_tracing() {
-    otel_impl::init_tracer_provider();
+#[pyo3(signature = (sample_ratio=None, service_name=None, span_name_format=None))]
+pub fn init_tracing(
+    sample_ratio: Option<f64>,
+    service_name: Option<&str>,
+    span_name_format: Option<&str>,
+) -> PyResult<()> {
+    let sample_ratio = apply_tracing_options(sample_ratio, span_name_format)?;
+    otel_impl::init_tracer_provider(sample_ratio, service_name);
+    otel_impl::init_meter_provider(service_name);
+    Ok(())
 }
 
 #[cfg(feature = "otel")]
 #[pyfunction]
 pub fn shutdown_tracing() {
     otel_impl::shutdown_tracer_provider();
+    otel_impl::shutdown_meter_provider();
 }
 
 #[cfg(not(feature = "otel"))]
@@ -272,8 +757,16 @@ use pyo3::prelude::*;
 
 #[cfg(not(feature = "otel"))]
 #[pyfunction]
-pub fn init_tracing() {
+#[pyo3(signature = (sample_ratio=None, service_name=None, span_name_format=None))]
+pub fn init_tracing(
+    sample_ratio: Option<f64>,
+    service_name: Option<&str>,
+    span_name_format: Option<&str>,
+) -> PyResult<()> {
+    let _ = service_name;
+    apply_tracing_options(sample_ratio, span_name_format)?;
     log::info!("OTel tracing not available (compiled without 'otel' feature)");
+    Ok(())
 }
 
 #[cfg(not(feature = "otel"))]
@@ -284,19 +777,47 @@ pub fn shutdown_tracing() {
 
 // ── traced_op! macro ────────────────────────────────────────────────────────
 
+/// Await `$body` with the operation span's context attached.
+///
+/// The context is attached on every poll and detached when the future yields,
+/// so on a shared Tokio worker (the async client) the span is current only
+/// while this operation runs: anything reading `Context::current()` during the
+/// call nests under it, and other tasks polled on the same thread do not.
+#[cfg(feature = "otel")]
+#[macro_export]
+macro_rules! with_span_context {
+    ($cx:expr, $body:expr) => {
+        opentelemetry::context::FutureExt::with_context(async { $body }, $cx.clone()).await
+    };
+}
+
 /// Instrument a data operation with **both** an OTel span and Prometheus metrics.
 ///
 /// When OTel is active: creates a span, records attributes, and collects metrics.
 /// When OTel is inactive: metrics-only fast path (zero Python calls, zero span alloc).
 ///
-/// Signature: `traced_op!(op, ns, set, parent_ctx, conn_info, { async_body })`
+/// Signature: `traced_op!(op, ns, set, parent_ctx, conn_info, [request: stats,] { async_body })`
+///
+/// The optional `request` [`PayloadStats`] is only evaluated when a span is
+/// created; the successful result's [`ResponseStats`] are recorded on the span.
 ///
 /// The expression must return `Result<T, aerospike_core::Error>`.
 /// Returns `Result<T, PyErr>`.
 #[cfg(feature = "otel")]
 #[macro_export]
 macro_rules! traced_op {
-    ($op:expr, $ns:expr, $set:expr, $parent_ctx:expr, $conn_info:expr, $body:expr) => {{
+    ($op:expr, $ns:expr, $set:expr, $parent_ctx:expr, $conn_info:expr, $body:expr) => {
+        $crate::traced_op!(
+            $op,
+            $ns,
+            $set,
+            $parent_ctx,
+            $conn_info,
+            request: $crate::tracing::PayloadStats::default(),
+            $body
+        )
+    };
+    ($op:expr, $ns:expr, $set:expr, $parent_ctx:expr, $conn_info:expr, request: $request:expr, $body:expr) => {{
         if $crate::tracing::otel_impl::is_otel_active() {
             // Full OTel span + metrics path
             use opentelemetry::trace::{SpanKind, TraceContextExt, Tracer};
@@ -304,50 +825,58 @@ macro_rules! traced_op {
 
             let op_upper = $crate::tracing::op_to_upper($op);
             let tracer = $crate::tracing::otel_impl::get_tracer();
-            let span_name = format!("{} {}.{}", op_upper, $ns, $set);
+            let span_name = $crate::tracing::span_name(&op_upper, $ns, $set);
             let conn = &$conn_info;
-            let span = tracer
+            let request: $crate::tracing::PayloadStats = $request;
+            let mut attributes = vec![
+                KeyValue::new("db.system.name", "aerospike"),
+                KeyValue::new("db.namespace", $ns.to_string()),
+                KeyValue::new("db.collection.name", $set.to_string()),
+                KeyValue::new("db.operation.name", op_upper.clone().into_owned()),
+                KeyValue::new(
+                    "server.address",
+                    opentelemetry::StringValue::from(std::sync::Arc::clone(&conn.server_address)),
+                ),
+                KeyValue::new("server.port", conn.server_port),
+                KeyValue::new(
+                    "db.aerospike.cluster_name",
+                    opentelemetry::StringValue::from(std::sync::Arc::clone(&conn.cluster_name)),
+                ),
+            ];
+            $crate::tracing::otel_impl::push_payload_attributes(
+                "request",
+                &request,
+                &mut attributes,
+            );
+            let mut span = tracer
                 .span_builder(span_name)
                 .with_kind(SpanKind::Client)
-                .with_attributes(vec![
-                    KeyValue::new("db.system.name", "aerospike"),
-                    KeyValue::new("db.namespace", $ns.to_string()),
-                    KeyValue::new("db.collection.name", $set.to_string()),
-                    KeyValue::new("db.operation.name", op_upper.clone().into_owned()),
-                    KeyValue::new(
-                        "server.address",
-                        opentelemetry::StringValue::from(std::sync::Arc::clone(
-                            &conn.server_address,
-                        )),
-                    ),
-                    KeyValue::new("server.port", conn.server_port),
-                    KeyValue::new(
-                        "db.aerospike.cluster_name",
-                        opentelemetry::StringValue::from(std::sync::Arc::clone(&conn.cluster_name)),
-                    ),
-                ])
+                .with_attributes(attributes)
                 .start_with_context(&tracer, &$parent_ctx);
+            $crate::tracing::otel_impl::record_retry_event(&mut span, &request);
             let _cx = $parent_ctx.with_span(span);
 
             let result = if $crate::metrics::is_metrics_enabled() {
+                let trace_id = $crate::tracing::otel_impl::sampled_trace_id(&_cx);
                 let timer = $crate::metrics::OperationTimer::start($op, $ns, $set);
-                let result = $body;
+                let result = $crate::with_span_context!(_cx, $body);
                 match &result {
-                    Ok(_) => timer.finish(""),
+                    Ok(_) => timer.finish_traced("", trace_id),
                     Err(e) => {
                         let err_type = $crate::metrics::error_type_from_aerospike_error(e);
-                        timer.finish(&err_type);
+                        timer.finish_traced(&err_type, trace_id);
                     }
                 }
                 result
             } else {
-                $body
+                $crate::with_span_context!(_cx, $body)
             };
 
             {
                 let span_ref = opentelemetry::trace::TraceContextExt::span(&_cx);
-                if let Err(e) = &result {
-                    $crate::tracing::otel_impl::record_error_on_span(&span_ref, e);
+                match &result {
+                    Ok(value) => $crate::tracing::otel_impl::record_response(&span_ref, value),
+                    Err(e) => $crate::tracing::otel_impl::record_error_on_span(&span_ref, e),
                 }
                 span_ref.end();
             }
@@ -371,6 +900,9 @@ macro_rules! traced_op {
         let _ = &$conn_info;
         $crate::timed_op!($op, $ns, $set, $body)
     }};
+    ($op:expr, $ns:expr, $set:expr, $parent_ctx:expr, $conn_info:expr, request: $request:expr, $body:expr) => {
+        $crate::traced_op!($op, $ns, $set, $parent_ctx, $conn_info, $body)
+    };
 }
 
 // ── traced_exists_op! macro ─────────────────────────────────────────────────
@@ -388,55 +920,62 @@ macro_rules! traced_exists_op {
 
             let op_upper = $crate::tracing::op_to_upper($op);
             let tracer = $crate::tracing::otel_impl::get_tracer();
-            let span_name = format!("{} {}.{}", op_upper, $ns, $set);
+            let span_name = $crate::tracing::span_name(&op_upper, $ns, $set);
             let conn = &$conn_info;
-            let span = tracer
+            let request = $crate::tracing::PayloadStats::key();
+            let mut attributes = vec![
+                KeyValue::new("db.system.name", "aerospike"),
+                KeyValue::new("db.namespace", $ns.to_string()),
+                KeyValue::new("db.collection.name", $set.to_string()),
+                KeyValue::new("db.operation.name", op_upper.clone().into_owned()),
+                KeyValue::new(
+                    "server.address",
+                    opentelemetry::StringValue::from(std::sync::Arc::clone(&conn.server_address)),
+                ),
+                KeyValue::new("server.port", conn.server_port),
+                KeyValue::new(
+                    "db.aerospike.cluster_name",
+                    opentelemetry::StringValue::from(std::sync::Arc::clone(&conn.cluster_name)),
+                ),
+            ];
+            $crate::tracing::otel_impl::push_payload_attributes(
+                "request",
+                &request,
+                &mut attributes,
+            );
+            let mut span = tracer
                 .span_builder(span_name)
                 .with_kind(SpanKind::Client)
-                .with_attributes(vec![
-                    KeyValue::new("db.system.name", "aerospike"),
-                    KeyValue::new("db.namespace", $ns.to_string()),
-                    KeyValue::new("db.collection.name", $set.to_string()),
-                    KeyValue::new("db.operation.name", op_upper.clone().into_owned()),
-                    KeyValue::new(
-                        "server.address",
-                        opentelemetry::StringValue::from(std::sync::Arc::clone(
-                            &conn.server_address,
-                        )),
-                    ),
-                    KeyValue::new("server.port", conn.server_port),
-                    KeyValue::new(
-                        "db.aerospike.cluster_name",
-                        opentelemetry::StringValue::from(std::sync::Arc::clone(&conn.cluster_name)),
-                    ),
-                ])
+                .with_attributes(attributes)
                 .start_with_context(&tracer, &$parent_ctx);
+            $crate::tracing::otel_impl::record_retry_event(&mut span, &request);
             let _cx = $parent_ctx.with_span(span);
 
             let result = if $crate::metrics::is_metrics_enabled() {
+                let trace_id = $crate::tracing::otel_impl::sampled_trace_id(&_cx);
                 let timer = $crate::metrics::OperationTimer::start($op, $ns, $set);
-                let result = $body;
+                let result = $crate::with_span_context!(_cx, $body);
                 match &result {
-                    Ok(_) => timer.finish(""),
+                    Ok(_) => timer.finish_traced("", trace_id),
                     Err(aerospike_core::Error::ServerError(
                         aerospike_core::ResultCode::KeyNotFoundError,
                         _,
                         _,
-                    )) => timer.finish(""),
+                    )) => timer.finish_traced("", trace_id),
                     Err(e) => {
                         let err_type = $crate::metrics::error_type_from_aerospike_error(e);
-                        timer.finish(&err_type);
+                        timer.finish_traced(&err_type, trace_id);
                     }
                 }
                 result
             } else {
-                $body
+                $crate::with_span_context!(_cx, $body)
             };
 
             {
                 let span_ref = opentelemetry::trace::TraceContextExt::span(&_cx);
                 match &result {
-                    Ok(_) => {}
+                    Ok(value) => $crate::tracing::otel_impl::record_response(&span_ref, value),
                     Err(aerospike_core::Error::ServerError(
                         aerospike_core::ResultCode::KeyNotFoundError,
                         _,
@@ -507,3 +1046,79 @@ macro_rules! traced_exists_op {
         }
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_request_counts_names_and_particles() {
+        let bins = vec![
+            Bin::new("name".into(), Value::from("Alice")),
+            Bin::new("age".into(), Value::from(30)),
+        ];
+        let stats = PayloadStats::single(&bins);
+        assert_eq!(stats.keys, Some(1));
+        assert_eq!(stats.bins, Some(2));
+        assert_eq!(stats.bytes, Some(4 + 5 + 3 + 8));
+        assert_eq!(stats.retry_attempt, None);
+        assert_eq!(PayloadStats::batch(3).with_retry(2).retry_attempt, Some(2));
+    }
+
+    #[test]
+    fn collection_sizes_include_headers() {
+        let list = Value::List(vec![Value::from(1), Value::from("ab")]);
+        assert_eq!(value_size(&list), 1 + (1 + 8) + (1 + 2));
+        assert_eq!(value_size(&Value::Nil), 0);
+    }
+
+    #[test]
+    fn span_name_format_renders_placeholders() {
+        let f = SpanNameFormat::parse("aerospike.{operation} [{namespace}/{set}]").unwrap();
+        assert_eq!(
+            f.render("GET", "test", "users"),
+            "aerospike.GET [test/users]"
+        );
+        let f = SpanNameFormat::parse("{operation}").unwrap();
+        assert_eq!(f.render("PUT", "test", "users"), "PUT");
+    }
+
+    #[test]
+    fn span_name_format_rejects_bad_templates() {
+        for bad in ["", "{op}", "{operation", "operation}", "{}"] {
+            assert!(SpanNameFormat::parse(bad).is_err(), "{bad:?}");
+        }
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn span_context_is_current_only_while_body_runs() {
+        use opentelemetry::Context;
+
+        #[derive(Debug, PartialEq)]
+        struct Marker(u32);
+
+        async fn current_marker() -> Option<u32> {
+            tokio::task::yield_now().await;
+            Context::current().get::<Marker>().map(|m| m.0)
+        }
+
+        let cx = Context::new().with_value(Marker(7));
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let (inside, after) = rt.block_on(async {
+            let inside = crate::with_span_context!(cx, current_marker().await);
+            (inside, Context::current().get::<Marker>().map(|m| m.0))
+        });
+        assert_eq!(inside, Some(7));
+        assert_eq!(after, None);
+    }
+
+    #[test]
+    fn empty_responses_record_nothing() {
+        assert_eq!(().response_stats(), PayloadStats::default());
+        assert_eq!(true.response_stats(), PayloadStats::default());
+        assert_eq!(Vec::<BatchRecord>::new().response_stats().keys, Some(0));
+    }
+}
@@ -12,16 +12,25 @@ use super::value::py_to_value;
 pub fn py_dict_to_bins(dict: &Bound<'_, PyDict>) -> PyResult<Vec<Bin>> {
     let mut bins = Vec::with_capacity(dict.len());
     for (key, val) in dict.iter() {
-        let name: String = key.cast::<PyString>()?.to_str()?.to_owned();
-        if name.len() > 15 {
-            return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "Bin name '{}' exceeds the 15-byte limit ({} bytes)",
-                name,
-                name.len()
-            )));
-        }
+        let name = bin_name(&key)?;
         let value = py_to_value(&val)?;
         bins.push(Bin::new(name, value));
     }
     Ok(bins)
 }
+
+/// Extract a bin name from a dict key, enforcing the 255-byte wire limit.
+///
+/// The tighter per-server limit is checked by `BinLimits` on writes.
+pub fn bin_name(key: &Bound<'_, PyAny>) -> PyResult<String> {
+    let name: String = key.cast::<PyString>()?.to_str()?.to_owned();
+    if name.len() > crate::bin_limits::MAX_BIN_NAME_LEN {
+        return Err(crate::errors::BinNameError::new_err(format!(
+            "Bin name '{}' exceeds the {}-byte limit ({} bytes)",
+            name,
+            crate::bin_limits::MAX_BIN_NAME_LEN,
+            name.len()
+        )));
+    }
+    Ok(name)
+}
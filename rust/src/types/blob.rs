@@ -0,0 +1,130 @@
+//! Zero-copy views over large blob bins.
+//!
+//! Blob values at or above [`blob_view_threshold`] bytes are returned to Python
+//! as a read-only `memoryview` over a [`PyBlobView`] that owns the `Vec<u8>`
+//! decoded from the wire, instead of being copied into a new `bytes` object.
+//! The threshold is `0` (disabled) by default so existing code keeps receiving
+//! `bytes`.
+
+use std::os::raw::{c_int, c_void};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use pyo3::exceptions::PyBufferError;
+use pyo3::ffi;
+use pyo3::prelude::*;
+use pyo3::types::PyMemoryView;
+
+/// Minimum blob size (bytes) returned as a `memoryview`; `0` disables views.
+static BLOB_VIEW_THRESHOLD: AtomicUsize = AtomicUsize::new(0);
+
+/// Current blob view threshold in bytes (`0` = disabled).
+#[inline]
+pub fn blob_view_threshold() -> usize {
+    BLOB_VIEW_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Whether a blob of `len` bytes should be returned as a view.
+#[inline]
+pub fn use_blob_view(len: usize) -> bool {
+    let threshold = blob_view_threshold();
+    threshold > 0 && len >= threshold
+}
+
+/// Read-only buffer that owns the bytes of a blob bin.
+///
+/// Never constructed from Python; `memoryview` objects returned by reads keep
+/// it alive through `memoryview.obj`.
+#[pyclass(name = "BlobView", module = "aerospike_py", frozen)]
+pub struct PyBlobView {
+    data: Vec<u8>,
+}
+
+#[pymethods]
+impl PyBlobView {
+    /// # Safety
+    /// Called by CPython with a valid `view` pointer.
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if flags & ffi::PyBUF_WRITABLE == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("BlobView is read-only"));
+        }
+        let data = &slf.get().data;
+        // SAFETY: `data` is owned by `slf`, which is frozen (never mutated) and
+        // kept alive by the `view.obj` reference taken by PyBuffer_FillInfo.
+        let rc = unsafe {
+            ffi::PyBuffer_FillInfo(
+                view,
+                slf.as_ptr(),
+                data.as_ptr() as *mut c_void,
+                data.len() as ffi::Py_ssize_t,
+                1,
+                flags,
+            )
+        };
+        if rc == -1 {
+            return Err(PyErr::fetch(slf.py()));
+        }
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut ffi::Py_buffer) {}
+
+    fn __len__(&self) -> usize {
+        self.data.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("BlobView(len={})", self.data.len())
+    }
+}
+
+/// Wrap owned blob bytes in a read-only `memoryview` without copying.
+pub fn blob_to_memoryview(py: Python<'_>, data: Vec<u8>) -> PyResult<Py<PyAny>> {
+    let owner = Bound::new(py, PyBlobView { data })?;
+    Ok(PyMemoryView::from(owner.as_any())?.into_any().unbind())
+}
+
+/// Return blob bins of at least `nbytes` bytes as read-only `memoryview`s.
+///
+/// Applies to top-level blob bins of records returned by `get`, `select` and
+/// `operate`. Pass `0` to always return `bytes` (the default).
+#[pyfunction]
+pub fn set_blob_view_threshold(nbytes: i64) -> PyResult<()> {
+    let nbytes = usize::try_from(nbytes)
+        .map_err(|_| crate::errors::ParamError::new_err("nbytes must be >= 0"))?;
+    BLOB_VIEW_THRESHOLD.store(nbytes, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Current blob view threshold in bytes (`0` = disabled).
+#[pyfunction]
+pub fn get_blob_view_threshold() -> usize {
+    blob_view_threshold()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn memoryview_shares_rust_buffer() {
+        Python::initialize();
+        Python::attach(|py| {
+            let mv = blob_to_memoryview(py, vec![1, 2, 3]).unwrap();
+            let mv = mv.bind(py);
+            let bytes: Vec<u8> = mv.call_method0("tobytes").unwrap().extract().unwrap();
+            assert_eq!(bytes, vec![1, 2, 3]);
+            assert!(mv.getattr("readonly").unwrap().extract::<bool>().unwrap());
+            assert!(mv.getattr("obj").unwrap().cast::<PyBlobView>().is_ok());
+        });
+    }
+
+    #[test]
+    fn negative_threshold_rejected() {
+        Python::initialize();
+        assert!(set_blob_view_threshold(-1).is_err());
+    }
+}
@@ -2,8 +2,9 @@
 
 use aerospike_core::{Key, Value};
 use log::trace;
+use pyo3::buffer::PyBuffer;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyList, PyString, PyTuple};
+use pyo3::types::{PyBool, PyByteArray, PyBytes, PyInt, PyList, PyMemoryView, PyString, PyTuple};
 use ripemd::{Digest, Ripemd160};
 
 use super::value::{py_to_value, value_to_py};
@@ -21,27 +22,105 @@ pub(crate) fn compute_bytes_key_digest(set_name: &str, bytes_data: &[u8]) -> [u8
     hash.finalize().into()
 }
 
-/// Convert a Python key tuple (namespace, set, key) to Rust Key
+/// Longest namespace name the server accepts, in bytes.
+pub const MAX_NAMESPACE_LEN: usize = 31;
+/// Longest set name the server accepts, in bytes.
+pub const MAX_SET_NAME_LEN: usize = 63;
+
+/// Check a namespace name: non-empty and at most 31 bytes.
+pub fn validate_namespace(namespace: &str) -> PyResult<()> {
+    if namespace.is_empty() {
+        return Err(crate::errors::ParamError::new_err(
+            "Namespace must not be empty",
+        ));
+    }
+    if namespace.len() > MAX_NAMESPACE_LEN {
+        return Err(crate::errors::ParamError::new_err(format!(
+            "Namespace '{namespace}' exceeds the {MAX_NAMESPACE_LEN}-byte limit ({} bytes)",
+            namespace.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Check a set name: at most 63 bytes, without `:`, `;` or NUL, which the
+/// server's info protocol uses as delimiters. An empty name is the null set.
+pub fn validate_set_name(set_name: &str) -> PyResult<()> {
+    if set_name.len() > MAX_SET_NAME_LEN {
+        return Err(crate::errors::ParamError::new_err(format!(
+            "Set name '{set_name}' exceeds the {MAX_SET_NAME_LEN}-byte limit ({} bytes)",
+            set_name.len()
+        )));
+    }
+    if let Some(c) = set_name.chars().find(|c| matches!(c, ':' | ';' | '\0')) {
+        return Err(crate::errors::ParamError::new_err(format!(
+            "Set name '{set_name}' contains invalid character {c:?}"
+        )));
+    }
+    Ok(())
+}
+
+/// Extract a set name, mapping `None` to the null set (`""`), and validate it.
+pub fn py_to_set_name(set_name: &Bound<'_, PyAny>) -> PyResult<String> {
+    if set_name.is_none() {
+        return Ok(String::new());
+    }
+    let set_name = set_name.cast::<PyString>()?.to_str()?.to_owned();
+    validate_set_name(&set_name)?;
+    Ok(set_name)
+}
+
+/// Copy a `bytes`, `bytearray` or `memoryview` user key; `None` for other types.
+fn py_key_bytes(key_item: &Bound<'_, PyAny>) -> PyResult<Option<Vec<u8>>> {
+    if let Ok(b) = key_item.cast::<PyBytes>() {
+        return Ok(Some(b.as_bytes().to_vec()));
+    }
+    if key_item.is_instance_of::<PyByteArray>() || key_item.is_instance_of::<PyMemoryView>() {
+        let buf = PyBuffer::<u8>::get(key_item).map_err(|_| {
+            crate::errors::ParamError::new_err("memoryview key must be a buffer of unsigned bytes")
+        })?;
+        return Ok(Some(buf.to_vec(key_item.py())?));
+    }
+    Ok(None)
+}
+
+/// Reject integer keys outside the signed 64-bit range the server stores.
+fn check_int_key_range(key_item: &Bound<'_, PyAny>) -> PyResult<()> {
+    if key_item.is_instance_of::<PyInt>()
+        && !key_item.is_instance_of::<PyBool>()
+        && key_item.extract::<i64>().is_err()
+    {
+        return Err(crate::errors::ParamError::new_err(format!(
+            "Integer key {key_item} is outside the 64-bit signed range [{}, {}]",
+            i64::MIN,
+            i64::MAX
+        )));
+    }
+    Ok(())
+}
+
+/// Convert a Python key tuple (namespace, set, key) to Rust Key.
+///
+/// A set of `None` or `""` is the null set (records outside any set).
 pub fn py_to_key(key_tuple: &Bound<'_, PyAny>) -> PyResult<Key> {
     trace!("Converting Python key to Rust key");
     let tuple = key_tuple.cast::<PyTuple>()?;
 
     if tuple.len() < 3 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
+        return Err(crate::errors::ParamError::new_err(
             "Key tuple must have at least 3 elements: (namespace, set, key)",
         ));
     }
 
     let namespace: String = tuple.get_item(0)?.cast::<PyString>()?.to_str()?.to_owned();
-    let set_name: String = tuple.get_item(1)?.cast::<PyString>()?.to_str()?.to_owned();
+    validate_namespace(&namespace)?;
+    let set_name = py_to_set_name(&tuple.get_item(1)?)?;
     let key_item = tuple.get_item(2)?;
 
     // For bytes keys, compute digest with STRING particle type (3) to match
     // the official Python C client behavior for cross-client compatibility.
-    // Check this before py_to_value() to avoid a redundant Vec<u8> allocation.
-    if let Ok(b) = key_item.cast::<PyBytes>() {
-        let bytes_data = b.as_bytes();
-
+    // bytearray / memoryview keys are treated the same as bytes.
+    if let Some(bytes_data) = py_key_bytes(&key_item)? {
         // Handle 4-element tuple with explicit digest
         if tuple.len() == 4 && !tuple.get_item(3)?.is_none() {
             let digest_bytes: Vec<u8> = tuple.get_item(3)?.extract()?;
@@ -51,21 +130,22 @@ pub fn py_to_key(key_tuple: &Bound<'_, PyAny>) -> PyResult<Key> {
                 return Ok(Key {
                     namespace,
                     set_name,
-                    user_key: Some(Value::Blob(bytes_data.to_vec())),
+                    user_key: Some(Value::Blob(bytes_data)),
                     digest,
                 });
             }
         }
 
-        let digest = compute_bytes_key_digest(&set_name, bytes_data);
+        let digest = compute_bytes_key_digest(&set_name, &bytes_data);
         return Ok(Key {
             namespace,
             set_name,
-            user_key: Some(Value::Blob(bytes_data.to_vec())),
+            user_key: Some(Value::Blob(bytes_data)),
             digest,
         });
     }
 
+    check_int_key_range(&key_item)?;
     let user_key = py_to_value(&key_item)?;
 
     // Handle 4-element tuple with explicit digest
@@ -87,7 +167,7 @@ pub fn py_to_key(key_tuple: &Bound<'_, PyAny>) -> PyResult<Key> {
     }
 
     Key::new(namespace, set_name, user_key)
-        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid key: {e}")))
+        .map_err(|e| crate::errors::ParamError::new_err(format!("Invalid key: {e}")))
 }
 
 /// Convert Rust Key to Python tuple (namespace, set, key, digest)
@@ -98,7 +178,7 @@ pub fn key_to_py(py: Python<'_>, key: &Key) -> PyResult<Py<PyAny>> {
         Some(v) => value_to_py(py, v)?,
         None => py.None(),
     };
-    let digest = pyo3::types::PyBytes::new(py, &key.digest);
+    let digest = pyo3::types::PyBytes::new(py, &key_digest(key));
 
     let tuple = PyTuple::new(
         py,
@@ -112,6 +192,52 @@ pub fn key_to_py(py: Python<'_>, key: &Key) -> PyResult<Py<PyAny>> {
     Ok(tuple.into_any().unbind())
 }
 
+/// The digest of `key`, computed from the user key when the server left it
+/// zeroed (keys echoed without a digest field).
+fn key_digest(key: &Key) -> [u8; 20] {
+    if key.digest != [0u8; 20] {
+        return key.digest;
+    }
+    match &key.user_key {
+        Some(Value::Blob(bytes)) => compute_bytes_key_digest(&key.set_name, bytes),
+        Some(user_key) => Key::new(
+            key.namespace.clone(),
+            key.set_name.clone(),
+            user_key.clone(),
+        )
+        .map_or(key.digest, |k| k.digest),
+        None => key.digest,
+    }
+}
+
+/// Compute the 20-byte RIPEMD-160 digest the server uses for a record key.
+#[pyfunction]
+pub fn calc_digest<'py>(
+    py: Python<'py>,
+    namespace: &str,
+    set: &str,
+    key: &Bound<'py, PyAny>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let tuple = PyTuple::new(
+        py,
+        [
+            namespace.into_pyobject(py)?.into_any(),
+            set.into_pyobject(py)?.into_any(),
+            key.clone(),
+        ],
+    )?;
+    let key = py_to_key(tuple.as_any())?;
+    Ok(PyBytes::new(py, &key.digest))
+}
+
+/// Number of partitions in an Aerospike namespace.
+pub const PARTITIONS: usize = 4096;
+
+/// Partition a record digest belongs to (its first two bytes, little endian).
+pub fn partition_id(digest: &[u8; 20]) -> usize {
+    usize::from(u16::from_le_bytes([digest[0], digest[1]])) & (PARTITIONS - 1)
+}
+
 /// Convert a Python list of key tuples to a `Vec<Key>`.
 pub fn py_to_keys(keys: &Bound<'_, PyList>) -> PyResult<Vec<Key>> {
     keys.iter().map(|k| py_to_key(&k)).collect()
@@ -121,6 +247,17 @@ pub fn py_to_keys(keys: &Bound<'_, PyList>) -> PyResult<Vec<Key>> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn partition_id_uses_low_twelve_bits_of_first_two_bytes() {
+        let mut digest = [0u8; 20];
+        digest[0] = 0x34;
+        digest[1] = 0x12;
+        assert_eq!(partition_id(&digest), 0x234);
+        digest[0] = 0xff;
+        digest[1] = 0xff;
+        assert_eq!(partition_id(&digest), PARTITIONS - 1);
+    }
+
     /// Verify that `compute_bytes_key_digest` produces the expected RIPEMD-160
     /// output for a known input. The expected values were derived by computing
     /// RIPEMD-160(set_name + [3u8] + bytes_data) with OpenSSL and cross-checked
@@ -138,6 +275,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn key_digest_fills_zeroed_digest_from_user_key() {
+        let expected = Key::new("test", "demo", Value::from("k1")).unwrap();
+        let zeroed = Key {
+            digest: [0u8; 20],
+            ..expected.clone()
+        };
+        assert_eq!(key_digest(&zeroed), expected.digest);
+
+        let blob = Key {
+            namespace: "test".into(),
+            set_name: "compat_edge".into(),
+            user_key: Some(Value::Blob(vec![])),
+            digest: [0u8; 20],
+        };
+        assert_eq!(
+            key_digest(&blob),
+            compute_bytes_key_digest("compat_edge", &[])
+        );
+
+        let digest_only = Key {
+            user_key: None,
+            ..zeroed
+        };
+        assert_eq!(key_digest(&digest_only), [0u8; 20]);
+    }
+
     #[test]
     fn test_bytes_key_digest_empty_bytes() {
         // RIPEMD-160("compat_edge" + [3] + b"")
@@ -167,4 +331,64 @@ mod tests {
             "STRING and BLOB particle types must yield different digests"
         );
     }
+
+    #[test]
+    fn none_and_empty_set_are_the_null_set() {
+        Python::initialize();
+        Python::attach(|py| {
+            let none_key = ("test", py.None(), "k").into_pyobject(py).unwrap();
+            let empty_key = PyTuple::new(py, ["test", "", "k"]).unwrap();
+            let a = py_to_key(none_key.as_any()).unwrap();
+            let b = py_to_key(empty_key.as_any()).unwrap();
+            assert_eq!(a.set_name, "");
+            assert_eq!(a.digest, b.digest);
+
+            let bad_set = PyTuple::new(py, ["test", "a:b", "k"]).unwrap();
+            assert!(py_to_key(bad_set.as_any()).is_err());
+            let long_ns = PyTuple::new(py, ["n".repeat(32).as_str(), "demo", "k"]).unwrap();
+            assert!(py_to_key(long_ns.as_any()).is_err());
+        });
+    }
+
+    #[test]
+    fn int_keys_outside_i64_are_param_errors() {
+        Python::initialize();
+        Python::attach(|py| {
+            for (expr, ok) in [
+                (c"2**63 - 1", true),
+                (c"-2**63", true),
+                (c"2**63", false),
+                (c"-2**63 - 1", false),
+                (c"2**100", false),
+            ] {
+                let pk = py.eval(expr, None, None).unwrap();
+                let key = ("test", "demo", pk).into_pyobject(py).unwrap();
+                match py_to_key(key.as_any()) {
+                    Ok(_) => assert!(ok, "{expr:?} should be rejected"),
+                    Err(e) => {
+                        assert!(!ok, "{expr:?} should be accepted");
+                        assert!(e.is_instance_of::<crate::errors::ParamError>(py));
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn bytearray_and_memoryview_keys_match_bytes() {
+        Python::initialize();
+        Python::attach(|py| {
+            let bytes_key = ("test", "demo", PyBytes::new(py, b"\x01\x02"))
+                .into_pyobject(py)
+                .unwrap();
+            let expected = py_to_key(bytes_key.as_any()).unwrap();
+            for expr in [c"bytearray(b'\\x01\\x02')", c"memoryview(b'\\x01\\x02')"] {
+                let pk = py.eval(expr, None, None).unwrap();
+                let key = ("test", "demo", pk).into_pyobject(py).unwrap();
+                let key = py_to_key(key.as_any()).unwrap();
+                assert_eq!(key.digest, expected.digest, "{expr:?}");
+                assert_eq!(key.user_key, expected.user_key, "{expr:?}");
+            }
+        });
+    }
 }
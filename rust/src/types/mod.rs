@@ -1,14 +1,18 @@
 //! Type conversion between Python objects and `aerospike_core` types.
 //!
 //! - [`value`]: Python ↔ `aerospike_core::Value`
+//! - [`blob`]: zero-copy `memoryview` for large blob bins
 //! - [`key`]: Python tuple ↔ `aerospike_core::Key`
 //! - [`bin`]: Python dict ↔ `Vec<aerospike_core::Bin>`
+//! - [`raw`]: `(particle_type, payload)` wire encoding for `get_raw` / `put_raw`
 //! - [`record`]: `aerospike_core::Record` → Python tuple `(key, meta, bins)`
 //! - [`host`]: Python config dict → connection string
 
 pub mod bin;
+pub mod blob;
 pub mod host;
 pub mod key;
 pub mod partition_filter;
+pub mod raw;
 pub mod record;
 pub mod value;
@@ -10,9 +10,10 @@
 //! handle from any in-flight state changes.
 
 use aerospike_core::query::PartitionFilter as CorePartitionFilter;
-use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
+use crate::errors::ParamError;
+
 /// Total number of partitions in an Aerospike cluster.
 const PARTITIONS: usize = 4096;
 
@@ -64,7 +65,7 @@ pub fn partition_filter_all() -> PyPartitionFilter {
 #[pyfunction]
 pub fn partition_filter_by_id(partition_id: usize) -> PyResult<PyPartitionFilter> {
     if partition_id >= PARTITIONS {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "partition_id must be in [0, {PARTITIONS}), got {partition_id}"
         )));
     }
@@ -80,7 +81,7 @@ pub fn partition_filter_by_id(partition_id: usize) -> PyResult<PyPartitionFilter
 #[pyfunction]
 pub fn partition_filter_by_range(begin: usize, count: usize) -> PyResult<PyPartitionFilter> {
     if begin >= PARTITIONS && count > 0 {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "begin must be in [0, {PARTITIONS}), got {begin}"
         )));
     }
@@ -89,7 +90,7 @@ pub fn partition_filter_by_range(begin: usize, count: usize) -> PyResult<PyParti
         .map(|s| s > PARTITIONS)
         .unwrap_or(true)
     {
-        return Err(PyValueError::new_err(format!(
+        return Err(ParamError::new_err(format!(
             "begin + count must be <= {PARTITIONS}, got begin={begin}, count={count}"
         )));
     }
@@ -0,0 +1,533 @@
+//! Raw particle encoding for `get_raw` / `put_raw`.
+//!
+//! A raw bin is `(particle_type, payload)` where `payload` is the bin value in
+//! Aerospike wire format: big-endian 8-byte integers and doubles, UTF-8
+//! strings, blob bytes as-is, and MessagePack (with Aerospike's particle-type
+//! prefix on strings / blobs) for lists and maps. Payloads are produced and
+//! parsed entirely in Rust, so bins never pass through Python value objects.
+
+use std::collections::{BTreeMap, HashMap};
+
+use aerospike_core::{Bin, FloatValue, Value};
+use pyo3::buffer::PyBuffer;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyTuple};
+
+use super::bin::bin_name;
+use crate::errors::ParamError;
+
+pub const PARTICLE_NULL: u8 = 0;
+pub const PARTICLE_INTEGER: u8 = 1;
+pub const PARTICLE_DOUBLE: u8 = 2;
+pub const PARTICLE_STRING: u8 = 3;
+pub const PARTICLE_BLOB: u8 = 4;
+pub const PARTICLE_BOOL: u8 = 17;
+pub const PARTICLE_HLL: u8 = 18;
+pub const PARTICLE_MAP: u8 = 19;
+pub const PARTICLE_LIST: u8 = 20;
+pub const PARTICLE_GEOJSON: u8 = 23;
+
+/// Maximum CDT nesting accepted by the decoder.
+const MAX_NESTING_DEPTH: usize = 64;
+
+fn invalid(msg: impl Into<String>) -> PyErr {
+    ParamError::new_err(msg.into())
+}
+
+// ── Encoding ─────────────────────────────────────────────────────────────────
+
+/// Encode a bin value as `(particle_type, payload)`.
+pub fn encode_particle(value: &Value) -> PyResult<(u8, Vec<u8>)> {
+    Ok(match value {
+        Value::Nil => (PARTICLE_NULL, Vec::new()),
+        Value::Int(i) => (PARTICLE_INTEGER, i.to_be_bytes().to_vec()),
+        Value::Float(f) => (PARTICLE_DOUBLE, f64::from(f).to_be_bytes().to_vec()),
+        Value::String(s) => (PARTICLE_STRING, s.as_bytes().to_vec()),
+        Value::Blob(b) => (PARTICLE_BLOB, b.clone()),
+        Value::Bool(b) => (PARTICLE_BOOL, vec![u8::from(*b)]),
+        Value::HLL(b) => (PARTICLE_HLL, b.clone()),
+        Value::GeoJSON(s) => {
+            // flags (1) + ncells (2) + json
+            let mut out = vec![0u8, 0, 0];
+            out.extend_from_slice(s.as_bytes());
+            (PARTICLE_GEOJSON, out)
+        }
+        Value::List(_) | Value::MultiResult(_) => {
+            let mut out = Vec::new();
+            pack_value(&mut out, value)?;
+            (PARTICLE_LIST, out)
+        }
+        Value::HashMap(_) | Value::OrderedMap(_) | Value::KeyValueList(_) => {
+            let mut out = Vec::new();
+            pack_value(&mut out, value)?;
+            (PARTICLE_MAP, out)
+        }
+        Value::Infinity | Value::Wildcard => {
+            return Err(invalid("INF / WILDCARD cannot be stored as a bin value"))
+        }
+    })
+}
+
+fn pack_header(out: &mut Vec<u8>, len: usize, fix: u8, fix_max: usize, m16: u8, m32: u8) {
+    if len < fix_max {
+        out.push(fix | len as u8);
+    } else if len < 1 << 16 {
+        out.push(m16);
+        out.extend_from_slice(&(len as u16).to_be_bytes());
+    } else {
+        out.push(m32);
+        out.extend_from_slice(&(len as u32).to_be_bytes());
+    }
+}
+
+/// String / blob / GeoJSON inside a CDT: msgpack str with a particle-type prefix.
+fn pack_prefixed(out: &mut Vec<u8>, ptype: u8, data: &[u8]) {
+    pack_header(out, data.len() + 1, 0xa0, 32, 0xda, 0xdb);
+    out.push(ptype);
+    out.extend_from_slice(data);
+}
+
+fn pack_integer(out: &mut Vec<u8>, v: i64) {
+    if v >= 0 {
+        if v < 0x80 {
+            out.push(v as u8);
+        } else if v <= u8::MAX as i64 {
+            out.extend_from_slice(&[0xcc, v as u8]);
+        } else if v <= u16::MAX as i64 {
+            out.push(0xcd);
+            out.extend_from_slice(&(v as u16).to_be_bytes());
+        } else if v <= u32::MAX as i64 {
+            out.push(0xce);
+            out.extend_from_slice(&(v as u32).to_be_bytes());
+        } else {
+            out.push(0xcf);
+            out.extend_from_slice(&(v as u64).to_be_bytes());
+        }
+    } else if v >= -32 {
+        out.push(v as u8);
+    } else if v >= i8::MIN as i64 {
+        out.extend_from_slice(&[0xd0, v as i8 as u8]);
+    } else if v >= i16::MIN as i64 {
+        out.push(0xd1);
+        out.extend_from_slice(&(v as i16).to_be_bytes());
+    } else if v >= i32::MIN as i64 {
+        out.push(0xd2);
+        out.extend_from_slice(&(v as i32).to_be_bytes());
+    } else {
+        out.push(0xd3);
+        out.extend_from_slice(&v.to_be_bytes());
+    }
+}
+
+fn pack_value(out: &mut Vec<u8>, value: &Value) -> PyResult<()> {
+    match value {
+        Value::Nil => out.push(0xc0),
+        Value::Bool(b) => out.push(if *b { 0xc3 } else { 0xc2 }),
+        Value::Int(i) => pack_integer(out, *i),
+        Value::Float(f @ FloatValue::F32(_)) => {
+            out.push(0xca);
+            out.extend_from_slice(&f32::from(f).to_be_bytes());
+        }
+        Value::Float(f) => {
+            out.push(0xcb);
+            out.extend_from_slice(&f64::from(f).to_be_bytes());
+        }
+        Value::String(s) => pack_prefixed(out, PARTICLE_STRING, s.as_bytes()),
+        Value::Blob(b) | Value::HLL(b) => pack_prefixed(out, PARTICLE_BLOB, b),
+        Value::GeoJSON(s) => pack_prefixed(out, PARTICLE_GEOJSON, s.as_bytes()),
+        Value::List(items) | Value::MultiResult(items) => {
+            pack_header(out, items.len(), 0x90, 16, 0xdc, 0xdd);
+            for item in items {
+                pack_value(out, item)?;
+            }
+        }
+        Value::HashMap(map) => {
+            pack_header(out, map.len(), 0x80, 16, 0xde, 0xdf);
+            for (k, v) in map {
+                pack_value(out, k)?;
+                pack_value(out, v)?;
+            }
+        }
+        Value::OrderedMap(map) => {
+            // Key-ordered flag travels as a leading ext entry, as the server sends it.
+            pack_header(out, map.len() + 1, 0x80, 16, 0xde, 0xdf);
+            out.extend_from_slice(&[0xc7, 0x00, 0x01, 0xc0]);
+            for (k, v) in map {
+                pack_value(out, k)?;
+                pack_value(out, v)?;
+            }
+        }
+        Value::KeyValueList(pairs) => {
+            pack_header(out, pairs.len(), 0x80, 16, 0xde, 0xdf);
+            for (k, v) in pairs {
+                pack_value(out, k)?;
+                pack_value(out, v)?;
+            }
+        }
+        Value::Infinity => out.extend_from_slice(&[0xd4, 0xff, 0x01]),
+        Value::Wildcard => out.extend_from_slice(&[0xd4, 0xff, 0x00]),
+    }
+    Ok(())
+}
+
+// ── Decoding ─────────────────────────────────────────────────────────────────
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> PyResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| invalid("truncated msgpack payload"))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn u8(&mut self) -> PyResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn array<const N: usize>(&mut self) -> PyResult<[u8; N]> {
+        Ok(self.take(N)?.try_into().expect("take returned N bytes"))
+    }
+
+    fn len16(&mut self) -> PyResult<usize> {
+        Ok(u16::from_be_bytes(self.array()?) as usize)
+    }
+
+    fn len32(&mut self) -> PyResult<usize> {
+        Ok(u32::from_be_bytes(self.array()?) as usize)
+    }
+}
+
+const fn is_ext(byte: u8) -> bool {
+    matches!(byte, 0xc7 | 0xc8 | 0xc9 | 0xd4..=0xd8)
+}
+
+/// Skip an ext value, returning its type byte (the server's map order flags).
+fn skip_ext(r: &mut Reader<'_>) -> PyResult<u8> {
+    let len = match r.u8()? {
+        0xd4 => 1,
+        0xd5 => 2,
+        0xd6 => 4,
+        0xd7 => 8,
+        0xd8 => 16,
+        0xc7 => r.u8()? as usize,
+        0xc8 => r.len16()?,
+        0xc9 => r.len32()?,
+        m => return Err(invalid(format!("expected msgpack ext, got 0x{m:02x}"))),
+    };
+    let ext_type = r.u8()?;
+    r.take(len)?;
+    Ok(ext_type)
+}
+
+fn unpack_prefixed(r: &mut Reader<'_>, len: usize) -> PyResult<Value> {
+    if len == 0 {
+        return Ok(Value::String(String::new()));
+    }
+    let ptype = r.u8()?;
+    let data = r.take(len - 1)?;
+    match ptype {
+        PARTICLE_STRING => Ok(Value::String(utf8(data)?)),
+        PARTICLE_BLOB => Ok(Value::Blob(data.to_vec())),
+        PARTICLE_GEOJSON => Ok(Value::GeoJSON(utf8(data)?)),
+        _ => Err(invalid(format!(
+            "unsupported particle type {ptype} inside list/map"
+        ))),
+    }
+}
+
+fn unpack_list(r: &mut Reader<'_>, mut count: usize, depth: usize) -> PyResult<Value> {
+    if count > 0 && r.peek().is_some_and(is_ext) {
+        skip_ext(r)?;
+        count -= 1;
+    }
+    let mut items = Vec::with_capacity(count.min(r.data.len()));
+    for _ in 0..count {
+        items.push(unpack_value(r, depth + 1)?);
+    }
+    Ok(Value::List(items))
+}
+
+fn unpack_map(r: &mut Reader<'_>, mut count: usize, depth: usize) -> PyResult<Value> {
+    let mut flags = 0u8;
+    if count > 0 && r.peek().is_some_and(is_ext) {
+        flags = skip_ext(r)?;
+        unpack_value(r, depth + 1)?;
+        count -= 1;
+    }
+    let mut pairs = Vec::with_capacity(count.min(r.data.len()));
+    for _ in 0..count {
+        let k = unpack_value(r, depth + 1)?;
+        let v = unpack_value(r, depth + 1)?;
+        pairs.push((k, v));
+    }
+    Ok(if flags & 0x08 != 0 {
+        Value::KeyValueList(pairs)
+    } else if flags & 0x01 != 0 {
+        Value::OrderedMap(pairs.into_iter().collect::<BTreeMap<_, _>>())
+    } else {
+        Value::HashMap(pairs.into_iter().collect::<HashMap<_, _>>())
+    })
+}
+
+fn unpack_value(r: &mut Reader<'_>, depth: usize) -> PyResult<Value> {
+    if depth > MAX_NESTING_DEPTH {
+        return Err(invalid(format!(
+            "msgpack nesting exceeds maximum depth of {MAX_NESTING_DEPTH}"
+        )));
+    }
+    let marker = r.u8()?;
+    Ok(match marker {
+        0x00..=0x7f => Value::Int(marker as i64),
+        0x80..=0x8f => return unpack_map(r, (marker & 0x0f) as usize, depth),
+        0x90..=0x9f => return unpack_list(r, (marker & 0x0f) as usize, depth),
+        0xa0..=0xbf => return unpack_prefixed(r, (marker & 0x1f) as usize),
+        0xc0 => Value::Nil,
+        0xc2 => Value::Bool(false),
+        0xc3 => Value::Bool(true),
+        0xc4 | 0xd9 => {
+            let len = r.u8()? as usize;
+            return unpack_prefixed(r, len);
+        }
+        0xc5 | 0xda => {
+            let len = r.len16()?;
+            return unpack_prefixed(r, len);
+        }
+        0xc6 | 0xdb => {
+            let len = r.len32()?;
+            return unpack_prefixed(r, len);
+        }
+        0xc7..=0xc9 | 0xd4..=0xd8 => {
+            r.pos -= 1;
+            skip_ext(r)?;
+            Value::Nil
+        }
+        0xca => Value::from(f32::from_be_bytes(r.array()?)),
+        0xcb => Value::Float(FloatValue::from(f64::from_be_bytes(r.array()?))),
+        0xcc => Value::Int(r.u8()? as i64),
+        0xcd => Value::Int(r.len16()? as i64),
+        0xce => Value::Int(r.len32()? as i64),
+        0xcf => Value::Int(u64::from_be_bytes(r.array()?) as i64),
+        0xd0 => Value::Int(r.u8()? as i8 as i64),
+        0xd1 => Value::Int(i16::from_be_bytes(r.array()?) as i64),
+        0xd2 => Value::Int(i32::from_be_bytes(r.array()?) as i64),
+        0xd3 => Value::Int(i64::from_be_bytes(r.array()?)),
+        0xdc => {
+            let n = r.len16()?;
+            return unpack_list(r, n, depth);
+        }
+        0xdd => {
+            let n = r.len32()?;
+            return unpack_list(r, n, depth);
+        }
+        0xde => {
+            let n = r.len16()?;
+            return unpack_map(r, n, depth);
+        }
+        0xdf => {
+            let n = r.len32()?;
+            return unpack_map(r, n, depth);
+        }
+        0xe0..=0xff => Value::Int(marker as i8 as i64),
+        0xc1 => return Err(invalid("invalid msgpack marker 0xc1")),
+    })
+}
+
+fn utf8(data: &[u8]) -> PyResult<String> {
+    String::from_utf8(data.to_vec()).map_err(|e| invalid(format!("invalid UTF-8 payload: {e}")))
+}
+
+fn fixed<const N: usize>(ptype: u8, data: &[u8]) -> PyResult<[u8; N]> {
+    data.try_into().map_err(|_| {
+        invalid(format!(
+            "particle type {ptype} expects a {N}-byte payload, got {} bytes",
+            data.len()
+        ))
+    })
+}
+
+/// Decode a `(particle_type, payload)` pair into a bin value.
+pub fn decode_particle(ptype: u8, data: &[u8]) -> PyResult<Value> {
+    Ok(match ptype {
+        PARTICLE_NULL => Value::Nil,
+        PARTICLE_INTEGER => Value::Int(i64::from_be_bytes(fixed(ptype, data)?)),
+        PARTICLE_DOUBLE => Value::Float(FloatValue::from(f64::from_be_bytes(fixed(ptype, data)?))),
+        PARTICLE_STRING => Value::String(utf8(data)?),
+        PARTICLE_BLOB => Value::Blob(data.to_vec()),
+        PARTICLE_BOOL => Value::Bool(fixed::<1>(ptype, data)?[0] != 0),
+        PARTICLE_HLL => Value::HLL(data.to_vec()),
+        PARTICLE_GEOJSON => {
+            if data.len() < 3 {
+                return Err(invalid("GeoJSON payload is shorter than its 3-byte header"));
+            }
+            let ncells = u16::from_be_bytes([data[1], data[2]]) as usize;
+            let json = data
+                .get(3 + ncells * 8..)
+                .ok_or_else(|| invalid("GeoJSON payload is shorter than its cell header"))?;
+            Value::GeoJSON(utf8(json)?)
+        }
+        PARTICLE_LIST | PARTICLE_MAP => {
+            let mut r = Reader { data, pos: 0 };
+            let value = unpack_value(&mut r, 0)?;
+            let ok = match value {
+                Value::List(_) => ptype == PARTICLE_LIST,
+                Value::HashMap(_) | Value::OrderedMap(_) | Value::KeyValueList(_) => {
+                    ptype == PARTICLE_MAP
+                }
+                _ => false,
+            };
+            if !ok || r.pos != data.len() {
+                return Err(invalid(format!(
+                    "payload is not a single msgpack {}",
+                    if ptype == PARTICLE_LIST {
+                        "array"
+                    } else {
+                        "map"
+                    }
+                )));
+            }
+            value
+        }
+        _ => return Err(invalid(format!("unsupported particle type {ptype}"))),
+    })
+}
+
+// ── Python conversion ────────────────────────────────────────────────────────
+
+/// Convert record bins into `{name: (particle_type, payload)}`.
+pub fn raw_bins_to_py<'py>(
+    py: Python<'py>,
+    bins: &HashMap<String, Value>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (name, value) in bins {
+        let (ptype, payload) = encode_particle(value)?;
+        let pair = PyTuple::new(
+            py,
+            [
+                ptype.into_pyobject(py)?.into_any(),
+                PyBytes::new(py, &payload).into_any(),
+            ],
+        )?;
+        dict.set_item(name, pair)?;
+    }
+    Ok(dict)
+}
+
+/// Convert `{name: (particle_type, payload)}` into bins for a write.
+///
+/// `payload` may be `bytes` or any contiguous byte buffer.
+pub fn py_raw_bins_to_bins(dict: &Bound<'_, PyDict>) -> PyResult<Vec<Bin>> {
+    let mut bins = Vec::with_capacity(dict.len());
+    for (key, item) in dict.iter() {
+        let name = bin_name(&key)?;
+        let (ptype, payload): (u8, Bound<'_, PyAny>) = item.extract().map_err(|_| {
+            ParamError::new_err(format!(
+                "raw bin '{name}' must be a (particle_type, bytes) tuple"
+            ))
+        })?;
+        let value = if let Ok(b) = payload.cast::<PyBytes>() {
+            decode_particle(ptype, b.as_bytes())?
+        } else if let Ok(buf) = PyBuffer::<u8>::get(&payload) {
+            decode_particle(ptype, &buf.to_vec(payload.py())?)?
+        } else {
+            return Err(ParamError::new_err(format!(
+                "raw bin '{name}' payload must be bytes-like, got {}",
+                payload.get_type().name()?
+            )));
+        };
+        bins.push(Bin::new(name, value));
+    }
+    Ok(bins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: Value) -> Value {
+        let (ptype, payload) = encode_particle(&value).unwrap();
+        decode_particle(ptype, &payload).unwrap()
+    }
+
+    #[test]
+    fn scalars_round_trip() {
+        for v in [
+            Value::Nil,
+            Value::Int(-1),
+            Value::Int(i64::MAX),
+            Value::Float(FloatValue::from(2.5)),
+            Value::String("héllo".into()),
+            Value::Blob(vec![0, 1, 255]),
+            Value::Bool(true),
+            Value::HLL(vec![7; 4]),
+            Value::GeoJSON(r#"{"type":"Point","coordinates":[0,0]}"#.into()),
+        ] {
+            assert_eq!(round_trip(v.clone()), v);
+        }
+    }
+
+    #[test]
+    fn integer_payload_is_big_endian() {
+        let (ptype, payload) = encode_particle(&Value::Int(1)).unwrap();
+        assert_eq!(ptype, PARTICLE_INTEGER);
+        assert_eq!(payload, vec![0, 0, 0, 0, 0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn cdts_round_trip() {
+        let ints = [
+            0,
+            127,
+            128,
+            255,
+            256,
+            65_536,
+            -1,
+            -32,
+            -33,
+            -129,
+            -40_000,
+            i64::MIN,
+        ];
+        let list = Value::List(
+            ints.iter()
+                .map(|&i| Value::Int(i))
+                .chain([Value::String("x".repeat(40)), Value::Blob(vec![1, 2])])
+                .collect(),
+        );
+        assert_eq!(round_trip(list.clone()), list);
+
+        let mut map = HashMap::new();
+        map.insert(Value::String("k".into()), list);
+        map.insert(Value::Int(3), Value::Nil);
+        let map = Value::HashMap(map);
+        assert_eq!(round_trip(map.clone()), map);
+
+        let ordered: BTreeMap<Value, Value> =
+            (0..20).map(|i| (Value::Int(i), Value::Int(-i))).collect();
+        let ordered = Value::OrderedMap(ordered);
+        assert_eq!(round_trip(ordered.clone()), ordered);
+    }
+
+    #[test]
+    fn rejects_malformed_payloads() {
+        assert!(decode_particle(PARTICLE_INTEGER, &[0, 1]).is_err());
+        assert!(decode_particle(PARTICLE_LIST, &[0x92, 0x01]).is_err());
+        assert!(decode_particle(PARTICLE_LIST, &[0x80]).is_err());
+        assert!(decode_particle(PARTICLE_STRING, &[0xff]).is_err());
+        assert!(decode_particle(99, &[]).is_err());
+    }
+}
@@ -2,21 +2,23 @@
 
 use aerospike_core::{Key, Record};
 use log::trace;
-use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyTuple};
 
 use super::key::key_to_py;
-use super::value::value_to_py;
-use crate::record_helpers::record_ttl_seconds;
+use super::raw::raw_bins_to_py;
+use super::value::{value_into_py, value_to_py};
+use crate::record_helpers::record_meta_dict;
 
 /// Convert a Rust Record to a Python tuple: (key, meta, bins)
 /// key = (namespace, set, user_key, digest)
-/// meta = {"gen": generation, "ttl": ttl_seconds}
+/// meta = {"gen": generation, "ttl": ttl_seconds, "void_time": unix_expiry}
 /// bins = {"bin_name": value, ...}
 ///
-/// When the server does not return a key (e.g. POLICY_KEY_DIGEST),
-/// `fallback_key` is used so the caller always gets a valid key tuple.
+/// The key tuple is always the original request key (`fallback_key`, with its
+/// client-computed digest and user key), whatever the policy sends or the
+/// server returns. Only records without a request key (query / scan results)
+/// use the key parsed from the server response.
 ///
 /// If `pre_key_py` is provided, it is used directly as the key tuple
 /// instead of converting `fallback_key` again (avoids double conversion).
@@ -25,7 +27,7 @@ pub fn record_to_py(
     record: &Record,
     fallback_key: Option<&Key>,
 ) -> PyResult<Py<PyAny>> {
-    record_to_py_inner(py, record, fallback_key, None)
+    record_to_py_inner(py, record, fallback_key, None, None)
 }
 
 /// Like `record_to_py` but accepts a pre-converted Python key to avoid
@@ -35,7 +37,52 @@ pub fn record_to_py_with_key(
     record: &Record,
     pre_key_py: Py<PyAny>,
 ) -> PyResult<Py<PyAny>> {
-    record_to_py_inner(py, record, None, Some(pre_key_py))
+    record_to_py_inner(py, record, None, Some(pre_key_py), None)
+}
+
+/// Like `record_to_py_with_key` but consumes the record, so large blob bins
+/// can be returned as zero-copy `memoryview`s (see [`super::blob`]).
+pub fn record_into_py_with_key(
+    py: Python<'_>,
+    mut record: Record,
+    pre_key_py: Py<PyAny>,
+) -> PyResult<Py<PyAny>> {
+    let bins = PyDict::new(py);
+    for (name, value) in record.bins.drain() {
+        bins.set_item(name, value_into_py(py, value)?)?;
+    }
+    record_to_py_inner(py, &record, None, Some(pre_key_py), Some(bins))
+}
+
+/// Like `record_to_py_with_key` but bins are `(particle_type, payload)`
+/// pairs (see [`super::raw`]) instead of decoded Python values.
+pub fn raw_record_to_py_with_key(
+    py: Python<'_>,
+    record: &Record,
+    pre_key_py: Py<PyAny>,
+) -> PyResult<Py<PyAny>> {
+    let bins = raw_bins_to_py(py, &record.bins)?;
+    record_to_py_inner(py, record, None, Some(pre_key_py), Some(bins))
+}
+
+/// Pick the key tuple for a returned record.
+///
+/// The request key wins: batch responses usually carry an empty server key
+/// (no namespace, zeroed digest) and single-record reads carry none, so
+/// preferring it would lose the key the caller asked for. `pre_key_py` is the request key already converted.
+pub(crate) fn record_key_py(
+    py: Python<'_>,
+    server_key: Option<&Key>,
+    fallback_key: Option<&Key>,
+    pre_key_py: Option<Py<PyAny>>,
+) -> PyResult<Py<PyAny>> {
+    if let Some(key_py) = pre_key_py {
+        return Ok(key_py);
+    }
+    match fallback_key.or(server_key) {
+        Some(key) => key_to_py(py, key),
+        None => Ok(py.None()),
+    }
 }
 
 fn record_to_py_inner(
@@ -43,31 +90,25 @@ fn record_to_py_inner(
     record: &Record,
     fallback_key: Option<&Key>,
     pre_key_py: Option<Py<PyAny>>,
+    pre_bins: Option<Bound<'_, PyDict>>,
 ) -> PyResult<Py<PyAny>> {
     trace!("Converting Rust record to Python");
-    // Key tuple: prefer the key returned by the server (honours POLICY_KEY_SEND),
-    // then the pre-converted key (avoids re-conversion), fall back to the original request key.
-    let key_py = match &record.key {
-        Some(key) => key_to_py(py, key)?,
-        None => match pre_key_py {
-            Some(k) => k,
-            None => match fallback_key {
-                Some(key) => key_to_py(py, key)?,
-                None => py.None(),
-            },
-        },
-    };
+    let key_py = record_key_py(py, record.key.as_ref(), fallback_key, pre_key_py)?;
 
-    // Meta dict — use interned keys to avoid repeated string allocation
-    let meta = PyDict::new(py);
-    meta.set_item(intern!(py, "gen"), record.generation)?;
-    meta.set_item(intern!(py, "ttl"), record_ttl_seconds(record))?;
+    // Meta dict (interned keys, see `record_meta_dict`)
+    let meta = record_meta_dict(py, record)?;
 
     // Bins dict
-    let bins = PyDict::new(py);
-    for (name, value) in &record.bins {
-        bins.set_item(name, value_to_py(py, value)?)?;
-    }
+    let bins = match pre_bins {
+        Some(bins) => bins,
+        None => {
+            let bins = PyDict::new(py);
+            for (name, value) in &record.bins {
+                bins.set_item(name, value_to_py(py, value)?)?;
+            }
+            bins
+        }
+    };
 
     let tuple = PyTuple::new(
         py,
@@ -75,3 +116,49 @@ fn record_to_py_inner(
     )?;
     Ok(tuple.into_any().unbind())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::record_key_py;
+    use aerospike_core::Key;
+    use pyo3::prelude::*;
+    use pyo3::types::PyTuple;
+
+    #[test]
+    fn record_key_py_prefers_request_key_over_server_key() {
+        Python::initialize();
+        Python::attach(|py| {
+            let request = aerospike_core::as_key!("test", "demo", "k1");
+            // What a batch response usually carries: no key fields at all.
+            let server = Key {
+                namespace: String::new(),
+                set_name: String::new(),
+                user_key: None,
+                digest: [0; 20],
+            };
+            let key_py = record_key_py(py, Some(&server), Some(&request), None).unwrap();
+            let tuple = key_py.bind(py).cast::<PyTuple>().unwrap();
+            assert_eq!(
+                tuple.get_item(0).unwrap().extract::<String>().unwrap(),
+                "test"
+            );
+            assert_eq!(
+                tuple.get_item(2).unwrap().extract::<String>().unwrap(),
+                "k1"
+            );
+        });
+    }
+
+    #[test]
+    fn record_key_py_uses_server_key_without_request_key() {
+        Python::initialize();
+        Python::attach(|py| {
+            let server = aerospike_core::as_key!("test", "demo", 7);
+            let key_py = record_key_py(py, Some(&server), None, None).unwrap();
+            let tuple = key_py.bind(py).cast::<PyTuple>().unwrap();
+            assert_eq!(tuple.get_item(2).unwrap().extract::<i64>().unwrap(), 7);
+
+            assert!(record_key_py(py, None, None, None).unwrap().is_none(py));
+        });
+    }
+}
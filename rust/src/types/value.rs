@@ -2,10 +2,13 @@
 
 use aerospike_core::Value;
 use log::warn;
+use pyo3::buffer::PyBuffer;
 use pyo3::prelude::*;
 use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString};
 use std::collections::HashMap;
 
+use super::blob::{blob_to_memoryview, use_blob_view};
+
 /// Maximum recursion depth for nested list/dict values to prevent stack overflow.
 const MAX_NESTING_DEPTH: usize = 64;
 
@@ -20,7 +23,7 @@ fn py_to_value_inner(obj: &Bound<'_, PyAny>, depth: usize) -> PyResult<Value> {
             "Value nesting exceeds maximum depth of {}",
             MAX_NESTING_DEPTH
         );
-        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+        return Err(crate::errors::ParamError::new_err(format!(
             "Value nesting exceeds maximum depth of {MAX_NESTING_DEPTH}"
         )));
     }
@@ -60,13 +63,29 @@ fn py_to_value_inner(obj: &Bound<'_, PyAny>, depth: usize) -> PyResult<Value> {
         }
         return Ok(Value::HashMap(map));
     }
+    // bytearray, memoryview, and other contiguous byte buffers (e.g. uint8 arrays)
+    if let Ok(buf) = PyBuffer::<u8>::get(obj) {
+        return Ok(Value::Blob(buf.to_vec(obj.py())?));
+    }
 
-    Err(pyo3::exceptions::PyTypeError::new_err(format!(
+    Err(crate::errors::ParamError::new_err(format!(
         "Unsupported type for Aerospike value: {}",
         obj.get_type().name()?
     )))
 }
 
+/// Convert an owned Aerospike Value to a Python object.
+///
+/// Blobs at or above the blob view threshold are handed to Python as a
+/// `memoryview` over the existing allocation; everything else goes through
+/// [`value_to_py`].
+pub fn value_into_py(py: Python<'_>, val: Value) -> PyResult<Py<PyAny>> {
+    match val {
+        Value::Blob(b) if use_blob_view(b.len()) => blob_to_memoryview(py, b),
+        other => value_to_py(py, &other),
+    }
+}
+
 /// Convert an Aerospike Value to a Python object
 pub fn value_to_py(py: Python<'_>, val: &Value) -> PyResult<Py<PyAny>> {
     match val {
@@ -0,0 +1,688 @@
+#!/usr/bin/env python3
+"""Generate type stubs for the native ``aerospike_py._aerospike`` module.
+
+Reads the PyO3 bindings in ``rust/src`` (``#[pyclass]`` / ``#[pymethods]``
+items, ``#[pyfunction]`` functions registered in ``lib.rs``, exceptions and
+constants) and writes ``src/aerospike_py/_aerospike.pyi``, so IDEs and type
+checkers see the native surface that the Python wrappers build on.
+
+Rust types map to Python types (``Option<T>`` -> ``T | None``, ``Vec<T>`` ->
+``list[T]``, ...). Loosely typed ``PyDict`` / ``PyAny`` parameters take their
+type from the parameter name: ``policy`` becomes the policy TypedDict of the
+method (``ReadPolicy`` for ``get``, ``BatchPolicy`` for ``batch_read``, ...),
+``meta`` becomes ``WriteMeta``.
+
+Usage:
+    python scripts/generate-native-stubs.py          # write the stub
+    python scripts/generate-native-stubs.py --check  # exit 1 if it is stale
+"""
+
+from __future__ import annotations
+
+import argparse
+import re
+import sys
+import textwrap
+from dataclasses import dataclass, field
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+RUST_SRC = ROOT / "rust" / "src"
+TYPES_PATH = ROOT / "src" / "aerospike_py" / "types.py"
+STUB_PATH = ROOT / "src" / "aerospike_py" / "_aerospike.pyi"
+
+HEADER = '''"""Native module built from ``rust/src`` (PyO3).
+
+AUTO-GENERATED by scripts/generate-native-stubs.py. Do not edit manually.
+"""
+
+'''
+
+# ---------------------------------------------------------------------------
+# Parameter types by name
+# ---------------------------------------------------------------------------
+
+_READ = ("get", "get_raw", "select", "exists")
+_WRITE = ("put", "put_raw", "remove", "touch", "append", "prepend", "increment", "remove_bin", "apply")
+_OPERATE = ("operate", "operate_ordered")
+_QUERY = ("results", "results_arrow", "results_columns", "results_numpy", "foreach", "to_df")
+_ADMIN_PREFIXES = ("admin_", "index_", "info_", "udf_")
+
+
+def _policy_type(method: str) -> str:
+    method = method.lstrip("_")  # ``_truncate_done`` / ``_udf_done`` pollers
+    if method in _READ:
+        return "ReadPolicy"
+    if method in _WRITE:
+        return "WritePolicy"
+    if method in _OPERATE:
+        return "OperatePolicy"
+    if method.startswith("batch_"):
+        return "BatchPolicy"
+    if method in _QUERY:
+        return "QueryPolicy"
+    if method.startswith(("truncate", "job_info", *_ADMIN_PREFIXES)):
+        return "AdminPolicy"
+    return "dict[str, Any]"
+
+
+# Types of loosely typed (``PyAny`` / ``PyDict`` / ``PyList``) method parameters.
+_PARAM_TYPES = {
+    "config": "dict[str, Any]",
+    "key": "_Key",
+    "keys": "list[_Key]",
+    "bins": "dict[str, Any]",
+    "meta": "WriteMeta",
+    "ops": "list[dict[str, Any]]",
+}
+
+_LOOSE = {"Any", "dict[str, Any]", "list[Any]", "tuple[Any, ...]"}
+
+
+def _kind(py_type: str) -> str:
+    if py_type.startswith("list["):
+        return "list"
+    if py_type.startswith(("tuple[", "_Key")):
+        return "tuple"
+    return "callable" if py_type.startswith("Callable") else "dict"
+
+
+def _override(py_type: str, override: str) -> str:
+    """``override`` in place of a loose ``py_type`` of the same container kind."""
+    base = py_type.removesuffix(" | None")
+    if base not in _LOOSE or (base != "Any" and _kind(base) != _kind(override)):
+        return py_type
+    return override + (" | None" if py_type.endswith(" | None") else "")
+
+
+# ---------------------------------------------------------------------------
+# Rust -> Python types
+# ---------------------------------------------------------------------------
+
+_SCALARS = {
+    **dict.fromkeys(
+        ("i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize"),
+        "int",
+    ),
+    "f32": "float",
+    "f64": "float",
+    "bool": "bool",
+    "String": "str",
+    "str": "str",
+    "PathBuf": "str",
+    "()": "None",
+}
+
+_PY_TYPES = {
+    "PyAny": "Any",
+    "PyDict": "dict[str, Any]",
+    "PyList": "list[Any]",
+    "PyTuple": "tuple[Any, ...]",
+    "PyBytes": "bytes",
+    "PyByteArray": "bytearray",
+    "PyString": "str",
+    "PyFloat": "float",
+    "PyInt": "int",
+    "PyBool": "bool",
+    "PyType": "type",
+    "PyIterator": "Iterator[Any]",
+}
+
+_WRAPPERS = ("Bound", "Py", "Borrowed", "PyRef", "PyRefMut")
+
+
+def _split_args(text: str) -> list[str]:
+    """Split on top-level commas (outside ``<>``, ``()``, ``[]``)."""
+    parts, depth, start = [], 0, 0
+    for i, ch in enumerate(text):
+        if ch in "<([":
+            depth += 1
+        elif ch in ">)]" and not (ch == ">" and text[i - 1] == "-"):
+            depth -= 1
+        elif ch == "," and depth == 0:
+            parts.append(text[start:i].strip())
+            start = i + 1
+    parts.append(text[start:].strip())
+    return [p for p in parts if p]
+
+
+def _generic(ty: str) -> tuple[str, list[str]] | None:
+    match = re.fullmatch(r"([\w:]+)\s*<(.*)>", ty, re.S)
+    if not match:
+        return None
+    name = match.group(1).rsplit("::", 1)[-1]
+    args = [a for a in _split_args(match.group(2)) if not a.startswith("'")]
+    return name, args
+
+
+class TypeMapper:
+    def __init__(self, classes: dict[str, str], typed_dicts: set[str]):
+        self.classes = classes
+        self.typed_dicts = typed_dicts
+
+    def __call__(self, ty: str, owner: str | None = None) -> str:
+        ty = re.sub(r"^&\s*('\w+\s+)?(mut\s+)?", "", ty.strip()).strip()
+        if ty in _SCALARS:
+            return _SCALARS[ty]
+        if ty.startswith("[") and ty.endswith("]"):
+            inner = ty[1:-1].split(";")[0]
+            return "bytes" if inner.strip() == "u8" else f"list[{self(inner, owner)}]"
+        if ty.startswith("(") and ty.endswith(")"):
+            items = [self(t, owner) for t in _split_args(ty[1:-1])]
+            return f"tuple[{', '.join(items)}]"
+        if ty == "Self":
+            return owner or "Any"
+        generic = _generic(ty)
+        if generic is None:
+            name = ty.rsplit("::", 1)[-1]
+            if name in self.classes:
+                return self.classes[name]
+            if name in self.typed_dicts:
+                return name
+            return _PY_TYPES.get(name, "Any")
+        name, args = generic
+        if name in ("PyResult", "Result"):
+            return self(args[0], owner)
+        if name == "Option":
+            inner = self(args[0], owner)
+            return inner if inner == "Any" else f"{inner} | None"
+        if name in ("Vec", "VecDeque", "HashSet", "BTreeSet"):
+            if args[0].strip() == "u8":
+                return "bytes"
+            return f"list[{self(args[0], owner)}]"
+        if name in ("HashMap", "BTreeMap", "IndexMap"):
+            return f"dict[{self(args[0], owner)}, {self(args[1], owner)}]"
+        if name == "Cow":
+            return self(args[-1], owner)
+        if name in _WRAPPERS:
+            return self(args[-1], owner) if args else "Any"
+        return "Any"
+
+
+# ---------------------------------------------------------------------------
+# Rust source scanning
+# ---------------------------------------------------------------------------
+
+
+def _skip_literal(src: str, i: int) -> int:
+    """Index after the string / char literal or comment starting at ``i``, or ``i``."""
+    if src.startswith("//", i):
+        end = src.find("\n", i)
+        return len(src) if end < 0 else end
+    if src.startswith("/*", i):
+        return src.index("*/", i) + 2
+    raw = re.match(r'(b?r)(#*)"', src[i : i + 16])
+    if raw:
+        closing = '"' + raw.group(2)
+        return src.index(closing, i + raw.end()) + len(closing)
+    if src[i] == '"' or src.startswith('b"', i):
+        j = i + (2 if src[i] == "b" else 1)
+        while src[j] != '"':
+            j += 2 if src[j] == "\\" else 1
+        return j + 1
+    if src[i] == "'":
+        char = re.match(r"'(\\.[^']*|[^\\'])'", src[i : i + 12])
+        if char:
+            return i + char.end()
+    return i
+
+
+def _block_end(src: str, open_brace: int) -> int:
+    """Index of the ``}`` matching the ``{`` at ``open_brace``."""
+    depth, i = 0, open_brace
+    while i < len(src):
+        j = _skip_literal(src, i)
+        if j != i:
+            i = j
+            continue
+        if src[i] == "{":
+            depth += 1
+        elif src[i] == "}":
+            depth -= 1
+            if depth == 0:
+                return i
+        i += 1
+    raise ValueError("unbalanced braces")
+
+
+def _paren_end(src: str, open_paren: int) -> int:
+    depth, i = 0, open_paren
+    while i < len(src):
+        j = _skip_literal(src, i)
+        if j != i:
+            i = j
+            continue
+        if src[i] == "(":
+            depth += 1
+        elif src[i] == ")":
+            depth -= 1
+            if depth == 0:
+                return i
+        i += 1
+    raise ValueError("unbalanced parentheses")
+
+
+@dataclass
+class RustFn:
+    name: str
+    attrs: list[str]
+    doc: list[str]
+    params: list[tuple[str, str]]
+    ret: str
+    body: str
+    start: int
+    end: int
+
+
+@dataclass
+class RustClass:
+    struct: str
+    name: str
+    fields: list[tuple[str, str, list[str]]] = field(default_factory=list)
+    methods: list[RustFn] = field(default_factory=list)
+
+
+def _attrs_and_doc(prefix: str) -> tuple[list[str], list[str]]:
+    attrs = re.findall(r"#\[(.*?)\]\s*$", prefix, re.M)
+    doc = [line.strip()[3:].removeprefix(" ") for line in prefix.splitlines() if line.strip().startswith("///")]
+    return attrs, doc
+
+
+def _parse_fns(src: str) -> list[RustFn]:
+    """Functions in ``src`` with their attributes and doc comments."""
+    fns = []
+    for match in re.finditer(r"\bfn\s+(\w+)\s*(<[^(]*>)?\s*\(", src):
+        start = src.rfind("\n", 0, match.start()) + 1
+        line_prefix = src[start : match.start()]
+        if line_prefix.strip() not in ("", "pub", "pub(crate)", "async", "pub async"):
+            continue
+        # Attributes and doc comments directly above the function.
+        above = src[:start]
+        lines = above.splitlines(keepends=True)
+        k = len(lines)
+        while k and (lines[k - 1].strip().startswith(("#[", "///", "//")) or _in_attr(lines, k - 1)):
+            k -= 1
+        prefix = "".join(lines[k:])
+        attrs, doc = _attrs_and_doc(_join_attrs(prefix))
+        close = _paren_end(src, match.end() - 1)
+        params = []
+        for param in _split_args(src[match.end() : close]):
+            if ":" not in param:
+                continue  # self / &self / &mut self
+            name, ty = param.split(":", 1)
+            params.append((name.strip().removeprefix("mut ").strip(), ty.strip()))
+        rest = src[close + 1 :]
+        ret_match = re.match(r"\s*(->\s*(.*?))?\s*(where\b[^{]*)?\{", rest, re.S)
+        ret = ret_match.group(2).strip() if ret_match and ret_match.group(2) else "()"
+        body_start = close + 1 + rest.index("{")
+        body_end = _block_end(src, body_start)
+        body = src[body_start : body_end + 1]
+        fns.append(RustFn(match.group(1), attrs, doc, params, ret, body, match.start(), body_end))
+    return fns
+
+
+def _in_attr(lines: list[str], idx: int) -> bool:
+    """Whether ``lines[idx]`` continues a multi-line ``#[...]`` attribute."""
+    depth = 0
+    for line in reversed(lines[: idx + 1]):
+        depth += line.count("]") - line.count("[")
+        if line.strip().startswith("#["):
+            return depth <= 0
+        if not line.strip() or line.rstrip().endswith(("{", "}", ";")):
+            return False
+    return False
+
+
+def _join_attrs(prefix: str) -> str:
+    """Put each ``#[...]`` attribute on one line."""
+    out, buf, depth = [], "", 0
+    for line in prefix.splitlines():
+        if depth == 0 and not line.strip().startswith("#["):
+            out.append(line)
+            continue
+        buf += " " + line.strip()
+        depth += line.count("[") - line.count("]")
+        if depth <= 0:
+            out.append(buf.strip())
+            buf, depth = "", 0
+    return "\n".join(out)
+
+
+_FIELD = re.compile(r"#\[pyo3\((get[^)]*)\)\]\s*(?:pub(?:\(crate\))?\s+)?(\w+):\s*([^,\n]+),")
+
+
+def _pyclasses(sources: dict[Path, str]) -> dict[str, RustClass]:
+    classes = {}
+    for src in sources.values():
+        for match in re.finditer(r"#\[pyclass\b(\((.*?)\))?\]", src, re.S):
+            struct = re.search(r"pub struct (\w+)", src[match.end() :])
+            if struct is None:
+                continue
+            name = re.search(r'name\s*=\s*"(\w+)"', match.group(2) or "")
+            cls = RustClass(struct.group(1), name.group(1) if name else struct.group(1))
+            body_start = match.end() + struct.end()
+            if src[body_start:].lstrip().startswith("{"):
+                open_brace = src.index("{", body_start)
+                body = src[open_brace + 1 : _block_end(src, open_brace)]
+                for flags, field_name, ty in _FIELD.findall(body):
+                    cls.fields.append((field_name, ty.strip(), flags.split(",")))
+            classes[cls.struct] = cls
+        for match in re.finditer(r"#\[pymethods\]\s*impl\s+(\w+)\s*\{", src):
+            open_brace = match.end() - 1
+            block = src[open_brace + 1 : _block_end(src, open_brace)]
+            methods = _items(_parse_fns(block))
+            classes.setdefault(match.group(1), RustClass(match.group(1), match.group(1))).methods.extend(methods)
+    return classes
+
+
+def _items(fns: list[RustFn]) -> list[RustFn]:
+    """Drop functions nested in the body of an earlier one."""
+    items: list[RustFn] = []
+    for fn in fns:
+        if not items or fn.start > items[-1].end:
+            items.append(fn)
+    return items
+
+
+def _pyo3_option(attrs: list[str], key: str) -> str | None:
+    for attr in attrs:
+        if not attr.startswith("pyo3("):
+            continue
+        inner = attr[5:-1]
+        for part in _split_args(inner):
+            name, _, value = part.partition("=")
+            if name.strip() == key:
+                return value.strip()
+    return None
+
+
+# ---------------------------------------------------------------------------
+# Stub rendering
+# ---------------------------------------------------------------------------
+
+_SKIPPED_DUNDERS = {"__traverse__", "__clear__", "__getbuffer__", "__releasebuffer__", "__richcmp__"}
+
+
+def _py_default(value: str) -> str:
+    value = value.strip()
+    if value in ("true", "false"):
+        return value.title()
+    return value if re.fullmatch(r'None|-?[\d.]+|"[^"]*"', value) else "..."
+
+
+@dataclass
+class Param:
+    name: str
+    annotation: str
+    default: str | None = None
+    prefix: str = ""
+
+
+def _signature_params(fn: RustFn, method: str, mapper: TypeMapper, owner: str | None) -> list[Param | str]:
+    types = {}
+    for name, ty in fn.params:
+        if re.search(r"\bPython\b", ty) or name in ("slf", "_slf"):
+            continue
+        py_type = mapper(ty, owner)
+        if name == "policy" and py_type.removesuffix(" | None") in _LOOSE:
+            py_type = _policy_type(method) + (" | None" if py_type.endswith(" | None") else "")
+        elif name in _PARAM_TYPES and owner is not None:
+            py_type = _override(py_type, _PARAM_TYPES[name])
+        types[name.lstrip("_") if name.startswith("_") and name != "_" else name] = (name, py_type)
+    signature = _pyo3_option(fn.attrs, "signature")
+    if signature is None:
+        return [Param(name, ty) for name, ty in types.values()]
+    params: list[Param | str] = []
+    for item in _split_args(signature.strip()[1:-1]):
+        if item in ("*", "/"):
+            params.append(item)
+            continue
+        prefix = "**" if item.startswith("**") else "*" if item.startswith("*") else ""
+        name, _, default = item.lstrip("*").partition("=")
+        name = name.strip()
+        _, py_type = types.get(name, types.get(name.lstrip("_"), (name, "Any")))
+        if prefix:
+            py_type = "Any"
+        elif default.strip() == "None" and not py_type.endswith("None") and py_type != "Any":
+            py_type += " | None"
+        params.append(Param(name, py_type, _py_default(default) if default else None, prefix))
+    return params
+
+
+def _render_def(
+    name: str,
+    params: list[Param | str],
+    ret: str,
+    doc: list[str],
+    indent: str,
+    decorators: list[str],
+    is_method: bool,
+) -> list[str]:
+    rendered = ["self"] if is_method else []
+    for p in params:
+        if isinstance(p, str):
+            rendered.append(p)
+            continue
+        text = f"{p.prefix}{p.name}: {p.annotation}"
+        if p.default is not None:
+            text += f" = {p.default}"
+        rendered.append(text)
+    lines = [f"{indent}@{d}" for d in decorators]
+    one_line = f"{indent}def {name}({', '.join(rendered)}) -> {ret}:"
+    if not doc and len(one_line) + 4 <= 120:
+        lines.append(one_line + " ...")
+        return lines
+    if len(one_line) <= 120:
+        lines.append(one_line)
+    else:
+        lines.append(f"{indent}def {name}(")
+        lines.extend(f"{indent}    {r}," for r in rendered)
+        lines.append(f"{indent}) -> {ret}:")
+    if doc:
+        body = "\n".join(doc).strip().replace('"""', r"\"\"\"")
+        doc_lines = body.splitlines()
+        if len(doc_lines) == 1 and len(indent) + 10 + len(body) <= 120:
+            lines.append(f'{indent}    """{body}"""')
+        else:
+            lines.append(f'{indent}    """{doc_lines[0]}')
+            lines.extend(f"{indent}    {line}".rstrip() for line in doc_lines[1:])
+            lines.append(f'{indent}    """')
+    lines.append(f"{indent}    ...")
+    return lines
+
+
+def _return_type(fn: RustFn, mapper: TypeMapper, owner: str | None, is_async: bool) -> str:
+    # Methods of an async class return their future as a bare ``PyAny``.
+    if is_async and re.search(r"Bound<'py,\s*PyAny>", fn.ret):
+        return "Awaitable[Any]"
+    return mapper(fn.ret, owner)
+
+
+def render_class(cls: RustClass, mapper: TypeMapper, bases: str = "") -> list[str]:
+    lines = [f"class {cls.name}{bases}:"]
+    is_async = any("future_into_py" in fn.body for fn in cls.methods)
+    for name, ty, flags in cls.fields:
+        lines.append("    @property")
+        lines.append(f"    def {name}(self) -> {mapper(ty, cls.name)}: ...")
+        if any(flag.strip() == "set" for flag in flags):
+            lines.append(f"    @{name}.setter")
+            lines.append(f"    def {name}(self, value: {mapper(ty, cls.name)}) -> None: ...")
+    for fn in cls.methods:
+        if fn.name in _SKIPPED_DUNDERS or any(a.startswith("cfg(test") for a in fn.attrs):
+            continue
+        py_name = (_pyo3_option(fn.attrs, "name") or f'"{fn.name}"').strip('"')
+        decorators = []
+        ret = _return_type(fn, mapper, cls.name, is_async)
+        if "new" in fn.attrs:
+            py_name, ret = "__init__", "None"
+        elif any(a.startswith("getter") for a in fn.attrs):
+            getter = re.match(r"getter\((\w+)\)", next(a for a in fn.attrs if a.startswith("getter")))
+            py_name = getter.group(1) if getter else fn.name.removeprefix("get_")
+            decorators.append("property")
+        elif any(a.startswith("setter") for a in fn.attrs):
+            continue
+        if "staticmethod" in fn.attrs:
+            decorators.append("staticmethod")
+        if "classmethod" in fn.attrs:
+            decorators.append("classmethod")
+        params = _signature_params(fn, py_name, mapper, cls.name)
+        if "classmethod" in fn.attrs:
+            params = params[1:]
+        is_method = "staticmethod" not in fn.attrs
+        if len(lines) > 1:
+            lines.append("")
+        lines.extend(_render_def(py_name, params, ret, fn.doc, "    ", decorators, is_method))
+    if len(lines) == 1:
+        lines.append("    ...")
+    return lines
+
+
+def render_function(fn: RustFn, mapper: TypeMapper) -> list[str]:
+    py_name = (_pyo3_option(fn.attrs, "name") or f'"{fn.name}"').strip('"')
+    params = _signature_params(fn, py_name, mapper, None)
+    return _render_def(py_name, params, mapper(fn.ret), fn.doc, "", [], False)
+
+
+# ---------------------------------------------------------------------------
+# Module surface
+# ---------------------------------------------------------------------------
+
+
+def _module_fns(lib: str, sources: dict[Path, str]) -> list[RustFn]:
+    fns = []
+    for path in re.findall(r"wrap_pyfunction!\(\s*([\w:]+)\s*,", lib):
+        *modules, name = path.split("::")
+        if modules:
+            file = RUST_SRC.joinpath(*modules).with_suffix(".rs")
+            if not file.exists():
+                file = RUST_SRC.joinpath(*modules, "mod.rs")
+        else:
+            file = RUST_SRC / "lib.rs"
+        # The first definition wins for ``#[cfg]`` alternatives.
+        fns.append(next(fn for fn in _parse_fns(sources[file]) if fn.name == name and "pyfunction" in fn.attrs))
+    return fns
+
+
+def _exceptions(errors: str) -> list[str]:
+    defs = {
+        m.group(1): (m.group(2), m.group(3))
+        for m in re.finditer(r'create_exception!\(\s*\w+,\s*(\w+),\s*(\w+),\s*"([^"]*)"\s*\)', errors, re.S)
+    }
+    retryable_block = re.search(r"fn mark_retryable.*?\[(.*?)\]", errors, re.S)
+    retryable = set(re.findall(r"get_type::<(\w+)>", retryable_block.group(1))) if retryable_block else set()
+    lines = []
+    for exported, rust in re.findall(r'm\.add\(\s*"(\w+)",\s*py\.get_type::<(\w+)>\(\)\s*,?\s*\)', errors):
+        base, doc = defs[rust]
+        doc = re.sub(r"\\\n\s*", "", doc)
+        if exported != rust and any(line.startswith(f"class {rust}(") for line in lines):
+            lines.extend(["", f"{exported} = {rust}"])
+            continue
+        if lines:
+            lines.append("")
+        base = {"PyException": "Exception"}.get(base, base)
+        bases = f"{base}, RetryableError" if rust in retryable else base
+        lines.append(f"class {exported}({bases}):")
+        wrapped = textwrap.wrap(doc, 113)
+        if len(wrapped) == 1:
+            lines.append(f'    """{doc}"""')
+        else:
+            lines.extend([f'    """{wrapped[0]}', *(f"    {line}" for line in wrapped[1:]), '    """'])
+        if exported == "AerospikeError":
+            lines.extend(
+                [
+                    "",
+                    "    code: int | None",
+                    "    msg: str",
+                    "    in_doubt: bool",
+                    "    key: tuple[str, str, Any, bytes] | None",
+                    "    bin: str | None",
+                ]
+            )
+        for attr in re.findall(r"for attr in \[(.*?)\]", errors) if exported == "UDFError" else []:
+            lines.append("")
+            for name in re.findall(r'"(\w+)"', attr):
+                py_type = "int | None" if name == "line" else "str | None"
+                lines.append(f"    {name}: {py_type}")
+    return lines
+
+
+def _constants(constants: str) -> list[str]:
+    lines = []
+    consts = dict(re.findall(r"pub const (\w+): \w+ = (-?\d+);", constants))
+    dicts = set(re.findall(r"let (\w+) = PyDict::new\(", constants))
+    # rustfmt wraps long `m.add(...)` calls over several lines
+    constants = re.sub(r'm\.add\(\s*("\w+"),\s*(\w+),?\s*\)\?;', r"m.add(\1, \2)?;", constants)
+    for line in constants.splitlines():
+        comment = re.match(r"\s*// --- (.*?) ---", line)
+        if comment:
+            lines.append(f"\n# {comment.group(1)}")
+            continue
+        match = re.match(r'\s*m\.add\("(\w+)",\s*(.*?)\)\?;', line)
+        if match is None:
+            continue
+        value = consts.get(match.group(2), match.group(2))
+        number = re.fullmatch(r"(-?\d+)(?:[iu]\d+|usize|isize)?", value)
+        if number:
+            lines.append(f"{match.group(1)}: Literal[{number.group(1)}]")
+        elif re.fullmatch(r'"[^"]*"', value):
+            lines.append(f"{match.group(1)}: Literal[{value}]")
+        elif value in dicts:
+            lines.append(f"{match.group(1)}: dict[str, int]")
+        else:
+            lines.append(f"{match.group(1)}: int")
+    return lines
+
+
+def generate() -> str:
+    sources = {path: path.read_text(encoding="utf-8") for path in RUST_SRC.rglob("*.rs")}
+    lib = sources[RUST_SRC / "lib.rs"]
+    rust_classes = _pyclasses(sources)
+    registered = [struct.rsplit("::", 1)[-1] for struct in re.findall(r"add_class::<([\w:]+)>", lib)]
+    classes = {struct: rust_classes[struct].name for struct in registered}
+    typed_dicts = set(re.findall(r"^class (\w+)\(", TYPES_PATH.read_text(encoding="utf-8"), re.M))
+    mapper = TypeMapper(classes, typed_dicts - set(classes.values()))
+
+    body = ["_Key = tuple[Any, ...]", ""]
+    for struct in registered:
+        body.append("")
+        body.extend(render_class(rust_classes[struct], mapper))
+        body.append("")
+    for fn in _module_fns(lib, sources):
+        body.append("")
+        body.extend(render_function(fn, mapper))
+    body.append("")
+    body.append("# -- Exceptions --")
+    body.append("")
+    body.extend(_exceptions(sources[RUST_SRC / "errors.rs"]))
+    body.append("")
+    body.append("# -- Constants --")
+    body.extend(_constants(sources[RUST_SRC / "constants.rs"]))
+
+    text = "\n".join(body)
+    code = re.sub(r'"""(.*?)"""|#[^\n]*', "", text, flags=re.S)
+    used = sorted(name for name in typed_dicts - set(classes.values()) if re.search(rf"\b{name}\b", code))
+    abc = [name for name in ("Awaitable", "Callable", "Iterator") if re.search(rf"\b{name}\[", code)]
+    imports = f"from collections.abc import {', '.join(abc)}\n" if abc else ""
+    imports += "from typing import Any, Literal\n\n"
+    if used:
+        imports += "from aerospike_py.types import (\n" + "".join(f"    {n},\n" for n in used) + ")\n\n"
+    text = re.sub(r"\n{3,}", "\n\n", text)
+    return HEADER + imports + text.strip() + "\n"
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--check", action="store_true", help="fail if the stub is out of date")
+    args = parser.parse_args()
+    stub = generate()
+    if args.check:
+        if not STUB_PATH.exists() or STUB_PATH.read_text(encoding="utf-8") != stub:
+            print(f"{STUB_PATH.relative_to(ROOT)} is out of date; run scripts/generate-native-stubs.py")
+            sys.exit(1)
+        return
+    STUB_PATH.write_text(stub, encoding="utf-8")
+    print(f"Generated {STUB_PATH.relative_to(ROOT)}")
+
+
+if __name__ == "__main__":
+    main()
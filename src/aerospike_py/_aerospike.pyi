@@ -0,0 +1,2044 @@
+"""Native module built from ``rust/src`` (PyO3).
+
+AUTO-GENERATED by scripts/generate-native-stubs.py. Do not edit manually.
+"""
+
+from collections.abc import Awaitable
+from typing import Any, Literal
+
+from aerospike_py.types import (
+    AdminPolicy,
+    BatchPolicy,
+    BulkPutResult,
+    JobInfo,
+    KeyNode,
+    NodeInfo,
+    NodeStats,
+    OperatePolicy,
+    QueryPolicy,
+    ReadPolicy,
+    WriteMeta,
+    WritePolicy,
+)
+
+_Key = tuple[Any, ...]
+
+class Client:
+    def __init__(self, config: dict[str, Any]) -> None: ...
+
+    def connect(self, username: str | None = None, password: str | None = None) -> None:
+        """Connect to the Aerospike cluster. Returns self for chaining."""
+        ...
+
+    def is_connected(self) -> bool:
+        """Check if the client is connected"""
+        ...
+
+    def ping(self) -> bool:
+        """Lightweight health check: returns `True` if a random node responds."""
+        ...
+
+    def warm_up(self, conns_per_node: int) -> int:
+        """Pre-open `conns_per_node` connections to each node; returns the total opened."""
+        ...
+
+    def _relogin(self) -> int:
+        """Close idle pooled connections so the next commands log in again.
+
+        Called by the Python wrapper after `SessionExpired`; returns the number
+        of connections closed.
+        """
+        ...
+
+    def on_cluster_event(self, callback: Any) -> None:
+        """Register a callback for node and partition map changes (`None` removes it)."""
+        ...
+
+    def set_bin_cipher(self, encrypt_fn: Any, decrypt_fn: Any, bins: list[str] | None = None) -> None:
+        """Register `bytes -> bytes` hooks that encrypt `bins` on write and
+        decrypt them on read (`None` for both removes them).
+        """
+        ...
+
+    def close(self) -> None:
+        """Close the connection to the cluster"""
+        ...
+
+    def __enter__(self) -> Client:
+        """Context manager entry."""
+        ...
+
+    def __exit__(self, _exc_type: Any = None, _exc_val: Any = None, _exc_tb: Any = None) -> bool:
+        """Context manager exit: closes the client and never suppresses the exception.
+
+        **Note:** the Python wrapper in `src/aerospike_py/_client.py` defines
+        its own `__exit__` that delegates to `self.close()`, so the wrapper's
+        `close()` bookkeeping (`auto_reconnect`) runs. This native method
+        serves callers that use the PyO3 class directly.
+        """
+        ...
+
+    def get_node_names(self) -> list[str]:
+        """Get node names in the cluster"""
+        ...
+
+    def get_nodes(self) -> list[NodeInfo]:
+        """Name, address, port, state and rack of every node."""
+        ...
+
+    def get_node_for_key(self, key: _Key) -> KeyNode:
+        """Master node (name, address, port) of a key's partition."""
+        ...
+
+    def get_partition_id(self, key: _Key) -> int:
+        """Partition (0-4095) a key belongs to; computed client-side."""
+        ...
+
+    def get_cluster_stats(self) -> dict[str, NodeStats]:
+        """Per-node client-side stats keyed by node name."""
+        ...
+
+    def info_all(self, command: str, policy: AdminPolicy | None = None) -> list[tuple[str, int, str]]:
+        """Send an info command to all nodes in the cluster.
+        Returns a list of (node_name, error_code, response) tuples.
+        """
+        ...
+
+    def info_random_node(self, command: str, policy: AdminPolicy | None = None) -> str:
+        """Send an info command to a random node in the cluster.
+        Returns the response string.
+        """
+        ...
+
+    def info_single_node(self, command: str, node: str, policy: AdminPolicy | None = None) -> str:
+        """Send an info command to one node, chosen by node name or `host:port`."""
+        ...
+
+    def info_namespaces(self, node: str | None = None, policy: AdminPolicy | None = None) -> list[str]:
+        """List namespaces (`info("namespaces")`)."""
+        ...
+
+    def info_sets(self, namespace: str, node: str | None = None, policy: AdminPolicy | None = None) -> list[Any]:
+        """List sets of a namespace with their statistics (`info("sets/<ns>")`)."""
+        ...
+
+    def info_sindex(
+        self,
+        namespace: str | None = None,
+        node: str | None = None,
+        policy: AdminPolicy | None = None,
+    ) -> list[Any]:
+        """List secondary indexes, optionally of one namespace (`info("sindex-list")`)."""
+        ...
+
+    def put(
+        self,
+        key: _Key,
+        bins: dict[str, Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        return_meta: bool = False,
+    ) -> Any:
+        """Write a record; with `return_meta`, return its meta after the write"""
+        ...
+
+    def get(self, key: _Key, policy: ReadPolicy | None = None) -> Any:
+        """Read a record"""
+        ...
+
+    def select(self, key: _Key, bins: list[Any], policy: ReadPolicy | None = None) -> Any:
+        """Read specific bins of a record"""
+        ...
+
+    def get_raw(self, key: _Key, policy: ReadPolicy | None = None) -> Any:
+        """Read a record with bins as `(particle_type, payload)` wire-format pairs"""
+        ...
+
+    def put_raw(
+        self,
+        key: _Key,
+        bins: dict[str, Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+    ) -> None:
+        """Write a record from `(particle_type, payload)` wire-format bins"""
+        ...
+
+    def exists(self, key: _Key, policy: ReadPolicy | None = None) -> Any:
+        """Check if a record exists. Returns (key, meta) or (key, None)"""
+        ...
+
+    def remove(self, key: _Key, meta: WriteMeta | None = None, policy: WritePolicy | None = None) -> None:
+        """Remove a record"""
+        ...
+
+    def touch(self, key: _Key, val: int = 0, meta: WriteMeta | None = None, policy: WritePolicy | None = None) -> None:
+        """Reset record's TTL"""
+        ...
+
+    def append(
+        self,
+        key: _Key,
+        bin: str,
+        val: Any,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        return_value: bool = False,
+    ) -> Any:
+        """Append a string to a bin; with `return_value`, return the bin's new value"""
+        ...
+
+    def prepend(
+        self,
+        key: _Key,
+        bin: str,
+        val: Any,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        return_value: bool = False,
+    ) -> Any:
+        """Prepend a string to a bin; with `return_value`, return the bin's new value"""
+        ...
+
+    def increment(
+        self,
+        key: _Key,
+        bin: str,
+        offset: Any,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        return_value: bool = False,
+    ) -> Any:
+        """Increment an integer bin; with `return_value`, return the bin's new value"""
+        ...
+
+    def remove_bin(
+        self,
+        key: _Key,
+        bin_names: list[Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+    ) -> None:
+        """Remove bins from a record by setting them to nil"""
+        ...
+
+    def operate(
+        self,
+        key: _Key,
+        ops: list[dict[str, Any]],
+        meta: WriteMeta | None = None,
+        policy: OperatePolicy | None = None,
+    ) -> Any:
+        """Perform multiple operations on a single record"""
+        ...
+
+    def operate_ordered(
+        self,
+        key: _Key,
+        ops: list[dict[str, Any]],
+        meta: WriteMeta | None = None,
+        policy: OperatePolicy | None = None,
+    ) -> Any:
+        """Perform multiple operations on a single record, returning ordered results"""
+        ...
+
+    def query(self, namespace: str, set_name: Any) -> Query:
+        """Create a Query object for the given namespace and set."""
+        ...
+
+    def bind_query(self, query: Query) -> Query:
+        """Bind a copy of a `Query` (e.g. one built with `Query(...)`) to this client."""
+        ...
+
+    def pipeline(self, max_concurrency: int = ...) -> Pipeline:
+        """Create a Pipeline that runs queued operations with bounded concurrency."""
+        ...
+
+    def index_integer_create(
+        self,
+        namespace: str,
+        set_name: str,
+        bin_name: str,
+        index_name: str,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> None:
+        """Create a secondary integer index."""
+        ...
+
+    def index_string_create(
+        self,
+        namespace: str,
+        set_name: str,
+        bin_name: str,
+        index_name: str,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> None:
+        """Create a secondary string index."""
+        ...
+
+    def index_geo2dsphere_create(
+        self,
+        namespace: str,
+        set_name: str,
+        bin_name: str,
+        index_name: str,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> None:
+        """Create a secondary geo2dsphere index."""
+        ...
+
+    def index_status(self, namespace: str, index_name: str, policy: AdminPolicy | None = None) -> int:
+        """Build progress of a secondary index in percent (lowest across nodes)."""
+        ...
+
+    def job_info(self, job_id: int, module: str, policy: AdminPolicy | None = None) -> JobInfo:
+        """State of a scan / query job across the cluster."""
+        ...
+
+    def index_remove(self, namespace: str, index_name: str, policy: AdminPolicy | None = None) -> None:
+        """Remove a secondary index."""
+        ...
+
+    def truncate(
+        self,
+        namespace: str,
+        set_name: str,
+        nanos: int = 0,
+        policy: AdminPolicy | None = None,
+        wait: bool = False,
+        timeout: float | None = None,
+    ) -> None:
+        """Remove records in specified namespace/set efficiently."""
+        ...
+
+    def _truncate_done(self, namespace: str, set_name: str, nanos: int = 0, policy: AdminPolicy | None = None) -> bool:
+        """Whether a truncate of `namespace`/`set_name` has finished on every
+        node. Polled by `TruncateTask`.
+        """
+        ...
+
+    def udf_put(self, filename: str, udf_type: int = 0, policy: AdminPolicy | None = None, wait: bool = True) -> None:
+        """Register a UDF module from a file."""
+        ...
+
+    def udf_remove(self, module: str, policy: AdminPolicy | None = None, wait: bool = True) -> None:
+        """Remove a UDF module."""
+        ...
+
+    def _udf_done(self, module: str, removed: bool = False, policy: AdminPolicy | None = None) -> bool:
+        """Whether a `udf_put()` (or, with `removed`, a `udf_remove()`) of
+        `module` has reached every node. Polled by `UdfTask`.
+        """
+        ...
+
+    def udf_list(self, policy: AdminPolicy | None = None) -> list[Any]:
+        """List registered UDF modules (`info("udf-list")`)."""
+        ...
+
+    def udf_get(self, module: str, policy: AdminPolicy | None = None) -> str:
+        """Return the Lua source of a registered UDF module (`info("udf-get")`)."""
+        ...
+
+    def apply(
+        self,
+        key: _Key,
+        module: str,
+        function: str,
+        args: list[Any] | None = None,
+        policy: WritePolicy | None = None,
+    ) -> Any:
+        """Execute a UDF on a single record."""
+        ...
+
+    def admin_create_user(
+        self,
+        username: str,
+        password: str,
+        roles: list[str],
+        policy: AdminPolicy | None = None,
+    ) -> None:
+        """Create a new user with the given roles."""
+        ...
+
+    def admin_drop_user(self, username: str, policy: AdminPolicy | None = None) -> None:
+        """Drop (delete) a user."""
+        ...
+
+    def admin_change_password(self, username: str, password: str, policy: AdminPolicy | None = None) -> None:
+        """Change user password."""
+        ...
+
+    def admin_grant_roles(self, username: str, roles: list[str], policy: AdminPolicy | None = None) -> None:
+        """Grant roles to a user."""
+        ...
+
+    def admin_revoke_roles(self, username: str, roles: list[str], policy: AdminPolicy | None = None) -> None:
+        """Revoke roles from a user."""
+        ...
+
+    def admin_query_user_info(self, username: str, policy: AdminPolicy | None = None) -> Any:
+        """Query info about a specific user."""
+        ...
+
+    def admin_query_users_info(self, policy: AdminPolicy | None = None) -> Any:
+        """Query info about all users."""
+        ...
+
+    def admin_whoami(self, policy: AdminPolicy | None = None) -> Any:
+        """Query info about the user this client is authenticated as."""
+        ...
+
+    def admin_create_role(
+        self,
+        role: str,
+        privileges: list[Any],
+        policy: AdminPolicy | None = None,
+        whitelist: list[str] | None = None,
+        read_quota: int = 0,
+        write_quota: int = 0,
+    ) -> None:
+        """Create a new role with the given privileges."""
+        ...
+
+    def admin_drop_role(self, role: str, policy: AdminPolicy | None = None) -> None:
+        """Drop (delete) a role."""
+        ...
+
+    def admin_grant_privileges(self, role: str, privileges: list[Any], policy: AdminPolicy | None = None) -> None:
+        """Grant privileges to a role."""
+        ...
+
+    def admin_revoke_privileges(self, role: str, privileges: list[Any], policy: AdminPolicy | None = None) -> None:
+        """Revoke privileges from a role."""
+        ...
+
+    def admin_query_role(self, role: str, policy: AdminPolicy | None = None) -> Any:
+        """Query info about a specific role."""
+        ...
+
+    def admin_query_roles(self, policy: AdminPolicy | None = None) -> Any:
+        """Query info about all roles."""
+        ...
+
+    def admin_set_whitelist(self, role: str, whitelist: list[str], policy: AdminPolicy | None = None) -> None:
+        """Set allowlist (whitelist) for a role."""
+        ...
+
+    def admin_set_quotas(
+        self,
+        role: str,
+        read_quota: int = 0,
+        write_quota: int = 0,
+        policy: AdminPolicy | None = None,
+    ) -> None:
+        """Set quotas for a role."""
+        ...
+
+    def batch_read(
+        self,
+        keys: list[_Key],
+        bins: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        _dtype: Any = None,
+        _null_mask: bool = False,
+        output: str | None = None,
+    ) -> Any:
+        """Read multiple records. Returns BatchRecords, NumpyBatchRecords when dtype is provided,
+        or a `pyarrow.RecordBatch` when `output="arrow"`.
+        """
+        ...
+
+    def batch_read_df(
+        self,
+        keys: list[_Key],
+        columns: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+    ) -> Any:
+        """Read multiple records into DataFrame column buffers.
+
+        Returns `(index, columns)`; the Python wrapper builds the `pandas.DataFrame`.
+        """
+        ...
+
+    def batch_exists(self, keys: list[_Key], policy: BatchPolicy | None = None, dtype: Any = None) -> Any:
+        """Check existence of multiple records with a header-only batch read.
+
+        Returns `list[(key, meta | None)]`, or `(found, meta)` NumPy arrays
+        when a boolean `dtype` is provided.
+        """
+        ...
+
+    def batch_operate(self, keys: list[_Key], ops: list[dict[str, Any]], policy: BatchPolicy | None = None) -> Any:
+        """Perform operations on multiple records. Returns list of (key, meta, bins) tuples."""
+        ...
+
+    def batch_write(self, records: list[Any], policy: BatchPolicy | None = None, retry: int = 0) -> Any:
+        """Write multiple records with per-record bins.
+
+        Each record is a (key, bins) tuple. Unlike `batch_operate()` (which applies
+        the same operations to all keys), `batch_write()` writes different bins per key.
+        """
+        ...
+
+    def batch_write_numpy(
+        self,
+        data: Any,
+        namespace: str,
+        set_name: str,
+        _dtype: Any,
+        key_field: str = "_key",
+        policy: BatchPolicy | None = None,
+        retry: int = 0,
+    ) -> Any:
+        """Write multiple records from a numpy structured array.
+
+        Each row becomes a separate write operation in the batch.
+        The dtype must contain a `_key` field (or custom key_field) for the record key,
+        and remaining non-underscore-prefixed fields become bins.
+        """
+        ...
+
+    def bulk_put(
+        self,
+        records: Any,
+        namespace: str,
+        set_name: str,
+        key_fn: Any = None,
+        policy: dict[str, Any] | None = None,
+        chunk_size: int = ...,
+        concurrency: int = ...,
+    ) -> BulkPutResult:
+        """Write records from an iterable of bins dicts or `(key, bins)` tuples.
+
+        The iterable is read one wave of `concurrency` chunks at a time; each
+        chunk of `chunk_size` records is written as one batch, with the wave's
+        batches in flight together. Per-record failures are counted in the
+        returned summary instead of raised.
+        """
+        ...
+
+    def batch_remove(self, keys: list[_Key], policy: BatchPolicy | None = None) -> Any:
+        """Remove multiple records."""
+        ...
+
+    def batch_apply(
+        self,
+        keys: list[_Key],
+        module: str,
+        function: str,
+        args: list[Any] | None = None,
+        policy: BatchPolicy | None = None,
+    ) -> Any:
+        """Execute a UDF on multiple records in a single batch call."""
+        ...
+
+class AsyncClient:
+    def __init__(self, config: dict[str, Any]) -> None: ...
+
+    def connect(self, username: str | None = None, password: str | None = None) -> Awaitable[Any]:
+        """Connect to the Aerospike cluster (async)."""
+        ...
+
+    def is_connected(self) -> bool:
+        """Check if connected (sync, no I/O, lock-free)."""
+        ...
+
+    def ping(self) -> Awaitable[Any]:
+        """Lightweight health check: returns `True` if a random node responds."""
+        ...
+
+    def warm_up(self, conns_per_node: int) -> Awaitable[Any]:
+        """Pre-open `conns_per_node` connections to each node (async)."""
+        ...
+
+    def _relogin(self) -> Awaitable[Any]:
+        """Close idle pooled connections so the next commands log in again (async).
+
+        Called by the Python wrapper after `SessionExpired`; resolves to the
+        number of connections closed.
+        """
+        ...
+
+    def on_cluster_event(self, callback: Any) -> None:
+        """Register a callback for node and partition map changes (`None` removes it)."""
+        ...
+
+    def set_bin_cipher(self, encrypt_fn: Any, decrypt_fn: Any, bins: list[str] | None = None) -> None:
+        """Register `bytes -> bytes` hooks that encrypt `bins` on write and
+        decrypt them on read (`None` for both removes them).
+        """
+        ...
+
+    def close(self) -> Awaitable[Any]:
+        """Close connection (async)."""
+        ...
+
+    def get_node_names(self) -> list[str]:
+        """Get node names (sync, no I/O, lock-free)."""
+        ...
+
+    def get_nodes(self) -> Awaitable[Any]:
+        """Name, address, port, state and rack of every node (async)."""
+        ...
+
+    def get_node_for_key(self, key: _Key) -> KeyNode:
+        """Master node (name, address, port) of a key's partition (sync, no I/O)."""
+        ...
+
+    def get_partition_id(self, key: _Key) -> int:
+        """Partition (0-4095) a key belongs to; computed client-side."""
+        ...
+
+    def get_cluster_stats(self) -> Awaitable[Any]:
+        """Per-node client-side stats keyed by node name (async)."""
+        ...
+
+    def info_all(self, command: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Send an info command to all nodes in the cluster (async)."""
+        ...
+
+    def info_random_node(self, command: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Send an info command to a random node in the cluster (async)."""
+        ...
+
+    def info_single_node(self, command: str, node: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Send an info command to one node, chosen by node name or `host:port` (async)."""
+        ...
+
+    def info_namespaces(self, node: str | None = None, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """List namespaces (`info("namespaces")`, async)."""
+        ...
+
+    def info_sets(self, namespace: str, node: str | None = None, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """List sets of a namespace with their statistics (`info("sets/<ns>")`, async)."""
+        ...
+
+    def info_sindex(
+        self,
+        namespace: str | None = None,
+        node: str | None = None,
+        policy: AdminPolicy | None = None,
+    ) -> Awaitable[Any]:
+        """List secondary indexes, optionally of one namespace (`info("sindex-list")`, async)."""
+        ...
+
+    def __aenter__(self) -> Awaitable[Any]:
+        """Async context manager entry."""
+        ...
+
+    def __aexit__(self, _exc_type: Any = None, _exc_val: Any = None, _exc_tb: Any = None) -> Awaitable[Any]:
+        """Async context manager exit.
+
+        Shares the close path with `close()` via `prepare_close()`. In
+        particular, exiting while `connect()` is still in flight raises
+        `ClientError` — the same behaviour as calling `close()` explicitly —
+        rather than silently leaking a half-initialized client.
+
+        **Note:** the Python wrapper in `src/aerospike_py/_async_client.py`
+        defines its own `__aexit__` that delegates to `self.close()`, so
+        Python user code going through `aerospike_py.AsyncClient` never
+        reaches this native method. It is kept consistent with `close()`
+        for callers that interact with the PyO3 class directly (e.g. via
+        `client._inner`) and to avoid a latent behaviour divergence if the
+        Python wrapper's delegation is ever removed.
+        """
+        ...
+
+    def put(
+        self,
+        key: _Key,
+        bins: dict[str, Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+        return_meta: bool = False,
+    ) -> Awaitable[Any]:
+        """Write a record (async); with `return_meta`, return its meta after the write."""
+        ...
+
+    def get(self, key: _Key, policy: ReadPolicy | None = None, timeout: float | None = None) -> Awaitable[Any]:
+        """Read a record (async)."""
+        ...
+
+    def select(
+        self,
+        key: _Key,
+        bins: list[Any],
+        policy: ReadPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Read specific bins (async)."""
+        ...
+
+    def get_raw(self, key: _Key, policy: ReadPolicy | None = None, timeout: float | None = None) -> Awaitable[Any]:
+        """Read a record with bins as `(particle_type, payload)` pairs (async)."""
+        ...
+
+    def put_raw(
+        self,
+        key: _Key,
+        bins: dict[str, Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Write a record from `(particle_type, payload)` bins (async)."""
+        ...
+
+    def exists(self, key: _Key, policy: ReadPolicy | None = None, timeout: float | None = None) -> Awaitable[Any]:
+        """Check if a record exists (async)."""
+        ...
+
+    def remove(
+        self,
+        key: _Key,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Remove a record (async)."""
+        ...
+
+    def touch(
+        self,
+        key: _Key,
+        val: int = 0,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Touch a record (async)."""
+        ...
+
+    def increment(
+        self,
+        key: _Key,
+        bin: str,
+        offset: Any,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+        return_value: bool = False,
+    ) -> Awaitable[Any]:
+        """Increment a bin (async); with `return_value`, resolve to the bin's new value."""
+        ...
+
+    def operate(
+        self,
+        key: _Key,
+        ops: list[dict[str, Any]],
+        meta: WriteMeta | None = None,
+        policy: OperatePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Operate on a record (async)."""
+        ...
+
+    def append(
+        self,
+        key: _Key,
+        bin: str,
+        val: Any,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+        return_value: bool = False,
+    ) -> Awaitable[Any]:
+        """Append a string to a bin (async); with `return_value`, resolve to the bin's new value."""
+        ...
+
+    def prepend(
+        self,
+        key: _Key,
+        bin: str,
+        val: Any,
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+        return_value: bool = False,
+    ) -> Awaitable[Any]:
+        """Prepend a string to a bin (async); with `return_value`, resolve to the bin's new value."""
+        ...
+
+    def remove_bin(
+        self,
+        key: _Key,
+        bin_names: list[Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Remove bins from a record by setting them to nil (async)."""
+        ...
+
+    def operate_ordered(
+        self,
+        key: _Key,
+        ops: list[dict[str, Any]],
+        meta: WriteMeta | None = None,
+        policy: OperatePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Perform multiple operations on a single record, returning ordered results (async)."""
+        ...
+
+    def truncate(
+        self,
+        namespace: str,
+        set_name: str,
+        nanos: int = 0,
+        policy: AdminPolicy | None = None,
+        wait: bool = False,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Remove records in specified namespace/set efficiently (async)."""
+        ...
+
+    def _truncate_done(
+        self,
+        namespace: str,
+        set_name: str,
+        nanos: int = 0,
+        policy: AdminPolicy | None = None,
+    ) -> Awaitable[Any]:
+        """Whether a truncate of `namespace`/`set_name` has finished on every
+        node (async). Polled by `AsyncTruncateTask`.
+        """
+        ...
+
+    def udf_put(
+        self,
+        filename: str,
+        udf_type: int = 0,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> Awaitable[Any]:
+        """Register a UDF module from a file (async)."""
+        ...
+
+    def udf_remove(self, module: str, policy: AdminPolicy | None = None, wait: bool = True) -> Awaitable[Any]:
+        """Remove a UDF module (async)."""
+        ...
+
+    def _udf_done(self, module: str, removed: bool = False, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Whether a `udf_put()` (or, with `removed`, a `udf_remove()`) of
+        `module` has reached every node (async). Polled by `AsyncUdfTask`.
+        """
+        ...
+
+    def udf_list(self, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """List registered UDF modules (`info("udf-list")`, async)."""
+        ...
+
+    def udf_get(self, module: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Return the Lua source of a registered UDF module (`info("udf-get")`, async)."""
+        ...
+
+    def apply(
+        self,
+        key: _Key,
+        module: str,
+        function: str,
+        args: list[Any] | None = None,
+        policy: WritePolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Execute a UDF on a single record (async)."""
+        ...
+
+    def batch_read(
+        self,
+        keys: list[_Key],
+        bins: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        _dtype: Any = None,
+        _null_mask: bool = False,
+        output: str | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Read multiple records (async).
+
+        Returns a `BatchReadHandle` — a zero-conversion handle wrapping raw
+        Rust results. The async future completes with near-zero GIL cost
+        (just `Arc::new`). Call methods on the handle to access data:
+        - `handle.as_dict()` — fastest, returns `dict[key, bins_dict]`
+        - `handle.batch_records` — compat, returns `list[BatchRecord]`
+
+        With `output="arrow"` the future resolves to a `pyarrow.RecordBatch`.
+        """
+        ...
+
+    def batch_read_chunked(
+        self,
+        keys: list[_Key],
+        chunk_size: int = ...,
+        concurrency: int = ...,
+        bins: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Read multiple records as concurrent sub-batches (async).
+
+        Splits `keys` into chunks of `chunk_size` and keeps at most
+        `concurrency` chunks in flight. Resolves to a `BatchReadHandle` with
+        the merged results in key order.
+        """
+        ...
+
+    def batch_read_df(
+        self,
+        keys: list[_Key],
+        columns: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Read multiple records into DataFrame column buffers (async).
+
+        Resolves to `(index, columns)`; the Python wrapper builds the `pandas.DataFrame`.
+        """
+        ...
+
+    def batch_exists(
+        self,
+        keys: list[_Key],
+        policy: BatchPolicy | None = None,
+        dtype: Any = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Check existence of multiple records with a header-only batch read (async).
+
+        Resolves to `list[(key, meta | None)]`, or `(found, meta)` NumPy arrays
+        when a boolean `dtype` is provided.
+        """
+        ...
+
+    def batch_operate(
+        self,
+        keys: list[_Key],
+        ops: list[dict[str, Any]],
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Perform operations on multiple records (async)."""
+        ...
+
+    def batch_write(
+        self,
+        records: list[Any],
+        policy: BatchPolicy | None = None,
+        retry: int = 0,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Write multiple records with per-record bins (async)."""
+        ...
+
+    def batch_write_numpy(
+        self,
+        data: Any,
+        namespace: str,
+        set_name: str,
+        _dtype: Any,
+        key_field: str = "_key",
+        policy: BatchPolicy | None = None,
+        retry: int = 0,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Write multiple records from a numpy structured array (async)."""
+        ...
+
+    def bulk_put(
+        self,
+        records: Any,
+        namespace: str,
+        set_name: str,
+        key_fn: Any = None,
+        policy: dict[str, Any] | None = None,
+        chunk_size: int = ...,
+        concurrency: int = ...,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Write records from an iterable of bins dicts or `(key, bins)` tuples (async).
+
+        Like the sync client, the iterable is read one wave of `concurrency`
+        chunks at a time, from the future (attaching to the GIL per wave), so
+        only one wave is held in memory. Per-record failures are counted in
+        the resulting summary instead of raised.
+        """
+        ...
+
+    def batch_remove(
+        self,
+        keys: list[_Key],
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Remove multiple records (async)."""
+        ...
+
+    def batch_apply(
+        self,
+        keys: list[_Key],
+        module: str,
+        function: str,
+        args: list[Any] | None = None,
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Awaitable[Any]:
+        """Execute a UDF on multiple records in a single batch call (async)."""
+        ...
+
+    def query(self, namespace: str, set_name: Any) -> Query:
+        """Create a Query object."""
+        ...
+
+    def bind_query(self, query: Query) -> Query:
+        """Bind a copy of a `Query` (e.g. one built with `Query(...)`) to this client."""
+        ...
+
+    def index_integer_create(
+        self,
+        namespace: str,
+        set_name: str,
+        bin_name: str,
+        index_name: str,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> Awaitable[Any]:
+        """Create a secondary integer index (async)."""
+        ...
+
+    def index_string_create(
+        self,
+        namespace: str,
+        set_name: str,
+        bin_name: str,
+        index_name: str,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> Awaitable[Any]:
+        """Create a secondary string index (async)."""
+        ...
+
+    def index_geo2dsphere_create(
+        self,
+        namespace: str,
+        set_name: str,
+        bin_name: str,
+        index_name: str,
+        policy: AdminPolicy | None = None,
+        wait: bool = True,
+    ) -> Awaitable[Any]:
+        """Create a secondary geo2dsphere index (async)."""
+        ...
+
+    def index_status(self, namespace: str, index_name: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Build progress of a secondary index in percent (lowest across nodes, async)."""
+        ...
+
+    def job_info(self, job_id: int, module: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """State of a scan / query job across the cluster (async)."""
+        ...
+
+    def index_remove(self, namespace: str, index_name: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Remove a secondary index (async)."""
+        ...
+
+    def admin_create_user(
+        self,
+        username: str,
+        password: str,
+        roles: list[str],
+        policy: AdminPolicy | None = None,
+    ) -> Awaitable[Any]:
+        """Create a new user with the given roles (async)."""
+        ...
+
+    def admin_drop_user(self, username: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Drop (delete) a user (async)."""
+        ...
+
+    def admin_change_password(self, username: str, password: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Change user password (async)."""
+        ...
+
+    def admin_grant_roles(self, username: str, roles: list[str], policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Grant roles to a user (async)."""
+        ...
+
+    def admin_revoke_roles(self, username: str, roles: list[str], policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Revoke roles from a user (async)."""
+        ...
+
+    def admin_query_user_info(self, username: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Query info about a specific user (async)."""
+        ...
+
+    def admin_query_users_info(self, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Query info about all users (async)."""
+        ...
+
+    def admin_whoami(self, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Query info about the user this client is authenticated as (async)."""
+        ...
+
+    def admin_create_role(
+        self,
+        role: str,
+        privileges: list[Any],
+        policy: AdminPolicy | None = None,
+        whitelist: list[str] | None = None,
+        read_quota: int = 0,
+        write_quota: int = 0,
+    ) -> Awaitable[Any]:
+        """Create a new role with the given privileges (async)."""
+        ...
+
+    def admin_drop_role(self, role: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Drop (delete) a role (async)."""
+        ...
+
+    def admin_grant_privileges(
+        self,
+        role: str,
+        privileges: list[Any],
+        policy: AdminPolicy | None = None,
+    ) -> Awaitable[Any]:
+        """Grant privileges to a role (async)."""
+        ...
+
+    def admin_revoke_privileges(
+        self,
+        role: str,
+        privileges: list[Any],
+        policy: AdminPolicy | None = None,
+    ) -> Awaitable[Any]:
+        """Revoke privileges from a role (async)."""
+        ...
+
+    def admin_query_role(self, role: str, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Query info about a specific role (async)."""
+        ...
+
+    def admin_query_roles(self, policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Query info about all roles (async)."""
+        ...
+
+    def admin_set_whitelist(self, role: str, whitelist: list[str], policy: AdminPolicy | None = None) -> Awaitable[Any]:
+        """Set allowlist (whitelist) for a role (async)."""
+        ...
+
+    def admin_set_quotas(
+        self,
+        role: str,
+        read_quota: int = 0,
+        write_quota: int = 0,
+        policy: AdminPolicy | None = None,
+    ) -> Awaitable[Any]:
+        """Set quotas for a role (async)."""
+        ...
+
+class Query:
+    def __init__(self, namespace: str, set_name: Any = None) -> None:
+        """Build a query not bound to any client, run later by `Client.execute()`."""
+        ...
+
+    def select(self, *bins: Any) -> None:
+        """Select specific bins to return in query results.
+
+        A dict argument maps output names to bin names; those bins are
+        returned under their output names.
+        """
+        ...
+
+    def max_records(self, n: int) -> None:
+        """Stop after roughly `n` records (spread across nodes); `0` means all."""
+        ...
+
+    def records_per_second(self, n: int) -> None:
+        """Limit each node to `n` records per second; `0` means unlimited."""
+        ...
+
+    def max_concurrent_nodes(self, n: int) -> None:
+        """Query at most `n` nodes at a time; `0` queries all nodes in parallel."""
+        ...
+
+    def set_nodes(self, nodes: list[str]) -> None:
+        """Restrict the query to the partitions mastered by the named nodes.
+        An empty list queries all nodes again.
+        """
+        ...
+
+    def where(self, predicate: tuple[Any, ...]) -> None:
+        """Add a filter predicate (secondary index query)."""
+        ...
+
+    def results(
+        self,
+        policy: QueryPolicy | None = None,
+        dtype: Any = None,
+        filter_expression: Any = None,
+        ops: list[dict[str, Any]] | None = None,
+        timeout: float | None = None,
+    ) -> Any:
+        """Execute the query and return all results as a list of (key, meta, bins),
+        or a `NumpyBatchRecords` when `dtype` is provided.
+
+        `ops` are read operations run on every matching record after the
+        query; the bins they return are added to (or replace) the record's bins.
+        """
+        ...
+
+    def execute_background(
+        self,
+        ops: list[dict[str, Any]],
+        policy: dict[str, Any] | None = None,
+        filter_expression: Any = None,
+        timeout: float | None = None,
+    ) -> int:
+        """Apply write `ops` to every matching record server-side and return the
+        background job's id, which `job_info(id, JOB_QUERY)` reports on.
+
+        Records are not sent back; the call returns once every node has
+        accepted the job. Predicates beyond the index filter and
+        `filter_expression` limit which records are written.
+        """
+        ...
+
+    def results_arrow(
+        self,
+        policy: QueryPolicy | None = None,
+        filter_expression: Any = None,
+        timeout: float | None = None,
+    ) -> Any:
+        """Execute the query and return all results as a `pyarrow.RecordBatch`.
+
+        Requires the `arrow` feature and `pyarrow` at runtime.
+        """
+        ...
+
+    def results_columns(
+        self,
+        policy: QueryPolicy | None = None,
+        filter_expression: Any = None,
+        timeout: float | None = None,
+    ) -> Any:
+        """Execute the query and return `(None, columns)` DataFrame column buffers."""
+        ...
+
+    def foreach(
+        self,
+        callback: Any,
+        policy: QueryPolicy | None = None,
+        filter_expression: Any = None,
+        timeout: float | None = None,
+    ) -> None:
+        """Execute the query and call callback for each record."""
+        ...
+
+class Pipeline:
+    def put(
+        self,
+        key: _Key,
+        bins: dict[str, Any],
+        meta: WriteMeta | None = None,
+        policy: WritePolicy | None = None,
+    ) -> None:
+        """Queue a write of `bins` to `key`."""
+        ...
+
+    def get(self, key: _Key, policy: ReadPolicy | None = None) -> None:
+        """Queue a read of all bins of `key`."""
+        ...
+
+    def operate(
+        self,
+        key: _Key,
+        ops: list[dict[str, Any]],
+        meta: WriteMeta | None = None,
+        policy: OperatePolicy | None = None,
+    ) -> None:
+        """Queue an `operate` call on `key`."""
+        ...
+
+    def execute(self) -> list[Any]:
+        """Run all queued operations and return their results in queue order.
+
+        Each item is `None` for a put, a `(key, meta, bins)` tuple for a get or
+        operate, or the exception instance if that operation failed.
+        """
+        ...
+
+    def clear(self) -> None:
+        """Drop all queued operations without running them."""
+        ...
+
+    def __len__(self) -> int: ...
+
+class PartitionFilter:
+    def __repr__(self) -> str: ...
+
+class BatchRecord:
+    @property
+    def key(self) -> Any: ...
+    @property
+    def result(self) -> int: ...
+    @property
+    def in_doubt(self) -> bool: ...
+
+    @property
+    def record(self) -> Any:
+        """Lazily convert the record to Python `(key, meta, bins)` tuple.
+        Returns `None` if the record was not found.
+        """
+        ...
+
+    def as_tuple(self) -> Any:
+        """The classic `(key, meta, bins)` record tuple; `meta` and `bins` are
+        `None` when the batch returned no record for this key.
+        """
+        ...
+
+    def raise_for_status(self) -> None:
+        """Raise the exception mapped from `result` (e.g. `RecordNotFound`),
+        with `.key` set; do nothing when it is `0`.
+        """
+        ...
+
+class BatchRecords:
+    @property
+    def batch_records(self) -> list[BatchRecord]: ...
+
+    def __len__(self) -> int: ...
+
+    def __getitem__(self, index: int) -> BatchRecord: ...
+
+    def __iter__(self) -> Any: ...
+
+    def get(self, key: _Key, default: Any = None) -> Any:
+        """The record for `key` (a `(namespace, set, key)` tuple), matched by
+        digest, or `default` when the batch does not contain it.
+        """
+        ...
+
+    @property
+    def ok_count(self) -> int:
+        """Number of records with result code `0`."""
+        ...
+
+    @property
+    def error_count(self) -> int:
+        """Number of records with a non-zero result code."""
+        ...
+
+class BatchReadHandle:
+    def __len__(self) -> int: ...
+
+    def __getitem__(self, index: int) -> BatchRecord: ...
+
+    def __iter__(self) -> Any: ...
+
+    def as_dict(self) -> dict[str, Any]:
+        """Fastest access path: returns `dict[key_str, bins_dict]` directly.
+
+        Skips all intermediate objects (BatchRecord wrapper, key tuple, meta dict).
+        Records without a `user_key` (digest-only) or with a failed result are
+        excluded from the dict. Use `batch_records` to access all records.
+        """
+        ...
+
+    @staticmethod
+    def merge_as_dict(handles: list[BatchReadHandle]) -> list[dict[str, Any]]:
+        """Merge multiple handles into a list of dicts in a single GIL acquisition.
+
+        Avoids 9 separate event-loop coroutine resumes when using `asyncio.gather`.
+        Instead of `[h.as_dict() for h in handles]`, call
+        `BatchReadHandle.merge_as_dict(handles)` once.
+        """
+        ...
+
+    @property
+    def batch_records(self) -> list[BatchRecord]:
+        """Compatibility path: returns `list[BatchRecord]` with lazy per-record conversion.
+
+        Each `BatchRecord`'s `.record` field is lazily converted on first access.
+        """
+        ...
+
+    def found_count(self) -> int:
+        """Count of records with successful result code (no conversion needed)."""
+        ...
+
+    def keys(self) -> list[Any]:
+        """Extract just the user keys without converting record data."""
+        ...
+
+class BlobView:
+    def __len__(self) -> int: ...
+
+    def __repr__(self) -> str: ...
+
+class CompiledExpression:
+    def __init__(self, expr: Any) -> None: ...
+
+    def __repr__(self) -> str: ...
+
+def get_metrics_text() -> str:
+    """Return collected metrics in Prometheus text format."""
+    ...
+
+def get_metrics_samples() -> list[Any]:
+    """Return current metric samples as a list of `{name, labels, value}` dicts."""
+    ...
+
+def reset_metrics() -> None:
+    """Clear recorded operation histograms (cluster gauges are re-sampled)."""
+    ...
+
+def set_metrics_enabled(enabled: bool) -> None:
+    """Enable or disable Prometheus metrics collection.
+
+    When disabled, operation timers are skipped entirely (~1ns atomic check).
+    Useful for benchmarking without metrics overhead.
+    """
+    ...
+
+def is_metrics_enabled() -> bool:
+    """Check if Prometheus metrics collection is currently enabled."""
+    ...
+
+def configure_metrics(buckets: list[float] | None = None, prefix: str | None = None) -> None:
+    """Set the operation histogram buckets (seconds) and/or a metric name prefix.
+
+    Must be called before the first operation or `get_metrics()`.
+    """
+    ...
+
+def set_internal_stage_metrics_enabled(enabled: bool) -> None:
+    """Enable or disable internal stage profiling metrics
+    (`db_client_internal_stage_seconds`).
+
+    Separate from `set_metrics_enabled` — this flag controls fine-grained
+    profiling spans like `key_parse`, `io`, `into_pyobject`, etc. Default is
+    `false`. When disabled, all stage timer call sites skip `Instant::now()`
+    entirely (single atomic load, ~1ns).
+    """
+    ...
+
+def is_internal_stage_metrics_enabled() -> bool:
+    """Check if internal stage profiling metrics are currently enabled."""
+    ...
+
+def dropped_log_count() -> int:
+    """Return the number of log messages dropped because the forwarding queue
+    was full or the Python GIL was unavailable (e.g. during interpreter shutdown).
+    """
+    ...
+
+def set_log_target_level(target: str, level: int) -> None:
+    """Set the Rust-side level filter for a log target (`""` for the default)."""
+    ...
+
+def flush_logs(timeout: float = 1.0) -> bool:
+    """Wait until queued Rust log records have been handed to Python `logging`."""
+    ...
+
+def calc_digest(namespace: str, set: str, key: Any) -> bytes:
+    """Compute the 20-byte RIPEMD-160 digest the server uses for a record key."""
+    ...
+
+def parse_config_url(url: str) -> dict[str, Any]:
+    """Parse a connection string into a config dict.
+
+    Format: `aerospike://[user[:password]@]host[:port][,host[:port]...][?key=value&...]`.
+    IPv6 hosts go in brackets (`[::1]:3000`) and the port defaults to 3000.
+    Query parameters become top-level config keys; `true` / `false` and
+    integers are converted, anything else stays a string. User, password
+    and values may be percent-encoded.
+    """
+    ...
+
+def is_retryable(exc: Any) -> bool:
+    """Whether `exc` is a transient Aerospike error worth retrying.
+
+    True for `RetryableError` instances and for any `AerospikeError` whose
+    `code` is a transient result code. Retrying a write whose `in_doubt` is
+    true may apply it twice unless the write is idempotent.
+    """
+    ...
+
+def _raise_for_result(code: int, in_doubt: bool = False, key: Any = None) -> None:
+    """Raise the exception mapped from a non-zero per-record result code.
+
+    Backs `BatchRecord.raise_for_status()` of the typed Python wrappers.
+    """
+    ...
+
+def runtime_stats() -> dict[str, Any]:
+    """Snapshot of the shared Tokio runtimes and of sync `block_on` calls.
+
+    `sync_runtime` / `async_runtime` are `None` until the runtime is started in this process
+    (first sync operation / `AsyncClient.connect()`). Dedicated client
+    runtimes are not listed, but their `block_on` calls are counted.
+    """
+    ...
+
+def set_blob_view_threshold(nbytes: int) -> None:
+    """Return blob bins of at least `nbytes` bytes as read-only `memoryview`s.
+
+    Applies to top-level blob bins of records returned by `get`, `select` and
+    `operate`. Pass `0` to always return `bytes` (the default).
+    """
+    ...
+
+def get_blob_view_threshold() -> int:
+    """Current blob view threshold in bytes (`0` = disabled)."""
+    ...
+
+def partition_filter_all() -> PartitionFilter:
+    """Build a filter that scans/queries every partition (0..4096)."""
+    ...
+
+def partition_filter_by_id(partition_id: int) -> PartitionFilter:
+    """Build a filter targeting a single partition (0..=4095)."""
+    ...
+
+def partition_filter_by_range(begin: int, count: int) -> PartitionFilter:
+    """Build a filter targeting `count` partitions starting at `begin`.
+
+    `begin` must be in `[0, 4096)` and `begin + count` must be `<= 4096`.
+    `count == 0` is permitted (yields an empty filter).
+    """
+    ...
+
+def init_tracing(
+    sample_ratio: float | None = None,
+    service_name: str | None = None,
+    span_name_format: str | None = None,
+) -> None:
+    ...
+
+def shutdown_tracing() -> None: ...
+
+# -- Exceptions --
+
+class AerospikeError(Exception):
+    """Base exception for all Aerospike errors."""
+
+    code: int | None
+    msg: str
+    in_doubt: bool
+    key: tuple[str, str, Any, bytes] | None
+    bin: str | None
+
+class ClientError(AerospikeError):
+    """Client-side error (connection, configuration, internal)."""
+
+class ServerError(AerospikeError):
+    """Server-side error returned by the Aerospike cluster."""
+
+class RecordError(AerospikeError):
+    """Record-level error (not found, exists, generation mismatch, etc.)."""
+
+class ClusterError(AerospikeError, RetryableError):
+    """Cluster connectivity or node error."""
+
+class CircuitOpenError(ClusterError):
+    """The key's node has an open circuit breaker; the command was not sent."""
+
+class AerospikeTimeoutError(AerospikeError, RetryableError):
+    """Operation timed out."""
+
+TimeoutError = AerospikeTimeoutError
+
+class ParamError(ClientError):
+    """Invalid argument passed to an operation (also exported as `InvalidArgError`)."""
+
+InvalidArgError = ParamError
+
+class RetryableError(Exception):
+    """Mixin base for transient errors: the same call may succeed if retried."""
+
+class BackpressureError(ClientError, RetryableError):
+    """Maximum concurrent operations exceeded; retry after backoff."""
+
+class RustPanicError(ClientError):
+    """Native Rust panic during an operation. The Python process survived; the operation did not complete. Common cause:
+    legacy records carrying language-specific blob particle types (PYTHON_BLOB, JAVA_BLOB, ...) that aerospike-core
+    2.0.0 cannot decode (see issue #280).
+    """
+
+class ForkError(ClientError):
+    """The client was connected before os.fork() and cannot be used in the child process until it reconnects."""
+
+class RecordNotFound(RecordError):
+    """Record does not exist (result code 2)."""
+
+class RecordExistsError(RecordError):
+    """Record already exists (result code 5)."""
+
+class RecordGenerationError(RecordError):
+    """Record generation mismatch (result code 3)."""
+
+class RecordTooBig(RecordError):
+    """Record size exceeds server limit (result code 13)."""
+
+class BinNameError(RecordError):
+    """Bin name too long (result code 21)."""
+
+class BinExistsError(RecordError):
+    """Bin already exists (result code 6)."""
+
+class BinNotFound(RecordError):
+    """Bin does not exist (result code 17)."""
+
+class BinTypeError(RecordError):
+    """Bin type mismatch for the operation (result code 12)."""
+
+class FilteredOut(RecordError):
+    """Record filtered out by expression filter (result code 27)."""
+
+class RecordBusy(RecordError, RetryableError):
+    """Too many concurrent requests for one record, a hot key (result code 14)."""
+
+class ElementNotFoundError(RecordError):
+    """CDT element not found (result code 23)."""
+
+class ElementExistsError(RecordError):
+    """CDT element already exists (result code 24)."""
+
+class AlwaysForbidden(ServerError):
+    """Operation is never allowed in this namespace configuration (result code 10)."""
+
+class UnsupportedFeature(ServerError):
+    """Feature not supported or not enabled on the server (result code 16, 25)."""
+
+class DeviceOverload(ServerError, RetryableError):
+    """Storage device is not keeping up with writes (result code 18)."""
+
+class ForbiddenError(ServerError, RetryableError):
+    """Operation is currently forbidden, e.g. during migrations (result code 22)."""
+
+class OpNotApplicable(ServerError):
+    """Operation cannot be applied to the current bin value (result code 26)."""
+
+class LostConflict(ServerError):
+    """Write lost a conflict resolution (XDR) (result code 28)."""
+
+class AerospikeIndexError(ServerError):
+    """Secondary index error."""
+
+IndexError = AerospikeIndexError
+
+class IndexNotFound(AerospikeIndexError):
+    """Secondary index does not exist (result code 201)."""
+
+class IndexFoundError(AerospikeIndexError):
+    """Secondary index already exists (result code 200)."""
+
+class QueryError(ServerError):
+    """Query execution error."""
+
+class QueryAbortedError(QueryError):
+    """Query was aborted by the server (result code 210)."""
+
+class AdminError(ServerError):
+    """Admin or security operation error."""
+
+class SessionExpired(AdminError):
+    """Login session expired (result code 66)."""
+
+class QuotaExceeded(AdminError):
+    """User quota exceeded (result code 83)."""
+
+class UDFError(ServerError):
+    """User-Defined Function (UDF) execution error."""
+
+    module: str | None
+    function: str | None
+    line: int | None
+    message: str | None
+
+# -- Constants --
+
+# Policy Key
+POLICY_KEY_DIGEST: Literal[0]
+POLICY_KEY_SEND: Literal[1]
+
+# Policy Exists
+POLICY_EXISTS_IGNORE: Literal[0]
+POLICY_EXISTS_UPDATE: Literal[1]
+POLICY_EXISTS_UPDATE_ONLY: Literal[1]
+POLICY_EXISTS_REPLACE: Literal[2]
+POLICY_EXISTS_REPLACE_ONLY: Literal[3]
+POLICY_EXISTS_CREATE_ONLY: Literal[4]
+
+# Policy Gen
+POLICY_GEN_IGNORE: Literal[0]
+POLICY_GEN_EQ: Literal[1]
+POLICY_GEN_GT: Literal[2]
+
+# Policy Replica
+POLICY_REPLICA_MASTER: Literal[0]
+POLICY_REPLICA_SEQUENCE: Literal[1]
+POLICY_REPLICA_PREFER_RACK: Literal[2]
+
+# Policy Commit Level
+POLICY_COMMIT_LEVEL_ALL: Literal[0]
+POLICY_COMMIT_LEVEL_MASTER: Literal[1]
+
+# Policy Read Mode AP
+POLICY_READ_MODE_AP_ONE: Literal[0]
+POLICY_READ_MODE_AP_ALL: Literal[1]
+
+# Policy Read Mode SC
+POLICY_READ_MODE_SC_SESSION: Literal[0]
+POLICY_READ_MODE_SC_LINEARIZE: Literal[1]
+POLICY_READ_MODE_SC_ALLOW_REPLICA: Literal[2]
+POLICY_READ_MODE_SC_ALLOW_UNAVAILABLE: Literal[3]
+
+# Batch Concurrency
+BATCH_CONCURRENCY_SEQUENTIAL: Literal[0]
+BATCH_CONCURRENCY_PARALLEL: Literal[1]
+
+# Read Touch TTL Percent (server v8+)
+READ_TOUCH_TTL_PERCENT_SERVER_DEFAULT: Literal[0]
+READ_TOUCH_TTL_PERCENT_DONT_RESET: Literal[-1]
+
+# Query Duration
+QUERY_DURATION_LONG: Literal[0]
+QUERY_DURATION_SHORT: Literal[1]
+QUERY_DURATION_LONG_RELAX_AP: Literal[2]
+
+# TTL Constants
+TTL_NAMESPACE_DEFAULT: Literal[0]
+TTL_NEVER_EXPIRE: Literal[-1]
+TTL_DONT_UPDATE: Literal[-2]
+TTL_CLIENT_DEFAULT: Literal[-3]
+
+# Auth Mode
+AUTH_INTERNAL: Literal[0]
+AUTH_EXTERNAL: Literal[1]
+AUTH_PKI: Literal[2]
+
+# Operator Constants
+OPERATOR_READ: Literal[1]
+OPERATOR_WRITE: Literal[2]
+OPERATOR_INCR: Literal[5]
+OPERATOR_APPEND: Literal[9]
+OPERATOR_PREPEND: Literal[10]
+OPERATOR_TOUCH: Literal[11]
+OPERATOR_DELETE: Literal[12]
+
+# List CDT Operation Codes
+OP_LIST_APPEND: Literal[1001]
+OP_LIST_APPEND_ITEMS: Literal[1002]
+OP_LIST_INSERT: Literal[1003]
+OP_LIST_INSERT_ITEMS: Literal[1004]
+OP_LIST_POP: Literal[1005]
+OP_LIST_POP_RANGE: Literal[1006]
+OP_LIST_REMOVE: Literal[1007]
+OP_LIST_REMOVE_RANGE: Literal[1008]
+OP_LIST_SET: Literal[1009]
+OP_LIST_TRIM: Literal[1010]
+OP_LIST_CLEAR: Literal[1011]
+OP_LIST_SIZE: Literal[1012]
+OP_LIST_GET: Literal[1013]
+OP_LIST_GET_RANGE: Literal[1014]
+OP_LIST_GET_BY_VALUE: Literal[1015]
+OP_LIST_GET_BY_INDEX: Literal[1016]
+OP_LIST_GET_BY_INDEX_RANGE: Literal[1017]
+OP_LIST_GET_BY_RANK: Literal[1018]
+OP_LIST_GET_BY_RANK_RANGE: Literal[1019]
+OP_LIST_GET_BY_VALUE_LIST: Literal[1020]
+OP_LIST_GET_BY_VALUE_RANGE: Literal[1021]
+OP_LIST_REMOVE_BY_VALUE: Literal[1022]
+OP_LIST_REMOVE_BY_VALUE_LIST: Literal[1023]
+OP_LIST_REMOVE_BY_VALUE_RANGE: Literal[1024]
+OP_LIST_REMOVE_BY_INDEX: Literal[1025]
+OP_LIST_REMOVE_BY_INDEX_RANGE: Literal[1026]
+OP_LIST_REMOVE_BY_RANK: Literal[1027]
+OP_LIST_REMOVE_BY_RANK_RANGE: Literal[1028]
+OP_LIST_INCREMENT: Literal[1029]
+OP_LIST_SORT: Literal[1030]
+OP_LIST_SET_ORDER: Literal[1031]
+OP_LIST_CREATE: Literal[1032]
+OP_LIST_GET_BY_VALUE_REL_RANK_RANGE: Literal[1033]
+OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE: Literal[1034]
+
+# Map CDT Operation Codes
+OP_MAP_SET_ORDER: Literal[2001]
+OP_MAP_PUT: Literal[2002]
+OP_MAP_PUT_ITEMS: Literal[2003]
+OP_MAP_INCREMENT: Literal[2004]
+OP_MAP_DECREMENT: Literal[2005]
+OP_MAP_CLEAR: Literal[2006]
+OP_MAP_REMOVE_BY_KEY: Literal[2007]
+OP_MAP_REMOVE_BY_KEY_LIST: Literal[2008]
+OP_MAP_REMOVE_BY_KEY_RANGE: Literal[2009]
+OP_MAP_REMOVE_BY_VALUE: Literal[2010]
+OP_MAP_REMOVE_BY_VALUE_LIST: Literal[2011]
+OP_MAP_REMOVE_BY_VALUE_RANGE: Literal[2012]
+OP_MAP_REMOVE_BY_INDEX: Literal[2013]
+OP_MAP_REMOVE_BY_INDEX_RANGE: Literal[2014]
+OP_MAP_REMOVE_BY_RANK: Literal[2015]
+OP_MAP_REMOVE_BY_RANK_RANGE: Literal[2016]
+OP_MAP_SIZE: Literal[2017]
+OP_MAP_GET_BY_KEY: Literal[2018]
+OP_MAP_GET_BY_KEY_RANGE: Literal[2019]
+OP_MAP_GET_BY_VALUE: Literal[2020]
+OP_MAP_GET_BY_VALUE_RANGE: Literal[2021]
+OP_MAP_GET_BY_INDEX: Literal[2022]
+OP_MAP_GET_BY_INDEX_RANGE: Literal[2023]
+OP_MAP_GET_BY_RANK: Literal[2024]
+OP_MAP_GET_BY_RANK_RANGE: Literal[2025]
+OP_MAP_GET_BY_KEY_LIST: Literal[2026]
+OP_MAP_GET_BY_VALUE_LIST: Literal[2027]
+OP_MAP_CREATE: Literal[2028]
+OP_MAP_GET_BY_KEY_REL_INDEX_RANGE: Literal[2029]
+OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE: Literal[2030]
+OP_MAP_GET_BY_VALUE_REL_RANK_RANGE: Literal[2031]
+OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE: Literal[2032]
+
+# HLL Operation Codes
+OP_HLL_INIT: Literal[3001]
+OP_HLL_ADD: Literal[3002]
+OP_HLL_GET_COUNT: Literal[3003]
+OP_HLL_GET_UNION: Literal[3004]
+OP_HLL_GET_UNION_COUNT: Literal[3005]
+OP_HLL_GET_INTERSECT_COUNT: Literal[3006]
+OP_HLL_GET_SIMILARITY: Literal[3007]
+OP_HLL_DESCRIBE: Literal[3008]
+OP_HLL_FOLD: Literal[3009]
+OP_HLL_SET_UNION: Literal[3010]
+
+# Bitwise Operation Codes
+OP_BIT_RESIZE: Literal[4001]
+OP_BIT_INSERT: Literal[4002]
+OP_BIT_REMOVE: Literal[4003]
+OP_BIT_SET: Literal[4004]
+OP_BIT_OR: Literal[4005]
+OP_BIT_XOR: Literal[4006]
+OP_BIT_AND: Literal[4007]
+OP_BIT_NOT: Literal[4008]
+OP_BIT_LSHIFT: Literal[4009]
+OP_BIT_RSHIFT: Literal[4010]
+OP_BIT_ADD: Literal[4011]
+OP_BIT_SUBTRACT: Literal[4012]
+OP_BIT_SET_INT: Literal[4013]
+OP_BIT_GET: Literal[4050]
+OP_BIT_COUNT: Literal[4051]
+OP_BIT_LSCAN: Literal[4052]
+OP_BIT_RSCAN: Literal[4053]
+OP_BIT_GET_INT: Literal[4054]
+
+# Index Type
+INDEX_NUMERIC: Literal[0]
+INDEX_STRING: Literal[1]
+INDEX_BLOB: Literal[2]
+INDEX_GEO2DSPHERE: Literal[3]
+
+# Raw Bin Particle Type (get_raw / put_raw)
+AS_BYTES_UNDEF: Literal[0]
+AS_BYTES_INTEGER: Literal[1]
+AS_BYTES_DOUBLE: Literal[2]
+AS_BYTES_STRING: Literal[3]
+AS_BYTES_BLOB: Literal[4]
+AS_BYTES_BOOL: Literal[17]
+AS_BYTES_HLL: Literal[18]
+AS_BYTES_MAP: Literal[19]
+AS_BYTES_LIST: Literal[20]
+AS_BYTES_GEOJSON: Literal[23]
+
+# Index Collection Type
+INDEX_TYPE_DEFAULT: Literal[0]
+INDEX_TYPE_LIST: Literal[1]
+INDEX_TYPE_MAPKEYS: Literal[2]
+INDEX_TYPE_MAPVALUES: Literal[3]
+
+# Expression Value Type (exp.key / exp.*_bin value types)
+EXP_TYPE_NIL: Literal[0]
+EXP_TYPE_BOOL: Literal[1]
+EXP_TYPE_INT: Literal[2]
+EXP_TYPE_STRING: Literal[3]
+EXP_TYPE_LIST: Literal[4]
+EXP_TYPE_MAP: Literal[5]
+EXP_TYPE_BLOB: Literal[6]
+EXP_TYPE_FLOAT: Literal[7]
+EXP_TYPE_GEO: Literal[8]
+EXP_TYPE_HLL: Literal[9]
+
+# Job
+JOB_SCAN: Literal["scan"]
+JOB_QUERY: Literal["query"]
+JOB_STATUS_UNDEF: Literal[0]
+JOB_STATUS_INPROGRESS: Literal[1]
+JOB_STATUS_COMPLETED: Literal[2]
+
+# Log Level
+LOG_LEVEL_OFF: Literal[-1]
+LOG_LEVEL_ERROR: Literal[0]
+LOG_LEVEL_WARN: Literal[1]
+LOG_LEVEL_INFO: Literal[2]
+LOG_LEVEL_DEBUG: Literal[3]
+LOG_LEVEL_TRACE: Literal[4]
+
+# Serializer
+SERIALIZER_NONE: Literal[0]
+SERIALIZER_PYTHON: Literal[1]
+SERIALIZER_USER: Literal[2]
+
+# List Return Type
+LIST_RETURN_NONE: Literal[0]
+LIST_RETURN_INDEX: Literal[1]
+LIST_RETURN_REVERSE_INDEX: Literal[2]
+LIST_RETURN_RANK: Literal[3]
+LIST_RETURN_REVERSE_RANK: Literal[4]
+LIST_RETURN_COUNT: Literal[5]
+LIST_RETURN_VALUE: Literal[7]
+LIST_RETURN_EXISTS: Literal[13]
+LIST_RETURN_INVERTED: Literal[65536]
+
+# List Order
+LIST_UNORDERED: Literal[0]
+LIST_ORDERED: Literal[1]
+
+# List Sort Flags
+LIST_SORT_DEFAULT: Literal[0]
+LIST_SORT_DESCENDING: Literal[1]
+LIST_SORT_DROP_DUPLICATES: Literal[2]
+
+# List Write Flags
+LIST_WRITE_DEFAULT: Literal[0]
+LIST_WRITE_ADD_UNIQUE: Literal[1]
+LIST_WRITE_INSERT_BOUNDED: Literal[2]
+LIST_WRITE_NO_FAIL: Literal[4]
+LIST_WRITE_PARTIAL: Literal[8]
+
+# Map Return Type
+MAP_RETURN_NONE: Literal[0]
+MAP_RETURN_INDEX: Literal[1]
+MAP_RETURN_REVERSE_INDEX: Literal[2]
+MAP_RETURN_RANK: Literal[3]
+MAP_RETURN_REVERSE_RANK: Literal[4]
+MAP_RETURN_COUNT: Literal[5]
+MAP_RETURN_KEY: Literal[6]
+MAP_RETURN_VALUE: Literal[7]
+MAP_RETURN_KEY_VALUE: Literal[8]
+MAP_RETURN_EXISTS: Literal[13]
+MAP_RETURN_INVERTED: Literal[65536]
+
+# Map Order
+MAP_UNORDERED: Literal[0]
+MAP_KEY_ORDERED: Literal[1]
+MAP_KEY_VALUE_ORDERED: Literal[3]
+
+# Map Write Mode
+MAP_WRITE_FLAGS_DEFAULT: Literal[0]
+MAP_WRITE_FLAGS_CREATE_ONLY: Literal[1]
+MAP_WRITE_FLAGS_UPDATE_ONLY: Literal[2]
+MAP_WRITE_FLAGS_NO_FAIL: Literal[4]
+MAP_WRITE_FLAGS_PARTIAL: Literal[8]
+
+# Map Write Flags (legacy names)
+MAP_UPDATE: Literal[0]
+MAP_UPDATE_ONLY: Literal[2]
+MAP_CREATE_ONLY: Literal[1]
+
+# Bit Write Flags
+BIT_WRITE_DEFAULT: Literal[0]
+BIT_WRITE_CREATE_ONLY: Literal[1]
+BIT_WRITE_UPDATE_ONLY: Literal[2]
+BIT_WRITE_NO_FAIL: Literal[4]
+BIT_WRITE_PARTIAL: Literal[8]
+
+# Bit Resize Flags
+BIT_RESIZE_DEFAULT: Literal[0]
+BIT_RESIZE_FROM_FRONT: Literal[1]
+BIT_RESIZE_GROW_ONLY: Literal[2]
+BIT_RESIZE_SHRINK_ONLY: Literal[4]
+
+# Bit Overflow Action
+BIT_OVERFLOW_FAIL: Literal[0]
+BIT_OVERFLOW_SATURATE: Literal[2]
+BIT_OVERFLOW_WRAP: Literal[4]
+
+# HLL Write Flags
+HLL_WRITE_DEFAULT: Literal[0]
+HLL_WRITE_CREATE_ONLY: Literal[1]
+HLL_WRITE_UPDATE_ONLY: Literal[2]
+HLL_WRITE_NO_FAIL: Literal[4]
+HLL_WRITE_ALLOW_FOLD: Literal[8]
+
+# Regex Flags (for exp.regex_compare)
+REGEX_NONE: Literal[0]
+REGEX_EXTENDED: Literal[1]
+REGEX_ICASE: Literal[2]
+REGEX_NOSUB: Literal[4]
+REGEX_NEWLINE: Literal[8]
+
+# Privilege codes
+PRIV_READ: Literal[10]
+PRIV_WRITE: Literal[13]
+PRIV_READ_WRITE: Literal[11]
+PRIV_READ_WRITE_UDF: Literal[12]
+PRIV_USER_ADMIN: Literal[0]
+PRIV_SYS_ADMIN: Literal[1]
+PRIV_DATA_ADMIN: Literal[2]
+PRIV_UDF_ADMIN: Literal[3]
+PRIV_SINDEX_ADMIN: Literal[4]
+PRIV_TRUNCATE: Literal[14]
+
+# Result / Status codes
+AEROSPIKE_OK: Literal[0]
+AEROSPIKE_ERR_SERVER: Literal[1]
+AEROSPIKE_ERR_RECORD_NOT_FOUND: Literal[2]
+AEROSPIKE_ERR_RECORD_GENERATION: Literal[3]
+AEROSPIKE_ERR_PARAM: Literal[4]
+AEROSPIKE_ERR_RECORD_EXISTS: Literal[5]
+AEROSPIKE_ERR_BIN_EXISTS: Literal[6]
+AEROSPIKE_ERR_CLUSTER_KEY_MISMATCH: Literal[7]
+AEROSPIKE_ERR_SERVER_MEM: Literal[8]
+AEROSPIKE_ERR_TIMEOUT: Literal[9]
+AEROSPIKE_ERR_ALWAYS_FORBIDDEN: Literal[10]
+AEROSPIKE_ERR_PARTITION_UNAVAILABLE: Literal[11]
+AEROSPIKE_ERR_BIN_TYPE: Literal[12]
+AEROSPIKE_ERR_RECORD_TOO_BIG: Literal[13]
+AEROSPIKE_ERR_KEY_BUSY: Literal[14]
+AEROSPIKE_ERR_SCAN_ABORT: Literal[15]
+AEROSPIKE_ERR_UNSUPPORTED_FEATURE: Literal[16]
+AEROSPIKE_ERR_BIN_NOT_FOUND: Literal[17]
+AEROSPIKE_ERR_DEVICE_OVERLOAD: Literal[18]
+AEROSPIKE_ERR_KEY_MISMATCH: Literal[19]
+AEROSPIKE_ERR_INVALID_NAMESPACE: Literal[20]
+AEROSPIKE_ERR_BIN_NAME: Literal[21]
+AEROSPIKE_ERR_FAIL_FORBIDDEN: Literal[22]
+AEROSPIKE_ERR_ELEMENT_NOT_FOUND: Literal[23]
+AEROSPIKE_ERR_ELEMENT_EXISTS: Literal[24]
+AEROSPIKE_ERR_ENTERPRISE_ONLY: Literal[25]
+AEROSPIKE_ERR_OP_NOT_APPLICABLE: Literal[26]
+AEROSPIKE_ERR_FILTERED_OUT: Literal[27]
+AEROSPIKE_ERR_LOST_CONFLICT: Literal[28]
+AEROSPIKE_QUERY_END: Literal[50]
+AEROSPIKE_SECURITY_NOT_SUPPORTED: Literal[51]
+AEROSPIKE_SECURITY_NOT_ENABLED: Literal[52]
+AEROSPIKE_ERR_INVALID_USER: Literal[60]
+AEROSPIKE_ERR_NOT_AUTHENTICATED: Literal[80]
+AEROSPIKE_ERR_ROLE_VIOLATION: Literal[81]
+AEROSPIKE_ERR_UDF: Literal[100]
+AEROSPIKE_ERR_BATCH_DISABLED: Literal[150]
+AEROSPIKE_ERR_INDEX_FOUND: Literal[200]
+AEROSPIKE_ERR_INDEX_NOT_FOUND: Literal[201]
+AEROSPIKE_ERR_QUERY_ABORTED: Literal[210]
+
+# Client error codes (negative)
+AEROSPIKE_ERR_CLIENT: Literal[-1]
+AEROSPIKE_ERR_CONNECTION: Literal[-10]
+AEROSPIKE_ERR_CLUSTER: Literal[-11]
+AEROSPIKE_ERR_INVALID_HOST: Literal[-4]
+AEROSPIKE_ERR_NO_MORE_CONNECTIONS: Literal[-7]
+
+# Operation Code Lookup
+OPERATORS: dict[str, int]
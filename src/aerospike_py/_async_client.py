@@ -4,18 +4,33 @@ from __future__ import annotations
 
 import asyncio
 import logging
+import os
 from typing import Any
 
+from aerospike_py._aerospike import JOB_SCAN, AerospikeTimeoutError, IndexNotFound, ParamError
 from aerospike_py._aerospike import AsyncClient as _NativeAsyncClient
 from aerospike_py._aerospike import Query as _NativeQuery
+from aerospike_py._aerospike import parse_config_url
 from aerospike_py._bug_report import catch_unexpected
-from aerospike_py._client import _wrap_batch_record, _wrap_exists, _wrap_operate_ordered, _wrap_record
+from aerospike_py._compat import LoopBridge, compat_from_config, run_on_asyncio
+from aerospike_py._dispatch import dispatch_hooks
+from aerospike_py._slow_log import SlowLog
+from aerospike_py._strict_params import strict_params_from_config
+from aerospike_py._client import _wrap_batch_record, _wrap_exists, _wrap_meta, _wrap_operate_ordered, _wrap_record
+from aerospike_py.dataframe import _columns_to_df
 from aerospike_py.types import (
+    BatchPolicy,
     BatchWriteResult,
+    BulkPutResult,
     ExistsResult,
     InfoNodeResult,
+    JobInfo,
+    KeyNode,
+    NodeInfo,
+    NodeStats,
     OperateOrderedResult,
     Record,
+    RecordMetadata,
 )
 
 logger = logging.getLogger("aerospike_py")
@@ -26,6 +41,7 @@ logger = logging.getLogger("aerospike_py")
 # ---------------------------------------------------------------------------
 
 
+@run_on_asyncio
 class AsyncQuery:
     """Async Python wrapper around the native Query object that returns typed records.
 
@@ -34,36 +50,161 @@ class AsyncQuery:
     query in a thread pool to avoid blocking the event loop.
     """
 
-    def __init__(self, inner: _NativeQuery):
+    def __init__(self, inner: _NativeQuery, compat: LoopBridge | None = None):
         self._inner = inner
+        self._compat = compat
 
-    def select(self, *bins: str) -> None:
+    def select(self, *bins: str | dict[str, str]) -> None:
         self._inner.select(*bins)
 
     def where(self, predicate) -> None:
         self._inner.where(predicate)
 
+    def max_records(self, n: int) -> None:
+        self._inner.max_records(n)
+
+    def records_per_second(self, n: int) -> None:
+        self._inner.records_per_second(n)
+
+    def max_concurrent_nodes(self, n: int) -> None:
+        self._inner.max_concurrent_nodes(n)
+
+    def set_nodes(self, nodes: list[str]) -> None:
+        self._inner.set_nodes(nodes)
+
     @catch_unexpected("AsyncQuery.results")
-    async def results(self, policy=None) -> list[Record]:
-        raw = await asyncio.to_thread(self._inner.results, policy)
+    async def results(self, policy=None, dtype=None, filter_expression=None, ops=None, timeout=None):
+        if dtype is not None:
+            return await asyncio.to_thread(self._inner.results, policy, dtype, filter_expression, ops, timeout=timeout)
+        raw = await asyncio.to_thread(self._inner.results, policy, None, filter_expression, ops, timeout=timeout)
         return [_wrap_record(r) for r in raw]
 
+    @catch_unexpected("AsyncQuery.results_arrow")
+    async def results_arrow(self, policy=None, filter_expression=None, timeout=None):
+        return await asyncio.to_thread(self._inner.results_arrow, policy, filter_expression, timeout=timeout)
+
+    @catch_unexpected("AsyncQuery.to_df")
+    async def to_df(self, policy=None, filter_expression=None, timeout=None):
+        raw = await asyncio.to_thread(self._inner.results_columns, policy, filter_expression, timeout=timeout)
+        return _columns_to_df(raw)
+
+    @catch_unexpected("AsyncQuery.execute_background")
+    async def execute_background(self, ops, policy=None, filter_expression=None, timeout=None) -> int:
+        return await asyncio.to_thread(self._inner.execute_background, ops, policy, filter_expression, timeout=timeout)
+
     @catch_unexpected("AsyncQuery.foreach")
-    async def foreach(self, callback, policy=None) -> None:
+    async def foreach(self, callback, policy=None, filter_expression=None, timeout=None) -> None:
         def _sync_foreach():
             def _cb(raw):
                 return callback(_wrap_record(raw))
 
-            self._inner.foreach(_cb, policy)
+            self._inner.foreach(_cb, policy, filter_expression, timeout=timeout)
 
         await asyncio.to_thread(_sync_foreach)
 
 
+@run_on_asyncio
+class AsyncTask:
+    """Async counterpart of ``Task``: ``done()``, ``progress()`` and ``wait()`` are coroutines."""
+
+    @property
+    def _compat(self) -> LoopBridge | None:
+        return self._client._compat
+
+    async def done(self) -> bool:
+        raise NotImplementedError
+
+    async def progress(self) -> int | None:
+        return None
+
+    def _timeout_message(self, timeout: float) -> str:
+        return f"{self!r} not finished within {timeout}s"
+
+    async def wait(self, timeout: float | None = None, poll_interval: float = 1.0) -> None:
+        loop = asyncio.get_running_loop()
+        deadline = None if timeout is None else loop.time() + timeout
+        while not await self.done():
+            if deadline is not None:
+                remaining = deadline - loop.time()
+                if remaining <= 0:
+                    raise AerospikeTimeoutError(self._timeout_message(timeout))
+                await asyncio.sleep(min(poll_interval, remaining))
+            else:
+                await asyncio.sleep(poll_interval)
+
+
+@run_on_asyncio
+class AsyncIndexTask(AsyncTask):
+    """Async handle for a secondary index build, returned by ``index_*_create()``.
+
+    Same semantics as ``IndexTask``; ``status()``, ``is_done()`` and
+    ``wait()`` are coroutines.
+    """
+
+    def __init__(self, client: "AsyncClient", namespace: str, index_name: str):
+        self._client = client
+        self.namespace = namespace
+        self.index_name = index_name
+
+    async def status(self) -> int:
+        try:
+            return await self._client.index_status(self.namespace, self.index_name)
+        except IndexNotFound:
+            return 0
+
+    async def is_done(self) -> bool:
+        return await self.status() >= 100
+
+    progress = status
+    done = is_done
+
+    def _timeout_message(self, timeout: float) -> str:
+        return f"Index {self.index_name} not built within {timeout}s"
+
+    def __repr__(self) -> str:
+        return f"AsyncIndexTask(namespace={self.namespace!r}, index_name={self.index_name!r})"
+
+
+@run_on_asyncio
+class AsyncUdfTask(AsyncTask):
+    """Async handle for a UDF registration or removal; see ``UdfTask``."""
+
+    def __init__(self, client: "AsyncClient", module: str, removed: bool = False):
+        self._client = client
+        self.module = module
+        self.removed = removed
+
+    async def done(self) -> bool:
+        return await self._client._inner._udf_done(self.module, self.removed)
+
+    def __repr__(self) -> str:
+        return f"AsyncUdfTask(module={self.module!r}, removed={self.removed!r})"
+
+
+@run_on_asyncio
+class AsyncTruncateTask(AsyncTask):
+    """Async handle for a truncate; see ``TruncateTask``."""
+
+    def __init__(self, client: "AsyncClient", namespace: str, set_name: str, nanos: int = 0):
+        self._client = client
+        self.namespace = namespace
+        self.set_name = set_name
+        self.nanos = nanos
+
+    async def done(self) -> bool:
+        return await self._client._inner._truncate_done(self.namespace, self.set_name, self.nanos)
+
+    def __repr__(self) -> str:
+        return f"AsyncTruncateTask(namespace={self.namespace!r}, set_name={self.set_name!r})"
+
+
 # ---------------------------------------------------------------------------
 # AsyncClient
 # ---------------------------------------------------------------------------
 
 
+@run_on_asyncio
+@dispatch_hooks
 class AsyncClient:
     """Aerospike async client wrapper with numpy batch_read support.
 
@@ -74,8 +215,15 @@ class AsyncClient:
     named field access: ``record.meta.gen``, ``record.bins["name"]``.
     """
 
-    def __init__(self, config: dict):
+    def __init__(self, config: dict | str):
+        if isinstance(config, str):
+            config = parse_config_url(config)
+        if config.get("auto_reconnect"):
+            raise ParamError("auto_reconnect is only supported by the sync Client")
         self._inner = _NativeAsyncClient(config)
+        self._slow_log = SlowLog.from_config(config)
+        self._strict_params = strict_params_from_config(config)
+        self._compat = compat_from_config(config)
 
     async def __aenter__(self) -> "AsyncClient":
         return self
@@ -122,25 +270,36 @@ class AsyncClient:
         logger.debug("Async client closing")
         return await self._inner.close()
 
+    async def _relogin(self) -> int:
+        return await self._inner._relogin()
+
     @catch_unexpected("AsyncClient.get")
-    async def get(self, key, policy=None) -> Record:
-        return _wrap_record(await self._inner.get(key, policy))
+    async def get(self, key, policy=None, timeout=None) -> Record:
+        return _wrap_record(await self._inner.get(key, policy, timeout=timeout))
+
+    @catch_unexpected("AsyncClient.get_raw")
+    async def get_raw(self, key, policy=None, timeout=None) -> Record:
+        return _wrap_record(await self._inner.get_raw(key, policy, timeout=timeout))
+
+    @catch_unexpected("AsyncClient.put_raw")
+    async def put_raw(self, key, bins, meta=None, policy=None, timeout=None) -> None:
+        return await self._inner.put_raw(key, bins, meta=meta, policy=policy, timeout=timeout)
 
     @catch_unexpected("AsyncClient.select")
-    async def select(self, key, bins, policy=None) -> Record:
-        return _wrap_record(await self._inner.select(key, bins, policy))
+    async def select(self, key, bins, policy=None, timeout=None) -> Record:
+        return _wrap_record(await self._inner.select(key, bins, policy, timeout=timeout))
 
     @catch_unexpected("AsyncClient.exists")
-    async def exists(self, key, policy=None) -> ExistsResult:
-        return _wrap_exists(await self._inner.exists(key, policy))
+    async def exists(self, key, policy=None, timeout=None) -> ExistsResult:
+        return _wrap_exists(await self._inner.exists(key, policy, timeout=timeout))
 
     @catch_unexpected("AsyncClient.operate")
-    async def operate(self, key, ops, meta=None, policy=None) -> Record:
-        return _wrap_record(await self._inner.operate(key, ops, meta, policy))
+    async def operate(self, key, ops, meta=None, policy=None, timeout=None) -> Record:
+        return _wrap_record(await self._inner.operate(key, ops, meta, policy, timeout=timeout))
 
     @catch_unexpected("AsyncClient.operate_ordered")
-    async def operate_ordered(self, key, ops, meta=None, policy=None) -> OperateOrderedResult:
-        return _wrap_operate_ordered(await self._inner.operate_ordered(key, ops, meta, policy))
+    async def operate_ordered(self, key, ops, meta=None, policy=None, timeout=None) -> OperateOrderedResult:
+        return _wrap_operate_ordered(await self._inner.operate_ordered(key, ops, meta, policy, timeout=timeout))
 
     @catch_unexpected("AsyncClient.info_all")
     async def info_all(self, command, policy=None) -> list[InfoNodeResult]:
@@ -148,7 +307,14 @@ class AsyncClient:
 
     @catch_unexpected("AsyncClient.batch_read")
     async def batch_read(
-        self, keys: list, bins: list[str] | None = None, policy: dict[str, Any] | None = None, _dtype: Any = None
+        self,
+        keys: list,
+        bins: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        _dtype: Any = None,
+        _null_mask: bool = False,
+        output: str | None = None,
+        timeout: float | None = None,
     ) -> Any:
         """Read multiple records in a single batch call.
 
@@ -165,10 +331,17 @@ class AsyncClient:
             policy: Optional batch policy dict.
             _dtype: Optional NumPy dtype. When provided, returns
                 ``NumpyBatchRecords`` instead of ``BatchRecords``.
+            _null_mask: When ``True`` (requires ``_dtype``), also fill
+                ``NumpyBatchRecords.null_mask`` to tell missing bins from zeros.
+            output: ``"arrow"`` returns a ``pyarrow.RecordBatch`` (requires
+                the ``arrow`` extra). Cannot be combined with ``_dtype``.
+            timeout: Optional limit in seconds for the whole call. Raises
+                ``AerospikeTimeoutError`` and aborts the batch when exceeded.
 
         Returns:
-            ``BatchRecords`` (``dict[Key, AerospikeRecord]``) or
-            ``NumpyBatchRecords`` when ``_dtype`` is set.
+            ``BatchRecords`` (``dict[Key, AerospikeRecord]``),
+            ``NumpyBatchRecords`` when ``_dtype`` is set, or
+            ``pyarrow.RecordBatch`` when ``output="arrow"``.
 
         Example:
             ```python
@@ -185,14 +358,117 @@ class AsyncClient:
         # their spawn_blocking threads almost immediately. The heavier dict
         # conversion (1-5ms) runs here in the coroutine on the event loop,
         # where there is no GIL contention between concurrent callers.
-        raw = await self._inner.batch_read(keys, bins, policy, _dtype)
-        if _dtype is not None:
-            return raw  # NumpyBatchRecords path unchanged
+        raw = await self._inner.batch_read(keys, bins, policy, _dtype, _null_mask, output, timeout=timeout)
+        if _dtype is not None or output is not None:
+            return raw  # NumpyBatchRecords / RecordBatch path unchanged
+        return raw.as_dict()
+
+    @catch_unexpected("AsyncClient.batch_read_chunked")
+    async def batch_read_chunked(
+        self,
+        keys: list,
+        chunk_size: int = 1000,
+        concurrency: int = 8,
+        bins: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Any:
+        """Read a large key list as concurrent sub-batches.
+
+        Splits ``keys`` into chunks of ``chunk_size`` and keeps at most
+        ``concurrency`` chunks in flight, then merges the results.
+
+        Args:
+            keys: List of ``(namespace, set, primary_key)`` tuples.
+            chunk_size: Keys per sub-batch.
+            concurrency: Maximum sub-batches in flight at once.
+            bins: Optional list of bin names to read. ``None`` reads all bins.
+            policy: Optional batch policy dict, applied to every sub-batch.
+            timeout: Optional limit in seconds for the whole call, covering
+                every sub-batch.
+
+        Returns:
+            ``BatchRecords`` (``dict[Key, AerospikeRecord]``), same as ``batch_read``.
+
+        Raises:
+            ParamError: ``chunk_size`` or ``concurrency`` is less than 1.
+
+        Example:
+            ```python
+            keys = [("test", "demo", i) for i in range(100_000)]
+            result = await client.batch_read_chunked(keys, chunk_size=2000, concurrency=16)
+            ```
+        """
+        raw = await self._inner.batch_read_chunked(keys, chunk_size, concurrency, bins, policy, timeout=timeout)
         return raw.as_dict()
 
+    @catch_unexpected("AsyncClient.batch_read_df")
+    async def batch_read_df(
+        self,
+        keys: list,
+        columns: list[str] | None = None,
+        policy: BatchPolicy | None = None,
+        timeout: float | None = None,
+    ) -> Any:
+        """Read multiple records into a ``pandas.DataFrame`` (async).
+
+        Args:
+            keys: List of ``(namespace, set, primary_key)`` tuples.
+            columns: Optional list of bin names to read, in column order.
+                ``None`` reads all bins (columns sorted by name).
+            policy: Optional batch policy dict.
+            timeout: Optional limit in seconds for the whole call.
+
+        Returns:
+            A ``pandas.DataFrame`` with one row per key, indexed by user key.
+
+        Example:
+            ```python
+            df = await client.batch_read_df(keys, columns=["age", "score"])
+            ```
+        """
+        return _columns_to_df(await self._inner.batch_read_df(keys, columns, policy, timeout=timeout))
+
+    @catch_unexpected("AsyncClient.batch_exists")
+    async def batch_exists(
+        self, keys: list, policy: BatchPolicy | None = None, dtype: Any = None, timeout: float | None = None
+    ) -> Any:
+        """Check whether multiple records exist in a single header-only batch call (async).
+
+        Args:
+            keys: List of ``(namespace, set, primary_key)`` tuples.
+            policy: Optional batch policy dict.
+            dtype: Optional boolean dtype (``bool`` / ``np.bool_``). When
+                provided, returns NumPy arrays instead of ``ExistsResult`` tuples.
+            timeout: Optional limit in seconds for the whole call.
+
+        Returns:
+            ``list[ExistsResult]`` in key order (``meta`` is ``None`` for missing
+            records), or a ``(found, meta)`` tuple of NumPy arrays when
+            ``dtype`` is set.
+
+        Example:
+            ```python
+            found, meta = await client.batch_exists(keys, dtype=bool)
+            print(f"{found.sum()} of {len(keys)} keys exist")
+            ```
+        """
+        raw = await self._inner.batch_exists(keys, policy, dtype, timeout=timeout)
+        if dtype is not None:
+            return raw
+        return [_wrap_exists(t) for t in raw]
+
     @catch_unexpected("AsyncClient.batch_write_numpy")
     async def batch_write_numpy(
-        self, data, namespace: str, set_name: str, _dtype, key_field: str = "_key", policy=None, retry: int = 0
+        self,
+        data,
+        namespace: str,
+        set_name: str,
+        _dtype,
+        key_field: str = "_key",
+        policy=None,
+        retry: int = 0,
+        timeout: float | None = None,
     ) -> BatchWriteResult:
         """Write multiple records from a numpy structured array (async).
 
@@ -202,8 +478,10 @@ class AsyncClient:
 
         Args:
             data: numpy structured array with record data.
-            namespace: Target namespace.
-            set_name: Target set.
+            namespace: Default namespace. Rows with a non-blank ``_namespace``
+                field (``S``/``U``) override it.
+            set_name: Default set. Rows with a non-blank ``_set`` field
+                override it.
             _dtype: numpy dtype describing the array layout.
             key_field: Name of the dtype field to use as the user key (default ``"_key"``).
             policy: Optional batch policy dict.
@@ -211,6 +489,8 @@ class AsyncClient:
                 When > 0, records that fail with transient errors (timeout,
                 device overload, key busy) are automatically retried with
                 exponential backoff.
+            timeout: Optional limit in seconds for the whole call, retries
+                included.
 
         Returns:
             ``BatchRecords`` containing per-record result codes.
@@ -226,32 +506,54 @@ class AsyncClient:
                     print(f"Failed: {br.key}, code={br.result}")
             ```
         """
-        raw = await self._inner.batch_write_numpy(data, namespace, set_name, _dtype, key_field, policy, retry)
+        raw = await self._inner.batch_write_numpy(
+            data, namespace, set_name, _dtype, key_field, policy, retry, timeout=timeout
+        )
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
     @catch_unexpected("AsyncClient.batch_write")
-    async def batch_write(self, records, policy=None, retry=0) -> BatchWriteResult:
+    async def batch_write(self, records, policy=None, retry=0, timeout=None) -> BatchWriteResult:
         """Write multiple records with per-record bins in a single batch call (async).
 
         See :meth:`AsyncClient.batch_write` in ``__init__.pyi`` for full documentation.
         """
-        raw = await self._inner.batch_write(records, policy, retry)
+        raw = await self._inner.batch_write(records, policy, retry, timeout=timeout)
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
+    @catch_unexpected("AsyncClient.bulk_put")
+    async def bulk_put(
+        self, records, namespace, set_name, key_fn=None, policy=None, chunk_size=1000, concurrency=8, timeout=None
+    ) -> BulkPutResult:
+        return await self._inner.bulk_put(
+            records, namespace, set_name, key_fn, policy, chunk_size, concurrency, timeout=timeout
+        )
+
     @catch_unexpected("AsyncClient.batch_operate")
-    async def batch_operate(self, keys, ops, policy=None) -> BatchWriteResult:
-        raw = await self._inner.batch_operate(keys, ops, policy)
+    async def batch_operate(self, keys, ops, policy=None, timeout=None) -> BatchWriteResult:
+        raw = await self._inner.batch_operate(keys, ops, policy, timeout=timeout)
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
     @catch_unexpected("AsyncClient.batch_remove")
-    async def batch_remove(self, keys, policy=None) -> BatchWriteResult:
-        raw = await self._inner.batch_remove(keys, policy)
+    async def batch_remove(self, keys, policy=None, timeout=None) -> BatchWriteResult:
+        raw = await self._inner.batch_remove(keys, policy, timeout=timeout)
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
     @catch_unexpected("AsyncClient.ping")
     async def ping(self) -> bool:
         return await self._inner.ping()
 
+    @catch_unexpected("AsyncClient.warm_up")
+    async def warm_up(self, conns_per_node: int) -> int:
+        return await self._inner.warm_up(conns_per_node)
+
+    @catch_unexpected("AsyncClient.on_cluster_event")
+    def on_cluster_event(self, callback) -> None:
+        return self._inner.on_cluster_event(callback)
+
+    @catch_unexpected("AsyncClient.set_bin_cipher")
+    def set_bin_cipher(self, encrypt_fn, decrypt_fn, bins: list[str] | None = None) -> None:
+        return self._inner.set_bin_cipher(encrypt_fn, decrypt_fn, bins)
+
     @catch_unexpected("AsyncClient.is_connected")
     def is_connected(self) -> bool:
         return self._inner.is_connected()
@@ -260,51 +562,112 @@ class AsyncClient:
     def get_node_names(self) -> list[str]:
         return self._inner.get_node_names()
 
+    @catch_unexpected("AsyncClient.get_nodes")
+    async def get_nodes(self) -> list[NodeInfo]:
+        return await self._inner.get_nodes()
+
+    @catch_unexpected("AsyncClient.get_node_for_key")
+    def get_node_for_key(self, key) -> KeyNode:
+        return self._inner.get_node_for_key(key)
+
+    @catch_unexpected("AsyncClient.get_partition_id")
+    def get_partition_id(self, key) -> int:
+        return self._inner.get_partition_id(key)
+
+    @catch_unexpected("AsyncClient.get_cluster_stats")
+    async def get_cluster_stats(self) -> dict[str, NodeStats]:
+        return await self._inner.get_cluster_stats()
+
     @catch_unexpected("AsyncClient.info_random_node")
     async def info_random_node(self, command, policy=None) -> str:
         return await self._inner.info_random_node(command, policy)
 
+    @catch_unexpected("AsyncClient.info_single_node")
+    async def info_single_node(self, command, node, policy=None) -> str:
+        return await self._inner.info_single_node(command, node, policy)
+
+    @catch_unexpected("AsyncClient.info_namespaces")
+    async def info_namespaces(self, node=None, policy=None) -> list[str]:
+        return await self._inner.info_namespaces(node, policy)
+
+    @catch_unexpected("AsyncClient.info_sets")
+    async def info_sets(self, namespace, node=None, policy=None) -> list[dict[str, Any]]:
+        return await self._inner.info_sets(namespace, node, policy)
+
+    @catch_unexpected("AsyncClient.info_sindex")
+    async def info_sindex(self, namespace=None, node=None, policy=None) -> list[dict[str, Any]]:
+        return await self._inner.info_sindex(namespace, node, policy)
+
     @catch_unexpected("AsyncClient.put")
-    async def put(self, key, bins, meta=None, policy=None) -> None:
-        return await self._inner.put(key, bins, meta=meta, policy=policy)
+    async def put(self, key, bins, meta=None, policy=None, timeout=None, return_meta=False) -> RecordMetadata | None:
+        return _wrap_meta(
+            await self._inner.put(key, bins, meta=meta, policy=policy, timeout=timeout, return_meta=return_meta)
+        )
 
     @catch_unexpected("AsyncClient.remove")
-    async def remove(self, key, meta=None, policy=None) -> None:
-        return await self._inner.remove(key, meta=meta, policy=policy)
+    async def remove(self, key, meta=None, policy=None, timeout=None) -> None:
+        return await self._inner.remove(key, meta=meta, policy=policy, timeout=timeout)
 
     @catch_unexpected("AsyncClient.touch")
-    async def touch(self, key, val=0, meta=None, policy=None) -> None:
-        return await self._inner.touch(key, val=val, meta=meta, policy=policy)
+    async def touch(self, key, val=0, meta=None, policy=None, timeout=None) -> None:
+        return await self._inner.touch(key, val=val, meta=meta, policy=policy, timeout=timeout)
 
     @catch_unexpected("AsyncClient.append")
-    async def append(self, key, bin, val, meta=None, policy=None) -> None:
-        return await self._inner.append(key, bin, val, meta=meta, policy=policy)
+    async def append(self, key, bin, val, meta=None, policy=None, timeout=None, return_value=False) -> Any:
+        return await self._inner.append(
+            key, bin, val, meta=meta, policy=policy, timeout=timeout, return_value=return_value
+        )
 
     @catch_unexpected("AsyncClient.prepend")
-    async def prepend(self, key, bin, val, meta=None, policy=None) -> None:
-        return await self._inner.prepend(key, bin, val, meta=meta, policy=policy)
+    async def prepend(self, key, bin, val, meta=None, policy=None, timeout=None, return_value=False) -> Any:
+        return await self._inner.prepend(
+            key, bin, val, meta=meta, policy=policy, timeout=timeout, return_value=return_value
+        )
 
     @catch_unexpected("AsyncClient.increment")
-    async def increment(self, key, bin, offset, meta=None, policy=None) -> None:
-        return await self._inner.increment(key, bin, offset, meta=meta, policy=policy)
+    async def increment(self, key, bin, offset, meta=None, policy=None, timeout=None, return_value=False) -> Any:
+        return await self._inner.increment(
+            key, bin, offset, meta=meta, policy=policy, timeout=timeout, return_value=return_value
+        )
 
     @catch_unexpected("AsyncClient.remove_bin")
-    async def remove_bin(self, key, bin_names, meta=None, policy=None) -> None:
-        return await self._inner.remove_bin(key, bin_names, meta=meta, policy=policy)
+    async def remove_bin(self, key, bin_names, meta=None, policy=None, timeout=None) -> None:
+        return await self._inner.remove_bin(key, bin_names, meta=meta, policy=policy, timeout=timeout)
 
     # -- Index --
 
     @catch_unexpected("AsyncClient.index_integer_create")
-    async def index_integer_create(self, namespace, set_name, bin_name, index_name, policy=None) -> None:
-        return await self._inner.index_integer_create(namespace, set_name, bin_name, index_name, policy)
+    async def index_integer_create(
+        self, namespace, set_name, bin_name, index_name, policy=None, wait=True
+    ) -> AsyncIndexTask:
+        await self._inner.index_integer_create(namespace, set_name, bin_name, index_name, policy, wait)
+        return AsyncIndexTask(self, namespace, index_name)
 
     @catch_unexpected("AsyncClient.index_string_create")
-    async def index_string_create(self, namespace, set_name, bin_name, index_name, policy=None) -> None:
-        return await self._inner.index_string_create(namespace, set_name, bin_name, index_name, policy)
+    async def index_string_create(
+        self, namespace, set_name, bin_name, index_name, policy=None, wait=True
+    ) -> AsyncIndexTask:
+        await self._inner.index_string_create(namespace, set_name, bin_name, index_name, policy, wait)
+        return AsyncIndexTask(self, namespace, index_name)
 
     @catch_unexpected("AsyncClient.index_geo2dsphere_create")
-    async def index_geo2dsphere_create(self, namespace, set_name, bin_name, index_name, policy=None) -> None:
-        return await self._inner.index_geo2dsphere_create(namespace, set_name, bin_name, index_name, policy)
+    async def index_geo2dsphere_create(
+        self, namespace, set_name, bin_name, index_name, policy=None, wait=True
+    ) -> AsyncIndexTask:
+        await self._inner.index_geo2dsphere_create(namespace, set_name, bin_name, index_name, policy, wait)
+        return AsyncIndexTask(self, namespace, index_name)
+
+    @catch_unexpected("AsyncClient.index_status")
+    async def index_status(self, namespace, index_name, policy=None) -> int:
+        return await self._inner.index_status(namespace, index_name, policy)
+
+    @catch_unexpected("AsyncClient.job_info")
+    async def job_info(self, job_id, module, policy=None) -> JobInfo:
+        return await self._inner.job_info(job_id, module, policy)
+
+    @catch_unexpected("AsyncClient.scan_info")
+    async def scan_info(self, scan_id, policy=None) -> JobInfo:
+        return await self._inner.job_info(scan_id, JOB_SCAN, policy)
 
     @catch_unexpected("AsyncClient.index_remove")
     async def index_remove(self, namespace, index_name, policy=None) -> None:
@@ -313,26 +676,37 @@ class AsyncClient:
     # -- Truncate --
 
     @catch_unexpected("AsyncClient.truncate")
-    async def truncate(self, namespace, set_name, nanos=0, policy=None) -> None:
-        return await self._inner.truncate(namespace, set_name, nanos, policy)
+    async def truncate(self, namespace, set_name, nanos=0, policy=None, wait=False, timeout=None) -> AsyncTruncateTask:
+        await self._inner.truncate(namespace, set_name, nanos, policy, wait, timeout)
+        return AsyncTruncateTask(self, namespace, set_name, nanos)
 
     # -- UDF --
 
     @catch_unexpected("AsyncClient.udf_put")
-    async def udf_put(self, filename, udf_type=0, policy=None) -> None:
-        return await self._inner.udf_put(filename, udf_type, policy)
+    async def udf_put(self, filename, udf_type=0, policy=None, wait=True) -> AsyncUdfTask:
+        await self._inner.udf_put(filename, udf_type, policy, wait)
+        return AsyncUdfTask(self, os.path.basename(filename))
 
     @catch_unexpected("AsyncClient.udf_remove")
-    async def udf_remove(self, module, policy=None) -> None:
-        return await self._inner.udf_remove(module, policy)
+    async def udf_remove(self, module, policy=None, wait=True) -> AsyncUdfTask:
+        await self._inner.udf_remove(module, policy, wait)
+        return AsyncUdfTask(self, module, removed=True)
+
+    @catch_unexpected("AsyncClient.udf_list")
+    async def udf_list(self, policy=None) -> list[dict[str, Any]]:
+        return await self._inner.udf_list(policy)
+
+    @catch_unexpected("AsyncClient.udf_get")
+    async def udf_get(self, module, policy=None) -> str:
+        return await self._inner.udf_get(module, policy)
 
     @catch_unexpected("AsyncClient.apply")
-    async def apply(self, key, module, function, args=None, policy=None):
-        return await self._inner.apply(key, module, function, args, policy)
+    async def apply(self, key, module, function, args=None, policy=None, timeout=None):
+        return await self._inner.apply(key, module, function, args, policy, timeout=timeout)
 
     @catch_unexpected("AsyncClient.batch_apply")
-    async def batch_apply(self, keys, module, function, args=None, policy=None) -> BatchWriteResult:
-        raw = await self._inner.batch_apply(keys, module, function, args, policy)
+    async def batch_apply(self, keys, module, function, args=None, policy=None, timeout=None) -> BatchWriteResult:
+        raw = await self._inner.batch_apply(keys, module, function, args, policy, timeout=timeout)
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
     # -- Admin: User --
@@ -365,6 +739,10 @@ class AsyncClient:
     async def admin_query_users_info(self, policy=None):
         return await self._inner.admin_query_users_info(policy)
 
+    @catch_unexpected("AsyncClient.admin_whoami")
+    async def admin_whoami(self, policy=None):
+        return await self._inner.admin_whoami(policy)
+
     # -- Admin: Role --
 
     @catch_unexpected("AsyncClient.admin_create_role")
@@ -403,7 +781,7 @@ class AsyncClient:
 
     # -- Query --
 
-    def query(self, namespace: str, set_name: str) -> AsyncQuery:
+    def query(self, namespace: str, set_name: str | None) -> AsyncQuery:
         """Create a query object for the given namespace and set.
 
         Returns an ``AsyncQuery`` whose ``results()`` and ``foreach()``
@@ -411,7 +789,8 @@ class AsyncClient:
 
         Args:
             namespace: The namespace to query.
-            set_name: The set name to query.
+            set_name: The set name to query. ``None`` or ``""`` queries
+                every record in the namespace.
 
         Returns:
             An ``AsyncQuery`` instance.
@@ -423,4 +802,33 @@ class AsyncClient:
             records = await query.results()
             ```
         """
-        return AsyncQuery(self._inner.query(namespace, set_name))
+        return AsyncQuery(self._inner.query(namespace, set_name), self._compat)
+
+    def scan(self, namespace: str, set_name: str | None) -> AsyncQuery:
+        """Create a scan of every record in the namespace and set.
+
+        A scan is an ``AsyncQuery`` without a ``where()`` predicate.
+
+        Example:
+            ```python
+            records = await client.scan("test", "demo").results()
+            ```
+        """
+        return self.query(namespace, set_name)
+
+    @catch_unexpected("AsyncClient.execute")
+    async def execute(self, query, policy=None, dtype=None, filter_expression=None, ops=None):
+        """Run a ``Query`` built with ``Query(namespace, set_name)`` on this client.
+
+        The query itself stays unbound, so it can be run again, on any client.
+        Arguments are those of ``AsyncQuery.results()``.
+
+        Example:
+            ```python
+            template = aerospike_py.Query("test", "demo")
+            template.where(predicates.between("age", 20, 30))
+            records = await client.execute(template)
+            ```
+        """
+        bound = AsyncQuery(self._inner.bind_query(query._inner), self._compat)
+        return await bound.results(policy, dtype, filter_expression, ops)
@@ -3,17 +3,33 @@
 from __future__ import annotations
 
 import logging
+import os
+import time
+from typing import Any
 
+from aerospike_py._aerospike import JOB_SCAN, AerospikeTimeoutError, IndexNotFound
 from aerospike_py._aerospike import Client as _NativeClient
+from aerospike_py._aerospike import Pipeline as _NativePipeline
 from aerospike_py._aerospike import Query as _NativeQuery
+from aerospike_py._aerospike import parse_config_url
 from aerospike_py._bug_report import catch_unexpected
+from aerospike_py._dispatch import dispatch_hooks
+from aerospike_py._reconnect import AutoReconnect
+from aerospike_py._slow_log import SlowLog
+from aerospike_py._strict_params import strict_params_from_config
+from aerospike_py.dataframe import _columns_to_df
 from aerospike_py.types import (
     AerospikeKey,
     BatchRecord as BatchRecordTuple,
     BatchWriteResult,
     BinTuple,
+    BulkPutResult,
     ExistsResult,
     InfoNodeResult,
+    JobInfo,
+    KeyNode,
+    NodeInfo,
+    NodeStats,
     OperateOrderedResult,
     Record,
     RecordMetadata,
@@ -36,7 +52,7 @@ def _wrap_key(raw: tuple | None) -> AerospikeKey | None:
 def _wrap_meta(raw: dict | None) -> RecordMetadata | None:
     if raw is None:
         return None
-    return RecordMetadata(gen=raw["gen"], ttl=raw["ttl"])
+    return RecordMetadata(gen=raw["gen"], ttl=raw["ttl"], void_time=raw.get("void_time", 0))
 
 
 def _wrap_record(raw: tuple) -> Record:
@@ -67,27 +83,210 @@ def _wrap_batch_record(br) -> BatchRecordTuple:
 
 
 class Query:
-    """Python wrapper around the native Query object that returns typed records."""
+    """Python wrapper around the native Query object that returns typed records.
 
-    def __init__(self, inner: _NativeQuery):
-        self._inner = inner
+    ``Query(namespace, set_name)`` builds a query bound to no client; run it
+    with ``Client.execute()`` / ``AsyncClient.execute()``.
+    """
+
+    def __init__(self, namespace: str, set_name: str | None = None):
+        self._inner = _NativeQuery(namespace, set_name)
+
+    @classmethod
+    def _from_native(cls, inner: _NativeQuery) -> "Query":
+        query = cls.__new__(cls)
+        query._inner = inner
+        return query
 
-    def select(self, *bins: str) -> None:
+    def select(self, *bins: str | dict[str, str]) -> None:
         self._inner.select(*bins)
 
     def where(self, predicate) -> None:
         self._inner.where(predicate)
 
+    def max_records(self, n: int) -> None:
+        self._inner.max_records(n)
+
+    def records_per_second(self, n: int) -> None:
+        self._inner.records_per_second(n)
+
+    def max_concurrent_nodes(self, n: int) -> None:
+        self._inner.max_concurrent_nodes(n)
+
+    def set_nodes(self, nodes: list[str]) -> None:
+        self._inner.set_nodes(nodes)
+
     @catch_unexpected("Query.results")
-    def results(self, policy=None) -> list[Record]:
-        return [_wrap_record(r) for r in self._inner.results(policy)]
+    def results(self, policy=None, dtype=None, filter_expression=None, ops=None):
+        if dtype is not None:
+            return self._inner.results(policy, dtype, filter_expression, ops)
+        return [_wrap_record(r) for r in self._inner.results(policy, None, filter_expression, ops)]
+
+    @catch_unexpected("Query.results_arrow")
+    def results_arrow(self, policy=None, filter_expression=None):
+        return self._inner.results_arrow(policy, filter_expression)
+
+    @catch_unexpected("Query.to_df")
+    def to_df(self, policy=None, filter_expression=None):
+        return _columns_to_df(self._inner.results_columns(policy, filter_expression))
+
+    @catch_unexpected("Query.execute_background")
+    def execute_background(self, ops, policy=None, filter_expression=None) -> int:
+        return self._inner.execute_background(ops, policy, filter_expression)
 
     @catch_unexpected("Query.foreach")
-    def foreach(self, callback, policy=None) -> None:
+    def foreach(self, callback, policy=None, filter_expression=None) -> None:
         def _cb(raw):
             return callback(_wrap_record(raw))
 
-        self._inner.foreach(_cb, policy)
+        self._inner.foreach(_cb, policy, filter_expression)
+
+
+# ---------------------------------------------------------------------------
+# Pipeline Python wrapper
+# ---------------------------------------------------------------------------
+
+
+class Pipeline:
+    """Python wrapper around the native Pipeline that returns typed records.
+
+    Used as a context manager, queued operations run when the ``with`` block
+    exits without an exception and their results are stored on ``results``.
+    """
+
+    def __init__(self, inner: _NativePipeline):
+        self._inner = inner
+        self.results: list | None = None
+
+    def put(self, key, bins, meta=None, policy=None) -> None:
+        self._inner.put(key, bins, meta, policy)
+
+    def get(self, key, policy=None) -> None:
+        self._inner.get(key, policy)
+
+    def operate(self, key, ops, meta=None, policy=None) -> None:
+        self._inner.operate(key, ops, meta, policy)
+
+    def __len__(self) -> int:
+        return len(self._inner)
+
+    @catch_unexpected("Pipeline.execute")
+    def execute(self, raise_on_error: bool = True) -> list:
+        results = [_wrap_record(r) if isinstance(r, tuple) else r for r in self._inner.execute()]
+        self.results = results
+        if raise_on_error:
+            for r in results:
+                if isinstance(r, BaseException):
+                    raise r
+        return results
+
+    def __enter__(self) -> "Pipeline":
+        return self
+
+    def __exit__(self, exc_type, exc_val, exc_tb) -> bool:
+        if exc_type is None:
+            self.execute()
+        else:
+            self._inner.clear()
+        return False
+
+
+class Task:
+    """Handle for work the server finishes in the background.
+
+    Subclasses implement ``done()``, and ``progress()`` where the server
+    reports a percentage.
+    """
+
+    def done(self) -> bool:
+        raise NotImplementedError
+
+    def progress(self) -> int | None:
+        return None
+
+    def _timeout_message(self, timeout: float) -> str:
+        return f"{self!r} not finished within {timeout}s"
+
+    def wait(self, timeout: float | None = None, poll_interval: float = 1.0) -> None:
+        deadline = None if timeout is None else time.monotonic() + timeout
+        while not self.done():
+            if deadline is not None:
+                remaining = deadline - time.monotonic()
+                if remaining <= 0:
+                    raise AerospikeTimeoutError(self._timeout_message(timeout))
+                time.sleep(min(poll_interval, remaining))
+            else:
+                time.sleep(poll_interval)
+
+
+class IndexTask(Task):
+    """Handle for a secondary index build, returned by ``index_*_create()``.
+
+    The server builds indexes in the background; ``status()`` polls
+    ``Client.index_status()``. An index the nodes have not registered yet
+    counts as 0%.
+    """
+
+    def __init__(self, client: "Client", namespace: str, index_name: str):
+        self._client = client
+        self.namespace = namespace
+        self.index_name = index_name
+
+    def status(self) -> int:
+        try:
+            return self._client.index_status(self.namespace, self.index_name)
+        except IndexNotFound:
+            return 0
+
+    def is_done(self) -> bool:
+        return self.status() >= 100
+
+    progress = status
+    done = is_done
+
+    def _timeout_message(self, timeout: float) -> str:
+        return f"Index {self.index_name} not built within {timeout}s"
+
+    def __repr__(self) -> str:
+        return f"IndexTask(namespace={self.namespace!r}, index_name={self.index_name!r})"
+
+
+class UdfTask(Task):
+    """Handle for a UDF registration or removal, returned by ``udf_put()`` / ``udf_remove()``.
+
+    Done once every node has the module (or, for a removal, none has).
+    """
+
+    def __init__(self, client: "Client", module: str, removed: bool = False):
+        self._client = client
+        self.module = module
+        self.removed = removed
+
+    def done(self) -> bool:
+        return self._client._udf_done(self.module, self.removed)
+
+    def __repr__(self) -> str:
+        return f"UdfTask(module={self.module!r}, removed={self.removed!r})"
+
+
+class TruncateTask(Task):
+    """Handle for a truncate, returned by ``truncate()``.
+
+    Done once no node reports the set as truncating and, for a full
+    truncate, the set holds no objects.
+    """
+
+    def __init__(self, client: "Client", namespace: str, set_name: str, nanos: int = 0):
+        self._client = client
+        self.namespace = namespace
+        self.set_name = set_name
+        self.nanos = nanos
+
+    def done(self) -> bool:
+        return self._client._truncate_done(self.namespace, self.set_name, self.nanos)
+
+    def __repr__(self) -> str:
+        return f"TruncateTask(namespace={self.namespace!r}, set_name={self.set_name!r})"
 
 
 # ---------------------------------------------------------------------------
@@ -95,6 +294,7 @@ class Query:
 # ---------------------------------------------------------------------------
 
 
+@dispatch_hooks
 class Client(_NativeClient):
     """Aerospike client wrapper that supports method chaining on connect().
 
@@ -103,6 +303,13 @@ class Client(_NativeClient):
     named field access: ``record.meta.gen``, ``record.bins["name"]``.
     """
 
+    def __init__(self, config: dict | str):
+        if isinstance(config, str):
+            config = parse_config_url(config)
+        self._slow_log = SlowLog.from_config(config)
+        self._auto_reconnect = AutoReconnect.from_config(config)
+        self._strict_params = strict_params_from_config(config)
+
     def connect(self, username: str | None = None, password: str | None = None) -> "Client":
         """Connect to the Aerospike cluster.
 
@@ -128,12 +335,33 @@ class Client(_NativeClient):
         """
         logger.info("Connecting to Aerospike cluster")
         super().connect(username, password)
+        if self._auto_reconnect is not None:
+            self._auto_reconnect.credentials = (username, password)
         return self
 
+    def close(self) -> None:
+        """Close the connection to the cluster. Disables ``auto_reconnect`` until the next ``connect()``."""
+        if self._auto_reconnect is not None:
+            self._auto_reconnect.credentials = None
+        super().close()
+
+    def _reconnect(self, username: str | None, password: str | None) -> None:
+        """Replace a client that lost its cluster with a fresh connection (``auto_reconnect``)."""
+        super().close()
+        super().connect(username, password)
+
     @catch_unexpected("Client.get")
     def get(self, key, policy=None) -> Record:
         return _wrap_record(super().get(key, policy))
 
+    @catch_unexpected("Client.get_raw")
+    def get_raw(self, key, policy=None) -> Record:
+        return _wrap_record(super().get_raw(key, policy))
+
+    @catch_unexpected("Client.put_raw")
+    def put_raw(self, key, bins, meta=None, policy=None) -> None:
+        return super().put_raw(key, bins, meta=meta, policy=policy)
+
     @catch_unexpected("Client.select")
     def select(self, key, bins, policy=None) -> Record:
         return _wrap_record(super().select(key, bins, policy))
@@ -155,7 +383,7 @@ class Client(_NativeClient):
         return [InfoNodeResult(*t) for t in super().info_all(command, policy)]
 
     @catch_unexpected("Client.batch_read")
-    def batch_read(self, keys, bins=None, policy=None, _dtype=None):
+    def batch_read(self, keys, bins=None, policy=None, _dtype=None, _null_mask=False, output=None):
         """Read multiple records in a single batch call.
 
         Args:
@@ -165,10 +393,15 @@ class Client(_NativeClient):
             policy: Optional batch policy dict.
             _dtype: Optional NumPy dtype. When provided, returns
                 ``NumpyBatchRecords`` instead of ``BatchRecords``.
+            _null_mask: When ``True`` (requires ``_dtype``), also fill
+                ``NumpyBatchRecords.null_mask`` to tell missing bins from zeros.
+            output: ``"arrow"`` returns a ``pyarrow.RecordBatch`` (requires
+                the ``arrow`` extra). Cannot be combined with ``_dtype``.
 
         Returns:
-            ``BatchRecords`` (``dict[Key, AerospikeRecord]``) or
-            ``NumpyBatchRecords`` when ``_dtype`` is set.
+            ``BatchRecords`` (``dict[Key, AerospikeRecord]``),
+            ``NumpyBatchRecords`` when ``_dtype`` is set, or
+            ``pyarrow.RecordBatch`` when ``output="arrow"``.
 
         Example:
             ```python
@@ -178,7 +411,59 @@ class Client(_NativeClient):
                 print(user_key, bins_dict)
             ```
         """
-        return super().batch_read(keys, bins, policy, _dtype)
+        return super().batch_read(keys, bins, policy, _dtype, _null_mask, output)
+
+    @catch_unexpected("Client.batch_read_df")
+    def batch_read_df(self, keys, columns=None, policy=None):
+        """Read multiple records into a ``pandas.DataFrame``.
+
+        Column buffers are built natively (int / float / bool bins as NumPy
+        arrays), so no per-row dict is created.
+
+        Args:
+            keys: List of ``(namespace, set, primary_key)`` tuples.
+            columns: Optional list of bin names to read, in column order.
+                ``None`` reads all bins (columns sorted by name).
+            policy: Optional batch policy dict.
+
+        Returns:
+            A ``pandas.DataFrame`` with one row per key, indexed by user key.
+            Missing records and bins are null (``NaN`` / ``<NA>`` / ``None``).
+
+        Example:
+            ```python
+            df = client.batch_read_df(keys, columns=["age", "score"])
+            print(df["score"].mean())
+            ```
+        """
+        return _columns_to_df(super().batch_read_df(keys, columns, policy))
+
+    @catch_unexpected("Client.batch_exists")
+    def batch_exists(self, keys, policy=None, dtype=None):
+        """Check whether multiple records exist in a single header-only batch call.
+
+        Args:
+            keys: List of ``(namespace, set, primary_key)`` tuples.
+            policy: Optional batch policy dict.
+            dtype: Optional boolean dtype (``bool`` / ``np.bool_``). When
+                provided, returns NumPy arrays instead of ``ExistsResult`` tuples.
+
+        Returns:
+            ``list[ExistsResult]`` in key order (``meta`` is ``None`` for missing
+            records), or a ``(found, meta)`` tuple of NumPy arrays when
+            ``dtype`` is set: ``found`` is a bool mask and ``meta`` a structured
+            ``gen``/``ttl`` array.
+
+        Example:
+            ```python
+            found, meta = client.batch_exists(keys, dtype=bool)
+            new_keys = [k for k, hit in zip(keys, found) if not hit]
+            ```
+        """
+        raw = super().batch_exists(keys, policy, dtype)
+        if dtype is not None:
+            return raw
+        return [_wrap_exists(t) for t in raw]
 
     @catch_unexpected("Client.batch_write_numpy")
     def batch_write_numpy(self, data, namespace, set_name, _dtype, key_field="_key", policy=None, retry=0):
@@ -190,8 +475,10 @@ class Client(_NativeClient):
 
         Args:
             data: numpy structured array with record data.
-            namespace: Target namespace.
-            set_name: Target set.
+            namespace: Default namespace. Rows with a non-blank ``_namespace``
+                field (``S``/``U``) override it.
+            set_name: Default set. Rows with a non-blank ``_set`` field
+                override it.
             _dtype: numpy dtype describing the array layout.
             key_field: Name of the dtype field to use as the user key (default ``"_key"``).
             policy: Optional batch policy dict.
@@ -226,6 +513,12 @@ class Client(_NativeClient):
         raw = super().batch_write(records, policy, retry)
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
+    @catch_unexpected("Client.bulk_put")
+    def bulk_put(
+        self, records, namespace, set_name, key_fn=None, policy=None, chunk_size=1000, concurrency=8
+    ) -> BulkPutResult:
+        return super().bulk_put(records, namespace, set_name, key_fn, policy, chunk_size, concurrency)
+
     @catch_unexpected("Client.batch_operate")
     def batch_operate(self, keys, ops, policy=None) -> BatchWriteResult:
         raw = super().batch_operate(keys, ops, policy)
@@ -237,8 +530,8 @@ class Client(_NativeClient):
         return BatchWriteResult(batch_records=[_wrap_batch_record(br) for br in raw.batch_records])
 
     @catch_unexpected("Client.put")
-    def put(self, key, bins, meta=None, policy=None) -> None:
-        return super().put(key, bins, meta=meta, policy=policy)
+    def put(self, key, bins, meta=None, policy=None, return_meta=False) -> RecordMetadata | None:
+        return _wrap_meta(super().put(key, bins, meta=meta, policy=policy, return_meta=return_meta))
 
     @catch_unexpected("Client.remove")
     def remove(self, key, meta=None, policy=None) -> None:
@@ -249,16 +542,16 @@ class Client(_NativeClient):
         return super().touch(key, val=val, meta=meta, policy=policy)
 
     @catch_unexpected("Client.append")
-    def append(self, key, bin, val, meta=None, policy=None) -> None:
-        return super().append(key, bin, val, meta=meta, policy=policy)
+    def append(self, key, bin, val, meta=None, policy=None, return_value=False) -> Any:
+        return super().append(key, bin, val, meta=meta, policy=policy, return_value=return_value)
 
     @catch_unexpected("Client.prepend")
-    def prepend(self, key, bin, val, meta=None, policy=None) -> None:
-        return super().prepend(key, bin, val, meta=meta, policy=policy)
+    def prepend(self, key, bin, val, meta=None, policy=None, return_value=False) -> Any:
+        return super().prepend(key, bin, val, meta=meta, policy=policy, return_value=return_value)
 
     @catch_unexpected("Client.increment")
-    def increment(self, key, bin, offset, meta=None, policy=None) -> None:
-        return super().increment(key, bin, offset, meta=meta, policy=policy)
+    def increment(self, key, bin, offset, meta=None, policy=None, return_value=False) -> Any:
+        return super().increment(key, bin, offset, meta=meta, policy=policy, return_value=return_value)
 
     @catch_unexpected("Client.remove_bin")
     def remove_bin(self, key, bin_names, meta=None, policy=None) -> None:
@@ -267,16 +560,31 @@ class Client(_NativeClient):
     # -- Index --
 
     @catch_unexpected("Client.index_integer_create")
-    def index_integer_create(self, namespace, set_name, bin_name, index_name, policy=None) -> None:
-        return super().index_integer_create(namespace, set_name, bin_name, index_name, policy)
+    def index_integer_create(self, namespace, set_name, bin_name, index_name, policy=None, wait=True) -> IndexTask:
+        super().index_integer_create(namespace, set_name, bin_name, index_name, policy, wait)
+        return IndexTask(self, namespace, index_name)
 
     @catch_unexpected("Client.index_string_create")
-    def index_string_create(self, namespace, set_name, bin_name, index_name, policy=None) -> None:
-        return super().index_string_create(namespace, set_name, bin_name, index_name, policy)
+    def index_string_create(self, namespace, set_name, bin_name, index_name, policy=None, wait=True) -> IndexTask:
+        super().index_string_create(namespace, set_name, bin_name, index_name, policy, wait)
+        return IndexTask(self, namespace, index_name)
 
     @catch_unexpected("Client.index_geo2dsphere_create")
-    def index_geo2dsphere_create(self, namespace, set_name, bin_name, index_name, policy=None) -> None:
-        return super().index_geo2dsphere_create(namespace, set_name, bin_name, index_name, policy)
+    def index_geo2dsphere_create(self, namespace, set_name, bin_name, index_name, policy=None, wait=True) -> IndexTask:
+        super().index_geo2dsphere_create(namespace, set_name, bin_name, index_name, policy, wait)
+        return IndexTask(self, namespace, index_name)
+
+    @catch_unexpected("Client.index_status")
+    def index_status(self, namespace, index_name, policy=None) -> int:
+        return super().index_status(namespace, index_name, policy)
+
+    @catch_unexpected("Client.job_info")
+    def job_info(self, job_id, module, policy=None) -> JobInfo:
+        return super().job_info(job_id, module, policy)
+
+    @catch_unexpected("Client.scan_info")
+    def scan_info(self, scan_id, policy=None) -> JobInfo:
+        return super().job_info(scan_id, JOB_SCAN, policy)
 
     @catch_unexpected("Client.index_remove")
     def index_remove(self, namespace, index_name, policy=None) -> None:
@@ -285,18 +593,29 @@ class Client(_NativeClient):
     # -- Truncate --
 
     @catch_unexpected("Client.truncate")
-    def truncate(self, namespace, set_name, nanos=0, policy=None) -> None:
-        return super().truncate(namespace, set_name, nanos, policy)
+    def truncate(self, namespace, set_name, nanos=0, policy=None, wait=False, timeout=None) -> TruncateTask:
+        super().truncate(namespace, set_name, nanos, policy, wait, timeout)
+        return TruncateTask(self, namespace, set_name, nanos)
 
     # -- UDF --
 
     @catch_unexpected("Client.udf_put")
-    def udf_put(self, filename, udf_type=0, policy=None) -> None:
-        return super().udf_put(filename, udf_type, policy)
+    def udf_put(self, filename, udf_type=0, policy=None, wait=True) -> UdfTask:
+        super().udf_put(filename, udf_type, policy, wait)
+        return UdfTask(self, os.path.basename(filename))
 
     @catch_unexpected("Client.udf_remove")
-    def udf_remove(self, module, policy=None) -> None:
-        return super().udf_remove(module, policy)
+    def udf_remove(self, module, policy=None, wait=True) -> UdfTask:
+        super().udf_remove(module, policy, wait)
+        return UdfTask(self, module, removed=True)
+
+    @catch_unexpected("Client.udf_list")
+    def udf_list(self, policy=None) -> list[dict[str, Any]]:
+        return super().udf_list(policy)
+
+    @catch_unexpected("Client.udf_get")
+    def udf_get(self, module, policy=None) -> str:
+        return super().udf_get(module, policy)
 
     @catch_unexpected("Client.apply")
     def apply(self, key, module, function, args=None, policy=None):
@@ -337,6 +656,10 @@ class Client(_NativeClient):
     def admin_query_users_info(self, policy=None):
         return super().admin_query_users_info(policy)
 
+    @catch_unexpected("Client.admin_whoami")
+    def admin_whoami(self, policy=None):
+        return super().admin_whoami(policy)
+
     # -- Admin: Role --
 
     @catch_unexpected("Client.admin_create_role")
@@ -377,6 +700,18 @@ class Client(_NativeClient):
     def ping(self) -> bool:
         return super().ping()
 
+    @catch_unexpected("Client.warm_up")
+    def warm_up(self, conns_per_node: int) -> int:
+        return super().warm_up(conns_per_node)
+
+    @catch_unexpected("Client.on_cluster_event")
+    def on_cluster_event(self, callback) -> None:
+        return super().on_cluster_event(callback)
+
+    @catch_unexpected("Client.set_bin_cipher")
+    def set_bin_cipher(self, encrypt_fn, decrypt_fn, bins: list[str] | None = None) -> None:
+        return super().set_bin_cipher(encrypt_fn, decrypt_fn, bins)
+
     @catch_unexpected("Client.is_connected")
     def is_connected(self) -> bool:
         return super().is_connected()
@@ -385,10 +720,39 @@ class Client(_NativeClient):
     def get_node_names(self) -> list[str]:
         return super().get_node_names()
 
+    @catch_unexpected("Client.get_nodes")
+    def get_nodes(self) -> list[NodeInfo]:
+        return super().get_nodes()
+
+    @catch_unexpected("Client.get_node_for_key")
+    def get_node_for_key(self, key) -> KeyNode:
+        return super().get_node_for_key(key)
+
+    @catch_unexpected("Client.get_partition_id")
+    def get_partition_id(self, key) -> int:
+        return super().get_partition_id(key)
+
+    @catch_unexpected("Client.get_cluster_stats")
+    def get_cluster_stats(self) -> dict[str, NodeStats]:
+        return super().get_cluster_stats()
+
     # -- Query --
 
     def query(self, namespace, set_name) -> Query:
-        return Query(super().query(namespace, set_name))
+        return Query._from_native(super().query(namespace, set_name))
+
+    @catch_unexpected("Client.execute")
+    def execute(self, query, policy=None, dtype=None, filter_expression=None, ops=None):
+        bound = Query._from_native(super().bind_query(query._inner))
+        return bound.results(policy, dtype, filter_expression, ops)
+
+    def scan(self, namespace, set_name) -> Query:
+        return self.query(namespace, set_name)
+
+    # -- Pipeline --
+
+    def pipeline(self, max_concurrency: int = 16) -> Pipeline:
+        return Pipeline(super().pipeline(max_concurrency))
 
     def __enter__(self) -> "Client":
         return self
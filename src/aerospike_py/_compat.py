@@ -0,0 +1,101 @@
+"""Run ``AsyncClient`` calls under event loops other than asyncio.
+
+The native awaitables are asyncio futures, so they need a running asyncio
+loop: the default loop or uvloop. When the client config sets
+``compat="anyio"``, an ``AsyncClient`` awaited from trio (directly or through
+anyio's trio backend) runs each call on a private asyncio loop in a
+background thread and waits for the result without blocking trio.
+Cancelling the trio task cancels the call. Under asyncio, calls run directly.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import functools
+import inspect
+import os
+import threading
+
+from aerospike_py._aerospike import ParamError
+
+__all__ = ["LoopBridge", "compat_from_config", "run_on_asyncio"]
+
+_MODES = ("asyncio", "anyio")
+
+
+class LoopBridge:
+    """Asyncio loop on a daemon thread, started on first use (again after ``os.fork()``)."""
+
+    def __init__(self):
+        self._loop: asyncio.AbstractEventLoop | None = None
+        self._pid = 0
+        self._lock = threading.Lock()
+
+    def _get_loop(self) -> asyncio.AbstractEventLoop:
+        with self._lock:
+            if self._loop is None or self._pid != os.getpid():
+                loop = asyncio.new_event_loop()
+                threading.Thread(target=loop.run_forever, name="aerospike-py-compat", daemon=True).start()
+                self._loop = loop
+                self._pid = os.getpid()
+            return self._loop
+
+    async def run(self, make_coro):
+        """Await ``make_coro()`` on the bridge loop from a trio task."""
+        try:
+            import trio
+        except ImportError:
+            raise RuntimeError(
+                "AsyncClient awaited outside an asyncio event loop; compat='anyio' supports asyncio and trio"
+            ) from None
+        token = trio.lowlevel.current_trio_token()
+        done = trio.Event()
+        future = asyncio.run_coroutine_threadsafe(make_coro(), self._get_loop())
+
+        def _wake(_future):
+            try:
+                token.run_sync_soon(done.set)
+            except trio.RunFinishedError:
+                pass
+
+        future.add_done_callback(_wake)
+        try:
+            await done.wait()
+        except BaseException:
+            future.cancel()
+            raise
+        return future.result()
+
+
+_BRIDGE = LoopBridge()
+
+
+def compat_from_config(config: dict) -> LoopBridge | None:
+    """Read ``compat`` from the client config: the shared bridge for ``"anyio"``, else ``None``."""
+    mode = config.get("compat", "asyncio")
+    if mode not in _MODES:
+        raise ParamError(f"compat must be one of {', '.join(map(repr, _MODES))}, got {mode!r}")
+    return _BRIDGE if mode == "anyio" else None
+
+
+def _bridged(func):
+    @functools.wraps(func)
+    async def wrapper(self, *args, **kwargs):
+        bridge = self._compat
+        if bridge is None or asyncio._get_running_loop() is not None:
+            return await func(self, *args, **kwargs)
+        return await bridge.run(lambda: func(self, *args, **kwargs))
+
+    return wrapper
+
+
+def run_on_asyncio(cls):
+    """Class decorator: run each coroutine method on the bridge loop outside asyncio.
+
+    Instances expose the bridge as ``_compat`` (``None`` to always run directly).
+    Apply outermost, so the wrapped call runs entirely on an asyncio loop.
+    """
+    for name, attr in list(vars(cls).items()):
+        if inspect.iscoroutinefunction(attr):
+            setattr(cls, name, _bridged(attr))
+    return cls
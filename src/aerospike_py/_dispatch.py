@@ -0,0 +1,114 @@
+"""Per-call client hooks, applied through a single wrapper per method.
+
+``dispatch_hooks`` wraps each ``@catch_unexpected`` method of a client class
+once. Around every call the wrapper:
+
+1. checks policy and operation dicts when ``self._strict_params`` is set
+   (see ``_strict_params``), before any other hook runs;
+2. times the call against ``self._slow_log`` (see ``_slow_log``), so a
+   reconnect or re-login below counts toward the reported latency;
+3. reconnects first when ``self._auto_reconnect`` finds the cluster lost
+   (see ``_reconnect``; sync methods only, health checks excluded);
+4. re-authenticates after ``SessionExpired`` and retries the methods in
+   ``RETRIED_AFTER_RELOGIN`` once (see ``_session``).
+
+With every hook disabled a call only pays for the attribute checks and a ``try``.
+"""
+
+from __future__ import annotations
+
+import asyncio
+import functools
+import inspect
+import logging
+import time
+
+from aerospike_py._aerospike import SessionExpired
+from aerospike_py._reconnect import HEALTH_CHECKS
+from aerospike_py._session import RETRIED_AFTER_RELOGIN
+from aerospike_py._strict_params import params_validator
+
+__all__ = ["dispatch_hooks"]
+
+logger = logging.getLogger("aerospike_py")
+
+
+def _log_expired(qualname: str, retry: bool) -> None:
+    if retry:
+        logger.info("%s: login session expired, re-authenticating and retrying once", qualname)
+    else:
+        logger.info("%s: login session expired, re-authenticating; not retried", qualname)
+
+
+def _async_dispatch(name: str, qualname: str, func):
+    validate = params_validator(name, func)
+    retry = name in RETRIED_AFTER_RELOGIN
+
+    async def call(self, args: tuple, kwargs: dict):
+        try:
+            return await func(self, *args, **kwargs)
+        except SessionExpired:
+            _log_expired(qualname, retry)
+            await self._relogin()
+            if not retry:
+                raise
+        return await func(self, *args, **kwargs)
+
+    @functools.wraps(func)
+    async def wrapper(self, *args, **kwargs):
+        if validate is not None and getattr(self, "_strict_params", False):
+            validate(self, args, kwargs)
+        slow_log = getattr(self, "_slow_log", None)
+        if slow_log is None:
+            return await call(self, args, kwargs)
+        start = time.perf_counter()
+        try:
+            return await call(self, args, kwargs)
+        finally:
+            slow_log.observe(name, args, kwargs, time.perf_counter() - start)
+
+    return wrapper
+
+
+def _sync_dispatch(name: str, qualname: str, func):
+    validate = params_validator(name, func)
+    retry = name in RETRIED_AFTER_RELOGIN
+    reconnects = name not in HEALTH_CHECKS
+
+    def call(self, args: tuple, kwargs: dict):
+        if reconnects:
+            auto_reconnect = getattr(self, "_auto_reconnect", None)
+            if auto_reconnect is not None:
+                auto_reconnect.ensure_connected(self)
+        try:
+            return func(self, *args, **kwargs)
+        except SessionExpired:
+            _log_expired(qualname, retry)
+            self._relogin()
+            if not retry:
+                raise
+        return func(self, *args, **kwargs)
+
+    @functools.wraps(func)
+    def wrapper(self, *args, **kwargs):
+        if validate is not None and getattr(self, "_strict_params", False):
+            validate(self, args, kwargs)
+        slow_log = getattr(self, "_slow_log", None)
+        if slow_log is None:
+            return call(self, args, kwargs)
+        start = time.perf_counter()
+        try:
+            return call(self, args, kwargs)
+        finally:
+            slow_log.observe(name, args, kwargs, time.perf_counter() - start)
+
+    return wrapper
+
+
+def dispatch_hooks(cls):
+    """Class decorator: wrap each ``@catch_unexpected`` method once with the per-call hooks."""
+    for name, attr in list(vars(cls).items()):
+        if inspect.isfunction(attr) and hasattr(attr, "__wrapped__"):
+            dispatch = _async_dispatch if asyncio.iscoroutinefunction(attr) else _sync_dispatch
+            setattr(cls, name, dispatch(name, f"{cls.__name__}.{name}", attr))
+    return cls
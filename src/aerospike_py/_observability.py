@@ -2,24 +2,31 @@
 
 from __future__ import annotations
 
+import atexit
 import logging
 import threading
 from contextlib import contextmanager
 from http.server import BaseHTTPRequestHandler, HTTPServer
 from typing import Iterator
 
+from aerospike_py._aerospike import configure_metrics as _configure_metrics
 from aerospike_py._aerospike import dropped_log_count as _dropped_log_count
+from aerospike_py._aerospike import flush_logs as _flush_logs
+from aerospike_py._aerospike import get_metrics_samples as _get_metrics_samples
 from aerospike_py._aerospike import get_metrics_text as _get_metrics_text
 from aerospike_py._aerospike import init_tracing as _init_tracing
 from aerospike_py._aerospike import (
     is_internal_stage_metrics_enabled as _is_internal_stage_metrics_enabled,
 )
 from aerospike_py._aerospike import is_metrics_enabled as _is_metrics_enabled
+from aerospike_py._aerospike import reset_metrics as _reset_metrics
 from aerospike_py._aerospike import (
     set_internal_stage_metrics_enabled as _set_internal_stage_metrics_enabled,
 )
+from aerospike_py._aerospike import set_log_target_level as _set_log_target_level
 from aerospike_py._aerospike import set_metrics_enabled as _set_metrics_enabled
 from aerospike_py._aerospike import shutdown_tracing as _shutdown_tracing
+from aerospike_py.types import MetricSample
 
 logger = logging.getLogger("aerospike_py")
 
@@ -39,7 +46,9 @@ def set_log_level(level: int) -> None:
     """Set the aerospike_py log level.
 
     Accepts ``LOG_LEVEL_*`` constants. Controls both Rust-internal
-    and Python-side logging.
+    and Python-side logging: ``LOG_LEVEL_*`` values also set the default
+    Rust-side filter, so records below it are never formatted or queued.
+    Use :func:`set_log_target_level` to open up individual targets.
 
     Args:
         level: One of ``LOG_LEVEL_OFF`` (-1), ``LOG_LEVEL_ERROR`` (0),
@@ -54,23 +63,82 @@ def set_log_level(level: int) -> None:
         ```
     """
     py_level = _LEVEL_MAP.get(level, level)
+    if level in _LEVEL_MAP:
+        _set_log_target_level("", level)
     logging.getLogger("aerospike_py").setLevel(py_level)
     logging.getLogger("_aerospike").setLevel(py_level)
     logging.getLogger("aerospike_core").setLevel(py_level)
     logging.getLogger("aerospike").setLevel(py_level)
 
 
+def set_log_target_level(target: str, level: int) -> None:
+    """Set the log level of one Rust log target and its sub-targets.
+
+    Applies both the Rust-side filter (records below ``level`` are skipped
+    before reaching Python) and the level of the matching Python logger.
+    Rust targets map to dotted logger names: ``aerospike_core::cluster`` is
+    the ``aerospike_core.cluster`` logger. The most specific target wins.
+
+    Args:
+        target: Rust module path or logger name, e.g. ``"aerospike_core.cluster"``.
+            ``""`` sets only the default Rust-side filter.
+        level: A ``LOG_LEVEL_*`` constant.
+
+    Raises:
+        ParamError: ``level`` is not a ``LOG_LEVEL_*`` constant.
+    """
+    _set_log_target_level(target, level)
+    if target:
+        logging.getLogger(target.replace("::", ".")).setLevel(_LEVEL_MAP[level])
+
+
+def flush_logs(timeout: float = 1.0) -> bool:
+    """Wait until queued Rust log records have been handed to Python ``logging``.
+
+    Rust records are forwarded by a background thread; call this before
+    inspecting captured logs or at shutdown. Registered with ``atexit``.
+
+    Args:
+        timeout: Maximum seconds to wait.
+
+    Returns:
+        ``False`` if the timeout elapsed first.
+    """
+    return _flush_logs(timeout)
+
+
+atexit.register(flush_logs)
+
+
 def get_metrics() -> str:
     """Return collected metrics in Prometheus text format."""
     return _get_metrics_text()
 
 
+def get_metrics_dict() -> list[MetricSample]:
+    """Return collected metrics as structured samples.
+
+    Each sample is a ``{"name", "labels", "value"}`` dict, one per line of
+    the Prometheus text output: histograms expand into ``<name>_bucket``
+    (with an ``le`` label), ``<name>_sum`` and ``<name>_count`` samples.
+    """
+    return _get_metrics_samples()
+
+
+def reset_metrics() -> None:
+    """Clear recorded operation latency histograms.
+
+    Cluster gauges are re-sampled on every read and are not affected.
+    """
+    _reset_metrics()
+
+
 def dropped_log_count() -> int:
-    """Return the number of log messages dropped because the GIL was unavailable.
+    """Return the number of log messages dropped by the Rust logging bridge.
 
-    When the Rust logging bridge cannot acquire the Python GIL (e.g. during
-    interpreter shutdown), log messages are counted as dropped. WARN and ERROR
-    level messages are still emitted to stderr as a fallback.
+    Messages are dropped when the forwarding queue is full or the Python GIL
+    is unavailable (e.g. during interpreter shutdown). WARN and ERROR level
+    messages are still emitted to stderr as a fallback.
 
     Returns:
         Count of dropped messages since process start.
@@ -78,6 +146,35 @@ def dropped_log_count() -> int:
     return _dropped_log_count()
 
 
+def configure_metrics(buckets: list[float] | None = None, prefix: str | None = None) -> None:
+    """Set latency histogram buckets and/or a metric name prefix.
+
+    Must be called before the first operation or ``get_metrics()`` call,
+    because the metrics registry is built on first use. Later calls raise
+    ``ClientError``.
+
+    Args:
+        buckets: Upper bounds in seconds for ``db_client_operation_duration_seconds``.
+            Must be positive and strictly increasing. Defaults to
+            ``0.001, 0.005, 0.01, 0.05, 0.1, 0.5, 1.0, 5.0, 10.0``.
+        prefix: Prepended to every metric name with an underscore, e.g.
+            ``"myapp_aerospike"`` yields ``myapp_aerospike_db_client_operation_duration_seconds``.
+
+    Raises:
+        ParamError: Invalid buckets or prefix.
+        ClientError: Metrics were already recorded or scraped.
+
+    Example:
+        ```python
+        aerospike_py.configure_metrics(
+            buckets=[0.0001, 0.00025, 0.0005, 0.001, 0.005, 0.01, 0.1, 1.0],
+            prefix="myapp_aerospike",
+        )
+        ```
+    """
+    _configure_metrics(buckets, prefix)
+
+
 def set_metrics_enabled(enabled: bool) -> None:
     """Enable or disable Prometheus metrics collection.
 
@@ -261,22 +358,39 @@ def stop_metrics_server() -> None:
                 _metrics_server_thread = None
 
 
-def init_tracing() -> None:
-    """Initialize OpenTelemetry tracing.
+def init_tracing(
+    sample_ratio: float | None = None,
+    service_name: str | None = None,
+    span_name_format: str | None = None,
+) -> None:
+    """Initialize OpenTelemetry tracing and OTLP metrics export.
 
     Reads standard OTEL_* environment variables for configuration.
     Key variables:
         OTEL_EXPORTER_OTLP_ENDPOINT  - gRPC endpoint (default: http://localhost:4317)
         OTEL_SERVICE_NAME            - service name (default: aerospike-py)
-        OTEL_SDK_DISABLED=true       - disable tracing entirely
+        OTEL_SDK_DISABLED=true       - disable tracing and metrics export entirely
         OTEL_TRACES_EXPORTER=none    - disable trace export
+        OTEL_METRICS_EXPORTER=none   - disable db.client.operation.duration export
+
+    Args:
+        sample_ratio: Fraction of root spans to keep (0.0-1.0). Child spans
+            follow the parent's sampling decision.
+        service_name: Resource ``service.name``; overrides OTEL_SERVICE_NAME.
+        span_name_format: Span name template using ``{operation}``,
+            ``{namespace}`` and ``{set}``. Defaults to
+            ``"{operation} {namespace}.{set}"``.
+
+    Raises:
+        ParamError: ``sample_ratio`` is outside 0.0-1.0 or
+            ``span_name_format`` has an unknown or unbalanced placeholder.
     """
-    _init_tracing()
+    _init_tracing(sample_ratio, service_name, span_name_format)
 
 
 def shutdown_tracing() -> None:
-    """Shut down the tracer provider, flushing pending spans.
+    """Shut down the tracer and meter providers, flushing pending data.
 
-    Call before process exit to ensure all spans are exported.
+    Call before process exit to ensure all spans and metrics are exported.
     """
     _shutdown_tracing()
@@ -0,0 +1,60 @@
+"""Transparent reconnection after the cluster connection is lost.
+
+When the client config sets ``auto_reconnect=True``, a ``Client`` whose
+cluster dropped out from under it (every node removed by the tend loop, so
+``is_connected()`` turns ``False``) closes and reconnects with the stored
+config and the credentials of the last ``connect()`` before running its next
+operation, instead of failing it (``_dispatch`` runs the check before every
+method except ``HEALTH_CHECKS``). An explicit ``close()`` disables this until
+the client is connected again.
+"""
+
+from __future__ import annotations
+
+import logging
+import threading
+
+from aerospike_py._aerospike import ParamError
+
+__all__ = ["HEALTH_CHECKS", "AutoReconnect"]
+
+logger = logging.getLogger("aerospike_py")
+
+# Health checks report the real state instead of repairing it.
+HEALTH_CHECKS = frozenset({"is_connected", "ping"})
+
+
+class AutoReconnect:
+    """Credentials of the last ``connect()`` and the lock serializing reconnects."""
+
+    def __init__(self):
+        # ``(username, password)`` while connected; ``None`` after ``close()``.
+        self.credentials: tuple[str | None, str | None] | None = None
+        self._lock = threading.Lock()
+
+    @classmethod
+    def from_config(cls, config: dict) -> AutoReconnect | None:
+        """Build from ``auto_reconnect``; ``None`` when disabled."""
+        enabled = config.get("auto_reconnect", False)
+        if not isinstance(enabled, bool):
+            raise ParamError(f"auto_reconnect must be a bool, got {enabled!r}")
+        return cls() if enabled else None
+
+    def ensure_connected(self, client) -> None:
+        """Reconnect ``client`` if it was connected and has lost its cluster."""
+        if self._lock.locked():
+            # Another thread is reconnecting; wait for it instead of racing.
+            with self._lock:
+                pass
+        if self.credentials is None or client.is_connected():
+            return
+        with self._lock:
+            credentials = self.credentials
+            if credentials is None or client.is_connected():
+                return
+            logger.warning("Cluster connection lost, reconnecting")
+            try:
+                client._reconnect(*credentials)
+            except Exception as e:
+                # The operation runs anyway and raises; the next one retries.
+                logger.warning("Reconnect failed: %s", e)
@@ -0,0 +1,61 @@
+"""Transparent re-login after ``SessionExpired``.
+
+The native client logs every pooled connection in with the configured
+credentials when the connection is opened. When the server rejects a command
+because that login session expired, ``_relogin()`` closes the idle pooled
+connections so the retried command runs on a freshly authenticated one.
+``_dispatch`` re-authenticates after ``SessionExpired`` in every client
+method, then retries the methods listed here once; any other method raises
+the ``SessionExpired`` and the caller decides whether to retry.
+"""
+
+from __future__ import annotations
+
+__all__ = ["RETRIED_AFTER_RELOGIN"]
+
+# Methods run again after a re-login. A single-key command talks to one node,
+# which rejects an expired session before executing anything; the others here
+# only read. Batch and bulk writes are left out: their per-node connections
+# expire at different times, so some nodes may already have applied their
+# part, and running them again would repeat non-idempotent operations.
+RETRIED_AFTER_RELOGIN = frozenset(
+    {
+        # single-key commands
+        "get",
+        "get_raw",
+        "select",
+        "exists",
+        "put",
+        "put_raw",
+        "remove",
+        "touch",
+        "append",
+        "prepend",
+        "increment",
+        "remove_bin",
+        "operate",
+        "operate_ordered",
+        "apply",
+        # read-only calls
+        "batch_read",
+        "batch_read_chunked",
+        "batch_read_df",
+        "batch_exists",
+        "info_all",
+        "info_random_node",
+        "info_single_node",
+        "info_namespaces",
+        "info_sets",
+        "info_sindex",
+        "index_status",
+        "job_info",
+        "scan_info",
+        "udf_list",
+        "udf_get",
+        "admin_query_user_info",
+        "admin_query_users_info",
+        "admin_whoami",
+        "admin_query_role",
+        "admin_query_roles",
+    }
+)
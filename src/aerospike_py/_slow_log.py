@@ -0,0 +1,96 @@
+"""Report client operations that exceed ``slow_log_ms``.
+
+When the client config sets ``slow_log_ms``, ``_dispatch`` times every
+``@catch_unexpected`` method. An operation slower than the threshold is
+passed to ``slow_log_callback`` as a ``SlowOperation`` dict, or logged as a
+warning on the ``aerospike_py`` logger with the same dict in
+``extra["slow_operation"]``.
+"""
+
+from __future__ import annotations
+
+import logging
+
+from aerospike_py._aerospike import ParamError, calc_digest
+
+__all__ = ["SlowLog"]
+
+logger = logging.getLogger("aerospike_py")
+
+
+def _is_key(value) -> bool:
+    return isinstance(value, tuple) and len(value) >= 3 and isinstance(value[0], str) and isinstance(value[1], str)
+
+
+def _key_digest(key: tuple) -> bytes | None:
+    if len(key) > 3 and isinstance(key[3], bytes):
+        return key[3]
+    try:
+        return calc_digest(key[0], key[1], key[2])
+    except Exception:
+        return None
+
+
+def _describe_target(args: tuple, kwargs: dict) -> tuple[str | None, str | None, bytes | None]:
+    """Return ``(namespace, set, digest)`` for the key or namespace a call targets."""
+    first = args[0] if args else kwargs.get("key", kwargs.get("keys"))
+    if _is_key(first):
+        return first[0], first[1], _key_digest(first)
+    if isinstance(first, list) and first and _is_key(first[0]):
+        return first[0][0], first[0][1], None
+    if isinstance(first, str):
+        second = args[1] if len(args) > 1 else None
+        return first, second if isinstance(second, str) else None, None
+    return None, None, None
+
+
+class SlowLog:
+    """Threshold and sink for slow operation reports."""
+
+    def __init__(self, threshold_ms: float, callback=None):
+        self.threshold_ms = threshold_ms
+        self.callback = callback
+
+    @classmethod
+    def from_config(cls, config: dict) -> SlowLog | None:
+        """Build from ``slow_log_ms`` / ``slow_log_callback``; ``None`` when disabled."""
+        threshold = config.get("slow_log_ms")
+        callback = config.get("slow_log_callback")
+        if threshold is None:
+            if callback is not None:
+                raise ParamError("slow_log_callback requires slow_log_ms")
+            return None
+        if isinstance(threshold, bool) or not isinstance(threshold, (int, float)) or threshold < 0:
+            raise ParamError(f"slow_log_ms must be a non-negative number, got {threshold!r}")
+        if callback is not None and not callable(callback):
+            raise ParamError("slow_log_callback must be callable")
+        return cls(float(threshold), callback)
+
+    def observe(self, operation: str, args: tuple, kwargs: dict, elapsed: float) -> None:
+        latency_ms = elapsed * 1000.0
+        if latency_ms < self.threshold_ms:
+            return
+        namespace, set_name, digest = _describe_target(args, kwargs)
+        info = {
+            "operation": operation,
+            "namespace": namespace,
+            "set": set_name,
+            "digest": digest,
+            "latency_ms": latency_ms,
+            "threshold_ms": self.threshold_ms,
+        }
+        if self.callback is None:
+            logger.warning(
+                "Slow operation %s on %s/%s took %.1f ms (threshold %.0f ms)",
+                operation,
+                namespace,
+                set_name,
+                latency_ms,
+                self.threshold_ms,
+                extra={"slow_operation": info},
+            )
+            return
+        try:
+            self.callback(info)
+        except Exception:
+            logger.exception("slow_log_callback raised for %s", operation)
@@ -0,0 +1,146 @@
+"""Reject unknown keys in policy and operation dicts.
+
+When the client config sets ``strict_params=True``, the ``policy`` dict and
+the operation dicts passed to a client method are checked against the keys
+that method accepts. An unknown key (a typo like ``"return_tyep"``, which
+otherwise falls back to the default silently) raises ``ParamError``
+listing the accepted keys.
+"""
+
+from __future__ import annotations
+
+import inspect
+
+from aerospike_py._aerospike import ParamError
+from aerospike_py._types import HLLPolicy, ListPolicy, MapPolicy
+from aerospike_py.types import AdminPolicy, BatchPolicy, OperatePolicy, ReadPolicy, WritePolicy
+
+__all__ = ["params_validator", "strict_params_from_config"]
+
+
+def _keys(typed_dict: type) -> frozenset[str]:
+    return frozenset(typed_dict.__required_keys__ | typed_dict.__optional_keys__)
+
+
+_READ = ("get", "get_raw", "select", "exists")
+_WRITE = ("put", "put_raw", "remove", "touch", "append", "prepend", "increment", "remove_bin", "apply")
+_OPERATE = ("operate", "operate_ordered")
+_BATCH = (
+    "batch_read",
+    "batch_read_chunked",
+    "batch_read_df",
+    "batch_exists",
+    "batch_write",
+    "batch_write_numpy",
+    "bulk_put",
+    "batch_operate",
+    "batch_remove",
+    "batch_apply",
+)
+
+_POLICY_KEYS: dict[str, frozenset[str]] = {
+    **dict.fromkeys(_READ, _keys(ReadPolicy)),
+    **dict.fromkeys(_WRITE, _keys(WritePolicy)),
+    **dict.fromkeys(_OPERATE, _keys(OperatePolicy)),
+    **dict.fromkeys(_BATCH, _keys(BatchPolicy)),
+}
+
+_ADMIN_PREFIXES = ("admin_", "index_", "info_", "udf_")
+
+# Every key ``operate()`` reads from an operation dict, across all op codes.
+_OP_KEYS = frozenset(
+    {
+        "op",
+        "bin",
+        "val",
+        "val_end",
+        "index",
+        "rank",
+        "count",
+        "return_type",
+        "map_key",
+        "list_policy",
+        "map_policy",
+        "hll_policy",
+        "bit_policy",
+        "bit_offset",
+        "bit_size",
+        "byte_offset",
+        "byte_size",
+        "shift",
+        "signed",
+        "action",
+        "resize_flags",
+        "index_bit_count",
+        "minhash_bit_count",
+        "ttl",
+        "durable_delete",
+    }
+)
+
+_NESTED_POLICY_KEYS = {
+    "list_policy": _keys(ListPolicy),
+    "map_policy": _keys(MapPolicy),
+    "hll_policy": _keys(HLLPolicy),
+}
+
+
+def strict_params_from_config(config: dict) -> bool:
+    """Read ``strict_params`` from the client config (default ``False``)."""
+    enabled = config.get("strict_params", False)
+    if not isinstance(enabled, bool):
+        raise ParamError(f"strict_params must be a bool, got {enabled!r}")
+    return enabled
+
+
+def _policy_keys(method: str) -> frozenset[str] | None:
+    if method in _POLICY_KEYS:
+        return _POLICY_KEYS[method]
+    if method in ("truncate", "job_info", "scan_info") or method.startswith(_ADMIN_PREFIXES):
+        return _keys(AdminPolicy)
+    return None
+
+
+def _check_keys(params: dict, accepted: frozenset[str], what: str) -> None:
+    unknown = [key for key in params if key not in accepted]
+    if unknown:
+        raise ParamError(
+            f"Unknown key(s) {', '.join(map(repr, unknown))} in {what}; "
+            f"accepted keys: {', '.join(sorted(accepted))}"
+        )
+
+
+def _check_ops(ops, method: str) -> None:
+    for op in ops:
+        # Op-builder objects are converted by ``__op_dict__()`` natively.
+        if not isinstance(op, dict):
+            continue
+        _check_keys(op, _OP_KEYS, f"{method}() operation")
+        for name, accepted in _NESTED_POLICY_KEYS.items():
+            nested = op.get(name)
+            if isinstance(nested, dict):
+                _check_keys(nested, accepted, f"{method}() {name}")
+
+
+def params_validator(method: str, func):
+    """Return ``validate(self, args, kwargs)`` for ``method``, or ``None`` if it takes no checked dicts.
+
+    ``_dispatch`` calls it before the other hooks when ``self._strict_params``
+    is set, so a rejected call is not timed, retried or reconnected.
+    """
+    signature = inspect.signature(func)
+    policy_keys = _policy_keys(method) if "policy" in signature.parameters else None
+    takes_ops = "ops" in signature.parameters
+    if policy_keys is None and not takes_ops:
+        return None
+
+    def validate(self, args: tuple, kwargs: dict) -> None:
+        bound = signature.bind_partial(self, *args, **kwargs).arguments
+        policy = bound.get("policy")
+        if policy_keys is not None and isinstance(policy, dict):
+            _check_keys(policy, policy_keys, f"{method}() policy")
+        ops = bound.get("ops")
+        if takes_ops and isinstance(ops, (list, tuple)):
+            _check_ops(ops, method)
+
+    return validate
@@ -23,6 +23,10 @@ Optional keys (CDT operations):
     ``bit_offset`` (int): Starting bit position for bitwise CDT operations.
     ``bit_size`` (int): Number of bits for bitwise CDT operations.
 
+Optional keys (record operations):
+    ``ttl`` (int): On ``OPERATOR_TOUCH``, the record TTL for the whole command.
+    ``durable_delete`` (bool): On ``OPERATOR_DELETE``, leave a tombstone.
+
 Use ``aerospike_py.list_operations``, ``aerospike_py.map_operations``,
 ``aerospike_py.hll_operations``, or ``aerospike_py.bit_operations`` helper modules
 to build CDT operation dicts.
@@ -34,7 +38,10 @@ class ListPolicy(TypedDict, total=False):
 
     Keys:
         order: List ordering (LIST_UNORDERED or LIST_ORDERED).
-        flags: List write flags (LIST_WRITE_DEFAULT, LIST_WRITE_ADD_UNIQUE, etc.).
+        flags: List write flags, ``LIST_WRITE_DEFAULT`` or a bitwise OR of
+            ``LIST_WRITE_ADD_UNIQUE``, ``LIST_WRITE_INSERT_BOUNDED``,
+            ``LIST_WRITE_NO_FAIL`` and ``LIST_WRITE_PARTIAL``. Unknown bits
+            raise ``ParamError``.
     """
 
     order: int
@@ -47,10 +54,15 @@ class MapPolicy(TypedDict, total=False):
     Keys:
         order: Map ordering (MAP_UNORDERED, MAP_KEY_ORDERED, MAP_KEY_VALUE_ORDERED).
         write_mode: Map write mode (MAP_UPDATE, MAP_UPDATE_ONLY, MAP_CREATE_ONLY).
+        flags: Map write flags (server 4.3+), ``MAP_WRITE_FLAGS_DEFAULT`` or a
+            bitwise OR of ``MAP_WRITE_FLAGS_CREATE_ONLY``,
+            ``MAP_WRITE_FLAGS_UPDATE_ONLY``, ``MAP_WRITE_FLAGS_NO_FAIL`` and
+            ``MAP_WRITE_FLAGS_PARTIAL``. Cannot be combined with ``write_mode``.
     """
 
     order: int
     write_mode: int
+    flags: int
 
 
 class HLLPolicy(TypedDict, total=False):
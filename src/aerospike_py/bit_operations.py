@@ -6,6 +6,7 @@ and ``client.operate_ordered()``.
 
 from typing import Optional, Union
 
+from aerospike_py import _aerospike as _native
 from aerospike_py._types import _UNSET, Operation, _build_op
 
 __all__ = [
@@ -30,25 +31,25 @@ __all__ = [
     "bit_get_int",
 ]
 
-# Bitwise operation codes (must match rust/src/constants.rs CDT codes)
-_OP_BIT_RESIZE = 4001
-_OP_BIT_INSERT = 4002
-_OP_BIT_REMOVE = 4003
-_OP_BIT_SET = 4004
-_OP_BIT_OR = 4005
-_OP_BIT_XOR = 4006
-_OP_BIT_AND = 4007
-_OP_BIT_NOT = 4008
-_OP_BIT_LSHIFT = 4009
-_OP_BIT_RSHIFT = 4010
-_OP_BIT_ADD = 4011
-_OP_BIT_SUBTRACT = 4012
-_OP_BIT_SET_INT = 4013
-_OP_BIT_GET = 4050
-_OP_BIT_COUNT = 4051
-_OP_BIT_LSCAN = 4052
-_OP_BIT_RSCAN = 4053
-_OP_BIT_GET_INT = 4054
+# Bitwise operation codes, registered on the native module by rust/src/constants.rs
+_OP_BIT_RESIZE = _native.OP_BIT_RESIZE
+_OP_BIT_INSERT = _native.OP_BIT_INSERT
+_OP_BIT_REMOVE = _native.OP_BIT_REMOVE
+_OP_BIT_SET = _native.OP_BIT_SET
+_OP_BIT_OR = _native.OP_BIT_OR
+_OP_BIT_XOR = _native.OP_BIT_XOR
+_OP_BIT_AND = _native.OP_BIT_AND
+_OP_BIT_NOT = _native.OP_BIT_NOT
+_OP_BIT_LSHIFT = _native.OP_BIT_LSHIFT
+_OP_BIT_RSHIFT = _native.OP_BIT_RSHIFT
+_OP_BIT_ADD = _native.OP_BIT_ADD
+_OP_BIT_SUBTRACT = _native.OP_BIT_SUBTRACT
+_OP_BIT_SET_INT = _native.OP_BIT_SET_INT
+_OP_BIT_GET = _native.OP_BIT_GET
+_OP_BIT_COUNT = _native.OP_BIT_COUNT
+_OP_BIT_LSCAN = _native.OP_BIT_LSCAN
+_OP_BIT_RSCAN = _native.OP_BIT_RSCAN
+_OP_BIT_GET_INT = _native.OP_BIT_GET_INT
 
 
 def bit_resize(
@@ -0,0 +1,37 @@
+"""pandas DataFrame construction from native column buffers."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Any
+
+if TYPE_CHECKING:
+    import pandas as pd
+
+__all__: list[str] = []
+
+
+def _columns_to_df(raw: tuple[Any, dict[str, tuple[Any, Any]]]) -> pd.DataFrame:
+    """Wrap ``(index, {name: (values, mask)})`` from the native layer into a DataFrame.
+
+    ``values`` is a NumPy array for int / float / bool bins and a list
+    otherwise. ``mask`` is a bool array (``True`` = null) for int / bool
+    columns that contain nulls; those become pandas nullable ``Int64`` /
+    ``boolean`` arrays so missing values are not coerced to ``0`` / ``False``.
+    """
+    try:
+        import pandas as pd
+    except ImportError:
+        raise ImportError("pandas is required for DataFrame output (pip install 'aerospike-py[pandas]')") from None
+
+    index, columns = raw
+    data: dict[str, Any] = {}
+    for name, (values, mask) in columns.items():
+        if mask is None:
+            data[name] = values
+        elif values.dtype.kind == "b":
+            data[name] = pd.arrays.BooleanArray(values, mask)
+        else:
+            data[name] = pd.arrays.IntegerArray(values, mask)
+    if index is None:
+        return pd.DataFrame(data)
+    return pd.DataFrame(data, index=pd.Index(index, name="key"))
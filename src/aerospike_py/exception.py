@@ -12,12 +12,16 @@ from aerospike_py._aerospike import (
     AerospikeError,
     ClientError,
     ClusterError,
+    CircuitOpenError,
+    ParamError,
     InvalidArgError,
     RecordError,
     ServerError,
     AerospikeTimeoutError,
+    RetryableError,
     BackpressureError,
     RustPanicError,
+    ForkError,
     # Record-level exceptions
     RecordNotFound,
     RecordExistsError,
@@ -28,6 +32,16 @@ from aerospike_py._aerospike import (
     BinNotFound,
     BinTypeError,
     FilteredOut,
+    RecordBusy,
+    ElementNotFoundError,
+    ElementExistsError,
+    # Server-level exceptions
+    AlwaysForbidden,
+    UnsupportedFeature,
+    DeviceOverload,
+    ForbiddenError,
+    OpNotApplicable,
+    LostConflict,
     # Index exceptions
     AerospikeIndexError,
     IndexNotFound,
@@ -37,6 +51,8 @@ from aerospike_py._aerospike import (
     QueryAbortedError,
     # Admin / UDF exceptions
     AdminError,
+    SessionExpired,
+    QuotaExceeded,
     UDFError,
 )
 
@@ -69,9 +85,13 @@ __all__ = [
     "ServerError",
     "RecordError",
     "ClusterError",
+    "CircuitOpenError",
     "AerospikeTimeoutError",
+    "RetryableError",
     "BackpressureError",
     "RustPanicError",
+    "ForkError",
+    "ParamError",
     "InvalidArgError",
     "RecordNotFound",
     "RecordExistsError",
@@ -82,11 +102,22 @@ __all__ = [
     "BinNotFound",
     "BinTypeError",
     "FilteredOut",
+    "RecordBusy",
+    "ElementNotFoundError",
+    "ElementExistsError",
+    "AlwaysForbidden",
+    "UnsupportedFeature",
+    "DeviceOverload",
+    "ForbiddenError",
+    "OpNotApplicable",
+    "LostConflict",
     "AerospikeIndexError",
     "IndexNotFound",
     "IndexFoundError",
     "QueryError",
     "QueryAbortedError",
     "AdminError",
+    "SessionExpired",
+    "QuotaExceeded",
     "UDFError",
 ]
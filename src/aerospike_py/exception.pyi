@@ -6,6 +6,8 @@ Exception hierarchy::
       +-- ClientError              (connection, configuration, internal)
       |     +-- BackpressureError  (concurrent operations limit exceeded)
       |     +-- RustPanicError     (native Rust panic; process survived)
+      |     +-- ForkError          (client used in a child after os.fork())
+      |     +-- ParamError         (invalid argument; alias InvalidArgError)
       +-- ServerError              (server-side errors)
       |     +-- AerospikeIndexError
       |     |     +-- IndexNotFound
@@ -13,7 +15,15 @@ Exception hierarchy::
       |     +-- QueryError
       |     |     +-- QueryAbortedError
       |     +-- AdminError
+      |     |     +-- SessionExpired
+      |     |     +-- QuotaExceeded
       |     +-- UDFError
+      |     +-- AlwaysForbidden
+      |     +-- UnsupportedFeature
+      |     +-- DeviceOverload
+      |     +-- ForbiddenError
+      |     +-- OpNotApplicable
+      |     +-- LostConflict
       +-- RecordError              (record-level errors)
       |     +-- RecordNotFound
       |     +-- RecordExistsError
@@ -24,17 +34,46 @@ Exception hierarchy::
       |     +-- BinNotFound
       |     +-- BinTypeError
       |     +-- FilteredOut
+      |     +-- RecordBusy
+      |     +-- ElementNotFoundError
+      |     +-- ElementExistsError
       +-- ClusterError             (cluster connectivity / node errors)
+      |     +-- CircuitOpenError   (node's circuit breaker is open)
       +-- AerospikeTimeoutError    (operation timed out)
-      +-- InvalidArgError          (invalid argument)
+
+Transient errors also derive from the ``RetryableError`` mixin:
+``AerospikeTimeoutError``, ``ClusterError``, ``BackpressureError``,
+``RecordBusy``, ``DeviceOverload`` and ``ForbiddenError``.
 
 Deprecated aliases (emit ``DeprecationWarning`` on access):
     - ``TimeoutError`` -- use ``AerospikeTimeoutError`` instead.
     - ``IndexError`` -- use ``AerospikeIndexError`` instead.
 """
 
+from typing import Any
+
 class AerospikeError(Exception):
-    """Base exception for all Aerospike errors."""
+    """Base exception for all Aerospike errors.
+
+    Attributes:
+        code: Server result code, or a negative client-side code.
+        msg: Error message.
+        in_doubt: Whether a failed write may have been applied.
+        key: ``(namespace, set, key, digest)`` of a single-key command.
+        bin: Bin name, when the error identifies one.
+    """
+
+    code: int | None
+    msg: str
+    in_doubt: bool
+    key: tuple[str, str, Any, bytes] | None
+    bin: str | None
+
+class RetryableError(Exception):
+    """Mixin base for transient errors: the same call may succeed if retried.
+
+    See ``aerospike_py.is_retryable()`` for the full classification.
+    """
 
 class ClientError(AerospikeError):
     """Client-side error such as connection failure, misconfiguration, or internal error."""
@@ -45,10 +84,17 @@ class ServerError(AerospikeError):
 class RecordError(AerospikeError):
     """Record-level error (not found, already exists, generation mismatch, etc.)."""
 
-class ClusterError(AerospikeError):
+class ClusterError(AerospikeError, RetryableError):
     """Cluster connectivity or node-level error."""
 
-class AerospikeTimeoutError(AerospikeError):
+class CircuitOpenError(ClusterError):
+    """Raised without contacting the server when the target node's circuit is open.
+
+    Only raised when ``circuit_breaker`` is configured in the client config.
+    The message includes the node name and the time until a probe is allowed.
+    """
+
+class AerospikeTimeoutError(AerospikeError, RetryableError):
     """Raised when an operation exceeds its timeout threshold."""
 
 class TimeoutError(AerospikeError):
@@ -57,12 +103,13 @@ class TimeoutError(AerospikeError):
     Accessing this name emits a ``DeprecationWarning``.
     """
 
-class BackpressureError(ClientError):
+class BackpressureError(ClientError, RetryableError):
     """Raised when the maximum number of concurrent operations is exceeded.
 
     Only raised when ``max_concurrent_operations`` is configured in the client
     config and the ``operation_queue_timeout_ms`` deadline expires while waiting
-    for a free slot.
+    for a free slot, or when ``max_ops_per_second`` is configured and the next
+    rate limit token is further away than ``operation_queue_timeout_ms``.
     """
 
 class RustPanicError(ClientError):
@@ -77,8 +124,21 @@ class RustPanicError(ClientError):
     instead of failing the whole request.
     """
 
-class InvalidArgError(AerospikeError):
-    """Raised when an invalid argument is passed to a client operation."""
+class ForkError(ClientError):
+    """Raised when a client connected before ``os.fork()`` is used in the child.
+
+    A sync ``Client`` recovers by calling ``connect()`` (or ``close()``) in the
+    child. An ``AsyncClient`` cannot be used in any process forked after its
+    event loop runtime started; create it in the child instead.
+    """
+
+class ParamError(ClientError):
+    """Raised when an invalid argument is passed to a client operation.
+
+    Also exported as ``InvalidArgError``.
+    """
+
+InvalidArgError = ParamError
 
 class RecordNotFound(RecordError):
     """Raised when the requested record does not exist (result code 2)."""
@@ -107,6 +167,15 @@ class BinTypeError(RecordError):
 class FilteredOut(RecordError):
     """Raised when a record is excluded by an expression filter (result code 27)."""
 
+class RecordBusy(RecordError, RetryableError):
+    """Raised when too many concurrent requests target one record, a hot key (result code 14)."""
+
+class ElementNotFoundError(RecordError):
+    """Raised when a list / map element does not exist (result code 23)."""
+
+class ElementExistsError(RecordError):
+    """Raised when a list / map element already exists (result code 24)."""
+
 class AerospikeIndexError(ServerError):
     """Base exception for secondary index errors."""
 
@@ -131,5 +200,45 @@ class QueryAbortedError(QueryError):
 class AdminError(ServerError):
     """Raised on admin or security operation failures (authentication, roles, etc.)."""
 
+class SessionExpired(AdminError):
+    """Raised when the server rejects a command because its login session expired (result code 66).
+
+    ``Client`` and ``AsyncClient`` re-authenticate and retry the command once
+    before letting this propagate.
+    """
+
+class QuotaExceeded(AdminError):
+    """Raised when a user exceeds its read or write quota (result code 83)."""
+
 class UDFError(ServerError):
-    """Raised when a User-Defined Function (UDF) execution fails on the server."""
+    """Raised when a User-Defined Function (UDF) execution fails on the server.
+
+    Attributes:
+        module: Lua module the error was raised in, else the module passed to ``apply()``.
+        function: Function passed to ``apply()``.
+        line: Line of the Lua error, when the error string has one.
+        message: Lua error message without the ``file.lua:line:`` prefix.
+    """
+
+    module: str | None
+    function: str | None
+    line: int | None
+    message: str | None
+
+class AlwaysForbidden(ServerError):
+    """Raised when the namespace configuration never allows the operation (result code 10)."""
+
+class UnsupportedFeature(ServerError):
+    """Raised when the server does not support or enable a feature (result code 16, or 25 for enterprise-only)."""
+
+class DeviceOverload(ServerError, RetryableError):
+    """Raised when the storage device cannot keep up with writes (result code 18)."""
+
+class ForbiddenError(ServerError, RetryableError):
+    """Raised when the operation is temporarily forbidden, e.g. during migrations (result code 22)."""
+
+class OpNotApplicable(ServerError):
+    """Raised when an operation cannot be applied to the current bin value (result code 26)."""
+
+class LostConflict(ServerError):
+    """Raised when a write loses XDR conflict resolution (result code 28)."""
@@ -35,15 +35,41 @@ This is a full set scan with server-side filtering — it does not use a primary
 index (Aerospike's primary index keys on the digest, not the user key string).
 Records written without ``POLICY_KEY_SEND`` will not match. Avoid on hot paths;
 prefer a separate prefix bin with a secondary index for production lookups.
+
+Reusing an expression on hot paths::
+
+    # Convert the dict tree once instead of on every request.
+    adults = exp.compile(exp.ge(exp.int_bin("age"), exp.int_val(21)))
+    for key in keys:
+        client.get(key, policy={"filter_expression": adults})
 """
 
 from typing import Any
 
+from aerospike_py._aerospike import (
+    EXP_TYPE_BLOB,
+    EXP_TYPE_BOOL,
+    EXP_TYPE_FLOAT,
+    EXP_TYPE_GEO,
+    EXP_TYPE_HLL,
+    EXP_TYPE_INT,
+    EXP_TYPE_LIST,
+    EXP_TYPE_MAP,
+    EXP_TYPE_NIL,
+    EXP_TYPE_STRING,
+    CompiledExpression,
+)
+
 # Type alias for expression nodes returned by builder functions
 Expr = dict[str, Any]
+# Type alias for CDT context items returned by the ``ctx_*`` functions
+Ctx = dict[str, Any]
 
 __all__ = [
     "Expr",
+    "Ctx",
+    "CompiledExpression",
+    "compile",
     "EXP_TYPE_NIL",
     "EXP_TYPE_BOOL",
     "EXP_TYPE_INT",
@@ -120,6 +146,44 @@ __all__ = [
     "int_count",
     "int_lscan",
     "int_rscan",
+    "ctx_list_index",
+    "ctx_list_rank",
+    "ctx_list_value",
+    "ctx_map_index",
+    "ctx_map_rank",
+    "ctx_map_key",
+    "ctx_map_value",
+    "list_size",
+    "list_get_by_value",
+    "list_get_by_value_range",
+    "list_get_by_value_list",
+    "list_get_by_value_rel_rank_range",
+    "list_get_by_value_rel_rank_range_count",
+    "list_get_by_index",
+    "list_get_by_index_range",
+    "list_get_by_index_range_count",
+    "list_get_by_rank",
+    "list_get_by_rank_range",
+    "list_get_by_rank_range_count",
+    "map_size",
+    "map_get_by_key",
+    "map_get_by_key_range",
+    "map_get_by_key_list",
+    "map_get_by_value",
+    "map_get_by_value_range",
+    "map_get_by_value_list",
+    "bit_get",
+    "bit_count",
+    "bit_lscan",
+    "bit_rscan",
+    "bit_get_int",
+    "hll_get_count",
+    "hll_describe",
+    "hll_get_union",
+    "hll_get_union_count",
+    "hll_get_intersect_count",
+    "hll_get_similarity",
+    "hll_may_contain",
     "regex_compare",
     "geo_compare",
     "cond",
@@ -128,18 +192,6 @@ __all__ = [
     "let_",
 ]
 
-# Expression type constants
-EXP_TYPE_NIL = 0
-EXP_TYPE_BOOL = 1
-EXP_TYPE_INT = 2
-EXP_TYPE_STRING = 3
-EXP_TYPE_LIST = 4
-EXP_TYPE_MAP = 5
-EXP_TYPE_BLOB = 6
-EXP_TYPE_FLOAT = 7
-EXP_TYPE_GEO = 8
-EXP_TYPE_HLL = 9
-
 
 _VALID_OPS: frozenset[str] = frozenset({
     # Value constructors
@@ -162,6 +214,19 @@ _VALID_OPS: frozenset[str] = frozenset({
     # Integer bitwise
     "int_and", "int_or", "int_xor", "int_not", "int_lshift", "int_rshift",
     "int_arshift", "int_count", "int_lscan", "int_rscan",
+    # List CDT reads
+    "list_size", "list_get_by_value", "list_get_by_value_range",
+    "list_get_by_value_list", "list_get_by_value_rel_rank_range",
+    "list_get_by_value_rel_rank_range_count", "list_get_by_index",
+    "list_get_by_index_range", "list_get_by_index_range_count",
+    "list_get_by_rank", "list_get_by_rank_range", "list_get_by_rank_range_count",
+    # Map CDT reads
+    "map_size", "map_get_by_key", "map_get_by_key_range", "map_get_by_key_list",
+    "map_get_by_value", "map_get_by_value_range", "map_get_by_value_list",
+    # Blob bit and HLL reads
+    "bit_get", "bit_count", "bit_lscan", "bit_rscan", "bit_get_int",
+    "hll_get_count", "hll_describe", "hll_get_union", "hll_get_union_count",
+    "hll_get_intersect_count", "hll_get_similarity", "hll_may_contain",
     # Pattern matching
     "regex_compare", "geo_compare",
     # Control flow
@@ -532,6 +597,330 @@ def int_rscan(value: Expr, search: Expr) -> Expr:
     return _cmd("int_rscan", exprs=[value, search])
 
 
+# ── CDT contexts ───────────────────────────────────────────────────
+
+
+def ctx_list_index(index: int) -> Ctx:
+    """Select the nested list item at ``index``."""
+    return {"__ctx__": "list_index", "val": index}
+
+
+def ctx_list_rank(rank: int) -> Ctx:
+    """Select the nested list item at ``rank``."""
+    return {"__ctx__": "list_rank", "val": rank}
+
+
+def ctx_list_value(value: Any) -> Ctx:
+    """Select the nested list item equal to ``value``."""
+    return {"__ctx__": "list_value", "val": value}
+
+
+def ctx_map_index(index: int) -> Ctx:
+    """Select the nested map entry at ``index``."""
+    return {"__ctx__": "map_index", "val": index}
+
+
+def ctx_map_rank(rank: int) -> Ctx:
+    """Select the nested map entry at value ``rank``."""
+    return {"__ctx__": "map_rank", "val": rank}
+
+
+def ctx_map_key(key: Any) -> Ctx:
+    """Select the nested map entry with ``key``."""
+    return {"__ctx__": "map_key", "val": key}
+
+
+def ctx_map_value(value: Any) -> Ctx:
+    """Select the nested map entry whose value is ``value``."""
+    return {"__ctx__": "map_value", "val": value}
+
+
+# ── List CDT reads ─────────────────────────────────────────────────
+
+
+def list_size(bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Create expression returning the number of items in a list."""
+    return _cmd("list_size", bin=bin_expr, ctx=ctx or [])
+
+
+def list_get_by_value(return_type: int, value: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items equal to ``value``."""
+    return _cmd("list_get_by_value", return_type=return_type, value=value, bin=bin_expr, ctx=ctx or [])
+
+
+def list_get_by_value_range(
+    return_type: int,
+    value_begin: Expr | None,
+    value_end: Expr | None,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select list items in ``[value_begin, value_end)``; ``None`` leaves that end open."""
+    return _cmd(
+        "list_get_by_value_range",
+        return_type=return_type,
+        value_begin=value_begin,
+        value_end=value_end,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def list_get_by_value_list(return_type: int, values: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items equal to any of ``values`` (a ``list_val``)."""
+    return _cmd("list_get_by_value_list", return_type=return_type, values=values, bin=bin_expr, ctx=ctx or [])
+
+
+def list_get_by_value_rel_rank_range(
+    return_type: int,
+    value: Expr,
+    rank: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select list items from the rank relative to ``value`` to the end."""
+    return _cmd(
+        "list_get_by_value_rel_rank_range",
+        return_type=return_type,
+        value=value,
+        rank=rank,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def list_get_by_value_rel_rank_range_count(
+    return_type: int,
+    value: Expr,
+    rank: Expr,
+    count: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select ``count`` list items from the rank relative to ``value``."""
+    return _cmd(
+        "list_get_by_value_rel_rank_range_count",
+        return_type=return_type,
+        value=value,
+        rank=rank,
+        count=count,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def list_get_by_index(
+    return_type: int,
+    value_type: int,
+    index: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select the list item at ``index``; ``value_type`` is its ``EXP_TYPE_*``."""
+    return _cmd(
+        "list_get_by_index",
+        return_type=return_type,
+        value_type=value_type,
+        index=index,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def list_get_by_index_range(return_type: int, index: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items from ``index`` to the end."""
+    return _cmd("list_get_by_index_range", return_type=return_type, index=index, bin=bin_expr, ctx=ctx or [])
+
+
+def list_get_by_index_range_count(
+    return_type: int,
+    index: Expr,
+    count: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select ``count`` list items starting at ``index``."""
+    return _cmd(
+        "list_get_by_index_range_count",
+        return_type=return_type,
+        index=index,
+        count=count,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def list_get_by_rank(
+    return_type: int,
+    value_type: int,
+    rank: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select the list item at ``rank`` (0 = smallest); ``value_type`` is its ``EXP_TYPE_*``."""
+    return _cmd(
+        "list_get_by_rank",
+        return_type=return_type,
+        value_type=value_type,
+        rank=rank,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def list_get_by_rank_range(return_type: int, rank: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items from ``rank`` to the largest."""
+    return _cmd("list_get_by_rank_range", return_type=return_type, rank=rank, bin=bin_expr, ctx=ctx or [])
+
+
+def list_get_by_rank_range_count(
+    return_type: int,
+    rank: Expr,
+    count: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select ``count`` list items starting at ``rank``."""
+    return _cmd(
+        "list_get_by_rank_range_count",
+        return_type=return_type,
+        rank=rank,
+        count=count,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+# ── Map CDT reads ──────────────────────────────────────────────────
+
+
+def map_size(bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Create expression returning the number of entries in a map."""
+    return _cmd("map_size", bin=bin_expr, ctx=ctx or [])
+
+
+def map_get_by_key(return_type: int, value_type: int, key: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select the map entry with ``key``; ``value_type`` is the ``EXP_TYPE_*`` of the result."""
+    return _cmd("map_get_by_key", return_type=return_type, value_type=value_type, key=key, bin=bin_expr, ctx=ctx or [])
+
+
+def map_get_by_key_range(
+    return_type: int,
+    key_begin: Expr | None,
+    key_end: Expr | None,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select map entries with keys in ``[key_begin, key_end)``; ``None`` leaves that end open."""
+    return _cmd(
+        "map_get_by_key_range",
+        return_type=return_type,
+        key_begin=key_begin,
+        key_end=key_end,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def map_get_by_key_list(return_type: int, keys: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select map entries whose key is any of ``keys`` (a ``list_val``)."""
+    return _cmd("map_get_by_key_list", return_type=return_type, keys=keys, bin=bin_expr, ctx=ctx or [])
+
+
+def map_get_by_value(return_type: int, value: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select map entries whose value equals ``value``."""
+    return _cmd("map_get_by_value", return_type=return_type, value=value, bin=bin_expr, ctx=ctx or [])
+
+
+def map_get_by_value_range(
+    return_type: int,
+    value_begin: Expr | None,
+    value_end: Expr | None,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select map entries with values in ``[value_begin, value_end)``; ``None`` leaves that end open."""
+    return _cmd(
+        "map_get_by_value_range",
+        return_type=return_type,
+        value_begin=value_begin,
+        value_end=value_end,
+        bin=bin_expr,
+        ctx=ctx or [],
+    )
+
+
+def map_get_by_value_list(return_type: int, values: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select map entries whose value is any of ``values`` (a ``list_val``)."""
+    return _cmd("map_get_by_value_list", return_type=return_type, values=values, bin=bin_expr, ctx=ctx or [])
+
+
+# ── Blob bit reads ─────────────────────────────────────────────────
+
+
+def bit_get(bit_offset: Expr, bit_size: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning ``bit_size`` bits from ``bit_offset`` of a blob as a blob."""
+    return _cmd("bit_get", bit_offset=bit_offset, bit_size=bit_size, bin=bin_expr)
+
+
+def bit_count(bit_offset: Expr, bit_size: Expr, bin_expr: Expr) -> Expr:
+    """Create expression counting the set bits in ``bit_size`` bits from ``bit_offset``."""
+    return _cmd("bit_count", bit_offset=bit_offset, bit_size=bit_size, bin=bin_expr)
+
+
+def bit_lscan(bit_offset: Expr, bit_size: Expr, value: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the position of the first bit equal to ``value`` (a ``bool_val``), or -1."""
+    return _cmd("bit_lscan", bit_offset=bit_offset, bit_size=bit_size, value=value, bin=bin_expr)
+
+
+def bit_rscan(bit_offset: Expr, bit_size: Expr, value: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the position of the last bit equal to ``value`` (a ``bool_val``), or -1."""
+    return _cmd("bit_rscan", bit_offset=bit_offset, bit_size=bit_size, value=value, bin=bin_expr)
+
+
+def bit_get_int(bit_offset: Expr, bit_size: Expr, signed: bool, bin_expr: Expr) -> Expr:
+    """Create expression reading ``bit_size`` bits (at most 64) from ``bit_offset`` as an integer."""
+    return _cmd("bit_get_int", bit_offset=bit_offset, bit_size=bit_size, signed=signed, bin=bin_expr)
+
+
+# ── HLL reads ──────────────────────────────────────────────────────
+
+
+def hll_get_count(bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated number of elements in an HLL bin."""
+    return _cmd("hll_get_count", bin=bin_expr)
+
+
+def hll_describe(bin_expr: Expr) -> Expr:
+    """Create expression returning ``[index_bits, minhash_bits]`` of an HLL bin."""
+    return _cmd("hll_describe", bin=bin_expr)
+
+
+def hll_get_union(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the union of an HLL bin and ``hll_list`` (a ``list_val`` of HLLs)."""
+    return _cmd("hll_get_union", list=hll_list, bin=bin_expr)
+
+
+def hll_get_union_count(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated element count of the union with ``hll_list``."""
+    return _cmd("hll_get_union_count", list=hll_list, bin=bin_expr)
+
+
+def hll_get_intersect_count(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated element count of the intersection with ``hll_list``."""
+    return _cmd("hll_get_intersect_count", list=hll_list, bin=bin_expr)
+
+
+def hll_get_similarity(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated similarity (0.0-1.0) with ``hll_list``."""
+    return _cmd("hll_get_similarity", list=hll_list, bin=bin_expr)
+
+
+def hll_may_contain(values: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning 1 if the HLL bin may contain all of ``values`` (a ``list_val``), else 0."""
+    return _cmd("hll_may_contain", list=values, bin=bin_expr)
+
+
 # ── Pattern matching ───────────────────────────────────────────────
 
 
@@ -557,8 +946,21 @@ def geo_compare(left: Expr, right: Expr) -> Expr:
 # ── Variables and control flow ─────────────────────────────────────
 
 
-def cond(*exprs: Expr) -> Expr:
-    """Create conditional expression: cond(bool1, action1, bool2, action2, ..., default)."""
+def cond(*exprs: Expr | tuple[Expr, Expr]) -> Expr:
+    """Create conditional expression returning the action of the first true condition.
+
+    Either flat, ``cond(bool1, action1, bool2, action2, ..., default)``, or as
+    ``(condition, action)`` pairs followed by the default::
+
+        exp.cond(
+            (exp.lt(exp.int_bin("age"), exp.int_val(18)), exp.string_val("minor")),
+            (exp.lt(exp.int_bin("age"), exp.int_val(65)), exp.string_val("adult")),
+            exp.string_val("senior"),
+        )
+    """
+    if any(isinstance(e, tuple) for e in exprs):
+        *cases, default = exprs
+        return _cmd("cond", cases=cases, default=default)
     return _cmd("cond", exprs=list(exprs))
 
 
@@ -575,3 +977,20 @@ def def_(name: str, value: Expr) -> Expr:
 def let_(*exprs: Expr) -> Expr:
     """Create let binding expression: let_(def_("x", ...), def_("y", ...), scope_expr)."""
     return _cmd("let", exprs=list(exprs))
+
+
+# ── Compilation ────────────────────────────────────────────────────
+
+
+def compile(expr: Expr) -> CompiledExpression:
+    """Convert an expression tree once for reuse across many calls.
+
+    The result is accepted wherever an expression dict is (policy
+    ``filter_expression``, query ``filter_expression=``, or as a sub-expression)
+    and skips re-walking the dict tree on every request.
+
+    Raises:
+        TypeError: If ``expr`` is not an expression built with this module.
+        ValueError: If the tree is malformed.
+    """
+    return CompiledExpression(expr)
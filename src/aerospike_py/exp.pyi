@@ -20,21 +20,28 @@ Usage example::
     client.get(key, policy=policy)
 """
 
-from typing import Any
+from typing import Any, Literal
 
 Expr = dict[str, Any]
+Ctx = dict[str, Any]
+
+class CompiledExpression:
+    """An expression tree converted once; accepted wherever an expression is."""
+
+    def __init__(self, expr: Expr | CompiledExpression) -> None: ...
+    def __repr__(self) -> str: ...
 
 # Expression type constants
-EXP_TYPE_NIL: int
-EXP_TYPE_BOOL: int
-EXP_TYPE_INT: int
-EXP_TYPE_STRING: int
-EXP_TYPE_LIST: int
-EXP_TYPE_MAP: int
-EXP_TYPE_BLOB: int
-EXP_TYPE_FLOAT: int
-EXP_TYPE_GEO: int
-EXP_TYPE_HLL: int
+EXP_TYPE_NIL: Literal[0]
+EXP_TYPE_BOOL: Literal[1]
+EXP_TYPE_INT: Literal[2]
+EXP_TYPE_STRING: Literal[3]
+EXP_TYPE_LIST: Literal[4]
+EXP_TYPE_MAP: Literal[5]
+EXP_TYPE_BLOB: Literal[6]
+EXP_TYPE_FLOAT: Literal[7]
+EXP_TYPE_GEO: Literal[8]
+EXP_TYPE_HLL: Literal[9]
 
 # ── Value constructors ──────────────────────────────────────────────
 
@@ -314,6 +321,223 @@ def int_rscan(value: Expr, search: Expr) -> Expr:
     """Create integer scan from LSB expression."""
     ...
 
+# ── CDT contexts ───────────────────────────────────────────────────
+
+def ctx_list_index(index: int) -> Ctx:
+    """Select the nested list item at ``index``."""
+    ...
+
+def ctx_list_rank(rank: int) -> Ctx:
+    """Select the nested list item at ``rank``."""
+    ...
+
+def ctx_list_value(value: Any) -> Ctx:
+    """Select the nested list item equal to ``value``."""
+    ...
+
+def ctx_map_index(index: int) -> Ctx:
+    """Select the nested map entry at ``index``."""
+    ...
+
+def ctx_map_rank(rank: int) -> Ctx:
+    """Select the nested map entry at value ``rank``."""
+    ...
+
+def ctx_map_key(key: Any) -> Ctx:
+    """Select the nested map entry with ``key``."""
+    ...
+
+def ctx_map_value(value: Any) -> Ctx:
+    """Select the nested map entry whose value is ``value``."""
+    ...
+
+# ── List CDT reads ─────────────────────────────────────────────────
+
+def list_size(bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Create expression returning the number of items in a list."""
+    ...
+
+def list_get_by_value(return_type: int, value: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items equal to ``value``."""
+    ...
+
+def list_get_by_value_range(
+    return_type: int,
+    value_begin: Expr | None,
+    value_end: Expr | None,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select list items in ``[value_begin, value_end)``; ``None`` leaves that end open."""
+    ...
+
+def list_get_by_value_list(return_type: int, values: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items equal to any of ``values`` (a ``list_val``)."""
+    ...
+
+def list_get_by_value_rel_rank_range(
+    return_type: int,
+    value: Expr,
+    rank: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select list items from the rank relative to ``value`` to the end."""
+    ...
+
+def list_get_by_value_rel_rank_range_count(
+    return_type: int,
+    value: Expr,
+    rank: Expr,
+    count: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select ``count`` list items from the rank relative to ``value``."""
+    ...
+
+def list_get_by_index(
+    return_type: int,
+    value_type: int,
+    index: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select the list item at ``index``; ``value_type`` is its ``EXP_TYPE_*``."""
+    ...
+
+def list_get_by_index_range(return_type: int, index: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items from ``index`` to the end."""
+    ...
+
+def list_get_by_index_range_count(
+    return_type: int,
+    index: Expr,
+    count: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select ``count`` list items starting at ``index``."""
+    ...
+
+def list_get_by_rank(
+    return_type: int,
+    value_type: int,
+    rank: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select the list item at ``rank`` (0 = smallest); ``value_type`` is its ``EXP_TYPE_*``."""
+    ...
+
+def list_get_by_rank_range(return_type: int, rank: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select list items from ``rank`` to the largest."""
+    ...
+
+def list_get_by_rank_range_count(
+    return_type: int,
+    rank: Expr,
+    count: Expr,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select ``count`` list items starting at ``rank``."""
+    ...
+
+# ── Map CDT reads ──────────────────────────────────────────────────
+
+def map_size(bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Create expression returning the number of entries in a map."""
+    ...
+
+def map_get_by_key(return_type: int, value_type: int, key: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select the map entry with ``key``; ``value_type`` is the ``EXP_TYPE_*`` of the result."""
+    ...
+
+def map_get_by_key_range(
+    return_type: int,
+    key_begin: Expr | None,
+    key_end: Expr | None,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select map entries with keys in ``[key_begin, key_end)``; ``None`` leaves that end open."""
+    ...
+
+def map_get_by_key_list(return_type: int, keys: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select map entries whose key is any of ``keys`` (a ``list_val``)."""
+    ...
+
+def map_get_by_value(return_type: int, value: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select map entries whose value equals ``value``."""
+    ...
+
+def map_get_by_value_range(
+    return_type: int,
+    value_begin: Expr | None,
+    value_end: Expr | None,
+    bin_expr: Expr,
+    ctx: list[Ctx] | None = None,
+) -> Expr:
+    """Select map entries with values in ``[value_begin, value_end)``; ``None`` leaves that end open."""
+    ...
+
+def map_get_by_value_list(return_type: int, values: Expr, bin_expr: Expr, ctx: list[Ctx] | None = None) -> Expr:
+    """Select map entries whose value is any of ``values`` (a ``list_val``)."""
+    ...
+
+# ── Blob bit reads ─────────────────────────────────────────────────
+
+def bit_get(bit_offset: Expr, bit_size: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning ``bit_size`` bits from ``bit_offset`` of a blob as a blob."""
+    ...
+
+def bit_count(bit_offset: Expr, bit_size: Expr, bin_expr: Expr) -> Expr:
+    """Create expression counting the set bits in ``bit_size`` bits from ``bit_offset``."""
+    ...
+
+def bit_lscan(bit_offset: Expr, bit_size: Expr, value: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the position of the first bit equal to ``value`` (a ``bool_val``), or -1."""
+    ...
+
+def bit_rscan(bit_offset: Expr, bit_size: Expr, value: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the position of the last bit equal to ``value`` (a ``bool_val``), or -1."""
+    ...
+
+def bit_get_int(bit_offset: Expr, bit_size: Expr, signed: bool, bin_expr: Expr) -> Expr:
+    """Create expression reading ``bit_size`` bits (at most 64) from ``bit_offset`` as an integer."""
+    ...
+
+# ── HLL reads ──────────────────────────────────────────────────────
+
+def hll_get_count(bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated number of elements in an HLL bin."""
+    ...
+
+def hll_describe(bin_expr: Expr) -> Expr:
+    """Create expression returning ``[index_bits, minhash_bits]`` of an HLL bin."""
+    ...
+
+def hll_get_union(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the union of an HLL bin and ``hll_list`` (a ``list_val`` of HLLs)."""
+    ...
+
+def hll_get_union_count(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated element count of the union with ``hll_list``."""
+    ...
+
+def hll_get_intersect_count(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated element count of the intersection with ``hll_list``."""
+    ...
+
+def hll_get_similarity(hll_list: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning the estimated similarity (0.0-1.0) with ``hll_list``."""
+    ...
+
+def hll_may_contain(values: Expr, bin_expr: Expr) -> Expr:
+    """Create expression returning 1 if the HLL bin may contain all of ``values`` (a ``list_val``), else 0."""
+    ...
+
 # ── Pattern matching ───────────────────────────────────────────────
 
 def regex_compare(regex: str, flags: int, bin_expr: Expr) -> Expr:
@@ -326,8 +550,21 @@ def geo_compare(left: Expr, right: Expr) -> Expr:
 
 # ── Variables and control flow ─────────────────────────────────────
 
-def cond(*exprs: Expr) -> Expr:
-    """Create conditional expression: cond(bool1, action1, bool2, action2, ..., default)."""
+def cond(*exprs: Expr | tuple[Expr, Expr]) -> Expr:
+    """Create conditional expression returning the action of the first true condition.
+
+    Either flat, ``cond(bool1, action1, bool2, action2, ..., default)``, or as
+    ``(condition, action)`` pairs followed by the default::
+
+        exp.cond(
+            (exp.lt(exp.int_bin("age"), exp.int_val(18)), exp.string_val("minor")),
+            (exp.lt(exp.int_bin("age"), exp.int_val(65)), exp.string_val("adult")),
+            exp.string_val("senior"),
+        )
+
+    Raises:
+        ValueError: On conversion, if the pairs or the default are missing.
+    """
     ...
 
 def var(name: str) -> Expr:
@@ -341,3 +578,18 @@ def def_(name: str, value: Expr) -> Expr:
 def let_(*exprs: Expr) -> Expr:
     """Create let binding expression: let_(def_("x", ...), def_("y", ...), scope_expr)."""
     ...
+
+# ── Compilation ────────────────────────────────────────────────────
+
+def compile(expr: Expr | CompiledExpression) -> CompiledExpression:
+    """Convert an expression tree once for reuse across many calls.
+
+    The result is accepted wherever an expression dict is (policy
+    ``filter_expression``, query ``filter_expression=``, or as a sub-expression)
+    and skips re-walking the dict tree on every request.
+
+    Raises:
+        TypeError: If ``expr`` is not an expression built with this module.
+        ValueError: If the tree is malformed.
+    """
+    ...
@@ -6,6 +6,7 @@ and ``client.operate_ordered()``.
 
 from typing import Any, Optional
 
+from aerospike_py import _aerospike as _native
 from aerospike_py._types import _UNSET, HLLPolicy, Operation, _build_op
 
 __all__ = [
@@ -22,17 +23,17 @@ __all__ = [
     "hll_set_union",
 ]
 
-# HLL operation codes (must match rust/src/constants.rs HLL CDT codes)
-_OP_HLL_INIT = 3001
-_OP_HLL_ADD = 3002
-_OP_HLL_GET_COUNT = 3003
-_OP_HLL_GET_UNION = 3004
-_OP_HLL_GET_UNION_COUNT = 3005
-_OP_HLL_GET_INTERSECT_COUNT = 3006
-_OP_HLL_GET_SIMILARITY = 3007
-_OP_HLL_DESCRIBE = 3008
-_OP_HLL_FOLD = 3009
-_OP_HLL_SET_UNION = 3010
+# HLL operation codes, registered on the native module by rust/src/constants.rs
+_OP_HLL_INIT = _native.OP_HLL_INIT
+_OP_HLL_ADD = _native.OP_HLL_ADD
+_OP_HLL_GET_COUNT = _native.OP_HLL_GET_COUNT
+_OP_HLL_GET_UNION = _native.OP_HLL_GET_UNION
+_OP_HLL_GET_UNION_COUNT = _native.OP_HLL_GET_UNION_COUNT
+_OP_HLL_GET_INTERSECT_COUNT = _native.OP_HLL_GET_INTERSECT_COUNT
+_OP_HLL_GET_SIMILARITY = _native.OP_HLL_GET_SIMILARITY
+_OP_HLL_DESCRIBE = _native.OP_HLL_DESCRIBE
+_OP_HLL_FOLD = _native.OP_HLL_FOLD
+_OP_HLL_SET_UNION = _native.OP_HLL_SET_UNION
 
 
 def hll_init(
@@ -6,6 +6,7 @@ and ``client.operate_ordered()``.
 
 from typing import Any, Optional
 
+from aerospike_py import _aerospike as _native
 from aerospike_py._types import _UNSET, ListPolicy, Operation, _build_op
 
 __all__ = [
@@ -41,40 +42,46 @@ __all__ = [
     "list_increment",
     "list_sort",
     "list_set_order",
+    "list_create",
+    "list_get_by_value_rel_rank_range",
+    "list_remove_by_value_rel_rank_range",
 ]
 
-# List operation codes (must match rust/src/operations.rs CDT codes)
-_OP_LIST_APPEND = 1001
-_OP_LIST_APPEND_ITEMS = 1002
-_OP_LIST_INSERT = 1003
-_OP_LIST_INSERT_ITEMS = 1004
-_OP_LIST_POP = 1005
-_OP_LIST_POP_RANGE = 1006
-_OP_LIST_REMOVE = 1007
-_OP_LIST_REMOVE_RANGE = 1008
-_OP_LIST_SET = 1009
-_OP_LIST_TRIM = 1010
-_OP_LIST_CLEAR = 1011
-_OP_LIST_SIZE = 1012
-_OP_LIST_GET = 1013
-_OP_LIST_GET_RANGE = 1014
-_OP_LIST_GET_BY_VALUE = 1015
-_OP_LIST_GET_BY_INDEX = 1016
-_OP_LIST_GET_BY_INDEX_RANGE = 1017
-_OP_LIST_GET_BY_RANK = 1018
-_OP_LIST_GET_BY_RANK_RANGE = 1019
-_OP_LIST_GET_BY_VALUE_LIST = 1020
-_OP_LIST_GET_BY_VALUE_RANGE = 1021
-_OP_LIST_REMOVE_BY_VALUE = 1022
-_OP_LIST_REMOVE_BY_VALUE_LIST = 1023
-_OP_LIST_REMOVE_BY_VALUE_RANGE = 1024
-_OP_LIST_REMOVE_BY_INDEX = 1025
-_OP_LIST_REMOVE_BY_INDEX_RANGE = 1026
-_OP_LIST_REMOVE_BY_RANK = 1027
-_OP_LIST_REMOVE_BY_RANK_RANGE = 1028
-_OP_LIST_INCREMENT = 1029
-_OP_LIST_SORT = 1030
-_OP_LIST_SET_ORDER = 1031
+# List operation codes, registered on the native module by rust/src/constants.rs
+_OP_LIST_APPEND = _native.OP_LIST_APPEND
+_OP_LIST_APPEND_ITEMS = _native.OP_LIST_APPEND_ITEMS
+_OP_LIST_INSERT = _native.OP_LIST_INSERT
+_OP_LIST_INSERT_ITEMS = _native.OP_LIST_INSERT_ITEMS
+_OP_LIST_POP = _native.OP_LIST_POP
+_OP_LIST_POP_RANGE = _native.OP_LIST_POP_RANGE
+_OP_LIST_REMOVE = _native.OP_LIST_REMOVE
+_OP_LIST_REMOVE_RANGE = _native.OP_LIST_REMOVE_RANGE
+_OP_LIST_SET = _native.OP_LIST_SET
+_OP_LIST_TRIM = _native.OP_LIST_TRIM
+_OP_LIST_CLEAR = _native.OP_LIST_CLEAR
+_OP_LIST_SIZE = _native.OP_LIST_SIZE
+_OP_LIST_GET = _native.OP_LIST_GET
+_OP_LIST_GET_RANGE = _native.OP_LIST_GET_RANGE
+_OP_LIST_GET_BY_VALUE = _native.OP_LIST_GET_BY_VALUE
+_OP_LIST_GET_BY_INDEX = _native.OP_LIST_GET_BY_INDEX
+_OP_LIST_GET_BY_INDEX_RANGE = _native.OP_LIST_GET_BY_INDEX_RANGE
+_OP_LIST_GET_BY_RANK = _native.OP_LIST_GET_BY_RANK
+_OP_LIST_GET_BY_RANK_RANGE = _native.OP_LIST_GET_BY_RANK_RANGE
+_OP_LIST_GET_BY_VALUE_LIST = _native.OP_LIST_GET_BY_VALUE_LIST
+_OP_LIST_GET_BY_VALUE_RANGE = _native.OP_LIST_GET_BY_VALUE_RANGE
+_OP_LIST_REMOVE_BY_VALUE = _native.OP_LIST_REMOVE_BY_VALUE
+_OP_LIST_REMOVE_BY_VALUE_LIST = _native.OP_LIST_REMOVE_BY_VALUE_LIST
+_OP_LIST_REMOVE_BY_VALUE_RANGE = _native.OP_LIST_REMOVE_BY_VALUE_RANGE
+_OP_LIST_REMOVE_BY_INDEX = _native.OP_LIST_REMOVE_BY_INDEX
+_OP_LIST_REMOVE_BY_INDEX_RANGE = _native.OP_LIST_REMOVE_BY_INDEX_RANGE
+_OP_LIST_REMOVE_BY_RANK = _native.OP_LIST_REMOVE_BY_RANK
+_OP_LIST_REMOVE_BY_RANK_RANGE = _native.OP_LIST_REMOVE_BY_RANK_RANGE
+_OP_LIST_INCREMENT = _native.OP_LIST_INCREMENT
+_OP_LIST_SORT = _native.OP_LIST_SORT
+_OP_LIST_SET_ORDER = _native.OP_LIST_SET_ORDER
+_OP_LIST_CREATE = _native.OP_LIST_CREATE
+_OP_LIST_GET_BY_VALUE_REL_RANK_RANGE = _native.OP_LIST_GET_BY_VALUE_REL_RANK_RANGE
+_OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE = _native.OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE
 
 
 def list_append(bin: str, val: Any, policy: Optional[ListPolicy] = None) -> Operation:
@@ -254,3 +261,36 @@ def list_sort(bin: str, sort_flags: int = 0) -> Operation:
 def list_set_order(bin: str, list_order: int = 0) -> Operation:
     """Set the list order."""
     return _build_op(_OP_LIST_SET_ORDER, bin, val=list_order)
+
+
+def list_create(bin: str, list_order: int = 0, pad: bool = False) -> Operation:
+    """Create the list with the given order if the bin does not hold one yet."""
+    return _build_op(_OP_LIST_CREATE, bin, val=list_order, pad=pad)
+
+
+def list_get_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Get items starting at *rank* relative to *value* in value order."""
+    return _build_op(
+        _OP_LIST_GET_BY_VALUE_REL_RANK_RANGE,
+        bin,
+        val=value,
+        rank=rank,
+        return_type=return_type,
+        count=count if count is not None else _UNSET,
+    )
+
+
+def list_remove_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Remove items starting at *rank* relative to *value* in value order."""
+    return _build_op(
+        _OP_LIST_REMOVE_BY_VALUE_REL_RANK_RANGE,
+        bin,
+        val=value,
+        rank=rank,
+        return_type=return_type,
+        count=count if count is not None else _UNSET,
+    )
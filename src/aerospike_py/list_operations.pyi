@@ -7,6 +7,10 @@ Common ``return_type`` constants (import from ``aerospike_py``):
     ``LIST_RETURN_NONE``, ``LIST_RETURN_INDEX``, ``LIST_RETURN_REVERSE_INDEX``,
     ``LIST_RETURN_RANK``, ``LIST_RETURN_REVERSE_RANK``, ``LIST_RETURN_COUNT``,
     ``LIST_RETURN_VALUE``, ``LIST_RETURN_EXISTS``.
+
+OR ``LIST_RETURN_INVERTED`` into ``return_type`` to select the items outside
+the matched range / values instead, e.g.
+``LIST_RETURN_VALUE | LIST_RETURN_INVERTED``.
 """
 
 from typing import Any, Optional
@@ -233,7 +237,8 @@ def list_sort(bin: str, sort_flags: int = 0) -> Operation:
 
     Args:
         bin: Name of the list bin.
-        sort_flags: ``LIST_SORT_DEFAULT`` or ``LIST_SORT_DROP_DUPLICATES``.
+        sort_flags: ``LIST_SORT_DEFAULT``, ``LIST_SORT_DESCENDING`` or
+            ``LIST_SORT_DROP_DUPLICATES``.
     """
 
 def list_set_order(bin: str, list_order: int = 0) -> Operation:
@@ -243,3 +248,42 @@ def list_set_order(bin: str, list_order: int = 0) -> Operation:
         bin: Name of the list bin.
         list_order: ``LIST_UNORDERED`` or ``LIST_ORDERED``.
     """
+
+def list_create(bin: str, list_order: int = 0, pad: bool = False) -> Operation:
+    """Create the list bin with the given order if it does not hold a list yet. (Write operation)
+
+    Args:
+        bin: Name of the list bin.
+        list_order: ``LIST_UNORDERED`` or ``LIST_ORDERED``.
+        pad: Let the server insert nil entries when the list is created
+            beyond the bounds of its parent list.
+    """
+
+def list_get_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Get items by rank relative to *value*. (Read operation)
+
+    Rank 0 is *value* itself, or the next larger value when *value* is
+    absent; negative ranks select smaller values.
+
+    Args:
+        bin: Name of the list bin.
+        value: Pivot value.
+        rank: Starting rank relative to *value*.
+        return_type: One of ``LIST_RETURN_*`` constants.
+        count: Number of items. If ``None``, returns all from *rank* onward.
+    """
+
+def list_remove_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Remove items by rank relative to *value*. (Write operation)
+
+    Args:
+        bin: Name of the list bin.
+        value: Pivot value.
+        rank: Starting rank relative to *value*.
+        return_type: One of ``LIST_RETURN_*`` constants.
+        count: Number of items. If ``None``, removes all from *rank* onward.
+    """
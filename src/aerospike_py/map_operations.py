@@ -6,6 +6,7 @@ and ``client.operate_ordered()``.
 
 from typing import Any, Optional
 
+from aerospike_py import _aerospike as _native
 from aerospike_py._types import _UNSET, MapPolicy, Operation, _build_op
 
 __all__ = [
@@ -37,36 +38,46 @@ __all__ = [
     "map_get_by_rank_range",
     "map_get_by_key_list",
     "map_get_by_value_list",
+    "map_create",
+    "map_get_by_key_rel_index_range",
+    "map_remove_by_key_rel_index_range",
+    "map_get_by_value_rel_rank_range",
+    "map_remove_by_value_rel_rank_range",
 ]
 
-# Map operation codes (must match rust/src/operations.rs CDT codes)
-_OP_MAP_SET_ORDER = 2001
-_OP_MAP_PUT = 2002
-_OP_MAP_PUT_ITEMS = 2003
-_OP_MAP_INCREMENT = 2004
-_OP_MAP_DECREMENT = 2005
-_OP_MAP_CLEAR = 2006
-_OP_MAP_REMOVE_BY_KEY = 2007
-_OP_MAP_REMOVE_BY_KEY_LIST = 2008
-_OP_MAP_REMOVE_BY_KEY_RANGE = 2009
-_OP_MAP_REMOVE_BY_VALUE = 2010
-_OP_MAP_REMOVE_BY_VALUE_LIST = 2011
-_OP_MAP_REMOVE_BY_VALUE_RANGE = 2012
-_OP_MAP_REMOVE_BY_INDEX = 2013
-_OP_MAP_REMOVE_BY_INDEX_RANGE = 2014
-_OP_MAP_REMOVE_BY_RANK = 2015
-_OP_MAP_REMOVE_BY_RANK_RANGE = 2016
-_OP_MAP_SIZE = 2017
-_OP_MAP_GET_BY_KEY = 2018
-_OP_MAP_GET_BY_KEY_RANGE = 2019
-_OP_MAP_GET_BY_VALUE = 2020
-_OP_MAP_GET_BY_VALUE_RANGE = 2021
-_OP_MAP_GET_BY_INDEX = 2022
-_OP_MAP_GET_BY_INDEX_RANGE = 2023
-_OP_MAP_GET_BY_RANK = 2024
-_OP_MAP_GET_BY_RANK_RANGE = 2025
-_OP_MAP_GET_BY_KEY_LIST = 2026
-_OP_MAP_GET_BY_VALUE_LIST = 2027
+# Map operation codes, registered on the native module by rust/src/constants.rs
+_OP_MAP_SET_ORDER = _native.OP_MAP_SET_ORDER
+_OP_MAP_PUT = _native.OP_MAP_PUT
+_OP_MAP_PUT_ITEMS = _native.OP_MAP_PUT_ITEMS
+_OP_MAP_INCREMENT = _native.OP_MAP_INCREMENT
+_OP_MAP_DECREMENT = _native.OP_MAP_DECREMENT
+_OP_MAP_CLEAR = _native.OP_MAP_CLEAR
+_OP_MAP_REMOVE_BY_KEY = _native.OP_MAP_REMOVE_BY_KEY
+_OP_MAP_REMOVE_BY_KEY_LIST = _native.OP_MAP_REMOVE_BY_KEY_LIST
+_OP_MAP_REMOVE_BY_KEY_RANGE = _native.OP_MAP_REMOVE_BY_KEY_RANGE
+_OP_MAP_REMOVE_BY_VALUE = _native.OP_MAP_REMOVE_BY_VALUE
+_OP_MAP_REMOVE_BY_VALUE_LIST = _native.OP_MAP_REMOVE_BY_VALUE_LIST
+_OP_MAP_REMOVE_BY_VALUE_RANGE = _native.OP_MAP_REMOVE_BY_VALUE_RANGE
+_OP_MAP_REMOVE_BY_INDEX = _native.OP_MAP_REMOVE_BY_INDEX
+_OP_MAP_REMOVE_BY_INDEX_RANGE = _native.OP_MAP_REMOVE_BY_INDEX_RANGE
+_OP_MAP_REMOVE_BY_RANK = _native.OP_MAP_REMOVE_BY_RANK
+_OP_MAP_REMOVE_BY_RANK_RANGE = _native.OP_MAP_REMOVE_BY_RANK_RANGE
+_OP_MAP_SIZE = _native.OP_MAP_SIZE
+_OP_MAP_GET_BY_KEY = _native.OP_MAP_GET_BY_KEY
+_OP_MAP_GET_BY_KEY_RANGE = _native.OP_MAP_GET_BY_KEY_RANGE
+_OP_MAP_GET_BY_VALUE = _native.OP_MAP_GET_BY_VALUE
+_OP_MAP_GET_BY_VALUE_RANGE = _native.OP_MAP_GET_BY_VALUE_RANGE
+_OP_MAP_GET_BY_INDEX = _native.OP_MAP_GET_BY_INDEX
+_OP_MAP_GET_BY_INDEX_RANGE = _native.OP_MAP_GET_BY_INDEX_RANGE
+_OP_MAP_GET_BY_RANK = _native.OP_MAP_GET_BY_RANK
+_OP_MAP_GET_BY_RANK_RANGE = _native.OP_MAP_GET_BY_RANK_RANGE
+_OP_MAP_GET_BY_KEY_LIST = _native.OP_MAP_GET_BY_KEY_LIST
+_OP_MAP_GET_BY_VALUE_LIST = _native.OP_MAP_GET_BY_VALUE_LIST
+_OP_MAP_CREATE = _native.OP_MAP_CREATE
+_OP_MAP_GET_BY_KEY_REL_INDEX_RANGE = _native.OP_MAP_GET_BY_KEY_REL_INDEX_RANGE
+_OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE = _native.OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE
+_OP_MAP_GET_BY_VALUE_REL_RANK_RANGE = _native.OP_MAP_GET_BY_VALUE_REL_RANK_RANGE
+_OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE = _native.OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE
 
 
 def map_set_order(bin: str, map_order: int) -> Operation:
@@ -226,3 +237,64 @@ def map_get_by_key_list(bin: str, keys: list[Any], return_type: int) -> Operatio
 def map_get_by_value_list(bin: str, values: list[Any], return_type: int) -> Operation:
     """Get items matching any of the given values."""
     return _build_op(_OP_MAP_GET_BY_VALUE_LIST, bin, val=values, return_type=return_type)
+
+
+def map_create(bin: str, map_order: int = 0) -> Operation:
+    """Create the map with the given order if the bin does not hold one yet."""
+    return _build_op(_OP_MAP_CREATE, bin, val=map_order)
+
+
+def map_get_by_key_rel_index_range(
+    bin: str, key: Any, index: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Get items starting at *index* relative to *key* in key order."""
+    return _build_op(
+        _OP_MAP_GET_BY_KEY_REL_INDEX_RANGE,
+        bin,
+        map_key=key,
+        index=index,
+        return_type=return_type,
+        count=count if count is not None else _UNSET,
+    )
+
+
+def map_remove_by_key_rel_index_range(
+    bin: str, key: Any, index: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Remove items starting at *index* relative to *key* in key order."""
+    return _build_op(
+        _OP_MAP_REMOVE_BY_KEY_REL_INDEX_RANGE,
+        bin,
+        map_key=key,
+        index=index,
+        return_type=return_type,
+        count=count if count is not None else _UNSET,
+    )
+
+
+def map_get_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Get items starting at *rank* relative to *value* in value order."""
+    return _build_op(
+        _OP_MAP_GET_BY_VALUE_REL_RANK_RANGE,
+        bin,
+        val=value,
+        rank=rank,
+        return_type=return_type,
+        count=count if count is not None else _UNSET,
+    )
+
+
+def map_remove_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Remove items starting at *rank* relative to *value* in value order."""
+    return _build_op(
+        _OP_MAP_REMOVE_BY_VALUE_REL_RANK_RANGE,
+        bin,
+        val=value,
+        rank=rank,
+        return_type=return_type,
+        count=count if count is not None else _UNSET,
+    )
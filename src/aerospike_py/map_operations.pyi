@@ -8,6 +8,10 @@ Common ``return_type`` constants (import from ``aerospike_py``):
     ``MAP_RETURN_RANK``, ``MAP_RETURN_REVERSE_RANK``, ``MAP_RETURN_COUNT``,
     ``MAP_RETURN_KEY``, ``MAP_RETURN_VALUE``, ``MAP_RETURN_KEY_VALUE``,
     ``MAP_RETURN_EXISTS``.
+
+OR ``MAP_RETURN_INVERTED`` into ``return_type`` to select the items outside
+the matched range / values instead, e.g.
+``MAP_RETURN_VALUE | MAP_RETURN_INVERTED``.
 """
 
 from typing import Any, Optional
@@ -255,3 +259,70 @@ def map_get_by_value_list(bin: str, values: list, return_type: int) -> Operation
         values: List of values to match.
         return_type: One of ``MAP_RETURN_*`` constants.
     """
+
+def map_create(bin: str, map_order: int = 0) -> Operation:
+    """Create the map bin with the given order if it does not hold a map yet. (Write operation)
+
+    Args:
+        bin: Name of the map bin.
+        map_order: ``MAP_UNORDERED``, ``MAP_KEY_ORDERED``, or
+            ``MAP_KEY_VALUE_ORDERED``.
+    """
+
+def map_get_by_key_rel_index_range(
+    bin: str, key: Any, index: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Get entries by key-order index relative to *key*. (Read operation)
+
+    Index 0 is *key* itself, or the next larger key when *key* is absent;
+    negative indexes select smaller keys.
+
+    Args:
+        bin: Name of the map bin.
+        key: Pivot key.
+        index: Starting index relative to *key*.
+        return_type: One of ``MAP_RETURN_*`` constants.
+        count: Number of entries. If ``None``, returns all from *index* onward.
+    """
+
+def map_remove_by_key_rel_index_range(
+    bin: str, key: Any, index: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Remove entries by key-order index relative to *key*. (Write operation)
+
+    Args:
+        bin: Name of the map bin.
+        key: Pivot key.
+        index: Starting index relative to *key*.
+        return_type: One of ``MAP_RETURN_*`` constants.
+        count: Number of entries. If ``None``, removes all from *index* onward.
+    """
+
+def map_get_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Get entries by rank relative to *value*. (Read operation)
+
+    Rank 0 is the first entry with *value*, or the next larger value when
+    *value* is absent; negative ranks select smaller values.
+
+    Args:
+        bin: Name of the map bin.
+        value: Pivot value.
+        rank: Starting rank relative to *value*.
+        return_type: One of ``MAP_RETURN_*`` constants.
+        count: Number of entries. If ``None``, returns all from *rank* onward.
+    """
+
+def map_remove_by_value_rel_rank_range(
+    bin: str, value: Any, rank: int, return_type: int, count: Optional[int] = None
+) -> Operation:
+    """Remove entries by rank relative to *value*. (Write operation)
+
+    Args:
+        bin: Name of the map bin.
+        value: Pivot value.
+        rank: Starting rank relative to *value*.
+        return_type: One of ``MAP_RETURN_*`` constants.
+        count: Number of entries. If ``None``, removes all from *rank* onward.
+    """
@@ -5,6 +5,8 @@ from __future__ import annotations
 import warnings
 from typing import TYPE_CHECKING, Iterator, Union
 
+from aerospike_py._aerospike import ParamError
+
 if TYPE_CHECKING:
     import numpy as np
 
@@ -21,9 +23,12 @@ class NumpyBatchRecords:
         batch_records: Structured numpy array containing bin data.
         meta: Structured numpy array with ``(gen, ttl)`` per record.
         result_codes: int32 array of Aerospike result codes (0 = success).
+        null_mask: Structured bool array with one column per data field,
+            ``True`` where the bin was missing or nil; ``None`` unless
+            requested with ``_null_mask=True``.
     """
 
-    __slots__ = ("_map", "batch_records", "meta", "result_codes")
+    __slots__ = ("_map", "batch_records", "meta", "null_mask", "result_codes")
 
     def __init__(
         self,
@@ -31,11 +36,13 @@ class NumpyBatchRecords:
         meta: np.ndarray,
         result_codes: np.ndarray,
         _map: dict[Union[str, int, bytes], int],
+        null_mask: np.ndarray | None = None,
     ):
         self.batch_records = batch_records
         self.meta = meta
         self.result_codes = result_codes
         self._map = _map
+        self.null_mask = null_mask
 
     def get(self, key: Union[str, int, bytes]) -> "np.void":
         """Retrieve a single record by primary key.
@@ -72,11 +79,11 @@ class NumpyBatchRecords:
         return f"NumpyBatchRecords(count={len(self)}, ok={ok_count}, fields={fields})"
 
 
-# int, uint, float, bytes, void
-_ALLOWED_KINDS = {"i", "u", "f", "S", "V"}
+# int, uint, float, bytes, void, bool, unicode, datetime64
+_ALLOWED_KINDS = {"i", "u", "f", "S", "V", "b", "U", "M"}
 
 
-def _batch_records_to_numpy(batch_records_obj, dtype, keys, *, strict=False):
+def _batch_records_to_numpy(batch_records_obj, dtype, keys, *, strict=False, null_mask=False):
     """Convert BatchRecords to NumpyBatchRecords.
 
     Args:
@@ -85,17 +92,18 @@ def _batch_records_to_numpy(batch_records_obj, dtype, keys, *, strict=False):
         keys: List of keys.
         strict: If True, raises warnings when dtype-defined bins are missing from records,
                 or when record bins are not in dtype.
+        null_mask: If True, also build a per-field bool mask marking missing/nil bins.
     """
     import numpy as np
 
-    # Validate dtype: only numeric (int/float) or fixed-length bytes allowed
+    # Validate dtype: only numeric (int/float/bool) or fixed-length bytes/unicode allowed
     for name in dtype.names:
         field_dtype = dtype[name]
         base = field_dtype.base  # Check base dtype for sub-array types
         if base.kind not in _ALLOWED_KINDS:
-            raise TypeError(
-                f"dtype field '{name}' must be numeric (int/float) or "
-                f"fixed-length bytes, got {field_dtype} (kind='{base.kind}')"
+            raise ParamError(
+                f"dtype field '{name}' must be numeric (int/float/bool) or "
+                f"fixed-length bytes/unicode, got {field_dtype} (kind='{base.kind}')"
             )
 
     dtype_fields = set(dtype.names)
@@ -103,6 +111,7 @@ def _batch_records_to_numpy(batch_records_obj, dtype, keys, *, strict=False):
     data = np.zeros(n, dtype=dtype)
     meta = np.zeros(n, dtype=[("gen", "u4"), ("ttl", "u4")])
     result_codes = np.zeros(n, dtype=np.int32)
+    mask = np.ones(n, dtype=[(name, "?") for name in dtype.names]) if null_mask else None
     key_map: dict[Union[str, int, bytes], int] = {}
 
     for i, br in enumerate(batch_records_obj.batch_records):
@@ -151,6 +160,8 @@ def _batch_records_to_numpy(batch_records_obj, dtype, keys, *, strict=False):
                 for field in dtype.names:
                     val = bins.get(field)
                     if val is not None:
+                        if mask is not None:
+                            mask[i][field] = False
                         try:
                             data[i][field] = val
                         except (ValueError, TypeError, OverflowError) as exc:
@@ -160,4 +171,4 @@ def _batch_records_to_numpy(batch_records_obj, dtype, keys, *, strict=False):
                                 f"(dtype={dtype[field]}) for record at index {i} (key={pk!r}): {exc}"
                             ) from exc
 
-    return NumpyBatchRecords(data, meta, result_codes, key_map)
+    return NumpyBatchRecords(data, meta, result_codes, key_map, mask)
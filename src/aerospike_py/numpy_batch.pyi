@@ -34,6 +34,9 @@ class NumpyBatchRecords:
             named ``"gen"`` and ``"ttl"``.
         result_codes: ``int32`` array of Aerospike result codes
             (``0`` = success; non-zero = per-record error).
+        null_mask: Structured ``bool`` array with one column per data
+            field; ``True`` where the bin was missing, nil, or the record
+            failed. ``None`` unless requested with ``_null_mask=True``.
     """
 
     # NOTE: numpy 2.x typing — ``ndarray[Any, np.dtype[np.void]]`` denotes a
@@ -42,6 +45,7 @@ class NumpyBatchRecords:
     batch_records: np.ndarray[Any, np.dtype[np.void]]
     meta: np.ndarray[Any, np.dtype[np.void]]
     result_codes: np.ndarray[Any, np.dtype[np.int32]]
+    null_mask: np.ndarray[Any, np.dtype[np.void]] | None
 
     def __init__(
         self,
@@ -49,6 +53,7 @@ class NumpyBatchRecords:
         meta: np.ndarray[Any, np.dtype[np.void]],
         result_codes: np.ndarray[Any, np.dtype[np.int32]],
         _map: dict[_UserKey, int],
+        null_mask: np.ndarray[Any, np.dtype[np.void]] | None = None,
     ) -> None: ...
     def get(self, key: _UserKey) -> np.void:
         """Retrieve a single record by primary key.
@@ -80,18 +85,20 @@ def _batch_records_to_numpy(
     keys: list[_UserKey],
     *,
     strict: bool = False,
+    null_mask: bool = False,
 ) -> NumpyBatchRecords:
     """Convert BatchRecords to NumpyBatchRecords.
 
     Args:
         batch_records_obj: BatchRecords object from batch_read.
         dtype: numpy structured array dtype defining bin field layout.
-            Each field must be numeric (``int``/``uint``/``float``) or a
-            fixed-length bytes (``S``) / void (``V``) field; non-numeric
-            object dtypes raise ``TypeError``.
+            Each field must be numeric (``int``/``uint``/``float``/``bool``),
+            ``datetime64``, or a fixed-length bytes (``S``) / void (``V``) /
+            unicode (``U``) field; object dtypes raise ``TypeError``.
         keys: List of primary keys corresponding to batch records.
         strict: If True, warns when dtype-defined bins are missing from
             records, or when record bins are not in dtype.
+        null_mask: If True, also populate ``NumpyBatchRecords.null_mask``.
 
     Returns:
         NumpyBatchRecords wrapping the converted numpy arrays.
@@ -7,6 +7,9 @@ Usage:
     query = client.query("test", "demo")
     query.where(p.equals("age", 30))
     query.where(p.between("age", 18, 65))
+
+The server serves one secondary index filter per query: the first predicate an
+index can serve uses it, and the others are applied as a filter expression.
 """
 
 import warnings
@@ -15,6 +18,7 @@ from typing import Any
 __all__ = [
     "equals",
     "between",
+    "in_list",
     "contains",
     "geo_within_geojson_region",
     "geo_within_radius",
@@ -36,10 +40,21 @@ def equals(bin_name: str, val: Any) -> tuple[str, str, Any]:
 
 
 def between(bin_name: str, min_val: Any, max_val: Any) -> tuple[str, str, Any, Any]:
-    """Create a range predicate for a secondary index query."""
+    """Create an inclusive range predicate on integers or strings.
+
+    String ranges have no secondary index and run as a filter expression.
+    """
     return ("between", bin_name, min_val, max_val)
 
 
+def in_list(bin_name: str, values: list[Any]) -> tuple[str, str, list[Any]]:
+    """Create a predicate matching records whose bin equals any of ``values``.
+
+    Runs as a filter expression (no secondary index serves it).
+    """
+    return ("in_list", bin_name, list(values))
+
+
 def contains(bin_name: str, index_type: int, val: Any) -> tuple[str, str, int, Any]:
     """Create a contains predicate for collection index queries.
 
@@ -7,6 +7,11 @@ Pass the returned tuple to ``query.where()``::
 
     query = client.query("test", "demo")
     query.where(p.equals("age", 30))
+
+``where()`` may be called more than once; all predicates must match. The
+server serves one secondary index filter per query: the first predicate an
+index can serve uses it, and the others are applied as a filter expression
+(combined with any ``filter_expression`` in the policy).
 """
 
 from typing import Any
@@ -24,11 +29,23 @@ def equals(bin_name: str, val: Any) -> tuple[str, str, Any]:
 def between(bin_name: str, min_val: Any, max_val: Any) -> tuple[str, str, Any, Any]:
     """Filter records where *bin_name* is in the range [min_val, max_val] (inclusive).
 
-    Requires a numeric secondary index on the bin.
+    Integer ranges use a numeric secondary index on the bin. String ranges
+    have no index and run as a filter expression.
 
     Example::
 
         query.where(predicates.between("age", 18, 65))
+        query.where(predicates.between("name", "a", "m"))
+    """
+
+def in_list(bin_name: str, values: list[Any]) -> tuple[str, str, list[Any]]:
+    """Filter records where *bin_name* equals any of *values*.
+
+    Runs as a filter expression; no secondary index serves it.
+
+    Example::
+
+        query.where(predicates.in_list("status", ["active", "trial"]))
     """
 
 def contains(bin_name: str, index_type: int, val: Any) -> tuple[str, str, int, Any]:
@@ -1,6 +1,6 @@
 """Typed data structures for aerospike-py API inputs and outputs."""
 
-from typing import Any, NamedTuple, TypeAlias, TypedDict
+from typing import Any, Callable, NamedTuple, TypeAlias, TypedDict
 
 # ---------------------------------------------------------------------------
 # NamedTuple types (return values - runtime wrapping)
@@ -16,13 +16,27 @@ class AerospikeKey(NamedTuple):
     digest: bytes
 
 
-class RecordMetadata(NamedTuple):
-    """Record metadata."""
-
+class _RecordMetadataFields(NamedTuple):
     gen: int
     ttl: int
 
 
+class RecordMetadata(_RecordMetadataFields):
+    """Record metadata: unpacks as ``(gen, ttl)``.
+
+    ``void_time`` is the absolute expiry in Unix seconds (``0`` = never
+    expires). It is an attribute rather than a tuple field, so existing
+    ``gen, ttl = meta`` unpacking keeps working.
+    """
+
+    void_time: int = 0
+
+    def __new__(cls, gen: int, ttl: int, void_time: int = 0):
+        self = super().__new__(cls, gen, ttl)
+        self.void_time = void_time
+        return self
+
+
 Bins = dict[str, Any]
 
 
@@ -72,12 +86,47 @@ class BatchRecord(NamedTuple):
     record: Record | None
     in_doubt: bool = False
 
+    def as_tuple(self) -> Record:
+        """The classic ``(key, meta, bins)`` tuple; ``meta`` and ``bins`` are ``None`` without a record."""
+        if self.record is not None:
+            return self.record
+        return Record(key=self.key, meta=None, bins=None)
+
+    def raise_for_status(self) -> None:
+        """Raise the exception mapped from ``result`` (e.g. ``RecordNotFound``); no-op when it is ``0``."""
+        from aerospike_py._aerospike import _raise_for_result
+
+        _raise_for_result(self.result, self.in_doubt, self.key)
+
 
 class BatchWriteResult(NamedTuple):
     """Container for batch write/operate/remove results."""
 
     batch_records: list[BatchRecord]
 
+    @property
+    def ok_count(self) -> int:
+        """Number of records with result code ``0``."""
+        return sum(1 for br in self.batch_records if br.result == 0)
+
+    @property
+    def error_count(self) -> int:
+        """Number of records with a non-zero result code."""
+        return sum(1 for br in self.batch_records if br.result != 0)
+
+    def get(self, key: tuple, default: BatchRecord | None = None) -> BatchRecord | None:
+        """The record for ``key`` (``(namespace, set, key)``), matched by digest."""
+        from aerospike_py._aerospike import calc_digest
+
+        if len(key) > 3 and key[3] is not None:
+            digest = key[3]
+        else:
+            digest = calc_digest(key[0], key[1], key[2])
+        for br in self.batch_records:
+            if br.key is not None and br.key.namespace == key[0] and br.key.digest == digest:
+                return br
+        return default
+
 
 # ---------------------------------------------------------------------------
 # batch_read return type aliases
@@ -108,6 +157,11 @@ class ReadPolicy(TypedDict, total=False):
     replica: int
     read_mode_ap: int
     read_touch_ttl_percent: int
+    # Client-side retry with exponential backoff. Setting any of these makes
+    # ``max_retries`` count client-side retries (core retries are disabled).
+    backoff_base_ms: int
+    backoff_cap_ms: int
+    retry_on: list[int]
 
 
 class WritePolicy(TypedDict, total=False):
@@ -124,6 +178,20 @@ class WritePolicy(TypedDict, total=False):
     filter_expression: Any
     read_mode_ap: int
     read_touch_ttl_percent: int
+    # ``put()`` only: ``put(key, None)`` deletes every bin (and so the record)
+    # instead of raising ``ParamError``.
+    clear_on_none: bool
+
+
+class OperatePolicy(WritePolicy, total=False):
+    """``WritePolicy`` plus the read-side fields ``operate()`` accepts."""
+
+    sleep_between_retries: int
+    respond_all_ops: bool
+    # Only ``POLICY_REPLICA_MASTER`` / ``POLICY_READ_MODE_SC_SESSION`` are
+    # supported; other values raise ``ParamError``.
+    replica: int
+    read_mode_sc: int
 
 
 class BatchPolicy(TypedDict, total=False):
@@ -142,6 +210,10 @@ class BatchPolicy(TypedDict, total=False):
     # ``BATCH_CONCURRENCY_SEQUENTIAL`` (0) or ``BATCH_CONCURRENCY_PARALLEL``
     # (1) constants. Default: parallel.
     concurrency: int
+    # Client-side: ``False`` raises the first per-record error (other than
+    # not found / filtered out) instead of returning partial results.
+    # Default: ``True``.
+    allow_partial_results: bool
     # Batch-level write defaults — used by ``batch_write``. Per-record
     # ``WriteMeta`` entries override these fields (matching the existing
     # ``ttl``/``gen`` precedence rule).
@@ -151,6 +223,11 @@ class BatchPolicy(TypedDict, total=False):
     commit_level: int
     durable_delete: bool
     ttl: int
+    # Client-side retry with exponential backoff. Setting any of these makes
+    # ``max_retries`` count client-side retries (core retries are disabled).
+    backoff_base_ms: int
+    backoff_cap_ms: int
+    retry_on: list[int]
 
 
 class BatchReadPolicy(TypedDict, total=False):
@@ -246,6 +323,11 @@ class QueryPolicy(TypedDict, total=False):
     include_bin_data: bool
     # PartitionFilter handle returned by partition_filter_all / _by_id / _by_range
     partition_filter: Any
+    # Client-side retry with exponential backoff. Setting any of these makes
+    # ``max_retries`` count client-side retries (core retries are disabled).
+    backoff_base_ms: int
+    backoff_cap_ms: int
+    retry_on: list[int]
 
 
 class ScanPolicy(TypedDict, total=False):
@@ -272,6 +354,11 @@ class ScanPolicy(TypedDict, total=False):
     ttl: int
     # PartitionFilter handle returned by partition_filter_all / _by_id / _by_range
     partition_filter: Any
+    # Client-side retry with exponential backoff. Setting any of these makes
+    # ``max_retries`` count client-side retries (core retries are disabled).
+    backoff_base_ms: int
+    backoff_cap_ms: int
+    retry_on: list[int]
 
 
 class WriteMeta(TypedDict, total=False):
@@ -283,8 +370,40 @@ class WriteMeta(TypedDict, total=False):
     durable_delete: bool
 
 
+class RustRuntimeConfig(TypedDict, total=False):
+    dedicated: bool
+    worker_threads: int
+
+
+class CircuitBreakerConfig(TypedDict, total=False):
+    failure_threshold: int
+    window_ms: int
+    reset_timeout_ms: int
+
+
+class BinCompressionConfig(TypedDict, total=False):
+    codec: str
+    min_size: int
+    level: int
+
+
+class SlowOperation(TypedDict):
+    operation: str
+    namespace: str | None
+    set: str | None
+    digest: bytes | None
+    latency_ms: float
+    threshold_ms: float
+
+
 class ClientConfig(TypedDict, total=False):
     hosts: list[tuple[str, int]]
+    # DNS SRV name seed hosts are looked up from, instead of or besides ``hosts``
+    hosts_srv: str
+    # Re-resolve ``hosts_srv`` at this interval (default 60, ``0`` disables)
+    hosts_srv_refresh_seconds: int
+    # ``"any"`` (default), ``"ipv4"``, ``"ipv6"``, ``"prefer_ipv4"`` or ``"prefer_ipv6"``
+    address_family: str
     cluster_name: str
     auth_mode: int
     user: str
@@ -297,7 +416,26 @@ class ClientConfig(TypedDict, total=False):
     tend_interval: int
     use_services_alternate: bool
     max_concurrent_operations: int
+    # Alias of ``max_concurrent_operations``
+    max_concurrent_requests: int
+    max_ops_per_second: float
     operation_queue_timeout_ms: int
+    refresh_seconds: float
+    rust_runtime: RustRuntimeConfig
+    circuit_breaker: CircuitBreakerConfig
+    bin_compression: BinCompressionConfig
+    # Sync ``Client`` only: reconnect before the next operation after the cluster is lost
+    auto_reconnect: bool
+    # Report operations slower than this many milliseconds
+    slow_log_ms: float
+    # Called with a ``SlowOperation`` dict; defaults to a logged warning
+    slow_log_callback: Callable[[SlowOperation], None]
+    # Reject unknown keys in policy and operation dicts with ``ParamError``
+    strict_params: bool
+    # Sync ``Client`` only: allow commands from a thread running an asyncio loop
+    allow_blocking_in_event_loop: bool
+    # ``AsyncClient`` only: ``"asyncio"`` (default, includes uvloop) or ``"anyio"`` (also trio)
+    compat: str
 
 
 class Privilege(TypedDict, total=False):
@@ -318,3 +456,70 @@ class RoleInfo(TypedDict):
     allowlist: list[str]
     read_quota: int
     write_quota: int
+
+
+class ClusterEvent(TypedDict, total=False):
+    event: str
+    node: str
+    host: str
+    partition_generation: int
+
+
+class NodeInfo(TypedDict):
+    name: str
+    address: str
+    port: int
+    active: bool
+    partition_generation: int
+    rack_id: int | None
+
+
+class KeyNode(TypedDict):
+    name: str
+    address: str
+    port: int
+    partition_id: int
+
+
+class BulkPutResult(TypedDict):
+    written: int
+    failed: int
+    errors: list[tuple[int, int, str]]
+
+
+class NodeStats(TypedDict):
+    address: str
+    active: bool
+    failures: int
+    partition_generation: int
+    rebalance_generation: int
+    partitions: dict[str, int]
+    max_conns: int
+    circuit_state: str
+    circuit_trips: int
+
+
+class JobInfo(TypedDict):
+    status: int
+    progress_pct: int
+    records_read: int
+
+
+class RuntimeMetrics(TypedDict):
+    workers: int
+    alive_tasks: int
+    queued_tasks: int
+
+
+class RuntimeStats(TypedDict):
+    sync_runtime: RuntimeMetrics | None
+    async_runtime: RuntimeMetrics | None
+    blocked_threads: int
+    block_on_calls: int
+
+
+class MetricSample(TypedDict):
+    name: str
+    labels: dict[str, str]
+    value: float
+
@@ -9,9 +9,13 @@ from tests.helpers import invoke
 
 @pytest.fixture(scope="module")
 def client():
-    """Create and connect a sync client for the test module."""
+    """Create and connect a sync client for the test module.
+
+    ``any_client`` tests call it from async test functions, so it opts out of
+    the event loop guard.
+    """
     try:
-        c = aerospike_py.client(AEROSPIKE_CONFIG).connect()
+        c = aerospike_py.client({**AEROSPIKE_CONFIG, "allow_blocking_in_event_loop": True}).connect()
     except Exception:
         pytest.skip("Aerospike server not available")
     yield c
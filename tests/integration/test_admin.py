@@ -1,6 +1,9 @@
 """Integration tests for Admin operations (requires Aerospike server with security enabled)."""
 
+import pytest
+
 import aerospike_py
+from tests import AEROSPIKE_CONFIG
 from tests.helpers import skip_if_no_security
 
 
@@ -51,6 +54,25 @@ class TestAdminUser:
         finally:
             client.admin_drop_user("test_user_pw")
 
+    @skip_if_no_security
+    def test_whoami(self, client):
+        """admin_whoami() returns the user the client authenticated as."""
+        me = client.admin_whoami()
+        assert me["user"]
+        assert isinstance(me["roles"], list)
+        assert me["conns_in_use"] >= 1
+        assert me["roles"] == client.admin_query_user_info(me["user"])["roles"]
+
+    def test_whoami_without_credentials_raises(self, client):
+        """A client connected without a user has no session user to report."""
+        config = {k: v for k, v in AEROSPIKE_CONFIG.items() if k not in ("user", "password")}
+        c = aerospike_py.client(config).connect()
+        try:
+            with pytest.raises(aerospike_py.AdminError, match="without security credentials"):
+                c.admin_whoami()
+        finally:
+            c.close()
+
 
 class TestAdminRole:
     @skip_if_no_security
@@ -0,0 +1,77 @@
+"""Integration tests for Arrow output of batch_read / query (requires Aerospike server)."""
+
+import pytest
+
+import aerospike_py
+
+pa = pytest.importorskip("pyarrow")
+
+NS = "test"
+SET = "arrow_out"
+
+
+class TestBatchReadArrow:
+    def test_columns_and_values(self, client, cleanup):
+        keys = [(NS, SET, f"arrow_{i}") for i in range(4)]
+        for i, key in enumerate(keys):
+            cleanup.append(key)
+            client.put(key, {"temp": 20.0 + i, "site": f"s{i}", "count": i})
+
+        batch = client.batch_read(keys, bins=["temp", "site", "count"], output="arrow")
+
+        assert isinstance(batch, pa.RecordBatch)
+        assert batch.num_rows == 4
+        assert batch.schema.names == ["_digest", "_result_code", "_gen", "_ttl", "temp", "site", "count"]
+        assert batch.column("temp").to_pylist() == [20.0, 21.0, 22.0, 23.0]
+        assert batch.column("site").to_pylist() == ["s0", "s1", "s2", "s3"]
+        assert batch.column("count").type == pa.int64()
+        assert batch.column("_result_code").to_pylist() == [0, 0, 0, 0]
+
+    def test_missing_record_is_null(self, client, cleanup):
+        key = (NS, SET, "arrow_present")
+        cleanup.append(key)
+        client.put(key, {"v": 1})
+
+        batch = client.batch_read([key, (NS, SET, "arrow_absent")], output="arrow")
+
+        assert batch.column("_result_code").to_pylist()[1] != 0
+        assert batch.column("_gen").to_pylist()[1] is None
+        assert batch.column("v").to_pylist() == [1, None]
+
+    def test_dtype_conflict_rejected(self, client):
+        import numpy as np
+
+        with pytest.raises(aerospike_py.InvalidArgError):
+            client.batch_read([(NS, SET, "x")], _dtype=np.dtype([("v", "i8")]), output="arrow")
+
+    def test_unknown_output_rejected(self, client):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            client.batch_read([(NS, SET, "x")], output="parquet")
+
+
+class TestQueryArrow:
+    def test_scan_results_arrow(self, client, cleanup):
+        keys = [(NS, SET + "_scan", f"scan_{i}") for i in range(3)]
+        for i, key in enumerate(keys):
+            cleanup.append(key)
+            client.put(key, {"n": i})
+
+        query = client.query(NS, SET + "_scan")
+        query.select("n")
+        batch = query.results_arrow()
+
+        assert isinstance(batch, pa.RecordBatch)
+        assert batch.schema.names == ["_digest", "_gen", "_ttl", "n"]
+        assert sorted(batch.column("n").to_pylist()) == [0, 1, 2]
+
+
+class TestAsyncArrow:
+    async def test_async_batch_read_arrow(self, async_client, cleanup):
+        key = (NS, SET, "arrow_async")
+        cleanup.append(key)
+        await async_client.put(key, {"v": 5})
+
+        batch = await async_client.batch_read([key], output="arrow")
+
+        assert isinstance(batch, pa.RecordBatch)
+        assert batch.column("v").to_pylist() == [5]
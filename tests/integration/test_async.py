@@ -314,3 +314,14 @@ class TestAsyncBatchWriteGen:
         assert bins["val"] == 2
         assert meta2.ttl > 0
         assert meta2.ttl <= 3600
+
+
+class TestAsyncIndexTask:
+    async def test_index_create_no_wait(self, async_client):
+        task = await async_client.index_integer_create("test", "demo", "async_idx_n", "async_idx_nowait", wait=False)
+        try:
+            assert isinstance(task, aerospike_py.AsyncIndexTask)
+            await task.wait(timeout=30, poll_interval=0.1)
+            assert await async_client.index_status("test", "async_idx_nowait") == 100
+        finally:
+            await async_client.index_remove("test", "async_idx_nowait")
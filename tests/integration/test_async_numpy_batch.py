@@ -189,3 +189,28 @@ class TestAsyncEmptyBatch:
         assert len(result.batch_records) == 0
         assert len(result.meta) == 0
         assert len(result.result_codes) == 0
+
+
+# ── batch_exists ─────────────────────────────────────────────────
+
+
+class TestAsyncBatchExists:
+    async def test_bool_mask(self, async_client, cleanup):
+        """dtype=bool returns a found mask aligned with the input keys."""
+        keys = [(NS, SET, f"exists_np_{i}") for i in range(3)]
+        cleanup.append(keys[1])
+        await async_client.put(keys[1], {"val": 1})
+
+        found, meta = await async_client.batch_exists(keys, dtype=bool)
+
+        np.testing.assert_array_equal(found, [False, True, False])
+        assert meta["gen"][1] >= 1
+
+    async def test_list_result(self, async_client, cleanup):
+        key = (NS, SET, "exists_list_0")
+        cleanup.append(key)
+        await async_client.put(key, {"val": 1})
+
+        results = await async_client.batch_exists([key, (NS, SET, "exists_list_missing")])
+
+        assert [r.meta is not None for r in results] == [True, False]
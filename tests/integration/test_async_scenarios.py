@@ -52,7 +52,10 @@ class TestAsyncTruncate:
         for key in keys:
             await async_client.put(key, {"v": 1})
 
-        await async_client.truncate(ns, set_name)
+        task = await async_client.truncate(ns, set_name)
+        assert isinstance(task, aerospike_py.AsyncTruncateTask)
+        await task.wait(timeout=30, poll_interval=0.2)
+        assert await task.done()
 
 
 class TestAsyncUDF:
@@ -136,6 +139,85 @@ class TestAsyncBackpressure:
             await client.close()
 
 
+class TestAsyncCancellation:
+    """Cancelling the awaiting task aborts the native operation."""
+
+    async def test_cancelled_put_is_never_sent(self):
+        from tests import AEROSPIKE_CONFIG
+
+        client = aerospike_py.AsyncClient({**AEROSPIKE_CONFIG, "max_ops_per_second": 1})
+        await client.connect()
+        key = ("test", "demo", "cancelled_put")
+        try:
+            await client.exists(key)  # takes the only token; the put below waits ~1s
+            with pytest.raises(asyncio.TimeoutError):
+                await asyncio.wait_for(client.put(key, {"v": 1}), timeout=0.1)
+            await asyncio.sleep(1.5)
+            _, meta = await client.exists(key)
+            assert meta is None
+        finally:
+            try:
+                await client.remove(key)
+            except aerospike_py.RecordNotFound:
+                pass
+            await client.close()
+
+    async def test_cancelled_close_still_closes(self):
+        from tests import AEROSPIKE_CONFIG
+
+        client = aerospike_py.AsyncClient(AEROSPIKE_CONFIG)
+        await client.connect()
+        task = asyncio.create_task(client.close())
+        await asyncio.sleep(0)
+        task.cancel()
+        # The close finishes on a detached task; reconnecting fails only until it does.
+        for _ in range(100):
+            try:
+                await client.connect()
+                break
+            except aerospike_py.ClientError:
+                await asyncio.sleep(0.01)
+        assert client.is_connected()
+        await client.close()
+
+
+class TestAsyncCallTimeout:
+    """``timeout=`` bounds a single AsyncClient call."""
+
+    async def test_timeout_raises_and_aborts(self):
+        from tests import AEROSPIKE_CONFIG
+
+        client = aerospike_py.AsyncClient({**AEROSPIKE_CONFIG, "max_ops_per_second": 1})
+        await client.connect()
+        key = ("test", "demo", "timed_out_put")
+        try:
+            await client.exists(key)  # takes the only token; the put below waits ~1s
+            with pytest.raises(aerospike_py.AerospikeTimeoutError):
+                await client.put(key, {"v": 1}, timeout=0.1)
+            await asyncio.sleep(1.5)
+            _, meta = await client.exists(key, timeout=5)
+            assert meta is None
+        finally:
+            try:
+                await client.remove(key)
+            except aerospike_py.RecordNotFound:
+                pass
+            await client.close()
+
+    @pytest.mark.parametrize("timeout", [0, -1.0, float("nan")])
+    async def test_rejects_non_positive_timeout(self, async_client, timeout):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            await async_client.get(("test", "demo", "any"), timeout=timeout)
+
+    async def test_query_results_accepts_timeout(self, async_client):
+        records = await async_client.query("test", "async_scen_empty").results(timeout=30)
+        assert records == []
+
+    async def test_query_rejects_non_positive_timeout(self, async_client):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            await async_client.query("test", "demo").results(timeout=0)
+
+
 class TestAsyncDataTypes:
     """Async-specific data type tests (comprehensive single-record check)."""
 
@@ -173,3 +255,79 @@ class TestAsyncDataTypes:
         _, _, bins = await async_client.get(key)
         assert len(bins["str"]) == 50_000
         assert len(bins["list"]) == 1000
+
+
+class TestSyncClientInEventLoop:
+    """A sync Client refuses to block a running event loop."""
+
+    @pytest.fixture
+    def client(self):
+        from tests import AEROSPIKE_CONFIG
+
+        try:
+            c = aerospike_py.client(AEROSPIKE_CONFIG).connect()
+        except Exception:
+            pytest.skip("Aerospike server not available")
+        yield c
+        c.close()
+
+    async def test_sync_command_in_loop_raises(self, client):
+        with pytest.raises(aerospike_py.ClientError, match="AsyncClient"):
+            client.get(("test", "demo", "loop_guard"))
+
+    async def test_sync_query_in_loop_raises(self, client):
+        with pytest.raises(aerospike_py.ClientError, match="AsyncClient"):
+            client.query("test", "demo").results()
+
+    async def test_sync_command_in_worker_thread_is_allowed(self, client):
+        assert await asyncio.to_thread(client.exists, ("test", "demo", "loop_guard")) is not None
+
+    async def test_opt_out_allows_blocking(self):
+        from tests import AEROSPIKE_CONFIG
+
+        client = aerospike_py.client({**AEROSPIKE_CONFIG, "allow_blocking_in_event_loop": True}).connect()
+        try:
+            assert client.exists(("test", "demo", "loop_guard")) is not None
+        finally:
+            client.close()
+
+
+class TestEventLoopCompat:
+    """AsyncClient under uvloop and trio (``compat="anyio"``)."""
+
+    @staticmethod
+    async def _roundtrip(config, key):
+        client = await AsyncClient(config).connect()
+        try:
+            await client.put(key, {"v": 1})
+            _, _, bins = await client.get(key)
+            await client.remove(key)
+            return bins
+        finally:
+            await client.close()
+
+    def test_uvloop(self):
+        uvloop = pytest.importorskip("uvloop")
+        from tests import AEROSPIKE_CONFIG
+
+        loop = uvloop.new_event_loop()
+        try:
+            bins = loop.run_until_complete(self._roundtrip(AEROSPIKE_CONFIG, ("test", "demo", "compat_uvloop")))
+        finally:
+            loop.close()
+        assert bins == {"v": 1}
+
+    def test_trio(self):
+        trio = pytest.importorskip("trio")
+        from tests import AEROSPIKE_CONFIG
+
+        config = {**AEROSPIKE_CONFIG, "compat": "anyio"}
+        bins = trio.run(self._roundtrip, config, ("test", "demo", "compat_trio"))
+        assert bins == {"v": 1}
+
+    def test_trio_without_compat_fails(self):
+        trio = pytest.importorskip("trio")
+        from tests import AEROSPIKE_CONFIG
+
+        with pytest.raises(RuntimeError):
+            trio.run(self._roundtrip, AEROSPIKE_CONFIG, ("test", "demo", "compat_trio_off"))
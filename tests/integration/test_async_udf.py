@@ -6,6 +6,8 @@ import os
 
 import pytest
 
+import aerospike_py
+
 UDF_FILE = os.path.join(os.path.dirname(__file__), "..", "test_udf.lua")
 
 
@@ -39,6 +41,29 @@ class TestAsyncApply:
         finally:
             await async_udf_client.remove(key)
 
+    async def test_apply_lua_error_is_structured(self, async_udf_client):
+        key = ("test", "demo", "async_udf_fail")
+        await async_udf_client.put(key, {"a": 1})
+        try:
+            with pytest.raises(aerospike_py.UDFError) as exc_info:
+                await async_udf_client.apply(key, "test_udf", "fail", ["cart is empty"])
+        finally:
+            await async_udf_client.remove(key)
+        err = exc_info.value
+        assert (err.module, err.function, err.line) == ("test_udf", "fail", 15)
+        assert err.message == "cart is empty"
+
+
+class TestAsyncUDFListGet:
+    async def test_udf_list(self, async_udf_client):
+        names = [m["name"] for m in await async_udf_client.udf_list()]
+        assert "test_udf.lua" in names
+
+    async def test_udf_get(self, async_udf_client):
+        with open(UDF_FILE) as f:
+            expected = f.read()
+        assert await async_udf_client.udf_get("test_udf") == expected
+
 
 class TestAsyncBatchApply:
     async def test_batch_apply_basic(self, async_udf_client):
@@ -1,5 +1,7 @@
 """Integration tests for batch operations (requires Aerospike server)."""
 
+import pytest
+
 import aerospike_py
 
 
@@ -112,6 +114,22 @@ class TestBatchOperate:
 class TestBatchConcurrency:
     """Smoke tests for ``BatchPolicy["concurrency"]`` (issue #320)."""
 
+    def test_batch_operate_allow_partial_results(self, client, cleanup):
+        keys = [("test", "demo", "batch_partial_1"), ("test", "demo", "batch_partial_2")]
+        for k in keys:
+            cleanup.append(k)
+
+        client.put(keys[0], {"counter": 1})
+        client.put(keys[1], {"counter": "not a number"})
+        ops = [{"op": aerospike_py.OPERATOR_INCR, "bin": "counter", "val": 1}]
+
+        results = client.batch_operate(keys, ops)
+        assert [br.result for br in results.batch_records] == [0, aerospike_py.AEROSPIKE_ERR_BIN_TYPE]
+
+        with pytest.raises(aerospike_py.BinTypeError) as exc_info:
+            client.batch_operate(keys, ops, policy={"allow_partial_results": False})
+        assert exc_info.value.key[2] == "batch_partial_2"
+
     def test_batch_operate_with_sequential_concurrency(self, client, cleanup):
         keys = [
             ("test", "demo", "batch_seq_1"),
@@ -184,6 +202,61 @@ class TestBatchWrite:
             assert bins["name"] == "test"
             assert bins["score"] == 100
 
+    def test_batch_write_result_lookup(self, client, cleanup):
+        """BatchWriteResult counts results and looks records up by key."""
+        keys = [("test", "demo", "batch_write_lookup_1"), ("test", "demo", "batch_write_lookup_2")]
+        for k in keys:
+            cleanup.append(k)
+
+        ops = [{"op": aerospike_py.OPERATOR_WRITE, "bin": "n", "val": 1}]
+        results = client.batch_operate(keys, ops)
+        assert results.ok_count == 2
+        assert results.error_count == 0
+        assert results.get(keys[1]).key.user_key == "batch_write_lookup_2"
+        assert results.get(("test", "demo", "batch_write_lookup_missing")) is None
+
+        raw = aerospike_py._aerospike.Client.batch_operate(client, keys, ops)
+        assert len(raw) == 2
+        assert [br.key[2] for br in raw] == [k[2] for k in keys]
+        assert raw[-1].key[2] == "batch_write_lookup_2"
+        assert raw.get(keys[0]).result == 0
+        assert raw.ok_count == 2
+
+    def test_batch_record_raise_for_status(self, client, cleanup):
+        """BatchRecord.raise_for_status() raises the mapped exception per record."""
+        keys = [("test", "demo", "batch_status_1"), ("test", "demo", "batch_status_missing")]
+        cleanup.append(keys[0])
+        client.put(keys[0], {"n": 1})
+
+        results = client.batch_operate(keys, [{"op": aerospike_py.OPERATOR_READ, "bin": "n"}])
+        found, missing = results.batch_records
+        found.raise_for_status()
+        assert found.as_tuple()[2] == {"n": 1}
+        with pytest.raises(aerospike_py.RecordNotFound):
+            missing.raise_for_status()
+        assert missing.as_tuple()[1:] == (None, None)
+
+        raw = aerospike_py._aerospike.Client.batch_operate(
+            client, keys, [{"op": aerospike_py.OPERATOR_READ, "bin": "n"}]
+        )
+        assert raw[0].as_tuple()[2] == {"n": 1}
+        with pytest.raises(aerospike_py.RecordNotFound) as exc_info:
+            raw[1].raise_for_status()
+        assert exc_info.value.key[2] == "batch_status_missing"
+
+    def test_batch_record_tuple_keeps_request_key(self, client, cleanup):
+        """Batch record tuples carry the request key, not the digest-only server key."""
+        key = ("test", "demo", "batch_record_key")
+        cleanup.append(key)
+        client.put(key, {"n": 1})
+
+        raw = aerospike_py._aerospike.Client.batch_operate(
+            client, [key], [{"op": aerospike_py.OPERATOR_READ, "bin": "n"}]
+        )
+        record_key = raw[0].record[0]
+        assert record_key[:3] == key
+        assert record_key == raw[0].key
+
     def test_batch_write_overwrite_existing(self, client, cleanup):
         """batch_operate with OPERATOR_WRITE overwrites existing records."""
         keys = [
@@ -686,6 +759,33 @@ class TestBatchWriteSendKey:
         scanned = self._scan_user_keys(client, "test", "bw_sendkey_default")
         assert scanned == [None]
 
+    def test_batch_operate_policy_send_key(self, client, cleanup):
+        """``batch_operate(policy={"key": POLICY_KEY_SEND})`` persists user keys."""
+        keys = [
+            ("test", "bo_sendkey_pol", "bo_sendkey_pol_1"),
+            ("test", "bo_sendkey_pol", "bo_sendkey_pol_2"),
+        ]
+        for k in keys:
+            cleanup.append(k)
+
+        ops = [{"op": aerospike_py.OPERATOR_WRITE, "bin": "val", "val": 1}]
+        results = client.batch_operate(keys, ops, policy={"key": aerospike_py.POLICY_KEY_SEND})
+        for br in results.batch_records:
+            assert br.result == 0
+
+        scanned = self._scan_user_keys(client, "test", "bo_sendkey_pol")
+        assert sorted(str(v) for v in scanned) == ["bo_sendkey_pol_1", "bo_sendkey_pol_2"]
+
+    def test_put_meta_send_key(self, client, cleanup):
+        """``put(meta={"key": POLICY_KEY_SEND})`` persists the user key."""
+        key = ("test", "put_sendkey_meta", "put_sendkey_meta_user")
+        cleanup.append(key)
+
+        client.put(key, {"val": 1}, meta={"key": aerospike_py.POLICY_KEY_SEND})
+
+        scanned = self._scan_user_keys(client, "test", "put_sendkey_meta")
+        assert scanned == ["put_sendkey_meta_user"]
+
 
 class TestBatchWriteWriteFields:
     """Test batch_write() parity with put() for the remaining BatchWritePolicy fields."""
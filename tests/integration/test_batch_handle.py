@@ -111,3 +111,20 @@ class TestBatchReadConcurrency:
         for d in results:
             assert isinstance(d, dict)
             assert len(d) == 5
+
+
+class TestBatchReadChunked:
+    """Tests for batch_read_chunked() fan-out."""
+
+    async def test_merges_chunks(self, async_client, _seed_records):
+        keys = _seed_records + [(NS, SET, "missing_1")]
+        result = await async_client.batch_read_chunked(keys, chunk_size=2, concurrency=2)
+        assert result == await async_client.batch_read(keys)
+        assert len(result) == 5
+
+    async def test_single_chunk(self, async_client, _seed_records):
+        result = await async_client.batch_read_chunked(_seed_records, chunk_size=100, bins=["name"])
+        assert result["h_3"] == {"name": "user_3"}
+
+    async def test_empty_keys(self, async_client):
+        assert await async_client.batch_read_chunked([]) == {}
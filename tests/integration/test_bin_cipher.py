@@ -0,0 +1,87 @@
+"""Integration tests for ``set_bin_cipher()`` (requires Aerospike server)."""
+
+import pytest
+
+import aerospike_py
+from tests import AEROSPIKE_CONFIG
+
+NS = "test"
+SET = "bin_cipher"
+
+
+def _xor(data: bytes) -> bytes:
+    return bytes(b ^ 0x5A for b in data)
+
+
+@pytest.fixture
+def cipher_client(client):
+    client.set_bin_cipher(_xor, _xor, bins=["secret"])
+    yield client
+    client.set_bin_cipher(None, None)
+
+
+class TestBinCipher:
+    def test_round_trip(self, cipher_client, cleanup):
+        key = (NS, SET, "round_trip")
+        cleanup.append(key)
+        values = {"secret": {"card": "4111-1111", "cvv": 123}, "public": "visible"}
+        cipher_client.put(key, values)
+
+        assert cipher_client.get(key).bins == values
+        assert cipher_client.select(key, ["secret"]).bins == {"secret": values["secret"]}
+
+    def test_stored_encrypted(self, cipher_client, cleanup):
+        key = (NS, SET, "stored")
+        cleanup.append(key)
+        cipher_client.put(key, {"secret": "4111-1111"})
+        cipher_client.set_bin_cipher(None, None)
+
+        stored = cipher_client.get(key).bins["secret"]
+        assert isinstance(stored, bytes)
+        assert b"4111-1111" not in stored
+
+    def test_plaintext_read_unchanged(self, client, cleanup):
+        key = (NS, SET, "plain")
+        cleanup.append(key)
+        client.put(key, {"secret": 42})
+        client.set_bin_cipher(_xor, _xor, bins=["secret"])
+        try:
+            assert client.get(key).bins == {"secret": 42}
+        finally:
+            client.set_bin_cipher(None, None)
+
+    def test_batch_and_query(self, cipher_client, cleanup):
+        keys = [(NS, SET, f"batch_{i}") for i in range(3)]
+        cleanup.extend(keys)
+        cipher_client.batch_write([(key, {"secret": f"s{i}"}) for i, key in enumerate(keys)])
+
+        assert sorted(bins["secret"] for bins in cipher_client.batch_read(keys).values()) == ["s0", "s1", "s2"]
+        records = cipher_client.query(NS, SET).results()
+        assert records and all(isinstance(bins["secret"], str) for _, _, bins in records)
+
+    def test_hook_error_fails_write(self, client, cleanup):
+        key = (NS, SET, "failing")
+        cleanup.append(key)
+
+        def fail(data):
+            raise RuntimeError("no key")
+
+        client.set_bin_cipher(fail, fail, bins=["secret"])
+        try:
+            with pytest.raises(RuntimeError, match="no key"):
+                client.put(key, {"secret": "x"})
+        finally:
+            client.set_bin_cipher(None, None)
+        assert client.exists(key).meta is None
+
+    async def test_async_round_trip(self, cleanup):
+        key = (NS, SET, "async")
+        cleanup.append(key)
+        c = aerospike_py.AsyncClient(AEROSPIKE_CONFIG)
+        await c.connect()
+        try:
+            c.set_bin_cipher(_xor, _xor, bins=["secret"])
+            await c.put(key, {"secret": b"\x00\x01"})
+            assert (await c.get(key)).bins == {"secret": b"\x00\x01"}
+        finally:
+            await c.close()
@@ -0,0 +1,70 @@
+"""Integration tests for the ``bin_compression`` client config (requires Aerospike server)."""
+
+import pytest
+
+import aerospike_py
+from tests import AEROSPIKE_CONFIG
+
+NS = "test"
+SET = "bin_compression"
+LOG = "GET /health 200 0.4ms\n" * 500
+COMPRESSION = {"bin_compression": {"codec": "zstd", "min_size": 1024}}
+
+
+@pytest.fixture(scope="module")
+def zclient():
+    try:
+        c = aerospike_py.client({**AEROSPIKE_CONFIG, **COMPRESSION}).connect()
+    except Exception:
+        pytest.skip("Aerospike server not available")
+    yield c
+    c.close()
+
+
+class TestBinCompression:
+    def test_round_trip(self, zclient, cleanup):
+        key = (NS, SET, "round_trip")
+        cleanup.append(key)
+        blob = bytes(range(256)) * 64
+        zclient.put(key, {"log": LOG, "blob": blob, "small": "short", "n": 1})
+
+        assert zclient.get(key).bins == {"log": LOG, "blob": blob, "small": "short", "n": 1}
+        assert zclient.select(key, ["log"]).bins == {"log": LOG}
+
+    def test_stored_compressed(self, zclient, client, cleanup):
+        key = (NS, SET, "stored")
+        cleanup.append(key)
+        zclient.put(key, {"log": LOG, "small": "short"})
+
+        bins = client.get(key).bins
+        assert isinstance(bins["log"], bytes)
+        assert len(bins["log"]) < len(LOG) // 10
+        assert bins["small"] == "short"
+
+    def test_uncompressed_records_read_unchanged(self, zclient, client, cleanup):
+        key = (NS, SET, "plain")
+        cleanup.append(key)
+        client.put(key, {"log": LOG, "blob": b"\x00ASZs"})
+
+        assert zclient.get(key).bins == {"log": LOG, "blob": b"\x00ASZs"}
+
+    def test_batch_and_query(self, zclient, cleanup):
+        keys = [(NS, SET, f"batch_{i}") for i in range(3)]
+        cleanup.extend(keys)
+        zclient.batch_write([(key, {"log": LOG}) for key in keys])
+
+        assert all(bins == {"log": LOG} for bins in zclient.batch_read(keys).values())
+        records = zclient.query(NS, SET).results()
+        assert records and all(isinstance(bins["log"], str) for _, _, bins in records)
+
+    async def test_async_round_trip(self, client, cleanup):
+        key = (NS, SET, "async")
+        cleanup.append(key)
+        c = aerospike_py.AsyncClient({**AEROSPIKE_CONFIG, **COMPRESSION})
+        await c.connect()
+        try:
+            await c.put(key, {"log": LOG})
+            assert (await c.get(key)).bins == {"log": LOG}
+        finally:
+            await c.close()
+        assert isinstance(client.get(key).bins["log"], bytes)
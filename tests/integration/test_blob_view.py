@@ -0,0 +1,83 @@
+"""Integration tests for zero-copy blob views (requires Aerospike server)."""
+
+import pytest
+
+import aerospike_py
+
+NS = "test"
+SET = "blob_view"
+
+
+@pytest.fixture
+def blob_threshold():
+    prev = aerospike_py.get_blob_view_threshold()
+    aerospike_py.set_blob_view_threshold(1024)
+    yield
+    aerospike_py.set_blob_view_threshold(prev)
+
+
+class TestBlobRead:
+    def test_large_blob_is_memoryview(self, client, cleanup, blob_threshold):
+        key = (NS, SET, "large")
+        cleanup.append(key)
+        payload = bytes(range(256)) * 16
+        client.put(key, {"big": payload, "small": b"abc"})
+
+        _, _, bins = client.get(key)
+
+        assert isinstance(bins["big"], memoryview)
+        assert bins["big"].readonly
+        assert bins["big"] == payload
+        assert isinstance(bins["small"], bytes)
+
+    def test_disabled_returns_bytes(self, client, cleanup):
+        key = (NS, SET, "disabled")
+        cleanup.append(key)
+        client.put(key, {"big": b"x" * 4096})
+
+        _, _, bins = client.get(key)
+
+        assert isinstance(bins["big"], bytes)
+
+    async def test_async_get(self, async_client, cleanup, blob_threshold):
+        key = (NS, SET, "async")
+        cleanup.append(key)
+        await async_client.put(key, {"big": b"y" * 2048})
+
+        _, _, bins = await async_client.get(key)
+
+        assert isinstance(bins["big"], memoryview)
+        assert bins["big"].tobytes() == b"y" * 2048
+
+
+class TestBufferWrite:
+    @pytest.mark.parametrize(
+        "value",
+        [bytearray(b"\x00\x01\x02"), memoryview(b"\x00\x01\x02")],
+        ids=["bytearray", "memoryview"],
+    )
+    def test_buffer_written_as_blob(self, client, cleanup, value):
+        key = (NS, SET, "write_buffer")
+        cleanup.append(key)
+        client.put(key, {"b": value})
+
+        _, _, bins = client.get(key)
+
+        assert bins["b"] == b"\x00\x01\x02"
+
+    def test_view_round_trip(self, client, cleanup, blob_threshold):
+        src = (NS, SET, "rt_src")
+        dst = (NS, SET, "rt_dst")
+        cleanup.extend([src, dst])
+        client.put(src, {"b": b"z" * 2000})
+
+        _, _, bins = client.get(src)
+        client.put(dst, {"b": bins["b"]})
+
+        _, _, copied = client.get(dst)
+        assert copied["b"] == b"z" * 2000
+
+    def test_non_byte_buffer_rejected(self, client):
+        np = pytest.importorskip("numpy")
+        with pytest.raises(TypeError):
+            client.put((NS, SET, "bad"), {"b": np.arange(3, dtype="i8")})
@@ -154,12 +154,24 @@ class TestPutNoneBinDeletion:
         assert bins["a"] == 1
         assert bins["c"] == 3
 
-    async def test_put_none_bins_raises_type_error(self, any_client):
-        """put(key, None) -- passing None instead of a dict raises TypeError."""
+    async def test_put_none_bins_raises_param_error(self, any_client):
+        """put(key, None) -- passing None instead of a dict raises ParamError."""
         key = ("test", "demo", "bugfix_put_none")
-        with pytest.raises(TypeError):
+        with pytest.raises(aerospike_py.ParamError, match="clear_on_none"):
             await invoke(any_client, "put", key, None)
 
+    async def test_put_none_bins_with_clear_on_none_deletes_record(self, any_client):
+        """put(key, None) with ``clear_on_none`` deletes every bin, and so the record."""
+        key = ("test", "demo", "bugfix_put_none_clear")
+
+        await invoke(any_client, "put", key, {"a": 1, "b": 2})
+        await invoke(any_client, "put", key, None, policy={"clear_on_none": True})
+        _, meta = await invoke(any_client, "exists", key)
+        assert meta is None
+
+        # A missing record is already clear.
+        await invoke(any_client, "put", key, None, policy={"clear_on_none": True})
+
     # ── sync-only ──
 
     def test_put_none_all_bins_removes_record(self, client):
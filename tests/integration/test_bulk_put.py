@@ -0,0 +1,58 @@
+"""Integration tests for bulk_put() (requires Aerospike server)."""
+
+import aerospike_py
+from tests.helpers import invoke
+
+NS = "test"
+SET = "bulk_put"
+
+
+class TestBulkPut:
+    async def test_writes_every_record_form(self, any_client, any_cleanup):
+        rows = [{"_key": f"bp_{i}", "n": i} for i in range(25)]
+        records = iter(
+            [
+                *rows,
+                ("bp_pair", {"n": 100}),
+                ((NS, "bulk_put_other", "bp_full"), {"n": 101}),
+            ]
+        )
+        keys = [(NS, SET, f"bp_{i}") for i in range(25)] + [(NS, SET, "bp_pair"), (NS, "bulk_put_other", "bp_full")]
+        any_cleanup.extend(keys)
+
+        result = await invoke(any_client, "bulk_put", records, NS, SET, chunk_size=4, concurrency=2)
+
+        assert result == {"written": 27, "failed": 0, "errors": []}
+        record = await invoke(any_client, "get", keys[3])
+        assert record.bins == {"n": 3}
+        assert (await invoke(any_client, "get", keys[-2])).bins == {"n": 100}
+        assert (await invoke(any_client, "get", keys[-1])).bins == {"n": 101}
+
+    async def test_key_fn_keeps_all_entries_as_bins(self, any_client, any_cleanup):
+        key = (NS, SET, "bp_user")
+        any_cleanup.append(key)
+
+        rows = [{"id": "bp_user", "name": "Alice"}]
+        result = await invoke(any_client, "bulk_put", rows, NS, SET, key_fn=lambda r: r["id"])
+
+        assert result["written"] == 1
+        assert (await invoke(any_client, "get", key)).bins == {"id": "bp_user", "name": "Alice"}
+
+    async def test_failures_reported_by_index(self, any_client, any_cleanup):
+        existing = (NS, SET, "bp_exists")
+        any_cleanup.extend([existing, (NS, SET, "bp_new")])
+        await invoke(any_client, "put", existing, {"v": 1})
+
+        records = [
+            {"_key": "bp_exists", "v": 2},
+            {"v": 3},
+            {"_key": "bp_new", "v": 4},
+        ]
+        policy = {"exists": aerospike_py.POLICY_EXISTS_CREATE}
+        result = await invoke(any_client, "bulk_put", records, NS, SET, policy=policy)
+
+        assert result["written"] == 1
+        assert result["failed"] == 2
+        assert [(index, code) for index, code, _ in result["errors"]] == [(0, 5), (1, -2)]
+        assert (await invoke(any_client, "get", existing)).bins == {"v": 1}
+        assert (await invoke(any_client, "get", (NS, SET, "bp_new"))).bins == {"v": 4}
@@ -0,0 +1,33 @@
+"""Integration tests for the cluster gauges in get_metrics()."""
+
+import re
+
+import aerospike_py
+
+
+def _samples(text: str, metric: str) -> list[str]:
+    return [line for line in text.splitlines() if line.startswith(metric + "{")]
+
+
+class TestClusterGauges:
+    def test_node_count_matches_client(self, client):
+        text = aerospike_py.get_metrics()
+        samples = _samples(text, "db_client_cluster_nodes")
+        assert samples
+        counts = [int(float(s.rsplit(" ", 1)[1])) for s in samples]
+        assert max(counts) >= len(client.get_node_names())
+
+    def test_per_node_gauges_present(self, client):
+        text = aerospike_py.get_metrics()
+        for name in client.get_node_names():
+            for metric in (
+                "db_client_node_partition_generation",
+                "db_client_node_active",
+                "db_client_node_connections_max",
+            ):
+                assert any(f'db_aerospike_node_name="{name}"' in s for s in _samples(text, metric)), metric
+
+    def test_active_node_reports_one(self, client):
+        text = aerospike_py.get_metrics()
+        values = [s.rsplit(" ", 1)[1] for s in _samples(text, "db_client_node_active")]
+        assert any(re.fullmatch(r"1(\.0)?", v) for v in values)
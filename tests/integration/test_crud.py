@@ -1,5 +1,9 @@
 """Integration tests for CRUD operations (requires Aerospike server)."""
 
+import time
+
+import pytest
+
 import aerospike_py
 
 
@@ -35,6 +39,29 @@ class TestPutGet:
         assert meta.ttl > 0
         assert meta.ttl <= 300
 
+    def test_get_meta_void_time(self, client, cleanup):
+        key = ("test", "demo", "test_void_time")
+        cleanup.append(key)
+
+        client.put(key, {"val": 1}, meta={"ttl": 300})
+        before = int(time.time())
+        _, meta, _ = client.get(key)
+
+        assert before + meta.ttl - 1 <= meta.void_time <= before + meta.ttl + 1
+
+    def test_put_meta_ttl_overrides_policy_ttl(self, client, cleanup):
+        key = ("test", "demo", "test_ttl_precedence")
+        cleanup.append(key)
+
+        client.put(key, {"val": 1}, meta={"ttl": 300}, policy={"ttl": 5000})
+        _, meta, _ = client.get(key)
+        assert meta.ttl <= 300
+
+        # TTL_CLIENT_DEFAULT falls back to the policy's ttl.
+        client.put(key, {"val": 2}, meta={"ttl": aerospike_py.TTL_CLIENT_DEFAULT}, policy={"ttl": 5000})
+        _, meta, _ = client.get(key)
+        assert 300 < meta.ttl <= 5000
+
     def test_put_various_types(self, client, cleanup):
         key = ("test", "demo", "test_types")
         cleanup.append(key)
@@ -177,6 +204,47 @@ class TestOperate:
         _, _, bins = client.operate(key, ops)
         assert bins["counter"] == 15
 
+    def test_operate_write_only_returns_meta(self, client, cleanup):
+        key = ("test", "demo", "test_operate_write_meta")
+        cleanup.append(key)
+
+        client.put(key, {"counter": 1})
+        _, meta, bins = client.operate(key, [{"op": aerospike_py.OPERATOR_INCR, "bin": "counter", "val": 1}])
+        assert meta.gen == 2
+        assert bins == {}
+
+        # Chain on the returned generation, updating the TTL in the same call.
+        _, meta, _ = client.operate(
+            key,
+            [{"op": aerospike_py.OPERATOR_WRITE, "bin": "state", "val": "done"}],
+            meta={"gen": meta.gen, "ttl": 3600},
+            policy={"respond_all_ops": True},
+        )
+        assert meta.gen == 3
+        assert 0 < meta.ttl <= 3600
+
+        with pytest.raises(aerospike_py.RecordGenerationError):
+            client.operate(key, [{"op": aerospike_py.OPERATOR_INCR, "bin": "counter", "val": 1}], meta={"gen": 2})
+
+    def test_operate_touch_ttl_and_delete(self, client, cleanup):
+        key = ("test", "demo", "test_operate_touch_ttl")
+        cleanup.append(key)
+
+        client.put(key, {"counter": 1})
+        _, meta, bins = client.operate(
+            key,
+            [
+                {"op": aerospike_py.OPERATOR_READ, "bin": "counter", "val": None},
+                {"op": aerospike_py.OPERATOR_TOUCH, "ttl": 600},
+            ],
+        )
+        assert bins == {"counter": 1}
+        assert 0 < meta.ttl <= 600
+
+        client.operate(key, [{"op": aerospike_py.OPERATOR_DELETE, "durable_delete": False}])
+        _, meta = client.exists(key)
+        assert meta is None
+
     def test_operate_ordered(self, client, cleanup):
         key = ("test", "demo", "test_operate_ordered")
         cleanup.append(key)
@@ -189,6 +257,25 @@ class TestOperate:
         assert isinstance(ordered, list)
         assert meta.gen == 1
 
+    def test_operate_accepts_op_dict_objects(self, client, cleanup):
+        key = ("test", "demo", "test_operate_op_dict")
+        cleanup.append(key)
+
+        class Incr:
+            def __init__(self, bin_name, val):
+                self.bin_name = bin_name
+                self.val = val
+
+            def __op_dict__(self):
+                return {"op": aerospike_py.OPERATOR_INCR, "bin": self.bin_name, "val": self.val}
+
+        client.put(key, {"counter": 1})
+        _, _, bins = client.operate(key, [Incr("counter", 2), {"op": aerospike_py.OPERATOR_READ, "bin": "counter"}])
+        assert bins["counter"] == 3
+
+        with pytest.raises(aerospike_py.ParamError):
+            client.operate(key, [object()])
+
 
 class TestConnection:
     def test_is_connected(self, client):
@@ -213,3 +300,33 @@ class TestTruncate:
 
         # Should not raise
         client.truncate("test", "trunc_test")
+
+    def test_truncate_wait(self, client):
+        keys = [("test", "trunc_wait", f"trunc_{i}") for i in range(10)]
+        for key in keys:
+            client.put(key, {"v": 1})
+
+        client.truncate("test", "trunc_wait", wait=True, timeout=30)
+
+        sets = {s["set"]: s for s in client.info_sets("test")}
+        assert sets["trunc_wait"]["objects"] == 0
+        for key in keys:
+            _, meta = client.exists(key)
+            assert meta is None
+
+    def test_truncate_returns_task(self, client):
+        for i in range(10):
+            client.put(("test", "trunc_task", f"trunc_{i}"), {"v": 1})
+
+        task = client.truncate("test", "trunc_task")
+        assert isinstance(task, aerospike_py.TruncateTask)
+        task.wait(timeout=30, poll_interval=0.2)
+        assert task.done()
+
+    def test_truncate_wait_unknown_set(self, client):
+        with pytest.raises(aerospike_py.InvalidArgError, match="Set does not exist"):
+            client.truncate("test", "no_such_set_xyz", wait=True)
+
+    def test_truncate_negative_timeout(self, client):
+        with pytest.raises(aerospike_py.InvalidArgError, match="timeout"):
+            client.truncate("test", "trunc_wait", wait=True, timeout=-1)
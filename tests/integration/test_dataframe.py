@@ -0,0 +1,58 @@
+"""Integration tests for batch_read_df / Query.to_df (requires Aerospike server)."""
+
+import pytest
+
+pd = pytest.importorskip("pandas")
+
+NS = "test"
+SET = "df_out"
+
+
+class TestBatchReadDf:
+    def test_columns_and_index(self, client, cleanup):
+        keys = [(NS, SET, f"df_{i}") for i in range(3)]
+        for i, key in enumerate(keys):
+            cleanup.append(key)
+            client.put(key, {"age": 20 + i, "score": i * 0.5, "name": f"u{i}"})
+
+        df = client.batch_read_df(keys, columns=["age", "score", "name"])
+
+        assert list(df.columns) == ["age", "score", "name"]
+        assert list(df.index) == ["df_0", "df_1", "df_2"]
+        assert df["age"].tolist() == [20, 21, 22]
+        assert df["name"].tolist() == ["u0", "u1", "u2"]
+
+    def test_missing_record_is_null(self, client, cleanup):
+        key = (NS, SET, "df_present")
+        cleanup.append(key)
+        client.put(key, {"n": 7})
+
+        df = client.batch_read_df([key, (NS, SET, "df_absent")], columns=["n"])
+
+        assert str(df["n"].dtype) == "Int64"
+        assert df["n"].isna().tolist() == [False, True]
+
+
+class TestQueryToDf:
+    def test_scan_to_df(self, client, cleanup):
+        keys = [(NS, SET + "_scan", f"scan_{i}") for i in range(3)]
+        for i, key in enumerate(keys):
+            cleanup.append(key)
+            client.put(key, {"n": i})
+
+        query = client.query(NS, SET + "_scan")
+        query.select("n")
+        df = query.to_df()
+
+        assert sorted(df["n"].tolist()) == [0, 1, 2]
+
+
+class TestAsyncDf:
+    async def test_async_batch_read_df(self, async_client, cleanup):
+        key = (NS, SET, "df_async")
+        cleanup.append(key)
+        await async_client.put(key, {"v": 5})
+
+        df = await async_client.batch_read_df([key])
+
+        assert df["v"].tolist() == [5]
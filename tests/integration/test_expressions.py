@@ -5,7 +5,7 @@ import uuid
 import pytest
 
 import aerospike_py
-from aerospike_py import exp
+from aerospike_py import exp, hll_operations
 
 
 class TestExpressionGet:
@@ -138,6 +138,15 @@ class TestExpressionGet:
         _, _, bins = client.get(key, policy={"filter_expression": expr})
         assert bins["name"] == "user_0"
 
+    def test_compiled_expression_reused(self, client):
+        """A compiled expression filters like its dict tree across calls."""
+        expr = exp.compile(exp.ge(exp.int_bin("age"), exp.int_val(30)))
+        policy = {"filter_expression": expr}
+        for key in self.keys[2:]:
+            client.get(key, policy=policy)
+        with pytest.raises(aerospike_py.FilteredOut):
+            client.get(self.keys[0], policy=policy)
+
     def test_string_eq_filter_no_match(self, client):
         """Expression string filter should raise FilteredOut on mismatch."""
         key = self.keys[0]  # name="user_0"
@@ -248,6 +257,125 @@ class TestExpressionMetadata:
         assert bins["age"] == 25
 
 
+class TestListExpressions:
+    """List CDT read expressions used as filters."""
+
+    @pytest.fixture(autouse=True)
+    def setup_record(self, client, cleanup):
+        self.key = ("test", "expr_test", "expr_list")
+        cleanup.append(self.key)
+        client.put(self.key, {"scores": [7, 3, 9], "matrix": [[1], [2, 3, 4, 5]]})
+
+    def test_list_size(self, client):
+        expr = exp.eq(exp.list_size(exp.list_bin("scores")), exp.int_val(3))
+        _, _, bins = client.get(self.key, policy={"filter_expression": expr})
+        assert bins["scores"] == [7, 3, 9]
+
+    def test_list_get_by_index(self, client):
+        expr = exp.eq(
+            exp.list_get_by_index(
+                aerospike_py.LIST_RETURN_VALUE, exp.EXP_TYPE_INT, exp.int_val(0), exp.list_bin("scores")
+            ),
+            exp.int_val(99),
+        )
+        with pytest.raises(aerospike_py.FilteredOut):
+            client.get(self.key, policy={"filter_expression": expr})
+
+    def test_list_get_by_value_exists(self, client):
+        expr = exp.list_get_by_value(aerospike_py.LIST_RETURN_EXISTS, exp.int_val(9), exp.list_bin("scores"))
+        _, _, bins = client.get(self.key, policy={"filter_expression": expr})
+        assert bins["scores"] == [7, 3, 9]
+
+    def test_nested_ctx(self, client):
+        expr = exp.gt(exp.list_size(exp.list_bin("matrix"), ctx=[exp.ctx_list_index(1)]), exp.int_val(3))
+        _, _, bins = client.get(self.key, policy={"filter_expression": expr})
+        assert bins["matrix"][1] == [2, 3, 4, 5]
+
+
+class TestMapExpressions:
+    """Map CDT read expressions used as filters."""
+
+    @pytest.fixture(autouse=True)
+    def setup_record(self, client, cleanup):
+        self.key = ("test", "expr_test", "expr_map")
+        cleanup.append(self.key)
+        client.put(self.key, {"attrs": {"tier": "gold", "level": 3, "address": {"zip": "06000"}}})
+
+    def _get(self, client, expr):
+        return client.get(self.key, policy={"filter_expression": expr})
+
+    def test_map_get_by_key(self, client):
+        tier = exp.map_get_by_key(
+            aerospike_py.MAP_RETURN_VALUE, exp.EXP_TYPE_STRING, exp.string_val("tier"), exp.map_bin("attrs")
+        )
+        _, _, bins = self._get(client, exp.eq(tier, exp.string_val("gold")))
+        assert bins["attrs"]["tier"] == "gold"
+        with pytest.raises(aerospike_py.FilteredOut):
+            self._get(client, exp.eq(tier, exp.string_val("silver")))
+
+    def test_map_size_and_key_list(self, client):
+        count = exp.map_get_by_key_list(
+            aerospike_py.MAP_RETURN_COUNT, exp.list_val(["tier", "level", "missing"]), exp.map_bin("attrs")
+        )
+        expr = exp.and_(
+            exp.eq(exp.map_size(exp.map_bin("attrs")), exp.int_val(3)),
+            exp.eq(count, exp.int_val(2)),
+        )
+        self._get(client, expr)
+
+    def test_map_get_by_value_range(self, client):
+        count = exp.map_get_by_value_range(
+            aerospike_py.MAP_RETURN_COUNT, exp.int_val(1), exp.int_val(5), exp.map_bin("attrs")
+        )
+        self._get(client, exp.eq(count, exp.int_val(1)))
+
+    def test_nested_ctx(self, client):
+        zip_code = exp.map_get_by_key(
+            aerospike_py.MAP_RETURN_VALUE,
+            exp.EXP_TYPE_STRING,
+            exp.string_val("zip"),
+            exp.map_bin("attrs"),
+            ctx=[exp.ctx_map_key("address")],
+        )
+        self._get(client, exp.eq(zip_code, exp.string_val("06000")))
+
+
+class TestBitAndHllExpressions:
+    """Blob bit and HLL read expressions used as filters."""
+
+    @pytest.fixture(autouse=True)
+    def setup_record(self, client, cleanup):
+        self.key = ("test", "expr_test", "expr_bits")
+        cleanup.append(self.key)
+        client.put(self.key, {"flags": bytes([0b1011_0000, 0xFF])})
+        client.operate(self.key, [hll_operations.hll_add("visitors", ["a", "b", "c"], 8)])
+
+    def _get(self, client, expr):
+        return client.get(self.key, policy={"filter_expression": expr})
+
+    def test_bit_count(self, client):
+        flags = exp.blob_bin("flags")
+        self._get(client, exp.eq(exp.bit_count(exp.int_val(0), exp.int_val(8), flags), exp.int_val(3)))
+        with pytest.raises(aerospike_py.FilteredOut):
+            self._get(client, exp.eq(exp.bit_count(exp.int_val(0), exp.int_val(16), flags), exp.int_val(3)))
+
+    def test_bit_get_int_and_lscan(self, client):
+        flags = exp.blob_bin("flags")
+        expr = exp.and_(
+            exp.eq(exp.bit_get_int(exp.int_val(8), exp.int_val(8), False, flags), exp.int_val(255)),
+            exp.eq(exp.bit_lscan(exp.int_val(0), exp.int_val(8), exp.bool_val(False), flags), exp.int_val(1)),
+        )
+        self._get(client, expr)
+
+    def test_hll_count_and_may_contain(self, client):
+        visitors = exp.hll_bin("visitors")
+        expr = exp.and_(
+            exp.eq(exp.hll_get_count(visitors), exp.int_val(3)),
+            exp.eq(exp.hll_may_contain(exp.list_val(["a", "b"]), visitors), exp.int_val(1)),
+        )
+        self._get(client, expr)
+
+
 class TestPkRegexFilterScan:
     """PK regex filter scan via exp.regex_compare(..., exp.key(EXP_TYPE_STRING)).
 
@@ -1,5 +1,8 @@
 """Integration tests for ping() health check (requires Aerospike server)."""
 
+import os
+import time
+
 import pytest
 
 import aerospike_py
@@ -44,3 +47,106 @@ class TestAsyncPing:
         assert await c.ping() is True
         await c.close()
         assert await c.ping() is False
+
+
+class TestWarmUp:
+    def test_warm_up_opens_connections(self, client):
+        nodes = len(client.get_node_names())
+        assert client.warm_up(4) == 4 * nodes
+
+    def test_warm_up_is_repeatable(self, client):
+        client.warm_up(2)
+        assert client.warm_up(2) >= 2
+        assert client.ping() is True
+
+    @pytest.mark.asyncio
+    async def test_async_warm_up(self, async_client):
+        nodes = len(async_client.get_node_names())
+        assert await async_client.warm_up(3) == 3 * nodes
+
+
+class TestClusterEvents:
+    def test_no_events_for_stable_cluster(self):
+        events = []
+        c = aerospike_py.client({**AEROSPIKE_CONFIG, "tend_interval": 100})
+        c.on_cluster_event(events.append)
+        c.connect()
+        try:
+            time.sleep(0.5)
+            assert [e for e in events if e["event"] == "node_removed"] == []
+        finally:
+            c.close()
+
+    def test_callback_replaced_and_removed(self, client):
+        client.on_cluster_event(lambda e: None)
+        client.on_cluster_event(None)
+        assert client.ping() is True
+
+
+class TestClusterName:
+    def test_mismatch_fails_connect(self):
+        c = aerospike_py.client({**AEROSPIKE_CONFIG, "cluster_name": "not-this-cluster"})
+        with pytest.raises(aerospike_py.ClusterError, match="expected 'not-this-cluster'"):
+            c.connect()
+        assert c.is_connected() is False
+
+    @pytest.mark.asyncio
+    async def test_async_mismatch_fails_connect(self):
+        c = aerospike_py.AsyncClient({**AEROSPIKE_CONFIG, "cluster_name": "not-this-cluster"})
+        with pytest.raises(aerospike_py.ClusterError, match="Cluster name mismatch"):
+            await c.connect()
+        assert c.is_connected() is False
+
+    def test_matching_name_connects(self, client):
+        assert client.ping() is True
+
+
+@pytest.mark.skipif(not hasattr(os, "fork"), reason="requires os.fork()")
+class TestFork:
+    @staticmethod
+    def _in_child(fn):
+        """Run ``fn`` in a forked child; return its exit status (0 = passed)."""
+        pid = os.fork()
+        if pid == 0:
+            code = 1
+            try:
+                fn()
+                code = 0
+            finally:
+                os._exit(code)
+        _, status = os.waitpid(pid, 0)
+        return os.waitstatus_to_exitcode(status)
+
+    def test_child_must_reconnect(self):
+        c = aerospike_py.client(AEROSPIKE_CONFIG).connect()
+        try:
+
+            def child():
+                assert c.is_connected() is False
+                try:
+                    c.get(("test", "demo", "fork"))
+                except aerospike_py.ForkError:
+                    pass
+                else:
+                    raise AssertionError("expected ForkError")
+                c.connect()
+                assert c.ping() is True
+                c.close()
+
+            assert self._in_child(child) == 0
+            # The parent's connection is untouched by the child.
+            assert c.ping() is True
+        finally:
+            c.close()
+
+    def test_auto_reconnect_in_child(self):
+        c = aerospike_py.client({**AEROSPIKE_CONFIG, "auto_reconnect": True}).connect()
+        try:
+
+            def child():
+                c.exists(("test", "demo", "fork"))
+                assert c.is_connected() is True
+
+            assert self._in_child(child) == 0
+        finally:
+            c.close()
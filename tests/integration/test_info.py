@@ -68,6 +68,54 @@ class TestInfoRandomNode:
             c.info_random_node("build")
 
 
+class TestInfoSingleNode:
+    def test_by_node_name(self, client):
+        """info_single_node() by name matches that node's info_all() entry."""
+        expected = {name: resp for name, _, resp in client.info_all("node")}
+        for name in client.get_node_names():
+            assert client.info_single_node("node", name) == expected[name]
+
+    def test_by_host(self, client):
+        """info_single_node() accepts the node's host:port address."""
+        address = next(iter(client.get_cluster_stats().values()))["address"]
+        assert "test" in client.info_single_node("namespaces", address)
+
+    def test_unknown_node(self, client):
+        with pytest.raises(aerospike_py.ClusterError):
+            client.info_single_node("build", "no-such-node")
+
+
+class TestInfoParsers:
+    def test_info_namespaces(self, client):
+        assert "test" in client.info_namespaces()
+
+    def test_info_namespaces_on_node(self, client):
+        node = client.get_node_names()[0]
+        assert "test" in client.info_namespaces(node=node)
+
+    def test_info_sets(self, client):
+        client.put(("test", "info_sets", "k1"), {"a": 1})
+        try:
+            sets = {s["set"]: s for s in client.info_sets("test")}
+            assert sets["info_sets"]["ns"] == "test"
+            assert isinstance(sets["info_sets"]["objects"], int)
+        finally:
+            client.remove(("test", "info_sets", "k1"))
+
+    def test_info_sets_unknown_namespace(self, client):
+        with pytest.raises(aerospike_py.ServerError):
+            client.info_sets("no_such_namespace")
+
+    def test_info_sindex(self, client):
+        client.index_integer_create("test", "info_sindex", "age", "info_sindex_age_idx")
+        try:
+            names = [idx["indexname"] for idx in client.info_sindex("test")]
+            assert "info_sindex_age_idx" in names
+            assert all(idx["ns"] == "test" for idx in client.info_sindex("test"))
+        finally:
+            client.index_remove("test", "info_sindex_age_idx")
+
+
 class TestAsyncInfoAll:
     @pytest.mark.asyncio
     async def test_async_info_all_build(self, async_client):
@@ -89,3 +137,82 @@ class TestAsyncInfoRandomNode:
         result = await async_client.info_random_node("build")
         assert isinstance(result, str)
         assert len(result) > 0
+
+    @pytest.mark.asyncio
+    async def test_async_info_single_node(self, async_client):
+        name = async_client.get_node_names()[0]
+        assert await async_client.info_single_node("node", name) == name
+
+
+class TestAsyncInfoParsers:
+    @pytest.mark.asyncio
+    async def test_async_info_namespaces(self, async_client):
+        assert "test" in await async_client.info_namespaces()
+
+    @pytest.mark.asyncio
+    async def test_async_info_sindex_all(self, async_client):
+        assert isinstance(await async_client.info_sindex(), list)
+
+
+class TestClusterStats:
+    def test_stats_per_node(self, client):
+        stats = client.get_cluster_stats()
+
+        assert set(stats) == set(client.get_node_names())
+        for node in stats.values():
+            assert node["active"] is True
+            assert ":" in node["address"]
+            assert node["max_conns"] > 0
+            assert node["partitions"]["test"] > 0
+
+    def test_partitions_cover_namespace(self, client):
+        stats = client.get_cluster_stats()
+        assert sum(n["partitions"]["test"] for n in stats.values()) == 4096
+
+    @pytest.mark.asyncio
+    async def test_async_stats(self, async_client):
+        stats = await async_client.get_cluster_stats()
+        assert set(stats) == set(async_client.get_node_names())
+
+
+class TestGetNodes:
+    def test_nodes_match_node_names(self, client):
+        nodes = client.get_nodes()
+
+        assert {n["name"] for n in nodes} == set(client.get_node_names())
+        for node in nodes:
+            assert node["active"] is True
+            assert node["address"]
+            assert node["port"] > 0
+            assert node["partition_generation"] >= 0
+            assert node["rack_id"] is None or node["rack_id"] >= 0
+
+    def test_address_matches_cluster_stats(self, client):
+        stats = client.get_cluster_stats()
+        for node in client.get_nodes():
+            assert stats[node["name"]]["address"] == f"{node['address']}:{node['port']}"
+
+    @pytest.mark.asyncio
+    async def test_async_nodes(self, async_client):
+        nodes = await async_client.get_nodes()
+        assert {n["name"] for n in nodes} == set(async_client.get_node_names())
+
+
+class TestKeyRouting:
+    def test_node_for_key_is_a_cluster_node(self, client):
+        key = ("test", "demo", "routing-1")
+        node = client.get_node_for_key(key)
+
+        nodes = {n["name"]: n for n in client.get_nodes()}
+        assert node["name"] in nodes
+        assert (node["address"], node["port"]) == (nodes[node["name"]]["address"], nodes[node["name"]]["port"])
+        assert node["partition_id"] == client.get_partition_id(key)
+
+    def test_unknown_namespace_raises(self, client):
+        with pytest.raises(aerospike_py.ClusterError):
+            client.get_node_for_key(("no_such_ns", "demo", "k"))
+
+    @pytest.mark.asyncio
+    async def test_async_node_for_key(self, async_client):
+        node = async_client.get_node_for_key(("test", "demo", "routing-1"))
+        assert node["name"] in async_client.get_node_names()
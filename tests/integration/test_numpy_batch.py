@@ -1,7 +1,9 @@
 """Integration tests for numpy batch_read (requires Aerospike server)."""
 
 import numpy as np
+import pytest
 
+import aerospike_py
 from aerospike_py.numpy_batch import NumpyBatchRecords
 
 NS = "test"
@@ -272,3 +274,128 @@ class TestEmptyBatch:
         assert len(result.batch_records) == 0
         assert len(result.meta) == 0
         assert len(result.result_codes) == 0
+
+
+# ── unicode / bool fields ───────────────────────────────────────
+
+
+class TestUnicodeBoolFields:
+    def test_write_read_roundtrip(self, client, cleanup):
+        """'U' and '?' fields round-trip through batch_write_numpy / batch_read."""
+        dtype = np.dtype([("_key", "U8"), ("name", "U6"), ("active", "?")])
+        data = np.array([("u_1", "héllo", True), ("u_2", "truncated", False)], dtype=dtype)
+        for k in data["_key"]:
+            cleanup.append((NS, SET, str(k)))
+
+        client.batch_write_numpy(data, NS, SET, dtype)
+
+        record = client.get((NS, SET, "u_1"))
+        assert record.bins == {"name": "héllo", "active": True}
+
+        read_dtype = np.dtype([("name", "U6"), ("active", "?")])
+        result = client.batch_read([(NS, SET, "u_1"), (NS, SET, "u_2")], _dtype=read_dtype)
+        assert result.batch_records[0]["name"] == "héllo"
+        assert result.batch_records[1]["name"] == "trunca"
+        np.testing.assert_array_equal(result.batch_records["active"], [True, False])
+
+
+class TestNullMask:
+    def test_missing_bin_vs_zero(self, client, cleanup):
+        """_null_mask separates a stored zero from a missing bin or record."""
+        keys = [(NS, SET, "null_mask_0"), (NS, SET, "null_mask_missing")]
+        cleanup.append(keys[0])
+        client.put(keys[0], {"a": 0})
+
+        dtype = np.dtype([("a", "i4"), ("b", "i4")])
+        result = client.batch_read(keys, _dtype=dtype, _null_mask=True)
+
+        np.testing.assert_array_equal(result.batch_records["a"], [0, 0])
+        np.testing.assert_array_equal(result.null_mask["a"], [False, True])
+        np.testing.assert_array_equal(result.null_mask["b"], [True, True])
+
+    def test_requires_dtype(self, client):
+        import aerospike_py
+
+        with pytest.raises(aerospike_py.InvalidArgError):
+            client.batch_read([(NS, SET, "null_mask_0")], _null_mask=True)
+
+
+class TestDatetimeFields:
+    def test_write_read_roundtrip(self, client, cleanup):
+        """datetime64[ms] fields are stored as epoch-millisecond integer bins."""
+        dtype = np.dtype([("_key", "i4"), ("ts", "M8[ms]")])
+        data = np.array([(1, np.datetime64("2024-01-01T00:00:00.250")), (2, np.datetime64("NaT"))], dtype=dtype)
+        cleanup.extend([(NS, SET, 1), (NS, SET, 2)])
+
+        client.batch_write_numpy(data, NS, SET, dtype)
+
+        assert client.get((NS, SET, 1)).bins == {"ts": 1_704_067_200_250}
+
+        read_dtype = np.dtype([("ts", "M8[ms]")])
+        result = client.batch_read([(NS, SET, 1)], _dtype=read_dtype)
+        assert result.batch_records[0]["ts"] == data[0]["ts"]
+
+
+# ── batch_exists ─────────────────────────────────────────────────
+
+
+class TestBatchExists:
+    def test_list_result(self, client, cleanup):
+        """Without dtype, returns ExistsResult per key in order."""
+        existing = (NS, SET, "exists_list_0")
+        missing = (NS, SET, "exists_list_missing")
+        cleanup.append(existing)
+        client.put(existing, {"val": 1})
+
+        results = client.batch_exists([existing, missing])
+
+        assert len(results) == 2
+        assert results[0].meta is not None
+        assert results[0].meta.gen >= 1
+        assert results[1].meta is None
+
+    def test_bool_mask(self, client, cleanup):
+        """dtype=bool returns a found mask and a gen/ttl meta array."""
+        keys = [(NS, SET, f"exists_np_{i}") for i in range(4)]
+        for key in keys[:2]:
+            cleanup.append(key)
+            client.put(key, {"val": 1})
+
+        found, meta = client.batch_exists(keys, dtype=bool)
+
+        assert found.dtype == np.bool_
+        np.testing.assert_array_equal(found, [True, True, False, False])
+        assert meta.dtype.names == ("gen", "ttl")
+        assert (meta["gen"][found] >= 1).all()
+        np.testing.assert_array_equal(meta["gen"][~found], [0, 0])
+
+    def test_non_bool_dtype_rejected(self, client):
+        with pytest.raises(aerospike_py.ParamError):
+            client.batch_exists([(NS, SET, "exists_bad_dtype")], dtype=np.int32)
+
+
+# ── Query / scan results with dtype ────────────────────────────
+
+
+class TestQueryResultsDtype:
+    def test_scan_into_numpy(self, client, cleanup):
+        scan_set = SET + "_scan"
+        keys = [(NS, scan_set, f"qs_{i}") for i in range(5)]
+        for i, key in enumerate(keys):
+            cleanup.append(key)
+            client.put(key, {"n": i, "score": i * 0.5}, policy={"key": aerospike_py.POLICY_KEY_SEND})
+
+        query = client.query(NS, scan_set)
+        dtype = np.dtype([("n", "i8"), ("score", "f8")])
+        result = query.results(dtype=dtype)
+
+        assert isinstance(result, NumpyBatchRecords)
+        assert sorted(result.batch_records["n"].tolist()) == [0, 1, 2, 3, 4]
+        np.testing.assert_array_equal(result.result_codes, np.zeros(5, dtype=np.int32))
+        assert result.get("qs_3")["score"] == 1.5
+        assert (result.meta["gen"] >= 1).all()
+
+    def test_empty_scan(self, client):
+        query = client.query(NS, SET + "_scan_empty")
+        result = query.results(dtype=np.dtype([("n", "i8")]))
+        assert len(result.batch_records) == 0
@@ -15,7 +15,9 @@ Covers scenarios that prior bugs would have silently corrupted:
 """
 
 import numpy as np
+import pytest
 
+import aerospike_py
 from aerospike_py.numpy_batch import NumpyBatchRecords
 
 NS = "test"
@@ -276,6 +278,38 @@ class TestCustomKeyField:
         np.testing.assert_almost_equal(read.batch_records[1]["val"], 2.72)
 
 
+# ── per-row _namespace / _set ──────────────────────────────────────
+
+
+class TestPerRowNamespaceSet:
+    def test_per_row_set_overrides_default(self, client, cleanup):
+        dtype = np.dtype([("_set", "S16"), ("_key", "i4"), ("val", "i4")])
+        other = SET + "_other"
+        data = np.array([(other.encode(), 4101, 1), (b"", 4102, 2)], dtype=dtype)
+        results = client.batch_write_numpy(data, NS, SET, dtype)
+        keys = [(NS, other, 4101), (NS, SET, 4102)]
+        for k in keys:
+            cleanup.append(k)
+        for br in results.batch_records:
+            assert br.result == 0
+
+        read = client.batch_read(keys, _dtype=np.dtype([("val", "i4")]))
+        assert read.batch_records["val"].tolist() == [1, 2]
+
+    def test_same_key_in_different_sets_allowed(self, client, cleanup):
+        dtype = np.dtype([("_set", "U16"), ("_key", "i4"), ("val", "i4")])
+        data = np.array([(SET, 4201, 1), (SET + "_other", 4201, 2)], dtype=dtype)
+        results = client.batch_write_numpy(data, NS, SET, dtype)
+        cleanup.extend([(NS, SET, 4201), (NS, SET + "_other", 4201)])
+        assert all(br.result == 0 for br in results.batch_records)
+
+    def test_colliding_rows_rejected(self, client):
+        dtype = np.dtype([("_set", "S16"), ("_key", "i4"), ("val", "i4")])
+        data = np.array([(SET.encode(), 4301, 1), (b"", 4301, 2)], dtype=dtype)
+        with pytest.raises(aerospike_py.ParamError, match="same record"):
+            client.batch_write_numpy(data, NS, SET, dtype)
+
+
 # ── vector embedding write → read ─────────────────────────────────
 
 
@@ -0,0 +1,69 @@
+"""Integration tests for Client.pipeline() (requires Aerospike server)."""
+
+import pytest
+
+import aerospike_py
+from aerospike_py.types import Record
+
+NS = "test"
+SET = "pipeline"
+
+
+class TestPipeline:
+    def test_mixed_ops_results_in_order(self, client, cleanup):
+        keys = [(NS, SET, f"p_{i}") for i in range(20)]
+        cleanup.extend(keys)
+
+        with client.pipeline(max_concurrency=4) as pipe:
+            for i, key in enumerate(keys):
+                pipe.put(key, {"n": i})
+        assert pipe.results == [None] * len(keys)
+
+        pipe = client.pipeline()
+        for key in keys:
+            pipe.get(key)
+        pipe.operate(keys[0], [{"op": aerospike_py.OPERATOR_INCR, "bin": "n", "val": 100}])
+        results = pipe.execute()
+
+        assert len(pipe) == 0
+        assert all(isinstance(r, Record) for r in results)
+        assert [r.bins["n"] for r in results[:-1]] == list(range(len(keys)))
+        assert results[-1].key.user_key == "p_0"
+        assert client.get(keys[0]).bins["n"] == 100
+
+    def test_failure_does_not_stop_other_ops(self, client, cleanup):
+        key = (NS, SET, "p_present")
+        cleanup.append(key)
+
+        pipe = client.pipeline()
+        pipe.get((NS, SET, "p_absent"))
+        pipe.put(key, {"v": 1})
+
+        with pytest.raises(aerospike_py.RecordNotFound):
+            pipe.execute()
+        assert client.get(key).bins == {"v": 1}
+        assert isinstance(pipe.results[0], aerospike_py.RecordNotFound)
+
+    def test_raise_on_error_false(self, client):
+        pipe = client.pipeline()
+        pipe.get((NS, SET, "p_absent"))
+
+        results = pipe.execute(raise_on_error=False)
+
+        assert isinstance(results[0], aerospike_py.RecordNotFound)
+
+    def test_invalid_args_raise_when_queued(self, client):
+        pipe = client.pipeline()
+        with pytest.raises(aerospike_py.ParamError):
+            pipe.put("not-a-key", {"v": 1})
+        assert len(pipe) == 0
+
+    def test_exception_in_block_discards_queue(self, client):
+        key = (NS, SET, "p_discarded")
+        with pytest.raises(RuntimeError):
+            with client.pipeline() as pipe:
+                pipe.put(key, {"v": 1})
+                raise RuntimeError("boom")
+
+        assert pipe.results is None
+        assert client.exists(key).meta is None
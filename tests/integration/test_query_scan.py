@@ -1,10 +1,14 @@
 """Integration tests for query operations (requires Aerospike server)."""
 
+import time
+
 import pytest
 
 import aerospike_py
+from aerospike_py import exp
+from aerospike_py import list_operations as lop
 from aerospike_py import predicates as p
-from tests.helpers import wait_for_index
+from tests.helpers import invoke, wait_for_index
 
 
 @pytest.fixture(scope="module")
@@ -63,6 +67,28 @@ class TestQuery:
         for _, _, bins in results:
             assert "age" in bins
 
+    def test_query_select_renames_bins(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.select({"user_name": "name", "years": "age"})
+        q.where(p.equals("age", 23))
+        results = q.results()
+        assert len(results) >= 1
+        for _, _, bins in results:
+            assert bins == {"user_name": "user_3", "years": 23}
+
+    def test_query_select_swaps_names(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.select({"age": "name", "name": "age"})
+        q.where(p.equals("age", 24))
+        _, _, bins = q.results()[0]
+        assert bins == {"age": "user_4", "name": 24}
+
+    def test_query_select_rejects_duplicate_output_name(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.select("name")
+        with pytest.raises(aerospike_py.InvalidArgError):
+            q.select({"name": "age"})
+
     def test_query_foreach(self, client, seed_data):
         q = client.query("test", "query_test")
         q.where(p.between("age", 20, 29))
@@ -75,6 +101,226 @@ class TestQuery:
         assert len(collected) >= 10
 
 
+class TestSampling:
+    def test_max_records_limits_scan(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.max_records(3)
+        assert 1 <= len(q.results()) <= 3
+
+    def test_policy_max_records_takes_precedence(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.max_records(1)
+        assert len(q.results(policy={"max_records": 0})) == 10
+
+    def test_records_per_second(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.records_per_second(1000)
+        assert len(q.results()) == 10
+
+    def test_set_nodes_covers_all_records_node_by_node(self, client, seed_data):
+        seen = []
+        for node in client.get_node_names():
+            q = client.query("test", "query_test")
+            q.set_nodes([node])
+            q.max_concurrent_nodes(1)
+            seen.extend(bins["name"] for _, _, bins in q.results())
+        assert sorted(seen) == sorted(f"user_{i}" for i in range(10))
+
+    def test_set_nodes_rejects_unknown_node(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.set_nodes(["NO_SUCH_NODE"])
+        with pytest.raises(aerospike_py.InvalidArgError):
+            q.results()
+
+    @pytest.mark.parametrize("method", ["max_records", "records_per_second", "max_concurrent_nodes"])
+    def test_rejects_negative(self, client, method):
+        q = client.query("test", "query_test")
+        with pytest.raises(aerospike_py.InvalidArgError):
+            getattr(q, method)(-1)
+
+
+class TestScanAndJobInfo:
+    def test_scan_returns_every_record(self, client, seed_data):
+        assert len(client.scan("test", "query_test").results()) == 10
+
+    async def test_async_scan_returns_every_record(self, async_client, seed_data):
+        assert len(await async_client.scan("test", "query_test").results()) == 10
+
+    async def test_unknown_job_is_undef(self, any_client):
+        info = await invoke(any_client, "job_info", 1234567, aerospike_py.JOB_QUERY)
+        assert info == {"status": aerospike_py.JOB_STATUS_UNDEF, "progress_pct": 0, "records_read": 0}
+        assert await invoke(any_client, "scan_info", 1234567) == info
+
+    async def test_job_info_rejects_unknown_module(self, any_client):
+        with pytest.raises(aerospike_py.InvalidArgError, match="JOB_SCAN"):
+            await invoke(any_client, "job_info", 1, "batch")
+
+
+class TestFilterExpression:
+    def test_scan_filtered_server_side(self, client, seed_data):
+        q = client.query("test", "query_test")
+        results = q.results(filter_expression=exp.eq(exp.string_bin("group"), exp.string_val("B")))
+        assert len(results) == 5
+        assert all(bins["group"] == "B" for _, _, bins in results)
+
+    def test_combined_with_predicate(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.where(p.between("age", 20, 29))
+        results = q.results(filter_expression=exp.ge(exp.int_bin("age"), exp.int_val(27)))
+        assert sorted(bins["age"] for _, _, bins in results) == [27, 28, 29]
+
+    def test_overrides_policy_filter(self, client, seed_data):
+        q = client.query("test", "query_test")
+        policy = {"filter_expression": exp.eq(exp.string_bin("group"), exp.string_val("A"))}
+        results = q.results(policy, filter_expression=exp.eq(exp.int_bin("age"), exp.int_val(29)))
+        assert [bins["age"] for _, _, bins in results] == [29]
+        assert "filter_expression" in policy
+
+    def test_foreach_filtered(self, client, seed_data):
+        q = client.query("test", "query_test")
+        collected = []
+        q.foreach(collected.append, filter_expression=exp.lt(exp.int_bin("age"), exp.int_val(22)))
+        assert sorted(r.bins["age"] for r in collected) == [20, 21]
+
+    def test_rejects_non_expression(self, client, seed_data):
+        q = client.query("test", "query_test")
+        with pytest.raises(aerospike_py.InvalidArgError, match="filter_expression"):
+            q.results(filter_expression={"group": "B"})
+
+    @pytest.mark.asyncio
+    async def test_async_scan_filtered(self, async_client, seed_data):
+        q = async_client.query("test", "query_test")
+        results = await q.results(filter_expression=exp.eq(exp.string_bin("group"), exp.string_val("A")))
+        assert len(results) == 5
+
+
+class TestResultsOps:
+    def test_read_ops_add_bins(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.select("age")
+        q.where(p.between("age", 20, 22))
+        results = q.results(ops=[{"op": aerospike_py.OPERATOR_READ, "bin": "name"}])
+        assert sorted((bins["age"], bins["name"]) for _, _, bins in results) == [
+            (20, "user_0"),
+            (21, "user_1"),
+            (22, "user_2"),
+        ]
+
+    def test_cdt_read_op(self, client, seed_data):
+        key = ("test", "query_test", "qkey_list")
+        client.put(key, {"age": 90, "tags": [1, 2, 3]})
+        try:
+            q = client.query("test", "query_test")
+            q.where(p.equals("age", 90))
+            results = q.results(ops=[lop.list_size("tags")])
+            assert [bins["tags"] for _, _, bins in results] == [3]
+        finally:
+            client.remove(key)
+
+    def test_rejects_write_ops(self, client, seed_data):
+        q = client.query("test", "query_test")
+        with pytest.raises(aerospike_py.InvalidArgError, match="read operations"):
+            q.results(ops=[lop.list_append("tags", 1)])
+
+    @pytest.mark.asyncio
+    async def test_async_read_ops(self, async_client, seed_data):
+        q = async_client.query("test", "query_test")
+        q.select("age")
+        q.where(p.equals("age", 25))
+        results = await q.results(ops=[{"op": aerospike_py.OPERATOR_READ, "bin": "group"}])
+        assert [bins for _, _, bins in results] == [{"age": 25, "group": "B"}]
+
+
+def _wait_for_job(client, job_id, timeout=10.0):
+    deadline = time.monotonic() + timeout
+    while client.job_info(job_id, aerospike_py.JOB_QUERY)["status"] == aerospike_py.JOB_STATUS_INPROGRESS:
+        assert time.monotonic() < deadline, "background query did not finish"
+        time.sleep(0.1)
+
+
+class TestExecuteBackground:
+    @pytest.fixture
+    def bg_keys(self, client):
+        keys = [("test", "query_bg", f"bg_{i}") for i in range(6)]
+        for i, key in enumerate(keys):
+            client.put(key, {"n": i, "flag": "old"})
+        yield keys
+        for key in keys:
+            client.remove(key)
+
+    def test_writes_matching_records(self, client, bg_keys):
+        q = client.query("test", "query_bg")
+        job_id = q.execute_background(
+            [{"op": aerospike_py.OPERATOR_WRITE, "bin": "flag", "val": "new"}],
+            filter_expression=exp.ge(exp.int_bin("n"), exp.int_val(3)),
+        )
+        assert isinstance(job_id, int)
+        _wait_for_job(client, job_id)
+        flags = [client.get(key).bins["flag"] for key in bg_keys]
+        assert flags == ["old", "old", "old", "new", "new", "new"]
+
+    def test_rejects_read_ops(self, client, bg_keys):
+        q = client.query("test", "query_bg")
+        with pytest.raises(aerospike_py.InvalidArgError, match="write operations"):
+            q.execute_background([{"op": aerospike_py.OPERATOR_READ, "bin": "n"}])
+
+    def test_rejects_empty_ops(self, client, bg_keys):
+        with pytest.raises(aerospike_py.InvalidArgError, match="at least one"):
+            client.query("test", "query_bg").execute_background([])
+
+    @pytest.mark.asyncio
+    async def test_async_execute_background(self, client, async_client, bg_keys):
+        q = async_client.query("test", "query_bg")
+        job_id = await q.execute_background([{"op": aerospike_py.OPERATOR_INCR, "bin": "n", "val": 10}])
+        _wait_for_job(client, job_id)
+        assert sorted(client.get(key).bins["n"] for key in bg_keys) == list(range(10, 16))
+
+
+class TestExecuteTemplate:
+    def test_template_runs_on_any_client(self, client, seed_data):
+        template = aerospike_py.Query("test", "query_test")
+        template.select("age")
+        template.where(p.between("age", 20, 22))
+        assert sorted(r.bins["age"] for r in client.execute(template)) == [20, 21, 22]
+        # The template stays unbound and can run again.
+        assert len(client.execute(template, filter_expression=exp.eq(exp.int_bin("age"), exp.int_val(21)))) == 1
+        with pytest.raises(aerospike_py.ClientError):
+            template.results()
+
+    @pytest.mark.asyncio
+    async def test_async_execute(self, async_client, seed_data):
+        template = aerospike_py.Query("test", "query_test")
+        template.where(p.equals("age", 25))
+        records = await async_client.execute(template)
+        assert [r.bins["name"] for r in records] == ["user_5"]
+
+
+class TestMultiplePredicates:
+    def test_second_predicate_runs_as_expression(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.where(p.between("age", 20, 29))
+        q.where(p.equals("group", "A"))
+        results = q.results()
+        assert sorted(bins["age"] for _, _, bins in results) == [20, 21, 22, 23, 24]
+
+    def test_in_list(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.where(p.in_list("name", ["user_1", "user_8", "nobody"]))
+        assert sorted(bins["name"] for _, _, bins in q.results()) == ["user_1", "user_8"]
+
+    def test_string_between_with_index_and_filter_expression(self, client, seed_data):
+        q = client.query("test", "query_test")
+        q.where(p.between("name", "user_3", "user_6"))
+        q.where(p.between("age", 20, 29))
+        results = q.results(filter_expression=exp.ne(exp.int_bin("age"), exp.int_val(25)))
+        assert sorted(bins["name"] for _, _, bins in results) == ["user_3", "user_4", "user_6"]
+
+    def test_between_rejects_mixed_bounds(self, client, seed_data):
+        q = client.query("test", "query_test")
+        with pytest.raises(aerospike_py.InvalidArgError, match="between"):
+            q.where(p.between("age", 1, "z"))
+
+
 class TestPartitionFilter:
     """Validates PartitionFilter / expected_duration / include_bin_data on QueryPolicy.
 
@@ -127,11 +373,11 @@ class TestPartitionFilter:
         assert isinstance(records, list)
 
     def test_partition_filter_by_id_out_of_range_raises(self):
-        with pytest.raises(ValueError, match="partition_id must be"):
+        with pytest.raises(aerospike_py.ParamError, match="partition_id must be"):
             aerospike_py.partition_filter_by_id(4096)
 
     def test_partition_filter_by_range_overflow_raises(self):
-        with pytest.raises(ValueError, match="begin \\+ count must be"):
+        with pytest.raises(aerospike_py.ParamError, match="begin \\+ count must be"):
             aerospike_py.partition_filter_by_range(4000, 1000)
 
     def test_expected_duration_short_runs(self, client, partitioned_data):
@@ -157,3 +403,24 @@ class TestIndex:
 
         # Cleanup
         client.index_remove("test", "idx_query_name")
+
+    def test_index_create_no_wait(self, client, seed_data):
+        task = client.index_string_create("test", "query_test", "name", "idx_query_name_nowait", wait=False)
+        try:
+            assert isinstance(task, aerospike_py.IndexTask)
+            task.wait(timeout=30, poll_interval=0.1)
+            assert task.is_done()
+            assert client.index_status("test", "idx_query_name_nowait") == 100
+        finally:
+            client.index_remove("test", "idx_query_name_nowait")
+
+    def test_index_create_wait_returns_completed_task(self, client, seed_data):
+        task = client.index_string_create("test", "query_test", "name", "idx_query_name_wait")
+        try:
+            assert task.status() == 100
+        finally:
+            client.index_remove("test", "idx_query_name_wait")
+
+    def test_index_status_unknown_index(self, client):
+        with pytest.raises(aerospike_py.IndexNotFound):
+            client.index_status("test", "no_such_index")
@@ -0,0 +1,82 @@
+"""Integration tests for get_raw / put_raw (requires Aerospike server)."""
+
+import struct
+
+import pytest
+
+import aerospike_py
+
+NS = "test"
+SET = "raw_bins"
+
+
+class TestGetRaw:
+    def test_scalar_payloads(self, client, cleanup):
+        key = (NS, SET, "scalars")
+        cleanup.append(key)
+        client.put(key, {"i": 30, "f": 1.5, "s": "héllo", "b": b"\x00\x01", "t": True})
+
+        record = client.get_raw(key)
+
+        assert record.meta.gen == 1
+        assert record.bins["i"] == (aerospike_py.AS_BYTES_INTEGER, struct.pack(">q", 30))
+        assert record.bins["f"] == (aerospike_py.AS_BYTES_DOUBLE, struct.pack(">d", 1.5))
+        assert record.bins["s"] == (aerospike_py.AS_BYTES_STRING, "héllo".encode())
+        assert record.bins["b"] == (aerospike_py.AS_BYTES_BLOB, b"\x00\x01")
+        assert record.bins["t"] == (aerospike_py.AS_BYTES_BOOL, b"\x01")
+
+    def test_cdt_payload_types(self, client, cleanup):
+        key = (NS, SET, "cdts")
+        cleanup.append(key)
+        client.put(key, {"l": [1, "a"], "m": {"k": [1.5]}})
+
+        bins = client.get_raw(key).bins
+
+        assert bins["l"][0] == aerospike_py.AS_BYTES_LIST
+        assert bins["m"][0] == aerospike_py.AS_BYTES_MAP
+
+    def test_missing_record(self, client):
+        with pytest.raises(aerospike_py.RecordNotFound):
+            client.get_raw((NS, SET, "raw_missing"))
+
+
+class TestPutRaw:
+    def test_copy_round_trip(self, client, cleanup):
+        src = (NS, SET, "src")
+        dst = (NS, SET, "dst")
+        cleanup.extend([src, dst])
+        bins = {"i": -7, "s": "x", "l": [1, [2, {"a": b"\xff"}]], "m": {"k": None, 2: 2.5}}
+        client.put(src, bins)
+
+        client.put_raw(dst, client.get_raw(src).bins)
+
+        assert client.get(dst).bins == client.get(src).bins
+
+    def test_hand_built_payload(self, client, cleanup):
+        key = (NS, SET, "hand_built")
+        cleanup.append(key)
+
+        client.put_raw(key, {"n": (aerospike_py.AS_BYTES_INTEGER, struct.pack(">q", 42))})
+
+        assert client.get(key).bins == {"n": 42}
+
+    def test_malformed_payload_rejected(self, client):
+        with pytest.raises(aerospike_py.ParamError):
+            client.put_raw((NS, SET, "bad"), {"n": (aerospike_py.AS_BYTES_INTEGER, b"\x01")})
+
+    def test_non_pair_rejected(self, client):
+        with pytest.raises(aerospike_py.ParamError):
+            client.put_raw((NS, SET, "bad"), {"n": b"\x01"})
+
+
+class TestAsyncRaw:
+    async def test_round_trip(self, async_client, cleanup):
+        src = (NS, SET, "async_src")
+        dst = (NS, SET, "async_dst")
+        cleanup.extend([src, dst])
+        await async_client.put(src, {"v": [1, 2, 3]})
+
+        record = await async_client.get_raw(src)
+        await async_client.put_raw(dst, record.bins)
+
+        assert (await async_client.get(dst)).bins == {"v": [1, 2, 3]}
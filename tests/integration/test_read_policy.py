@@ -127,3 +127,37 @@ class TestReadTouchTtlPercent:
         _, meta_after, _ = client.get(key)
         # With percent=99, a read should reset TTL up.
         assert meta_after.ttl >= meta_before.ttl - 1
+
+
+class TestOperatePolicy:
+    def test_read_and_write_fields_accepted(self, client, cleanup):
+        key = ("test", "demo", "operate_policy_mixed")
+        cleanup.append(key)
+        client.put(key, {"n": 1})
+        policy = {
+            "read_mode_ap": aerospike_py.POLICY_READ_MODE_AP_ALL,
+            "replica": aerospike_py.POLICY_REPLICA_MASTER,
+            "read_mode_sc": aerospike_py.POLICY_READ_MODE_SC_SESSION,
+            "respond_all_ops": True,
+        }
+        ops = [
+            {"op": aerospike_py.OPERATOR_INCR, "bin": "n", "val": 1},
+            {"op": aerospike_py.OPERATOR_READ, "bin": "n", "val": None},
+        ]
+        _, _, bins = client.operate(key, ops, policy=policy)
+        assert bins["n"] == 2
+
+    @pytest.mark.parametrize(
+        "policy",
+        [
+            {"replica": aerospike_py.POLICY_REPLICA_SEQUENCE},
+            {"read_mode_sc": aerospike_py.POLICY_READ_MODE_SC_LINEARIZE},
+        ],
+    )
+    def test_unsupported_read_side_values_raise(self, client, cleanup, policy):
+        key = ("test", "demo", "operate_policy_bad")
+        cleanup.append(key)
+        client.put(key, {"n": 1})
+        ops = [{"op": aerospike_py.OPERATOR_READ, "bin": "n", "val": None}]
+        with pytest.raises(aerospike_py.InvalidArgError):
+            client.operate(key, ops, policy=policy)
@@ -69,6 +69,34 @@ class TestCRUDWorkflow:
         _, _, bins = await invoke(any_client, "get", key)
         assert bins["counter"] == 10
 
+    async def test_write_returns_new_value(self, any_client, any_cleanup):
+        key = ("test", "scenario", "return_value")
+        any_cleanup.append(key)
+
+        await invoke(any_client, "put", key, {"counter": 1, "name": "b"})
+        assert await invoke(any_client, "increment", key, "counter", 2, return_value=True) == 3
+        assert await invoke(any_client, "append", key, "name", "c", return_value=True) == "bc"
+        assert await invoke(any_client, "prepend", key, "name", "a", return_value=True) == "abc"
+        assert await invoke(any_client, "increment", key, "counter", 1) is None
+
+    async def test_put_returns_meta(self, any_client, any_cleanup):
+        key = ("test", "scenario", "return_meta")
+        any_cleanup.append(key)
+
+        meta = await invoke(any_client, "put", key, {"v": 1}, return_meta=True)
+        assert meta.gen == 1
+        meta = await invoke(
+            any_client,
+            "put",
+            key,
+            {"v": 2},
+            meta={"gen": meta.gen},
+            policy={"gen": aerospike_py.POLICY_GEN_EQ},
+            return_meta=True,
+        )
+        assert meta.gen == 2
+        assert await invoke(any_client, "put", key, {"v": 3}) is None
+
     async def test_operate_multi_ops_workflow(self, any_client, any_cleanup):
         """Use operate() to perform multiple operations atomically."""
         key = ("test", "scenario", "multi_ops")
@@ -43,6 +43,35 @@ class TestUDFPutRemove:
         client.udf_put(UDF_FILE)
         client.udf_remove("test_udf")
 
+    def test_udf_put_no_wait_returns_task(self, client):
+        task = client.udf_put(UDF_FILE, wait=False)
+        assert isinstance(task, aerospike_py.UdfTask)
+        task.wait(timeout=30, poll_interval=0.1)
+        assert task.done()
+        assert task.progress() is None
+
+        task = client.udf_remove("test_udf", wait=False)
+        task.wait(timeout=30, poll_interval=0.1)
+        assert "test_udf.lua" not in {m["name"] for m in client.udf_list()}
+
+
+class TestUDFListGet:
+    def test_udf_list(self, udf_client):
+        modules = {m["name"]: m for m in udf_client.udf_list()}
+        assert modules["test_udf.lua"]["type"] == "LUA"
+        assert modules["test_udf.lua"]["hash"]
+        assert "filename" not in modules["test_udf.lua"]
+
+    def test_udf_get_returns_source(self, udf_client):
+        with open(UDF_FILE) as f:
+            expected = f.read()
+        assert udf_client.udf_get("test_udf") == expected
+        assert udf_client.udf_get("test_udf.lua") == expected
+
+    def test_udf_get_unknown_module(self, client):
+        with pytest.raises(aerospike_py.UDFError):
+            client.udf_get("no_such_module")
+
 
 class TestApply:
     def test_apply_echo(self, udf_client):
@@ -86,6 +115,32 @@ class TestApply:
         finally:
             udf_client.remove(key)
 
+    def test_apply_lua_error_is_structured(self, udf_client):
+        key = ("test", "demo", "udf_fail")
+        udf_client.put(key, {"a": 1})
+        try:
+            with pytest.raises(aerospike_py.UDFError) as exc_info:
+                udf_client.apply(key, "test_udf", "fail", ["cart is empty"])
+        finally:
+            udf_client.remove(key)
+        err = exc_info.value
+        assert err.code == 100
+        assert (err.module, err.function, err.line) == ("test_udf", "fail", 15)
+        assert err.message == "cart is empty"
+        assert err.key[:3] == key
+
+    def test_apply_lua_error_without_position(self, udf_client):
+        key = ("test", "demo", "udf_fail_no_position")
+        udf_client.put(key, {"a": 1})
+        try:
+            with pytest.raises(aerospike_py.UDFError) as exc_info:
+                udf_client.apply(key, "test_udf", "fail_no_position", ["cart is empty"])
+        finally:
+            udf_client.remove(key)
+        err = exc_info.value
+        assert (err.module, err.function, err.line) == ("test_udf", "fail_no_position", None)
+        assert err.message == "cart is empty"
+
 
 class TestBatchApply:
     def test_batch_apply_basic(self, udf_client):
@@ -11,6 +11,14 @@ function get_bin(rec, bin_name)
     return rec[bin_name]
 end
 
+function fail(rec, msg)
+    error(msg)
+end
+
+function fail_no_position(rec, msg)
+    error(msg, 0)
+end
+
 function set_bin(rec, bin_name, val)
     rec[bin_name] = val
     aerospike:update(rec)
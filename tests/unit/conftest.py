@@ -0,0 +1,123 @@
+"""Shared fixtures for the unit tests."""
+
+import time
+
+import pytest
+
+import aerospike_py
+from aerospike_py._bug_report import catch_unexpected
+from aerospike_py._dispatch import dispatch_hooks
+from aerospike_py._reconnect import AutoReconnect
+from aerospike_py._slow_log import SlowLog
+from aerospike_py._strict_params import strict_params_from_config
+
+
+class _FakeState:
+    """Hook state built from a client config, plus call counters."""
+
+    def __init__(self, config, failures, delay, connected, reconnect_fails):
+        self._slow_log = SlowLog.from_config(config)
+        self._auto_reconnect = AutoReconnect.from_config(config)
+        self._strict_params = strict_params_from_config(config)
+        self.failures = failures
+        self.delay = delay
+        self.connected = connected
+        self.reconnect_fails = reconnect_fails
+        self.calls = 0
+        self.relogins = 0
+        self.reconnects = []
+
+    def _command(self, result):
+        """Body of every fake command: the first ``failures`` calls hit an expired session."""
+        self.calls += 1
+        time.sleep(self.delay)
+        if not self.connected:
+            raise aerospike_py.ClientError("Client is not connected. Call connect() first.")
+        if self.calls <= self.failures:
+            raise aerospike_py.SessionExpired("AEROSPIKE_ERR (66): Login session expired")
+        return result
+
+    def connect(self, username=None, password=None):
+        self.connected = True
+        if self._auto_reconnect is not None:
+            self._auto_reconnect.credentials = (username, password)
+
+    def close(self):
+        if self._auto_reconnect is not None:
+            self._auto_reconnect.credentials = None
+        self.connected = False
+
+    def _reconnect(self, username, password):
+        self.reconnects.append((username, password))
+        if self.reconnect_fails:
+            raise aerospike_py.ClusterError("Failed to connect to host(s)")
+        self.connected = True
+
+
+@dispatch_hooks
+class FakeClient(_FakeState):
+    """Sync client stand-in whose methods go through the same hooks as ``Client``."""
+
+    def _relogin(self):
+        self.relogins += 1
+        return 0
+
+    @catch_unexpected("FakeClient.get")
+    def get(self, key, policy=None):
+        return self._command(key)
+
+    @catch_unexpected("FakeClient.batch_read")
+    def batch_read(self, keys, policy=None):
+        return self._command(keys)
+
+    @catch_unexpected("FakeClient.batch_write")
+    def batch_write(self, records, policy=None):
+        return self._command(records)
+
+    @catch_unexpected("FakeClient.operate")
+    def operate(self, key, ops, meta=None, policy=None):
+        return self._command(ops)
+
+    @catch_unexpected("FakeClient.truncate")
+    def truncate(self, namespace, set_name, nanos=0, policy=None):
+        return self._command(namespace)
+
+    @catch_unexpected("FakeClient.fail")
+    def fail(self, key):
+        self._command(key)
+        raise aerospike_py.AerospikeTimeoutError("timeout")
+
+    @catch_unexpected("FakeClient.is_connected")
+    def is_connected(self):
+        return self.connected
+
+    def undecorated(self):
+        raise aerospike_py.SessionExpired("not retried")
+
+
+@dispatch_hooks
+class FakeAsyncClient(_FakeState):
+    """Async counterpart of ``FakeClient``, hooked like ``AsyncClient``."""
+
+    async def _relogin(self):
+        self.relogins += 1
+        return 0
+
+    @catch_unexpected("FakeAsyncClient.get")
+    async def get(self, key, policy=None):
+        return self._command(key)
+
+    @catch_unexpected("FakeAsyncClient.batch_write")
+    async def batch_write(self, records, policy=None):
+        return self._command(records)
+
+
+@pytest.fixture
+def make_client():
+    """Factory for ``FakeClient`` / ``FakeAsyncClient`` instances configured like a real client."""
+
+    def make(config=None, *, asynchronous=False, failures=0, delay=0.0, connected=True, reconnect_fails=False):
+        cls = FakeAsyncClient if asynchronous else FakeClient
+        return cls(config or {}, failures, delay, connected, reconnect_fails)
+
+    return make
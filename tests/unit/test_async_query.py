@@ -0,0 +1,42 @@
+"""Unit tests for AsyncQuery argument forwarding to the native query."""
+
+import pytest
+
+from aerospike_py._async_client import AsyncQuery
+
+
+class _RecordingQuery:
+    """Native query stand-in that records the last call."""
+
+    def __init__(self):
+        self.calls = []
+
+    def __getattr__(self, name):
+        def call(*args, **kwargs):
+            self.calls.append((name, args, kwargs))
+            return [] if name == "results" else 0
+
+        return call
+
+
+@pytest.mark.parametrize(
+    ("method", "args", "native"),
+    [
+        ("results", (), "results"),
+        ("results_arrow", (), "results_arrow"),
+        ("execute_background", ([],), "execute_background"),
+        ("foreach", (print,), "foreach"),
+    ],
+)
+async def test_timeout_is_forwarded(method, args, native):
+    inner = _RecordingQuery()
+    await getattr(AsyncQuery(inner), method)(*args, timeout=0.5)
+    ((name, _, kwargs),) = inner.calls
+    assert name == native
+    assert kwargs == {"timeout": 0.5}
+
+
+async def test_timeout_defaults_to_none():
+    inner = _RecordingQuery()
+    await AsyncQuery(inner).results()
+    assert inner.calls[0][2] == {"timeout": None}
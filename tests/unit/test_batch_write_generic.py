@@ -55,18 +55,18 @@ class TestBatchWriteInputValidation:
     """Verify batch_write() raises correct errors for invalid inputs."""
 
     def test_record_must_be_tuple(self, client):
-        """Non-tuple record raises TypeError."""
-        with pytest.raises(TypeError, match="must be a tuple"):
+        """Non-tuple record raises ParamError."""
+        with pytest.raises(aerospike_py.ParamError, match="must be a tuple"):
             client.batch_write([{"key": ("test", "demo", "k1"), "bins": {"a": 1}}])
 
     def test_tuple_must_have_at_least_2_elements(self, client):
-        """Single-element tuple raises ValueError."""
-        with pytest.raises(ValueError, match="at least 2 elements"):
+        """Single-element tuple raises ParamError."""
+        with pytest.raises(aerospike_py.ParamError, match="at least 2 elements"):
             client.batch_write([(("test", "demo", "k1"),)])
 
     def test_bins_must_be_dict(self, client):
-        """Non-dict bins element raises TypeError."""
-        with pytest.raises(TypeError, match="must be a dict"):
+        """Non-dict bins element raises ParamError."""
+        with pytest.raises(aerospike_py.ParamError, match="must be a dict"):
             client.batch_write([(("test", "demo", "k1"), [("a", 1)])])
 
     def test_3_tuple_meta_accepted(self, client):
@@ -77,8 +77,8 @@ class TestBatchWriteInputValidation:
         assert len(result.batch_records) == 1
 
     def test_3_tuple_meta_must_be_dict(self, client):
-        """Non-dict meta element raises TypeError."""
-        with pytest.raises(TypeError, match="meta element must be a dict"):
+        """Non-dict meta element raises ParamError."""
+        with pytest.raises(aerospike_py.ParamError, match="meta element must be a dict"):
             client.batch_write([(("test", "demo", "k1"), {"a": 1}, "not_a_dict")])
 
     def test_policy_ttl_accepted(self, client):
@@ -95,5 +95,5 @@ class TestBatchWriteInputValidation:
 
     def test_meta_ttl_invalid_type_raises(self, client):
         """Non-integer TTL in meta raises an error."""
-        with pytest.raises((TypeError, aerospike_py.InvalidArgError)):
+        with pytest.raises((TypeError, aerospike_py.ParamError)):
             client.batch_write([(("test", "demo", "k1"), {"a": 1}, {"ttl": "not_a_number"})])
@@ -0,0 +1,62 @@
+"""Unit tests for ``BatchWriteResult`` helpers (no server required)."""
+
+from __future__ import annotations
+
+import pytest
+
+import aerospike_py
+from aerospike_py.types import AerospikeKey, BatchRecord, BatchWriteResult, Record, RecordMetadata
+
+
+def _result(*entries):
+    records = []
+    for user_key, rc in entries:
+        digest = aerospike_py.calc_digest("test", "demo", user_key)
+        key = AerospikeKey("test", "demo", user_key, digest)
+        records.append(BatchRecord(key, rc, None))
+    return BatchWriteResult(records)
+
+
+class TestBatchWriteResult:
+    def test_counts(self):
+        result = _result(("a", 0), ("b", 2), ("c", 0))
+        assert result.ok_count == 2
+        assert result.error_count == 1
+
+    def test_get_by_key(self):
+        result = _result(("a", 0), ("b", 2))
+        br = result.get(("test", "demo", "b"))
+        assert br is not None
+        assert br.key.user_key == "b"
+        assert br.result == 2
+
+    def test_get_by_digest(self):
+        result = _result(("a", 0))
+        digest = aerospike_py.calc_digest("test", "demo", "a")
+        assert result.get(("test", "demo", None, digest)) is result.batch_records[0]
+
+    def test_get_missing_returns_default(self):
+        result = _result(("a", 0))
+        assert result.get(("test", "demo", "zzz")) is None
+        assert result.get(("other", "demo", "a"), "missing") == "missing"
+
+
+class TestBatchRecord:
+    def test_as_tuple_without_record(self):
+        br = _result(("a", 2)).batch_records[0]
+        key, meta, bins = br.as_tuple()
+        assert key == br.key
+        assert meta is None and bins is None
+
+    def test_as_tuple_returns_record(self):
+        br = _result(("a", 0)).batch_records[0]
+        record = Record(br.key, RecordMetadata(gen=1, ttl=100), {"n": 1})
+        assert br._replace(record=record).as_tuple() is record
+
+    def test_raise_for_status(self):
+        ok, missing = _result(("a", 0), ("b", 2)).batch_records
+        ok.raise_for_status()
+        with pytest.raises(aerospike_py.RecordNotFound) as exc_info:
+            missing.raise_for_status()
+        assert exc_info.value.code == 2
+        assert exc_info.value.key == missing.key
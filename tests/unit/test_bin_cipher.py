@@ -0,0 +1,38 @@
+"""Unit tests for ``set_bin_cipher()`` argument validation (no server required)."""
+
+import pytest
+
+import aerospike_py
+from tests import DUMMY_CONFIG
+
+
+def _identity(data: bytes) -> bytes:
+    return data
+
+
+@pytest.fixture(params=["sync", "async"])
+def client(request):
+    if request.param == "sync":
+        return aerospike_py.client(DUMMY_CONFIG)
+    return aerospike_py.AsyncClient(DUMMY_CONFIG)
+
+
+def test_register_and_remove(client):
+    client.set_bin_cipher(_identity, _identity, bins=["ssn"])
+    client.set_bin_cipher(None, None)
+
+
+def test_single_hook_rejected(client):
+    with pytest.raises(aerospike_py.InvalidArgError, match="both"):
+        client.set_bin_cipher(_identity, None, bins=["ssn"])
+
+
+def test_non_callable_rejected(client):
+    with pytest.raises(aerospike_py.InvalidArgError, match="callables"):
+        client.set_bin_cipher(_identity, b"key", bins=["ssn"])
+
+
+@pytest.mark.parametrize("bins", [None, []])
+def test_bins_required(client, bins):
+    with pytest.raises(aerospike_py.InvalidArgError, match="bins"):
+        client.set_bin_cipher(_identity, _identity, bins=bins)
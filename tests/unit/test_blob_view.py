@@ -0,0 +1,28 @@
+"""Unit tests for the blob view threshold setting (no Aerospike server required)."""
+
+from __future__ import annotations
+
+import pytest
+
+import aerospike_py
+
+
+@pytest.fixture(autouse=True)
+def _reset_threshold():
+    prev = aerospike_py.get_blob_view_threshold()
+    yield
+    aerospike_py.set_blob_view_threshold(prev)
+
+
+def test_default_disabled():
+    assert aerospike_py.get_blob_view_threshold() == 0
+
+
+def test_round_trip():
+    aerospike_py.set_blob_view_threshold(1 << 20)
+    assert aerospike_py.get_blob_view_threshold() == 1 << 20
+
+
+def test_negative_rejected():
+    with pytest.raises(aerospike_py.ParamError):
+        aerospike_py.set_blob_view_threshold(-1)
@@ -6,6 +6,9 @@ Covers:
 - Operations on closed client raise ClientError
 """
 
+import asyncio
+import contextlib
+
 import pytest
 
 import aerospike_py
@@ -53,6 +56,52 @@ class TestClientLifecycle:
         with pytest.raises(aerospike_py.ClientError, match="not connected"):
             c.get(("test", "demo", "key1"))
 
+    def test_pipeline_on_disconnected_client_raises(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.pipeline()
+
+    def test_warm_up_on_disconnected_client_raises(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.warm_up(4)
+        with pytest.raises(aerospike_py.InvalidArgError, match="conns_per_node"):
+            c.warm_up(0)
+
+    def test_on_cluster_event_before_connect(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        c.on_cluster_event(lambda event: None)
+        c.on_cluster_event(None)
+        with pytest.raises(aerospike_py.ParamError, match="callable"):
+            c.on_cluster_event("not callable")
+
+    def test_get_cluster_stats_on_disconnected_client_raises(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.get_cluster_stats()
+
+    def test_get_nodes_on_disconnected_client_raises(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.get_nodes()
+
+    def test_get_node_for_key_on_disconnected_client_raises(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.get_node_for_key(("test", "demo", "k1"))
+
+    def test_get_partition_id_needs_no_connection(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        key = ("test", "demo", "k1")
+        digest = aerospike_py.calc_digest(*key)
+        assert c.get_partition_id(key) == int.from_bytes(digest[:2], "little") & 0xFFF
+        assert aerospike_py.AsyncClient(DUMMY_CONFIG).get_partition_id(key) == c.get_partition_id(key)
+
+    def test_pipeline_zero_concurrency_rejected(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.InvalidArgError, match="max_concurrency"):
+            c.pipeline(max_concurrency=0)
+
 
 class TestAsyncClientLifecycle:
     """AsyncClient lifecycle state tests."""
@@ -78,3 +127,246 @@ class TestAsyncClientLifecycle:
         c = aerospike_py.AsyncClient(DUMMY_CONFIG)
         await c.close()
         assert c.is_connected() is False
+
+
+class TestRustRuntimeConfig:
+    """`rust_runtime` config validation (raised before any network I/O)."""
+
+    def test_worker_threads_requires_dedicated(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "rust_runtime": {"worker_threads": 2}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="dedicated"):
+            c.connect()
+
+    @pytest.mark.parametrize("workers", [0, 33])
+    def test_worker_threads_out_of_range(self, workers):
+        c = aerospike_py.client({**DUMMY_CONFIG, "rust_runtime": {"dedicated": True, "worker_threads": workers}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="worker_threads"):
+            c.connect()
+
+    def test_unknown_option_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "rust_runtime": {"dedicate": True}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="dedicate"):
+            c.connect()
+
+    def test_dedicated_failed_connect_allows_retry(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "rust_runtime": {"dedicated": True, "worker_threads": 1}})
+        with pytest.raises(aerospike_py.AerospikeError):
+            c.connect()
+        with pytest.raises(aerospike_py.AerospikeError):
+            c.connect()
+
+    async def test_async_client_rejects_dedicated(self):
+        c = aerospike_py.AsyncClient({**DUMMY_CONFIG, "rust_runtime": {"dedicated": True}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="sync Client"):
+            await c.connect()
+
+
+class TestCircuitBreakerConfig:
+    """`circuit_breaker` config validation (raised before any network I/O)."""
+
+    def test_unknown_option_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "circuit_breaker": {"threshold": 3}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="threshold"):
+            c.connect()
+
+    @pytest.mark.parametrize("field", ["failure_threshold", "window_ms", "reset_timeout_ms"])
+    def test_zero_rejected(self, field):
+        c = aerospike_py.client({**DUMMY_CONFIG, "circuit_breaker": {field: 0}})
+        with pytest.raises(aerospike_py.InvalidArgError, match=field):
+            c.connect()
+
+    async def test_async_client_rejects_non_dict(self):
+        c = aerospike_py.AsyncClient({**DUMMY_CONFIG, "circuit_breaker": True})
+        with pytest.raises(aerospike_py.InvalidArgError, match="circuit_breaker"):
+            await c.connect()
+
+
+class TestBinCompressionConfig:
+    """`bin_compression` config validation (raised before any network I/O)."""
+
+    def test_unknown_option_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "bin_compression": {"threshold": 1024}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="threshold"):
+            c.connect()
+
+    def test_unknown_codec_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "bin_compression": {"codec": "lz4"}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="lz4"):
+            c.connect()
+
+    def test_level_out_of_range(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "bin_compression": {"level": 100}})
+        with pytest.raises(aerospike_py.InvalidArgError, match="level"):
+            c.connect()
+
+    async def test_async_client_rejects_non_dict(self):
+        c = aerospike_py.AsyncClient({**DUMMY_CONFIG, "bin_compression": "zstd"})
+        with pytest.raises(aerospike_py.InvalidArgError, match="bin_compression"):
+            await c.connect()
+
+
+class TestRateLimitConfig:
+    """`max_ops_per_second` / `max_concurrent_requests` validation (raised before any network I/O)."""
+
+    @pytest.mark.parametrize("value", [-1, float("nan"), float("inf"), 1e-30])
+    def test_invalid_rate_rejected(self, value):
+        c = aerospike_py.client({**DUMMY_CONFIG, "max_ops_per_second": value})
+        with pytest.raises(aerospike_py.InvalidArgError, match="max_ops_per_second"):
+            c.connect()
+
+    @pytest.mark.parametrize("value", [-1, float("inf"), 1e30])
+    def test_invalid_refresh_seconds_rejected(self, value):
+        c = aerospike_py.client({**DUMMY_CONFIG, "refresh_seconds": value})
+        with pytest.raises(aerospike_py.ParamError, match="refresh_seconds"):
+            c.connect()
+
+    async def test_conflicting_concurrency_alias_rejected(self):
+        c = aerospike_py.AsyncClient({**DUMMY_CONFIG, "max_concurrent_operations": 32, "max_concurrent_requests": 64})
+        with pytest.raises(aerospike_py.InvalidArgError, match="max_concurrent_requests"):
+            await c.connect()
+
+
+class TestEventLoopGuardConfig:
+    """`allow_blocking_in_event_loop` validation (raised before any network I/O)."""
+
+    def test_non_bool_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "allow_blocking_in_event_loop": "yes"})
+        with pytest.raises(aerospike_py.ParamError, match="allow_blocking_in_event_loop"):
+            c.connect()
+
+    async def test_unconnected_client_reports_not_connected_first(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.get(("test", "demo", "k1"))
+
+
+class TestConnectionString:
+    """`aerospike://` connection strings in place of the config dict."""
+
+    def test_url_settings_reach_python_wrapper(self):
+        c = aerospike_py.client("aerospike://127.0.0.1:3000?slow_log_ms=5&strict_params=true")
+        assert c._slow_log is not None
+        assert c._strict_params is True
+
+    def test_url_settings_reach_native_config(self):
+        c = aerospike_py.client("aerospike://127.0.0.1:3000?allow_blocking_in_event_loop=yes")
+        with pytest.raises(aerospike_py.ParamError, match="allow_blocking_in_event_loop"):
+            c.connect()
+
+    async def test_async_client_accepts_url(self):
+        with pytest.raises(aerospike_py.ParamError, match="auto_reconnect"):
+            aerospike_py.AsyncClient("aerospike://127.0.0.1?auto_reconnect=true")
+
+    @pytest.mark.parametrize("url", ["127.0.0.1:3000", "aerospike://host:port", "aerospike://h?timeout"])
+    def test_malformed_url_rejected(self, url):
+        with pytest.raises(aerospike_py.ParamError, match="connection string"):
+            aerospike_py.client(url)
+
+
+class TestSeedDiscoveryConfig:
+    """`hosts_srv` / `address_family` validation (raised before any network I/O)."""
+
+    def test_hosts_or_hosts_srv_required(self):
+        c = aerospike_py.client({"cluster_name": "docker"})
+        with pytest.raises(aerospike_py.ParamError, match="hosts_srv"):
+            c.connect()
+
+    def test_invalid_address_family_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "address_family": "inet6"})
+        with pytest.raises(aerospike_py.ParamError, match="address_family"):
+            c.connect()
+
+    async def test_async_client_rejects_negative_refresh(self):
+        c = aerospike_py.AsyncClient({"hosts_srv": "_aerospike._tcp.db.local", "hosts_srv_refresh_seconds": -1})
+        with pytest.raises(aerospike_py.ParamError, match="hosts_srv_refresh_seconds"):
+            await c.connect()
+
+    def test_ipv4_only_without_ipv4_seed_fails(self):
+        c = aerospike_py.client({"hosts": [("::1", 3000)], "address_family": "ipv4"})
+        with pytest.raises(aerospike_py.ClusterError, match="No seed hosts"):
+            c.connect()
+
+
+class TestConfigValidation:
+    """Whole-config validation on `connect()`, reported as one `ParamError`."""
+
+    def test_unknown_key_suggests_closest(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "max_conn_per_node": 10})
+        with pytest.raises(aerospike_py.ParamError, match="did you mean 'max_conns_per_node'"):
+            c.connect()
+
+    def test_problems_are_aggregated(self):
+        c = aerospike_py.client({"hots": [("127.0.0.1", 3000)], "timeout": "1s", "password": "pw"})
+        with pytest.raises(aerospike_py.ParamError) as exc_info:
+            c.connect()
+        message = str(exc_info.value)
+        assert "4 problems" in message
+        assert "unknown key 'hots'" in message
+        assert "timeout must be" in message
+        assert "password requires user" in message
+
+    def test_nested_unknown_key_rejected(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "rust_runtime": {"dedicatd": True}})
+        with pytest.raises(aerospike_py.ParamError, match="rust_runtime.dedicatd"):
+            c.connect()
+
+    def test_bool_is_not_an_int(self):
+        c = aerospike_py.client({**DUMMY_CONFIG, "max_conns_per_node": True})
+        with pytest.raises(aerospike_py.ParamError, match="max_conns_per_node must be a non-negative int, got bool"):
+            c.connect()
+
+    async def test_async_client_validates(self):
+        c = aerospike_py.AsyncClient({**DUMMY_CONFIG, "policies": {"read": {"timeout": 5000}}})
+        with pytest.raises(aerospike_py.ParamError, match="unknown key 'policies'"):
+            await c.connect()
+
+
+class TestBatchReadChunkedArgs:
+    """`batch_read_chunked` argument validation (raised before any network I/O)."""
+
+    @pytest.mark.parametrize("kwargs", [{"chunk_size": 0}, {"concurrency": 0}])
+    async def test_zero_rejected(self, kwargs):
+        c = aerospike_py.AsyncClient(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.InvalidArgError, match="at least 1"):
+            await c.batch_read_chunked([("test", "demo", 1)], **kwargs)
+
+
+class TestBulkPutArgs:
+    """`bulk_put` argument validation (raised before any network I/O)."""
+
+    @pytest.mark.parametrize("kwargs", [{"chunk_size": 0}, {"concurrency": 0}])
+    async def test_zero_rejected(self, kwargs):
+        c = aerospike_py.AsyncClient(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ParamError, match="at least 1"):
+            await c.bulk_put([{"_key": 1}], "test", "demo", **kwargs)
+
+    def test_key_fn_must_be_callable(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ParamError, match="key_fn must be callable"):
+            c.bulk_put([{"a": 1}], "test", "demo", key_fn="id")
+
+    def test_disconnected_client_raises(self):
+        c = aerospike_py.client(DUMMY_CONFIG)
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.bulk_put([{"_key": 1, "a": 1}], "test", "demo")
+
+
+class TestAsyncCancellation:
+    """Cancelling `connect()` must not leave the client stuck in the connecting state."""
+
+    async def test_cancelled_connect_allows_retry(self):
+        c = aerospike_py.AsyncClient(DUMMY_CONFIG)
+        task = asyncio.create_task(c.connect())
+        await asyncio.sleep(0)
+        task.cancel()
+        with contextlib.suppress(asyncio.CancelledError, aerospike_py.AerospikeError):
+            await task
+        assert c.is_connected() is False
+        # The native future is dropped on the Tokio side shortly after the cancel.
+        for _ in range(100):
+            with pytest.raises(aerospike_py.AerospikeError) as exc_info:
+                await c.connect()
+            if "already connecting" not in str(exc_info.value):
+                break
+            await asyncio.sleep(0.01)
+        assert "already connecting" not in str(exc_info.value)
@@ -0,0 +1,86 @@
+"""Unit tests for aerospike_py._compat (AsyncClient under non-asyncio loops)."""
+
+import asyncio
+import time
+
+import pytest
+
+import aerospike_py
+from aerospike_py._compat import compat_from_config, run_on_asyncio
+from tests import DUMMY_CONFIG
+
+
+def _make(compat):
+    @run_on_asyncio
+    class Fake:
+        def __init__(self):
+            self._compat = compat_from_config({"compat": compat})
+
+        async def get(self, key):
+            # Needs a running asyncio loop, like the native awaitables.
+            await asyncio.sleep(0)
+            return key, asyncio.get_running_loop()
+
+    return Fake()
+
+
+class TestCompatConfig:
+    def test_default_runs_directly(self):
+        assert compat_from_config({}) is None
+        assert compat_from_config({"compat": "asyncio"}) is None
+
+    def test_anyio_returns_shared_bridge(self):
+        assert compat_from_config({"compat": "anyio"}) is compat_from_config({"compat": "anyio"})
+
+    def test_rejects_unknown_mode(self):
+        with pytest.raises(aerospike_py.ParamError, match="compat"):
+            compat_from_config({"compat": "gevent"})
+
+    def test_async_client_rejects_unknown_mode(self):
+        with pytest.raises(aerospike_py.ParamError, match="compat"):
+            aerospike_py.AsyncClient({**DUMMY_CONFIG, "compat": "curio"})
+
+
+class TestRunOnAsyncio:
+    def test_asyncio_caller_runs_on_its_own_loop(self):
+        async def main():
+            key, loop = await _make("anyio").get("k")
+            return key, loop is asyncio.get_running_loop()
+
+        assert asyncio.run(main()) == ("k", True)
+
+    def test_trio_caller_runs_on_bridge_loop(self):
+        trio = pytest.importorskip("trio")
+
+        async def main():
+            return await _make("anyio").get("k")
+
+        key, loop = trio.run(main)
+        assert key == "k"
+        assert loop.is_running()
+
+    def test_trio_cancellation_cancels_call(self):
+        trio = pytest.importorskip("trio")
+        cancelled = []
+
+        @run_on_asyncio
+        class Slow:
+            _compat = compat_from_config({"compat": "anyio"})
+
+            async def get(self):
+                try:
+                    await asyncio.sleep(10)
+                except asyncio.CancelledError:
+                    cancelled.append(True)
+                    raise
+
+        async def main():
+            with trio.move_on_after(0.1):
+                await Slow().get()
+
+        trio.run(main)
+        for _ in range(50):
+            if cancelled:
+                break
+            time.sleep(0.01)
+        assert cancelled == [True]
@@ -42,6 +42,15 @@ class TestContextManager:
         result = c.__exit__(None, None, None)
         assert result is False
 
+    def test_native_client_is_context_manager(self):
+        """The PyO3 Client class supports ``with`` without the Python wrapper."""
+        from aerospike_py._aerospike import Client as NativeClient
+
+        c = NativeClient(DUMMY_CONFIG)
+        with c as entered:
+            assert entered is c
+        assert not c.is_connected()
+
 
 class TestAsyncClientInitFailure:
     def test_getattr_after_bad_init_no_recursion(self):
@@ -0,0 +1,50 @@
+"""Unit tests for DataFrame construction from native column buffers (no server required)."""
+
+import numpy as np
+import pytest
+
+from aerospike_py.dataframe import _columns_to_df
+
+pd = pytest.importorskip("pandas")
+
+
+class TestColumnsToDf:
+    def test_plain_columns_and_key_index(self):
+        raw = (
+            ["a", "b"],
+            {
+                "age": (np.array([30, 40], dtype="int64"), None),
+                "score": (np.array([1.5, np.nan]), None),
+                "name": (["x", None], None),
+            },
+        )
+        df = _columns_to_df(raw)
+
+        assert list(df.columns) == ["age", "score", "name"]
+        assert df.index.name == "key"
+        assert list(df.index) == ["a", "b"]
+        assert df["age"].dtype == np.int64
+        assert np.isnan(df.loc["b", "score"])
+        assert df.loc["b", "name"] is None
+
+    def test_masked_int_is_nullable(self):
+        values = np.array([1, 0, 3], dtype="int64")
+        mask = np.array([False, True, False])
+        df = _columns_to_df((None, {"n": (values, mask)}))
+
+        assert str(df["n"].dtype) == "Int64"
+        assert df["n"].isna().tolist() == [False, True, False]
+        assert isinstance(df.index, pd.RangeIndex)
+
+    def test_masked_bool_is_nullable(self):
+        values = np.array([True, False])
+        mask = np.array([False, True])
+        df = _columns_to_df((None, {"flag": (values, mask)}))
+
+        assert str(df["flag"].dtype) == "boolean"
+        assert df["flag"].isna().tolist() == [False, True]
+
+    def test_no_columns(self):
+        df = _columns_to_df((["a"], {}))
+        assert len(df) == 1
+        assert df.columns.empty
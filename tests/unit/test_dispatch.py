@@ -0,0 +1,45 @@
+"""Unit tests for aerospike_py._dispatch (one wrapper per client method)."""
+
+import pytest
+
+import aerospike_py
+
+
+class TestDispatchHooks:
+    def test_retry_after_relogin_is_timed_once(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append}, failures=1)
+        assert client.get("k") == "k"
+        assert (client.calls, client.relogins) == (2, 1)
+        assert [op["operation"] for op in seen] == ["get"]
+
+    def test_failed_relogin_retry_is_still_timed(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append}, failures=2)
+        with pytest.raises(aerospike_py.SessionExpired):
+            client.get("k")
+        assert len(seen) == 1
+
+    async def test_async_retry_after_relogin_is_timed_once(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append}, asynchronous=True, failures=1)
+        assert await client.get("k") == "k"
+        assert (client.calls, client.relogins) == (2, 1)
+        assert len(seen) == 1
+
+    def test_rejected_params_are_not_timed_or_sent(self, make_client):
+        seen = []
+        client = make_client({"strict_params": True, "slow_log_ms": 0, "slow_log_callback": seen.append})
+        with pytest.raises(aerospike_py.InvalidArgError):
+            client.get("k", policy={"total_timout": 100})
+        assert (client.calls, seen) == (0, [])
+
+    # dispatch wrapper -> catch_unexpected wrapper -> method body; AsyncClient
+    # coroutine methods also get the run_on_asyncio bridge on top.
+    @pytest.mark.parametrize(("cls", "layers"), [(aerospike_py.Client, 2), (aerospike_py.AsyncClient, 3)])
+    def test_client_methods_are_wrapped_once(self, cls, layers):
+        method = cls.get
+        assert method.__name__ == "get"
+        for _ in range(layers):
+            method = method.__wrapped__
+        assert not hasattr(method, "__wrapped__")
@@ -158,6 +158,12 @@ class TestNamedTupleUnpacking:
         assert gen == 1
         assert ttl == 0
 
+    def test_record_metadata_void_time(self):
+        meta = RecordMetadata(gen=1, ttl=300, void_time=1_700_000_300)
+        assert meta.void_time == 1_700_000_300
+        assert tuple(meta) == (1, 300)
+        assert RecordMetadata(gen=1, ttl=0).void_time == 0
+
     def test_exists_result_unpacking(self):
         key = AerospikeKey("ns", "set", "pk", b"\x00" * 20)
         meta = RecordMetadata(gen=3, ttl=100)
@@ -516,7 +522,7 @@ class TestHostParsing:
     def test_invalid_port_in_string_host_raises_error(self):
         """String host with non-numeric port should raise ValueError on connect()."""
         c = aerospike_py.client({"hosts": ["192.168.1.1:abc"]})
-        with pytest.raises(ValueError, match="Invalid port"):
+        with pytest.raises(aerospike_py.ParamError, match="Invalid port"):
             c.connect()
 
     def test_string_host_without_port_defaults_to_3000(self):
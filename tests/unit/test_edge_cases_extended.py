@@ -106,6 +106,13 @@ class TestInvalidPolicyValueTypes:
         with pytest.raises((TypeError, aerospike_py.ClientError)):
             c.get(key, policy={policy_key: invalid_value})
 
+    @pytest.mark.parametrize("make_client", [_make_client, _make_async_client], ids=["sync", "async"])
+    async def test_batch_allow_partial_results_must_be_bool(self, make_client):
+        """allow_partial_results is checked before the connection."""
+        c = make_client()
+        with pytest.raises(aerospike_py.ParamError, match="allow_partial_results must be a bool"):
+            await invoke(c, "batch_read", [("test", "demo", "k")], policy={"allow_partial_results": 0})
+
 
 class TestPolicyOutOfRangeDefaults:
     """Out-of-range integer policy values fall back to defaults silently.
@@ -147,16 +154,35 @@ class TestKeyEdgeCases:
         with pytest.raises((aerospike_py.ClientError, aerospike_py.AerospikeError, ValueError)):
             c.get(("", "set", "key"))
 
+    def test_put_key_with_empty_namespace(self):
+        """put() rejects an empty namespace before connecting."""
+        c = _make_client()
+        with pytest.raises(aerospike_py.ParamError, match="Namespace must not be empty"):
+            c.put(("", "set", "key"), {"a": 1})
+
+    def test_put_key_with_too_long_namespace(self):
+        """put() rejects a namespace over 31 bytes before connecting."""
+        c = _make_client()
+        with pytest.raises(aerospike_py.ParamError, match="31-byte limit"):
+            c.put(("n" * 32, "set", "key"), {"a": 1})
+
+    @pytest.mark.parametrize("set_name", ["a:b", "a;b", "a\x00b", "s" * 64])
+    def test_put_key_with_invalid_set_name(self, set_name):
+        """put() rejects set names with info delimiters or over 63 bytes."""
+        c = _make_client()
+        with pytest.raises(aerospike_py.ParamError, match="Set name"):
+            c.put(("test", set_name, "key"), {"a": 1})
+
     def test_key_with_empty_set(self):
         """Key with empty set string should parse but fail on unconnected client."""
         c = _make_client()
         with pytest.raises(aerospike_py.ClientError):
             c.get(("ns", "", "key"))
 
-    def test_key_with_none_set_raises(self):
-        """Key with None set should raise TypeError because set_name expects a string."""
+    def test_key_with_none_set(self):
+        """Key with None set is the null set, same as an empty string."""
         c = _make_client()
-        with pytest.raises((TypeError, aerospike_py.ClientError)):
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
             c.get(("ns", None, "key"))
 
     def test_key_with_integer_user_key(self):
@@ -207,6 +233,27 @@ class TestKeyEdgeCases:
         with pytest.raises(aerospike_py.ClientError):
             c.get(("test", "demo", 2**63 - 1))
 
+    @pytest.mark.parametrize("pk", [2**63, -(2**63) - 1, 2**100])
+    def test_put_key_with_integer_outside_i64_raises(self, pk):
+        """Integer user keys outside the i64 range raise ParamError, not OverflowError."""
+        c = _make_client()
+        with pytest.raises(aerospike_py.ParamError, match="64-bit signed range"):
+            c.put(("test", "demo", pk), {"a": 1})
+
+    @pytest.mark.parametrize("pk", [2**63 - 1, -(2**63), 0, -1])
+    def test_put_key_with_integer_at_i64_edges(self, pk):
+        """Integer user keys at the i64 edges are accepted."""
+        c = _make_client()
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.put(("test", "demo", pk), {"a": 1})
+
+    @pytest.mark.parametrize("pk", [bytearray(b"\x01\x02"), memoryview(b"\x01\x02")])
+    def test_put_key_with_bytes_like_user_key(self, pk):
+        """bytearray and memoryview user keys are accepted like bytes."""
+        c = _make_client()
+        with pytest.raises(aerospike_py.ClientError, match="not connected"):
+            c.put(("test", "demo", pk), {"a": 1})
+
 
 # ═══════════════════════════════════════════════════════════════════
 # 4. Bin name edge cases
@@ -217,12 +264,19 @@ class TestBinNameEdgeCases:
     """Test bin name edge cases on put operations."""
 
     def test_very_long_bin_name(self):
-        """Bin name exceeding 15 bytes should raise ValueError at client level."""
+        """Bin name exceeding 15 bytes should raise BinNameError at client level."""
         c = _make_client()
         long_name = "a" * 20
-        with pytest.raises(ValueError, match="exceeds the 15-byte limit"):
+        with pytest.raises(aerospike_py.BinNameError, match="exceeds the 15-byte limit"):
             c.put(("test", "demo", "key1"), {long_name: "value"})
 
+    def test_too_many_bins(self):
+        """A put with more bins than a record can hold is rejected before sending."""
+        c = _make_client()
+        bins = {str(i): i for i in range(32_768)}
+        with pytest.raises(aerospike_py.ParamError, match="at most 32767 bins"):
+            c.put(("test", "demo", "key1"), bins)
+
     def test_bin_name_exactly_15_chars(self):
         """Bin name with exactly 15 chars is valid (Aerospike max)."""
         c = _make_client()
@@ -2,6 +2,7 @@
 
 import pytest
 
+import aerospike_py
 from aerospike_py import exp
 
 # ── Parametrized value constructor tests ────────────────────────────────
@@ -320,6 +321,85 @@ class TestExpPatternMatching:
         assert e["__expr__"] == "geo_compare"
 
 
+# ── List CDT tests ──────────────────────────────────────────────────
+
+
+class TestExpListReads:
+    def test_list_size(self):
+        e = exp.list_size(exp.list_bin("tags"))
+        assert e["__expr__"] == "list_size"
+        assert e["bin"]["__expr__"] == "list_bin"
+        assert e["ctx"] == []
+
+    def test_list_get_by_index(self):
+        e = exp.list_get_by_index(
+            aerospike_py.LIST_RETURN_VALUE, exp.EXP_TYPE_INT, exp.int_val(0), exp.list_bin("scores")
+        )
+        assert e["__expr__"] == "list_get_by_index"
+        assert e["return_type"] == aerospike_py.LIST_RETURN_VALUE
+        assert e["value_type"] == exp.EXP_TYPE_INT
+        assert e["index"]["__expr__"] == "int_val"
+
+    def test_list_get_by_value_range_open_end(self):
+        e = exp.list_get_by_value_range(
+            aerospike_py.LIST_RETURN_COUNT, exp.int_val(10), None, exp.list_bin("scores")
+        )
+        assert e["value_begin"]["val"] == 10
+        assert e["value_end"] is None
+
+    def test_ctx(self):
+        e = exp.list_size(
+            exp.list_bin("matrix"), ctx=[exp.ctx_list_index(1), exp.ctx_map_key("a")]
+        )
+        assert e["ctx"] == [
+            {"__ctx__": "list_index", "val": 1},
+            {"__ctx__": "map_key", "val": "a"},
+        ]
+
+
+class TestExpMapReads:
+    def test_map_size(self):
+        e = exp.map_size(exp.map_bin("attrs"))
+        assert e["__expr__"] == "map_size"
+        assert e["ctx"] == []
+
+    def test_map_get_by_key(self):
+        e = exp.map_get_by_key(
+            aerospike_py.MAP_RETURN_VALUE,
+            exp.EXP_TYPE_STRING,
+            exp.string_val("tier"),
+            exp.map_bin("attrs"),
+            ctx=[exp.ctx_map_key("profile")],
+        )
+        assert e["__expr__"] == "map_get_by_key"
+        assert e["key"]["val"] == "tier"
+        assert e["ctx"] == [{"__ctx__": "map_key", "val": "profile"}]
+
+    def test_map_get_by_value_range_open_begin(self):
+        e = exp.map_get_by_value_range(aerospike_py.MAP_RETURN_COUNT, None, exp.int_val(5), exp.map_bin("m"))
+        assert e["value_begin"] is None
+        assert e["value_end"]["val"] == 5
+
+
+class TestExpBitAndHllReads:
+    def test_bit_count(self):
+        e = exp.bit_count(exp.int_val(0), exp.int_val(8), exp.blob_bin("flags"))
+        assert e["__expr__"] == "bit_count"
+        assert e["bit_offset"]["val"] == 0
+        assert e["bit_size"]["val"] == 8
+        assert e["bin"]["__expr__"] == "blob_bin"
+
+    def test_bit_get_int(self):
+        e = exp.bit_get_int(exp.int_val(0), exp.int_val(16), True, exp.blob_bin("flags"))
+        assert e["signed"] is True
+
+    def test_hll_may_contain(self):
+        e = exp.hll_may_contain(exp.list_val(["a"]), exp.hll_bin("visitors"))
+        assert e["__expr__"] == "hll_may_contain"
+        assert e["list"]["__expr__"] == "list_val"
+        assert e["bin"]["__expr__"] == "hll_bin"
+
+
 # ── Control flow tests ──────────────────────────────────────────────
 
 
@@ -333,6 +413,37 @@ class TestExpControlFlow:
         assert e["__expr__"] == "cond"
         assert len(e["exprs"]) == 3
 
+    def test_cond_pairs(self):
+        is_minor = exp.lt(exp.int_bin("age"), exp.int_val(18))
+        e = exp.cond((is_minor, exp.string_val("minor")), exp.string_val("adult"))
+        assert e["__expr__"] == "cond"
+        assert e["cases"] == [(is_minor, exp.string_val("minor"))]
+        assert e["default"] == exp.string_val("adult")
+        exp.compile(e)
+
+    @pytest.mark.parametrize(
+        "args",
+        [
+            (exp.bool_val(True), exp.int_val(1)),
+            ((exp.bool_val(True), exp.int_val(1)),),
+            ((exp.bool_val(True),), exp.int_val(0)),
+        ],
+    )
+    def test_cond_arity_rejected(self, args):
+        with pytest.raises(aerospike_py.ParamError, match="cond"):
+            exp.compile(exp.cond(*args))
+
+    def test_cond_condition_must_be_bool(self):
+        e = exp.cond((exp.int_bin("age"), exp.int_val(1)), exp.int_val(0))
+        with pytest.raises(aerospike_py.ParamError, match=r"cond conditions must be boolean, got int_bin"):
+            exp.compile(e)
+
+    def test_let_requires_defs_then_scope(self):
+        with pytest.raises(aerospike_py.ParamError, match="let_"):
+            exp.compile(exp.let_(exp.gt(exp.var("x"), exp.int_val(0))))
+        with pytest.raises(aerospike_py.ParamError, match="scope expression"):
+            exp.compile(exp.let_(exp.def_("x", exp.int_val(1)), exp.def_("y", exp.int_val(2))))
+
     def test_let_and_var(self):
         e = exp.let_(
             exp.def_("x", exp.int_bin("count")),
@@ -439,6 +550,46 @@ class TestExpComplexExpressions:
         assert inner_not["expr"]["__expr__"] == "eq"
 
 
+# ── Compiled expression tests ───────────────────────────────────────
+
+
+class TestExpCompile:
+    def test_compile_returns_compiled_expression(self):
+        compiled = exp.compile(exp.ge(exp.int_bin("age"), exp.int_val(21)))
+        assert isinstance(compiled, aerospike_py.CompiledExpression)
+        assert repr(compiled) == "CompiledExpression(ge)"
+
+    def test_compiled_nests_in_dict_tree(self):
+        compiled = exp.compile(exp.ge(exp.int_bin("age"), exp.int_val(21)))
+        assert isinstance(exp.compile(exp.not_(compiled)), aerospike_py.CompiledExpression)
+
+    def test_compile_rejects_non_expression(self):
+        with pytest.raises(aerospike_py.ParamError):
+            exp.compile(42)
+        with pytest.raises(aerospike_py.ParamError, match="__expr__"):
+            exp.compile({"op": "eq"})
+
+
+# ── Type checking tests ─────────────────────────────────────────────
+
+
+class TestExpTypeChecking:
+    def test_comparison_mismatch_reports_path(self):
+        e = exp.and_(exp.key_exists(), exp.eq(exp.string_bin("name"), exp.int_val(1)))
+        match = r"at and\.exprs\[1\]: eq compares string_bin \(STRING\) with int_val"
+        with pytest.raises(aerospike_py.ParamError, match=match):
+            exp.compile(e)
+
+    def test_mixed_int_float_arithmetic_rejected(self):
+        e = exp.gt(exp.num_mul(exp.float_bin("a"), exp.int_bin("b")), exp.float_val(1.0))
+        with pytest.raises(aerospike_py.ParamError, match="num_mul mixes"):
+            exp.compile(e)
+
+    def test_matching_types_accepted(self):
+        e = exp.gt(exp.num_mul(exp.float_bin("a"), exp.to_float(exp.int_bin("b"))), exp.float_val(1.0))
+        exp.compile(e)
+
+
 # ── Invalid op tests ───────────────────────────────────────────────
 
 
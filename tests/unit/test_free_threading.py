@@ -0,0 +1,47 @@
+"""Free-threaded CPython (3.13t+) smoke tests (no server required)."""
+
+import sys
+import threading
+
+import pytest
+
+import aerospike_py
+from tests import DUMMY_CONFIG
+
+free_threaded = pytest.mark.skipif(
+    not hasattr(sys, "_is_gil_enabled") or sys._is_gil_enabled(),
+    reason="requires a free-threaded interpreter with the GIL disabled",
+)
+
+
+@free_threaded
+def test_import_keeps_gil_disabled():
+    """The native module is declared gil_used=false, so import must not re-enable the GIL."""
+    assert aerospike_py._aerospike is not None
+    assert not sys._is_gil_enabled()
+
+
+def test_shared_client_from_threads():
+    """Lifecycle and module-level calls on one client from many threads don't crash."""
+    c = aerospike_py.client(DUMMY_CONFIG)
+    errors: list[BaseException] = []
+    barrier = threading.Barrier(8)
+
+    def worker():
+        try:
+            barrier.wait()
+            for _ in range(200):
+                assert not c.is_connected()
+                aerospike_py.set_blob_view_threshold(1024)
+                aerospike_py.get_blob_view_threshold()
+        except BaseException as e:
+            errors.append(e)
+
+    threads = [threading.Thread(target=worker) for _ in range(8)]
+    for t in threads:
+        t.start()
+    for t in threads:
+        t.join()
+    aerospike_py.set_blob_view_threshold(0)
+
+    assert errors == []
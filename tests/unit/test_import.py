@@ -68,6 +68,10 @@ def test_client_not_connected_raises():
         ("OPERATOR_PREPEND", 10),
         ("OPERATOR_TOUCH", 11),
         ("OPERATOR_DELETE", 12),
+        # Raw bin particle types
+        ("AS_BYTES_INTEGER", 1),
+        ("AS_BYTES_BLOB", 4),
+        ("AS_BYTES_LIST", 20),
         # Status codes
         ("AEROSPIKE_OK", 0),
         ("AEROSPIKE_ERR_RECORD_NOT_FOUND", 2),
@@ -90,9 +94,14 @@ def test_constants(const_name, expected_value):
         (aerospike_py.ServerError, aerospike_py.AerospikeError),
         (aerospike_py.RecordError, aerospike_py.AerospikeError),
         (aerospike_py.ClusterError, aerospike_py.AerospikeError),
+        (aerospike_py.CircuitOpenError, aerospike_py.ClusterError),
         (aerospike_py.AerospikeTimeoutError, aerospike_py.AerospikeError),
         (aerospike_py.TimeoutError, aerospike_py.AerospikeError),
         (aerospike_py.InvalidArgError, aerospike_py.AerospikeError),
+        (aerospike_py.ParamError, aerospike_py.ClientError),
+        (exception.ParamError, exception.ClientError),
+        (aerospike_py.ForkError, aerospike_py.ClientError),
+        (exception.ForkError, exception.ClientError),
         # Record-level subclasses
         (aerospike_py.RecordNotFound, aerospike_py.RecordError),
         (aerospike_py.RecordExistsError, aerospike_py.RecordError),
@@ -103,6 +112,17 @@ def test_constants(const_name, expected_value):
         (aerospike_py.BinNotFound, aerospike_py.RecordError),
         (aerospike_py.BinTypeError, aerospike_py.RecordError),
         (aerospike_py.FilteredOut, aerospike_py.RecordError),
+        (aerospike_py.RecordBusy, aerospike_py.RecordError),
+        (aerospike_py.ElementNotFoundError, aerospike_py.RecordError),
+        (aerospike_py.ElementExistsError, aerospike_py.RecordError),
+        # Server-level subclasses
+        (aerospike_py.AlwaysForbidden, aerospike_py.ServerError),
+        (aerospike_py.UnsupportedFeature, aerospike_py.ServerError),
+        (aerospike_py.DeviceOverload, aerospike_py.ServerError),
+        (aerospike_py.ForbiddenError, aerospike_py.ServerError),
+        (aerospike_py.OpNotApplicable, aerospike_py.ServerError),
+        (aerospike_py.LostConflict, aerospike_py.ServerError),
+        (aerospike_py.QuotaExceeded, aerospike_py.AdminError),
         # Exception module classes
         (exception.RecordNotFound, aerospike_py.RecordError),
         (exception.RecordExistsError, aerospike_py.RecordError),
@@ -112,7 +132,17 @@ def test_constants(const_name, expected_value):
         (exception.IndexNotFound, exception.IndexError),
         (exception.QueryAbortedError, exception.QueryError),
         (exception.AdminError, aerospike_py.ServerError),
+        (exception.SessionExpired, exception.AdminError),
         (exception.UDFError, aerospike_py.ServerError),
+        # RetryableError mixin
+        (aerospike_py.AerospikeTimeoutError, aerospike_py.RetryableError),
+        (aerospike_py.ClusterError, aerospike_py.RetryableError),
+        (exception.CircuitOpenError, exception.RetryableError),
+        (aerospike_py.BackpressureError, aerospike_py.RetryableError),
+        (aerospike_py.BackpressureError, aerospike_py.ClientError),
+        (aerospike_py.RecordBusy, aerospike_py.RetryableError),
+        (aerospike_py.DeviceOverload, aerospike_py.RetryableError),
+        (exception.ForbiddenError, exception.RetryableError),
     ],
 )
 def test_exception_hierarchy(child, parent):
@@ -126,6 +156,67 @@ def test_exception_aliases():
     assert exception.AerospikeIndexError is exception.IndexError
     assert exception.RecordNotFound is aerospike_py.RecordNotFound
     assert exception.IndexNotFound is aerospike_py.IndexNotFound
+    assert aerospike_py.InvalidArgError is aerospike_py.ParamError
+    assert exception.InvalidArgError is exception.ParamError
+
+
+@pytest.mark.parametrize(
+    "cls,code",
+    [
+        (aerospike_py.ClientError, -1),
+        (aerospike_py.ParamError, -2),
+        (aerospike_py.AerospikeTimeoutError, 9),
+        (aerospike_py.CircuitOpenError, -8),
+        (aerospike_py.RecordNotFound, 2),
+        (aerospike_py.RecordExistsError, 5),
+        (exception.RecordGenerationError, 3),
+        (exception.IndexNotFound, 201),
+        (aerospike_py.RecordBusy, 14),
+        (exception.DeviceOverload, 18),
+        (exception.QuotaExceeded, 83),
+        (aerospike_py.ServerError, None),
+    ],
+)
+def test_exception_attributes(cls, code):
+    """Exceptions raised from Python carry class-level attribute defaults."""
+    e = cls("boom")
+    assert e.code == code
+    assert e.msg == "boom"
+    assert e.in_doubt is False
+    assert e.key is None
+    assert e.bin is None
+
+
+def test_not_connected_error_has_code():
+    c = aerospike_py.client(DUMMY_CONFIG)
+    with pytest.raises(aerospike_py.ClientError) as exc_info:
+        c.get(("test", "demo", "key1"))
+    assert exc_info.value.code == -1
+    assert exc_info.value.msg == str(exc_info.value)
+
+
+
+@pytest.mark.parametrize(
+    "exc,expected",
+    [
+        (aerospike_py.AerospikeTimeoutError("t"), True),
+        (aerospike_py.RecordBusy("hot"), True),
+        (aerospike_py.BackpressureError("full"), True),
+        (aerospike_py.RecordNotFound("missing"), False),
+        (aerospike_py.InvalidArgError("bad"), False),
+        (aerospike_py.RecordError("plain"), False),
+        (ValueError("not aerospike"), False),
+    ],
+)
+def test_is_retryable(exc, expected):
+    assert aerospike_py.is_retryable(exc) is expected
+
+
+def test_is_retryable_uses_code():
+    e = aerospike_py.ServerError("AEROSPIKE_ERR (11): partition unavailable")
+    e.code = 11
+    assert aerospike_py.is_retryable(e)
+    assert not issubclass(aerospike_py.ServerError, aerospike_py.RetryableError)
 
 
 # ── Unconnected client operations tests (parametrized) ──────────────
@@ -150,6 +241,8 @@ def test_exception_aliases():
         ("batch_operate", ([("test", "demo", "key1")], [{"op": 1, "bin": "a", "val": 1}])),
         ("batch_remove", ([("test", "demo", "key1")],)),
         ("info_all", ("status",)),
+        ("job_info", (1, aerospike_py.JOB_SCAN)),
+        ("scan_info", (1,)),
     ],
 )
 def test_client_not_connected_operations(method, args):
@@ -170,11 +263,16 @@ def test_sync_async_method_parity():
         m for m in dir(_NativeAsyncClient) if not m.startswith("_") and callable(getattr(_NativeAsyncClient, m))
     }
 
-    # query() is sync-only (returns PyQuery object)
-    sync_only_expected = {"query"}
+    # query() is sync-only (returns PyQuery object); pipeline() is sync-only
+    # (AsyncClient users get the same effect from asyncio.gather). scan(),
+    # scan_info() and execute() wrap query() / job_info() / bind_query() in
+    # Python on both clients.
+    sync_only_expected = {"query", "scan", "scan_info", "execute", "pipeline"}
+    # batch_read_chunked() fans sub-batches out on the event loop
+    async_only_expected = {"batch_read_chunked"}
 
     sync_extra = sync_methods - async_methods - sync_only_expected
-    async_extra = async_methods - sync_methods
+    async_extra = async_methods - sync_methods - async_only_expected
 
     assert not sync_extra, f"Methods in Client but missing from AsyncClient: {sync_extra}"
     assert not async_extra, f"Methods in AsyncClient but missing from Client: {async_extra}"
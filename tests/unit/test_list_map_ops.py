@@ -2,11 +2,13 @@
 
 import pytest
 
+import aerospike_py
 from aerospike_py import list_operations, map_operations
 from aerospike_py.list_operations import (
     list_append,
     list_append_items,
     list_clear,
+    list_create,
     list_get,
     list_get_by_index,
     list_get_by_rank,
@@ -27,6 +29,7 @@ from aerospike_py.list_operations import (
 )
 from aerospike_py.map_operations import (
     map_clear,
+    map_create,
     map_decrement,
     map_get_by_index,
     map_get_by_key,
@@ -91,6 +94,10 @@ class TestListOperations:
         assert op["op"] == 1030
         assert op["val"] == 2
 
+    def test_list_create(self):
+        op = list_create("mybin", 1, pad=True)
+        assert op == {"op": 1032, "bin": "mybin", "val": 1, "pad": True}
+
     def test_list_append_with_policy(self):
         op = list_append("mybin", "val", policy={"order": 1, "flags": 0})
         assert op["list_policy"]["order"] == 1
@@ -220,6 +227,21 @@ class TestMapOperations:
         for k, v in extra.items():
             assert op[k] == v
 
+    def test_map_create(self):
+        op = map_create("mybin", 1)
+        assert op == {"op": 2028, "bin": "mybin", "val": 1}
+
+    def test_relative_range_ops(self):
+        op = map_operations.map_get_by_value_rel_rank_range("lb", 1200, -5, 8, count=10)
+        assert op == {"op": 2031, "bin": "lb", "val": 1200, "rank": -5, "return_type": 8, "count": 10}
+        op = map_operations.map_remove_by_key_rel_index_range("ev", "2024", 0, 0)
+        assert op == {"op": 2030, "bin": "ev", "map_key": "2024", "index": 0, "return_type": 0}
+        assert map_operations.map_get_by_key_rel_index_range("ev", "a", 1, 6)["op"] == 2029
+        assert map_operations.map_remove_by_value_rel_rank_range("lb", 1, 0, 0)["op"] == 2032
+        op = list_operations.list_get_by_value_rel_rank_range("s", 500, -2, 7, count=4)
+        assert op == {"op": 1033, "bin": "s", "val": 500, "rank": -2, "return_type": 7, "count": 4}
+        assert list_operations.list_remove_by_value_rel_rank_range("s", 500, 0, 5)["op"] == 1034
+
     def test_map_put_with_policy(self):
         op = map_put("mybin", "k", "v", policy={"order": 1, "write_mode": 0})
         assert op["map_policy"]["order"] == 1
@@ -358,3 +380,10 @@ class TestModuleAccess:
         assert hasattr(map_operations, "map_put")
         assert hasattr(map_operations, "map_size")
         assert hasattr(map_operations, "map_clear")
+
+    def test_operators_dict_matches_helpers(self):
+        assert aerospike_py.OPERATORS["OPERATOR_DELETE"] == aerospike_py.OPERATOR_DELETE
+        assert aerospike_py.OPERATORS["OP_MAP_GET_BY_KEY"] == map_get_by_key("m", "k", 1)["op"]
+        assert aerospike_py.OPERATORS["OP_LIST_SORT"] == list_sort("l")["op"]
+        assert list_sort("l", aerospike_py.LIST_SORT_DESCENDING)["val"] == 1
+        assert len(set(aerospike_py.OPERATORS.values())) == len(aerospike_py.OPERATORS)
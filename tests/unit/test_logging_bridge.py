@@ -0,0 +1,67 @@
+"""Unit tests for the Rust -> Python logging bridge (no Aerospike server required)."""
+
+import logging
+
+import pytest
+
+import aerospike_py
+
+
+@pytest.fixture
+def tracing_log(monkeypatch, caplog):
+    """Emit one INFO record from the ``_aerospike::tracing`` Rust target."""
+    monkeypatch.setenv("OTEL_SDK_DISABLED", "true")
+    caplog.set_level(logging.INFO, logger="_aerospike")
+
+    def emit():
+        caplog.clear()
+        aerospike_py.init_tracing()
+        aerospike_py.shutdown_tracing()
+        assert aerospike_py.flush_logs()
+        return [r for r in caplog.records if r.name == "_aerospike.tracing"]
+
+    return emit
+
+
+class TestLoggingBridgeExports:
+    @pytest.mark.parametrize("name", ["set_log_target_level", "flush_logs"])
+    def test_exported(self, name):
+        assert name in aerospike_py.__all__
+        assert callable(getattr(aerospike_py, name))
+
+
+class TestLoggingBridge:
+    def test_records_use_dotted_logger_names(self, tracing_log):
+        records = tracing_log()
+        assert records, "expected a record from the _aerospike.tracing logger"
+        assert all(r.levelno == logging.INFO for r in records)
+
+    def test_target_filter_drops_records_in_rust(self, tracing_log):
+        aerospike_py.set_log_target_level("_aerospike.tracing", aerospike_py.LOG_LEVEL_WARN)
+        try:
+            assert tracing_log() == []
+        finally:
+            aerospike_py.set_log_target_level("_aerospike.tracing", aerospike_py.LOG_LEVEL_TRACE)
+        assert tracing_log()
+
+    def test_target_level_sets_python_logger(self):
+        name = "aerospike_core.cluster"
+        previous = logging.getLogger(name).level
+        try:
+            aerospike_py.set_log_target_level("aerospike_core::cluster", aerospike_py.LOG_LEVEL_DEBUG)
+            assert logging.getLogger(name).level == logging.DEBUG
+        finally:
+            aerospike_py.set_log_target_level(name, aerospike_py.LOG_LEVEL_TRACE)
+            logging.getLogger(name).setLevel(previous)
+
+    def test_invalid_level_raises(self):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            aerospike_py.set_log_target_level("aerospike_core", 7)
+
+    def test_flush_without_pending_records(self):
+        assert aerospike_py.flush_logs(timeout=0.5) is True
+
+    @pytest.mark.parametrize("timeout", [float("inf"), 1e30])
+    def test_flush_rejects_unrepresentable_timeout(self, timeout):
+        with pytest.raises(aerospike_py.ParamError):
+            aerospike_py.flush_logs(timeout=timeout)
@@ -38,6 +38,20 @@ class TestGetMetrics:
                 continue
             assert line.startswith("#") or re.match(r"^[a-zA-Z_]", line), f"Unexpected line format: {line!r}"
 
+    @pytest.mark.parametrize(
+        "gauge",
+        [
+            "db_client_cluster_nodes",
+            "db_client_node_partition_generation",
+            "db_client_node_active",
+            "db_client_node_connections_max",
+        ],
+    )
+    def test_contains_cluster_gauge_headers(self, gauge):
+        """Cluster gauges are registered even before any client connects."""
+        text = aerospike_py.get_metrics()
+        assert f"# TYPE {gauge} gauge" in text
+
     def test_multiple_calls_consistent(self):
         """Repeated calls return structurally identical output (no ops between)."""
         a = aerospike_py.get_metrics()
@@ -69,6 +83,26 @@ class TestGetMetrics:
             assert "db_client_operation_duration_seconds" in r
 
 
+class TestGetMetricsDict:
+    def test_returns_samples(self):
+        samples = aerospike_py.get_metrics_dict()
+        assert isinstance(samples, list)
+        for s in samples:
+            assert set(s) == {"name", "labels", "value"}
+            assert isinstance(s["name"], str)
+            assert isinstance(s["labels"], dict)
+            assert isinstance(s["value"], float)
+
+    def test_matches_text_sample_count(self):
+        text_lines = [line for line in aerospike_py.get_metrics().splitlines() if line and not line.startswith("#")]
+        assert len(aerospike_py.get_metrics_dict()) == len(text_lines)
+
+    def test_reset_clears_operation_samples(self):
+        aerospike_py.reset_metrics()
+        names = {s["name"] for s in aerospike_py.get_metrics_dict()}
+        assert not any(n.startswith("db_client_operation_duration_seconds") for n in names)
+
+
 class TestMetricsExports:
     def test_get_metrics_in_all(self):
         assert "get_metrics" in aerospike_py.__all__
@@ -79,6 +113,11 @@ class TestMetricsExports:
     def test_stop_metrics_server_in_all(self):
         assert "stop_metrics_server" in aerospike_py.__all__
 
+    @pytest.mark.parametrize("name", ["get_metrics_dict", "reset_metrics"])
+    def test_snapshot_api_in_all(self, name):
+        assert name in aerospike_py.__all__
+        assert callable(getattr(aerospike_py, name))
+
     def test_get_metrics_callable(self):
         assert callable(aerospike_py.get_metrics)
 
@@ -0,0 +1,101 @@
+"""Unit tests for configure_metrics() (buckets and metric name prefix).
+
+The metrics registry is built once per process on first use, so every case
+runs in a fresh interpreter.
+"""
+
+import subprocess
+import sys
+import textwrap
+
+import pytest
+
+import aerospike_py
+from tests import AEROSPIKE_CONFIG
+
+
+def _run(body: str) -> str:
+    script = "import aerospike_py\n" + textwrap.dedent(body)
+    proc = subprocess.run(
+        [sys.executable, "-c", script],
+        capture_output=True,
+        text=True,
+        timeout=30,
+    )
+    assert proc.returncode == 0, proc.stderr
+    return proc.stdout
+
+
+def test_configure_metrics_exported():
+    assert "configure_metrics" in aerospike_py.__all__
+    assert callable(aerospike_py.configure_metrics)
+
+
+def test_custom_buckets_appear_in_output():
+    # Bucket lines are only written once an operation has been recorded, so
+    # this needs a server; a miss still records a duration.
+    out = _run(
+        f"""
+        aerospike_py.configure_metrics(buckets=[0.0001, 0.0005, 0.002])
+        try:
+            client = aerospike_py.client({AEROSPIKE_CONFIG!r}).connect()
+        except aerospike_py.AerospikeError:
+            print("no server")
+            raise SystemExit
+        try:
+            client.get(("test", "metrics_config", "missing"))
+        except aerospike_py.RecordNotFound:
+            pass
+        client.close()
+        print(aerospike_py.get_metrics())
+        """
+    )
+    if out.startswith("no server"):
+        pytest.skip("Aerospike server not available")
+    assert "# TYPE db_client_operation_duration_seconds histogram" in out
+    assert 'le="0.0005"' in out
+    assert 'le="0.05"' not in out
+
+
+def test_prefix_applies_to_all_metrics():
+    out = _run(
+        """
+        aerospike_py.configure_metrics(prefix="myapp_aerospike")
+        print(aerospike_py.get_metrics())
+        """
+    )
+    assert "# TYPE myapp_aerospike_db_client_operation_duration_seconds histogram" in out
+    assert "# TYPE myapp_aerospike_db_client_cluster_nodes gauge" in out
+    assert "# TYPE db_client_operation_duration_seconds" not in out
+
+
+def test_configure_after_first_use_raises():
+    out = _run(
+        """
+        aerospike_py.get_metrics()
+        try:
+            aerospike_py.configure_metrics(prefix="late")
+        except aerospike_py.ClientError as e:
+            print("raised:", e)
+        """
+    )
+    assert out.startswith("raised:")
+
+
+@pytest.mark.parametrize(
+    "kwargs",
+    [
+        {"buckets": []},
+        {"buckets": [0.0, 0.1]},
+        {"buckets": [0.5, 0.1]},
+        {"buckets": [0.1, 0.1]},
+        {"prefix": ""},
+        {"prefix": "1app"},
+        {"prefix": "my-app"},
+    ],
+)
+def test_invalid_arguments_raise(kwargs):
+    # Validation happens before the "already in use" check, so this is safe
+    # to run in-process.
+    with pytest.raises(aerospike_py.InvalidArgError):
+        aerospike_py.configure_metrics(**kwargs)
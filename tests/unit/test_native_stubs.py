@@ -0,0 +1,80 @@
+"""Check ``_aerospike.pyi`` against the Rust sources and the built native module."""
+
+from __future__ import annotations
+
+import ast
+import subprocess
+import sys
+from pathlib import Path
+
+import pytest
+
+from aerospike_py import _aerospike
+
+ROOT = Path(__file__).resolve().parent.parent.parent
+STUB_PATH = ROOT / "src" / "aerospike_py" / "_aerospike.pyi"
+GENERATOR = ROOT / "scripts" / "generate-native-stubs.py"
+
+_TREE = ast.parse(STUB_PATH.read_text(encoding="utf-8"))
+_CLASSES = {node.name: node for node in _TREE.body if isinstance(node, ast.ClassDef)}
+
+
+def _top_level_names() -> set[str]:
+    names = set()
+    for node in _TREE.body:
+        if isinstance(node, (ast.ClassDef, ast.FunctionDef)):
+            names.add(node.name)
+        elif isinstance(node, ast.AnnAssign) and isinstance(node.target, ast.Name):
+            names.add(node.target.id)
+        elif isinstance(node, ast.Assign):
+            names.update(t.id for t in node.targets if isinstance(t, ast.Name))
+    return names
+
+
+def test_stub_is_up_to_date():
+    proc = subprocess.run(
+        [sys.executable, str(GENERATOR), "--check"],
+        capture_output=True,
+        text=True,
+        timeout=60,
+    )
+    assert proc.returncode == 0, proc.stdout + proc.stderr
+
+
+def test_stub_covers_native_module():
+    native = {name for name in dir(_aerospike) if not name.startswith("__")}
+    assert native - _top_level_names() == set()
+
+
+@pytest.mark.parametrize("name", ["Client", "AsyncClient", "Query", "Pipeline", "BatchRecords", "BatchReadHandle"])
+def test_stub_covers_class_members(name):
+    stubbed = {node.name for node in _CLASSES[name].body if isinstance(node, ast.FunctionDef)}
+    native = {attr for attr in vars(getattr(_aerospike, name)) if not attr.startswith("__")}
+    assert native - stubbed == set()
+
+
+def test_async_methods_return_awaitables():
+    methods = {node.name: node for node in _CLASSES["AsyncClient"].body if isinstance(node, ast.FunctionDef)}
+    assert ast.unparse(methods["get"].returns) == "Awaitable[Any]"
+    assert ast.unparse(methods["query"].returns) == "Query"
+
+
+def test_policy_parameters_are_typed():
+    methods = {node.name: node for node in _CLASSES["Client"].body if isinstance(node, ast.FunctionDef)}
+
+    def policy(method: str) -> str:
+        arg = next(a for a in methods[method].args.args if a.arg == "policy")
+        return ast.unparse(arg.annotation)
+
+    assert policy("get") == "ReadPolicy | None"
+    assert policy("put") == "WritePolicy | None"
+    assert policy("operate") == "OperatePolicy | None"
+    assert policy("batch_read") == "BatchPolicy | None"
+    assert policy("admin_create_user") == "AdminPolicy | None"
+
+
+def test_constant_literals_match_runtime():
+    for node in _TREE.body:
+        if isinstance(node, ast.AnnAssign) and ast.unparse(node.annotation).startswith("Literal["):
+            expected = ast.literal_eval(node.annotation.slice)  # type: ignore[attr-defined]
+            assert getattr(_aerospike, node.target.id) == expected, node.target.id  # type: ignore[union-attr]
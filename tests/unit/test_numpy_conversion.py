@@ -7,6 +7,7 @@ from typing import Any
 import numpy as np
 import pytest
 
+import aerospike_py
 from aerospike_py.numpy_batch import NumpyBatchRecords, _batch_records_to_numpy
 
 
@@ -150,16 +151,33 @@ class TestSubArrayDtype:
 
 
 class TestDtypeValidation:
-    def test_unicode_string_rejected(self):
-        dtype = np.dtype([("name", "U10")])
-        batch = _make_batch_records([])
-        with pytest.raises(TypeError, match="kind='U'"):
-            _batch_records_to_numpy(batch, dtype, [])
+    def test_unicode_and_bool_accepted(self):
+        dtype = np.dtype([("name", "U4"), ("active", "?")])
+        batch = _make_batch_records(
+            [
+                _make_batch_record(
+                    ("test", "demo", "k1"),
+                    0,
+                    (None, {"gen": 1, "ttl": 100}, {"name": "alexander", "active": True}),
+                ),
+            ]
+        )
+        result = _batch_records_to_numpy(batch, dtype, ["k1"])
+        assert result.batch_records[0]["name"] == "alex"
+        assert result.batch_records[0]["active"]
+
+    def test_datetime64_from_epoch_int(self):
+        dtype = np.dtype([("ts", "M8[ms]")])
+        batch = _make_batch_records(
+            [_make_batch_record(("test", "demo", "k1"), 0, (None, {"gen": 1, "ttl": 100}, {"ts": 1_700_000_000_123}))]
+        )
+        result = _batch_records_to_numpy(batch, dtype, ["k1"])
+        assert result.batch_records[0]["ts"] == np.datetime64(1_700_000_000_123, "ms")
 
     def test_object_rejected(self):
         dtype = np.dtype([("data", "O")])
         batch = _make_batch_records([])
-        with pytest.raises(TypeError, match="kind='O'"):
+        with pytest.raises(aerospike_py.ParamError, match="kind='O'"):
             _batch_records_to_numpy(batch, dtype, [])
 
 
@@ -207,6 +225,22 @@ class TestMissingBins:
         result = _batch_records_to_numpy(batch, dtype, [("test", "demo", "k1")])
         assert result.batch_records[0]["a"] == 5
         assert result.batch_records[0]["b"] == 0.0
+        assert result.null_mask is None
+
+    def test_null_mask_distinguishes_missing_from_zero(self):
+        dtype = np.dtype([("a", "i4"), ("b", "f8")])
+        batch = _make_batch_records(
+            [
+                _make_batch_record(("test", "demo", "k1"), 0, (None, {"gen": 1, "ttl": 0}, {"a": 0})),
+                _make_batch_record(("test", "demo", "k2"), 2, None),
+            ]
+        )
+        keys = [("test", "demo", "k1"), ("test", "demo", "k2")]
+        result = _batch_records_to_numpy(batch, dtype, keys, null_mask=True)
+
+        assert result.null_mask.dtype.names == ("a", "b")
+        np.testing.assert_array_equal(result.null_mask["a"], [False, True])
+        np.testing.assert_array_equal(result.null_mask["b"], [True, True])
 
     def test_none_bins(self):
         dtype = np.dtype([("a", "i4")])
@@ -0,0 +1,37 @@
+"""Unit tests for the ``OperatePolicy`` TypedDict."""
+
+from typing import get_type_hints
+
+import aerospike_py
+from aerospike_py.types import OperatePolicy, WritePolicy
+
+
+def test_operate_policy_importable_from_top_level():
+    """``OperatePolicy`` is exported from the top-level ``aerospike_py`` package."""
+    assert aerospike_py.OperatePolicy is OperatePolicy
+    assert "OperatePolicy" in aerospike_py.__all__
+
+
+def test_operate_policy_empty_dict_is_valid():
+    """All fields are optional — empty ``OperatePolicy()`` must be allowed."""
+    assert OperatePolicy() == {}
+
+
+def test_operate_policy_extends_write_policy():
+    """Every ``WritePolicy`` key is also accepted by ``OperatePolicy``."""
+    hints = get_type_hints(OperatePolicy)
+    assert set(get_type_hints(WritePolicy)) <= set(hints)
+
+
+def test_operate_policy_read_side_fields():
+    """Read-side fields are annotated on top of the write fields."""
+    hints = get_type_hints(OperatePolicy)
+    for field in ("read_mode_ap", "replica", "read_mode_sc", "respond_all_ops", "sleep_between_retries"):
+        assert field in hints
+
+
+def test_read_mode_sc_constants():
+    assert aerospike_py.POLICY_READ_MODE_SC_SESSION == 0
+    assert aerospike_py.POLICY_READ_MODE_SC_LINEARIZE == 1
+    assert aerospike_py.POLICY_READ_MODE_SC_ALLOW_REPLICA == 2
+    assert aerospike_py.POLICY_READ_MODE_SC_ALLOW_UNAVAILABLE == 3
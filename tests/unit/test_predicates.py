@@ -57,6 +57,12 @@ def test_between(bin_name, min_val, max_val):
     assert result[0] == "between"
 
 
+def test_in_list():
+    """in_list() produces a 3-tuple with the values as a list."""
+    result = p.in_list("status", ("active", "trial"))
+    assert result == ("in_list", "status", ["active", "trial"])
+
+
 # ── Contains predicate ────────────────────────────────────────────
 
 
@@ -1,8 +1,9 @@
 """Unit tests for put() input validation (no server required).
 
 Covers:
-- #118: put(key, None) should raise TypeError, not RecordNotFound
-- put(key, non_dict) should raise TypeError
+- #118: put(key, None) should raise ParamError, not RecordNotFound
+- put(key, non_dict) should raise ParamError
+- put(key, None, policy={"clear_on_none": True}) is accepted
 """
 
 import pytest
@@ -30,8 +31,38 @@ def _make_client():
     ],
     ids=["None", "string", "int", "list", "tuple", "bool", "bytes", "float", "set"],
 )
-def test_put_non_dict_bins_raises_type_error(invalid_bins, desc):
-    """put(key, non_dict) raises TypeError for type: {desc}."""
+def test_put_non_dict_bins_raises_param_error(invalid_bins, desc):
+    """put(key, non_dict) raises ParamError for type: {desc}."""
     c = _make_client()
-    with pytest.raises(TypeError):
+    with pytest.raises(aerospike_py.ParamError):
         c.put(("test", "demo", "k1"), invalid_bins)
+
+
+def test_put_none_bins_error_names_clear_on_none():
+    """The ParamError for put(key, None) points at the opt-in flag."""
+    c = _make_client()
+    with pytest.raises(aerospike_py.ParamError, match="clear_on_none"):
+        c.put(("test", "demo", "k1"), None)
+
+
+def test_put_none_bins_with_clear_on_none_passes_validation():
+    """With clear_on_none, put(key, None) gets past validation to the connection check."""
+    c = _make_client()
+    with pytest.raises(aerospike_py.ClientError) as exc_info:
+        c.put(("test", "demo", "k1"), None, policy={"clear_on_none": True})
+    assert not isinstance(exc_info.value, aerospike_py.ParamError)
+
+
+def test_clear_on_none_does_not_accept_other_types():
+    """clear_on_none only changes the meaning of None."""
+    c = _make_client()
+    with pytest.raises(aerospike_py.ParamError):
+        c.put(("test", "demo", "k1"), "string", policy={"clear_on_none": True})
+
+
+@pytest.mark.parametrize("where", ["policy", "meta"])
+def test_put_rejects_unknown_key_policy(where):
+    """A ``key`` value other than POLICY_KEY_DIGEST/POLICY_KEY_SEND is a ParamError."""
+    c = _make_client()
+    with pytest.raises(aerospike_py.ParamError, match="POLICY_KEY_SEND"):
+        c.put(("test", "demo", "k1"), {"a": 1}, **{where: {"key": 2}})
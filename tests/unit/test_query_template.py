@@ -0,0 +1,34 @@
+"""Unit tests for queries built with ``Query(...)`` and bound to no client."""
+
+import pytest
+
+import aerospike_py
+from aerospike_py import predicates as p
+
+
+def test_detached_query_builds_without_client():
+    query = aerospike_py.Query("test", "demo")
+    query.select("name", {"years": "age"})
+    query.where(p.between("age", 20, 30))
+    query.max_records(10)
+
+
+def test_detached_query_cannot_run_itself():
+    query = aerospike_py.Query("test")
+    with pytest.raises(aerospike_py.ClientError, match="client.execute"):
+        query.results()
+    with pytest.raises(aerospike_py.ClientError, match="client.execute"):
+        query.foreach(lambda record: None)
+
+
+def test_detached_query_validates_names():
+    with pytest.raises(aerospike_py.ParamError):
+        aerospike_py.Query("")
+    with pytest.raises(aerospike_py.ParamError):
+        aerospike_py.Query("test", "bad;set")
+
+
+def test_execute_requires_connected_client():
+    client = aerospike_py.client({"hosts": [("127.0.0.1", 3000)]})
+    with pytest.raises(aerospike_py.ClientError, match="not connected"):
+        client.execute(aerospike_py.Query("test", "demo"))
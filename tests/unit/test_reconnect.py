@@ -0,0 +1,83 @@
+"""Unit tests for aerospike_py._reconnect (auto_reconnect)."""
+
+import logging
+
+import pytest
+
+import aerospike_py
+from aerospike_py._reconnect import AutoReconnect
+from tests import DUMMY_CONFIG
+
+
+class TestAutoReconnectConfig:
+    def test_disabled_by_default(self):
+        assert AutoReconnect.from_config({"hosts": []}) is None
+        assert AutoReconnect.from_config({"auto_reconnect": False}) is None
+
+    @pytest.mark.parametrize("value", [1, "yes", None])
+    def test_rejects_non_bool(self, value):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            AutoReconnect.from_config({"auto_reconnect": value})
+
+    def test_client_rejects_bad_config(self):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            aerospike_py.Client({**DUMMY_CONFIG, "auto_reconnect": "yes"})
+
+    def test_async_client_rejects_auto_reconnect(self):
+        with pytest.raises(aerospike_py.InvalidArgError, match="sync Client"):
+            aerospike_py.AsyncClient({**DUMMY_CONFIG, "auto_reconnect": True})
+
+
+class TestReconnectWhenDisconnected:
+    def test_lost_connection_reconnects_before_operation(self, make_client):
+        client = make_client({"auto_reconnect": True}, connected=False)
+        client.connect("admin", "secret")
+        client.connected = False  # cluster dropped
+        assert client.get("k") == "k"
+        assert client.reconnects == [("admin", "secret")]
+
+    def test_connected_client_does_not_reconnect(self, make_client):
+        client = make_client({"auto_reconnect": True}, connected=False)
+        client.connect()
+        client.get("k")
+        assert client.reconnects == []
+
+    def test_never_connected_client_is_not_connected(self, make_client):
+        client = make_client({"auto_reconnect": True}, connected=False)
+        with pytest.raises(aerospike_py.ClientError):
+            client.get("k")
+        assert client.reconnects == []
+
+    def test_explicit_close_disables_reconnect(self, make_client):
+        client = make_client({"auto_reconnect": True}, connected=False)
+        client.connect()
+        client.close()
+        with pytest.raises(aerospike_py.ClientError):
+            client.get("k")
+        assert client.reconnects == []
+
+    def test_disabled_raises(self, make_client):
+        client = make_client({"auto_reconnect": False}, connected=False)
+        client.connect()
+        client.connected = False
+        with pytest.raises(aerospike_py.ClientError):
+            client.get("k")
+        assert client.reconnects == []
+
+    def test_failed_reconnect_retries_on_next_operation(self, make_client, caplog):
+        client = make_client({"auto_reconnect": True}, connected=False, reconnect_fails=True)
+        client.connect()
+        client.connected = False
+        with caplog.at_level(logging.WARNING, logger="aerospike_py"):
+            for _ in range(2):
+                with pytest.raises(aerospike_py.ClientError):
+                    client.get("k")
+        assert len(client.reconnects) == 2
+        assert "Reconnect failed" in caplog.text
+
+    def test_is_connected_is_not_wrapped(self, make_client):
+        client = make_client({"auto_reconnect": True}, connected=False)
+        client.connect()
+        client.connected = False
+        assert client.is_connected() is False
+        assert client.reconnects == []
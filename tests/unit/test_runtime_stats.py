@@ -0,0 +1,22 @@
+"""Unit tests for aerospike_py.runtime_stats() (no server required)."""
+
+import aerospike_py
+
+
+def test_runtime_stats_keys():
+    stats = aerospike_py.runtime_stats()
+    assert set(stats) == {"sync_runtime", "async_runtime", "blocked_threads", "block_on_calls"}
+    assert stats["blocked_threads"] >= 0
+    assert stats["block_on_calls"] >= 0
+
+
+def test_runtime_metrics_fields():
+    for name in ("sync_runtime", "async_runtime"):
+        metrics = aerospike_py.runtime_stats()[name]
+        if metrics is not None:
+            assert set(metrics) == {"workers", "alive_tasks", "queued_tasks"}
+            assert metrics["workers"] >= 1
+
+
+def test_no_thread_blocked_outside_client_calls():
+    assert aerospike_py.runtime_stats()["blocked_threads"] == 0
@@ -71,6 +71,9 @@ def test_scan_policy_field_annotations():
         "socket_timeout",
         "total_timeout",
         "max_retries",
+        "backoff_base_ms",
+        "backoff_cap_ms",
+        "retry_on",
         "timeout_delay",
         "filter_expression",
         "replica",
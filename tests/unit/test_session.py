@@ -0,0 +1,71 @@
+"""Unit tests for aerospike_py._session (re-login after SessionExpired)."""
+
+import pytest
+
+import aerospike_py
+from aerospike_py._session import RETRIED_AFTER_RELOGIN
+
+
+class TestReloginOnSessionExpired:
+    def test_success_does_not_relogin(self, make_client):
+        client = make_client()
+        assert client.get("k") == "k"
+        assert (client.calls, client.relogins) == (1, 0)
+
+    def test_retries_once_after_relogin(self, make_client):
+        client = make_client(failures=1)
+        assert client.get("k") == "k"
+        assert (client.calls, client.relogins) == (2, 1)
+
+    def test_second_expiry_propagates(self, make_client):
+        client = make_client(failures=2)
+        with pytest.raises(aerospike_py.SessionExpired):
+            client.get("k")
+        assert (client.calls, client.relogins) == (2, 1)
+
+    def test_batch_write_relogins_without_retry(self, make_client):
+        client = make_client(failures=1)
+        with pytest.raises(aerospike_py.SessionExpired):
+            client.batch_write([])
+        assert (client.calls, client.relogins) == (1, 1)
+
+    def test_only_catch_unexpected_methods_are_wrapped(self, make_client):
+        client = make_client()
+        with pytest.raises(aerospike_py.SessionExpired):
+            client.undecorated()
+        assert client.relogins == 0
+
+    def test_other_errors_are_not_retried(self, make_client):
+        client = make_client()
+        with pytest.raises(aerospike_py.AerospikeTimeoutError):
+            client.fail("k")
+        assert (client.calls, client.relogins) == (1, 0)
+
+    async def test_async_retries_once_after_relogin(self, make_client):
+        client = make_client(asynchronous=True, failures=1)
+        assert await client.get("k") == "k"
+        assert (client.calls, client.relogins) == (2, 1)
+
+    async def test_async_second_expiry_propagates(self, make_client):
+        client = make_client(asynchronous=True, failures=2)
+        with pytest.raises(aerospike_py.SessionExpired):
+            await client.get("k")
+        assert (client.calls, client.relogins) == (2, 1)
+
+    async def test_async_batch_write_relogins_without_retry(self, make_client):
+        client = make_client(asynchronous=True, failures=1)
+        with pytest.raises(aerospike_py.SessionExpired):
+            await client.batch_write([])
+        assert (client.calls, client.relogins) == (1, 1)
+
+    @pytest.mark.parametrize(
+        "name", ["batch_write", "batch_operate", "batch_apply", "batch_remove", "bulk_put", "batch_write_numpy"]
+    )
+    def test_multi_node_writes_are_not_retried(self, name):
+        assert name not in RETRIED_AFTER_RELOGIN
+        assert hasattr(aerospike_py.Client, name)
+        assert hasattr(aerospike_py.AsyncClient, name)
+
+
+def test_session_expired_is_admin_error():
+    assert issubclass(aerospike_py.SessionExpired, aerospike_py.AdminError)
@@ -0,0 +1,94 @@
+"""Unit tests for aerospike_py._slow_log (slow_log_ms / slow_log_callback)."""
+
+import logging
+
+import pytest
+
+import aerospike_py
+from aerospike_py._slow_log import SlowLog
+
+KEY = ("test", "demo", "user1")
+
+
+class TestSlowLogConfig:
+    def test_disabled_without_threshold(self):
+        assert SlowLog.from_config({"hosts": []}) is None
+
+    @pytest.mark.parametrize("value", [-1, "50", True])
+    def test_rejects_bad_threshold(self, value):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            SlowLog.from_config({"slow_log_ms": value})
+
+    def test_rejects_non_callable_callback(self):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            SlowLog.from_config({"slow_log_ms": 10, "slow_log_callback": "nope"})
+
+    def test_callback_requires_threshold(self):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            SlowLog.from_config({"slow_log_callback": print})
+
+    @pytest.mark.parametrize("cls", [aerospike_py.Client, aerospike_py.AsyncClient])
+    def test_client_rejects_bad_config(self, cls):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            cls({"hosts": [("127.0.0.1", 3000)], "slow_log_ms": -5})
+
+
+class TestSlowOperationLog:
+    def test_callback_receives_slow_operation(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append})
+        assert client.get(KEY) == KEY
+        (op,) = seen
+        assert op["operation"] == "get"
+        assert (op["namespace"], op["set"]) == ("test", "demo")
+        assert op["digest"] == aerospike_py.calc_digest("test", "demo", "user1")
+        assert op["latency_ms"] >= 0
+        assert op["threshold_ms"] == 0
+
+    def test_fast_operation_not_reported(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 10_000, "slow_log_callback": seen.append})
+        client.get(KEY)
+        assert seen == []
+
+    def test_batch_reports_first_key_without_digest(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append})
+        client.batch_read([KEY, ("test", "other", 2)])
+        assert (seen[0]["namespace"], seen[0]["set"], seen[0]["digest"]) == ("test", "demo", None)
+
+    def test_failed_operation_is_reported(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append})
+        with pytest.raises(aerospike_py.AerospikeTimeoutError):
+            client.fail(KEY)
+        assert seen[0]["operation"] == "fail"
+
+    def test_logs_warning_without_callback(self, make_client, caplog):
+        client = make_client({"slow_log_ms": 1}, delay=0.01)
+        with caplog.at_level(logging.WARNING, logger="aerospike_py"):
+            client.get(KEY)
+        (record,) = [r for r in caplog.records if hasattr(r, "slow_operation")]
+        assert "Slow operation get on test/demo" in record.getMessage()
+        assert record.slow_operation["latency_ms"] >= 1
+
+    def test_callback_errors_do_not_propagate(self, make_client, caplog):
+        def boom(_op):
+            raise RuntimeError("boom")
+
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": boom})
+        with caplog.at_level(logging.ERROR, logger="aerospike_py"):
+            assert client.get(KEY) == KEY
+        assert "slow_log_callback raised" in caplog.text
+
+    async def test_async_callback(self, make_client):
+        seen = []
+        client = make_client({"slow_log_ms": 0, "slow_log_callback": seen.append}, asynchronous=True)
+        assert await client.get(KEY) == KEY
+        assert seen[0]["operation"] == "get"
+
+
+def test_calc_digest_matches_key_digest():
+    digest = aerospike_py.calc_digest("test", "demo", "user1")
+    assert isinstance(digest, bytes) and len(digest) == 20
+    assert aerospike_py.calc_digest("test", "demo", 1) != aerospike_py.calc_digest("test", "demo", "1")
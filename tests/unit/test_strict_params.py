@@ -0,0 +1,77 @@
+"""Unit tests for aerospike_py._strict_params (strict_params)."""
+
+import pytest
+
+import aerospike_py
+from aerospike_py._strict_params import strict_params_from_config
+from tests import DUMMY_CONFIG
+
+KEY = ("test", "demo", "k")
+STRICT = {"strict_params": True}
+
+
+class TestStrictParamsConfig:
+    def test_disabled_by_default(self):
+        assert strict_params_from_config({"hosts": []}) is False
+
+    @pytest.mark.parametrize("value", [1, "yes", None])
+    def test_rejects_non_bool(self, value):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            strict_params_from_config({"strict_params": value})
+
+    @pytest.mark.parametrize("cls", [aerospike_py.Client, aerospike_py.AsyncClient])
+    def test_client_rejects_bad_config(self, cls):
+        with pytest.raises(aerospike_py.InvalidArgError):
+            cls({**DUMMY_CONFIG, "strict_params": "yes"})
+
+
+class TestValidateParams:
+    def test_accepts_known_keys(self, make_client):
+        client = make_client(STRICT)
+        assert client.get(KEY, policy={"total_timeout": 100, "backoff_base_ms": 5}) == KEY
+        ops = [{"op": aerospike_py.OPERATOR_READ, "bin": "a"}]
+        assert client.operate(KEY, ops, policy={"respond_all_ops": True}) == ops
+        assert client.truncate("test", "demo", policy={"timeout": 100}) == "test"
+
+    def test_unknown_policy_key_lists_accepted_keys(self, make_client):
+        client = make_client(STRICT)
+        with pytest.raises(aerospike_py.InvalidArgError, match="'total_timout' in get\\(\\) policy") as exc_info:
+            client.get(KEY, {"total_timout": 100})
+        assert "total_timeout" in str(exc_info.value)
+
+    def test_unknown_op_key(self, make_client):
+        client = make_client(STRICT)
+        with pytest.raises(aerospike_py.InvalidArgError, match="return_tyep"):
+            client.operate(KEY, [{"op": 1001, "bin": "a", "return_tyep": 1}])
+
+    def test_unknown_nested_policy_key(self, make_client):
+        client = make_client(STRICT)
+        with pytest.raises(aerospike_py.InvalidArgError, match="list_policy"):
+            client.operate(KEY, [{"op": 1001, "bin": "a", "list_policy": {"ordr": 1}}])
+
+    def test_op_builder_objects_are_not_checked(self, make_client):
+        class Op:
+            def __op_dict__(self):
+                return {"op": aerospike_py.OPERATOR_READ}
+
+        ops = [Op()]
+        assert make_client(STRICT).operate(KEY, ops) == ops
+
+    def test_disabled_ignores_unknown_keys(self, make_client):
+        client = make_client()
+        assert client.get(KEY, policy={"total_timout": 100}) == KEY
+
+    def test_client_rejects_before_connecting(self):
+        client = aerospike_py.Client({**DUMMY_CONFIG, "strict_params": True})
+        with pytest.raises(aerospike_py.InvalidArgError):
+            client.put(KEY, {"a": 1}, policy={"tll": 10})
+
+    async def test_async_client_rejects_unknown_key(self):
+        client = aerospike_py.AsyncClient({**DUMMY_CONFIG, "strict_params": True})
+        with pytest.raises(aerospike_py.InvalidArgError):
+            await client.get(KEY, policy={"total_timout": 100})
+
+    def test_job_info_policy_is_an_admin_policy(self):
+        client = aerospike_py.Client({**DUMMY_CONFIG, "strict_params": True})
+        with pytest.raises(aerospike_py.InvalidArgError, match="accepted keys: timeout"):
+            client.scan_info(1, policy={"total_timeout": 100})
@@ -0,0 +1,60 @@
+"""Unit tests for the background task handles (no server required)."""
+
+import pytest
+
+import aerospike_py
+from aerospike_py import AsyncTask, Task
+
+
+class _Countdown(Task):
+    def __init__(self, polls):
+        self.polls = polls
+
+    def done(self):
+        self.polls -= 1
+        return self.polls <= 0
+
+
+class _AsyncCountdown(AsyncTask):
+    def __init__(self, polls):
+        self.polls = polls
+
+    async def done(self):
+        self.polls -= 1
+        return self.polls <= 0
+
+
+class TestTask:
+    def test_wait_polls_until_done(self):
+        task = _Countdown(3)
+        task.wait(poll_interval=0)
+        assert task.polls == 0
+
+    def test_wait_times_out(self):
+        with pytest.raises(aerospike_py.AerospikeTimeoutError, match="not finished"):
+            _Countdown(10**9).wait(timeout=0.05, poll_interval=0.01)
+
+    def test_progress_defaults_to_none(self):
+        assert _Countdown(1).progress() is None
+
+    def test_index_task_is_a_task(self):
+        assert issubclass(aerospike_py.IndexTask, Task)
+        assert issubclass(aerospike_py.UdfTask, Task)
+        assert issubclass(aerospike_py.TruncateTask, Task)
+
+
+class TestAsyncTask:
+    @pytest.mark.asyncio
+    async def test_wait_polls_until_done(self):
+        task = _AsyncCountdown(3)
+        await task.wait(poll_interval=0)
+        assert task.polls == 0
+
+    @pytest.mark.asyncio
+    async def test_wait_times_out(self):
+        with pytest.raises(aerospike_py.AerospikeTimeoutError, match="not finished"):
+            await _AsyncCountdown(10**9).wait(timeout=0.05, poll_interval=0.01)
+
+    @pytest.mark.asyncio
+    async def test_progress_defaults_to_none(self):
+        assert await _AsyncCountdown(1).progress() is None
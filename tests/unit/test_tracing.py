@@ -256,6 +256,29 @@ class TestTracingEnvVars:
         aerospike_py.shutdown_tracing()
 
 
+class TestTracingOptions:
+    """init_tracing() keyword options."""
+
+    def test_options_accepted(self, monkeypatch):
+        monkeypatch.setenv("OTEL_SDK_DISABLED", "true")
+        aerospike_py.init_tracing(
+            sample_ratio=0.01,
+            service_name="checkout-api",
+            span_name_format="aerospike {operation} {namespace}/{set}",
+        )
+        aerospike_py.shutdown_tracing()
+
+    @pytest.mark.parametrize("ratio", [-0.1, 1.5, float("nan")])
+    def test_invalid_sample_ratio(self, ratio):
+        with pytest.raises(aerospike_py.InvalidArgError, match="sample_ratio"):
+            aerospike_py.init_tracing(sample_ratio=ratio)
+
+    @pytest.mark.parametrize("template", ["", "{op}", "{operation", "operation}"])
+    def test_invalid_span_name_format(self, template):
+        with pytest.raises(aerospike_py.InvalidArgError, match="span_name_format"):
+            aerospike_py.init_tracing(span_name_format=template)
+
+
 # ---------------------------------------------------------------------------
 # Async client tracing tests
 # ---------------------------------------------------------------------------